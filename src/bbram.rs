@@ -0,0 +1,320 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::bus::AccessCode;
+use crate::bus::Device;
+use crate::err::BusError;
+
+use std::fmt::Debug;
+use std::fmt::Error;
+use std::fmt::Formatter;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+const START_ADDRESS: usize = 0x600000;
+const END_ADDRESS: usize = 0x602000;
+const ADDRESS_RANGE: Range<usize> = START_ADDRESS..END_ADDRESS;
+
+/// Size of the NVRAM image as seen by hosts, covering the whole
+/// address range of the device.
+pub const NVRAM_SIZE: usize = END_ADDRESS - START_ADDRESS;
+
+/// The BBRAM is a 2K x 8 part wired to a single byte lane of the
+/// 32-bit data bus, so only the byte at offset 2 of each word is
+/// backed by a memory cell.
+const LANE_OFFSET: usize = 2;
+
+///
+/// Battery-backed RAM (Non-volatile RAM)
+///
+/// The firmware keeps the terminal's setup options here. Hosts see
+/// the device as a flat image of `NVRAM_SIZE` bytes, in which only
+/// every fourth byte is significant. The image may be bound to a file,
+/// in which case it is loaded from the file when bound and written
+/// back whenever it has changed and `flush` is called, or when the
+/// device is dropped.
+///
+pub struct Bbram {
+    ram: Vec<u8>,
+    file: Option<PathBuf>,
+    dirty: bool,
+}
+
+impl Default for Bbram {
+    fn default() -> Self {
+        Bbram::new()
+    }
+}
+
+impl Bbram {
+    pub fn new() -> Bbram {
+        Bbram {
+            ram: vec![0; NVRAM_SIZE],
+            file: None,
+            dirty: false,
+        }
+    }
+
+    /// Return the full NVRAM image.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.ram
+    }
+
+    /// Replace the NVRAM image with the supplied bytes. Images shorter
+    /// than `NVRAM_SIZE` only replace the start of the image.
+    pub fn set_image(&mut self, image: &[u8]) {
+        for (i, b) in image.iter().take(NVRAM_SIZE).enumerate() {
+            self.ram[i] = *b;
+        }
+        self.dirty = true;
+    }
+
+    /// Bind the NVRAM to a file. If the file exists, its contents
+    /// replace the current image; otherwise it is created from the
+    /// current image.
+    pub fn bind(&mut self, path: &Path) -> io::Result<()> {
+        if path.exists() {
+            let image = fs::read(path)?;
+            if image.len() != NVRAM_SIZE {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("NVRAM file must be {} bytes, found {}", NVRAM_SIZE, image.len()),
+                ));
+            }
+            self.ram.copy_from_slice(&image);
+            self.dirty = false;
+        } else {
+            self.dirty = true;
+        }
+
+        self.file = Some(path.to_path_buf());
+        self.flush()
+    }
+
+    /// Stop persisting the NVRAM, after writing any pending changes.
+    pub fn unbind(&mut self) -> io::Result<()> {
+        let result = self.flush();
+        self.file = None;
+        result
+    }
+
+    /// Write the image to the bound file, if it has changed since the
+    /// last flush.
+    pub fn flush(&mut self) -> io::Result<()> {
+        if let Some(path) = &self.file {
+            if self.dirty {
+                fs::write(path, &self.ram)?;
+                self.dirty = false;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn is_cell(address: usize) -> bool {
+        address & 3 == LANE_OFFSET
+    }
+}
+
+impl Drop for Bbram {
+    fn drop(&mut self) {
+        // Nothing useful can be done with an error at this point.
+        let _ = self.flush();
+    }
+}
+
+impl Debug for Bbram {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "[BBRAM]")
+    }
+}
+
+impl Device for Bbram {
+    fn address_range(&self) -> &Range<usize> {
+        &ADDRESS_RANGE
+    }
+
+    fn name(&self) -> &str {
+        "BBRAM"
+    }
+
+    fn is_read_only(&self) -> bool {
+        false
+    }
+
+    fn read_byte(&mut self, address: usize, _access: AccessCode) -> Result<u8, BusError> {
+        self.peek_byte(address)
+    }
+
+    fn read_half(&mut self, address: usize, _access: AccessCode) -> Result<u16, BusError> {
+        self.peek_half(address)
+    }
+
+    fn read_word(&mut self, address: usize, _access: AccessCode) -> Result<u32, BusError> {
+        self.peek_word(address)
+    }
+
+    /// Bytes outside the connected lane are not driven, and read as 0.
+    fn peek_byte(&self, address: usize) -> Result<u8, BusError> {
+        if !ADDRESS_RANGE.contains(&address) {
+            return Err(BusError::Range);
+        }
+
+        if Bbram::is_cell(address) {
+            Ok(self.ram[address - START_ADDRESS])
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn peek_half(&self, address: usize) -> Result<u16, BusError> {
+        let hi = self.peek_byte(address)?;
+        let lo = self.peek_byte(address + 1)?;
+        Ok(u16::from_be_bytes([hi, lo]))
+    }
+
+    fn peek_word(&self, address: usize) -> Result<u32, BusError> {
+        let hi = self.peek_half(address)?;
+        let lo = self.peek_half(address + 2)?;
+        Ok(u32::from(hi) << 16 | u32::from(lo))
+    }
+
+    /// Writes to bytes outside the connected lane are ignored.
+    fn write_byte(&mut self, address: usize, val: u8, _access: AccessCode) -> Result<(), BusError> {
+        if !ADDRESS_RANGE.contains(&address) {
+            return Err(BusError::Range);
+        }
+
+        if Bbram::is_cell(address) {
+            let cell = &mut self.ram[address - START_ADDRESS];
+            if *cell != val {
+                *cell = val;
+                self.dirty = true;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_half(&mut self, address: usize, val: u16, access: AccessCode) -> Result<(), BusError> {
+        let [hi, lo] = val.to_be_bytes();
+        self.write_byte(address, hi, AccessCode::Write)?;
+        self.write_byte(address + 1, lo, access)
+    }
+
+    fn write_word(&mut self, address: usize, val: u32, access: AccessCode) -> Result<(), BusError> {
+        self.write_half(address, (val >> 16) as u16, AccessCode::Write)?;
+        self.write_half(address + 2, val as u16, access)
+    }
+
+    /// Load a raw image into the device, starting at the specified
+    /// absolute address.
+    fn load(&mut self, address: usize, data: &[u8]) -> Result<(), BusError> {
+        let offset = address.wrapping_sub(START_ADDRESS);
+
+        if offset + data.len() > NVRAM_SIZE {
+            return Err(BusError::Range);
+        }
+
+        self.ram[offset..offset + data.len()].copy_from_slice(data);
+        self.dirty = true;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("dmd_core_{}_{}.bin", name, process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn only_one_byte_lane_is_connected() {
+        let mut bbram = Bbram::new();
+
+        for offset in 0..4 {
+            bbram.write_byte(START_ADDRESS + 0x10 + offset, 0x5a, AccessCode::Write).unwrap();
+        }
+
+        assert_eq!(0, bbram.read_byte(START_ADDRESS + 0x10, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0, bbram.read_byte(START_ADDRESS + 0x11, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0x5a, bbram.read_byte(START_ADDRESS + 0x12, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0, bbram.read_byte(START_ADDRESS + 0x13, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0x5a, bbram.as_slice()[0x12]);
+        assert_eq!(0, bbram.as_slice()[0x10]);
+    }
+
+    #[test]
+    fn wide_accesses_use_bits_8_to_15() {
+        let mut bbram = Bbram::new();
+
+        bbram.write_word(START_ADDRESS + 0x20, 0x1234abcd, AccessCode::Write).unwrap();
+        assert_eq!(0xab, bbram.as_slice()[0x22]);
+        assert_eq!(0x0000ab00, bbram.read_word(START_ADDRESS + 0x20, AccessCode::OperandFetch).unwrap());
+
+        bbram.write_half(START_ADDRESS + 0x26, 0x7e01, AccessCode::Write).unwrap();
+        assert_eq!(0x7e, bbram.as_slice()[0x26]);
+        assert_eq!(0x7e00, bbram.read_half(START_ADDRESS + 0x26, AccessCode::OperandFetch).unwrap());
+    }
+
+    #[test]
+    fn only_changes_mark_image_dirty() {
+        let mut bbram = Bbram::new();
+        assert!(!bbram.is_dirty());
+
+        bbram.write_byte(START_ADDRESS + 2, 0, AccessCode::Write).unwrap();
+        bbram.write_byte(START_ADDRESS + 3, 0x10, AccessCode::Write).unwrap();
+        assert!(!bbram.is_dirty());
+
+        bbram.write_byte(START_ADDRESS + 2, 0x10, AccessCode::Write).unwrap();
+        assert!(bbram.is_dirty());
+    }
+
+    #[test]
+    fn persists_image_to_bound_file() {
+        let path = temp_file("persist");
+
+        {
+            let mut bbram = Bbram::new();
+            bbram.bind(&path).unwrap();
+            assert_eq!(NVRAM_SIZE, fs::read(&path).unwrap().len());
+
+            bbram.write_byte(START_ADDRESS + 0x6e6, 0x03, AccessCode::Write).unwrap();
+            bbram.flush().unwrap();
+            assert_eq!(0x03, fs::read(&path).unwrap()[0x6e6]);
+
+            // Pending changes are written when the device is dropped.
+            bbram.write_byte(START_ADDRESS + 0x1ffe, 0x0a, AccessCode::Write).unwrap();
+        }
+
+        let mut bbram = Bbram::new();
+        bbram.bind(&path).unwrap();
+        assert_eq!(0x03, bbram.read_byte(START_ADDRESS + 0x6e6, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0x0a, bbram.read_byte(START_ADDRESS + 0x1ffe, AccessCode::OperandFetch).unwrap());
+
+        bbram.unbind().unwrap();
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn rejects_file_of_wrong_size() {
+        let path = temp_file("wrong_size");
+        fs::write(&path, [0u8; 16]).unwrap();
+
+        let mut bbram = Bbram::new();
+        assert!(bbram.bind(&path).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
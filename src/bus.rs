@@ -1,13 +1,28 @@
 #![allow(clippy::unreadable_literal)]
 
+use crate::bbram::Bbram;
+use crate::debug::{Watch, WatchHit, Watchpoint};
 use crate::err::BusError;
 use crate::mem::Mem;
 use crate::duart::Duart;
+use crate::duart::{LineError, ModemLine};
+use crate::keyboard::Keyboard;
 use crate::mouse::Mouse;
 use std::fmt::Debug;
+use std::io;
 use std::ops::Range;
+use std::path::Path;
 
-const NVRAM_SIZE: usize = 8192;
+//
+// Interrupt Request Lines
+//
+// The DMD routes six interrupt request lines from the DUART to the
+// CPU. Lines 0-2 request IPL 14 and lines 3-5 request IPL 15. When
+// several lines are active at once, the highest line sets the level,
+// and the vector read during the acknowledge cycle is the complement
+// of all active lines.
+//
+const IRQ_LINE_IPL: [u32; 6] = [14, 14, 14, 15, 15, 15];
 
 /// Access Status Code
 pub enum AccessCode {
@@ -38,12 +53,27 @@ pub trait Device: Send + Sync + Debug {
     fn read_byte(&mut self, address: usize, access: AccessCode) -> Result<u8, BusError>;
     fn read_half(&mut self, address: usize, access: AccessCode) -> Result<u16, BusError>;
     fn read_word(&mut self, address: usize, access: AccessCode) -> Result<u32, BusError>;
+    /// Read without side effects, for debuggers.
+    fn peek_byte(&self, address: usize) -> Result<u8, BusError>;
+    fn peek_half(&self, address: usize) -> Result<u16, BusError>;
+    fn peek_word(&self, address: usize) -> Result<u32, BusError>;
     fn write_byte(&mut self, address: usize, val: u8, access: AccessCode) -> Result<(), BusError>;
     fn write_half(&mut self, address: usize, val: u16, access: AccessCode) -> Result<(), BusError>;
     fn write_word(&mut self, address: usize, val: u32, access: AccessCode) -> Result<(), BusError>;
     fn load(&mut self, address: usize, data: &[u8]) -> Result<(), BusError>;
 }
 
+/// Return the interrupt priority level requested by a set of active
+/// interrupt request lines, or 0 if none are active.
+pub fn interrupt_priority(lines: u8) -> u32 {
+    IRQ_LINE_IPL
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(line, _)| lines & (1 << line) != 0)
+        .map_or(0, |(_, ipl)| *ipl)
+}
+
 //
 // Bus Memory Map
 //
@@ -56,13 +86,25 @@ pub trait Device: Send + Sync + Debug {
 //  0x700000..0x7fffff     RAM (256K or 1M)
 //
 
+// Device decoded from an address
+enum Slot {
+    Rom,
+    Duart,
+    Mouse,
+    Vid,
+    Bbram,
+    Ram,
+}
+
 pub struct Bus {
     rom: Mem,
     duart: Duart,
     mouse: Mouse,
     vid: Mem,      // TODO: Figure out what device this really is
-    bbram: Mem,    // TODO: change to BBRAM when implemented
+    bbram: Bbram,
     ram: Mem,
+    watchpoints: Vec<Watchpoint>,
+    watch_hit: Option<WatchHit>,
 }
 
 impl Bus {
@@ -72,47 +114,103 @@ impl Bus {
             duart: Duart::new(),
             mouse: Mouse::new(),
             vid: Mem::new(0x500000, 0x2, false),
-            bbram: Mem::new(0x600000, 0x2000, false),
+            bbram: Bbram::new(),
             ram: Mem::new(0x700000, mem_size, false),
+            watchpoints: Vec::new(),
+            watch_hit: None,
         }
     }
 
-    fn get_device(&mut self, address: usize) -> Result<&mut dyn Device, BusError> {
-        if address < 0x20000 {
-            return Ok(&mut self.rom);
+    fn slot(address: usize) -> Result<Slot, BusError> {
+        match address {
+            0..=0x1ffff => Ok(Slot::Rom),
+            0x200000..=0x20003f => Ok(Slot::Duart),
+            0x400000..=0x400003 => Ok(Slot::Mouse),
+            0x500000..=0x500001 => Ok(Slot::Vid),
+            0x600000..=0x601fff => Ok(Slot::Bbram),
+            0x700000..=0x7fffff => Ok(Slot::Ram),
+            _ => Err(BusError::NoDevice(address as u32)),
         }
+    }
 
-        if address >= 0x200000 && address < 0x200040 {
-            return Ok(&mut self.duart);
-        }
+    fn get_device(&mut self, address: usize) -> Result<&mut dyn Device, BusError> {
+        Ok(match Bus::slot(address)? {
+            Slot::Rom => &mut self.rom,
+            Slot::Duart => &mut self.duart,
+            Slot::Mouse => &mut self.mouse,
+            Slot::Vid => &mut self.vid,
+            Slot::Bbram => &mut self.bbram,
+            Slot::Ram => &mut self.ram,
+        })
+    }
 
-        if address >= 0x400000 && address < 0x400004 {
-            return Ok(&mut self.mouse);
-        }
+    fn device(&self, address: usize) -> Result<&dyn Device, BusError> {
+        Ok(match Bus::slot(address)? {
+            Slot::Rom => &self.rom,
+            Slot::Duart => &self.duart,
+            Slot::Mouse => &self.mouse,
+            Slot::Vid => &self.vid,
+            Slot::Bbram => &self.bbram,
+            Slot::Ram => &self.ram,
+        })
+    }
 
-        if address >= 0x500000 && address < 0x500002 {
-            return Ok(&mut self.vid);
-        }
+    /// The ROM or RAM holding `address`, with the address of its
+    /// first byte, for the CPU's instruction fetches.
+    pub fn code(&self, address: usize) -> Option<(&[u8], usize)> {
+        let mem = match Bus::slot(address).ok()? {
+            Slot::Rom => &self.rom,
+            Slot::Ram => &self.ram,
+            _ => return None,
+        };
+        let range = mem.address_range();
+        Some((mem.as_slice(0..range.len()), range.start))
+    }
 
-        if address >= 0x600000 && address < 0x602000 {
-            return Ok(&mut self.bbram);
+    // Record the first watched access since the last hit was taken.
+    fn watch(&mut self, address: usize, size: u32, write: bool) {
+        if self.watch_hit.is_none() && self.watchpoints.iter().any(|w| w.matches(address as u32, size, write)) {
+            self.watch_hit = Some(WatchHit {
+                address: address as u32,
+                kind: if write { Watch::Write } else { Watch::Read },
+            });
         }
+    }
 
-        if address >= 0x700000 && address < 0x800000 {
-            return Ok(&mut self.ram);
+    pub fn read_byte(&mut self, address: usize, access: AccessCode) -> Result<u8, BusError> {
+        if !self.watchpoints.is_empty() {
+            self.watch(address, 1, false);
         }
+        self.get_device(address)?.read_byte(address, access)
+    }
 
-        Err(BusError::NoDevice(address as u32))
+    /// Read a byte without side effects: no device changes state,
+    /// and watchpoints are not triggered.
+    pub fn peek_byte(&self, address: usize) -> Result<u8, BusError> {
+        self.device(address)?.peek_byte(address)
     }
 
-    pub fn read_byte(&mut self, address: usize, access: AccessCode) -> Result<u8, BusError> {
-        self.get_device(address)?.read_byte(address, access)
+    pub fn peek_half(&self, address: usize) -> Result<u16, BusError> {
+        if address & 1 != 0 {
+            return Err(BusError::Alignment);
+        }
+        self.device(address)?.peek_half(address)
+    }
+
+    pub fn peek_word(&self, address: usize) -> Result<u32, BusError> {
+        if address & 3 != 0 {
+            return Err(BusError::Alignment);
+        }
+        self.device(address)?.peek_word(address)
     }
 
     pub fn read_half(&mut self, address: usize, access: AccessCode) -> Result<u16, BusError> {
         if address & 1 != 0 {
             return Err(BusError::Alignment);
         }
+        if !self.watchpoints.is_empty() {
+            self.watch(address, 2, false);
+        }
         self.get_device(address)?.read_half(address, access)
     }
 
@@ -120,6 +218,9 @@ impl Bus {
         if address & 3 != 0 {
             return Err(BusError::Alignment);
         }
+        if !self.watchpoints.is_empty() {
+            self.watch(address, 4, false);
+        }
         self.get_device(address)?.read_word(address, access)
     }
 
@@ -140,6 +241,9 @@ impl Bus {
     }
 
     pub fn write_byte(&mut self, address: usize, val: u8) -> Result<(), BusError> {
+        if !self.watchpoints.is_empty() {
+            self.watch(address, 1, true);
+        }
         self.get_device(address)?.write_byte(address, val, AccessCode::Write)
     }
 
@@ -147,6 +251,9 @@ impl Bus {
         if address & 1 != 0 {
             return Err(BusError::Alignment);
         }
+        if !self.watchpoints.is_empty() {
+            self.watch(address, 2, true);
+        }
         self.get_device(address)?.write_half(address, val, AccessCode::Write)
     }
 
@@ -154,6 +261,9 @@ impl Bus {
         if address & 3 != 0 {
             return Err(BusError::Alignment);
         }
+        if !self.watchpoints.is_empty() {
+            self.watch(address, 4, true);
+        }
         self.get_device(address)?.write_word(address, val, AccessCode::Write)
     }
 
@@ -161,6 +271,39 @@ impl Bus {
         self.get_device(address)?.load(address, data)
     }
 
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        if !self.watchpoints.contains(&watchpoint) {
+            self.watchpoints.push(watchpoint);
+        }
+    }
+
+    /// Remove the watchpoints on a range of addresses. Returns false
+    /// if there were none.
+    pub fn remove_watchpoint(&mut self, addresses: &Range<u32>) -> bool {
+        let count = self.watchpoints.len();
+        self.watchpoints.retain(|w| w.addresses != *addresses);
+        self.watchpoints.len() != count
+    }
+
+    pub fn clear_watchpoints(&mut self) {
+        self.watchpoints.clear();
+        self.watch_hit = None;
+    }
+
+    pub fn watchpoints(&self) -> &[Watchpoint] {
+        &self.watchpoints
+    }
+
+    /// Take the first watched access since the last call.
+    pub fn take_watch_hit(&mut self) -> Option<WatchHit> {
+        self.watch_hit.take()
+    }
+
+    /// Addresses of the RAM device.
+    pub fn ram_range(&self) -> &Range<usize> {
+        self.ram.address_range()
+    }
+
     pub fn video_ram(&self) -> &[u8] {
         let vid_register = (u16::from(self.vid[0]) << 8 | u16::from(self.vid[1])) as usize;
         let start = vid_register * 4;
@@ -176,6 +319,21 @@ impl Bus {
         self.duart.get_interrupt()
     }
 
+    /// Run an interrupt acknowledge cycle for the given priority
+    /// level, and return the interrupt vector number.
+    ///
+    /// For a vectored acknowledge, the DMD places the complement of the
+    /// active request lines on the data bus. No device on the DMD
+    /// answers an autovector acknowledge, so the vector is formed from
+    /// the priority level alone.
+    pub fn irq_ack(&mut self, access: AccessCode, ipl: u32) -> Result<u8, BusError> {
+        match access {
+            AccessCode::IrqAck => Ok(!self.duart.interrupt_lines() & 0x3f),
+            AccessCode::AutoVectorIrqAck => Ok((ipl & 0xf) as u8),
+            _ => Err(BusError::Permission),
+        }
+    }
+
     pub fn mouse_move(&mut self, x: u16, y: u16) {
         self.mouse.x = x;
         self.mouse.y = y;
@@ -197,26 +355,84 @@ impl Bus {
         self.duart.kb_tx_poll()
     }
 
+    pub fn rs232_tx_break(&self) -> bool {
+        self.duart.rs232_tx_break()
+    }
+
+    pub fn printer_poll(&mut self) -> Option<u8> {
+        self.duart.printer_poll()
+    }
+
+    pub fn keyboard(&self) -> &Keyboard {
+        self.duart.keyboard()
+    }
+
+    pub fn keyboard_mut(&mut self) -> &mut Keyboard {
+        self.duart.keyboard_mut()
+    }
+
     pub fn rx_char(&mut self, char: u8) {
         self.duart.rx_char(char);
     }
 
+    pub fn rx_break(&mut self) {
+        self.duart.rx_break();
+    }
+
+    pub fn rx_char_error(&mut self, char: u8, error: LineError) {
+        self.duart.rx_char_error(char, error);
+    }
+
+    pub fn set_rx_fifo(&mut self, enabled: bool) {
+        self.duart.set_rx_fifo(enabled);
+    }
+
+    pub fn set_xon_xoff(&mut self, enabled: bool) {
+        self.duart.set_xon_xoff(enabled);
+    }
+
+    pub fn rx_space(&self) -> usize {
+        self.duart.rx_space()
+    }
+
     pub fn rx_keyboard(&mut self, keycode: u8) {
         self.duart.rx_keyboard(keycode);
     }
 
+    pub fn modem_line(&self, line: ModemLine) -> bool {
+        self.duart.modem_line(line)
+    }
+
+    pub fn set_modem_line(&mut self, line: ModemLine, asserted: bool) {
+        self.duart.set_modem_line(line, asserted);
+    }
+
+    pub fn modem_poll(&mut self) -> Option<(ModemLine, bool)> {
+        self.duart.modem_poll()
+    }
+
     pub fn duart_output(&self) -> u8 {
         self.duart.output_port()
     }
 
     pub fn get_nvram(&self) -> &[u8] {
-        self.bbram.as_slice(0..NVRAM_SIZE)
+        self.bbram.as_slice()
     }
 
     pub fn set_nvram(&mut self, nvram: &[u8]) {
-        for (i, b) in nvram.into_iter().enumerate() {
-            self.bbram[i] = *b;
-        }
+        self.bbram.set_image(nvram);
+    }
+
+    pub fn bind_nvram(&mut self, path: &Path) -> io::Result<()> {
+        self.bbram.bind(path)
+    }
+
+    pub fn unbind_nvram(&mut self) -> io::Result<()> {
+        self.bbram.unbind()
+    }
+
+    pub fn flush_nvram(&mut self) -> io::Result<()> {
+        self.bbram.flush()
     }
 }
 
@@ -238,4 +454,80 @@ mod tests {
         assert!(bus.write_word(0x700003, 0x1f1f1f1f).is_err());
         assert!(bus.write_word(0x700004, 0x1f1f1f1f).is_ok());
     }
+
+    #[test]
+    fn highest_active_line_sets_interrupt_priority() {
+        assert_eq!(0, interrupt_priority(0));
+        assert_eq!(14, interrupt_priority(0x02));
+        assert_eq!(14, interrupt_priority(0x04));
+        assert_eq!(14, interrupt_priority(0x06));
+        assert_eq!(15, interrupt_priority(0x10));
+        assert_eq!(15, interrupt_priority(0x20));
+        assert_eq!(15, interrupt_priority(0x22));
+        assert_eq!(15, interrupt_priority(0x3f));
+    }
+
+    #[test]
+    fn acknowledge_returns_complement_of_active_lines() {
+        let mut bus: Bus = Bus::new(0x10000);
+
+        // No lines active
+        assert_eq!(0x3f, bus.irq_ack(AccessCode::IrqAck, 15).unwrap());
+
+        // Enable input port change interrupts for a mouse button.
+        bus.write_byte(0x200013, 0x0f).unwrap();
+        bus.write_byte(0x200017, 0x80).unwrap();
+        bus.mouse_down(0);
+        assert_eq!(Some(0x02), bus.get_interrupts());
+        assert_eq!(0x3d, bus.irq_ack(AccessCode::IrqAck, 14).unwrap());
+    }
+
+    #[test]
+    fn autovector_acknowledge_uses_priority_level() {
+        let mut bus: Bus = Bus::new(0x10000);
+
+        assert_eq!(15, bus.irq_ack(AccessCode::AutoVectorIrqAck, 15).unwrap());
+        assert_eq!(14, bus.irq_ack(AccessCode::AutoVectorIrqAck, 14).unwrap());
+        assert!(bus.irq_ack(AccessCode::Write, 14).is_err());
+    }
+
+    #[test]
+    fn peeks_without_side_effects() {
+        let mut bus: Bus = Bus::new(0x10000);
+        bus.write_byte(0x700010, 0xa5).unwrap();
+        bus.write_byte(0x600002, 0x5a).unwrap();
+
+        assert_eq!(0xa5, bus.peek_byte(0x700010).unwrap());
+        assert_eq!(0x5a, bus.peek_byte(0x600002).unwrap());
+        assert_eq!(0x5a00, bus.peek_word(0x600000).unwrap());
+        assert!(bus.peek_byte(0x710000).is_err());
+        assert!(bus.peek_byte(0x300000).is_err());
+
+        // Reading MR1A/MR2A advances the mode register pointer, but
+        // peeking does not.
+        bus.write_byte(0x200003, 0x13).unwrap();
+        bus.write_byte(0x200003, 0x07).unwrap();
+        assert_eq!(0x13, bus.peek_byte(0x200003).unwrap());
+        assert_eq!(0x13, bus.peek_byte(0x200003).unwrap());
+        assert_eq!(0x13, bus.read_byte(0x200003, AccessCode::AddressFetch).unwrap());
+        assert_eq!(0x07, bus.peek_byte(0x200003).unwrap());
+
+        bus.mouse_move(100, 200);
+        assert_eq!(200, bus.peek_half(0x400000).unwrap());
+        assert_eq!(100, bus.peek_half(0x400002).unwrap());
+    }
+
+    #[test]
+    fn finds_code_in_rom_and_ram() {
+        let mut bus: Bus = Bus::new(0x10000);
+        bus.write_byte(0x700010, 0xa5).unwrap();
+
+        let (code, base) = bus.code(0x700010).unwrap();
+        assert_eq!((0x700000, 0x10000), (base, code.len()));
+        assert_eq!(0xa5, code[0x10]);
+        assert_eq!(0x20000, bus.code(0x100).unwrap().0.len());
+
+        assert!(bus.code(0x600000).is_none());
+        assert!(bus.code(0x200003).is_none());
+    }
 }
@@ -0,0 +1,384 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::err::CoffError;
+use crate::symbols::SymbolTable;
+
+//
+// WE32000 Common Object File Format
+//
+// All fields are big-endian. A file starts with a 20 byte file
+// header, followed by the optional a.out header and the section
+// headers. The symbol table is an array of 18 byte entries, each
+// followed by its auxiliary entries, and is followed by the string
+// table holding names longer than eight characters.
+//
+
+/// Magic number of WE32000 object files.
+pub const WE32MAGIC: u16 = 0x170;
+
+const FILE_HEADER_SIZE: usize = 20;
+const OPTIONAL_HEADER_SIZE: usize = 28;
+const SECTION_HEADER_SIZE: usize = 40;
+const SYMBOL_SIZE: usize = 18;
+
+// Section types
+pub const STYP_TEXT: u32 = 0x20;
+pub const STYP_DATA: u32 = 0x40;
+pub const STYP_BSS: u32 = 0x80;
+
+// Storage classes
+pub const C_EXT: u8 = 2;
+pub const C_STAT: u8 = 3;
+pub const C_LABEL: u8 = 6;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileHeader {
+    pub magic: u16,
+    pub section_count: u16,
+    pub timestamp: u32,
+    pub symbol_offset: u32,
+    pub symbol_count: u32,
+    pub optional_header_size: u16,
+    pub flags: u16,
+}
+
+/// The a.out header of an executable.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptionalHeader {
+    pub magic: u16,
+    pub version: u16,
+    pub text_size: u32,
+    pub data_size: u32,
+    pub bss_size: u32,
+    pub entry: u32,
+    pub text_start: u32,
+    pub data_start: u32,
+}
+
+/// A section and its contents. A `.bss` section has a size but no
+/// contents.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Section {
+    pub name: String,
+    pub address: u32,
+    pub size: u32,
+    pub flags: u32,
+    pub data: Vec<u8>,
+}
+
+impl Section {
+    /// True for the `.text`, `.data` and `.bss` sections of a program.
+    pub fn is_loaded(&self) -> bool {
+        self.flags & (STYP_TEXT | STYP_DATA | STYP_BSS) != 0
+    }
+}
+
+/// An entry in the symbol table. Auxiliary entries are skipped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoffSymbol {
+    pub name: String,
+    pub value: u32,
+    /// 1-based section number. 0 is undefined, -1 absolute and -2 a
+    /// debugging symbol.
+    pub section: i16,
+    pub symbol_type: u16,
+    pub class: u8,
+}
+
+/// A parsed COFF file.
+#[derive(Clone, Debug)]
+pub struct Coff {
+    pub header: FileHeader,
+    /// Present in executables, but not in relocatable objects
+    pub optional_header: Option<OptionalHeader>,
+    pub sections: Vec<Section>,
+    pub symbols: Vec<CoffSymbol>,
+}
+
+/// A program loaded by `Dmd::load_coff`.
+#[derive(Clone, Debug)]
+pub struct LoadedProgram {
+    pub entry: u32,
+    pub symbols: SymbolTable,
+}
+
+pub(crate) fn be16(data: &[u8], offset: usize) -> Result<u16, CoffError> {
+    match data.get(offset..offset + 2) {
+        Some(b) => Ok(u16::from_be_bytes([b[0], b[1]])),
+        None => Err(CoffError::Truncated),
+    }
+}
+
+pub(crate) fn be32(data: &[u8], offset: usize) -> Result<u32, CoffError> {
+    match data.get(offset..offset + 4) {
+        Some(b) => Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]])),
+        None => Err(CoffError::Truncated),
+    }
+}
+
+/// Read a name of at most `max` bytes, ending at the first NUL.
+fn name(data: &[u8], offset: usize, max: usize) -> Result<String, CoffError> {
+    let bytes = data.get(offset..).ok_or(CoffError::Truncated)?;
+    let bytes = &bytes[..bytes.len().min(max)];
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    if end == bytes.len() && end < max {
+        return Err(CoffError::Truncated);
+    }
+    Ok(String::from_utf8_lossy(&bytes[..end]).into_owned())
+}
+
+impl FileHeader {
+    fn parse(data: &[u8]) -> Result<FileHeader, CoffError> {
+        let magic = be16(data, 0)?;
+        if magic != WE32MAGIC {
+            return Err(CoffError::Magic(magic));
+        }
+        if data.len() < FILE_HEADER_SIZE {
+            return Err(CoffError::Truncated);
+        }
+
+        Ok(FileHeader {
+            magic,
+            section_count: be16(data, 2)?,
+            timestamp: be32(data, 4)?,
+            symbol_offset: be32(data, 8)?,
+            symbol_count: be32(data, 12)?,
+            optional_header_size: be16(data, 16)?,
+            flags: be16(data, 18)?,
+        })
+    }
+}
+
+impl OptionalHeader {
+    fn parse(data: &[u8], header: &FileHeader) -> Result<Option<OptionalHeader>, CoffError> {
+        if (header.optional_header_size as usize) < OPTIONAL_HEADER_SIZE {
+            return Ok(None);
+        }
+
+        let base = FILE_HEADER_SIZE;
+        Ok(Some(OptionalHeader {
+            magic: be16(data, base)?,
+            version: be16(data, base + 2)?,
+            text_size: be32(data, base + 4)?,
+            data_size: be32(data, base + 8)?,
+            bss_size: be32(data, base + 12)?,
+            entry: be32(data, base + 16)?,
+            text_start: be32(data, base + 20)?,
+            data_start: be32(data, base + 24)?,
+        }))
+    }
+}
+
+fn parse_sections(data: &[u8], header: &FileHeader) -> Result<Vec<Section>, CoffError> {
+    let base = FILE_HEADER_SIZE + header.optional_header_size as usize;
+    let mut sections = Vec::with_capacity(header.section_count as usize);
+
+    for i in 0..header.section_count as usize {
+        let entry = base + i * SECTION_HEADER_SIZE;
+        let size = be32(data, entry + 16)?;
+        let offset = be32(data, entry + 20)? as usize;
+        let flags = be32(data, entry + 36)?;
+
+        // Sections with no file offset, like .bss, only reserve space
+        let contents = if flags & STYP_BSS != 0 || offset == 0 {
+            Vec::new()
+        } else {
+            data.get(offset..offset.saturating_add(size as usize)).ok_or(CoffError::Truncated)?.to_vec()
+        };
+
+        sections.push(Section {
+            name: name(data, entry, 8)?,
+            address: be32(data, entry + 12)?,
+            size,
+            flags,
+            data: contents,
+        });
+    }
+
+    Ok(sections)
+}
+
+fn parse_symbols(data: &[u8], header: &FileHeader) -> Result<Vec<CoffSymbol>, CoffError> {
+    let mut symbols = Vec::new();
+
+    if header.symbol_offset == 0 || header.symbol_count == 0 {
+        return Ok(symbols);
+    }
+
+    let table = header.symbol_offset as usize;
+    let count = header.symbol_count as usize;
+    let strings = table + count * SYMBOL_SIZE;
+    if strings > data.len() {
+        return Err(CoffError::Truncated);
+    }
+
+    let mut i = 0;
+    while i < count {
+        let entry = table + i * SYMBOL_SIZE;
+
+        // Long names are kept in the string table, and are marked by
+        // a zero first word followed by their offset in the table.
+        let name = if be32(data, entry)? == 0 {
+            name(data, strings + be32(data, entry + 4)? as usize, usize::MAX)?
+        } else {
+            name(data, entry, 8)?
+        };
+
+        symbols.push(CoffSymbol {
+            name,
+            value: be32(data, entry + 8)?,
+            section: be16(data, entry + 12)? as i16,
+            symbol_type: be16(data, entry + 14)?,
+            class: data[entry + 16],
+        });
+
+        i += 1 + data[entry + 17] as usize;
+    }
+
+    Ok(symbols)
+}
+
+impl Coff {
+    pub fn parse(data: &[u8]) -> Result<Coff, CoffError> {
+        let header = FileHeader::parse(data)?;
+        let optional_header = OptionalHeader::parse(data, &header)?;
+        let sections = parse_sections(data, &header)?;
+        let symbols = parse_symbols(data, &header)?;
+
+        Ok(Coff {
+            header,
+            optional_header,
+            sections,
+            symbols,
+        })
+    }
+}
+
+/// Build an executable with the given sections, as (name, address,
+/// flags, contents, size), and symbols, as (name, value, section).
+#[cfg(test)]
+pub(crate) fn build_executable(entry: u32, sections: &[(&str, u32, u32, &[u8], u32)], symbols: &[(&str, u32, i16)]) -> Vec<u8> {
+    let headers = FILE_HEADER_SIZE + OPTIONAL_HEADER_SIZE + sections.len() * SECTION_HEADER_SIZE;
+    let contents: usize = sections.iter().map(|s| s.3.len()).sum();
+    let symbol_offset = headers + contents;
+
+    let mut file = Vec::new();
+    file.extend_from_slice(&WE32MAGIC.to_be_bytes());
+    file.extend_from_slice(&(sections.len() as u16).to_be_bytes());
+    file.extend_from_slice(&0u32.to_be_bytes());
+    file.extend_from_slice(&(symbol_offset as u32).to_be_bytes());
+    file.extend_from_slice(&(symbols.len() as u32).to_be_bytes());
+    file.extend_from_slice(&(OPTIONAL_HEADER_SIZE as u16).to_be_bytes());
+    file.extend_from_slice(&0x0002u16.to_be_bytes());
+
+    file.extend_from_slice(&0x010bu16.to_be_bytes());
+    file.extend_from_slice(&0u16.to_be_bytes());
+    for word in &[0, 0, 0, entry, 0, 0] {
+        file.extend_from_slice(&u32::to_be_bytes(*word));
+    }
+
+    let mut offset = headers;
+    for (name, address, flags, data, size) in sections {
+        let mut n = [0u8; 8];
+        n[..name.len()].copy_from_slice(name.as_bytes());
+        file.extend_from_slice(&n);
+        let ptr = if data.is_empty() {
+            0
+        } else {
+            offset
+        };
+        for word in &[*address, *address, *size, ptr as u32, 0, 0] {
+            file.extend_from_slice(&u32::to_be_bytes(*word));
+        }
+        file.extend_from_slice(&[0, 0, 0, 0]);
+        file.extend_from_slice(&flags.to_be_bytes());
+        offset += data.len();
+    }
+
+    for (_, _, _, data, _) in sections {
+        file.extend_from_slice(data);
+    }
+
+    for (name, value, section) in symbols {
+        let mut n = [0u8; 8];
+        n[..name.len()].copy_from_slice(name.as_bytes());
+        file.extend_from_slice(&n);
+        file.extend_from_slice(&value.to_be_bytes());
+        file.extend_from_slice(&section.to_be_bytes());
+        file.extend_from_slice(&[0, 0, C_EXT, 0]);
+    }
+    file.extend_from_slice(&4u32.to_be_bytes());
+
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &[u8; 8], value: u32, section: i16, class: u8, aux: u8) -> Vec<u8> {
+        let mut entry = name.to_vec();
+        entry.extend_from_slice(&value.to_be_bytes());
+        entry.extend_from_slice(&section.to_be_bytes());
+        entry.extend_from_slice(&[0, 0, class, aux]);
+        entry
+    }
+
+    #[test]
+    fn parses_symbol_table() {
+        let mut file = vec![0x01, 0x70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 4, 0, 0, 0, 0];
+        file.extend(symbol(b"main\0\0\0\0", 0x700000, 1, C_EXT, 1));
+        file.extend(vec![0; SYMBOL_SIZE]); // Auxiliary entry
+        file.extend(symbol(b"\0\0\0\0\0\0\0\x04", 0x700040, 2, C_STAT, 0));
+        file.extend(symbol(b"exactly8", 0x700080, 1, C_LABEL, 0));
+        file.extend_from_slice(&[0, 0, 0, 18]);
+        file.extend_from_slice(b"a_long_name\0\0\0");
+
+        let coff = Coff::parse(&file).unwrap();
+
+        assert_eq!(4, coff.header.symbol_count);
+        assert_eq!(3, coff.symbols.len());
+        assert_eq!("main", coff.symbols[0].name);
+        assert_eq!(0x700000, coff.symbols[0].value);
+        assert_eq!(C_EXT, coff.symbols[0].class);
+        assert_eq!("a_long_name", coff.symbols[1].name);
+        assert_eq!(2, coff.symbols[1].section);
+        assert_eq!("exactly8", coff.symbols[2].name);
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(matches!(Coff::parse(&[0x01, 0x4c, 0, 0]), Err(CoffError::Magic(0x14c))));
+        assert!(matches!(Coff::parse(&[0x01, 0x70, 0, 0]), Err(CoffError::Truncated)));
+
+        let mut file = vec![0x01, 0x70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 2, 0, 0, 0, 0];
+        file.extend(symbol(b"main\0\0\0\0", 0x700000, 1, C_EXT, 0));
+        assert!(matches!(Coff::parse(&file), Err(CoffError::Truncated)));
+    }
+
+    #[test]
+    fn parses_executable_sections() {
+        let file = build_executable(
+            0x700000,
+            &[
+                (".text", 0x700000, STYP_TEXT, &[0x70, 0x70, 0x08, 0x00], 4),
+                (".data", 0x700004, STYP_DATA, &[1, 2, 3, 4], 4),
+                (".bss", 0x700008, STYP_BSS, &[], 0x10),
+            ],
+            &[("main", 0x700000, 1)],
+        );
+
+        let coff = Coff::parse(&file).unwrap();
+
+        assert_eq!(0x700000, coff.optional_header.unwrap().entry);
+        assert_eq!(3, coff.sections.len());
+        assert_eq!(".text", coff.sections[0].name);
+        assert_eq!(vec![0x70, 0x70, 0x08, 0x00], coff.sections[0].data);
+        assert_eq!(0x700004, coff.sections[1].address);
+        assert_eq!(vec![1, 2, 3, 4], coff.sections[1].data);
+        assert_eq!(0x10, coff.sections[2].size);
+        assert!(coff.sections[2].data.is_empty());
+        assert!(coff.sections.iter().all(|s| s.is_loaded()));
+        assert_eq!("main", coff.symbols[0].name);
+    }
+}
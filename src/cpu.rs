@@ -1,8 +1,11 @@
 #![allow(clippy::unreadable_literal)]
 
-use crate::bus::{AccessCode, Bus};
+use crate::bus::{interrupt_priority, AccessCode, Bus};
+use crate::disasm;
 use crate::err::*;
 use crate::instr::*;
+use crate::profile::Profiler;
+use crate::trace::Tracer;
 
 ///
 /// PSW Flags and Offsets
@@ -30,24 +33,13 @@ const O_ISC: u32 = 3;
 ///
 /// Register Indexes
 ///
-const R_FP: usize = 9;
-const R_AP: usize = 10;
-const R_PSW: usize = 11;
-const R_SP: usize = 12;
-const R_PCBP: usize = 13;
-const R_ISP: usize = 14;
-const R_PC: usize = 15;
-
-const IPL_TABLE: [u32; 64] = [
-    0,  14, 14, 14, 14, 14, 14, 14,
-    15, 15, 15, 15, 15, 15, 15, 15,
-    15, 15, 15, 15, 15, 15, 15, 15,
-    15, 15, 15, 15, 15, 15, 15, 15,
-    15, 15, 15, 15, 15, 15, 15, 15,
-    15, 15, 15, 15, 15, 15, 15, 15,
-    15, 15, 15, 15, 15, 15, 15, 15,
-    15, 15, 15, 15, 15, 15, 15, 15,
-];
+pub(crate) const R_FP: usize = 9;
+pub(crate) const R_AP: usize = 10;
+pub(crate) const R_PSW: usize = 11;
+pub(crate) const R_SP: usize = 12;
+pub(crate) const R_PCBP: usize = 13;
+pub(crate) const R_ISP: usize = 14;
+pub(crate) const R_PC: usize = 15;
 
 const WE32100_VERSION: u32 = 0x1a;
 const HALFWORD_MNEMONIC_COUNT: usize = 11;
@@ -156,6 +148,10 @@ impl Operand {
             None => self.data_type,
         }
     }
+
+    pub fn expanded_type(&self) -> Option<Data> {
+        self.expanded_type
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -166,7 +162,7 @@ struct Mnemonic {
     ops: [OpType; 4],
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Instruction {
     pub opcode: u16,
     pub name: &'static str,
@@ -175,9 +171,27 @@ pub struct Instruction {
     pub operands: [Operand; 4],
 }
 
+impl Default for Instruction {
+    fn default() -> Self {
+        Instruction {
+            opcode: 0,
+            name: "???",
+            data_type: Data::None,
+            bytes: 0,
+            operands: [
+                Operand::new(0, AddrMode::None, Data::None, None, None, 0),
+                Operand::new(0, AddrMode::None, Data::None, None, None, 0),
+                Operand::new(0, AddrMode::None, Data::None, None, None, 0),
+                Operand::new(0, AddrMode::None, Data::None, None, None, 0),
+            ],
+        }
+    }
+}
+
 impl Instruction {
-    pub fn decode(&self) -> String {
-        format!("{}\t0x{:x}", self.name, 1000)
+    /// Render the instruction in AT&T syntax, as located at `address`.
+    pub fn decode(&self, address: u32) -> String {
+        disasm::format_instruction(self, address, None)
     }
 }
 
@@ -480,6 +494,330 @@ static HALFWORD_MNEMONICS: [Option<Mnemonic>; HALFWORD_MNEMONIC_COUNT] = [
 
 static NULL_MNEMONIC: Option<Mnemonic> = None;
 
+//
+// Instruction Decoding
+//
+// The decoder has no side effects. It reads from a ByteSource and
+// returns an owned Instruction, so the disassembler and tools that
+// analyze ROM images offline can use it as well as the CPU.
+//
+
+/// A source of instruction bytes for the decoder.
+pub trait ByteSource {
+    fn fetch_byte(&self, address: usize) -> Result<u8, BusError>;
+
+    /// Fetch a little-endian halfword, as embedded in the instruction stream.
+    fn fetch_op_half(&self, address: usize) -> Result<u16, BusError> {
+        Ok(u16::from(self.fetch_byte(address)?) | u16::from(self.fetch_byte(address + 1)?) << 8)
+    }
+
+    /// Fetch a little-endian word, as embedded in the instruction stream.
+    fn fetch_op_word(&self, address: usize) -> Result<u32, BusError> {
+        Ok(u32::from(self.fetch_op_half(address)?) | u32::from(self.fetch_op_half(address + 2)?) << 16)
+    }
+}
+
+/// A raw memory image, such as a ROM, addressed from zero.
+impl ByteSource for [u8] {
+    fn fetch_byte(&self, address: usize) -> Result<u8, BusError> {
+        self.get(address).copied().ok_or(BusError::Range)
+    }
+}
+
+/// Bus memory, read without device side effects.
+impl ByteSource for Bus {
+    fn fetch_byte(&self, address: usize) -> Result<u8, BusError> {
+        self.peek_byte(address)
+    }
+}
+
+// The CPU's instruction fetches read the ROM or RAM holding the PC
+// directly, without a device lookup for every byte. Anything else
+// goes through the bus.
+struct Fetch<'a> {
+    bus: &'a Bus,
+    code: &'a [u8],
+    base: usize,
+}
+
+impl<'a> Fetch<'a> {
+    fn new(bus: &'a Bus, pc: u32) -> Fetch<'a> {
+        let (code, base) = bus.code(pc as usize).unwrap_or((&[], 0));
+        Fetch { bus, code, base }
+    }
+}
+
+impl ByteSource for Fetch<'_> {
+    fn fetch_byte(&self, address: usize) -> Result<u8, BusError> {
+        match self.code.get(address.wrapping_sub(self.base)) {
+            Some(b) => Ok(*b),
+            None => self.bus.peek_byte(address),
+        }
+    }
+}
+
+/// Decode a literal Operand type.
+///
+/// These operands belong to only certain instructions, where a word without
+/// a descriptor byte immediately follows the opcode.
+fn decode_literal_operand<S: ByteSource + ?Sized>(source: &S, mn: &Mnemonic, addr: usize) -> Result<Operand, CpuError> {
+    match mn.dtype {
+        Data::Byte => {
+            let b: u8 = source.fetch_byte(addr)?;
+            Ok(Operand::new(1, AddrMode::None, Data::Byte, None, None, u32::from(b)))
+        }
+        Data::Half => {
+            let h: u16 = source.fetch_op_half(addr)?;
+            Ok(Operand::new(2, AddrMode::None, Data::Half, None, None, u32::from(h)))
+        }
+        Data::Word => {
+            let w: u32 = source.fetch_op_word(addr)?;
+            Ok(Operand::new(4, AddrMode::None, Data::Word, None, None, w))
+        }
+        _ => Err(CpuError::Exception(CpuException::IllegalOpcode)),
+    }
+}
+
+/// Decode a descriptor Operand type.
+fn decode_descriptor_operand<S: ByteSource + ?Sized>(
+    source: &S,
+    dtype: Data,
+    etype: Option<Data>,
+    addr: usize,
+    recur: bool,
+) -> Result<Operand, CpuError> {
+    let descriptor_byte: u8 = source.fetch_byte(addr)?;
+
+    let m = (descriptor_byte & 0xf0) >> 4;
+    let r = descriptor_byte & 0xf;
+
+    // The descriptor is either 1 or 2 bytes, depending on whether this is a recursive
+    // call or not.
+    let dsize = if recur {
+        2
+    } else {
+        1
+    };
+
+    let op = match m {
+        0..=3 => {
+            // Positive Literal
+            Operand::new(dsize, AddrMode::PositiveLiteral, dtype, etype, None, u32::from(descriptor_byte))
+        }
+        4 => {
+            match r {
+                15 => {
+                    // Word Immediate
+                    let w = source.fetch_op_word(addr + 1)?;
+                    Operand::new(dsize + 4, AddrMode::WordImmediate, dtype, etype, None, w)
+                }
+                _ => {
+                    // Register
+                    Operand::new(dsize, AddrMode::Register, dtype, etype, Some(r as usize), 0)
+                }
+            }
+        }
+        5 => {
+            match r {
+                15 => {
+                    // Halfword Immediate
+                    let h = source.fetch_op_half(addr + 1)?;
+                    Operand::new(dsize + 2, AddrMode::HalfwordImmediate, dtype, etype, None, u32::from(h))
+                }
+                11 => {
+                    // Illegal
+                    return Err(CpuError::Exception(CpuException::IllegalOpcode))
+                }
+                _ => {
+                    // Register Deferred Mode
+                    Operand::new(dsize, AddrMode::RegisterDeferred, dtype, etype, Some(r as usize), 0)
+                }
+            }
+        }
+        6 => {
+            match r {
+                15 => {
+                    // Byte Immediate
+                    let b = source.fetch_byte(addr + 1)?;
+                    Operand::new(dsize + 1, AddrMode::ByteImmediate, dtype, etype, None, u32::from(b))
+                }
+                _ => {
+                    // FP Short Offset
+                    Operand::new(dsize, AddrMode::FPShortOffset, dtype, etype, Some(R_FP), u32::from(r))
+                }
+            }
+        }
+        7 => {
+            match r {
+                15 => {
+                    // Absolute
+                    let w = source.fetch_op_word(addr + 1)?;
+                    Operand::new(dsize + 4, AddrMode::Absolute, dtype, etype, None, w)
+                }
+                _ => {
+                    // AP Short Offset
+                    Operand::new(dsize, AddrMode::APShortOffset, dtype, etype, Some(R_AP), u32::from(r))
+                }
+            }
+        }
+        8 => {
+            match r {
+                11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
+                _ => {
+                    // Word Displacement
+                    let disp = source.fetch_op_word(addr + 1)?;
+                    Operand::new(dsize + 4, AddrMode::WordDisplacement, dtype, etype, Some(r as usize), disp)
+                }
+            }
+        }
+        9 => {
+            match r {
+                11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
+                _ => {
+                    // Word Displacement Deferred
+                    let disp = source.fetch_op_word(addr + 1)?;
+                    Operand::new(dsize + 4, AddrMode::WordDisplacementDeferred, dtype, etype, Some(r as usize), disp)
+                }
+            }
+        }
+        10 => {
+            match r {
+                11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
+                _ => {
+                    // Halfword Displacement
+                    let disp = source.fetch_op_half(addr + 1)?;
+                    Operand::new(dsize + 2, AddrMode::HalfwordDisplacement, dtype, etype, Some(r as usize), u32::from(disp))
+                }
+            }
+        }
+        11 => {
+            match r {
+                11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
+                _ => {
+                    // Halfword Displacement Deferred
+                    let disp = source.fetch_op_half(addr + 1)?;
+                    Operand::new(
+                        dsize + 2,
+                        AddrMode::HalfwordDisplacementDeferred,
+                        dtype,
+                        etype,
+                        Some(r as usize),
+                        u32::from(disp),
+                    )
+                }
+            }
+        }
+        12 => {
+            match r {
+                11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
+                _ => {
+                    // Byte Displacement
+                    let disp = source.fetch_byte(addr + 1)?;
+                    Operand::new(dsize + 1, AddrMode::ByteDisplacement, dtype, etype, Some(r as usize), u32::from(disp))
+                }
+            }
+        }
+        13 => {
+            match r {
+                11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
+                _ => {
+                    // Byte Displacement Deferred
+                    let disp = source.fetch_byte(addr + 1)?;
+                    Operand::new(dsize + 1, AddrMode::ByteDisplacementDeferred, dtype, etype, Some(r as usize), u32::from(disp))
+                }
+            }
+        }
+        14 => match r {
+            0 => decode_descriptor_operand(source, dtype, Some(Data::UWord), addr + 1, true)?,
+            2 => decode_descriptor_operand(source, dtype, Some(Data::UHalf), addr + 1, true)?,
+            3 => decode_descriptor_operand(source, dtype, Some(Data::Byte), addr + 1, true)?,
+            4 => decode_descriptor_operand(source, dtype, Some(Data::Word), addr + 1, true)?,
+            6 => decode_descriptor_operand(source, dtype, Some(Data::Half), addr + 1, true)?,
+            7 => decode_descriptor_operand(source, dtype, Some(Data::SByte), addr + 1, true)?,
+            15 => {
+                let w = source.fetch_op_word(addr + 1)?;
+                Operand::new(dsize + 4, AddrMode::AbsoluteDeferred, dtype, etype, None, w)
+            }
+            _ => { return Err(CpuError::Exception(CpuException::IllegalOpcode)); }
+        },
+        15 => {
+            // Negative Literal
+            Operand::new(1, AddrMode::NegativeLiteral, dtype, etype, None, u32::from(descriptor_byte))
+        },
+        _ => { return Err(CpuError::Exception(CpuException::IllegalOpcode)); }
+    };
+
+    Ok(op)
+}
+
+/// Decode the instruction at `address`. Nothing is written, and
+/// nothing is read through a device, so this is safe to call on a
+/// running system.
+pub fn decode_instruction<S: ByteSource + ?Sized>(source: &S, address: u32) -> Result<Instruction, CpuError> {
+    let mut instr = Instruction::default();
+    decode_instruction_into(source, address, &mut instr)?;
+    Ok(instr)
+}
+
+// Decode in place, as the CPU does for every step.
+fn decode_instruction_into<S: ByteSource + ?Sized>(source: &S, address: u32, instr: &mut Instruction) -> Result<(), CpuError> {
+    let mut addr = address as usize;
+    let initial_addr = addr;
+
+    // Read the first byte of the instruction. Most instructions are only
+    // one byte, so this is usually enough.
+    let b1 = source.fetch_byte(addr)?;
+    addr += 1;
+
+    // Map the Mnemonic to the  opcode we just read. But there's a special
+    // case if the value we read was '0x30'. This indicates that the instruction
+    // we're reading is a halfword, requiring two bytes.
+
+    let mut mn: &Option<Mnemonic> = &NULL_MNEMONIC;
+
+    if b1 == 0x30 {
+        let b2 = source.fetch_byte(addr)?;
+        addr += 1;
+
+        let opcode = (u16::from(b1) << 8) | u16::from(b2);
+
+        for m in &HALFWORD_MNEMONICS {
+            if m.is_some() && m.as_ref().unwrap().opcode == opcode {
+                mn = m;
+                break;
+            }
+        }
+    } else {
+        mn = &BYTE_MNEMONICS[b1 as usize];
+    };
+
+    // If we found a valid mnemonic, read in and decode all of its operands.
+    // Otherwise, we must return a CpuException::IllegalOpcode
+    match mn {
+        Some(mn) => {
+            let mut etype: Option<Data> = None;
+
+            for (index, ot) in mn.ops.iter().enumerate() {
+                instr.operands[index] = match ot {
+                    OpType::Lit => decode_literal_operand(source, mn, addr)?,
+                    OpType::Src | OpType::Dest => decode_descriptor_operand(source, mn.dtype, etype, addr, false)?,
+                    OpType::None => Operand::new(0, AddrMode::None, Data::None, None, None, 0),
+                };
+                etype = instr.operands[index].expanded_type;
+                addr += instr.operands[index].size as usize;
+            }
+
+            instr.opcode = mn.opcode;
+            instr.name = mn.name;
+            instr.data_type = mn.dtype;
+            instr.bytes = (addr - initial_addr) as u8;
+
+            Ok(())
+        }
+        None => Err(CpuError::Exception(CpuException::IllegalOpcode))
+    }
+}
+
 pub struct Cpu {
     //
     // Note that we store registers as an array of type u32 because
@@ -490,6 +828,8 @@ pub struct Cpu {
     error_context: ErrorContext,
     steps: u64,
     ir: Instruction,
+    trace: Tracer,
+    profiler: Profiler,
 }
 
 impl Default for Cpu {
@@ -504,18 +844,9 @@ impl Cpu {
             r: [0; 16],
             error_context: ErrorContext::None,
             steps: 0,
-            ir: Instruction {
-                opcode: 0,
-                name: "???",
-                data_type: Data::None,
-                bytes: 0,
-                operands: [
-                    Operand::new(0, AddrMode::None, Data::None, None, None, 0),
-                    Operand::new(0, AddrMode::None, Data::None, None, None, 0),
-                    Operand::new(0, AddrMode::None, Data::None, None, None, 0),
-                    Operand::new(0, AddrMode::None, Data::None, None, None, 0),
-                ]
-            }
+            ir: Instruction::default(),
+            trace: Tracer::new(),
+            profiler: Profiler::new(),
         }
     }
 
@@ -624,7 +955,7 @@ impl Cpu {
     /// Read the value pointed at by an Operand
     pub fn read_op(&mut self, bus: &mut Bus, index: usize) -> Result<u32, CpuError> {
 
-        let mut op = self.ir.operands[index];
+        let op = self.ir.operands[index];
 
         let val: u32 = match op.mode {
             AddrMode::Register => {
@@ -659,7 +990,7 @@ impl Cpu {
             }
         };
 
-        op.data = val;
+        self.ir.operands[index].data = val;
 
         Ok(val)
     }
@@ -861,21 +1192,34 @@ impl Cpu {
         self.context_switch_3(bus).unwrap();
     }
 
-    #[allow(clippy::cyclomatic_complexity)]
+    #[allow(clippy::cognitive_complexity)]
     fn dispatch(&mut self, bus: &mut Bus) -> Result<i32, CpuError> {
         self.steps += 1;
 
         // Update anything that needs updating.
         bus.service();
 
-        if let Some(val) = bus.get_interrupts() {
-            let cpu_ipl = (self.r[R_PSW]) >> 13 & 0xf;
-            if cpu_ipl < IPL_TABLE[(val & 0x3f) as usize] {
-                self.on_interrupt(bus, (!val) & 0x3f);
+        if let Some(lines) = bus.get_interrupts() {
+            let cpu_ipl = (self.r[R_PSW] & F_IPL) >> 13;
+            let ipl = interrupt_priority(lines);
+            if cpu_ipl < ipl {
+                let vector = bus.irq_ack(AccessCode::IrqAck, ipl)?;
+                self.on_interrupt(bus, vector);
             }
         }
 
-        self.decode_instruction(bus)?;
+        if self.trace.is_active() {
+            self.trace.begin(&self.r, self.priv_level());
+        }
+
+        if self.profiler.is_enabled() {
+            self.profiler.begin(self.r[R_PC]);
+        }
+
+        if let Err(e) = decode_instruction_into(&Fetch::new(bus, self.r[R_PC]), self.r[R_PC], &mut self.ir) {
+            self.ir = Instruction::default();
+            return Err(e);
+        }
         let mut pc_increment: i32 = i32::from(self.ir.bytes);
 
         match self.ir.opcode {
@@ -1813,10 +2157,27 @@ impl Cpu {
         Ok(())
     }
 
+    /// Dispatch one instruction, and trace and profile it if tracing
+    /// or profiling is on.
+    fn execute(&mut self, bus: &mut Bus) -> Result<i32, CpuError> {
+        let result = self.dispatch(bus);
+
+        if self.trace.is_active() {
+            let instr = Some(&self.ir).filter(|i| i.bytes > 0);
+            self.trace.end(instr, &self.r, &result);
+        }
+
+        if self.profiler.is_enabled() && result.is_ok() {
+            self.profiler.end(&self.ir, self.r[R_PC]);
+        }
+
+        result
+    }
+
     /// Step the CPU by one instruction.
     pub fn step(&mut self, bus: &mut Bus) {
         // TODO: On CPU Exception or Bus Error, handle each error with the appropriate exception handler routine
-        match self.dispatch(bus) {
+        match self.execute(bus) {
             Ok(i) => self.r[R_PC] = (self.r[R_PC] as i32 + i) as u32,
             Err(CpuError::Bus(BusError::Alignment)) => {}
             Err(CpuError::Bus(BusError::Permission)) => {}
@@ -1833,7 +2194,7 @@ impl Cpu {
     }
 
     pub fn step_with_error(&mut self, bus: &mut Bus) -> Result<(), CpuError> {
-        match self.dispatch(bus) {
+        match self.execute(bus) {
             Ok(i) => self.r[R_PC] = (self.r[R_PC] as i32 + i) as u32,
             Err(e) => return Err(e),
         }
@@ -1846,302 +2207,8 @@ impl Cpu {
         self.r[R_PC] = val;
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn set_operand(
-        &mut self,
-        index: usize,
-        size: u8,
-        mode: AddrMode,
-        data_type: Data,
-        expanded_type: Option<Data>,
-        register: Option<usize>,
-        embedded: u32
-    ) {
-        self.ir.operands[index].size = size;
-        self.ir.operands[index].mode = mode;
-        self.ir.operands[index].data_type = data_type;
-        self.ir.operands[index].expanded_type = expanded_type;
-        self.ir.operands[index].register = register;
-        self.ir.operands[index].embedded = embedded;
-    }
-
-    /// Decode a literal Operand type.
-    ///
-    /// These operands belong to only certain instructions, where a word without
-    /// a descriptor byte immediately follows the opcode.
-    fn decode_literal_operand(&mut self, bus: &mut Bus, index: usize, mn: &Mnemonic, addr: usize) -> Result<(), CpuError> {
-        match mn.dtype {
-            Data::Byte => {
-                let b: u8 = bus.read_byte(addr, AccessCode::OperandFetch)?;
-                self.set_operand(index, 1, AddrMode::None, Data::Byte, None, None, u32::from(b));
-            }
-            Data::Half => {
-                let h: u16 = bus.read_op_half(addr)?;
-                self.set_operand(index, 2, AddrMode::None, Data::Half, None, None, u32::from(h));
-            }
-            Data::Word => {
-                let w: u32 = bus.read_op_word(addr)?;
-                self.set_operand(index, 4, AddrMode::None, Data::Word, None, None, w);
-            }
-            _ => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
-        }
-
-        Ok(())
-    }
-
-    /// Decode a descriptor Operand type.
-    fn decode_descriptor_operand(
-        &mut self,
-        bus: &mut Bus,
-        index: usize,
-        dtype: Data,
-        etype: Option<Data>,
-        addr: usize,
-        recur: bool,
-    ) -> Result<(), CpuError> {
-        let descriptor_byte: u8 = bus.read_byte(addr, AccessCode::OperandFetch)?;
-
-        let m = (descriptor_byte & 0xf0) >> 4;
-        let r = descriptor_byte & 0xf;
-
-        // The descriptor is either 1 or 2 bytes, depending on whether this is a recursive
-        // call or not.
-        let dsize = if recur {
-            2
-        } else {
-            1
-        };
-
-        match m {
-            0 | 1 | 2 | 3 => {
-                // Positive Literal
-                self.set_operand(index, dsize, AddrMode::PositiveLiteral, dtype, etype, None, u32::from(descriptor_byte));
-            }
-            4 => {
-                match r {
-                    15 => {
-                        // Word Immediate
-                        let w = bus.read_op_word(addr + 1)?;
-                        self.set_operand(index, dsize + 4, AddrMode::WordImmediate, dtype, etype, None, w);
-                    }
-                    _ => {
-                        // Register
-                        self.set_operand(index, dsize, AddrMode::Register, dtype, etype, Some(r as usize), 0);
-                    }
-                }
-            }
-            5 => {
-                match r {
-                    15 => {
-                        // Halfword Immediate
-                        let h = bus.read_op_half(addr + 1)?;
-                        self.set_operand(index, dsize + 2, AddrMode::HalfwordImmediate, dtype, etype, None, u32::from(h));
-                    }
-                    11 => {
-                        // Illegal
-                        return Err(CpuError::Exception(CpuException::IllegalOpcode))
-                    }
-                    _ => {
-                        // Register Deferred Mode
-                        self.set_operand(index, dsize, AddrMode::RegisterDeferred, dtype, etype, Some(r as usize), 0);
-                    }
-                }
-            }
-            6 => {
-                match r {
-                    15 => {
-                        // Byte Immediate
-                        let b = bus.read_byte(addr + 1, AccessCode::OperandFetch)?;
-                        self.set_operand(index, dsize + 1, AddrMode::ByteImmediate, dtype, etype, None, u32::from(b));
-                    }
-                    _ => {
-                        // FP Short Offset
-                        self.set_operand(index, dsize, AddrMode::FPShortOffset, dtype, etype, Some(R_FP), u32::from(r));
-                    }
-                }
-            }
-            7 => {
-                match r {
-                    15 => {
-                        // Absolute
-                        let w = bus.read_op_word(addr + 1)?;
-                        self.set_operand(index, dsize + 4, AddrMode::Absolute, dtype, etype, None, w);
-                    }
-                    _ => {
-                        // AP Short Offset
-                        self.set_operand(index, dsize, AddrMode::APShortOffset, dtype, etype, Some(R_AP), u32::from(r));
-                    }
-                }
-            }
-            8 => {
-                match r {
-                    11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
-                    _ => {
-                        // Word Displacement
-                        let disp = bus.read_op_word(addr + 1)?;
-                        self.set_operand(index, dsize + 4, AddrMode::WordDisplacement, dtype, etype, Some(r as usize), disp);
-                    }
-                }
-            }
-            9 => {
-                match r {
-                    11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
-                    _ => {
-                        // Word Displacement Deferred
-                        let disp = bus.read_op_word(addr + 1)?;
-                        self.set_operand(index, dsize + 4, AddrMode::WordDisplacementDeferred, dtype, etype, Some(r as usize), disp);
-                    }
-                }
-            }
-            10 => {
-                match r {
-                    11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
-                    _ => {
-                        // Halfword Displacement
-                        let disp = bus.read_op_half(addr + 1)?;
-                        self.set_operand(index, dsize + 2, AddrMode::HalfwordDisplacement, dtype, etype, Some(r as usize), u32::from(disp));
-                    }
-                }
-            }
-            11 => {
-                match r {
-                    11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
-                    _ => {
-                        // Halfword Displacement Deferred
-                        let disp = bus.read_op_half(addr + 1)?;
-                        self.set_operand(
-                            index,
-                            dsize + 2,
-                            AddrMode::HalfwordDisplacementDeferred,
-                            dtype,
-                            etype,
-                            Some(r as usize),
-                            u32::from(disp),
-                        );
-                    }
-                }
-            }
-            12 => {
-                match r {
-                    11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
-                    _ => {
-                        // Byte Displacement
-                        let disp = bus.read_byte(addr + 1, AccessCode::OperandFetch)?;
-                        self.set_operand(index, dsize + 1, AddrMode::ByteDisplacement, dtype, etype, Some(r as usize), u32::from(disp));
-                    }
-                }
-            }
-            13 => {
-                match r {
-                    11 => return Err(CpuError::Exception(CpuException::IllegalOpcode)),
-                    _ => {
-                        // Byte Displacement Deferred
-                        let disp = bus.read_byte(addr + 1, AccessCode::OperandFetch)?;
-                        self.set_operand(index, dsize + 1, AddrMode::ByteDisplacementDeferred, dtype, etype, Some(r as usize), u32::from(disp));
-                    }
-                }
-            }
-            14 => match r {
-                0 => self.decode_descriptor_operand(bus, index, dtype, Some(Data::UWord), addr + 1, true)?,
-                2 => self.decode_descriptor_operand(bus, index, dtype, Some(Data::UHalf), addr + 1, true)?,
-                3 => self.decode_descriptor_operand(bus, index, dtype, Some(Data::Byte), addr + 1, true)?,
-                4 => self.decode_descriptor_operand(bus, index, dtype, Some(Data::Word), addr + 1, true)?,
-                6 => self.decode_descriptor_operand(bus, index, dtype, Some(Data::Half), addr + 1, true)?,
-                7 => self.decode_descriptor_operand(bus, index, dtype, Some(Data::SByte), addr + 1, true)?,
-                15 => {
-                    let w = bus.read_op_word(addr + 1)?;
-                    self.set_operand(index, dsize + 4, AddrMode::AbsoluteDeferred, dtype, etype, None, w);
-                }
-                _ => { return Err(CpuError::Exception(CpuException::IllegalOpcode)); }
-            },
-            15 => {
-                // Negative Literal
-                self.set_operand(index, 1, AddrMode::NegativeLiteral, dtype, etype, None, u32::from(descriptor_byte));
-            },
-            _ => { return Err(CpuError::Exception(CpuException::IllegalOpcode)); }
-        };
-
-        Ok(())
-    }
-
-    /// Fully decode an Operand
-    fn decode_operand(
-        &mut self,
-        bus: &mut Bus,
-        index: usize,
-        mn: &Mnemonic,
-        ot: OpType,
-        etype: Option<Data>,
-        addr: usize,
-    ) -> Result<(), CpuError> {
-        match ot {
-            OpType::Lit => self.decode_literal_operand(bus, index, mn, addr),
-            OpType::Src | OpType::Dest => self.decode_descriptor_operand(bus, index, mn.dtype, etype, addr, false),
-            OpType::None => Ok(())
-        }
-    }
-
-    /// Decode the instruction currently pointed at by the Program Counter.
-    /// Returns the number of bytes consumed, or a CpuError.
-    fn decode_instruction(&mut self, bus: &mut Bus) -> Result<(), CpuError> {
-        // The next address to read from is pointed to by the PC
-        let mut addr = self.r[R_PC] as usize;
-        let initial_addr = addr;
-
-        // Read the first byte of the instruction. Most instructions are only
-        // one byte, so this is usually enough.
-        let b1 = bus.read_byte(addr, AccessCode::InstrFetch)?;
-        addr += 1;
-
-        // Map the Mnemonic to the  opcode we just read. But there's a special
-        // case if the value we read was '0x30'. This indicates that the instruction
-        // we're reading is a halfword, requiring two bytes.
-
-        let mut mn: &Option<Mnemonic> = &NULL_MNEMONIC;
-
-        if b1 == 0x30 {
-            let b2 = bus.read_byte(addr, AccessCode::InstrFetch)?;
-            addr += 1;
-
-            let opcode = (u16::from(b1) << 8) | u16::from(b2);
-
-            for m in &HALFWORD_MNEMONICS {
-                if m.is_some() && m.as_ref().unwrap().opcode == opcode {
-                    mn = m;
-                    break;
-                }
-            }
-        } else {
-            mn = &BYTE_MNEMONICS[b1 as usize];
-        };
-
-        // If we found a valid mnemonic, read in and decode all of its operands.
-        // Otherwise, we must return a CpuException::IllegalOpcode
-        match mn {
-            Some(mn) => {
-                let mut etype: Option<Data> = None;
-
-                for (index, ot) in mn.ops.iter().enumerate() {
-                    if *ot == OpType::None {
-                        break;
-                    }
-                    // Push a decoded operand
-                    self.decode_operand(bus, index, mn, *ot, etype, addr)?;
-                    etype = self.ir.operands[index].expanded_type;
-                    addr += self.ir.operands[index].size as usize;
-                }
-
-                let total_bytes = addr - initial_addr;
-
-                self.ir.opcode = mn.opcode;
-                self.ir.name = mn.name;
-                self.ir.data_type = mn.dtype;
-                self.ir.bytes = total_bytes as u8;
-            }
-            None => return Err(CpuError::Exception(CpuException::IllegalOpcode))
-        }
-
-        Ok(())
+    pub fn set_psw(&mut self, val: u32) {
+        self.r[R_PSW] = val;
     }
 
     /// Convenience operations on flags.
@@ -2249,7 +2316,7 @@ impl Cpu {
             0 => CpuLevel::Kernel,
             1 => CpuLevel::Executive,
             2 => CpuLevel::Supervisor,
-            3 | _ => CpuLevel::User,
+            _ => CpuLevel::User,
         }
     }
 
@@ -2292,17 +2359,39 @@ impl Cpu {
     pub fn get_steps(&self) -> u64 {
         self.steps
     }
+
+    pub fn tracer(&self) -> &Tracer {
+        &self.trace
+    }
+
+    pub fn tracer_mut(&mut self) -> &mut Tracer {
+        &mut self.trace
+    }
+
+    pub fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
+    pub fn profiler_mut(&mut self) -> &mut Profiler {
+        &mut self.profiler
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::bus::Bus;
+    use crate::symbols::SymbolTable;
+    use crate::trace::TraceFilter;
+
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
 
     const BASE: usize = 0x700000;
 
     /// Helper function to set up and prepare a cpu and bus
     /// with a supplied program.
+    #[allow(clippy::needless_borrow)]
     fn do_with_program<F>(program: &[u8], test: F)
     where
         F: Fn(&mut Cpu, &mut Bus),
@@ -2357,31 +2446,34 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_cast)]
     fn decodes_byte_literal_operand() {
         let program: [u8; 2] = [0x4f, 0x06]; // BLEB 0x6
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_literal_operand(&mut bus, 0, BYTE_MNEMONICS[0x4F as usize].as_ref().unwrap(), BASE + 1).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_literal_operand(bus, BYTE_MNEMONICS[0x4F as usize].as_ref().unwrap(), BASE + 1).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(1, AddrMode::None, Data::Byte, None, None, 6));
         })
     }
 
     #[test]
+    #[allow(clippy::unnecessary_cast)]
     fn decodes_halfword_literal_operand() {
         let program: [u8; 3] = [0x4e, 0xff, 0x0f]; // BLEH 0xfff
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_literal_operand(&mut bus, 0, BYTE_MNEMONICS[0x4e as usize].as_ref().unwrap(), BASE + 1).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_literal_operand(bus, BYTE_MNEMONICS[0x4e as usize].as_ref().unwrap(), BASE + 1).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(2, AddrMode::None, Data::Half, None, None, 0xfff));
         })
     }
 
     #[test]
+    #[allow(clippy::unnecessary_cast)]
     fn decodes_word_literal_operand() {
         let program: [u8; 5] = [0x32, 0xff, 0x4f, 0x00, 0x00]; // SPOP 0x4fff
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_literal_operand(&mut bus, 0, BYTE_MNEMONICS[0x32 as usize].as_ref().unwrap(), BASE + 1).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_literal_operand(bus, BYTE_MNEMONICS[0x32 as usize].as_ref().unwrap(), BASE + 1).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(4, AddrMode::None, Data::Word, None, None, 0x4fff));
         });
     }
@@ -2390,8 +2482,8 @@ mod tests {
     fn decodes_positive_literal_operand() {
         let program: [u8; 3] = [0x87, 0x04, 0x44]; // MOVB &4,%r4
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(1, AddrMode::PositiveLiteral, Data::Byte, None, None, 0x04));
         });
     }
@@ -2400,8 +2492,8 @@ mod tests {
     fn decodes_word_immediate_operand() {
         let program = [0x84, 0x4f, 0x78, 0x56, 0x34, 0x12, 0x43]; // MOVW &0x12345678,%r3
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(5, AddrMode::WordImmediate, Data::Word, None, None, 0x12345678));
         });
     }
@@ -2410,8 +2502,8 @@ mod tests {
     fn decodes_register_operand() {
         let program: [u8; 3] = [0x87, 0x04, 0x44]; // MOVB &4,%r4
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 2, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 2, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(1, AddrMode::Register, Data::Byte, None, Some(4), 0));
         });
     }
@@ -2420,8 +2512,8 @@ mod tests {
     fn decodes_halfword_immediate_operand() {
         let program = [0x84, 0x5f, 0x34, 0x12, 0x42]; // MOVW &0x1234,%r2
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(3, AddrMode::HalfwordImmediate, Data::Word, None, None, 0x1234,));
         });
     }
@@ -2430,8 +2522,8 @@ mod tests {
     fn decodes_register_deferred_operand() {
         let program: [u8; 3] = [0x86, 0x52, 0x41]; // MOVH (%r2),%r1
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Half, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Half, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(1, AddrMode::RegisterDeferred, Data::Half, None, Some(2), 0));
         });
     }
@@ -2440,8 +2532,8 @@ mod tests {
     fn decodes_byte_immediate_operand() {
         let program: [u8; 4] = [0x84, 0x6f, 0x28, 0x46]; // MOVW &40,%r6
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(2, AddrMode::ByteImmediate, Data::Word, None, None, 40));
         });
     }
@@ -2450,8 +2542,8 @@ mod tests {
     fn decodes_fp_short_offset_operand() {
         let program: [u8; 3] = [0x84, 0x6C, 0x40]; // MOVW 12(%fp),%r0
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(1, AddrMode::FPShortOffset, Data::Word, None, Some(R_FP), 12));
         });
     }
@@ -2460,8 +2552,8 @@ mod tests {
     fn decodes_absolute_operand() {
         let program: [u8; 7] = [0x87, 0x7f, 0x00, 0x01, 0x00, 0x00, 0x40]; // MOVB $0x100, %r0
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(5, AddrMode::Absolute, Data::Byte, None, None, 0x00000100));
         });
     }
@@ -2470,8 +2562,8 @@ mod tests {
     fn decodes_absolute_deferred_operand() {
         let program = [0x87, 0xef, 0x00, 0x01, 0x00, 0x00, 0x40]; // MOVB *$0x100,%r0
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(5, AddrMode::AbsoluteDeferred, Data::Byte, None, None, 0x00000100));
         });
     }
@@ -2480,8 +2572,8 @@ mod tests {
     fn decodes_ap_short_offset_operand() {
         let program: [u8; 3] = [0x84, 0x74, 0x43]; // MOVW 4(%ap),%r3
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(1, AddrMode::APShortOffset, Data::Word, None, Some(R_AP), 4));
         });
     }
@@ -2490,8 +2582,8 @@ mod tests {
     fn decodes_word_displacement_operand() {
         let program: [u8; 7] = [0x87, 0x82, 0x34, 0x12, 0x00, 0x00, 0x44]; // MOVB 0x1234(%r2),%r4
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(5, AddrMode::WordDisplacement, Data::Byte, None, Some(2), 0x1234,));
         });
     }
@@ -2500,8 +2592,8 @@ mod tests {
     fn decodes_word_displacement_deferred_operand() {
         let program: [u8; 7] = [0x87, 0x92, 0x50, 0x40, 0x00, 0x00, 0x40]; // MOVB *0x4050(%r2),%r0
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(5, AddrMode::WordDisplacementDeferred, Data::Byte, None, Some(2), 0x4050,));
         });
     }
@@ -2510,8 +2602,8 @@ mod tests {
     fn decodes_halfword_displacement_operand() {
         let program: [u8; 5] = [0x87, 0xa2, 0x34, 0x12, 0x44]; // MOVB 0x1234(%r2),%r4
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(3, AddrMode::HalfwordDisplacement, Data::Byte, None, Some(2), 0x1234,));
         });
     }
@@ -2520,8 +2612,8 @@ mod tests {
     fn decodes_halfword_displacement_deferred_operand() {
         let program: [u8; 5] = [0x87, 0xb2, 0x50, 0x40, 0x40]; // MOVB *0x4050(%r2),%r0
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(3, AddrMode::HalfwordDisplacementDeferred, Data::Byte, None, Some(2), 0x4050,));
         });
     }
@@ -2530,8 +2622,8 @@ mod tests {
     fn decodes_byte_displacement_operand() {
         let program: [u8; 4] = [0x87, 0xc1, 0x06, 0x40]; // MOVB 6(%r1),%r0
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(2, AddrMode::ByteDisplacement, Data::Byte, None, Some(1), 6));
         });
     }
@@ -2540,8 +2632,8 @@ mod tests {
     fn decodes_byte_displacement_deferred_operand() {
         let program: [u8; 4] = [0x87, 0xd2, 0x30, 0x43]; // MOVB *0x30(%r2),%r3
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(2, AddrMode::ByteDisplacementDeferred, Data::Byte, None, Some(2), 0x30));
         });
     }
@@ -2550,9 +2642,9 @@ mod tests {
     fn decodes_expanded_type_operand() {
         let program: [u8; 6] = [0x87, 0xe7, 0x40, 0xe2, 0xc1, 0x04]; // MOVB {sbyte}%r0,{uhalf}4(%r1)
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 1, Data::Byte, None, BASE + 3, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[1] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 3, false).unwrap();
 
             assert_eq!(cpu.ir.operands[0], Operand::new(2, AddrMode::Register, Data::Byte, Some(Data::SByte), Some(0), 0,));
             assert_eq!(cpu.ir.operands[1], Operand::new(3, AddrMode::ByteDisplacement, Data::Byte, Some(Data::UHalf), Some(1), 4,));
@@ -2563,8 +2655,8 @@ mod tests {
     fn decodes_negative_literal_operand() {
         let program: [u8; 3] = [0x87, 0xff, 0x40]; // MOVB &-1,%r0
 
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(cpu.ir.operands[0], Operand::new(1, AddrMode::NegativeLiteral, Data::Byte, None, None, 0xff));
         });
     }
@@ -2580,12 +2672,13 @@ mod tests {
     fn decodes_halfword_instructions() {
         let program = [0x30, 0x0d]; // ENBVJMP
         do_with_program(&program, |cpu, bus| {
-            cpu.decode_instruction(bus).unwrap();
+            cpu.ir = decode_instruction(bus, cpu.r[R_PC]).unwrap();
             assert_instruction(cpu, 0x300d, 2, "ENBVJMP", Data::None);
         })
     }
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn decodes_instructions() {
         let program: [u8; 10] = [
             0x87, 0xe7, 0x40, 0xe2, 0xc1, 0x04, // MOVB {sbyte}%r0,{uhalf}4(%r1)
@@ -2595,7 +2688,7 @@ mod tests {
         do_with_program(&program, |cpu, bus| {
             {
                 cpu.set_pc(BASE as u32);
-                cpu.decode_instruction(bus).unwrap();
+                cpu.ir = decode_instruction(bus, cpu.r[R_PC]).unwrap();
                 let expected_operands = vec![
                     Operand::new(2, AddrMode::Register, Data::Byte, Some(Data::SByte), Some(0), 0),
                     Operand::new(3, AddrMode::ByteDisplacement, Data::Byte, Some(Data::UHalf), Some(1), 4),
@@ -2606,7 +2699,7 @@ mod tests {
             }
             {
                 cpu.set_pc((BASE + 6) as u32);
-                cpu.decode_instruction(bus).unwrap();
+                cpu.ir = decode_instruction(bus, cpu.r[R_PC]).unwrap();
                 let expected_operands = vec![
                     Operand::new(2, AddrMode::ByteDisplacementDeferred, Data::Byte, None, Some(2), 0x30),
                     Operand::new(1, AddrMode::Register, Data::Byte, None, Some(3), 0),
@@ -2618,22 +2711,152 @@ mod tests {
         })
     }
 
+    #[test]
+    fn decodes_from_raw_image() {
+        let image: [u8; 8] = [
+            0x70, // NOP
+            0x87, 0xe7, 0x40, 0xe2, 0xc1, 0x04, // MOVB {sbyte}%r0,{uhalf}4(%r1)
+            0x84, // MOVW, truncated
+        ];
+
+        let instr = decode_instruction(&image[..], 1).unwrap();
+        assert_eq!("MOVB", instr.name);
+        assert_eq!(6, instr.bytes);
+        assert_eq!(instr.operands[0], Operand::new(2, AddrMode::Register, Data::Byte, Some(Data::SByte), Some(0), 0));
+        assert_eq!(instr.operands[1], Operand::new(3, AddrMode::ByteDisplacement, Data::Byte, Some(Data::UHalf), Some(1), 4));
+
+        assert!(decode_instruction(&image[..], 7).is_err());
+    }
+
+    #[test]
+    fn decoding_leaves_cpu_untouched() {
+        let program = [0x84, 0x4f, 0x00, 0x00, 0x60, 0x00, 0x46]; // MOVW &0x600000,%r6
+        do_with_program(&program, |cpu, bus| {
+            let instr = decode_instruction(bus, BASE as u32).unwrap();
+            assert_eq!(0x84, instr.opcode);
+            assert_eq!(7, instr.bytes);
+            assert_eq!(instr.operands[0], Operand::new(5, AddrMode::WordImmediate, Data::Word, None, None, 0x600000));
+            assert_eq!(cpu.ir, Instruction::default());
+            assert_eq!(BASE as u32, cpu.r[R_PC]);
+        })
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuf {
+        fn lines(&self) -> Vec<String> {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap().lines().map(String::from).collect()
+        }
+    }
+
+    #[test]
+    fn traces_steps() {
+        let program = [
+            0x84, 0x4f, 0x00, 0x00, 0x60, 0x00, 0x46, // MOVW &0x600000,%r6
+            0x3c, 0x46, 0x46, // CMPW %r6,%r6
+            0x70, // NOP
+        ];
+        do_with_program(&program, |cpu, bus| {
+            let out = SharedBuf::default();
+            cpu.tracer_mut().set_writer(out.clone());
+            cpu.tracer_mut().set_enabled(true);
+
+            cpu.step(bus);
+            cpu.step(bus);
+            cpu.tracer_mut().set_enabled(false);
+            cpu.step(bus);
+
+            assert_eq!(
+                vec![
+                    "00700000 K  movw &0x600000,%r6           [600000 600000] %r6=00600000 ----",
+                    "00700007 K  cmpw %r6,%r6                 [600000 600000] -Z--",
+                ],
+                out.lines()
+            );
+        })
+    }
+
+    #[test]
+    fn traces_with_symbols() {
+        let program = [
+            0x7b, 0x03, // BRB .+3
+            0x70, // NOP
+            0x70, // NOP
+        ];
+        do_with_program(&program, |cpu, bus| {
+            let mut symbols = SymbolTable::new();
+            symbols.insert(BASE as u32, "start");
+
+            let out = SharedBuf::default();
+            cpu.tracer_mut().set_writer(out.clone());
+            cpu.tracer_mut().set_symbols(Some(Arc::new(symbols)));
+            cpu.tracer_mut().set_enabled(true);
+
+            cpu.step(bus);
+            cpu.step(bus);
+
+            assert_eq!(
+                vec![
+                    "00700000 <start> K  brb 0x700003 <start+0x3>     [0] ----",
+                    "00700003 <start+0x3> K  nop                          ----",
+                ],
+                out.lines()
+            );
+        })
+    }
+
+    #[test]
+    fn filters_traced_steps() {
+        let program = [
+            0x84, 0x4f, 0x00, 0x00, 0x60, 0x00, 0x46, // MOVW &0x600000,%r6
+            0x70, // NOP
+            0x70, // NOP
+        ];
+        do_with_program(&program, |cpu, bus| {
+            let out = SharedBuf::default();
+            cpu.tracer_mut().set_writer(out.clone());
+            cpu.tracer_mut().set_enabled(true);
+            cpu.tracer_mut().set_filter(TraceFilter {
+                addresses: Some(BASE as u32..BASE as u32 + 8),
+                levels: vec![CpuLevel::Kernel],
+                opcodes: vec![NOP],
+            });
+
+            cpu.step(bus);
+            cpu.step(bus);
+            cpu.step(bus);
+
+            assert_eq!(vec!["00700007 K  nop                          ----"], out.lines());
+        })
+    }
+
     #[test]
     fn reads_register_operand_data() {
         {
             let program = [0x87, 0xe7, 0x40, 0xe2, 0x41]; // MOVB {sbyte}%r0,{uhalf}%r1
-            do_with_program(&program, |cpu, mut bus| {
+            do_with_program(&program, |cpu, bus| {
                 cpu.r[0] = 0xff;
-                cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+                cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
                 assert_eq!(0xffffffff, cpu.read_op(bus, 0).unwrap());
             });
         }
 
         {
             let program = [0x87, 0x40, 0x41]; // MOVB %r0,%r1
-            do_with_program(&program, |cpu, mut bus| {
+            do_with_program(&program, |cpu, bus| {
                 cpu.r[0] = 0xff;
-                cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+                cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
                 assert_eq!(0xff, cpu.read_op(bus, 0).unwrap());
             });
         }
@@ -2642,8 +2865,8 @@ mod tests {
     #[test]
     fn reads_positive_literal_operand_data() {
         let program = [0x87, 0x04, 0x44];
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(4, cpu.read_op(bus, 0).unwrap() as i8);
         });
     }
@@ -2651,8 +2874,8 @@ mod tests {
     #[test]
     fn reads_negative_literal_operand_data() {
         let program = [0x87, 0xff, 0x44];
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(-1, cpu.read_op(bus, 0).unwrap() as i8);
         });
     }
@@ -2660,8 +2883,8 @@ mod tests {
     #[test]
     fn reads_word_immediate_operand_data() {
         let program = [0x84, 0x4f, 0x78, 0x56, 0x34, 0x12, 0x43]; // MOVW &0x12345678,%r3
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(0x12345678, cpu.read_op(bus, 0).unwrap())
         });
     }
@@ -2669,8 +2892,8 @@ mod tests {
     #[test]
     fn reads_halfword_immediate_operand_data() {
         let program = [0x84, 0x5f, 0x34, 0x12, 0x42]; // MOVW &0x1234,%r2
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(0x1234, cpu.read_op(bus, 0).unwrap())
         });
     }
@@ -2678,8 +2901,8 @@ mod tests {
     #[test]
     fn reads_negative_halfword_immediate_operand_data() {
         let program = [0x84, 0x5f, 0x00, 0x80, 0x42]; // MOVW &0x8000,%r2
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(0xffff8000, cpu.read_op(bus, 0).unwrap())
         });
     }
@@ -2687,8 +2910,8 @@ mod tests {
     #[test]
     fn reads_byte_immediate_operand_data() {
         let program = [0x84, 0x6f, 0x28, 0x42]; // MOVW &40,%r2
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(40, cpu.read_op(bus, 0).unwrap())
         });
     }
@@ -2696,8 +2919,8 @@ mod tests {
     #[test]
     fn reads_negative_byte_immediate_operand_data() {
         let program = [0x84, 0x6f, 0xff, 0x42]; // MOVW &-1,%r2
-        do_with_program(&program, |cpu, mut bus| {
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+        do_with_program(&program, |cpu, bus| {
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(-1, cpu.read_op(bus, 0).unwrap() as i32)
         });
     }
@@ -2705,9 +2928,9 @@ mod tests {
     #[test]
     fn reads_absolute_operand_data() {
         let program = [0x87, 0x7f, 0x00, 0x02, 0x70, 0x00, 0x04]; // MOVB $0x700200,%r0
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             bus.write_byte(0x700200, 0x5a).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x5a, cpu.read_op(bus, 0).unwrap());
         });
     }
@@ -2715,10 +2938,10 @@ mod tests {
     #[test]
     fn reads_absolute_deferred_operand_data() {
         let program = [0x87, 0xef, 0x00, 0x01, 0x70, 0x00, 0x41]; // MOVB *$0x700100,%r0
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             bus.write_word(0x700100, 0x700300).unwrap();
             bus.write_byte(0x700300, 0x1f).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x1f, cpu.read_op(bus, 0).unwrap());
         });
     }
@@ -2729,13 +2952,13 @@ mod tests {
             0x87, 0xc1, 0x06, 0x40, // MOVB 6(%r1),%r0
             0x87, 0xc1, 0xfe, 0x40, // MOVB -2(%r1),%r0
         ];
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[1] = 0x700200;
             bus.write_byte(0x700206, 0x1f).unwrap();
             bus.write_byte(0x7001fe, 0xc5).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x1f, cpu.read_op(bus, 0).unwrap());
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 5, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 5, false).unwrap();
             assert_eq!(0xc5, cpu.read_op(bus, 0).unwrap());
         });
     }
@@ -2743,11 +2966,11 @@ mod tests {
     #[test]
     fn reads_byte_displacement_deferred_operand_data() {
         let program = [0x87, 0xd2, 0x30, 0x43]; // MOVB *0x30(%r2),%r3
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[2] = 0x700200;
             bus.write_word(0x700230, 0x700300).unwrap();
             bus.write_byte(0x700300, 0x5a).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x5a, cpu.read_op(bus, 0).unwrap());
         })
     }
@@ -2755,10 +2978,10 @@ mod tests {
     #[test]
     fn reads_halword_displacement_operand_data() {
         let program = [0x87, 0xa2, 0x01, 0x11, 0x48]; // MOVB 0x1101(%r2),%r8
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[2] = 0x700000;
             bus.write_byte(0x701101, 0x1f).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x1f, cpu.read_op(bus, 0).unwrap());
         });
     }
@@ -2766,11 +2989,11 @@ mod tests {
     #[test]
     fn reads_halfword_displacement_deferred_operand_data() {
         let program = [0x87, 0xb2, 0x00, 0x02, 0x46]; // MOVB *0x200(%r2),%r6
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[2] = 0x700000;
             bus.write_word(0x700200, 0x700500).unwrap();
             bus.write_byte(0x700500, 0x5a).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x5a, cpu.read_op(bus, 0).unwrap());
         })
     }
@@ -2778,10 +3001,10 @@ mod tests {
     #[test]
     fn reads_word_displacement_operand_data() {
         let program = [0x87, 0x82, 0x01, 0x11, 0x00, 0x00, 0x48]; // MOVB 0x1101(%r2),%r8
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[2] = 0x700000;
             bus.write_byte(0x701101, 0x1f).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x1f, cpu.read_op(bus, 0).unwrap());
         });
     }
@@ -2789,11 +3012,11 @@ mod tests {
     #[test]
     fn reads_word_displacement_deferred_operand_data() {
         let program = [0x87, 0x92, 0x00, 0x02, 0x00, 0x00, 0x46]; // MOVB *0x200(%r2),%r6
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[2] = 0x700000;
             bus.write_word(0x700200, 0x700500).unwrap();
             bus.write_byte(0x700500, 0x5a).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE + 1, false).unwrap();
             assert_eq!(0x5a, cpu.read_op(bus, 0).unwrap());
         })
     }
@@ -2801,10 +3024,10 @@ mod tests {
     #[test]
     fn reads_ap_short_offset_operand_data() {
         let program = [0x84, 0x74, 0x43]; // MOVW 4(%ap),%r3
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[R_AP] = 0x700500;
             bus.write_word(0x700504, 0x12345678).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(0x12345678, cpu.read_op(bus, 0).unwrap());
         });
     }
@@ -2812,20 +3035,78 @@ mod tests {
     #[test]
     fn reads_fp_short_offset_operand_data() {
         let program = [0x84, 0x6c, 0x40]; // MOVW 12(%fp),%r0
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[R_FP] = 0x700200;
             bus.write_word(0x70020c, 0x12345678).unwrap();
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Word, None, BASE + 1, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
             assert_eq!(0x12345678, cpu.read_op(bus, 0).unwrap());
         });
     }
 
+    #[test]
+    fn read_op_stores_value_in_operand() {
+        let program = [0x84, 0x6c, 0x40]; // MOVW 12(%fp),%r0
+        do_with_program(&program, |cpu, bus| {
+            cpu.r[R_FP] = 0x700200;
+            bus.write_word(0x70020c, 0x12345678).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Word, None, BASE + 1, false).unwrap();
+            assert_eq!(0, cpu.ir.operands[0].data);
+            cpu.read_op(bus, 0).unwrap();
+            assert_eq!(0x12345678, cpu.ir.operands[0].data);
+        });
+    }
+
+    /// Set up a vector table entry and PCB for the input port change
+    /// interrupt (vector 0x3d), with its handler at BASE + 0x100, and
+    /// enable that interrupt in the DUART.
+    fn setup_mouse_interrupt(cpu: &mut Cpu, bus: &mut Bus) {
+        let pcb: u32 = BASE as u32 + 0x200;
+        let vector_entry = 0x8c + 4 * 0x3d;
+        bus.load(vector_entry, &pcb.to_be_bytes()).unwrap();
+        bus.write_word(pcb as usize, 0).unwrap(); // PSW
+        bus.write_word(pcb as usize + 4, BASE as u32 + 0x100).unwrap(); // PC
+        bus.write_word(pcb as usize + 8, BASE as u32 + 0x400).unwrap(); // SP
+        bus.write_byte(BASE + 0x100, 0x70).unwrap(); // NOP
+        cpu.r[R_ISP] = BASE as u32 + 0x300;
+        cpu.r[R_PCBP] = BASE as u32 + 0x500;
+
+        bus.write_byte(0x200013, 0x0f).unwrap(); // ACR
+        bus.write_byte(0x200017, 0x80).unwrap(); // IMR
+    }
+
+    #[test]
+    fn takes_interrupt_above_current_ipl() {
+        let program = [0x70]; // NOP
+        do_with_program(&program, |cpu, bus| {
+            setup_mouse_interrupt(cpu, bus);
+            cpu.r[R_PSW] = 13 << 13;
+            bus.mouse_down(0);
+            cpu.step_with_error(bus).unwrap();
+            assert_eq!(BASE as u32 + 0x101, cpu.r[R_PC]);
+            assert_eq!(BASE as u32 + 0x304, cpu.r[R_ISP]);
+            assert_eq!(BASE as u32 + 0x200, cpu.r[R_PCBP]);
+        });
+    }
+
+    #[test]
+    fn ignores_interrupt_at_or_below_current_ipl() {
+        let program = [0x70]; // NOP
+        do_with_program(&program, |cpu, bus| {
+            setup_mouse_interrupt(cpu, bus);
+            cpu.r[R_PSW] = 14 << 13;
+            bus.mouse_down(0);
+            cpu.step_with_error(bus).unwrap();
+            assert_eq!(BASE as u32 + 1, cpu.r[R_PC]);
+            assert_eq!(BASE as u32 + 0x300, cpu.r[R_ISP]);
+        });
+    }
+
     #[test]
     fn writes_register_operand_data() {
         let program = [0x40];
-        do_with_program(&program, |cpu, mut bus| {
+        do_with_program(&program, |cpu, bus| {
             cpu.r[0] = 0;
-            cpu.decode_descriptor_operand(&mut bus, 0, Data::Byte, None, BASE, false).unwrap();
+            cpu.ir.operands[0] = decode_descriptor_operand(bus, Data::Byte, None, BASE, false).unwrap();
             cpu.write_op(bus, 0, 0x5a).unwrap();
             assert_eq!(0x5a, cpu.r[0]);
         });
@@ -0,0 +1,287 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::bus::Bus;
+use crate::cpu::{decode_instruction, R_AP, R_FP, R_PC, R_SP};
+use crate::instr::{RET, SAVE};
+use crate::symbols::SymbolTable;
+
+use std::fmt;
+use std::ops::Range;
+
+/// The kind of bus access a watchpoint triggers on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Watch {
+    Read,
+    Write,
+    /// Either a read or a write
+    Access,
+}
+
+impl Watch {
+    fn matches(self, write: bool) -> bool {
+        match self {
+            Watch::Read => !write,
+            Watch::Write => write,
+            Watch::Access => true,
+        }
+    }
+}
+
+/// Stops execution when the CPU accesses memory in a range of
+/// addresses. Instruction fetches are not watched.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Watchpoint {
+    pub addresses: Range<u32>,
+    pub kind: Watch,
+}
+
+impl Watchpoint {
+    /// True if an access of `size` bytes at `address` triggers the
+    /// watchpoint.
+    pub fn matches(&self, address: u32, size: u32, write: bool) -> bool {
+        self.kind.matches(write) && address < self.addresses.end && address.saturating_add(size) > self.addresses.start
+    }
+}
+
+/// A watched access. `kind` is either `Watch::Read` or `Watch::Write`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WatchHit {
+    pub address: u32,
+    pub kind: Watch,
+}
+
+/// Unsigned comparisons for conditional breakpoints.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A condition on the value of a register.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Condition {
+    pub register: usize,
+    pub comparison: Comparison,
+    pub value: u32,
+}
+
+impl Condition {
+    pub fn holds(&self, registers: &[u32; 16]) -> bool {
+        let r = registers[self.register & 0xf];
+        match self.comparison {
+            Comparison::Eq => r == self.value,
+            Comparison::Ne => r != self.value,
+            Comparison::Lt => r < self.value,
+            Comparison::Le => r <= self.value,
+            Comparison::Gt => r > self.value,
+            Comparison::Ge => r >= self.value,
+        }
+    }
+}
+
+/// Stops execution before the instruction at `address` runs, if the
+/// condition, when there is one, holds.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Breakpoint {
+    pub address: u32,
+    pub condition: Option<Condition>,
+}
+
+impl Breakpoint {
+    pub fn new(address: u32) -> Breakpoint {
+        Breakpoint {
+            address,
+            condition: None,
+        }
+    }
+
+    pub fn when(address: u32, register: usize, comparison: Comparison, value: u32) -> Breakpoint {
+        Breakpoint {
+            address,
+            condition: Some(Condition {
+                register,
+                comparison,
+                value,
+            }),
+        }
+    }
+
+    pub fn is_hit(&self, registers: &[u32; 16]) -> bool {
+        match self.condition {
+            Some(c) => registers[R_PC] == self.address && c.holds(registers),
+            None => registers[R_PC] == self.address,
+        }
+    }
+}
+
+/// Why `Dmd::run_until_break` returned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StopReason {
+    /// The PC reached a breakpoint at this address
+    Breakpoint(u32),
+    /// The last instruction accessed a watched address
+    Watchpoint(WatchHit),
+    /// The maximum number of steps ran
+    StepLimit,
+}
+
+//
+// Stack Frames
+//
+// CALL pushes the return PC and the caller's AP, and points the AP
+// at the arguments pushed before it. SAVE then pushes the caller's
+// FP and the saved registers, always reserving 28 bytes, and points
+// the FP past them. The stack grows upwards, so below a frame's FP
+// are:
+//
+//   FP - 36   return PC
+//   FP - 32   caller's AP
+//   FP - 28   caller's FP
+//   FP - 24   saved registers, up to %r8
+//
+// and the arguments run from the AP up to the return PC.
+//
+
+const FRAME_RETURN_PC: u32 = 36;
+const FRAME_SAVED_AP: u32 = 32;
+const FRAME_SAVED_FP: u32 = 28;
+
+/// More words than this between the AP and the return PC are not
+/// taken to be arguments.
+const MAX_ARGS: u32 = 16;
+
+/// A stack frame in a backtrace.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    /// The PC in the innermost frame, and the return address in the
+    /// others
+    pub pc: u32,
+    /// `pc` as symbol+offset, when the symbol table resolves it
+    pub symbol: Option<String>,
+    pub ap: u32,
+    pub args: Vec<u32>,
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:08x}", self.pc)?;
+        if let Some(symbol) = &self.symbol {
+            write!(f, " <{}>", symbol)?;
+        }
+        let args: Vec<String> = self.args.iter().map(|a| format!("0x{:x}", a)).collect();
+        write!(f, " ({})", args.join(", "))
+    }
+}
+
+/// The words from `ap` up to, but not including, `end`.
+fn arguments(bus: &Bus, ap: u32, end: u32) -> Vec<u32> {
+    if ap > end || (end - ap) / 4 > MAX_ARGS {
+        return Vec::new();
+    }
+    (ap..end).step_by(4).map_while(|a| bus.peek_word(a as usize).ok()).collect()
+}
+
+/// Walk the CALL/SAVE frames from the registers `r`, innermost first,
+/// for at most `max_frames` frames. Memory is read without side
+/// effects. The walk ends at the first frame whose saved values can't
+/// be read, or whose caller's FP is not below its own.
+pub fn backtrace(bus: &Bus, r: &[u32; 16], symbols: &SymbolTable, max_frames: usize) -> Vec<Frame> {
+    let mut frames = Vec::new();
+    let mut pc = r[R_PC];
+    let mut ap = r[R_AP];
+    let mut fp = r[R_FP];
+
+    let frame = |pc: u32, ap: u32, end: u32| Frame {
+        pc,
+        symbol: symbols.symbolize(pc),
+        ap,
+        args: arguments(bus, ap, end),
+    };
+
+    // Between CALL and SAVE, and between RESTORE and RET, the return
+    // PC and caller's AP are on top of the stack, and the FP is still
+    // the caller's.
+    let opcode = decode_instruction(bus, pc).map(|i| i.opcode).ok();
+    if opcode == Some(SAVE) || opcode == Some(RET) {
+        let sp = r[R_SP];
+        let saved = (bus.peek_word(sp.wrapping_sub(8) as usize), bus.peek_word(sp.wrapping_sub(4) as usize));
+
+        frames.push(frame(pc, ap, sp.wrapping_sub(8)));
+        match saved {
+            (Ok(return_pc), Ok(caller_ap)) => {
+                pc = return_pc;
+                ap = caller_ap;
+            }
+            _ => return frames,
+        }
+    }
+
+    while frames.len() < max_frames {
+        frames.push(frame(pc, ap, fp.wrapping_sub(FRAME_RETURN_PC)));
+
+        let saved = (
+            bus.peek_word(fp.wrapping_sub(FRAME_RETURN_PC) as usize),
+            bus.peek_word(fp.wrapping_sub(FRAME_SAVED_AP) as usize),
+            bus.peek_word(fp.wrapping_sub(FRAME_SAVED_FP) as usize),
+        );
+
+        match saved {
+            (Ok(return_pc), Ok(caller_ap), Ok(caller_fp)) if caller_fp < fp && bus.peek_byte(return_pc as usize).is_ok() => {
+                pc = return_pc;
+                ap = caller_ap;
+                fp = caller_fp;
+            }
+            _ => break,
+        }
+    }
+
+    frames.truncate(max_frames);
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watchpoints_match_overlapping_accesses() {
+        let wp = Watchpoint {
+            addresses: 0x700100..0x700104,
+            kind: Watch::Write,
+        };
+
+        assert!(wp.matches(0x700100, 1, true));
+        assert!(wp.matches(0x7000fe, 4, true));
+        assert!(wp.matches(0x700103, 1, true));
+        assert!(!wp.matches(0x700104, 4, true));
+        assert!(!wp.matches(0x7000fc, 4, true));
+        assert!(!wp.matches(0x700100, 4, false));
+
+        let wp = Watchpoint {
+            addresses: 0x700100..0x700104,
+            kind: Watch::Access,
+        };
+        assert!(wp.matches(0x700100, 4, false));
+        assert!(wp.matches(0x700100, 4, true));
+    }
+
+    #[test]
+    fn conditional_breakpoints_compare_registers() {
+        let mut r = [0u32; 16];
+        r[15] = 0x4460;
+        r[3] = 10;
+
+        assert!(Breakpoint::new(0x4460).is_hit(&r));
+        assert!(!Breakpoint::new(0x4464).is_hit(&r));
+        assert!(Breakpoint::when(0x4460, 3, Comparison::Eq, 10).is_hit(&r));
+        assert!(!Breakpoint::when(0x4460, 3, Comparison::Ne, 10).is_hit(&r));
+        assert!(Breakpoint::when(0x4460, 3, Comparison::Ge, 10).is_hit(&r));
+        assert!(!Breakpoint::when(0x4460, 3, Comparison::Gt, 10).is_hit(&r));
+        assert!(Breakpoint::when(0x4460, 3, Comparison::Lt, 11).is_hit(&r));
+        assert!(!Breakpoint::when(0x4460, 3, Comparison::Le, 9).is_hit(&r));
+    }
+}
@@ -0,0 +1,307 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::cpu::{decode_instruction, AddrMode, ByteSource, Data, Instruction, Operand};
+use crate::symbols::SymbolTable;
+
+use std::fmt;
+
+pub(crate) const REGISTER_NAMES: [&str; 16] =
+    ["%r0", "%r1", "%r2", "%r3", "%r4", "%r5", "%r6", "%r7", "%r8", "%fp", "%ap", "%psw", "%sp", "%pcbp", "%isp", "%pc"];
+
+/// A single disassembled instruction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Line {
+    pub address: u32,
+    /// The address as `symbol+offset`, when a symbol table was given
+    pub symbol: Option<String>,
+    pub bytes: Vec<u8>,
+    pub text: String,
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bytes: Vec<String> = self.bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        write!(f, "{:08x}", self.address)?;
+        if let Some(symbol) = &self.symbol {
+            write!(f, " <{}>", symbol)?;
+        }
+        write!(f, ":  {:<30}{}", bytes.join(" "), self.text)
+    }
+}
+
+fn register_name(r: Option<usize>) -> &'static str {
+    r.map_or("%??", |r| REGISTER_NAMES[r & 0xf])
+}
+
+fn expanded_type_name(t: Data) -> &'static str {
+    match t {
+        Data::UWord => "uword",
+        Data::UHalf => "uhalf",
+        Data::Byte => "ubyte",
+        Data::Word => "sword",
+        Data::Half => "shalf",
+        Data::SByte => "sbyte",
+        Data::None => "none",
+    }
+}
+
+fn signed(val: i32) -> String {
+    if val > -10 && val < 10 {
+        format!("{}", val)
+    } else if val < 0 {
+        format!("-0x{:x}", -(val as i64))
+    } else {
+        format!("0x{:x}", val)
+    }
+}
+
+/// Size of an operand with the given mode, when it has no expanded
+/// type descriptor.
+fn base_size(mode: AddrMode) -> u8 {
+    match mode {
+        AddrMode::ByteImmediate | AddrMode::ByteDisplacement | AddrMode::ByteDisplacementDeferred => 2,
+        AddrMode::HalfwordImmediate | AddrMode::HalfwordDisplacement | AddrMode::HalfwordDisplacementDeferred => 3,
+        AddrMode::WordImmediate
+        | AddrMode::WordDisplacement
+        | AddrMode::WordDisplacementDeferred
+        | AddrMode::Absolute
+        | AddrMode::AbsoluteDeferred => 5,
+        _ => 1,
+    }
+}
+
+/// Render a code or data address, followed by `<symbol+offset>` if it
+/// can be resolved.
+fn format_address(prefix: &str, target: u32, symbols: Option<&SymbolTable>) -> String {
+    match symbols.and_then(|s| s.symbolize(target)) {
+        Some(symbol) => format!("{}0x{:x} <{}>", prefix, target, symbol),
+        None => format!("{}0x{:x}", prefix, target),
+    }
+}
+
+fn format_operand(op: &Operand, data_type: Data, address: u32, symbols: Option<&SymbolTable>) -> String {
+    let r = register_name(op.register);
+
+    let text = match op.mode {
+        // Literal operands without a descriptor are branch
+        // displacements, or words for the support processor ops.
+        AddrMode::None => match data_type {
+            Data::Byte => return format_address("", address.wrapping_add(op.embedded as u8 as i8 as u32), symbols),
+            Data::Half => return format_address("", address.wrapping_add(op.embedded as u16 as i16 as u32), symbols),
+            _ => return format!("&0x{:x}", op.embedded),
+        },
+        AddrMode::PositiveLiteral => format!("&{}", op.embedded),
+        AddrMode::NegativeLiteral => format!("&{}", op.embedded as u8 as i8),
+        AddrMode::ByteImmediate => format!("&{}", signed(i32::from(op.embedded as u8 as i8))),
+        AddrMode::HalfwordImmediate => format!("&{}", signed(i32::from(op.embedded as u16 as i16))),
+        AddrMode::WordImmediate => format!("&{}", signed(op.embedded as i32)),
+        AddrMode::Register => r.to_string(),
+        AddrMode::RegisterDeferred => format!("({})", r),
+        AddrMode::FPShortOffset | AddrMode::APShortOffset => format!("{}({})", op.embedded, r),
+        AddrMode::ByteDisplacement => format!("{}({})", signed(i32::from(op.embedded as u8 as i8)), r),
+        AddrMode::ByteDisplacementDeferred => format!("*{}({})", signed(i32::from(op.embedded as u8 as i8)), r),
+        AddrMode::HalfwordDisplacement => format!("{}({})", signed(i32::from(op.embedded as u16 as i16)), r),
+        AddrMode::HalfwordDisplacementDeferred => {
+            format!("*{}({})", signed(i32::from(op.embedded as u16 as i16)), r)
+        }
+        AddrMode::WordDisplacement => format!("{}({})", signed(op.embedded as i32), r),
+        AddrMode::WordDisplacementDeferred => format!("*{}({})", signed(op.embedded as i32), r),
+        AddrMode::Absolute => format_address("$", op.embedded, symbols),
+        AddrMode::AbsoluteDeferred => format_address("*$", op.embedded, symbols),
+        AddrMode::Expanded => String::from("?"),
+    };
+
+    // An expanded type carried over from a previous operand does not
+    // take up a descriptor byte of its own.
+    match op.expanded_type() {
+        Some(t) if op.size == base_size(op.mode) + 1 => format!("{{{}}}{}", expanded_type_name(t), text),
+        _ => text,
+    }
+}
+
+/// Render a decoded instruction in AT&T syntax. Branch targets are
+/// computed relative to `address`. Branch targets and absolute
+/// addresses are followed by `<symbol+offset>` when `symbols` resolves
+/// them.
+pub fn format_instruction(instr: &Instruction, address: u32, symbols: Option<&SymbolTable>) -> String {
+    let operands: Vec<String> = instr
+        .operands
+        .iter()
+        .take_while(|op| op.size > 0)
+        .map(|op| format_operand(op, instr.data_type, address, symbols))
+        .collect();
+
+    let name = instr.name.to_lowercase();
+
+    if operands.is_empty() {
+        name
+    } else {
+        format!("{}\t{}", name, operands.join(","))
+    }
+}
+
+/// Disassemble the instruction at `address`. Bytes that do not
+/// decode as an instruction are rendered as a single `.byte`.
+///
+/// Any byte source can be disassembled, such as a ROM image, or the
+/// bus of a running system, whose devices are never touched.
+pub fn disassemble<S: ByteSource + ?Sized>(source: &S, address: u32, symbols: Option<&SymbolTable>) -> Line {
+    let symbol = symbols.and_then(|s| s.symbolize(address));
+
+    match decode_instruction(source, address) {
+        Ok(instr) => {
+            let bytes = (0..instr.bytes as usize).map(|i| source.fetch_byte(address as usize + i).unwrap_or(0)).collect();
+
+            Line {
+                address,
+                symbol,
+                bytes,
+                text: format_instruction(&instr, address, symbols),
+            }
+        }
+        Err(_) => match source.fetch_byte(address as usize) {
+            Ok(b) => Line {
+                address,
+                symbol,
+                bytes: vec![b],
+                text: format!(".byte\t0x{:02x}", b),
+            },
+            Err(_) => Line {
+                address,
+                symbol,
+                bytes: vec![],
+                text: String::from("???"),
+            },
+        },
+    }
+}
+
+/// Disassemble `count` consecutive instructions starting at `address`.
+pub fn disassemble_range<S: ByteSource + ?Sized>(
+    source: &S,
+    address: u32,
+    count: usize,
+    symbols: Option<&SymbolTable>,
+) -> Vec<Line> {
+    let mut lines = Vec::with_capacity(count);
+    let mut address = address;
+
+    for _ in 0..count {
+        let line = disassemble(source, address, symbols);
+        address = address.wrapping_add(line.bytes.len().max(1) as u32);
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bus::Bus;
+
+    const BASE: u32 = 0x700000;
+
+    fn dis(program: &[u8]) -> Vec<String> {
+        let mut bus = Bus::new(0x10000);
+        bus.load(BASE as usize, program).unwrap();
+        disassemble_range(&bus, BASE, 16, None)
+            .into_iter()
+            .take_while(|l| l.address < BASE + program.len() as u32)
+            .map(|l| l.text)
+            .collect()
+    }
+
+    #[test]
+    fn formats_register_and_immediate_modes() {
+        assert_eq!(
+            vec!["movw\t&0x600000,%r6", "movw\t%r0,%r7", "addw2\t&4,%r6", "clrb\t2(%r0)", "pushw\t(%r2)"],
+            dis(&[
+                0x84, 0x4f, 0x00, 0x00, 0x60, 0x00, 0x46, // MOVW &0x600000,%r6
+                0x84, 0x40, 0x47, // MOVW %r0,%r7
+                0x9c, 0x04, 0x46, // ADDW2 &4,%r6
+                0x83, 0xc0, 0x02, // CLRB 2(%r0)
+                0xa0, 0x52, // PUSHW (%r2)
+            ])
+        );
+    }
+
+    #[test]
+    fn formats_displacement_and_absolute_modes() {
+        assert_eq!(
+            vec!["call\t-4(%sp),$0x1dcc", "movw\t4(%ap),%r8", "jmp\t*$0x71c34c", "movw\t0x1000(%fp),*-0x10(%r1)"],
+            dis(&[
+                0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00, // CALL -4(%sp),$0x1dcc
+                0x84, 0x74, 0x48, // MOVW 4(%ap),%r8
+                0x24, 0xef, 0x4c, 0xc3, 0x71, 0x00, // JMP *$0x71c34c
+                0x84, 0xa9, 0x00, 0x10, 0xd1, 0xf0, // MOVW 0x1000(%fp),*-0x10(%r1)
+            ])
+        );
+    }
+
+    #[test]
+    fn formats_expanded_types_and_literals() {
+        assert_eq!(
+            vec!["movb\t$0x601ffa,{uword}%r0", "andb2\t&0xff,%r0", "mcomw\t&-1,%r3"],
+            dis(&[
+                0x87, 0x7f, 0xfa, 0x1f, 0x60, 0x00, 0xe0, 0x40, // MOVB $0x601ffa,{uword}%r0
+                0xbb, 0x5f, 0xff, 0x00, 0x40, // ANDB2 &0xff,%r0
+                0x88, 0xff, 0x43, // MCOMW &-1,%r3
+            ])
+        );
+    }
+
+    #[test]
+    fn formats_branch_targets() {
+        assert_eq!(
+            vec!["brb\t0x700010", "bneb\t0x6ffff4", "nop", "brh\t0x700003"],
+            dis(&[
+                0x7b, 0x10, // BRB .+0x10
+                0x77, 0xf2, // BNEB .-0x0e
+                0x70, // NOP
+                0x7a, 0xfe, 0xff, // BRH .-2
+            ])
+        );
+    }
+
+    #[test]
+    fn renders_undecodable_bytes() {
+        let mut bus = Bus::new(0x10000);
+        bus.load(BASE as usize, &[0x01, 0x70]).unwrap();
+
+        let lines = disassemble_range(&bus, BASE, 2, None);
+
+        assert_eq!(".byte\t0x01", lines[0].text);
+        assert_eq!(BASE + 1, lines[1].address);
+        assert_eq!("nop", lines[1].text);
+        assert_eq!("00700001:  70                            nop", lines[1].to_string());
+    }
+
+    #[test]
+    fn resolves_symbols() {
+        let mut bus = Bus::new(0x10000);
+        bus.load(
+            BASE as usize,
+            &[
+                0x2c, 0x5c, 0x7f, 0x0c, 0x00, 0x70, 0x00, // CALL (%sp),$0x70000c
+                0x7b, 0x04, // BRB .+4
+                0x70, // NOP
+                0x70, // NOP
+                0x70, // NOP
+                0x08, // RET
+            ],
+        )
+        .unwrap();
+
+        let mut symbols = SymbolTable::new();
+        symbols.insert(BASE, "main");
+        symbols.insert(BASE + 0xc, "done");
+
+        let lines = disassemble_range(&bus, BASE, 3, Some(&symbols));
+
+        assert_eq!("call\t(%sp),$0x70000c <done>", lines[0].text);
+        assert_eq!("brb\t0x70000b <main+0xb>", lines[1].text);
+        assert_eq!(Some("main+0x9".to_string()), lines[2].symbol);
+        assert_eq!("00700009 <main+0x9>:  70                            nop", lines[2].to_string());
+    }
+}
@@ -1,27 +1,100 @@
 #![allow(clippy::unreadable_literal)]
 
 use crate::bus::{Bus, AccessCode};
-use crate::cpu::Cpu;
-use crate::err::BusError;
+use crate::coff::{Coff, LoadedProgram};
+use crate::cpu::{Cpu, CpuLevel};
+use crate::debug::{self, Breakpoint, Comparison, Frame, StopReason, Watch, Watchpoint};
+use crate::disasm::{self, Line};
+use crate::duart::{LineError, ModemLine};
+use crate::err::{BusError, CoffError, NvramError, RomError, SymbolError};
+use crate::gdb::{GdbServer, RUN_CHUNK};
+#[cfg(unix)]
+use crate::host::{Pty, TcpLine, Transport};
+use crate::keyboard::{Key, Keyboard, KeyboardEvent, Keymap};
+use crate::nvram::NvramSettings;
+use crate::profile::Profiler;
+use crate::rom::{Rom, RomInfo};
+use crate::symbols::SymbolTable;
+use crate::trace::TraceFilter;
+#[cfg(feature = "embedded-rom")]
 use crate::rom_hi::HI_ROM;
+#[cfg(feature = "embedded-rom")]
 use crate::rom_lo::LO_ROM;
 
 use libc::*;
+use std::ffi::CStr;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::ops::Range;
+use std::path::Path;
 use std::ptr;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 lazy_static! {
     static ref DMD: Mutex<Dmd> = Mutex::new(Dmd::new());
 }
 
+#[cfg(unix)]
+lazy_static! {
+    static ref PTY: Mutex<Option<Pty>> = Mutex::new(None);
+    static ref TCP: Mutex<Option<TcpLine>> = Mutex::new(None);
+}
+
 // Return vlaues for the C library
 const SUCCESS: c_int = 0;
 const ERROR: c_int = 1;
 const BUSY: c_int = 2;
 
+// Frames returned by Dmd::backtrace
+const BACKTRACE_DEPTH: usize = 64;
+
+// Single steps between writes of changed NVRAM to its file
+const NVRAM_FLUSH_STEPS: u32 = 100_000;
+
+// Stop reasons returned by dmd_run_until_break
+const STOP_STEP_LIMIT: c_int = 0;
+const STOP_BREAKPOINT: c_int = 1;
+const STOP_READ_WATCH: c_int = 2;
+const STOP_WRITE_WATCH: c_int = 3;
+
+// Errors injected by dmd_rx_char_error
+const LINE_PARITY: c_int = 0;
+const LINE_FRAMING: c_int = 1;
+
+// Modem control lines, for dmd_modem_line, dmd_set_modem_line and
+// dmd_modem_poll
+const MODEM_DTR: c_int = 0;
+const MODEM_RTS: c_int = 1;
+const MODEM_CTS: c_int = 2;
+const MODEM_DCD: c_int = 3;
+const MODEM_DSR: c_int = 4;
+
+// Transports selected by dmd_tcp_connect
+const TRANSPORT_RAW: c_int = 0;
+const TRANSPORT_TELNET: c_int = 1;
+
+// Keymaps selected by dmd_select_keymap
+const KEYMAP_5620: c_int = 0;
+const KEYMAP_ASCII: c_int = 1;
+
+// Keyboard events returned by dmd_keyboard_poll
+const KEYBOARD_ALARM: c_int = 0;
+const KEYBOARD_CLICK: c_int = 1;
+const KEYBOARD_LIGHTS: c_int = 2;
+
 pub struct Dmd {
     cpu: Cpu,
     bus: Bus,
+    rom: Option<Rom>,
+    breakpoints: Vec<Breakpoint>,
+    // The PC at which run_until_break last ran out of steps
+    step_limit_pc: Option<u32>,
+    // Single steps since the NVRAM file was last brought up to date
+    nvram_steps: u32,
+    nvram_error: Option<io::Error>,
+    debug_access: bool,
+    symbols: Arc<SymbolTable>,
+    keymap: Keymap,
 }
 
 impl Default for Dmd {
@@ -31,23 +104,74 @@ impl Default for Dmd {
 }
 
 impl Dmd {
+    /// Create a Dmd running the compiled-in firmware.
+    #[cfg(feature = "embedded-rom")]
+    pub fn new() -> Dmd {
+        let mut dmd = Dmd::new_without_rom();
+        dmd.set_rom(Rom::from_halves(&LO_ROM, &HI_ROM).unwrap());
+        dmd
+    }
+
+    /// Without compiled-in firmware, a ROM must be supplied before
+    /// the Dmd can be reset.
+    #[cfg(not(feature = "embedded-rom"))]
     pub fn new() -> Dmd {
+        Dmd::new_without_rom()
+    }
+
+    /// Create a Dmd with no firmware. A ROM must be supplied with
+    /// `set_rom`, `load_rom` or `load_rom_file` before it is reset.
+    pub fn new_without_rom() -> Dmd {
         let cpu = Cpu::new();
         let bus = Bus::new(0x100000);
         Dmd {
             cpu,
             bus,
+            rom: None,
+            breakpoints: Vec::new(),
+            step_limit_pc: None,
+            nvram_steps: 0,
+            nvram_error: None,
+            debug_access: false,
+            symbols: Arc::new(SymbolTable::new()),
+            keymap: Keymap::new(),
         }
     }
 
     pub fn reset(&mut self) -> Result<(), BusError> {
-        self.bus.load(0, &LO_ROM)?;
-        self.bus.load(0x10000, &HI_ROM)?;
+        match &self.rom {
+            Some(rom) => self.bus.load(0, rom.as_slice())?,
+            None => return Err(BusError::Init),
+        }
         self.cpu.reset(&mut self.bus)?;
 
         Ok(())
     }
 
+    /// Replace the firmware. The new ROM is loaded on the next reset.
+    /// The symbols bundled for a known release are added to the
+    /// symbol table.
+    pub fn set_rom(&mut self, rom: Rom) -> &RomInfo {
+        if let Some(Ok(symbols)) = rom.info().symbols.map(SymbolTable::parse) {
+            self.add_symbols(&symbols);
+        }
+        self.rom.insert(rom).info()
+    }
+
+    /// Replace the firmware with a complete ROM image.
+    pub fn load_rom(&mut self, image: &[u8]) -> Result<&RomInfo, RomError> {
+        Ok(self.set_rom(Rom::new(image)?))
+    }
+
+    /// Replace the firmware with a complete ROM image read from a file.
+    pub fn load_rom_file<P: AsRef<Path>>(&mut self, path: P) -> Result<&RomInfo, RomError> {
+        Ok(self.set_rom(Rom::from_file(path)?))
+    }
+
+    pub fn rom_info(&self) -> Option<&RomInfo> {
+        self.rom.as_ref().map(|rom| rom.info())
+    }
+
     pub fn video_ram(&self) -> &[u8] {
         self.bus.video_ram()
     }
@@ -68,28 +192,318 @@ impl Dmd {
         self.cpu.r[(reg & 0xf) as usize]
     }
 
+    pub fn set_register(&mut self, reg: u8, val: u32) {
+        self.cpu.r[(reg & 0xf) as usize] = val;
+    }
+
+    pub fn set_psw(&mut self, val: u32) {
+        self.cpu.set_psw(val);
+    }
+
+    /// In debug access mode, memory reads have no side effects: I/O
+    /// registers can be inspected without, for example, clearing the
+    /// DUART's receiver status, and watchpoints are not triggered.
+    /// Writes are unaffected.
+    pub fn set_debug_access(&mut self, enabled: bool) {
+        self.debug_access = enabled;
+    }
+
+    pub fn debug_access(&self) -> bool {
+        self.debug_access
+    }
+
     pub fn read_word(&mut self, addr: usize) -> Option<u32> {
-        match self.bus.read_word(addr, AccessCode::AddressFetch) {
-            Ok(d) => Some(d),
-            _ => None,
+        if self.debug_access {
+            self.bus.peek_word(addr).ok()
+        } else {
+            self.bus.read_word(addr, AccessCode::AddressFetch).ok()
+        }
+    }
+
+    pub fn read_half(&mut self, addr: usize) -> Option<u16> {
+        if self.debug_access {
+            self.bus.peek_half(addr).ok()
+        } else {
+            self.bus.read_half(addr, AccessCode::AddressFetch).ok()
         }
     }
 
     pub fn read_byte(&mut self, addr: usize) -> Option<u8> {
-        match self.bus.read_byte(addr, AccessCode::AddressFetch) {
-            Ok(d) => Some(d),
-            _ => None,
+        if self.debug_access {
+            self.bus.peek_byte(addr).ok()
+        } else {
+            self.bus.read_byte(addr, AccessCode::AddressFetch).ok()
+        }
+    }
+
+    /// Fill `buf` with the bytes starting at `addr`.
+    pub fn read_memory(&mut self, addr: usize, buf: &mut [u8]) -> Result<(), BusError> {
+        if self.debug_access {
+            return self.peek_memory(addr, buf);
+        }
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = self.bus.read_byte(addr + i, AccessCode::AddressFetch)?;
+        }
+        Ok(())
+    }
+
+    /// Fill `buf` with the bytes starting at `addr`, without side
+    /// effects, whatever the debug access setting.
+    pub fn peek_memory(&self, addr: usize, buf: &mut [u8]) -> Result<(), BusError> {
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = self.bus.peek_byte(addr + i)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_word(&mut self, addr: usize, val: u32) -> Result<(), BusError> {
+        self.bus.write_word(addr, val)
+    }
+
+    pub fn write_half(&mut self, addr: usize, val: u16) -> Result<(), BusError> {
+        self.bus.write_half(addr, val)
+    }
+
+    pub fn write_byte(&mut self, addr: usize, val: u8) -> Result<(), BusError> {
+        self.bus.write_byte(addr, val)
+    }
+
+    /// Write `data` to memory starting at `addr`. Writing stops at
+    /// the first byte that can't be written.
+    pub fn write_memory(&mut self, addr: usize, data: &[u8]) -> Result<(), BusError> {
+        for (i, b) in data.iter().enumerate() {
+            self.bus.write_byte(addr + i, *b)?;
+        }
+        Ok(())
+    }
+
+    /// Load a WE32000 COFF executable straight into RAM, without the
+    /// firmware's download protocol. The `.text` and `.data` sections
+    /// are copied, `.bss` is cleared, and the program's symbols are
+    /// added to the symbol table. If `start` is true the PC is set to
+    /// the entry point; the stack and PSW are left as they are.
+    pub fn load_coff(&mut self, data: &[u8], start: bool) -> Result<LoadedProgram, CoffError> {
+        let coff = Coff::parse(data)?;
+        let entry = coff.optional_header.as_ref().ok_or(CoffError::NotExecutable)?.entry;
+
+        // Check every section before anything is written
+        let ram = self.bus.ram_range().clone();
+        let sections: Vec<_> = coff.sections.iter().filter(|s| s.is_loaded()).collect();
+        for section in &sections {
+            let start = section.address as usize;
+            if start < ram.start || start + section.size as usize > ram.end {
+                return Err(CoffError::Address(section.address));
+            }
+        }
+
+        for section in sections {
+            let mut contents = section.data.clone();
+            contents.resize(section.size as usize, 0);
+            self.bus
+                .load(section.address as usize, &contents)
+                .map_err(|_| CoffError::Address(section.address))?;
+        }
+
+        let symbols = SymbolTable::from_coff_symbols(&coff.symbols);
+        self.add_symbols(&symbols);
+
+        if start {
+            self.set_register(15, entry);
         }
+
+        Ok(LoadedProgram { entry, symbols })
+    }
+
+    /// Disassemble `count` instructions starting at `addr`. The CPU
+    /// and devices are left untouched.
+    pub fn disassemble(&self, addr: u32, count: usize) -> Vec<Line> {
+        disasm::disassemble_range(&self.bus, addr, count, Some(&self.symbols))
+    }
+
+    /// Symbols used by the disassembler, trace and debugger.
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    /// Add symbols, replacing any already at the same addresses.
+    pub fn add_symbols(&mut self, symbols: &SymbolTable) {
+        Arc::make_mut(&mut self.symbols).extend(symbols);
+        self.cpu.tracer_mut().set_symbols(Some(self.symbols.clone()));
+    }
+
+    /// Add the symbols in a COFF file or a text symbol table file.
+    /// Returns the number of symbols read.
+    pub fn load_symbols_file<P: AsRef<Path>>(&mut self, path: P) -> Result<usize, SymbolError> {
+        let symbols = SymbolTable::from_file(path)?;
+        self.add_symbols(&symbols);
+        Ok(symbols.len())
+    }
+
+    pub fn clear_symbols(&mut self) {
+        self.symbols = Arc::new(SymbolTable::new());
+        self.cpu.tracer_mut().set_symbols(None);
+    }
+
+    /// Render `addr` as `symbol+offset`, or in hex if no symbol
+    /// resolves it.
+    pub fn symbolize(&self, addr: u32) -> String {
+        self.symbols.symbolize(addr).unwrap_or_else(|| format!("0x{:x}", addr))
+    }
+
+    pub fn symbol_address(&self, name: &str) -> Option<u32> {
+        self.symbols.address_of(name)
+    }
+
+    /// Trace execution to `writer`, replacing any previous trace
+    /// output, and enable tracing.
+    pub fn trace_to<W: Write + Send + 'static>(&mut self, writer: W) {
+        let tracer = self.cpu.tracer_mut();
+        tracer.set_writer(writer);
+        tracer.set_enabled(true);
+    }
+
+    /// Trace execution to a file, which is created or truncated.
+    pub fn trace_to_file<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        self.trace_to(BufWriter::new(file));
+        Ok(())
+    }
+
+    /// Stop tracing and close the trace output.
+    pub fn stop_trace(&mut self) {
+        self.cpu.tracer_mut().close();
+    }
+
+    /// Pause or resume tracing without closing the trace output.
+    pub fn set_trace_enabled(&mut self, enabled: bool) {
+        self.cpu.tracer_mut().set_enabled(enabled);
+    }
+
+    pub fn set_trace_filter(&mut self, filter: TraceFilter) {
+        self.cpu.tracer_mut().set_filter(filter);
+    }
+
+    /// Start or stop counting executed instructions. Counts are kept
+    /// until cleared.
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.cpu.profiler_mut().set_enabled(enabled);
+    }
+
+    pub fn profiler(&self) -> &Profiler {
+        self.cpu.profiler()
+    }
+
+    pub fn clear_profile(&mut self) {
+        self.cpu.profiler_mut().clear();
+    }
+
+    /// Write the `top` hottest addresses and the opcode histogram.
+    pub fn write_profile_report<W: Write>(&self, writer: &mut W, top: usize) -> io::Result<()> {
+        self.cpu.profiler().write_report(writer, Some(&self.symbols), top)
+    }
+
+    /// Write the profile as folded call stacks, for flamegraph tools.
+    pub fn write_folded_stacks<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.cpu.profiler().write_folded(writer, Some(&self.symbols))
     }
 
     pub fn step(&mut self) {
+        self.step_limit_pc = None;
         self.cpu.step(&mut self.bus);
+        self.nvram_steps += 1;
+        if self.nvram_steps >= NVRAM_FLUSH_STEPS {
+            self.sync_nvram();
+        }
     }
 
     pub fn run(&mut self, count: usize) {
+        self.step_limit_pc = None;
         for _ in 0..count {
             self.cpu.step(&mut self.bus);
         }
+        self.sync_nvram();
+    }
+
+    /// Add a breakpoint, replacing any other at the same address.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.remove_breakpoint(breakpoint.address);
+        self.breakpoints.push(breakpoint);
+    }
+
+    /// Remove the breakpoint at `address`. Returns false if there was
+    /// none.
+    pub fn remove_breakpoint(&mut self, address: u32) -> bool {
+        let count = self.breakpoints.len();
+        self.breakpoints.retain(|b| b.address != address);
+        self.breakpoints.len() != count
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    pub fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        self.bus.add_watchpoint(watchpoint);
+    }
+
+    /// Remove the watchpoints on a range of addresses. Returns false
+    /// if there were none.
+    pub fn remove_watchpoint(&mut self, addresses: &Range<u32>) -> bool {
+        self.bus.remove_watchpoint(addresses)
+    }
+
+    pub fn clear_watchpoints(&mut self) {
+        self.bus.clear_watchpoints();
+    }
+
+    pub fn watchpoints(&self) -> &[Watchpoint] {
+        self.bus.watchpoints()
+    }
+
+    /// Run for at most `max_steps` steps, stopping early before an
+    /// instruction with a breakpoint, or after one that accessed a
+    /// watched address. A breakpoint at the starting PC is ignored,
+    /// so that a stopped run can be resumed, unless the previous run
+    /// ran out of steps there: a long run split into chunks still
+    /// stops at a breakpoint on a chunk boundary.
+    pub fn run_until_break(&mut self, max_steps: usize) -> StopReason {
+        // Discard accesses made by the host, e.g. through read_word
+        self.bus.take_watch_hit();
+
+        let mut reason = StopReason::StepLimit;
+        let continuing = self.step_limit_pc.take() == Some(self.cpu.get_pc());
+
+        for i in 0..max_steps {
+            if (i > 0 || continuing) && self.breakpoints.iter().any(|b| b.is_hit(&self.cpu.r)) {
+                reason = StopReason::Breakpoint(self.cpu.get_pc());
+                break;
+            }
+
+            self.cpu.step(&mut self.bus);
+
+            if let Some(hit) = self.bus.take_watch_hit() {
+                reason = StopReason::Watchpoint(hit);
+                break;
+            }
+        }
+
+        if reason == StopReason::StepLimit {
+            self.step_limit_pc = Some(self.cpu.get_pc());
+        }
+
+        self.sync_nvram();
+        reason
+    }
+
+    /// The CALL/SAVE frames from the current FP and AP, innermost
+    /// first, without side effects. Each frame has its PC, or return
+    /// address, symbolized when the symbol table resolves it.
+    pub fn backtrace(&self) -> Vec<Frame> {
+        debug::backtrace(&self.bus, &self.cpu.r, &self.symbols, BACKTRACE_DEPTH)
     }
 
     pub fn rs232_tx_poll(&mut self) -> Option<u8> {
@@ -100,14 +514,109 @@ impl Dmd {
         self.bus.kb_tx_poll()
     }
 
+    /// True while the terminal is sending a BREAK on the RS-232 port.
+    pub fn rs232_tx_break(&self) -> bool {
+        self.bus.rs232_tx_break()
+    }
+
+    /// Take the next byte sent to the printer port.
+    pub fn printer_poll(&mut self) -> Option<u8> {
+        self.bus.printer_poll()
+    }
+
+    /// Take the next change in the keyboard's state.
+    pub fn keyboard_poll(&mut self) -> Option<KeyboardEvent> {
+        self.bus.keyboard_mut().poll()
+    }
+
+    pub fn keyboard(&self) -> &Keyboard {
+        self.bus.keyboard()
+    }
+
+    /// Set the byte the keyboard sends in reply to status requests.
+    pub fn set_keyboard_status(&mut self, status: u8) {
+        self.bus.keyboard_mut().set_status(status);
+    }
+
     pub fn rx_char(&mut self, character: u8) {
         self.bus.rx_char(character);
     }
 
+    /// Send a BREAK to the RS-232 port.
+    pub fn rx_break(&mut self) {
+        self.bus.rx_break();
+    }
+
+    /// Send a character with a parity or framing error to the RS-232
+    /// port.
+    pub fn rx_char_error(&mut self, character: u8, error: LineError) {
+        self.bus.rx_char_error(character, error);
+    }
+
+    /// Model the RS-232 port's 3 character receive FIFO, which
+    /// overruns if the host sends faster than the firmware reads.
+    pub fn set_rx_fifo(&mut self, enabled: bool) {
+        self.bus.set_rx_fifo(enabled);
+    }
+
+    /// Honor XON and XOFF sent by the terminal in `rx_space`.
+    pub fn set_xon_xoff(&mut self, enabled: bool) {
+        self.bus.set_xon_xoff(enabled);
+    }
+
+    /// The number of characters the RS-232 port can take without
+    /// losing any.
+    pub fn rx_space(&self) -> usize {
+        self.bus.rx_space()
+    }
+
     pub fn rx_keyboard(&mut self, keycode: u8) {
         self.bus.rx_keyboard(keycode);
     }
 
+    /// Return true if a modem control line on the RS-232 port is
+    /// asserted.
+    pub fn modem_line(&self, line: ModemLine) -> bool {
+        self.bus.modem_line(line)
+    }
+
+    /// Assert or negate CTS, DCD or DSR on the RS-232 port.
+    pub fn set_modem_line(&mut self, line: ModemLine, asserted: bool) {
+        self.bus.set_modem_line(line, asserted);
+    }
+
+    /// Take the next change of DTR or RTS on the RS-232 port.
+    pub fn modem_poll(&mut self) -> Option<(ModemLine, bool)> {
+        self.bus.modem_poll()
+    }
+
+    /// Send the codes for `key`, with the keyboard::MOD_* bits in
+    /// `modifiers`, from the keyboard. Returns false if the keymap
+    /// has no mapping for the key.
+    pub fn key_press(&mut self, key: Key, modifiers: u8) -> bool {
+        match self.keymap.translate(key, modifiers) {
+            Some(codes) => {
+                for c in codes {
+                    self.bus.rx_keyboard(c);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    pub fn keymap_mut(&mut self) -> &mut Keymap {
+        &mut self.keymap
+    }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) {
+        self.keymap = keymap;
+    }
+
     pub fn mouse_move(&mut self, x: u16, y: u16) {
         self.bus.mouse_move(x, y);
     }
@@ -131,6 +640,51 @@ impl Dmd {
     pub fn get_nvram(&self) -> &[u8] {
         self.bus.get_nvram()
     }
+
+    /// Decode the terminal settings held in NVRAM.
+    pub fn nvram_settings(&self) -> Result<NvramSettings, NvramError> {
+        NvramSettings::decode(self.bus.get_nvram())
+    }
+
+    /// Store terminal settings in NVRAM. The firmware picks them up
+    /// on the next reset.
+    pub fn set_nvram_settings(&mut self, settings: &NvramSettings) -> Result<(), NvramError> {
+        let mut image = self.bus.get_nvram().to_vec();
+        settings.encode(&mut image)?;
+        self.bus.set_nvram(&image);
+        Ok(())
+    }
+
+    /// Persist the NVRAM in a file. An existing file is loaded into
+    /// the NVRAM, otherwise a new one is created. From then on, once
+    /// the firmware has changed the NVRAM, the file is rewritten at
+    /// the end of each run, periodically while single stepping, and
+    /// when the Dmd is dropped.
+    pub fn bind_nvram<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        self.bus.bind_nvram(path.as_ref())
+    }
+
+    /// Stop persisting the NVRAM, writing out any pending changes.
+    pub fn unbind_nvram(&mut self) -> io::Result<()> {
+        self.bus.unbind_nvram()
+    }
+
+    /// Write any pending NVRAM changes to the bound file.
+    pub fn flush_nvram(&mut self) -> io::Result<()> {
+        self.bus.flush_nvram()
+    }
+
+    /// The error from the last automatic write of the NVRAM file,
+    /// if it failed. The write is retried until it succeeds.
+    pub fn nvram_error(&self) -> Option<&io::Error> {
+        self.nvram_error.as_ref()
+    }
+
+    // Errors are not fatal to execution, but are kept for the host.
+    fn sync_nvram(&mut self) {
+        self.nvram_steps = 0;
+        self.nvram_error = self.bus.flush_nvram().err();
+    }
 }
 
 //
@@ -204,6 +758,28 @@ fn dmd_get_register(reg: u8, val: &mut u32) -> c_int {
     }
 }
 
+#[no_mangle]
+fn dmd_set_register(reg: u8, val: u32) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_register(reg, val);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_set_psw(psw: u32) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_psw(psw);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
 #[no_mangle]
 fn dmd_read_word(addr: u32, val: &mut u32) -> c_int {
     match DMD.lock() {
@@ -237,80 +813,401 @@ fn dmd_read_byte(addr: u32, val: &mut u8) -> c_int {
 }
 
 #[no_mangle]
-fn dmd_get_duart_output_port(oport: &mut u8) -> c_int {
+fn dmd_read_half(addr: u32, val: &mut u16) -> c_int {
     match DMD.lock() {
-        Ok(dmd) => {
-            *oport = dmd.duart_output();
-            SUCCESS
-        }
+        Ok(mut dmd) => {
+            match dmd.read_half(addr as usize) {
+                Some(half) => {
+                    *val = half;
+                    SUCCESS
+                },
+                None => ERROR
+            }
+        },
         Err(_) => ERROR
     }
 }
 
 #[no_mangle]
-fn dmd_rx_char(c: u8) -> c_int {
+fn dmd_read_memory(addr: u32, buf: *mut u8, len: size_t) -> c_int {
+    if buf.is_null() {
+        return ERROR;
+    }
+
+    let buf = unsafe { std::slice::from_raw_parts_mut(buf, len) };
+
     match DMD.lock() {
         Ok(mut dmd) => {
-            dmd.rx_char(c as u8);
-            SUCCESS
+            match dmd.read_memory(addr as usize, buf) {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
         }
         Err(_) => ERROR
     }
 }
 
 #[no_mangle]
-fn dmd_rx_keyboard(c: u8) -> c_int {
+fn dmd_write_word(addr: u32, val: u32) -> c_int {
     match DMD.lock() {
         Ok(mut dmd) => {
-            dmd.rx_keyboard(c);
-            SUCCESS
+            match dmd.write_word(addr as usize, val) {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
         }
         Err(_) => ERROR
     }
 }
 
 #[no_mangle]
-fn dmd_mouse_move(x: u16, y: u16) -> c_int {
+fn dmd_write_half(addr: u32, val: u16) -> c_int {
     match DMD.lock() {
         Ok(mut dmd) => {
-            dmd.mouse_move(x, y);
-            SUCCESS
+            match dmd.write_half(addr as usize, val) {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
         }
         Err(_) => ERROR
     }
 }
 
 #[no_mangle]
-fn dmd_mouse_down(button: u8) -> c_int {
+fn dmd_write_byte(addr: u32, val: u8) -> c_int {
     match DMD.lock() {
         Ok(mut dmd) => {
-            dmd.mouse_down(button);
-            SUCCESS
+            match dmd.write_byte(addr as usize, val) {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
         }
         Err(_) => ERROR
     }
 }
 
 #[no_mangle]
-fn dmd_mouse_up(button: u8) -> c_int {
+fn dmd_write_memory(addr: u32, data: *const u8, len: size_t) -> c_int {
+    if data.is_null() {
+        return ERROR;
+    }
+
+    let data = unsafe { std::slice::from_raw_parts(data, len) };
+
     match DMD.lock() {
         Ok(mut dmd) => {
-            dmd.mouse_up(button);
-            SUCCESS
+            match dmd.write_memory(addr as usize, data) {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
         }
         Err(_) => ERROR
     }
 }
 
 #[no_mangle]
-fn dmd_rs232_tx_poll(tx_char: &mut u8) -> c_int {
+fn dmd_set_debug_access(enable: c_int) -> c_int {
     match DMD.lock() {
         Ok(mut dmd) => {
-            match dmd.rs232_tx_poll() {
-                Some(c) => {
-                    *tx_char = c;
-                    SUCCESS
-                }
+            dmd.set_debug_access(enable != 0);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_get_duart_output_port(oport: &mut u8) -> c_int {
+    match DMD.lock() {
+        Ok(dmd) => {
+            *oport = dmd.duart_output();
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rx_char(c: u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            if dmd.rx_space() == 0 {
+                return BUSY;
+            }
+            dmd.rx_char(c);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rx_space(space: &mut u32) -> c_int {
+    match DMD.lock() {
+        Ok(dmd) => {
+            *space = dmd.rx_space().min(u32::MAX as usize) as u32;
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_set_rx_fifo(enable: c_int) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_rx_fifo(enable != 0);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_set_xon_xoff(enable: c_int) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_xon_xoff(enable != 0);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rx_keyboard(c: u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.rx_keyboard(c);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rx_break() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.rx_break();
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rx_char_error(c: u8, error: c_int) -> c_int {
+    let error = match error {
+        LINE_PARITY => LineError::Parity,
+        LINE_FRAMING => LineError::Framing,
+        _ => return ERROR,
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.rx_char_error(c, error);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+fn modem_line(line: c_int) -> Option<ModemLine> {
+    match line {
+        MODEM_DTR => Some(ModemLine::Dtr),
+        MODEM_RTS => Some(ModemLine::Rts),
+        MODEM_CTS => Some(ModemLine::Cts),
+        MODEM_DCD => Some(ModemLine::Dcd),
+        MODEM_DSR => Some(ModemLine::Dsr),
+        _ => None,
+    }
+}
+
+#[no_mangle]
+fn dmd_modem_line(line: c_int, asserted: &mut u8) -> c_int {
+    let line = match modem_line(line) {
+        Some(line) => line,
+        None => return ERROR,
+    };
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            *asserted = dmd.modem_line(line) as u8;
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_set_modem_line(line: c_int, asserted: u8) -> c_int {
+    let line = match modem_line(line) {
+        Some(line @ ModemLine::Cts) | Some(line @ ModemLine::Dcd) | Some(line @ ModemLine::Dsr) => line,
+        _ => return ERROR,
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_modem_line(line, asserted != 0);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_modem_poll(line: &mut c_int, asserted: &mut u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.modem_poll() {
+                Some((changed, state)) => {
+                    *line = match changed {
+                        ModemLine::Dtr => MODEM_DTR,
+                        ModemLine::Rts => MODEM_RTS,
+                        ModemLine::Cts => MODEM_CTS,
+                        ModemLine::Dcd => MODEM_DCD,
+                        ModemLine::Dsr => MODEM_DSR,
+                    };
+                    *asserted = state as u8;
+                    SUCCESS
+                }
+                None => BUSY
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_key(key: u32, modifiers: u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match Key::from_id(key) {
+                Some(key) if dmd.key_press(key, modifiers) => SUCCESS,
+                _ => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_bind_key(key: u32, plain: u8, shifted: u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match Key::from_id(key) {
+                Some(key) => {
+                    dmd.keymap_mut().bind(key, &[plain], &[shifted]);
+                    SUCCESS
+                }
+                None => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_select_keymap(keymap: c_int) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match keymap {
+                KEYMAP_5620 => dmd.set_keymap(Keymap::new()),
+                KEYMAP_ASCII => dmd.set_keymap(Keymap::empty()),
+                _ => return ERROR
+            }
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_mouse_move(x: u16, y: u16) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.mouse_move(x, y);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_mouse_down(button: u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.mouse_down(button);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_mouse_up(button: u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.mouse_up(button);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rs232_tx_poll(tx_char: &mut u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.rs232_tx_poll() {
+                Some(c) => {
+                    *tx_char = c;
+                    SUCCESS
+                }
+                None => BUSY
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rs232_tx_break(sending: &mut u8) -> c_int {
+    match DMD.lock() {
+        Ok(dmd) => {
+            *sending = dmd.rs232_tx_break() as u8;
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_kb_tx_poll(tx_char: &mut u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.kb_tx_poll() {
+                Some(c) => {
+                    *tx_char = c;
+                    SUCCESS
+                }
+                None => BUSY
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_printer_poll(tx_char: &mut u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.printer_poll() {
+                Some(c) => {
+                    *tx_char = c;
+                    SUCCESS
+                }
                 None => BUSY
             }
         }
@@ -319,37 +1216,920 @@ fn dmd_rs232_tx_poll(tx_char: &mut u8) -> c_int {
 }
 
 #[no_mangle]
-fn dmd_kb_tx_poll(tx_char: &mut u8) -> c_int {
+fn dmd_keyboard_poll(event: &mut c_int, value: &mut u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.keyboard_poll() {
+                Some(KeyboardEvent::Alarm) => {
+                    *event = KEYBOARD_ALARM;
+                    *value = 0;
+                }
+                Some(KeyboardEvent::Click(on)) => {
+                    *event = KEYBOARD_CLICK;
+                    *value = on as u8;
+                }
+                Some(KeyboardEvent::Lights(lights)) => {
+                    *event = KEYBOARD_LIGHTS;
+                    *value = lights;
+                }
+                None => return BUSY
+            }
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_set_keyboard_status(status: u8) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_keyboard_status(status);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_set_nvram(nvram: &[u8; 8192]) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_nvram(nvram);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_get_nvram(nvram: &mut [u8; 8192]) -> c_int {
+    match DMD.lock() {
+        Ok(dmd) => {
+            nvram.clone_from_slice(dmd.get_nvram());
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Disassemble the instruction at `addr` into `text` as a NUL
+/// terminated line, truncated to fit, and return the address of the
+/// following instruction in `next_addr`.
+#[no_mangle]
+fn dmd_disassemble(addr: u32, text: *mut c_char, len: size_t, next_addr: &mut u32) -> c_int {
+    if text.is_null() || len == 0 {
+        return ERROR;
+    }
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            let line = dmd.disassemble(addr, 1).remove(0);
+            let rendered = line.to_string();
+            let count = rendered.len().min(len - 1);
+            unsafe {
+                ptr::copy_nonoverlapping(rendered.as_ptr() as *const c_char, text, count);
+                *text.add(count) = 0;
+            }
+            *next_addr = addr.wrapping_add(line.bytes.len().max(1) as u32);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Add the symbols in a COFF or text symbol table file.
+#[no_mangle]
+fn dmd_load_symbols(path: *const c_char) -> c_int {
+    if path.is_null() {
+        return ERROR;
+    }
+
+    let path = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(p) => p,
+        Err(_) => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.load_symbols_file(path) {
+                Ok(_) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_clear_symbols() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.clear_symbols();
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Write `addr` as a NUL terminated `symbol+offset` string, truncated
+/// to fit.
+#[no_mangle]
+fn dmd_symbolize(addr: u32, text: *mut c_char, len: size_t) -> c_int {
+    if text.is_null() || len == 0 {
+        return ERROR;
+    }
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            let rendered = dmd.symbolize(addr);
+            let count = rendered.len().min(len - 1);
+            unsafe {
+                ptr::copy_nonoverlapping(rendered.as_ptr() as *const c_char, text, count);
+                *text.add(count) = 0;
+            }
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_symbol_address(name: *const c_char, addr: &mut u32) -> c_int {
+    if name.is_null() {
+        return ERROR;
+    }
+
+    let name = match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(n) => n,
+        Err(_) => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            match dmd.symbol_address(name) {
+                Some(a) => {
+                    *addr = a;
+                    SUCCESS
+                }
+                None => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Load a COFF executable into RAM and return its entry point in
+/// `entry`. If `start` is non-zero, execution continues there.
+#[no_mangle]
+fn dmd_load_coff(data: *const u8, len: size_t, start: c_int, entry: &mut u32) -> c_int {
+    if data.is_null() {
+        return ERROR;
+    }
+
+    let data = unsafe { std::slice::from_raw_parts(data, len) };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.load_coff(data, start != 0) {
+                Ok(program) => {
+                    *entry = program.entry;
+                    SUCCESS
+                }
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_load_rom(rom: *const u8, len: size_t) -> c_int {
+    if rom.is_null() {
+        return ERROR;
+    }
+
+    let image = unsafe { std::slice::from_raw_parts(rom, len) };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.load_rom(image) {
+                Ok(_) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_load_rom_file(path: *const c_char) -> c_int {
+    if path.is_null() {
+        return ERROR;
+    }
+
+    let path = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(p) => p,
+        Err(_) => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.load_rom_file(path) {
+                Ok(_) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_rom_crc32(crc: &mut u32) -> c_int {
+    match DMD.lock() {
+        Ok(dmd) => {
+            match dmd.rom_info() {
+                Some(info) => {
+                    *crc = info.crc32;
+                    SUCCESS
+                }
+                None => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Write the identified firmware release, e.g. "8;7;5 (Dec 17, 1984)",
+/// as a NUL terminated string, truncated to fit. Returns ERROR if no
+/// ROM is loaded or it is not a known release.
+#[no_mangle]
+fn dmd_rom_release(text: *mut c_char, len: size_t) -> c_int {
+    if text.is_null() || len == 0 {
+        return ERROR;
+    }
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            match dmd.rom_info().and_then(|info| info.release) {
+                Some(release) => {
+                    let count = release.len().min(len - 1);
+                    unsafe {
+                        ptr::copy_nonoverlapping(release.as_ptr() as *const c_char, text, count);
+                        *text.add(count) = 0;
+                    }
+                    SUCCESS
+                }
+                None => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Write the version in the firmware's terminal ID response, e.g.
+/// "8;7;5", as a NUL terminated string, truncated to fit. Returns
+/// ERROR if no ROM is loaded or no version was found in it.
+#[no_mangle]
+fn dmd_rom_version(text: *mut c_char, len: size_t) -> c_int {
+    if text.is_null() || len == 0 {
+        return ERROR;
+    }
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            match dmd.rom_info().and_then(|info| info.version.as_ref()) {
+                Some(version) => {
+                    let count = version.len().min(len - 1);
+                    unsafe {
+                        ptr::copy_nonoverlapping(version.as_ptr() as *const c_char, text, count);
+                        *text.add(count) = 0;
+                    }
+                    SUCCESS
+                }
+                None => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_bind_nvram(path: *const c_char) -> c_int {
+    if path.is_null() {
+        return ERROR;
+    }
+
+    let path = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(p) => p,
+        Err(_) => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.bind_nvram(path) {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_unbind_nvram() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.unbind_nvram() {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_flush_nvram() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.flush_nvram() {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Returns ERROR if the last automatic write of the NVRAM file failed.
+#[no_mangle]
+fn dmd_nvram_status() -> c_int {
+    match DMD.lock() {
+        Ok(dmd) => {
+            match dmd.nvram_error() {
+                None => SUCCESS,
+                Some(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_trace_file(path: *const c_char) -> c_int {
+    if path.is_null() {
+        return ERROR;
+    }
+
+    let path = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(p) => p,
+        Err(_) => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.trace_to_file(path) {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_trace_stop() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.stop_trace();
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_trace_enable(enable: c_int) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_trace_enabled(enable != 0);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Set the trace filter. Only addresses in `start..end` are traced,
+/// unless the range is empty. `levels` is a mask of privilege levels
+/// to trace, by their PSW encoding (bit 0 kernel, bit 1 executive,
+/// bit 2 supervisor, bit 3 user), with 0 tracing all levels.
+/// `opcodes` lists the opcodes to trace, with none tracing all.
+#[no_mangle]
+fn dmd_trace_filter(start: u32, end: u32, levels: u8, opcodes: *const u16, count: size_t) -> c_int {
+    if opcodes.is_null() && count > 0 {
+        return ERROR;
+    }
+
+    let opcodes = if count > 0 {
+        unsafe { std::slice::from_raw_parts(opcodes, count) }.to_vec()
+    } else {
+        vec![]
+    };
+
+    let filter = TraceFilter {
+        addresses: if start < end { Some(start..end) } else { None },
+        levels: [CpuLevel::Kernel, CpuLevel::Executive, CpuLevel::Supervisor, CpuLevel::User]
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| levels & (1 << i) != 0)
+            .map(|(_, l)| *l)
+            .collect(),
+        opcodes,
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_trace_filter(filter);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_profile_enable(enabled: c_int) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.set_profiling(enabled != 0);
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_profile_clear() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.clear_profile();
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Write a profile report of the `top` hottest addresses to a file.
+#[no_mangle]
+fn dmd_profile_report(path: *const c_char, top: size_t) -> c_int {
+    if path.is_null() {
+        return ERROR;
+    }
+
+    let path = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(p) => p,
+        Err(_) => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            let written = File::create(path).and_then(|f| {
+                let mut w = BufWriter::new(f);
+                dmd.write_profile_report(&mut w, top)?;
+                w.flush()
+            });
+            match written {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Write the profile to a file as folded call stacks.
+#[no_mangle]
+fn dmd_profile_folded(path: *const c_char) -> c_int {
+    if path.is_null() {
+        return ERROR;
+    }
+
+    let path = match unsafe { CStr::from_ptr(path) }.to_str() {
+        Ok(p) => p,
+        Err(_) => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(dmd) => {
+            let written = File::create(path).and_then(|f| {
+                let mut w = BufWriter::new(f);
+                dmd.write_folded_stacks(&mut w)?;
+                w.flush()
+            });
+            match written {
+                Ok(()) => SUCCESS,
+                Err(_) => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_add_breakpoint(addr: u32) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.add_breakpoint(Breakpoint::new(addr));
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Add a breakpoint that only stops when register `reg` compares
+/// with `value` as given by `comparison`: 0 for equal, 1 not equal,
+/// 2 less, 3 less or equal, 4 greater, 5 greater or equal.
+#[no_mangle]
+fn dmd_add_conditional_breakpoint(addr: u32, reg: u8, comparison: c_int, value: u32) -> c_int {
+    let comparison = match comparison {
+        0 => Comparison::Eq,
+        1 => Comparison::Ne,
+        2 => Comparison::Lt,
+        3 => Comparison::Le,
+        4 => Comparison::Gt,
+        5 => Comparison::Ge,
+        _ => return ERROR
+    };
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.add_breakpoint(Breakpoint::when(addr, (reg & 0xf) as usize, comparison, value));
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_remove_breakpoint(addr: u32) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            if dmd.remove_breakpoint(addr) {
+                SUCCESS
+            } else {
+                ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_clear_breakpoints() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.clear_breakpoints();
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Watch `start..end` for reads (`kind` 0), writes (1) or any
+/// access (2).
+#[no_mangle]
+fn dmd_add_watchpoint(start: u32, end: u32, kind: c_int) -> c_int {
+    let kind = match kind {
+        0 => Watch::Read,
+        1 => Watch::Write,
+        2 => Watch::Access,
+        _ => return ERROR
+    };
+
+    if start >= end {
+        return ERROR;
+    }
+
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.add_watchpoint(Watchpoint {
+                addresses: start..end,
+                kind,
+            });
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_remove_watchpoint(start: u32, end: u32) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            if dmd.remove_watchpoint(&(start..end)) {
+                SUCCESS
+            } else {
+                ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[no_mangle]
+fn dmd_clear_watchpoints() -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            dmd.clear_watchpoints();
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Run for at most `max_steps` steps. On return, `reason` is 0 if
+/// the step limit was reached, 1 for a breakpoint, 2 for a read
+/// watchpoint or 3 for a write watchpoint, and `addr` holds the
+/// breakpoint or watched address.
+#[no_mangle]
+fn dmd_run_until_break(max_steps: usize, reason: &mut c_int, addr: &mut u32) -> c_int {
+    match DMD.lock() {
+        Ok(mut dmd) => {
+            match dmd.run_until_break(max_steps) {
+                StopReason::StepLimit => {
+                    *reason = STOP_STEP_LIMIT;
+                    *addr = dmd.get_pc();
+                }
+                StopReason::Breakpoint(pc) => {
+                    *reason = STOP_BREAKPOINT;
+                    *addr = pc;
+                }
+                StopReason::Watchpoint(hit) => {
+                    *reason = if hit.kind == Watch::Write { STOP_WRITE_WATCH } else { STOP_READ_WATCH };
+                    *addr = hit.address;
+                }
+            }
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Fill `pcs` with the PC and the return addresses of the frames on
+/// the stack, innermost first, and set `count` to the number of
+/// frames written.
+#[no_mangle]
+fn dmd_backtrace(pcs: *mut u32, len: size_t, count: &mut size_t) -> c_int {
+    if pcs.is_null() {
+        return ERROR;
+    }
+
     match DMD.lock() {
-        Ok(mut dmd) => {
-            match dmd.kb_tx_poll() {
-                Some(c) => {
-                    *tx_char = c;
+        Ok(dmd) => {
+            let frames = dmd.backtrace();
+            let n = frames.len().min(len);
+            let pcs = unsafe { std::slice::from_raw_parts_mut(pcs, n) };
+            for (pc, frame) in pcs.iter_mut().zip(frames.iter()) {
+                *pc = frame.pc;
+            }
+            *count = n;
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Wait for a GDB connection on the local TCP `port` and serve it
+/// until the debugger detaches. This blocks the calling thread, so
+/// it should be called from a thread of its own, but the emulator is
+/// only locked while requests are handled or the debugger has it
+/// running. The host should not step the emulator while a
+/// debugger is attached.
+#[no_mangle]
+fn dmd_gdb_serve(port: u16) -> c_int {
+    let server = match GdbServer::bind(("127.0.0.1", port)) {
+        Ok(s) => s,
+        Err(_) => return ERROR
+    };
+
+    let mut stub = match server.accept() {
+        Ok(s) => s,
+        Err(_) => return ERROR
+    };
+
+    loop {
+        match stub.receive() {
+            Ok(true) => {}
+            Ok(false) => return SUCCESS,
+            Err(_) => return ERROR
+        }
+
+        let result = match DMD.lock() {
+            Ok(mut dmd) => stub.process(&mut dmd, RUN_CHUNK),
+            Err(_) => return ERROR
+        };
+
+        match result {
+            Ok(true) => {}
+            Ok(false) => return SUCCESS,
+            Err(_) => return ERROR
+        }
+    }
+}
+
+/// Attach the RS-232 port to a new pseudo-terminal, replacing any
+/// attached before. If `shell` is not NULL, it is started on the
+/// pseudo-terminal.
+#[cfg(unix)]
+#[no_mangle]
+fn dmd_pty_open(shell: *const c_char) -> c_int {
+    let pty = if shell.is_null() {
+        Pty::open()
+    } else {
+        match unsafe { CStr::from_ptr(shell) }.to_str() {
+            Ok(shell) => Pty::spawn(shell, &[]),
+            Err(_) => return ERROR
+        }
+    };
+
+    match (pty, PTY.lock()) {
+        (Ok(pty), Ok(mut attached)) => {
+            *attached = Some(pty);
+            SUCCESS
+        }
+        _ => ERROR
+    }
+}
+
+/// Write the path of the pseudo-terminal's slave side as a NUL
+/// terminated string, truncated to fit.
+#[cfg(unix)]
+#[no_mangle]
+fn dmd_pty_name(text: *mut c_char, len: size_t) -> c_int {
+    if text.is_null() || len == 0 {
+        return ERROR;
+    }
+
+    match PTY.lock() {
+        Ok(attached) => {
+            match attached.as_ref() {
+                Some(pty) => {
+                    let name = pty.slave_name();
+                    let count = name.len().min(len - 1);
+                    unsafe {
+                        ptr::copy_nonoverlapping(name.as_ptr() as *const c_char, text, count);
+                        *text.add(count) = 0;
+                    }
                     SUCCESS
                 }
-                None => BUSY
+                None => ERROR
             }
         }
         Err(_) => ERROR
     }
 }
 
+#[cfg(unix)]
 #[no_mangle]
-fn dmd_set_nvram(nvram: &[u8; 8192]) -> c_int {
-    match DMD.lock() {
-        Ok(mut dmd) => {
-            dmd.set_nvram(nvram);
+fn dmd_pty_set_baud(baud: u32) -> c_int {
+    match PTY.lock() {
+        Ok(mut attached) => {
+            match attached.as_mut() {
+                Some(pty) => {
+                    pty.set_baud(baud);
+                    SUCCESS
+                }
+                None => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Move characters between the pseudo-terminal and the RS-232 port.
+/// `open` is set to 0, and the pseudo-terminal closed, once its shell
+/// has exited.
+#[cfg(unix)]
+#[no_mangle]
+fn dmd_pty_poll(open: &mut u8) -> c_int {
+    let mut attached = match PTY.lock() {
+        Ok(attached) => attached,
+        Err(_) => return ERROR
+    };
+
+    let result = match (attached.as_mut(), DMD.lock()) {
+        (Some(pty), Ok(mut dmd)) => pty.poll(&mut dmd),
+        _ => return ERROR
+    };
+
+    match result {
+        Ok(true) => {
+            *open = 1;
+            SUCCESS
+        }
+        Ok(false) => {
+            *attached = None;
+            *open = 0;
             SUCCESS
         }
         Err(_) => ERROR
     }
 }
 
+#[cfg(unix)]
 #[no_mangle]
-fn dmd_get_nvram(nvram: &mut [u8; 8192]) -> c_int {
-    match DMD.lock() {
-        Ok(dmd) => {
-            nvram.clone_from_slice(dmd.get_nvram());
+fn dmd_pty_close() -> c_int {
+    match PTY.lock() {
+        Ok(mut attached) => {
+            *attached = None;
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Attach the RS-232 port to a TCP connection to `host` and `port`,
+/// replacing any attached before.
+#[cfg(unix)]
+#[no_mangle]
+fn dmd_tcp_connect(host: *const c_char, port: u16, transport: c_int) -> c_int {
+    if host.is_null() {
+        return ERROR;
+    }
+
+    let host = match unsafe { CStr::from_ptr(host) }.to_str() {
+        Ok(h) => h,
+        Err(_) => return ERROR
+    };
+
+    let transport = match transport {
+        TRANSPORT_RAW => Transport::Raw,
+        TRANSPORT_TELNET => Transport::Telnet,
+        _ => return ERROR
+    };
+
+    match (TcpLine::connect((host, port), transport), TCP.lock()) {
+        (Ok(tcp), Ok(mut attached)) => {
+            *attached = Some(tcp);
+            SUCCESS
+        }
+        _ => ERROR
+    }
+}
+
+#[cfg(unix)]
+#[no_mangle]
+fn dmd_tcp_set_baud(baud: u32) -> c_int {
+    match TCP.lock() {
+        Ok(mut attached) => {
+            match attached.as_mut() {
+                Some(tcp) => {
+                    tcp.set_baud(baud);
+                    SUCCESS
+                }
+                None => ERROR
+            }
+        }
+        Err(_) => ERROR
+    }
+}
+
+/// Move characters between the TCP connection and the RS-232 port.
+/// `open` is set to 0, and the connection dropped, once the far end
+/// has closed it.
+#[cfg(unix)]
+#[no_mangle]
+fn dmd_tcp_poll(open: &mut u8) -> c_int {
+    let mut attached = match TCP.lock() {
+        Ok(attached) => attached,
+        Err(_) => return ERROR
+    };
+
+    let result = match (attached.as_mut(), DMD.lock()) {
+        (Some(tcp), Ok(mut dmd)) => tcp.poll(&mut dmd),
+        _ => return ERROR
+    };
+
+    match result {
+        Ok(true) => {
+            *open = 1;
+            SUCCESS
+        }
+        Ok(false) => {
+            *attached = None;
+            *open = 0;
+            SUCCESS
+        }
+        Err(_) => ERROR
+    }
+}
+
+#[cfg(unix)]
+#[no_mangle]
+fn dmd_tcp_close() -> c_int {
+    match TCP.lock() {
+        Ok(mut attached) => {
+            *attached = None;
             SUCCESS
         }
         Err(_) => ERROR
@@ -358,9 +2138,16 @@ fn dmd_get_nvram(nvram: &mut [u8; 8192]) -> c_int {
 
 #[cfg(test)]
 mod tests {
+    use crate::debug::{Breakpoint, Comparison, StopReason, Watch, WatchHit, Watchpoint};
     use crate::dmd::Dmd;
+    use crate::coff::{self, STYP_BSS, STYP_DATA, STYP_TEXT};
+    use crate::err::CoffError;
+    use crate::gdb::RUN_CHUNK;
+    use crate::rom::image_with_program;
+    use crate::symbols::SymbolTable;
 
     #[test]
+    #[cfg(feature = "embedded-rom")]
     fn creates_dmd() {
         let mut dmd = Dmd::new();
         dmd.reset().unwrap();
@@ -389,4 +2176,521 @@ mod tests {
         assert_eq!(0xa5, new_nvram[0xfff]);
         assert_eq!(0xff, new_nvram[0x1fff]);
     }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn persists_nvram_written_by_firmware() {
+        use std::env;
+        use std::fs;
+        use std::process;
+
+        let path = env::temp_dir().join(format!("dmd_core_nvram_{}.bin", process::id()));
+        let _ = fs::remove_file(&path);
+
+        {
+            let mut dmd = Dmd::new();
+            dmd.bind_nvram(&path).unwrap();
+            dmd.reset().unwrap();
+            dmd.run(1_000_000);
+        }
+
+        // On first boot the firmware initializes the setup options and
+        // their checksum.
+        let image = fs::read(&path).unwrap();
+        assert!(image.iter().any(|b| *b != 0));
+
+        let mut dmd = Dmd::new();
+        dmd.bind_nvram(&path).unwrap();
+        assert_eq!(&image[..], dmd.get_nvram());
+
+        dmd.unbind_nvram().unwrap();
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn batches_nvram_writes_while_stepping() {
+        use std::env;
+        use std::fs;
+        use std::process;
+
+        let dir = env::temp_dir().join(format!("dmd_core_nvram_dir_{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir(&dir).unwrap();
+        let path = dir.join("nvram.bin");
+
+        let mut dmd = dmd_with_counter_loop();
+        dmd.bind_nvram(&path).unwrap();
+
+        dmd.set_nvram(&[0x5a]);
+        dmd.step();
+        assert_eq!(0, fs::read(&path).unwrap()[0]);
+        dmd.run(0);
+        assert_eq!(0x5a, fs::read(&path).unwrap()[0]);
+
+        // A failed write is kept until one succeeds.
+        fs::remove_dir_all(&dir).unwrap();
+        dmd.set_nvram(&[0xa5]);
+        dmd.run(0);
+        assert!(dmd.nvram_error().is_some());
+
+        fs::create_dir(&dir).unwrap();
+        dmd.run(0);
+        assert!(dmd.nvram_error().is_none());
+        assert_eq!(0xa5, fs::read(&path).unwrap()[0]);
+
+        dmd.unbind_nvram().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn firmware_accepts_provisioned_settings() {
+        use crate::nvram::{BaudRate, NvramSettings, ReturnKey, Screen};
+
+        let mut dmd = Dmd::new();
+
+        let mut settings = NvramSettings::default();
+        settings.port_a.speed = BaudRate::B19200;
+        settings.screen = Screen::Light;
+        settings.return_key = ReturnKey::CrLf;
+        settings.pf_keys[0] = b"date\r".to_vec();
+        dmd.set_nvram_settings(&settings).unwrap();
+
+        dmd.reset().unwrap();
+        dmd.run(1_000_000);
+
+        // A bad checksum would have made the firmware restore defaults
+        let settings = dmd.nvram_settings().unwrap();
+        assert_eq!(BaudRate::B19200, settings.port_a.speed);
+        assert_eq!(Screen::Light, settings.screen);
+        assert_eq!(ReturnKey::CrLf, settings.return_key);
+        assert_eq!(b"date\r", &settings.pf_keys[0][..]);
+    }
+
+    /// Press `key` and collect what the firmware sends on port A.
+    #[cfg(feature = "embedded-rom")]
+    fn press_and_collect(dmd: &mut Dmd, key: crate::keyboard::Key, modifiers: u8, len: usize) -> Vec<u8> {
+        assert!(dmd.key_press(key, modifiers));
+
+        let mut sent = Vec::new();
+        for _ in 0..200 {
+            dmd.run(50_000);
+            while let Some(c) = dmd.rs232_tx_poll() {
+                sent.push(c);
+            }
+            if sent.len() >= len {
+                break;
+            }
+        }
+        sent
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn firmware_translates_mapped_keys() {
+        use crate::keyboard::{Key, MOD_SHIFT};
+        use crate::nvram::NvramSettings;
+
+        let mut dmd = Dmd::new();
+        let mut settings = NvramSettings::default();
+        settings.pf_keys[0] = b"date\r".to_vec();
+        dmd.set_nvram_settings(&settings).unwrap();
+        dmd.reset().unwrap();
+        dmd.run(5_000_000);
+        while dmd.rs232_tx_poll().is_some() {}
+
+        assert_eq!(b"Q".to_vec(), press_and_collect(&mut dmd, Key::Char(b'q'), MOD_SHIFT, 1));
+        assert_eq!(b"\x1b[A".to_vec(), press_and_collect(&mut dmd, Key::Up, 0, 3));
+        assert_eq!(b"date\r".to_vec(), press_and_collect(&mut dmd, Key::F(1), 0, 5));
+
+        dmd.keymap_mut().unbind(Key::Up);
+        assert!(!dmd.key_press(Key::Up, 0));
+    }
+
+    #[test]
+    fn runs_supplied_rom() {
+        let mut dmd = Dmd::new_without_rom();
+        assert!(dmd.rom_info().is_none());
+        assert!(dmd.reset().is_err());
+
+        // The reset PCB starts a NOP followed by a branch to itself
+        let image = image_with_program(&[0x70, 0x7b, 0x00]);
+
+        let info = dmd.load_rom(&image).unwrap();
+        assert_eq!(None, info.release);
+        assert_eq!(None, info.version);
+
+        dmd.reset().unwrap();
+        assert_eq!(0x200, dmd.get_pc());
+        dmd.run(10);
+        assert_eq!(0x201, dmd.get_pc());
+    }
+
+    fn dmd_with_counter_loop() -> Dmd {
+        let program = [
+            0x84, 0x01, 0x40, // 200: MOVW &1,%r0
+            0x9c, 0x01, 0x40, // 203: ADDW2 &1,%r0
+            0x84, 0x40, 0x7f, 0x00, 0x01, 0x70, 0x00, // 206: MOVW %r0,$0x700100
+            0x84, 0x7f, 0x00, 0x01, 0x70, 0x00, 0x41, // 20d: MOVW $0x700100,%r1
+            0x7b, 0xef, // 214: BRB 0x203
+        ];
+
+        let mut dmd = Dmd::new_without_rom();
+        dmd.load_rom(&image_with_program(&program)).unwrap();
+        dmd.reset().unwrap();
+        dmd
+    }
+
+    #[test]
+    fn stops_at_breakpoints() {
+        let mut dmd = dmd_with_counter_loop();
+        dmd.add_breakpoint(Breakpoint::new(0x206));
+
+        assert_eq!(StopReason::Breakpoint(0x206), dmd.run_until_break(100));
+        assert_eq!(0x206, dmd.get_pc());
+        assert_eq!(2, dmd.get_register(0));
+
+        // Resuming steps off the breakpoint and around the loop
+        assert_eq!(StopReason::Breakpoint(0x206), dmd.run_until_break(100));
+        assert_eq!(3, dmd.get_register(0));
+
+        assert!(dmd.remove_breakpoint(0x206));
+        assert_eq!(StopReason::StepLimit, dmd.run_until_break(100));
+    }
+
+    #[test]
+    fn stops_at_breakpoints_on_chunk_boundaries() {
+        let mut dmd = dmd_with_counter_loop();
+        // Reached after exactly RUN_CHUNK steps
+        dmd.add_breakpoint(Breakpoint::when(0x214, 0, Comparison::Eq, 2501));
+
+        assert_eq!(StopReason::StepLimit, dmd.run_until_break(RUN_CHUNK));
+        assert_eq!(0x214, dmd.get_pc());
+        assert_eq!(StopReason::Breakpoint(0x214), dmd.run_until_break(RUN_CHUNK));
+        assert_eq!(0x214, dmd.get_pc());
+        assert_eq!(2501, dmd.get_register(0));
+    }
+
+    #[test]
+    fn stops_at_conditional_breakpoints() {
+        let mut dmd = dmd_with_counter_loop();
+        dmd.add_breakpoint(Breakpoint::when(0x206, 0, Comparison::Eq, 5));
+
+        assert_eq!(StopReason::Breakpoint(0x206), dmd.run_until_break(100));
+        assert_eq!(5, dmd.get_register(0));
+    }
+
+    #[test]
+    fn stops_after_watched_accesses() {
+        let mut dmd = dmd_with_counter_loop();
+        dmd.add_watchpoint(Watchpoint {
+            addresses: 0x700100..0x700104,
+            kind: Watch::Write,
+        });
+
+        let hit = WatchHit {
+            address: 0x700100,
+            kind: Watch::Write,
+        };
+        assert_eq!(StopReason::Watchpoint(hit), dmd.run_until_break(100));
+        assert_eq!(0x20d, dmd.get_pc());
+
+        // Host reads don't count
+        dmd.read_word(0x700100);
+        dmd.clear_watchpoints();
+        dmd.add_watchpoint(Watchpoint {
+            addresses: 0x700102..0x700103,
+            kind: Watch::Read,
+        });
+
+        let hit = WatchHit {
+            address: 0x700100,
+            kind: Watch::Read,
+        };
+        assert_eq!(StopReason::Watchpoint(hit), dmd.run_until_break(100));
+        assert_eq!(0x214, dmd.get_pc());
+        assert_eq!(2, dmd.get_register(1));
+    }
+
+    #[test]
+    fn writes_registers_and_memory() {
+        let mut dmd = Dmd::new_without_rom();
+
+        dmd.set_register(3, 0x1234);
+        dmd.set_psw(0x81e100);
+        assert_eq!(0x1234, dmd.get_register(3));
+        assert_eq!(0x81e100, dmd.get_psw());
+
+        dmd.write_word(0x700000, 0x01020304).unwrap();
+        dmd.write_half(0x700004, 0x0506).unwrap();
+        dmd.write_byte(0x700006, 0x07).unwrap();
+        assert_eq!(Some(0x01020304), dmd.read_word(0x700000));
+        assert_eq!(Some(0x0304), dmd.read_half(0x700002));
+
+        dmd.write_memory(0x700007, &[0x08, 0x09]).unwrap();
+        let mut buf = [0; 9];
+        dmd.read_memory(0x700001, &mut buf).unwrap();
+        assert_eq!([0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x00], buf);
+
+        assert!(dmd.write_half(0x700001, 0).is_err());
+        assert!(dmd.write_memory(0x7ffffe, &[0; 4]).is_err());
+    }
+
+    #[test]
+    fn rejects_unsupported_mouse_accesses() {
+        let mut dmd = Dmd::new_without_rom();
+        dmd.mouse_move(0x12, 0x34);
+
+        assert!(dmd.write_byte(0x400000, 0xff).is_err());
+        assert!(dmd.write_half(0x400000, 0xffff).is_err());
+        assert!(dmd.write_word(0x400000, 0xffffffff).is_err());
+        assert!(dmd.write_memory(0x400000, &[0xff]).is_err());
+        assert_eq!(None, dmd.read_word(0x400000));
+        assert_eq!(None, dmd.read_byte(0x400000));
+
+        let mut buf = [0; 4];
+        assert!(dmd.read_memory(0x400000, &mut buf).is_err());
+        dmd.set_debug_access(true);
+        assert!(dmd.read_memory(0x400000, &mut buf).is_err());
+
+        assert_eq!(Some(0x34), dmd.read_half(0x400000));
+        assert_eq!(Some(0x12), dmd.read_half(0x400002));
+    }
+
+    #[test]
+    fn debug_access_has_no_side_effects() {
+        let mut dmd = Dmd::new_without_rom();
+
+        // MR1A and MR2A share an address, with a pointer that
+        // advances on every access.
+        dmd.write_byte(0x200003, 0x13).unwrap();
+        dmd.write_byte(0x200003, 0x07).unwrap();
+
+        dmd.set_debug_access(true);
+        assert_eq!(Some(0x13), dmd.read_byte(0x200003));
+        assert_eq!(Some(0x13), dmd.read_byte(0x200003));
+
+        dmd.set_debug_access(false);
+        assert_eq!(Some(0x13), dmd.read_byte(0x200003));
+        assert_eq!(Some(0x07), dmd.read_byte(0x200003));
+    }
+
+    #[test]
+    fn rejects_truncated_rom() {
+        let mut dmd = Dmd::new_without_rom();
+        assert!(dmd.load_rom(&[0; 0x1000]).is_err());
+        assert!(dmd.rom_info().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn new_dmd_runs_compiled_in_rom() {
+        let dmd = Dmd::new();
+        assert_eq!(Some("8;7;5"), dmd.rom_info().unwrap().version.as_deref());
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn disassembles_without_changing_cpu_state() {
+        let mut dmd = Dmd::new();
+        dmd.reset().unwrap();
+        dmd.run(1000);
+
+        let pc = dmd.get_pc();
+        let registers: Vec<u32> = (0..16).map(|r| dmd.get_register(r)).collect();
+
+        let lines = dmd.disassemble(0x4460, 3);
+        assert_eq!("movw\t&0x600000,%r0", lines[0].text);
+        assert_eq!("brb\t0x4470", lines[1].text);
+        assert_eq!("clrb\t2(%r0)", lines[2].text);
+        assert_eq!(0x4469, lines[2].address);
+
+        assert_eq!(pc, dmd.get_pc());
+        assert_eq!(registers, (0..16).map(|r| dmd.get_register(r)).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn resolves_symbols() {
+        let mut dmd = dmd_with_counter_loop();
+        assert!(dmd.symbols().is_empty());
+        assert_eq!("0x203", dmd.symbolize(0x203));
+
+        dmd.add_symbols(&SymbolTable::parse("200 start\n203 loop\n").unwrap());
+
+        assert_eq!("loop+0x3", dmd.symbolize(0x206));
+        assert_eq!(Some(0x203), dmd.symbol_address("loop"));
+        assert_eq!(Some("loop+0x11".to_string()), dmd.disassemble(0x214, 1)[0].symbol);
+        assert_eq!("brb\t0x203 <loop>", dmd.disassemble(0x214, 1)[0].text);
+
+        dmd.clear_symbols();
+        assert_eq!(None, dmd.symbol_address("loop"));
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn compiled_in_rom_has_symbols() {
+        let mut dmd = Dmd::new();
+        dmd.reset().unwrap();
+        assert_eq!(Some(0x1aec), dmd.symbol_address("reset"));
+        assert_eq!("call\t(%sp),$0x43fc", dmd.disassemble(0x1b0e, 1)[0].text);
+        assert_eq!("call\t-4(%sp),$0x1f0c <selftest>", dmd.disassemble(0x1b26, 1)[0].text);
+    }
+
+    // Increments the word in .data and stores it in .bss
+    fn coff_program(text_address: u32) -> Vec<u8> {
+        let text = [
+            0x84, 0x7f, 0x00, 0x02, 0x70, 0x00, 0x40, // MOVW $0x700200,%r0
+            0x9c, 0x01, 0x40, // ADDW2 &1,%r0
+            0x84, 0x40, 0x7f, 0x04, 0x02, 0x70, 0x00, // MOVW %r0,$0x700204
+            0x7b, 0x00, // BRB .
+            0x70, // NOP
+        ];
+
+        coff::build_executable(
+            text_address,
+            &[
+                (".text", text_address, STYP_TEXT, &text, text.len() as u32),
+                (".data", 0x700200, STYP_DATA, &[0x00, 0x00, 0x00, 0x29], 4),
+                (".bss", 0x700204, STYP_BSS, &[], 8),
+            ],
+            &[("main", text_address, 1), ("counter", 0x700200, 2)],
+        )
+    }
+
+    #[test]
+    fn loads_and_starts_coff_executables() {
+        let mut dmd = dmd_with_counter_loop();
+        dmd.write_memory(0x700204, &[0xff; 8]).unwrap();
+
+        let program = dmd.load_coff(&coff_program(0x700100), true).unwrap();
+
+        assert_eq!(0x700100, program.entry);
+        assert_eq!(Some(0x700200), program.symbols.address_of("counter"));
+        assert_eq!(Some(0x700100), dmd.symbol_address("main"));
+        assert_eq!(0x700100, dmd.get_pc());
+        assert_eq!(Some(0), dmd.read_word(0x700208));
+
+        dmd.run(4);
+
+        assert_eq!(Some(0x2a), dmd.read_word(0x700204));
+        assert_eq!(0x700111, dmd.get_pc());
+    }
+
+    #[test]
+    fn loads_coff_without_starting() {
+        let mut dmd = dmd_with_counter_loop();
+        let pc = dmd.get_pc();
+
+        dmd.load_coff(&coff_program(0x700100), false).unwrap();
+
+        assert_eq!(pc, dmd.get_pc());
+        assert_eq!(Some(0x847f0002), dmd.read_word(0x700100));
+    }
+
+    #[test]
+    fn rejects_coff_outside_ram() {
+        let mut dmd = dmd_with_counter_loop();
+
+        assert!(matches!(dmd.load_coff(&coff_program(0x1000), true), Err(CoffError::Address(0x1000))));
+        assert!(matches!(dmd.load_coff(&coff_program(0x7ffff0), true), Err(CoffError::Address(0x7ffff0))));
+        assert_eq!(Some(0), dmd.read_word(0x700200));
+        assert_eq!(None, dmd.symbol_address("main"));
+        assert!(matches!(dmd.load_coff(&[0x01, 0x70], true), Err(CoffError::Truncated)));
+    }
+
+    fn dmd_with_nested_calls() -> Dmd {
+        let mut program = vec![0x70; 0x50];
+        let code: [(usize, &[u8]); 3] = [
+            (0x00, &[
+                0xa0, 0x01, // 200: PUSHW &1
+                0xa0, 0x02, // 202: PUSHW &2
+                0x2c, 0xcc, 0xf8, 0x7f, 0x20, 0x02, 0x00, 0x00, // 204: CALL -8(%sp),$0x220
+                0x7b, 0x00, // 20c: BRB .
+            ]),
+            (0x20, &[
+                0x10, 0x48, // 220: SAVE %r8
+                0xa0, 0x07, // 222: PUSHW &7
+                0x2c, 0xcc, 0xfc, 0x7f, 0x40, 0x02, 0x00, 0x00, // 224: CALL -4(%sp),$0x240
+                0x18, 0x48, // 22c: RESTORE %r8
+                0x08, // 22e: RET
+            ]),
+            (0x40, &[
+                0x10, 0x47, // 240: SAVE %r7
+                0x70, // 242: NOP
+                0x18, 0x47, // 243: RESTORE %r7
+                0x08, // 245: RET
+            ]),
+        ];
+        for (offset, bytes) in code.iter() {
+            program[*offset..*offset + bytes.len()].copy_from_slice(bytes);
+        }
+
+        let mut dmd = Dmd::new_without_rom();
+        dmd.load_rom(&image_with_program(&program)).unwrap();
+        dmd.reset().unwrap();
+        dmd.add_symbols(&SymbolTable::parse("200 main\n220 outer\n240 inner\n").unwrap());
+        dmd
+    }
+
+    #[test]
+    fn walks_call_frames() {
+        let mut dmd = dmd_with_nested_calls();
+        dmd.add_breakpoint(Breakpoint::new(0x242));
+        assert_eq!(StopReason::Breakpoint(0x242), dmd.run_until_break(100));
+
+        let frames = dmd.backtrace();
+        let pcs: Vec<u32> = frames.iter().map(|f| f.pc).collect();
+
+        assert_eq!(vec![0x242, 0x22c, 0x20c], pcs);
+        assert_eq!(vec![7], frames[0].args);
+        assert_eq!(vec![1, 2], frames[1].args);
+        assert_eq!(Some("inner+0x2".to_string()), frames[0].symbol);
+        assert_eq!("0000022c <outer+0xc> (0x1, 0x2)", frames[1].to_string());
+    }
+
+    #[test]
+    fn walks_frames_before_save_and_after_restore() {
+        let mut dmd = dmd_with_nested_calls();
+
+        // Just called, before SAVE
+        dmd.add_breakpoint(Breakpoint::new(0x240));
+        assert_eq!(StopReason::Breakpoint(0x240), dmd.run_until_break(100));
+        let pcs: Vec<u32> = dmd.backtrace().iter().map(|f| f.pc).collect();
+        assert_eq!(vec![0x240, 0x22c, 0x20c], pcs);
+        assert_eq!(vec![7], dmd.backtrace()[0].args);
+
+        // Restored, about to return
+        dmd.add_breakpoint(Breakpoint::new(0x245));
+        assert_eq!(StopReason::Breakpoint(0x245), dmd.run_until_break(100));
+        let pcs: Vec<u32> = dmd.backtrace().iter().map(|f| f.pc).collect();
+        assert_eq!(vec![0x245, 0x22c, 0x20c], pcs);
+
+        dmd.add_breakpoint(Breakpoint::new(0x22e));
+        assert_eq!(StopReason::Breakpoint(0x22e), dmd.run_until_break(100));
+        let frames = dmd.backtrace();
+        let pcs: Vec<u32> = frames.iter().map(|f| f.pc).collect();
+        assert_eq!(vec![0x22e, 0x20c], pcs);
+        assert_eq!(vec![1, 2], frames[0].args);
+    }
+
+    #[test]
+    fn profiles_calls_and_returns() {
+        let mut dmd = dmd_with_nested_calls();
+        dmd.set_profiling(true);
+        dmd.add_breakpoint(Breakpoint::new(0x20c));
+        assert_eq!(StopReason::Breakpoint(0x20c), dmd.run_until_break(100));
+        dmd.set_profiling(false);
+        dmd.run(10);
+
+        assert_eq!(12, dmd.profiler().total());
+        assert_eq!(1, dmd.profiler().count(0x240));
+        assert_eq!(0, dmd.profiler().count(0x20c));
+
+        let mut folded = Vec::new();
+        dmd.write_folded_stacks(&mut folded).unwrap();
+        assert_eq!("[top] 3\nouter 5\nouter;inner 4\n", String::from_utf8(folded).unwrap());
+
+        dmd.clear_profile();
+        assert_eq!(0, dmd.profiler().total());
+    }
 }
@@ -3,6 +3,7 @@
 use crate::bus::AccessCode;
 use crate::bus::Device;
 use crate::err::BusError;
+use crate::keyboard::{Keyboard, CTL_ALARM};
 
 use std::fmt::Debug;
 use std::fmt::Error;
@@ -19,18 +20,18 @@ const ADDRESS_RANGE: Range<usize> = START_ADDR..END_ADDR;
 // Vertical blanks should occur at 60Hz. This value is in nanoseconds
 const VERTICAL_BLANK_DELAY: u32 = 16_666_666;  // 60 Hz
 
-// Delay rates, in nanoseconds, selected when ACR[7] = 0
-const DELAY_RATES_A: [u32;13] = [
-    160000000, 72727272, 59259260, 40000000,
-    26666668, 13333334, 6666667, 7619047,
-    3333333, 1666666, 1111111, 833333, 208333
+// Baud rates, in tenths of a baud, selected when ACR[7] = 0
+const BAUD_RATES_A: [u64;13] = [
+    500, 1100, 1345, 2000,
+    3000, 6000, 12000, 10500,
+    24000, 48000, 72000, 96000, 384000
 ];
 
-// Delay rates, in nanoseconds, selected when ACR[7] = 1
-const DELAY_RATES_B: [u32;13] = [
-    106666672, 72727272, 59259260, 53333336,
-    26666668, 13333334, 6666667, 4000000,
-    3333333, 1666666, 4444444, 833333, 416666
+// Baud rates, in tenths of a baud, selected when ACR[7] = 1
+const BAUD_RATES_B: [u64;13] = [
+    750, 1100, 1345, 1500,
+    3000, 6000, 12000, 20000,
+    24000, 48000, 18000, 96000, 192000
 ];
 
 const PORT_0: usize = 0;
@@ -45,6 +46,8 @@ const CRA: u8 = 0x0b;
 const THRA: u8 = 0x0f;
 const IPCR_ACR: u8 = 0x13;
 const ISR_MASK: u8 = 0x17;
+const CTU_CTUR: u8 = 0x1b;
+const CTL_CTLR: u8 = 0x1f;
 const MR12B: u8 = 0x23;
 const CSRB: u8 = 0x27;
 const CRB: u8 = 0x2b;
@@ -53,6 +56,10 @@ const IP_OPCR: u8 = 0x37;
 const OPBITS_SET: u8 = 0x3b;
 const OPBITS_RESET: u8 = 0x3f;
 
+// Reading these registers starts and stops the counter
+const START_CTR: u8 = OPBITS_SET;
+const STOP_CTR: u8 = OPBITS_RESET;
+
 
 //
 // Port Configuration Bits
@@ -64,11 +71,57 @@ const CNF_ERX: u8 = 0x02;
 // Status Flags
 //
 const STS_RXR: u8 = 0x01;
+const STS_FFULL: u8 = 0x02;
 const STS_TXR: u8 = 0x04;
 const STS_TXE: u8 = 0x08;
 const STS_OER: u8 = 0x10;
 const STS_PER: u8 = 0x20;
 const STS_FER: u8 = 0x40;
+const STS_RXB: u8 = 0x80;
+const STS_ERRORS: u8 = STS_RXB | STS_FER | STS_PER;
+
+//
+// Mode Register 1
+//
+const MR1_BITS: u8 = 0x03;
+const MR1_PARITY_TYPE: u8 = 0x04;
+const MR1_PARITY_MODE: u8 = 0x18;
+const MR1_BLOCK_ERRORS: u8 = 0x20;
+const MR1_RX_RTS: u8 = 0x80;
+
+// Parity modes
+const PARITY_WITH: u8 = 0x00;
+const PARITY_FORCE: u8 = 0x08;
+const PARITY_NONE: u8 = 0x10;
+
+//
+// Mode Register 2
+//
+const MR2_STOP_BITS: u8 = 0x0f;
+const MR2_CTS_TX: u8 = 0x10;
+const MR2_TX_RTS: u8 = 0x20;
+const MR2_CHANNEL_MODE: u8 = 0xc0;
+
+// Channel modes
+const MODE_AUTO_ECHO: u8 = 0x40;
+const MODE_LOCAL_LOOP: u8 = 0x80;
+const MODE_REMOTE_LOOP: u8 = 0xc0;
+
+// The mode registers aren't reset, so start with 8 data bits, no
+// parity and one stop bit.
+const MODE_8N1: u8 = PARITY_NONE | MR1_BITS;
+const MODE_8N1_STOP: u8 = 0x07;
+
+//
+// Character Framing
+//
+// The host sends and receives each character as a byte. When the
+// data bits and parity bit fit in eight bits, the parity bit is the
+// bit after the data bits, so a host using eight bits and no parity
+// sees the parity the DUART generates, and can send bad parity.
+// Longer frames carry no parity from the host, and are only
+// received with parity errors when those are injected.
+//
 
 //
 // Commands
@@ -83,18 +136,103 @@ const CMD_DTX: u8 = 0x08;
 //
 const ISTS_TAI: u8 = 0x01;
 const ISTS_RAI: u8 = 0x02;
+const ISTS_DBA: u8 = 0x04;
+const ISTS_CRDY: u8 = 0x08;
 const ISTS_TBI: u8 = 0x10;
 const ISTS_RBI: u8 = 0x20;
+const ISTS_DBB: u8 = 0x40;
 const ISTS_IPC: u8 = 0x80;
 
 //
-// Interrupt Masks
+// Auxiliary Control Register
+//
+const ACR_BRG_SET: u8 = 0x80;
+const ACR_TIMER: u8 = 0x40;
+
+//
+// Counter/Timer
+//
+// ACR[6:4] selects the mode and clock. The counter counts down once
+// from the preload set in CTUR and CTLR, sets ISR[3] when it reaches
+// zero, and keeps counting until stopped. The timer reloads itself
+// at zero to make a square wave, and sets ISR[3] once per cycle.
+// The crystal and the 16x baud rate clocks run in real time; the IP2
+// clock is the vertical blank.
+//
+const X1_PERIOD_PS: u64 = 271_267; // 3.6864 MHz
+
+//
+// Output Port Configuration
+//
+const OPCR_RXRDYA: u8 = 0x10;
+const OPCR_RXRDYB: u8 = 0x20;
+const OPCR_TXRDYA: u8 = 0x40;
+
+//
+// Interrupt Request Lines
+//
+// The DUART's INTR output drives line 1. OP4, OP5 and OP6 drive
+// lines 5, 2 and 4 when the OPCR routes RxRDYA, RxRDYB and TxRDYA
+// to them.
 //
 const KEYBOARD_INT: u8 = 0x04;
 const MOUSE_BLANK_INT: u8 = 0x02;
 const TX_INT: u8 = 0x10;
 const RX_INT: u8 = 0x20;
 
+//
+// Port B's transmitter is shared by the keyboard and the printer
+// port. The firmware drives OP3 low (sets bit 3 of the OPR) while it
+// sends to the printer, and high again before talking to the
+// keyboard.
+//
+const OPR_PRINTER: u8 = 0x08;
+
+//
+// Modem Control
+//
+// The firmware asserts DTR on OP0 at power-up and negates it to hang
+// up. OP0 is also the SCN2681's RTSA output, which the DUART negates
+// by itself while MR1[7] is set and the receiver is full, and drops
+// when the transmitter is disabled while MR2[5] is set. The SCN2681
+// reads CTSA on IP0, but IP0 is a mouse button on the 5620, so CTS,
+// DCD and DSR are read from IP4, IP5 and IP6. The inputs are active
+// low.
+//
+const OPR_DTR: u8 = 0x01;
+const IP_CTS: u8 = 0x10;
+const IP_DCD: u8 = 0x20;
+const IP_DSR: u8 = 0x40;
+
+// Bits of Duart::modem_out
+const MODEM_DTR: u8 = 0x01;
+const MODEM_RTS: u8 = 0x02;
+
+// Depth of the receive FIFO
+const RX_FIFO_DEPTH: usize = 3;
+
+// Flow control characters sent by the terminal
+const XON: u8 = 0x11;
+const XOFF: u8 = 0x13;
+
+/// A modem control line on the RS-232 port. DTR and RTS are outputs,
+/// CTS, DCD and DSR inputs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModemLine {
+    Dtr,
+    Rts,
+    Cts,
+    Dcd,
+    Dsr,
+}
+
+/// A receive error on a serial line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LineError {
+    Parity,
+    Framing,
+}
+
 struct Port {
     mode: [u8;2],
     stat: u8,
@@ -102,8 +240,13 @@ struct Port {
     rx_data: u8,
     tx_data: u8,
     mode_ptr: usize,
-    rx_queue: VecDeque<u8>,
+    csr: Option<u8>,
+    rx_queue: VecDeque<(u8, u8)>,
+    rx_fifo: Option<VecDeque<(u8, u8)>>,
+    rx_shift: Option<(u8, u8)>,
     tx_queue: VecDeque<u8>,
+    tx_printer: bool,
+    baud_rate: Option<u64>,
     char_delay: Duration,
     next_rx: Instant,
     next_tx: Instant,
@@ -112,14 +255,19 @@ struct Port {
 impl Port {
     fn new() -> Port {
         Port {
-            mode: [0; 2],
+            mode: [MODE_8N1, MODE_8N1_STOP],
             stat: 0,
             conf: 0,
             rx_data: 0,
             tx_data: 0,
             mode_ptr: 0,
+            csr: None,
             rx_queue: VecDeque::new(),
+            rx_fifo: None,
+            rx_shift: None,
             tx_queue: VecDeque::new(),
+            tx_printer: false,
+            baud_rate: None,
             char_delay: Duration::new(0, 1_000_000),
             next_rx: Instant::now(),
             next_tx: Instant::now(),
@@ -127,6 +275,234 @@ impl Port {
     }
 }
 
+impl Port {
+    /// Queue a character for the receiver, with the error status
+    /// bits to report alongside it.
+    fn receive(&mut self, c: u8, errors: u8) {
+        if self.rx_queue.is_empty() {
+            self.next_rx = Instant::now() + self.char_delay;
+        }
+
+        self.rx_queue.push_front((c, errors));
+    }
+
+    /// Load a received character. Without a FIFO, it replaces the one
+    /// in the holding register.
+    ///
+    /// With a FIFO, a character that finds the FIFO full waits in the
+    /// shift register, and is lost with an overrun error if another
+    /// arrives before there is room for it.
+    fn load(&mut self, c: u8, errors: u8) {
+        let fifo = match self.rx_fifo.as_mut() {
+            Some(fifo) => fifo,
+            None => {
+                self.rx_data = c;
+                // In character mode the error bits describe the
+                // character just received; in block mode they
+                // accumulate until the error status is reset.
+                if self.mode[0] & MR1_BLOCK_ERRORS == 0 {
+                    self.stat &= !STS_ERRORS;
+                }
+                self.stat |= errors | STS_RXR;
+                return;
+            }
+        };
+
+        if fifo.len() < RX_FIFO_DEPTH {
+            fifo.push_back((c, errors));
+        } else {
+            if self.rx_shift.is_some() {
+                self.stat |= STS_OER;
+            }
+            self.rx_shift = Some((c, errors));
+        }
+
+        if self.mode[0] & MR1_BLOCK_ERRORS != 0 {
+            self.stat |= errors;
+        }
+        self.update_fifo();
+    }
+
+    /// Read the receive holding register, taking the character at the
+    /// top of the FIFO. Returns true if another character is ready.
+    fn read_rhr(&mut self) -> bool {
+        match self.rx_fifo.as_mut() {
+            Some(fifo) => {
+                fifo.pop_front();
+                if let Some(held) = self.rx_shift.take() {
+                    fifo.push_back(held);
+                }
+                self.update_fifo();
+            }
+            None => {
+                self.stat &= !STS_RXR;
+                if self.mode[0] & MR1_BLOCK_ERRORS == 0 {
+                    self.stat &= !STS_ERRORS;
+                }
+            }
+        }
+
+        self.stat & STS_RXR != 0
+    }
+
+    /// Discard everything in the receiver.
+    fn flush_rx(&mut self) {
+        if let Some(fifo) = self.rx_fifo.as_mut() {
+            fifo.clear();
+        }
+        self.rx_shift = None;
+        self.stat &= !(STS_RXR | STS_FFULL);
+    }
+
+    /// Point the holding register and status at the top of the FIFO.
+    /// In character mode, the error bits belong to that character.
+    fn update_fifo(&mut self) {
+        let fifo = match self.rx_fifo.as_ref() {
+            Some(fifo) => fifo,
+            None => return,
+        };
+
+        let char_mode = self.mode[0] & MR1_BLOCK_ERRORS == 0;
+
+        match fifo.front() {
+            Some(&(c, errors)) => {
+                self.rx_data = c;
+                self.stat |= STS_RXR;
+                if char_mode {
+                    self.stat = (self.stat & !STS_ERRORS) | errors;
+                }
+            }
+            None => {
+                self.stat &= !STS_RXR;
+                if char_mode {
+                    self.stat &= !STS_ERRORS;
+                }
+            }
+        }
+
+        if fifo.len() == RX_FIFO_DEPTH {
+            self.stat |= STS_FFULL;
+        } else {
+            self.stat &= !STS_FFULL;
+        }
+    }
+
+    /// Set the baud rate from the receiver's clock select, in the rate
+    /// set chosen by ACR[7]. Until the clock is selected, and for codes
+    /// 0xd-0xf, which select the counter/timer or an external clock,
+    /// the rate is left unchanged.
+    fn set_clock(&mut self, acr: u8) {
+        let baud_bits = match self.csr {
+            Some(csr) => usize::from(csr >> 4),
+            None => return,
+        };
+        let rates = if acr & ACR_BRG_SET == 0 { &BAUD_RATES_A } else { &BAUD_RATES_B };
+
+        if let Some(rate) = rates.get(baud_bits) {
+            self.baud_rate = Some(*rate);
+            self.update_delay();
+        }
+    }
+
+    /// Set the character delay to the time to send a whole frame: the
+    /// start bit, data bits, parity bit and stop bits.
+    fn update_delay(&mut self) {
+        let rate = match self.baud_rate {
+            Some(rate) => rate,
+            None => return,
+        };
+
+        let stop_code = u64::from(self.mode[1] & MR2_STOP_BITS);
+        let stop = match stop_code {
+            0..=7 if self.data_bits() == 5 => 17 + stop_code,
+            0..=7 => 9 + stop_code,
+            _ => 17 + stop_code,
+        };
+        let bits = 1 + u64::from(self.data_bits()) + self.parity_enabled() as u64;
+        let sixteenths = bits * 16 + stop;
+
+        self.char_delay = Duration::from_nanos(10_000_000_000 * sixteenths / (16 * rate));
+    }
+
+    /// The period of the transmitter's 1x clock, in picoseconds.
+    fn bit_period_ps(&self) -> u64 {
+        match self.baud_rate {
+            Some(rate) => 10_000_000_000_000 / rate,
+            None => self.char_delay.as_nanos() as u64 * 100,
+        }
+    }
+
+    fn data_bits(&self) -> u32 {
+        5 + u32::from(self.mode[0] & MR1_BITS)
+    }
+
+    fn parity_enabled(&self) -> bool {
+        self.mode[0] & MR1_PARITY_MODE != PARITY_NONE
+    }
+
+    fn channel_mode(&self) -> u8 {
+        self.mode[1] & MR2_CHANNEL_MODE
+    }
+
+    /// The parity bit sent with `data`. In force parity and multidrop
+    /// modes it is MR1[2].
+    fn parity_bit(&self, data: u8) -> u8 {
+        let odd = self.mode[0] & MR1_PARITY_TYPE != 0;
+        match self.mode[0] & MR1_PARITY_MODE {
+            PARITY_WITH => ((data.count_ones() & 1 == 1) != odd) as u8,
+            _ => odd as u8,
+        }
+    }
+
+    /// The data bits of `c`, masking the rest.
+    fn data(&self, c: u8) -> u8 {
+        match self.data_bits() {
+            8 => c,
+            bits => c & ((1 << bits) - 1),
+        }
+    }
+
+    /// Frame a character to send to the host.
+    fn frame(&self, c: u8) -> u8 {
+        let data = self.data(c);
+        let bits = self.data_bits();
+
+        if self.parity_enabled() && bits < 8 {
+            data | self.parity_bit(data) << bits
+        } else {
+            data
+        }
+    }
+
+    /// Take the data bits from a character received from the host,
+    /// with the parity error bit if its parity bit is wrong. In
+    /// multidrop mode the parity error bit is the address bit.
+    fn unframe(&self, c: u8) -> (u8, u8) {
+        let data = self.data(c);
+        let bits = self.data_bits();
+
+        if !self.parity_enabled() || bits == 8 {
+            return (data, 0);
+        }
+
+        let parity = (c >> bits) & 1;
+        let error = match self.mode[0] & MR1_PARITY_MODE {
+            PARITY_WITH | PARITY_FORCE => parity != self.parity_bit(data),
+            _ => parity != 0,
+        };
+
+        (data, if error { STS_PER } else { 0 })
+    }
+
+    /// True if the receiver has no room for another character.
+    fn rx_full(&self) -> bool {
+        match &self.rx_fifo {
+            Some(fifo) => fifo.len() == RX_FIFO_DEPTH,
+            None => self.stat & STS_RXR != 0,
+        }
+    }
+}
+
 impl Default for Port {
     fn default() -> Self {
         Port::new()
@@ -139,9 +515,25 @@ pub struct Duart {
     ipcr: u8,
     inprt: u8,
     outprt: u8,
+    opcr: u8,
     istat: u8,
     imr: u8,
     ivec: u8,
+    ctur: u8,
+    ctlr: u8,
+    ct_count: u16,
+    ct_running: bool,
+    ct_halves: u64,
+    ct_prescale: u8,
+    ct_residue: u64,
+    ct_last: Instant,
+    tx_break: bool,
+    printer_queue: VecDeque<u8>,
+    keyboard: Keyboard,
+    xon_xoff: bool,
+    rx_paused: bool,
+    modem_out: u8,
+    modem_events: VecDeque<(ModemLine, bool)>,
     next_vblank: Instant
 }
 
@@ -162,9 +554,25 @@ impl Duart {
             ipcr: 0x40,
             inprt: 0xb,
             outprt: 0,
+            opcr: 0,
             istat: 0,
             imr: 0,
             ivec: 0,
+            ctur: 0,
+            ctlr: 0,
+            ct_count: 0,
+            ct_running: false,
+            ct_halves: 0,
+            ct_prescale: 0,
+            ct_residue: 0,
+            ct_last: Instant::now(),
+            tx_break: false,
+            printer_queue: VecDeque::new(),
+            keyboard: Keyboard::new(),
+            xon_xoff: false,
+            rx_paused: false,
+            modem_out: 0,
+            modem_events: VecDeque::new(),
             next_vblank: Instant::now() + Duration::new(0, VERTICAL_BLANK_DELAY),
         }
     }
@@ -175,7 +583,13 @@ impl Duart {
             self.vertical_blank();
         }
 
-        let val = self.ivec;
+        // The counter is brought up to date when its registers are
+        // read, and here only while it could interrupt the CPU.
+        if self.counter_can_interrupt() {
+            self.update_counter();
+        }
+
+        let val = self.interrupt_lines();
 
         if val == 0 {
             None
@@ -184,59 +598,180 @@ impl Duart {
         }
     }
 
+    /// Return the interrupt request lines currently asserted by the
+    /// DUART. INTR follows the unmasked bits of the ISR. The OP4-OP6
+    /// lines are not affected by the IMR, but only reach the CPU when
+    /// the OPCR routes the receiver or transmitter ready signal to them.
+    pub fn interrupt_lines(&self) -> u8 {
+        let mut lines = 0;
+
+        if self.isr() & self.imr != 0 {
+            lines |= MOUSE_BLANK_INT;
+        }
+
+        if self.opcr & OPCR_RXRDYA != 0 {
+            lines |= self.ivec & RX_INT;
+        }
+
+        if self.opcr & OPCR_RXRDYB != 0 {
+            lines |= self.ivec & KEYBOARD_INT;
+        }
+
+        if self.opcr & OPCR_TXRDYA != 0 {
+            lines |= self.ivec & TX_INT;
+        }
+
+        lines
+    }
+
+    /// Compute the Interrupt Status Register. The transmitter and
+    /// receiver bits mirror each port's status register; the rest
+    /// are latched in `istat` until cleared.
+    fn isr(&self) -> u8 {
+        let mut isr = self.istat & (ISTS_IPC | ISTS_DBA | ISTS_DBB | ISTS_CRDY);
+
+        if self.ports[PORT_0].stat & STS_TXR != 0 {
+            isr |= ISTS_TAI;
+        }
+
+        if self.ports[PORT_0].stat & STS_RXR != 0 {
+            isr |= ISTS_RAI;
+        }
+
+        if self.ports[PORT_1].stat & STS_TXR != 0 {
+            isr |= ISTS_TBI;
+        }
+
+        if self.ports[PORT_1].stat & STS_RXR != 0 {
+            isr |= ISTS_RBI;
+        }
+
+        isr
+    }
+
+    /// Latch a change on the input port. The upper nibble of `deltas`
+    /// holds the IPCR change bits; only the inputs enabled in ACR[3:0]
+    /// raise the input port change bit in the ISR.
+    fn input_change(&mut self, deltas: u8) {
+        self.ipcr |= deltas;
+
+        if (deltas >> 4) & self.acr & 0x0f != 0 {
+            self.istat |= ISTS_IPC;
+        }
+    }
+
     fn handle_rx(&mut self, port: usize) {
-        let mut ctx = &mut self.ports[port];
+        // With receiver flow control enabled, the far end is assumed
+        // to stop sending while RTS is negated.
+        if port == PORT_0 && self.ports[PORT_0].mode[0] & MR1_RX_RTS != 0 && !self.rts() {
+            return;
+        }
+
+        let ctx = &mut self.ports[port];
+
+        let (ivec, delta_break) = match port {
+            0 => (RX_INT, ISTS_DBA),
+            _ => (KEYBOARD_INT, ISTS_DBB),
+        };
 
-        let (istat, ivec) = match port {
-            0 => (ISTS_RAI, RX_INT),
-            _ => (ISTS_RBI, KEYBOARD_INT),
+        if ctx.rx_queue.is_empty() || Instant::now() < ctx.next_rx {
+            return;
+        }
+
+        let received = ctx.rx_queue.pop_back();
+        if !ctx.rx_queue.is_empty() {
+            ctx.next_rx = Instant::now() + ctx.char_delay;
+        }
+
+        let (c, errors) = match received {
+            Some(received) => received,
+            None => return,
         };
 
-        if !ctx.rx_queue.is_empty() && Instant::now() >= ctx.next_rx {
-            if let Some(c) = ctx.rx_queue.pop_back() {
+        // A break is seen whether or not the receiver is enabled. Its
+        // start and end both set the delta break bit, but the two are
+        // indistinguishable here.
+        if errors & STS_RXB != 0 {
+            self.istat |= delta_break;
+        }
+
+        match ctx.channel_mode() {
+            // The receiver only hears the transmitter.
+            MODE_LOCAL_LOOP => {}
+            // Characters go straight back, without reaching the CPU.
+            MODE_REMOTE_LOOP => self.line_out(port, c),
+            mode => {
                 if ctx.conf & CNF_ERX != 0 {
-                    ctx.rx_data = c;
-                    ctx.stat |= STS_RXR;
-                    self.istat |= istat;
+                    let (data, parity) = if errors & STS_RXB != 0 { (c, 0) } else { ctx.unframe(c) };
+                    ctx.load(data, errors | parity);
                     self.ivec |= ivec;
+                    if mode == MODE_AUTO_ECHO {
+                        self.line_out(port, c);
+                    }
                 }
             }
-
-            if !ctx.rx_queue.is_empty() {
-                ctx.next_rx = Instant::now() + ctx.char_delay;
-            }
         }
     }
 
     fn handle_tx(&mut self, port: usize) {
-        let mut ctx = &mut self.ports[port];
+        // MR2[4] holds the transmitter while CTS is negated.
+        if port == PORT_0 && self.ports[PORT_0].mode[1] & MR2_CTS_TX != 0 && self.inprt & IP_CTS != 0 {
+            return;
+        }
+
+        let ctx = &mut self.ports[port];
 
-        let (tx_istat, rx_istat) = match port {
-            0 => (ISTS_TAI, ISTS_RAI),
-            _ => (ISTS_TBI, ISTS_RBI),
+        let (tx_ivec, rx_ivec) = match port {
+            0 => (TX_INT, RX_INT),
+            _ => (0, KEYBOARD_INT),
         };
 
         if (ctx.conf & CNF_ETX) != 0 &&
             (ctx.stat & STS_TXR) == 0 &&
             (ctx.stat & STS_TXE) == 0 && Instant::now() >= ctx.next_tx
         {
-            let c = ctx.tx_data;
+            let c = ctx.frame(ctx.tx_data);
             ctx.stat |= STS_TXR;
             ctx.stat |= STS_TXE;
-            self.istat |= tx_istat;
             // Only RS232 transmit generates an interrupt.
-            if port == 0 {
-                self.ivec |= TX_INT;
+            self.ivec |= tx_ivec;
+            match ctx.channel_mode() {
+                MODE_LOCAL_LOOP => {
+                    let (data, parity) = ctx.unframe(c);
+                    ctx.load(data, parity);
+                    self.ivec |= rx_ivec;
+                }
+                // The line is driven by the receiver.
+                MODE_AUTO_ECHO | MODE_REMOTE_LOOP => {}
+                _ => self.line_out(port, c),
             }
-            if (ctx.mode[1] >> 6) & 3 == 0x2 {
-                // Loopback Mode.
-                ctx.rx_data = c;
-                ctx.stat |= STS_RXR;
-                self.istat |= rx_istat;
-                self.ivec |= RX_INT;
-            } else {
+        }
+    }
+
+    /// Send a character out on a port's line.
+    fn line_out(&mut self, port: usize, c: u8) {
+        let ctx = &mut self.ports[port];
+
+        if port == PORT_1 && ctx.tx_printer {
+            self.printer_queue.push_front(c);
+        } else if port == PORT_1 {
+            // The keyboard marks its status replies with a parity
+            // error, so the firmware can tell them from key codes.
+            if let Some(status) = self.keyboard.control(c) {
+                ctx.receive(status, STS_PER);
+            }
+            if c & CTL_ALARM != 0 {
                 ctx.tx_queue.push_front(c);
             }
+        } else {
+            if self.xon_xoff {
+                match ctx.data(c) {
+                    XOFF => self.rx_paused = true,
+                    XON => self.rx_paused = false,
+                    _ => {}
+                }
+            }
+            ctx.tx_queue.push_front(c);
         }
     }
 
@@ -247,18 +782,118 @@ impl Duart {
         // Deal with RS-232 Receive
         self.handle_rx(PORT_0);
 
-        // Deal with Keyboard Transmit
-        // (note: This is used only for keyboard beeps!)
+        // Deal with Keyboard and Printer Transmit
         self.handle_tx(PORT_1);
 
         // Deal with Keyboard Receive
         self.handle_rx(PORT_1);
+
+        self.update_modem();
+    }
+
+    fn ct_preload(&self) -> u16 {
+        u16::from(self.ctur) << 8 | u16::from(self.ctlr)
+    }
+
+    /// The period of the counter/timer's clock in picoseconds, or
+    /// None if it is clocked by IP2.
+    fn ct_period_ps(&self) -> Option<u64> {
+        match (self.acr >> 4) & 7 {
+            1 => Some(self.ports[PORT_0].bit_period_ps()),
+            2 => Some(self.ports[PORT_1].bit_period_ps()),
+            3 | 7 => Some(X1_PERIOD_PS * 16),
+            6 => Some(X1_PERIOD_PS),
+            _ => None,
+        }
+    }
+
+    /// Run the counter/timer for the real time elapsed since it was
+    /// last updated.
+    fn update_counter(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.ct_last);
+        self.ct_last = now;
+
+        if let Some(period) = self.ct_period_ps() {
+            let ps = elapsed.as_nanos() as u64 * 1000 + self.ct_residue;
+            self.ct_residue = ps % period;
+            self.counter_ticks(ps / period);
+        }
+    }
+
+    /// True if the counter/timer runs on a clock, rather than IP2,
+    /// and could raise an unmasked counter ready interrupt.
+    fn counter_can_interrupt(&self) -> bool {
+        self.imr & ISTS_CRDY != 0
+            && self.istat & ISTS_CRDY == 0
+            && (self.ct_running || self.acr & ACR_TIMER != 0)
+            && self.ct_period_ps().is_some()
+    }
+
+    /// Count a pulse on IP2, for the counter/timer clocked by it.
+    fn ip2_pulse(&mut self) {
+        match (self.acr >> 4) & 7 {
+            0 | 4 => self.counter_ticks(1),
+            5 => {
+                self.ct_prescale = (self.ct_prescale + 1) % 16;
+                if self.ct_prescale == 0 {
+                    self.counter_ticks(1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn counter_ticks(&mut self, ticks: u64) {
+        if ticks == 0 {
+            return;
+        }
+
+        if self.acr & ACR_TIMER != 0 {
+            let preload = u64::from(self.ct_preload());
+            if preload == 0 {
+                return;
+            }
+            // Each time the count reaches zero the output changes
+            // state; two changes make a cycle.
+            let pos = preload.saturating_sub(u64::from(self.ct_count)) + ticks;
+            let halves = pos / preload;
+            self.ct_count = (preload - pos % preload) as u16;
+            if (self.ct_halves + halves) / 2 != self.ct_halves / 2 {
+                self.istat |= ISTS_CRDY;
+            }
+            self.ct_halves += halves;
+        } else if self.ct_running {
+            let count = u64::from(self.ct_count);
+            let to_zero = if count == 0 { 0x10000 } else { count };
+            if ticks >= to_zero {
+                self.istat |= ISTS_CRDY;
+            }
+            self.ct_count = (count.wrapping_sub(ticks) & 0xffff) as u16;
+        }
+    }
+
+    fn start_counter(&mut self) {
+        self.update_counter();
+        self.ct_count = self.ct_preload();
+        self.ct_running = true;
+        self.ct_halves = 0;
+        self.ct_residue = 0;
+    }
+
+    /// Stop the counter, and clear the counter ready bit. The timer
+    /// keeps running.
+    fn stop_counter(&mut self) {
+        self.update_counter();
+        if self.acr & ACR_TIMER == 0 {
+            self.ct_running = false;
+        }
+        self.istat &= !ISTS_CRDY;
     }
 
     pub fn vertical_blank(&mut self) {
-        self.ivec |= MOUSE_BLANK_INT;
-        self.ipcr |= 0x40;
-        self.istat |= ISTS_IPC;
+        self.input_change(0x40);
+        self.ip2_pulse();
 
         if self.inprt & 0x04 == 0 {
             self.ipcr |= 0x40;
@@ -272,31 +907,112 @@ impl Duart {
         !self.outprt
     }
 
+    fn dtr(&self) -> bool {
+        self.outprt & OPR_DTR != 0
+    }
+
+    fn rts(&self) -> bool {
+        let ctx = &self.ports[PORT_0];
+        self.dtr() && !(ctx.mode[0] & MR1_RX_RTS != 0 && ctx.rx_full())
+    }
+
+    /// Return true if a modem control line is asserted.
+    pub fn modem_line(&self, line: ModemLine) -> bool {
+        match line {
+            ModemLine::Dtr => self.dtr(),
+            ModemLine::Rts => self.rts(),
+            ModemLine::Cts => self.inprt & IP_CTS == 0,
+            ModemLine::Dcd => self.inprt & IP_DCD == 0,
+            ModemLine::Dsr => self.inprt & IP_DSR == 0,
+        }
+    }
+
+    /// Assert or negate one of the modem control inputs. The outputs
+    /// are driven by the terminal, and can't be set.
+    pub fn set_modem_line(&mut self, line: ModemLine, asserted: bool) {
+        let bit = match line {
+            ModemLine::Cts => IP_CTS,
+            ModemLine::Dcd => IP_DCD,
+            ModemLine::Dsr => IP_DSR,
+            _ => return,
+        };
+
+        if asserted {
+            self.inprt &= !bit;
+        } else {
+            self.inprt |= bit;
+        }
+    }
+
+    /// Queue an event for each modem control output that has changed
+    /// since the last call.
+    fn update_modem(&mut self) {
+        let mut out = 0;
+        if self.dtr() {
+            out |= MODEM_DTR;
+        }
+        if self.rts() {
+            out |= MODEM_RTS;
+        }
+
+        let changed = out ^ self.modem_out;
+        if changed & MODEM_DTR != 0 {
+            self.modem_events.push_front((ModemLine::Dtr, out & MODEM_DTR != 0));
+        }
+        if changed & MODEM_RTS != 0 {
+            self.modem_events.push_front((ModemLine::Rts, out & MODEM_RTS != 0));
+        }
+        self.modem_out = out;
+    }
+
+    /// Take the next change of DTR or RTS, with the line's new state.
+    pub fn modem_poll(&mut self) -> Option<(ModemLine, bool)> {
+        self.modem_events.pop_back()
+    }
+
+    /// True while the terminal holds its RS-232 line in the break
+    /// condition.
+    pub fn rs232_tx_break(&self) -> bool {
+        self.tx_break
+    }
+
     pub fn rs232_tx_poll(&mut self) -> Option<u8> {
         self.ports[PORT_0].tx_queue.pop_back()
     }
 
+    /// Take the next bell request sent to the keyboard. Hosts that
+    /// want the rest of the keyboard's state should use `keyboard`.
     pub fn kb_tx_poll(&mut self) -> Option<u8> {
         self.ports[PORT_1].tx_queue.pop_back()
     }
 
+    pub fn printer_poll(&mut self) -> Option<u8> {
+        self.printer_queue.pop_back()
+    }
+
+    pub fn keyboard(&self) -> &Keyboard {
+        &self.keyboard
+    }
+
+    pub fn keyboard_mut(&mut self) -> &mut Keyboard {
+        &mut self.keyboard
+    }
+
     pub fn mouse_down(&mut self, button: u8) {
         self.ipcr = 0;
         self.inprt |= 0xb;
-        self.istat |= ISTS_IPC;
-        self.ivec |= MOUSE_BLANK_INT;
         match button {
             0 => {
-                self.ipcr |= 0x80;
                 self.inprt &= !(0x08);
+                self.input_change(0x80);
             }
             1 => {
-                self.ipcr |= 0x20;
                 self.inprt &= !(0x02);
+                self.input_change(0x20);
             }
             2 => {
-                self.ipcr |= 0x10;
-                self.inprt &= !(0x01)
+                self.inprt &= !(0x01);
+                self.input_change(0x10);
             }
             _ => {}
         }
@@ -305,40 +1021,73 @@ impl Duart {
     pub fn mouse_up(&mut self, button: u8) {
         self.ipcr = 0;
         self.inprt |= 0xb;
-        self.istat |= ISTS_IPC;
-        self.ivec |= MOUSE_BLANK_INT;
         match button {
-            0 => {
-                self.ipcr |= 0x80;
-            }
-            1 => {
-                self.ipcr |= 0x20;
-            }
-            2 => {
-                self.ipcr |= 0x10;
-            }
+            0 => self.input_change(0x80),
+            1 => self.input_change(0x20),
+            2 => self.input_change(0x10),
             _ => {}
         }
     }
 
-    pub fn rx_keyboard(&mut self, c: u8) {
-        let mut ctx = &mut self.ports[PORT_1];
+    /// Model the RS-232 receiver's 3 character FIFO, with overrun
+    /// errors, rather than a single holding register that each new
+    /// character replaces.
+    pub fn set_rx_fifo(&mut self, enabled: bool) {
+        let ctx = &mut self.ports[PORT_0];
+        ctx.flush_rx();
+        ctx.rx_fifo = if enabled { Some(VecDeque::with_capacity(RX_FIFO_DEPTH)) } else { None };
+        self.ivec &= !RX_INT;
+    }
 
-        if ctx.rx_queue.is_empty() {
-            ctx.next_rx = Instant::now() + ctx.char_delay;
+    /// Stop accepting characters from the host while the terminal
+    /// has sent XOFF, until it sends XON.
+    pub fn set_xon_xoff(&mut self, enabled: bool) {
+        self.xon_xoff = enabled;
+        self.rx_paused = false;
+    }
+
+    /// The number of characters the RS-232 port can take from the host
+    /// without losing any. This is unlimited without a receive FIFO,
+    /// unless the terminal has sent XOFF.
+    pub fn rx_space(&self) -> usize {
+        let ctx = &self.ports[PORT_0];
+
+        if self.rx_paused {
+            return 0;
+        }
+
+        match &ctx.rx_fifo {
+            Some(fifo) => {
+                let free = RX_FIFO_DEPTH - fifo.len() + ctx.rx_shift.is_none() as usize;
+                free.saturating_sub(ctx.rx_queue.len())
+            }
+            None => usize::MAX,
         }
+    }
 
-        ctx.rx_queue.push_front(c);
+    pub fn rx_keyboard(&mut self, c: u8) {
+        self.ports[PORT_1].receive(c, 0);
     }
 
     pub fn rx_char(&mut self, c: u8) {
-        let mut ctx = &mut self.ports[PORT_0];
+        self.ports[PORT_0].receive(c, 0);
+    }
 
-        if ctx.rx_queue.is_empty() {
-            ctx.next_rx = Instant::now() + ctx.char_delay;
-        }
+    /// Hold the RS-232 line in the break condition for longer than a
+    /// character. The receiver loads a single NUL with the received
+    /// break bit set.
+    pub fn rx_break(&mut self) {
+        self.ports[PORT_0].receive(0, STS_RXB);
+    }
 
-        ctx.rx_queue.push_front(c);
+    /// Receive a character on the RS-232 line with a parity or
+    /// framing error.
+    pub fn rx_char_error(&mut self, c: u8, error: LineError) {
+        let status = match error {
+            LineError::Parity => STS_PER,
+            LineError::Framing => STS_FER,
+        };
+        self.ports[PORT_0].receive(c, status);
     }
 
     pub fn handle_command(&mut self, cmd: u8, port: usize) {
@@ -346,7 +1095,7 @@ impl Duart {
             return;
         }
 
-        let mut ctx = &mut self.ports[port];
+        let ctx = &mut self.ports[port];
 
         // Enable or disable transmitter
         if cmd & CMD_DTX != 0 {
@@ -355,14 +1104,12 @@ impl Duart {
             ctx.stat &= !STS_TXE;
             if port == PORT_0 {
                 self.ivec &= !TX_INT;
-                self.istat &= !ISTS_TAI;
             }
         } else if cmd & CMD_ETX != 0 {
             ctx.conf |= CNF_ETX;
             ctx.stat |= STS_TXR;
             ctx.stat |= STS_TXE;
             if port == PORT_0 {
-                self.istat |= ISTS_TAI;
                 self.ivec |= TX_INT;
             }
         }
@@ -370,34 +1117,57 @@ impl Duart {
         // Enable or disable receiver
         if cmd & CMD_DRX != 0 {
             ctx.conf &= !CNF_ERX;
-            ctx.stat &= !STS_RXR;
+            ctx.flush_rx();
             if port == PORT_0 {
                 self.ivec &= !RX_INT;
-                self.istat &= !ISTS_RAI;
             } else {
                 self.ivec &= !KEYBOARD_INT;
-                self.istat &= !ISTS_RBI;
             }
         } else if cmd & CMD_ERX != 0 {
             ctx.conf |= CNF_ERX;
-            ctx.stat |= STS_RXR;
         }
 
         // Extra commands
         match (cmd >> 4) & 7 {
             1 => ctx.mode_ptr = 0,
             2 => {
-                ctx.stat |= STS_RXR;
-                ctx.conf |= CNF_ERX;
+                // Reset the receiver, discarding the character it
+                // holds.
+                ctx.flush_rx();
+                ctx.stat &= !STS_ERRORS;
+                ctx.conf &= !CNF_ERX;
+                if port == PORT_0 {
+                    self.ivec &= !RX_INT;
+                } else {
+                    self.ivec &= !KEYBOARD_INT;
+                }
             }
             3 => {
                 ctx.stat |= STS_TXR;
                 ctx.stat |= STS_TXE;
                 ctx.conf &= !CNF_ETX;
             }
-            4 => ctx.stat &= !(STS_FER|STS_PER|STS_OER),
+            4 => ctx.stat &= !(STS_ERRORS | STS_OER),
+            5 => {
+                if port == PORT_0 {
+                    self.istat &= !ISTS_DBA;
+                } else {
+                    self.istat &= !ISTS_DBB;
+                }
+            }
+            // Start and stop break. Only the RS-232 line's is seen
+            // by the host.
+            6 if port == PORT_0 => self.tx_break = true,
+            7 if port == PORT_0 => self.tx_break = false,
             _ => {}
         }
+
+        // MR2[5] drops RTS once the transmitter is disabled. Nothing
+        // is left to send by then, so it drops at once.
+        let tx_stopped = cmd & CMD_DTX != 0 || (cmd >> 4) & 7 == 3;
+        if port == PORT_0 && tx_stopped && ctx.mode[1] & MR2_TX_RTS != 0 {
+            self.outprt &= !OPR_DTR;
+        }
     }
 }
 
@@ -421,48 +1191,89 @@ impl Device for Duart {
     }
 
     fn read_byte(&mut self, address: usize, _access: AccessCode) -> Result<u8, BusError> {
+        let val = self.peek_byte(address)?;
+
+        // Reading some registers changes the DUART's state
         match (address - START_ADDR) as u8 {
             MR12A => {
-                let mut ctx = &mut self.ports[PORT_0];
-                let val = ctx.mode[ctx.mode_ptr];
+                let ctx = &mut self.ports[PORT_0];
+                ctx.mode_ptr = (ctx.mode_ptr + 1) % 2;
+            }
+            THRA => {
+                let ready = self.ports[PORT_0].read_rhr();
+                if !ready {
+                    self.ivec &= !RX_INT;
+                }
+            }
+            IPCR_ACR => {
+                self.ipcr &= !0xf0;
+                self.istat &= !ISTS_IPC;
+            }
+            CTU_CTUR | CTL_CTLR | ISR_MASK => {
+                self.update_counter();
+                return self.peek_byte(address);
+            }
+            START_CTR => self.start_counter(),
+            STOP_CTR => self.stop_counter(),
+            MR12B => {
+                let ctx = &mut self.ports[PORT_1];
                 ctx.mode_ptr = (ctx.mode_ptr + 1) % 2;
-                Ok(val)
+            }
+            THRB => {
+                let ready = self.ports[PORT_1].read_rhr();
+                if !ready {
+                    self.ivec &= !KEYBOARD_INT;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(val)
+    }
+
+    fn read_half(&mut self, address: usize, access: AccessCode) -> Result<u16, BusError> {
+        let b = self.read_byte(address + 2, access)?;
+        Ok(u16::from(b))
+    }
+
+    fn read_word(&mut self, address: usize, access: AccessCode) -> Result<u32, BusError> {
+        let b = self.read_byte(address + 3, access)?;
+        Ok(u32::from(b))
+    }
+
+    fn peek_byte(&self, address: usize) -> Result<u8, BusError> {
+        match (address - START_ADDR) as u8 {
+            MR12A => {
+                let ctx = &self.ports[PORT_0];
+                Ok(ctx.mode[ctx.mode_ptr])
             }
             CSRA => {
                 Ok(self.ports[PORT_0].stat)
             }
             THRA => {
-                let mut ctx = &mut self.ports[PORT_0];
-                ctx.stat &= !STS_RXR;
-                self.istat &= !ISTS_RAI;
-                self.ivec &= !RX_INT;
-                Ok(ctx.rx_data)
+                Ok(self.ports[PORT_0].rx_data)
             }
             IPCR_ACR => {
-                let result = self.ipcr;
-                self.ipcr &= !0x0f;
-                self.ivec = 0;
-                self.istat &= !ISTS_IPC;
-                Ok(result)
+                Ok((self.ipcr & 0xf0) | (self.inprt & 0x0f))
             }
             ISR_MASK => {
-                Ok(self.istat)
+                Ok(self.isr())
+            }
+            CTU_CTUR => {
+                Ok((self.ct_count >> 8) as u8)
+            }
+            CTL_CTLR => {
+                Ok(self.ct_count as u8)
             }
             MR12B => {
-                let mut ctx = &mut self.ports[PORT_1];
-                let val = ctx.mode[ctx.mode_ptr];
-                ctx.mode_ptr = (ctx.mode_ptr + 1) % 2;
-                Ok(val)
+                let ctx = &self.ports[PORT_1];
+                Ok(ctx.mode[ctx.mode_ptr])
             }
             CSRB => {
                 Ok(self.ports[PORT_1].stat)
             }
             THRB => {
-                let mut ctx = &mut self.ports[PORT_1];
-                ctx.stat &= !STS_RXR;
-                self.istat &= !ISTS_RBI;
-                self.ivec &= !KEYBOARD_INT;
-                Ok(ctx.rx_data)
+                Ok(self.ports[PORT_1].rx_data)
             }
             IP_OPCR => {
                 Ok(self.inprt)
@@ -471,82 +1282,88 @@ impl Device for Duart {
         }
     }
 
-    fn read_half(&mut self, address: usize, access: AccessCode) -> Result<u16, BusError> {
-        let b = self.read_byte(address + 2, access)?;
-        Ok(u16::from(b))
+    fn peek_half(&self, address: usize) -> Result<u16, BusError> {
+        Ok(u16::from(self.peek_byte(address + 2)?))
     }
 
-    fn read_word(&mut self, address: usize, access: AccessCode) -> Result<u32, BusError> {
-        let b = self.read_byte(address + 3, access)?;
-        Ok(u32::from(b))
+    fn peek_word(&self, address: usize) -> Result<u32, BusError> {
+        Ok(u32::from(self.peek_byte(address + 3)?))
     }
 
     fn write_byte(&mut self, address: usize, val: u8, _access: AccessCode) -> Result<(), BusError> {
         match (address - START_ADDR) as u8 {
             MR12A => {
-                let mut ctx = &mut self.ports[PORT_0];
+                let ctx = &mut self.ports[PORT_0];
                 ctx.mode[ctx.mode_ptr] = val;
                 ctx.mode_ptr = (ctx.mode_ptr + 1) % 2;
+                ctx.update_delay();
             }
             CSRA => {
-                // Set the baud rate.
-                let baud_bits: usize = ((val >> 4) & 0xf) as usize;
-                let delay = if self.acr & 0x80 == 0 {
-                    DELAY_RATES_A[baud_bits]
-                } else {
-                    DELAY_RATES_B[baud_bits]
-                };
-                let mut ctx = &mut self.ports[PORT_0];
-                ctx.char_delay = Duration::new(0, delay);
+                let ctx = &mut self.ports[PORT_0];
+                ctx.csr = Some(val);
+                ctx.set_clock(self.acr);
             }
             CRA => {
                 self.handle_command(val, PORT_0);
             }
             THRA => {
-                let mut ctx = &mut self.ports[PORT_0];
+                let ctx = &mut self.ports[PORT_0];
                 ctx.tx_data = val;
                 // Update state. Since we're transmitting, the
                 // transmitter buffer is not empty.  The actual
                 // transmit will happen in the 'service' function.
                 ctx.next_tx = Instant::now() + ctx.char_delay;
                 ctx.stat &= !(STS_TXE | STS_TXR);
-                self.istat &= !ISTS_TAI;
                 self.ivec &= !TX_INT;
             }
             IPCR_ACR => {
+                self.update_counter();
+                let timer = val & ACR_TIMER != 0 && self.acr & ACR_TIMER == 0;
                 self.acr = val;
+                for ctx in self.ports.iter_mut() {
+                    ctx.set_clock(val);
+                }
+                // Entering timer mode starts the timer.
+                if timer {
+                    self.ct_count = self.ct_preload();
+                    self.ct_halves = 0;
+                }
             }
             ISR_MASK => {
                 self.imr = val;
             }
+            CTU_CTUR => {
+                self.ctur = val;
+            }
+            CTL_CTLR => {
+                self.ctlr = val;
+            }
             MR12B => {
-                let mut ctx = &mut self.ports[PORT_1];
+                let ctx = &mut self.ports[PORT_1];
                 ctx.mode[ctx.mode_ptr] = val;
                 ctx.mode_ptr = (ctx.mode_ptr + 1) % 2;
+                ctx.update_delay();
+            }
+            CSRB => {
+                let ctx = &mut self.ports[PORT_1];
+                ctx.csr = Some(val);
+                ctx.set_clock(self.acr);
             }
             CRB => {
                 self.handle_command(val, PORT_1);
             }
             THRB => {
-                // TODO: When OP3 is low, do not send data to
-                // the keyboard! It's meant for the printer.
-
-                // Keyboard transmit requires special handling,
-                // because the only things the terminal transmits to
-                // the keyboard are status requests, or keyboard beep
-                // requests. We ignore status requests, and only
-                // put beep requests into the queue.
-                let mut ctx = &mut self.ports[PORT_1];
-
-                if (val & 0x08) != 0 {
-                    ctx.tx_data = val;
-                    ctx.next_tx = Instant::now() + ctx.char_delay;
-                    ctx.stat &= !(STS_TXE | STS_TXR);
-                    self.istat &= !ISTS_TBI;
-                }
+                // Port B transmits to the keyboard, or to the printer
+                // while OP3 is low.
+                let printer = self.outprt & OPR_PRINTER != 0;
+                let ctx = &mut self.ports[PORT_1];
+                ctx.tx_data = val;
+                ctx.tx_printer = printer;
+                ctx.next_tx = Instant::now() + ctx.char_delay;
+                ctx.stat &= !(STS_TXE | STS_TXR);
             }
             IP_OPCR => {
-                // Not implemented
+                self.opcr = val;
             }
             OPBITS_SET => {
                 self.outprt |= val;
@@ -557,6 +1374,8 @@ impl Device for Duart {
             _ => {}
         };
 
+        self.update_modem();
+
         Ok(())
     }
 
@@ -572,3 +1391,668 @@ impl Device for Duart {
         unimplemented!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::{KeyboardEvent, CTL_CLICK, CTL_STATUS, DEFAULT_STATUS};
+
+    /// Enable the receiver on a port and deliver one character to it.
+    fn receive(duart: &mut Duart, port: usize, c: u8) {
+        duart.handle_command(CMD_ERX, port);
+        duart.ports[port].rx_queue.push_front((c, 0));
+        duart.ports[port].next_rx = Instant::now();
+        duart.service();
+    }
+
+    #[test]
+    fn no_interrupt_when_idle() {
+        let mut duart = Duart::new();
+        duart.opcr = OPCR_RXRDYA | OPCR_RXRDYB | OPCR_TXRDYA;
+        duart.imr = 0xff;
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn rs232_receive_asserts_rx_line_when_routed() {
+        let mut duart = Duart::new();
+        receive(&mut duart, PORT_0, 0x41);
+        assert_eq!(ISTS_RAI, duart.isr());
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.opcr = OPCR_RXRDYA;
+        assert_eq!(RX_INT, duart.interrupt_lines());
+
+        // Reading the character clears RxRDY and the line.
+        assert_eq!(0x41, duart.read_byte(START_ADDR + THRA as usize, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn keyboard_receive_asserts_keyboard_line_when_routed() {
+        let mut duart = Duart::new();
+        receive(&mut duart, PORT_1, 0x20);
+        assert_eq!(ISTS_RBI, duart.isr());
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.opcr = OPCR_RXRDYB;
+        assert_eq!(KEYBOARD_INT, duart.interrupt_lines());
+
+        assert_eq!(0x20, duart.read_byte(START_ADDR + THRB as usize, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn transmitter_ready_asserts_tx_line_when_routed() {
+        let mut duart = Duart::new();
+        duart.handle_command(CMD_ETX, PORT_0);
+        assert_eq!(ISTS_TAI, duart.isr());
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.opcr = OPCR_TXRDYA;
+        assert_eq!(TX_INT, duart.interrupt_lines());
+
+        // Loading the holding register clears TxRDY.
+        duart.write_byte(START_ADDR + THRA as usize, 0x5a, AccessCode::Write).unwrap();
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.handle_command(CMD_DTX, PORT_0);
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn keyboard_transmitter_has_no_interrupt_line() {
+        let mut duart = Duart::new();
+        duart.opcr = 0xf0;
+        duart.handle_command(CMD_ETX, PORT_1);
+        assert_eq!(ISTS_TBI, duart.isr());
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    /// Write a character to port B's holding register and send it.
+    fn transmit_b(duart: &mut Duart, c: u8) {
+        duart.write_byte(START_ADDR + THRB as usize, c, AccessCode::Write).unwrap();
+        duart.ports[PORT_1].next_tx = Instant::now();
+        duart.service();
+    }
+
+    #[test]
+    fn op3_routes_port_b_to_printer() {
+        let mut duart = Duart::new();
+        duart.handle_command(CMD_ETX, PORT_1);
+
+        // OP3 high: everything goes to the keyboard, and only bell
+        // requests reach the keyboard transmit queue.
+        transmit_b(&mut duart, 0x10);
+        transmit_b(&mut duart, 0x08);
+        assert_eq!(Some(0x08), duart.kb_tx_poll());
+        assert_eq!(None, duart.kb_tx_poll());
+        assert_eq!(None, duart.printer_poll());
+
+        // OP3 low: every byte goes to the printer.
+        duart.write_byte(START_ADDR + OPBITS_SET as usize, OPR_PRINTER, AccessCode::Write).unwrap();
+        transmit_b(&mut duart, b'h');
+        transmit_b(&mut duart, b'i');
+        assert_eq!(STS_TXR | STS_TXE, duart.ports[PORT_1].stat);
+        assert_eq!(Some(b'h'), duart.printer_poll());
+        assert_eq!(Some(b'i'), duart.printer_poll());
+        assert_eq!(None, duart.printer_poll());
+        assert_eq!(None, duart.kb_tx_poll());
+
+        duart.write_byte(START_ADDR + OPBITS_RESET as usize, OPR_PRINTER, AccessCode::Write).unwrap();
+        transmit_b(&mut duart, 0x08);
+        assert_eq!(Some(0x08), duart.kb_tx_poll());
+        assert_eq!(None, duart.printer_poll());
+    }
+
+    #[test]
+    fn keyboard_answers_status_requests_with_parity_error() {
+        let mut duart = Duart::new();
+        duart.handle_command(CMD_ETX | CMD_ERX, PORT_1);
+        assert_eq!(0, duart.ports[PORT_1].stat & STS_RXR);
+
+        transmit_b(&mut duart, CTL_STATUS | CTL_CLICK);
+        assert_eq!(Some(KeyboardEvent::Click(true)), duart.keyboard_mut().poll());
+        assert_eq!(None, duart.kb_tx_poll());
+
+        duart.ports[PORT_1].next_rx = Instant::now();
+        duart.service();
+        assert_eq!(STS_RXR | STS_PER, duart.ports[PORT_1].stat & (STS_RXR | STS_PER));
+        assert_eq!(DEFAULT_STATUS, duart.read_byte(START_ADDR + THRB as usize, AccessCode::OperandFetch).unwrap());
+
+        // Key codes arrive without the error.
+        receive(&mut duart, PORT_1, 0x61);
+        assert_eq!(STS_RXR, duart.ports[PORT_1].stat & (STS_RXR | STS_PER));
+
+        // Resetting the receiver drops the character and disables it.
+        duart.handle_command(0x20, PORT_1);
+        assert_eq!(0, duart.ports[PORT_1].stat & STS_RXR);
+        assert_eq!(0, duart.ports[PORT_1].conf & CNF_ERX);
+    }
+
+    /// Enable port A's receiver and deliver what the host has queued.
+    fn deliver(duart: &mut Duart) {
+        duart.handle_command(CMD_ERX, PORT_0);
+        duart.ports[PORT_0].next_rx = Instant::now();
+        duart.service();
+    }
+
+    #[test]
+    fn break_loads_nul_and_sets_delta_break() {
+        let mut duart = Duart::new();
+        duart.rx_break();
+        deliver(&mut duart);
+
+        assert_eq!(STS_RXR | STS_RXB, duart.ports[PORT_0].stat & (STS_RXR | STS_ERRORS));
+        assert_eq!(ISTS_DBA | ISTS_RAI, duart.isr());
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.imr = ISTS_DBA;
+        assert_eq!(MOUSE_BLANK_INT, duart.interrupt_lines());
+
+        // Reading the NUL clears the character's status, and the
+        // reset break change command clears the interrupt.
+        assert_eq!(0, duart.read_byte(START_ADDR + THRA as usize, AccessCode::OperandFetch).unwrap());
+        assert_eq!(0, duart.ports[PORT_0].stat & STS_RXB);
+        assert_eq!(MOUSE_BLANK_INT, duart.interrupt_lines());
+        duart.write_byte(START_ADDR + CRA as usize, 0x50, AccessCode::Write).unwrap();
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn errors_follow_characters_in_character_mode() {
+        let mut duart = Duart::new();
+        duart.rx_char_error(0x41, LineError::Parity);
+        deliver(&mut duart);
+        assert_eq!(STS_PER, duart.ports[PORT_0].stat & STS_ERRORS);
+
+        duart.rx_char_error(0x42, LineError::Framing);
+        deliver(&mut duart);
+        assert_eq!(STS_FER, duart.ports[PORT_0].stat & STS_ERRORS);
+
+        duart.rx_char(0x43);
+        deliver(&mut duart);
+        assert_eq!(0, duart.ports[PORT_0].stat & STS_ERRORS);
+    }
+
+    #[test]
+    fn errors_accumulate_in_block_mode() {
+        let mut duart = Duart::new();
+        duart.write_byte(START_ADDR + CRA as usize, 0x10, AccessCode::Write).unwrap();
+        duart.write_byte(START_ADDR + MR12A as usize, MR1_BLOCK_ERRORS | MODE_8N1, AccessCode::Write).unwrap();
+
+        duart.rx_char_error(0x41, LineError::Parity);
+        deliver(&mut duart);
+        duart.read_byte(START_ADDR + THRA as usize, AccessCode::OperandFetch).unwrap();
+        duart.rx_char_error(0x42, LineError::Framing);
+        deliver(&mut duart);
+        duart.rx_char(0x43);
+        deliver(&mut duart);
+        assert_eq!(STS_PER | STS_FER, duart.ports[PORT_0].stat & STS_ERRORS);
+
+        duart.write_byte(START_ADDR + CRA as usize, 0x40, AccessCode::Write).unwrap();
+        assert_eq!(0, duart.ports[PORT_0].stat & STS_ERRORS);
+    }
+
+    fn write(duart: &mut Duart, reg: u8, val: u8) {
+        duart.write_byte(START_ADDR + reg as usize, val, AccessCode::Write).unwrap();
+    }
+
+    #[test]
+    fn dtr_follows_op0() {
+        let mut duart = Duart::new();
+        assert!(!duart.modem_line(ModemLine::Dtr));
+
+        write(&mut duart, OPBITS_SET, OPR_DTR);
+        assert!(duart.modem_line(ModemLine::Dtr));
+        assert!(duart.modem_line(ModemLine::Rts));
+        assert_eq!(Some((ModemLine::Dtr, true)), duart.modem_poll());
+        assert_eq!(Some((ModemLine::Rts, true)), duart.modem_poll());
+        assert_eq!(None, duart.modem_poll());
+
+        write(&mut duart, OPBITS_RESET, OPR_DTR);
+        assert_eq!(Some((ModemLine::Dtr, false)), duart.modem_poll());
+        assert_eq!(Some((ModemLine::Rts, false)), duart.modem_poll());
+    }
+
+    #[test]
+    fn modem_inputs_are_active_low() {
+        let mut duart = Duart::new();
+        assert!(duart.modem_line(ModemLine::Cts));
+        assert!(duart.modem_line(ModemLine::Dcd));
+        assert!(duart.modem_line(ModemLine::Dsr));
+
+        duart.set_modem_line(ModemLine::Dcd, false);
+        assert!(!duart.modem_line(ModemLine::Dcd));
+        assert_eq!(0x2b, duart.read_byte(START_ADDR + IP_OPCR as usize, AccessCode::OperandFetch).unwrap());
+
+        // Outputs are not set by the host
+        duart.set_modem_line(ModemLine::Dtr, true);
+        assert!(!duart.modem_line(ModemLine::Dtr));
+    }
+
+    #[test]
+    fn rx_rts_holds_line_while_receiver_full() {
+        let mut duart = Duart::new();
+        write(&mut duart, CRA, 0x10);
+        write(&mut duart, MR12A, MR1_RX_RTS | MODE_8N1);
+        write(&mut duart, OPBITS_SET, OPR_DTR);
+
+        duart.rx_char(0x41);
+        duart.rx_char(0x42);
+        deliver(&mut duart);
+        assert!(!duart.modem_line(ModemLine::Rts));
+        assert!(duart.modem_line(ModemLine::Dtr));
+
+        // The second character waits until the first is read.
+        deliver(&mut duart);
+        assert_eq!(0x41, duart.read_byte(START_ADDR + THRA as usize, AccessCode::OperandFetch).unwrap());
+        assert!(duart.modem_line(ModemLine::Rts));
+        deliver(&mut duart);
+        assert_eq!(0x42, duart.read_byte(START_ADDR + THRA as usize, AccessCode::OperandFetch).unwrap());
+    }
+
+    #[test]
+    fn cts_holds_transmitter_when_enabled() {
+        let mut duart = Duart::new();
+        write(&mut duart, CRA, 0x10);
+        write(&mut duart, MR12A, MODE_8N1);
+        write(&mut duart, MR12A, MR2_CTS_TX | MODE_8N1_STOP);
+        duart.handle_command(CMD_ETX, PORT_0);
+        duart.set_modem_line(ModemLine::Cts, false);
+
+        write(&mut duart, THRA, 0x5a);
+        duart.ports[PORT_0].next_tx = Instant::now();
+        duart.service();
+        assert_eq!(None, duart.rs232_tx_poll());
+
+        duart.set_modem_line(ModemLine::Cts, true);
+        duart.service();
+        assert_eq!(Some(0x5a), duart.rs232_tx_poll());
+    }
+
+    #[test]
+    fn tx_rts_drops_rts_when_transmitter_disabled() {
+        let mut duart = Duart::new();
+        write(&mut duart, CRA, 0x10);
+        write(&mut duart, MR12A, MODE_8N1);
+        write(&mut duart, MR12A, MR2_TX_RTS | MODE_8N1_STOP);
+        write(&mut duart, OPBITS_SET, OPR_DTR);
+        write(&mut duart, CRA, CMD_ETX);
+        assert!(duart.modem_line(ModemLine::Rts));
+
+        write(&mut duart, CRA, CMD_DTX);
+        assert!(!duart.modem_line(ModemLine::Rts));
+    }
+
+    #[test]
+    fn fifo_holds_three_characters_then_overruns() {
+        let mut duart = Duart::new();
+        duart.set_rx_fifo(true);
+        assert_eq!(4, duart.rx_space());
+
+        for c in b"ABC" {
+            duart.rx_char(*c);
+            deliver(&mut duart);
+        }
+        assert_eq!(STS_RXR | STS_FFULL, duart.ports[PORT_0].stat);
+        assert_eq!(1, duart.rx_space());
+
+        // D waits in the shift register, and is lost when E arrives.
+        duart.rx_char(b'D');
+        deliver(&mut duart);
+        assert_eq!(0, duart.rx_space());
+        duart.rx_char(b'E');
+        deliver(&mut duart);
+        assert_eq!(STS_OER, duart.ports[PORT_0].stat & STS_OER);
+
+        let mut read = Vec::new();
+        while duart.ports[PORT_0].stat & STS_RXR != 0 {
+            read.push(duart.read_byte(START_ADDR + THRA as usize, AccessCode::OperandFetch).unwrap());
+        }
+        assert_eq!(b"ABCE".to_vec(), read);
+        assert_eq!(0, duart.interrupt_lines() & RX_INT);
+        assert_eq!(4, duart.rx_space());
+    }
+
+    #[test]
+    fn fifo_errors_follow_top_character() {
+        let mut duart = Duart::new();
+        duart.set_rx_fifo(true);
+
+        duart.rx_char_error(b'A', LineError::Parity);
+        deliver(&mut duart);
+        duart.rx_char(b'B');
+        deliver(&mut duart);
+        assert_eq!(STS_PER, duart.ports[PORT_0].stat & STS_ERRORS);
+
+        duart.read_byte(START_ADDR + THRA as usize, AccessCode::OperandFetch).unwrap();
+        assert_eq!(0, duart.ports[PORT_0].stat & STS_ERRORS);
+        assert_eq!(b'B', duart.ports[PORT_0].rx_data);
+    }
+
+    #[test]
+    fn rx_space_counts_characters_in_flight() {
+        let mut duart = Duart::new();
+        assert_eq!(usize::MAX, duart.rx_space());
+
+        duart.set_rx_fifo(true);
+        duart.rx_char(b'A');
+        duart.rx_char(b'B');
+        assert_eq!(2, duart.rx_space());
+    }
+
+    #[test]
+    fn xoff_pauses_host() {
+        let mut duart = Duart::new();
+        duart.set_xon_xoff(true);
+        duart.handle_command(CMD_ETX, PORT_0);
+
+        for c in &[XOFF, XON] {
+            write(&mut duart, THRA, *c);
+            duart.ports[PORT_0].next_tx = Instant::now();
+            duart.service();
+            assert_eq!(Some(*c), duart.rs232_tx_poll());
+            let expected = if *c == XOFF {
+                0
+            } else {
+                usize::MAX
+            };
+            assert_eq!(expected, duart.rx_space());
+        }
+    }
+
+    fn read(duart: &mut Duart, reg: u8) -> u8 {
+        duart.read_byte(START_ADDR + reg as usize, AccessCode::OperandFetch).unwrap()
+    }
+
+    #[test]
+    fn counter_counts_ip2_pulses_to_zero() {
+        let mut duart = Duart::new();
+        write(&mut duart, IPCR_ACR, 0x00);
+        write(&mut duart, CTU_CTUR, 0);
+        write(&mut duart, CTL_CTLR, 3);
+        write(&mut duart, ISR_MASK, ISTS_CRDY);
+
+        // Nothing counts until the counter is started.
+        duart.vertical_blank();
+        assert_eq!(0, read(&mut duart, CTL_CTLR));
+
+        read(&mut duart, START_CTR);
+        duart.vertical_blank();
+        duart.vertical_blank();
+        assert_eq!(1, read(&mut duart, CTL_CTLR));
+        assert_eq!(0, duart.isr() & ISTS_CRDY);
+
+        duart.vertical_blank();
+        assert_eq!(ISTS_CRDY, read(&mut duart, ISR_MASK) & ISTS_CRDY);
+        assert_eq!(MOUSE_BLANK_INT, duart.interrupt_lines());
+
+        // The counter runs on past zero until stopped.
+        duart.vertical_blank();
+        assert_eq!(0xff, read(&mut duart, CTU_CTUR));
+        read(&mut duart, STOP_CTR);
+        assert_eq!(0, duart.isr() & ISTS_CRDY);
+        duart.vertical_blank();
+        assert_eq!(0xff, read(&mut duart, CTL_CTLR));
+    }
+
+    #[test]
+    fn timer_sets_counter_ready_once_per_cycle() {
+        let mut duart = Duart::new();
+        write(&mut duart, CTL_CTLR, 2);
+        write(&mut duart, IPCR_ACR, 0x40);
+
+        for _ in 0..3 {
+            duart.vertical_blank();
+        }
+        assert_eq!(0, duart.isr() & ISTS_CRDY);
+        duart.vertical_blank();
+        assert_eq!(ISTS_CRDY, duart.isr() & ISTS_CRDY);
+
+        // Stopping clears the ready bit, but the timer runs on.
+        read(&mut duart, STOP_CTR);
+        for _ in 0..4 {
+            duart.vertical_blank();
+        }
+        assert_eq!(ISTS_CRDY, duart.isr() & ISTS_CRDY);
+    }
+
+    #[test]
+    fn timer_runs_from_crystal() {
+        let mut duart = Duart::new();
+        write(&mut duart, CTL_CTLR, 0x10);
+        write(&mut duart, IPCR_ACR, 0x60);
+
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(ISTS_CRDY, read(&mut duart, ISR_MASK) & ISTS_CRDY);
+    }
+
+    #[test]
+    fn timer_interrupts_only_when_unmasked() {
+        let mut duart = Duart::new();
+        write(&mut duart, CTL_CTLR, 0x10);
+        write(&mut duart, IPCR_ACR, 0x60);
+
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(None, duart.get_interrupt());
+        assert_eq!(0, duart.istat & ISTS_CRDY);
+
+        write(&mut duart, ISR_MASK, ISTS_CRDY);
+        assert_eq!(Some(MOUSE_BLANK_INT), duart.get_interrupt());
+    }
+
+    #[test]
+    fn ipcr_read_clears_changes() {
+        let mut duart = Duart::new();
+        duart.mouse_down(2);
+        assert_eq!(0x1a, read(&mut duart, IPCR_ACR));
+        assert_eq!(0x0a, read(&mut duart, IPCR_ACR));
+    }
+
+    #[test]
+    fn clock_select_from_counter_keeps_delay() {
+        let mut duart = Duart::new();
+        write(&mut duart, CSRA, 0xbb);
+        assert_eq!(Duration::new(0, 1041666), duart.ports[PORT_0].char_delay);
+        write(&mut duart, CSRA, 0xdd);
+        assert_eq!(Duration::new(0, 1041666), duart.ports[PORT_0].char_delay);
+
+        // ACR[7] selects the other rate set.
+        write(&mut duart, CSRA, 0x99);
+        write(&mut duart, IPCR_ACR, ACR_BRG_SET);
+        assert_eq!(Duration::new(0, 2083333), duart.ports[PORT_0].char_delay);
+        write(&mut duart, CSRA, 0xaa);
+        assert_eq!(Duration::new(0, 5555555), duart.ports[PORT_0].char_delay);
+    }
+
+    #[test]
+    fn break_commands_hold_line() {
+        let mut duart = Duart::new();
+        write(&mut duart, CRA, 0x60);
+        assert!(duart.rs232_tx_break());
+        write(&mut duart, CRA, 0x70);
+        assert!(!duart.rs232_tx_break());
+    }
+
+    fn transmit_a(duart: &mut Duart, c: u8) {
+        write(duart, THRA, c);
+        duart.ports[PORT_0].next_tx = Instant::now();
+        duart.service();
+    }
+
+    fn set_mode(duart: &mut Duart, mr1: u8, mr2: u8) {
+        write(duart, CRA, 0x10);
+        write(duart, MR12A, mr1);
+        write(duart, MR12A, mr2);
+    }
+
+    #[test]
+    fn char_delay_covers_whole_frame() {
+        let mut duart = Duart::new();
+        write(&mut duart, CSRA, 0xbb);
+
+        // 7 data bits, even parity and 2 stop bits make 11 bits.
+        set_mode(&mut duart, 0x02, 0x0f);
+        assert_eq!(Duration::new(0, 1145833), duart.ports[PORT_0].char_delay);
+
+        // 5 data bits, no parity and 1.5 stop bits.
+        set_mode(&mut duart, 0x10, 0x07);
+        assert_eq!(Duration::new(0, 781250), duart.ports[PORT_0].char_delay);
+    }
+
+    #[test]
+    fn parity_is_generated_and_checked() {
+        let mut duart = Duart::new();
+        set_mode(&mut duart, 0x02, 0x07);
+        duart.handle_command(CMD_ETX, PORT_0);
+
+        transmit_a(&mut duart, 0x41);
+        transmit_a(&mut duart, 0xc3);
+        assert_eq!(Some(0x41), duart.rs232_tx_poll());
+        assert_eq!(Some(0xc3), duart.rs232_tx_poll());
+
+        duart.rx_char(0xc3);
+        deliver(&mut duart);
+        assert_eq!(0x43, read(&mut duart, THRA));
+        assert_eq!(0, duart.ports[PORT_0].stat & STS_PER);
+
+        duart.rx_char(0x43);
+        deliver(&mut duart);
+        assert_eq!(STS_PER, duart.ports[PORT_0].stat & STS_PER);
+        assert_eq!(0x43, read(&mut duart, THRA));
+    }
+
+    #[test]
+    fn xoff_pauses_host_with_parity() {
+        let mut duart = Duart::new();
+        duart.set_xon_xoff(true);
+        set_mode(&mut duart, 0x02, 0x07);
+        duart.handle_command(CMD_ETX, PORT_0);
+
+        transmit_a(&mut duart, XOFF);
+        assert_eq!(Some(0x93), duart.rs232_tx_poll());
+        assert_eq!(0, duart.rx_space());
+
+        transmit_a(&mut duart, XON);
+        assert_eq!(Some(0x11), duart.rs232_tx_poll());
+        assert_eq!(usize::MAX, duart.rx_space());
+    }
+
+    #[test]
+    fn data_bits_mask_characters() {
+        let mut duart = Duart::new();
+        set_mode(&mut duart, 0x10, 0x07);
+        duart.handle_command(CMD_ETX, PORT_0);
+
+        duart.rx_char(0xff);
+        deliver(&mut duart);
+        assert_eq!(0x1f, read(&mut duart, THRA));
+
+        transmit_a(&mut duart, 0xff);
+        assert_eq!(Some(0x1f), duart.rs232_tx_poll());
+    }
+
+    #[test]
+    fn auto_echo_returns_received_characters() {
+        let mut duart = Duart::new();
+        set_mode(&mut duart, MODE_8N1, MODE_AUTO_ECHO | MODE_8N1_STOP);
+        duart.handle_command(CMD_ETX, PORT_0);
+
+        duart.rx_char(0x78);
+        deliver(&mut duart);
+        assert_eq!(0x78, read(&mut duart, THRA));
+        assert_eq!(Some(0x78), duart.rs232_tx_poll());
+
+        // The CPU's transmitter is cut off from the line.
+        transmit_a(&mut duart, 0x79);
+        assert_eq!(None, duart.rs232_tx_poll());
+    }
+
+    #[test]
+    fn remote_loopback_bypasses_cpu() {
+        let mut duart = Duart::new();
+        set_mode(&mut duart, MODE_8N1, MODE_REMOTE_LOOP | MODE_8N1_STOP);
+
+        duart.rx_char(0x78);
+        deliver(&mut duart);
+        assert_eq!(0, duart.ports[PORT_0].stat & STS_RXR);
+        assert_eq!(Some(0x78), duart.rs232_tx_poll());
+    }
+
+    #[test]
+    fn local_loopback_disconnects_line() {
+        let mut duart = Duart::new();
+        set_mode(&mut duart, MODE_8N1, MODE_LOCAL_LOOP | MODE_8N1_STOP);
+        duart.handle_command(CMD_ETX | CMD_ERX, PORT_0);
+
+        duart.rx_char(0x78);
+        deliver(&mut duart);
+        assert_eq!(0, duart.ports[PORT_0].stat & STS_RXR);
+
+        transmit_a(&mut duart, 0x79);
+        assert_eq!(None, duart.rs232_tx_poll());
+        assert_eq!(0x79, read(&mut duart, THRA));
+    }
+
+    #[test]
+    fn input_port_change_honors_imr() {
+        let mut duart = Duart::new();
+        duart.acr = 0x0f;
+        duart.mouse_down(0);
+        assert_eq!(ISTS_IPC, duart.isr());
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.write_byte(START_ADDR + ISR_MASK as usize, ISTS_IPC, AccessCode::Write).unwrap();
+        assert_eq!(MOUSE_BLANK_INT, duart.interrupt_lines());
+
+        // Reading the IPCR acknowledges the change.
+        duart.read_byte(START_ADDR + IPCR_ACR as usize, AccessCode::OperandFetch).unwrap();
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn input_port_change_honors_acr() {
+        let mut duart = Duart::new();
+        duart.imr = ISTS_IPC;
+
+        duart.vertical_blank();
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.acr = 0x04;
+        duart.mouse_down(0);
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.vertical_blank();
+        assert_eq!(MOUSE_BLANK_INT, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn imr_masks_receiver_status_on_intr() {
+        let mut duart = Duart::new();
+        receive(&mut duart, PORT_0, 0x41);
+        assert_eq!(0, duart.interrupt_lines());
+
+        duart.imr = ISTS_RAI;
+        assert_eq!(MOUSE_BLANK_INT, duart.interrupt_lines());
+
+        duart.imr = ISTS_RBI;
+        assert_eq!(0, duart.interrupt_lines());
+    }
+
+    #[test]
+    fn simultaneous_sources_assert_all_lines() {
+        let mut duart = Duart::new();
+        duart.opcr = OPCR_RXRDYA | OPCR_RXRDYB | OPCR_TXRDYA;
+        duart.acr = 0x0f;
+        duart.imr = ISTS_IPC;
+
+        receive(&mut duart, PORT_0, 0x41);
+        receive(&mut duart, PORT_1, 0x20);
+        duart.handle_command(CMD_ETX, PORT_0);
+        duart.vertical_blank();
+
+        assert_eq!(RX_INT | TX_INT | KEYBOARD_INT | MOUSE_BLANK_INT, duart.interrupt_lines());
+        assert_eq!(ISTS_IPC | ISTS_RBI | ISTS_RAI | ISTS_TAI, duart.isr());
+    }
+}
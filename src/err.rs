@@ -1,5 +1,6 @@
 use std::error::Error;
 use std::fmt;
+use std::io;
 
 #[derive(Debug)]
 pub enum CpuException {
@@ -107,6 +108,7 @@ impl fmt::Display for CpuError {
 }
 
 impl Error for CpuError {
+    #[allow(deprecated)]
     fn description(&self) -> &str {
         match *self {
             CpuError::Exception(ref e) => e.description(),
@@ -133,3 +135,167 @@ impl From<BusError> for CpuError {
         CpuError::Bus(err)
     }
 }
+
+#[derive(Debug)]
+pub enum NvramError {
+    Size(usize),
+    Checksum,
+    Value(usize),
+    PfKeyLength(usize),
+}
+
+impl fmt::Display for NvramError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NvramError::Size(size) => write!(f, "Invalid NVRAM image size {}", size),
+            NvramError::Checksum => write!(f, "NVRAM checksum mismatch"),
+            NvramError::Value(offset) => write!(f, "Invalid NVRAM setting at offset {:x}", offset),
+            NvramError::PfKeyLength(key) => write!(f, "PF key f{} definition too long", key + 1),
+        }
+    }
+}
+
+impl Error for NvramError {
+    fn description(&self) -> &str {
+        match *self {
+            NvramError::Size(_) => "image size",
+            NvramError::Checksum => "checksum",
+            NvramError::Value(_) => "invalid setting",
+            NvramError::PfKeyLength(_) => "pf key length",
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        match *self {
+            NvramError::Size(_) => None,
+            NvramError::Checksum => None,
+            NvramError::Value(_) => None,
+            NvramError::PfKeyLength(_) => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RomError {
+    Size(usize),
+    Io(io::Error),
+}
+
+impl fmt::Display for RomError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RomError::Size(size) => write!(f, "Invalid ROM image size {}", size),
+            RomError::Io(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for RomError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        match *self {
+            RomError::Size(_) => "image size",
+            RomError::Io(ref e) => e.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        match *self {
+            RomError::Size(_) => None,
+            RomError::Io(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for RomError {
+    fn from(err: io::Error) -> RomError {
+        RomError::Io(err)
+    }
+}
+
+#[derive(Debug)]
+pub enum CoffError {
+    Magic(u16),
+    Truncated,
+    NotExecutable,
+    Address(u32),
+}
+
+impl fmt::Display for CoffError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CoffError::Magic(magic) => write!(f, "Not a WE32000 COFF file (magic {:x})", magic),
+            CoffError::Truncated => write!(f, "COFF file is truncated"),
+            CoffError::NotExecutable => write!(f, "COFF file has no a.out header"),
+            CoffError::Address(addr) => write!(f, "Section at {:x} does not fit in RAM", addr),
+        }
+    }
+}
+
+impl Error for CoffError {
+    fn description(&self) -> &str {
+        match *self {
+            CoffError::Magic(_) => "bad magic",
+            CoffError::Truncated => "truncated",
+            CoffError::NotExecutable => "not executable",
+            CoffError::Address(_) => "section address",
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        match *self {
+            CoffError::Magic(_) => None,
+            CoffError::Truncated => None,
+            CoffError::NotExecutable => None,
+            CoffError::Address(_) => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SymbolError {
+    Syntax(usize),
+    Coff(CoffError),
+    Io(io::Error),
+}
+
+impl fmt::Display for SymbolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SymbolError::Syntax(line) => write!(f, "Invalid symbol on line {}", line),
+            SymbolError::Coff(ref e) => e.fmt(f),
+            SymbolError::Io(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for SymbolError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        match *self {
+            SymbolError::Syntax(_) => "syntax",
+            SymbolError::Coff(ref e) => e.description(),
+            SymbolError::Io(ref e) => e.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&dyn Error> {
+        match *self {
+            SymbolError::Syntax(_) => None,
+            SymbolError::Coff(ref e) => Some(e),
+            SymbolError::Io(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<CoffError> for SymbolError {
+    fn from(err: CoffError) -> SymbolError {
+        SymbolError::Coff(err)
+    }
+}
+
+impl From<io::Error> for SymbolError {
+    fn from(err: io::Error) -> SymbolError {
+        SymbolError::Io(err)
+    }
+}
@@ -0,0 +1,568 @@
+use crate::debug::{Breakpoint, StopReason, Watch, Watchpoint};
+use crate::dmd::Dmd;
+
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+//
+// GDB Remote Serial Protocol
+//
+// A debugger talks to the stub in packets of the form $data#cs, where
+// cs is the modulo 256 sum of the data in two hex digits. Each packet
+// is acknowledged with '+', or '-' to ask for it again. A lone ^C
+// interrupts a running target.
+//
+// Registers are sent in the order of Cpu::r (r0-r8, fp, ap, psw, sp,
+// pcbp, isp, pc), each as a big-endian word. Breakpoints (Z0, Z1) and
+// watchpoints (Z2-Z4) map to the Dmd debugger API, so ROM code can be
+// stopped without patching it.
+//
+
+const SIGINT: u8 = 2;
+const SIGTRAP: u8 = 5;
+const REGISTER_COUNT: usize = 16;
+const PACKET_SIZE: usize = 0x1000;
+
+/// Steps run per poll while the target is running
+pub const RUN_CHUNK: usize = 10_000;
+
+// How long an idle poll waits for input from the debugger.
+const IDLE_WAIT: Duration = Duration::from_millis(10);
+
+/// Accepts debugger connections on a TCP port.
+pub struct GdbServer {
+    listener: TcpListener,
+}
+
+impl GdbServer {
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<GdbServer> {
+        Ok(GdbServer {
+            listener: TcpListener::bind(addr)?,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Wait for a debugger to connect.
+    pub fn accept(&self) -> io::Result<GdbStub> {
+        let (stream, _) = self.listener.accept()?;
+        GdbStub::new(stream)
+    }
+
+    /// Serve a single debugger session on `dmd`, until the debugger
+    /// detaches, kills the target or disconnects.
+    pub fn serve(&self, dmd: &mut Dmd) -> io::Result<()> {
+        let mut stub = self.accept()?;
+        while stub.poll(dmd, RUN_CHUNK)? {}
+        Ok(())
+    }
+}
+
+/// One debugger session. The stub is driven by calling `poll`, so
+/// that the Dmd only needs to be borrowed for one poll at a time.
+pub struct GdbStub {
+    stream: TcpStream,
+    input: Vec<u8>,
+    running: bool,
+    last_stop: String,
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|d| d as u8)
+}
+
+fn parse_hex(s: &[u8]) -> Option<u32> {
+    if s.is_empty() || s.len() > 8 {
+        return None;
+    }
+    s.iter().try_fold(0u32, |acc, c| Some(acc << 4 | u32::from(hex_digit(*c)?)))
+}
+
+fn parse_hex_bytes(s: &[u8]) -> Option<Vec<u8>> {
+    let pairs = s.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs.map(|p| Some(hex_digit(p[0])? << 4 | hex_digit(p[1])?)).collect()
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+// Split "addr,len" or "addr,len:rest" into its parts.
+fn parse_addr_len(args: &[u8]) -> Option<(u32, u32, &[u8])> {
+    let comma = args.iter().position(|c| *c == b',')?;
+    let (len, rest) = match args.iter().position(|c| *c == b':') {
+        Some(colon) => (&args[comma + 1..colon], &args[colon + 1..]),
+        None => (&args[comma + 1..], &args[args.len()..]),
+    };
+    Some((parse_hex(&args[..comma])?, parse_hex(len)?, rest))
+}
+
+impl GdbStub {
+    pub fn new(stream: TcpStream) -> io::Result<GdbStub> {
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(IDLE_WAIT))?;
+        Ok(GdbStub {
+            stream,
+            input: Vec::new(),
+            running: false,
+            last_stop: format!("S{:02x}", SIGTRAP),
+        })
+    }
+
+    /// True while the debugger has the target running.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Wait for input from the debugger, then handle it as `process`
+    /// does. Returns false once the session has ended.
+    pub fn poll(&mut self, dmd: &mut Dmd, steps: usize) -> io::Result<bool> {
+        Ok(self.receive()? && self.process(dmd, steps)?)
+    }
+
+    /// Read any input from the debugger, without needing the Dmd.
+    /// While the target is stopped this waits briefly for input.
+    /// Returns false if the debugger disconnected.
+    pub fn receive(&mut self) -> io::Result<bool> {
+        self.stream.set_nonblocking(self.running)?;
+
+        let mut buf = [0u8; PACKET_SIZE];
+        match self.stream.read(&mut buf) {
+            Ok(0) => Ok(false),
+            Ok(n) => {
+                self.input.extend_from_slice(&buf[..n]);
+                Ok(true)
+            }
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Handle the packets received so far and, if the target is
+    /// running, run it for up to `steps` steps. Returns false once
+    /// the session has ended.
+    pub fn process(&mut self, dmd: &mut Dmd, steps: usize) -> io::Result<bool> {
+        while let Some(packet) = self.next_packet()? {
+            match packet {
+                None => {
+                    // Interrupt
+                    if self.running {
+                        self.stop(format!("S{:02x}", SIGINT))?;
+                    }
+                }
+                Some(data) => {
+                    if !self.handle(dmd, &data)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+
+        if self.running {
+            match dmd.run_until_break(steps) {
+                StopReason::StepLimit => {}
+                reason => self.stop(Self::stop_reply(reason))?,
+            }
+        }
+
+        Ok(true)
+    }
+
+    // Take the next complete packet from the input. Returns Some(None)
+    // for an interrupt, and None when no complete packet is buffered.
+    fn next_packet(&mut self) -> io::Result<Option<Option<Vec<u8>>>> {
+        loop {
+            let start = match self.input.iter().position(|c| *c == b'$' || *c == 0x03) {
+                Some(i) => i,
+                None => {
+                    // Acknowledgements and noise
+                    self.input.clear();
+                    return Ok(None);
+                }
+            };
+
+            if self.input[start] == 0x03 {
+                self.input.drain(..=start);
+                return Ok(Some(None));
+            }
+
+            let end = match self.input[start..].iter().position(|c| *c == b'#') {
+                Some(i) if start + i + 2 < self.input.len() => start + i,
+                _ => {
+                    self.input.drain(..start);
+                    return Ok(None);
+                }
+            };
+
+            let raw: Vec<u8> = self.input[start + 1..end].to_vec();
+            let sum = parse_hex(&self.input[end + 1..end + 3]);
+            self.input.drain(..end + 3);
+
+            if sum != Some(u32::from(checksum(&raw))) {
+                self.stream.write_all(b"-")?;
+                continue;
+            }
+
+            self.stream.write_all(b"+")?;
+
+            // Undo escaping of binary data
+            let mut data = Vec::with_capacity(raw.len());
+            let mut iter = raw.into_iter();
+            while let Some(c) = iter.next() {
+                if c == b'}' {
+                    if let Some(e) = iter.next() {
+                        data.push(e ^ 0x20);
+                    }
+                } else {
+                    data.push(c);
+                }
+            }
+
+            return Ok(Some(Some(data)));
+        }
+    }
+
+    fn send(&mut self, data: &str) -> io::Result<()> {
+        let packet = format!("${}#{:02x}", data, checksum(data.as_bytes()));
+        self.stream.set_nonblocking(false)?;
+        self.stream.write_all(packet.as_bytes())
+    }
+
+    fn stop(&mut self, reply: String) -> io::Result<()> {
+        self.running = false;
+        self.send(&reply)?;
+        self.last_stop = reply;
+        Ok(())
+    }
+
+    fn stop_reply(reason: StopReason) -> String {
+        match reason {
+            StopReason::Watchpoint(hit) => {
+                let kind = if hit.kind == Watch::Write {
+                    "watch"
+                } else {
+                    "rwatch"
+                };
+                format!("T{:02x}{}:{:x};", SIGTRAP, kind, hit.address)
+            }
+            _ => format!("S{:02x}", SIGTRAP),
+        }
+    }
+
+    fn read_registers(dmd: &Dmd) -> String {
+        (0..REGISTER_COUNT as u8).map(|r| format!("{:08x}", dmd.get_register(r))).collect()
+    }
+
+    // Reads must not disturb devices, e.g. pop the DUART's receiver
+    fn read_memory(dmd: &Dmd, addr: u32, len: u32) -> Option<String> {
+        let mut buf = vec![0; len as usize];
+        dmd.peek_memory(addr as usize, &mut buf).ok()?;
+        Some(to_hex(&buf))
+    }
+
+    fn watchpoint(kind: u8, addr: u32, len: u32) -> Option<Watchpoint> {
+        let kind = match kind {
+            b'2' => Watch::Write,
+            b'3' => Watch::Read,
+            b'4' => Watch::Access,
+            _ => return None,
+        };
+        Some(Watchpoint {
+            addresses: addr..addr.wrapping_add(len.max(1)),
+            kind,
+        })
+    }
+
+    // Insert or remove a breakpoint or watchpoint.
+    fn set_point(dmd: &mut Dmd, insert: bool, args: &[u8]) -> Option<&'static str> {
+        let kind = *args.first()?;
+        let (addr, len, _) = parse_addr_len(args.get(2..)?)?;
+
+        match kind {
+            b'0' | b'1' if insert => dmd.add_breakpoint(Breakpoint::new(addr)),
+            b'0' | b'1' => {
+                dmd.remove_breakpoint(addr);
+            }
+            _ => {
+                let wp = Self::watchpoint(kind, addr, len)?;
+                if insert {
+                    dmd.add_watchpoint(wp);
+                } else {
+                    dmd.remove_watchpoint(&wp.addresses);
+                }
+            }
+        }
+
+        Some("OK")
+    }
+
+    // Handle one packet. Returns false when the session ends.
+    fn handle(&mut self, dmd: &mut Dmd, packet: &[u8]) -> io::Result<bool> {
+        let (cmd, args) = match packet.split_first() {
+            Some((c, a)) => (*c, a),
+            None => return self.send("").map(|_| true),
+        };
+
+        let reply: String = match cmd {
+            b'?' => self.last_stop.clone(),
+            b'g' => Self::read_registers(dmd),
+            b'G' => match parse_hex_bytes(args) {
+                Some(ref data) if data.len() == REGISTER_COUNT * 4 => {
+                    for (r, w) in data.chunks(4).enumerate() {
+                        dmd.set_register(r as u8, u32::from_be_bytes([w[0], w[1], w[2], w[3]]));
+                    }
+                    String::from("OK")
+                }
+                _ => String::from("E01"),
+            },
+            b'p' => match parse_hex(args) {
+                Some(r) if (r as usize) < REGISTER_COUNT => format!("{:08x}", dmd.get_register(r as u8)),
+                _ => String::from("E01"),
+            },
+            b'P' => {
+                let eq = args.iter().position(|c| *c == b'=').unwrap_or(args.len());
+                match (parse_hex(&args[..eq]), args.get(eq + 1..).and_then(parse_hex)) {
+                    (Some(r), Some(val)) if (r as usize) < REGISTER_COUNT => {
+                        dmd.set_register(r as u8, val);
+                        String::from("OK")
+                    }
+                    _ => String::from("E01"),
+                }
+            }
+            b'm' => parse_addr_len(args)
+                .filter(|(_, len, _)| (*len as usize) <= PACKET_SIZE / 2)
+                .and_then(|(addr, len, _)| Self::read_memory(dmd, addr, len))
+                .unwrap_or_else(|| String::from("E01")),
+            b'M' => match parse_addr_len(args) {
+                Some((addr, len, hex)) => match parse_hex_bytes(hex) {
+                    Some(ref data) if data.len() == len as usize && dmd.write_memory(addr as usize, data).is_ok() => {
+                        String::from("OK")
+                    }
+                    _ => String::from("E01"),
+                },
+                None => String::from("E01"),
+            },
+            b'c' | b's' => {
+                if let Some(addr) = parse_hex(args) {
+                    dmd.set_register(15, addr);
+                }
+                if cmd == b'c' {
+                    self.running = true;
+                    return Ok(true);
+                }
+                let reply = Self::stop_reply(dmd.run_until_break(1));
+                self.last_stop = reply.clone();
+                reply
+            }
+            b'Z' | b'z' => Self::set_point(dmd, cmd == b'Z', args).unwrap_or("").to_string(),
+            b'H' => String::from("OK"),
+            b'T' => String::from("OK"),
+            b'q' => {
+                if args.starts_with(b"Supported") {
+                    format!("PacketSize={:x}", PACKET_SIZE)
+                } else if args == b"Attached" {
+                    String::from("1")
+                } else if args == b"C" {
+                    String::from("QC1")
+                } else if args == b"fThreadInfo" {
+                    String::from("m1")
+                } else if args == b"sThreadInfo" {
+                    String::from("l")
+                } else {
+                    String::new()
+                }
+            }
+            b'D' => {
+                self.send("OK")?;
+                return Ok(false);
+            }
+            b'k' => return Ok(false),
+            _ => String::new(),
+        };
+
+        self.send(&reply)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rom::image_with_program;
+
+    use std::thread;
+
+    #[test]
+    fn parses_packet_fields() {
+        assert_eq!(Some(0x700100), parse_hex(b"700100"));
+        assert_eq!(None, parse_hex(b"70010g"));
+        assert_eq!(Some(vec![0x12, 0xab]), parse_hex_bytes(b"12ab"));
+        assert_eq!(None, parse_hex_bytes(b"12a"));
+        assert_eq!(Some((0x200, 4, &b""[..])), parse_addr_len(b"200,4"));
+        assert_eq!(Some((0x700000, 2, &b"beef"[..])), parse_addr_len(b"700000,2:beef"));
+        assert_eq!(0x37, checksum(b"qSupported"));
+    }
+
+    struct Client {
+        stream: TcpStream,
+    }
+
+    impl Client {
+        fn send(&mut self, data: &str) {
+            let packet = format!("${}#{:02x}", data, checksum(data.as_bytes()));
+            self.stream.write_all(packet.as_bytes()).unwrap();
+        }
+
+        // Read one reply packet, skipping acknowledgements.
+        fn reply(&mut self) -> String {
+            let mut data = Vec::new();
+            let mut byte = [0u8; 1];
+            loop {
+                self.stream.read_exact(&mut byte).unwrap();
+                if byte[0] == b'$' {
+                    break;
+                }
+            }
+            loop {
+                self.stream.read_exact(&mut byte).unwrap();
+                if byte[0] == b'#' {
+                    break;
+                }
+                data.push(byte[0]);
+            }
+            let mut sum = [0u8; 2];
+            self.stream.read_exact(&mut sum).unwrap();
+            assert_eq!(Some(u32::from(checksum(&data))), parse_hex(&sum));
+            self.stream.write_all(b"+").unwrap();
+            String::from_utf8(data).unwrap()
+        }
+
+        fn command(&mut self, data: &str) -> String {
+            self.send(data);
+            self.reply()
+        }
+    }
+
+    #[test]
+    fn serves_a_debugger_session() {
+        let program = [
+            0x84, 0x01, 0x40, // 200: MOVW &1,%r0
+            0x9c, 0x01, 0x40, // 203: ADDW2 &1,%r0
+            0x84, 0x40, 0x7f, 0x00, 0x01, 0x70, 0x00, // 206: MOVW %r0,$0x700100
+            0x7b, 0xf6, // 20d: BRB 0x203
+        ];
+        let image = image_with_program(&program);
+
+        let server = GdbServer::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+
+        let target = thread::spawn(move || {
+            let mut dmd = Dmd::new_without_rom();
+            dmd.load_rom(&image).unwrap();
+            dmd.reset().unwrap();
+            server.serve(&mut dmd).unwrap();
+        });
+
+        let mut gdb = Client {
+            stream: TcpStream::connect(addr).unwrap(),
+        };
+
+        assert_eq!("PacketSize=1000", gdb.command("qSupported:swbreak+"));
+        assert_eq!("S05", gdb.command("?"));
+
+        let regs = gdb.command("g");
+        assert_eq!(REGISTER_COUNT * 8, regs.len());
+        assert_eq!("00000200", &regs[15 * 8..]);
+        assert_eq!("840140", gdb.command("m200,3"));
+
+        assert_eq!("OK", gdb.command("Z0,206,1"));
+        gdb.send("c");
+        assert_eq!("S05", gdb.reply());
+        assert_eq!("00000206", gdb.command("pf"));
+        assert_eq!("00000002", gdb.command("p0"));
+
+        assert_eq!("S05", gdb.command("s"));
+        assert_eq!("0000020d", gdb.command("pf"));
+        assert_eq!("00000002", gdb.command("m700100,4"));
+
+        assert_eq!("OK", gdb.command("z0,206,1"));
+        assert_eq!("OK", gdb.command("Z2,700100,4"));
+        gdb.send("c");
+        assert_eq!("T05watch:700100;", gdb.reply());
+        assert_eq!("00000003", gdb.command("m700100,4"));
+
+        assert_eq!("OK", gdb.command("P0=00000010"));
+        assert_eq!("OK", gdb.command("M700200,2:beef"));
+        assert_eq!("beef", gdb.command("m700200,2"));
+        assert_eq!("E01", gdb.command("M0,1:ff"));
+
+        assert_eq!("OK", gdb.command("D"));
+        target.join().unwrap();
+    }
+
+    #[test]
+    fn reads_memory_without_side_effects() {
+        let server = GdbServer::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+
+        let target = thread::spawn(move || {
+            let mut dmd = Dmd::new_without_rom();
+            server.serve(&mut dmd).unwrap();
+        });
+
+        let mut gdb = Client {
+            stream: TcpStream::connect(addr).unwrap(),
+        };
+
+        // MR1A and MR2A share an address, with a pointer that
+        // advances on every access.
+        assert_eq!("OK", gdb.command("M200003,1:13"));
+        assert_eq!("OK", gdb.command("M200003,1:07"));
+        assert_eq!("13", gdb.command("m200003,1"));
+        assert_eq!("13", gdb.command("m200003,1"));
+
+        assert_eq!("E01", gdb.command("m400000,4"));
+        assert_eq!("E01", gdb.command("M400000,1:ff"));
+
+        assert_eq!("OK", gdb.command("D"));
+        target.join().unwrap();
+    }
+
+    #[test]
+    fn interrupts_a_running_target() {
+        let image = image_with_program(&[0x7b, 0x00]); // BRB 0x200
+
+        let server = GdbServer::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+
+        let target = thread::spawn(move || {
+            let mut dmd = Dmd::new_without_rom();
+            dmd.load_rom(&image).unwrap();
+            dmd.reset().unwrap();
+            server.serve(&mut dmd).unwrap();
+        });
+
+        let mut gdb = Client {
+            stream: TcpStream::connect(addr).unwrap(),
+        };
+
+        gdb.send("c");
+        thread::sleep(Duration::from_millis(50));
+        gdb.stream.write_all(&[0x03]).unwrap();
+        assert_eq!("S02", gdb.reply());
+        assert_eq!("S02", gdb.command("?"));
+
+        gdb.send("k");
+        target.join().unwrap();
+    }
+}
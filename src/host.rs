@@ -0,0 +1,699 @@
+use crate::dmd::Dmd;
+
+use std::collections::VecDeque;
+use std::ffi::CStr;
+use std::fs::{File, OpenOptions};
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+//
+// Host Connection
+//
+// A Pty connects the terminal's RS-232 port to the master side of a
+// Unix pseudo-terminal. Whatever holds the slave side, typically a
+// shell started by `spawn`, sees the emulated 5620 as its terminal.
+// A TcpLine connects it to a TCP socket instead, either as a raw
+// byte stream or as a telnet client.
+//
+// Both are driven by calling `poll` between runs of the emulator.
+// Characters from the host are handed to the DUART no faster than
+// the configured baud rate allows, and never faster than its receive
+// FIFO can take them when that is enabled.
+//
+
+const BUFFER_SIZE: usize = 0x1000;
+const DEFAULT_BAUD: u32 = 9600;
+
+// The terminfo name of the 5620
+const TERM: &str = "dmd";
+
+// The host's side of the RS-232 line. A None in the input is a
+// BREAK.
+struct Line {
+    char_time: Duration,
+    next_rx: Instant,
+    input: VecDeque<Option<u8>>,
+    output: VecDeque<u8>,
+}
+
+impl Line {
+    fn new() -> Line {
+        let mut line = Line {
+            char_time: Duration::default(),
+            next_rx: Instant::now(),
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+        };
+        line.set_baud(DEFAULT_BAUD);
+        line
+    }
+
+    fn set_baud(&mut self, baud: u32) {
+        self.char_time = match baud {
+            0 => Duration::default(),
+            _ => Duration::from_nanos(10_000_000_000 / u64::from(baud)),
+        };
+    }
+
+    // Call before adding input. Time spent with nothing to send
+    // doesn't let later input go faster.
+    fn idle(&mut self) {
+        if self.input.is_empty() {
+            self.next_rx = self.next_rx.max(Instant::now());
+        }
+    }
+
+    fn deliver(&mut self, dmd: &mut Dmd) {
+        let now = Instant::now();
+
+        while self.next_rx <= now && dmd.rx_space() > 0 {
+            match self.input.pop_front() {
+                Some(Some(c)) => dmd.rx_char(c),
+                Some(None) => dmd.rx_break(),
+                None => break,
+            }
+            self.next_rx += self.char_time;
+        }
+    }
+
+    fn collect(&mut self, dmd: &mut Dmd) {
+        while let Some(c) = dmd.rs232_tx_poll() {
+            self.output.push_back(c);
+        }
+    }
+
+    // Write as much output as `w` will take without blocking.
+    fn write_to<W: Write>(&mut self, w: &mut W) -> io::Result<()> {
+        while !self.output.is_empty() {
+            let (data, _) = self.output.as_slices();
+            match w.write(data) {
+                Ok(0) => return Err(io::Error::new(ErrorKind::WriteZero, "host closed")),
+                Ok(n) => {
+                    self.output.drain(..n);
+                }
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One end of a pseudo-terminal attached to the RS-232 port.
+pub struct Pty {
+    master: File,
+    slave_name: String,
+    child: Option<Child>,
+    line: Line,
+}
+
+fn os_error<T>(result: T, failed: bool) -> io::Result<T> {
+    if failed {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(result)
+    }
+}
+
+impl Pty {
+    /// Open a new pseudo-terminal. Nothing is attached to its slave
+    /// side until something opens `slave_name`.
+    pub fn open() -> io::Result<Pty> {
+        let fd = unsafe { libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY) };
+        let fd = os_error(fd, fd < 0)?;
+        // Owning the descriptor first closes it on any error below.
+        let master = unsafe { File::from_raw_fd(fd) };
+
+        unsafe {
+            os_error((), libc::grantpt(fd) < 0)?;
+            os_error((), libc::unlockpt(fd) < 0)?;
+        }
+
+        // ptsname's buffer is only good until the next call.
+        let name = unsafe { libc::ptsname(fd) };
+        let name = os_error(name, name.is_null())?;
+        let slave_name = unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned();
+
+        unsafe {
+            let flags = libc::fcntl(fd, libc::F_GETFL);
+            let flags = os_error(flags, flags < 0)?;
+            os_error((), libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) < 0)?;
+        }
+
+        Ok(Pty {
+            master,
+            slave_name,
+            child: None,
+            line: Line::new(),
+        })
+    }
+
+    /// Open a new pseudo-terminal and start `shell` on it, as the
+    /// leader of a new session with the pseudo-terminal as its
+    /// controlling terminal.
+    pub fn spawn(shell: &str, args: &[&str]) -> io::Result<Pty> {
+        let mut pty = Pty::open()?;
+
+        let slave = OpenOptions::new().read(true).write(true).open(&pty.slave_name)?;
+        let mut command = Command::new(shell);
+        command
+            .args(args)
+            .env("TERM", TERM)
+            .stdin(Stdio::from(slave.try_clone()?))
+            .stdout(Stdio::from(slave.try_clone()?))
+            .stderr(Stdio::from(slave));
+
+        unsafe {
+            command.pre_exec(|| {
+                os_error((), libc::setsid() < 0)?;
+                os_error((), libc::ioctl(0, libc::TIOCSCTTY as _, 0) < 0)
+            });
+        }
+
+        pty.child = Some(command.spawn()?);
+        Ok(pty)
+    }
+
+    /// The path of the slave side, such as /dev/pts/3.
+    pub fn slave_name(&self) -> &str {
+        &self.slave_name
+    }
+
+    /// Pace the characters sent to the terminal as though they came
+    /// over a line at `baud`, with ten bits to a character. Zero
+    /// removes the limit.
+    pub fn set_baud(&mut self, baud: u32) {
+        self.line.set_baud(baud);
+    }
+
+    /// Move characters between the pseudo-terminal and the RS-232
+    /// port. Returns false once the shell started by `spawn` has
+    /// exited and everything it wrote has been delivered.
+    pub fn poll(&mut self, dmd: &mut Dmd) -> io::Result<bool> {
+        // Whatever the shell wrote before it exited is still there to
+        // be read.
+        let exited = match self.child.as_mut() {
+            Some(child) => child.try_wait()?.is_some(),
+            None => false,
+        };
+
+        self.line.idle();
+        self.read_host()?;
+        self.line.deliver(dmd);
+
+        self.line.collect(dmd);
+        match self.line.write_to(&mut self.master) {
+            // Nobody has the slave side open.
+            Err(ref e) if e.raw_os_error() == Some(libc::EIO) => self.line.output.clear(),
+            result => result?,
+        }
+
+        Ok(!(exited && self.line.input.is_empty()))
+    }
+
+    fn read_host(&mut self) -> io::Result<()> {
+        let mut buf = [0u8; BUFFER_SIZE];
+
+        loop {
+            match self.master.read(&mut buf) {
+                Ok(0) => return Ok(()),
+                Ok(n) => self.line.input.extend(buf[..n].iter().map(|c| Some(*c))),
+                // Reads fail with EIO while the slave side is closed.
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(ref e) if e.raw_os_error() == Some(libc::EIO) => return Ok(()),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl Drop for Pty {
+    fn drop(&mut self) {
+        if let Some(child) = self.child.as_mut() {
+            unsafe {
+                libc::kill(child.id() as libc::pid_t, libc::SIGHUP);
+            }
+            let _ = child.wait();
+        }
+    }
+}
+
+impl AsRawFd for Pty {
+    fn as_raw_fd(&self) -> RawFd {
+        self.master.as_raw_fd()
+    }
+}
+
+//
+// Telnet
+//
+// As a telnet client the terminal offers its type, DMD, and accepts
+// the server's echo, suppress go-ahead and binary options. Window
+// size and every other option are refused. A BREAK on the line is
+// sent and received as IAC BRK. Until binary mode is agreed, a CR
+// from the terminal goes as CR NUL, and the NUL after a CR from the
+// server is dropped.
+//
+
+const IAC: u8 = 255;
+const DONT: u8 = 254;
+const DO: u8 = 253;
+const WONT: u8 = 252;
+const WILL: u8 = 251;
+const SB: u8 = 250;
+const BRK: u8 = 243;
+const SE: u8 = 240;
+
+const OPT_BINARY: u8 = 0;
+const OPT_ECHO: u8 = 1;
+const OPT_SGA: u8 = 3;
+const OPT_TTYPE: u8 = 24;
+
+const TTYPE_IS: u8 = 0;
+const TTYPE_SEND: u8 = 1;
+
+const TERMINAL_TYPE: &[u8] = b"DMD";
+
+// Longest subnegotiation kept
+const SB_LIMIT: usize = 64;
+
+/// How a TcpLine talks to the far end.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Transport {
+    /// Bytes are passed through unchanged.
+    Raw,
+    /// The far end is a telnet server.
+    Telnet,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TelnetState {
+    Data,
+    Cr,
+    Iac,
+    Option(u8),
+    Sub,
+    SubIac,
+}
+
+struct Telnet {
+    state: TelnetState,
+    local: [bool; 256],
+    remote: [bool; 256],
+    sub: Vec<u8>,
+}
+
+impl Telnet {
+    fn new() -> Telnet {
+        Telnet {
+            state: TelnetState::Data,
+            local: [false; 256],
+            remote: [false; 256],
+            sub: Vec::new(),
+        }
+    }
+
+    /// The negotiation that opens a session.
+    fn start(&mut self) -> Vec<u8> {
+        self.local[OPT_TTYPE as usize] = true;
+        vec![IAC, WILL, OPT_TTYPE]
+    }
+
+    /// Decode bytes from the server into line input, appending any
+    /// replies to `reply`.
+    fn receive(&mut self, data: &[u8], input: &mut VecDeque<Option<u8>>, reply: &mut Vec<u8>) {
+        for &c in data {
+            self.state = match self.state {
+                TelnetState::Data | TelnetState::Cr if c == IAC => TelnetState::Iac,
+                TelnetState::Cr if c == 0 => TelnetState::Data,
+                TelnetState::Data | TelnetState::Cr => {
+                    input.push_back(Some(c));
+                    if c == b'\r' && !self.remote[OPT_BINARY as usize] {
+                        TelnetState::Cr
+                    } else {
+                        TelnetState::Data
+                    }
+                }
+                TelnetState::Iac => match c {
+                    IAC => {
+                        input.push_back(Some(IAC));
+                        TelnetState::Data
+                    }
+                    DO | DONT | WILL | WONT => TelnetState::Option(c),
+                    SB => {
+                        self.sub.clear();
+                        TelnetState::Sub
+                    }
+                    BRK => {
+                        input.push_back(None);
+                        TelnetState::Data
+                    }
+                    _ => TelnetState::Data,
+                },
+                TelnetState::Option(command) => {
+                    self.negotiate(command, c, reply);
+                    TelnetState::Data
+                }
+                TelnetState::Sub if c == IAC => TelnetState::SubIac,
+                TelnetState::Sub => {
+                    if self.sub.len() < SB_LIMIT {
+                        self.sub.push(c);
+                    }
+                    TelnetState::Sub
+                }
+                TelnetState::SubIac => match c {
+                    SE => {
+                        self.subnegotiate(reply);
+                        TelnetState::Data
+                    }
+                    _ => {
+                        if self.sub.len() < SB_LIMIT {
+                            self.sub.push(c);
+                        }
+                        TelnetState::Sub
+                    }
+                },
+            };
+        }
+    }
+
+    // Agree to options we support, refuse the rest, and only answer
+    // a request that changes an option's state, so that the two ends
+    // can't loop.
+    fn negotiate(&mut self, command: u8, option: u8, reply: &mut Vec<u8>) {
+        let index = option as usize;
+
+        match command {
+            DO => {
+                if option == OPT_TTYPE || option == OPT_BINARY {
+                    if !self.local[index] {
+                        self.local[index] = true;
+                        reply.extend_from_slice(&[IAC, WILL, option]);
+                    }
+                } else {
+                    reply.extend_from_slice(&[IAC, WONT, option]);
+                }
+            }
+            DONT => {
+                if self.local[index] {
+                    self.local[index] = false;
+                    reply.extend_from_slice(&[IAC, WONT, option]);
+                }
+            }
+            WILL => {
+                if option == OPT_ECHO || option == OPT_SGA || option == OPT_BINARY {
+                    if !self.remote[index] {
+                        self.remote[index] = true;
+                        reply.extend_from_slice(&[IAC, DO, option]);
+                    }
+                } else {
+                    reply.extend_from_slice(&[IAC, DONT, option]);
+                }
+            }
+            _ => {
+                if self.remote[index] {
+                    self.remote[index] = false;
+                    reply.extend_from_slice(&[IAC, DONT, option]);
+                }
+            }
+        }
+    }
+
+    fn subnegotiate(&mut self, reply: &mut Vec<u8>) {
+        if self.sub == [OPT_TTYPE, TTYPE_SEND] && self.local[OPT_TTYPE as usize] {
+            reply.extend_from_slice(&[IAC, SB, OPT_TTYPE, TTYPE_IS]);
+            reply.extend_from_slice(TERMINAL_TYPE);
+            reply.extend_from_slice(&[IAC, SE]);
+        }
+    }
+
+    /// Encode a character from the terminal.
+    fn send(&self, c: u8, out: &mut Vec<u8>) {
+        match c {
+            IAC => out.extend_from_slice(&[IAC, IAC]),
+            b'\r' if !self.local[OPT_BINARY as usize] => out.extend_from_slice(&[b'\r', 0]),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// A TCP connection attached to the RS-232 port.
+pub struct TcpLine {
+    stream: TcpStream,
+    telnet: Option<Telnet>,
+    line: Line,
+    tx_break: bool,
+}
+
+impl TcpLine {
+    /// Connect to a host listening at `addr`.
+    pub fn connect<A: ToSocketAddrs>(addr: A, transport: Transport) -> io::Result<TcpLine> {
+        TcpLine::new(TcpStream::connect(addr)?, transport)
+    }
+
+    /// Use an established connection.
+    pub fn new(stream: TcpStream, transport: Transport) -> io::Result<TcpLine> {
+        stream.set_nodelay(true)?;
+        stream.set_nonblocking(true)?;
+
+        let mut tcp = TcpLine {
+            stream,
+            telnet: None,
+            line: Line::new(),
+            tx_break: false,
+        };
+
+        if transport == Transport::Telnet {
+            let mut telnet = Telnet::new();
+            tcp.line.output.extend(telnet.start());
+            tcp.telnet = Some(telnet);
+        }
+
+        Ok(tcp)
+    }
+
+    /// Pace the characters sent to the terminal as though they came
+    /// over a line at `baud`, with ten bits to a character. Zero
+    /// removes the limit.
+    pub fn set_baud(&mut self, baud: u32) {
+        self.line.set_baud(baud);
+    }
+
+    /// Move characters between the socket and the RS-232 port.
+    /// Returns false once the far end has closed the connection.
+    /// A BREAK from the terminal can only be sent over telnet.
+    pub fn poll(&mut self, dmd: &mut Dmd) -> io::Result<bool> {
+        self.line.idle();
+        let open = self.read_host()?;
+        self.line.deliver(dmd);
+        if !open {
+            return Ok(false);
+        }
+
+        let mut out = Vec::new();
+        while let Some(c) = dmd.rs232_tx_poll() {
+            match self.telnet.as_ref() {
+                Some(telnet) => telnet.send(c, &mut out),
+                None => out.push(c),
+            }
+        }
+
+        let tx_break = dmd.rs232_tx_break();
+        if tx_break && !self.tx_break && self.telnet.is_some() {
+            out.extend_from_slice(&[IAC, BRK]);
+        }
+        self.tx_break = tx_break;
+
+        self.line.output.extend(out);
+        self.line.write_to(&mut self.stream)?;
+
+        Ok(true)
+    }
+
+    fn read_host(&mut self) -> io::Result<bool> {
+        let mut buf = [0u8; BUFFER_SIZE];
+
+        loop {
+            match self.stream.read(&mut buf) {
+                Ok(0) => return Ok(false),
+                Ok(n) => match self.telnet.as_mut() {
+                    Some(telnet) => {
+                        let mut reply = Vec::new();
+                        telnet.receive(&buf[..n], &mut self.line.input, &mut reply);
+                        self.line.output.extend(reply);
+                    }
+                    None => self.line.input.extend(buf[..n].iter().map(|c| Some(*c))),
+                },
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => return Ok(true),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll_until<F: Fn(&Dmd) -> bool>(pty: &mut Pty, dmd: &mut Dmd, done: F) -> bool {
+        for _ in 0..500 {
+            pty.poll(dmd).unwrap();
+            if done(dmd) {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[test]
+    fn delivers_slave_output_at_baud_rate() {
+        let mut dmd = Dmd::new();
+        dmd.set_rx_fifo(true);
+
+        let mut pty = Pty::open().unwrap();
+        pty.set_baud(300);
+        let mut slave = OpenOptions::new().read(true).write(true).open(pty.slave_name()).unwrap();
+        slave.write_all(b"abc").unwrap();
+
+        assert!(poll_until(&mut pty, &mut dmd, |dmd| dmd.rx_space() < 4));
+        // One character goes at once; the next is 33ms behind it.
+        assert_eq!(3, dmd.rx_space());
+        assert!(poll_until(&mut pty, &mut dmd, |dmd| dmd.rx_space() == 2));
+    }
+
+    fn decode(telnet: &mut Telnet, data: &[u8]) -> (Vec<Option<u8>>, Vec<u8>) {
+        let mut input = VecDeque::new();
+        let mut reply = Vec::new();
+        telnet.receive(data, &mut input, &mut reply);
+        (input.into_iter().collect(), reply)
+    }
+
+    #[test]
+    fn telnet_decodes_commands() {
+        let mut telnet = Telnet::new();
+
+        let (input, reply) = decode(&mut telnet, &[b'a', IAC, IAC, b'\r', 0, b'b', IAC, BRK, IAC, 241]);
+        assert_eq!(vec![Some(b'a'), Some(IAC), Some(b'\r'), Some(b'b'), None], input);
+        assert!(reply.is_empty());
+
+        // Commands may be split across reads.
+        let (input, _) = decode(&mut telnet, &[IAC]);
+        assert!(input.is_empty());
+        let (input, _) = decode(&mut telnet, &[IAC]);
+        assert_eq!(vec![Some(IAC)], input);
+    }
+
+    #[test]
+    fn telnet_negotiates_options() {
+        let mut telnet = Telnet::new();
+        assert_eq!(vec![IAC, WILL, OPT_TTYPE], telnet.start());
+
+        let (_, reply) = decode(&mut telnet, &[IAC, DO, OPT_TTYPE, IAC, WILL, OPT_ECHO, IAC, DO, 31, IAC, WILL, 5]);
+        assert_eq!(vec![IAC, DO, OPT_ECHO, IAC, WONT, 31, IAC, DONT, 5], reply);
+
+        // Requests that change nothing go unanswered.
+        let (_, reply) = decode(&mut telnet, &[IAC, WILL, OPT_ECHO, IAC, WONT, 5]);
+        assert!(reply.is_empty());
+
+        let (_, reply) = decode(&mut telnet, &[IAC, SB, OPT_TTYPE, TTYPE_SEND, IAC, SE]);
+        assert_eq!(vec![IAC, SB, OPT_TTYPE, TTYPE_IS, b'D', b'M', b'D', IAC, SE], reply);
+    }
+
+    #[test]
+    fn telnet_encodes_terminal_output() {
+        let mut telnet = Telnet::new();
+        let mut out = Vec::new();
+        for c in &[b'\r', IAC, b'x'] {
+            telnet.send(*c, &mut out);
+        }
+        assert_eq!(vec![b'\r', 0, IAC, IAC, b'x'], out);
+
+        decode(&mut telnet, &[IAC, DO, OPT_BINARY]);
+        out.clear();
+        telnet.send(b'\r', &mut out);
+        assert_eq!(vec![b'\r'], out);
+    }
+
+    fn read_some(stream: &mut TcpStream, len: usize) -> Vec<u8> {
+        let mut data = vec![0; len];
+        stream.read_exact(&mut data).unwrap();
+        data
+    }
+
+    fn poll_until_tcp<F: Fn(&Dmd) -> bool>(tcp: &mut TcpLine, dmd: &mut Dmd, done: F) -> bool {
+        for _ in 0..500 {
+            if !tcp.poll(dmd).unwrap() {
+                return false;
+            }
+            if done(dmd) {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[test]
+    fn telnet_line_talks_to_server() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut dmd = Dmd::new();
+        dmd.set_rx_fifo(true);
+
+        let mut tcp = TcpLine::connect(listener.local_addr().unwrap(), Transport::Telnet).unwrap();
+        tcp.set_baud(0);
+        let (mut server, _) = listener.accept().unwrap();
+        server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+        tcp.poll(&mut dmd).unwrap();
+        assert_eq!(vec![IAC, WILL, OPT_TTYPE], read_some(&mut server, 3));
+
+        server.write_all(&[IAC, SB, OPT_TTYPE, TTYPE_SEND, IAC, SE, b'o', b'k', IAC, BRK]).unwrap();
+        assert!(poll_until_tcp(&mut tcp, &mut dmd, |dmd| dmd.rx_space() == 1));
+        assert_eq!(vec![IAC, SB, OPT_TTYPE, TTYPE_IS, b'D', b'M', b'D', IAC, SE], read_some(&mut server, 9));
+
+        drop(server);
+        let mut polls = 0;
+        while tcp.poll(&mut dmd).unwrap() {
+            polls += 1;
+            assert!(polls < 500);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn raw_line_passes_bytes_through() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut dmd = Dmd::new();
+        dmd.set_rx_fifo(true);
+
+        let mut tcp = TcpLine::connect(listener.local_addr().unwrap(), Transport::Raw).unwrap();
+        tcp.set_baud(0);
+        let (mut server, _) = listener.accept().unwrap();
+        server.write_all(&[IAC, BRK]).unwrap();
+
+        assert!(poll_until_tcp(&mut tcp, &mut dmd, |dmd| dmd.rx_space() == 2));
+    }
+
+    #[test]
+    fn session_ends_when_shell_exits() {
+        let mut dmd = Dmd::new();
+        dmd.set_rx_fifo(true);
+
+        let mut pty = Pty::spawn("/bin/sh", &["-c", "printf $TERM"]).unwrap();
+        pty.set_baud(0);
+
+        let mut polls = 0;
+        while pty.poll(&mut dmd).unwrap() {
+            polls += 1;
+            assert!(polls < 500);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        // "dmd" fills three of the FIFO's four places.
+        assert_eq!(1, dmd.rx_space());
+    }
+}
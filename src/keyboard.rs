@@ -0,0 +1,406 @@
+use std::collections::{HashMap, VecDeque};
+
+//
+// Keyboard Control Bytes
+//
+// Everything the terminal sends to the keyboard is a single control
+// byte holding the whole keyboard state. The firmware sets the status
+// request bit to ask the keyboard to identify itself, and the alarm
+// bit to sound the bell. The click bit enables the key click. The
+// firmware leaves the other bits clear, but programs may use them to
+// drive the keyboard's lights.
+//
+pub const CTL_STATUS: u8 = 0x02;
+pub const CTL_ALARM: u8 = 0x08;
+pub const CTL_CLICK: u8 = 0x10;
+pub const CTL_LIGHTS: u8 = !(CTL_STATUS | CTL_ALARM | CTL_CLICK);
+
+/// The keyboard's answer to a status request when no host has set
+/// another. The firmware reads bits 2 and 4 of the reply; this is the
+/// value that leaves its lock indicators off.
+pub const DEFAULT_STATUS: u8 = 0x14;
+
+/// A change in the keyboard's state, for the host to act on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyboardEvent {
+    /// Sound the bell.
+    Alarm,
+    /// Key click was turned on or off.
+    Click(bool),
+    /// The lights changed to the given CTL_LIGHTS bits.
+    Lights(u8),
+}
+
+/// The keyboard attached to DUART port B.
+#[derive(Debug)]
+pub struct Keyboard {
+    status: u8,
+    click: bool,
+    lights: u8,
+    events: VecDeque<KeyboardEvent>,
+}
+
+impl Default for Keyboard {
+    fn default() -> Self {
+        Keyboard::new()
+    }
+}
+
+impl Keyboard {
+    pub fn new() -> Keyboard {
+        Keyboard {
+            status: DEFAULT_STATUS,
+            click: false,
+            lights: 0,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Handle a control byte from the terminal. Returns the status
+    /// byte the keyboard sends back, if one was requested.
+    pub fn control(&mut self, val: u8) -> Option<u8> {
+        if val & CTL_ALARM != 0 {
+            self.events.push_front(KeyboardEvent::Alarm);
+        }
+
+        let click = val & CTL_CLICK != 0;
+        if click != self.click {
+            self.click = click;
+            self.events.push_front(KeyboardEvent::Click(click));
+        }
+
+        let lights = val & CTL_LIGHTS;
+        if lights != self.lights {
+            self.lights = lights;
+            self.events.push_front(KeyboardEvent::Lights(lights));
+        }
+
+        if val & CTL_STATUS != 0 {
+            Some(self.status)
+        } else {
+            None
+        }
+    }
+
+    /// Set the byte sent in reply to status requests.
+    pub fn set_status(&mut self, status: u8) {
+        self.status = status;
+    }
+
+    pub fn status(&self) -> u8 {
+        self.status
+    }
+
+    pub fn click(&self) -> bool {
+        self.click
+    }
+
+    pub fn lights(&self) -> u8 {
+        self.lights
+    }
+
+    /// Take the oldest state change not yet seen by the host.
+    pub fn poll(&mut self) -> Option<KeyboardEvent> {
+        self.events.pop_back()
+    }
+}
+
+//
+// Keymap
+//
+// The keyboard sends ASCII for the keys that have it, applying Shift,
+// Control and Caps Lock itself, and codes with the high bit set for
+// the rest. The firmware translates these through a table at 0xf29c.
+// Most of the special keys send one code unshifted and another
+// shifted, which the firmware treats alike. Shift-Setup resets the
+// terminal instead, and Shift-Break is Discon.
+//
+// The keypad's 0-3, "." and "-" keys double as cursor keys until the
+// Num Lock key switches the firmware to digits.
+//
+
+/// Modifier bits for `Keymap::translate`.
+pub const MOD_SHIFT: u8 = 0x01;
+pub const MOD_CTRL: u8 = 0x02;
+pub const MOD_CAPS: u8 = 0x04;
+
+/// A key, as a host front end sees it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Key {
+    /// A key that sends the given ASCII character unshifted.
+    Char(u8),
+    /// Function keys F1 to F8.
+    F(u8),
+    Setup,
+    Break,
+    Discon,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    HomeDown,
+    Clear,
+    Return,
+    Tab,
+    Backspace,
+    Escape,
+    Delete,
+    LineFeed,
+    /// A keypad key: a digit, '.' or '-'.
+    Keypad(u8),
+    NumLock,
+}
+
+// Identifiers for the keys that don't send ASCII, as used by the C API.
+const KEY_F1: u32 = 0x101;
+const KEY_F8: u32 = 0x108;
+const KEY_SETUP: u32 = 0x110;
+const KEY_BREAK: u32 = 0x111;
+const KEY_DISCON: u32 = 0x112;
+const KEY_UP: u32 = 0x120;
+const KEY_DOWN: u32 = 0x121;
+const KEY_LEFT: u32 = 0x122;
+const KEY_RIGHT: u32 = 0x123;
+const KEY_HOME: u32 = 0x124;
+const KEY_HOME_DOWN: u32 = 0x125;
+const KEY_CLEAR: u32 = 0x126;
+const KEY_KEYPAD: u32 = 0x130;
+const KEY_KEYPAD_DOT: u32 = 0x13a;
+const KEY_KEYPAD_MINUS: u32 = 0x13b;
+const KEY_NUM_LOCK: u32 = 0x13c;
+
+impl Key {
+    /// Find the key for an identifier. Identifiers below 0x80 are
+    /// ASCII, with Return, Tab, Backspace, Escape, Delete and Line
+    /// Feed mapped to their own keys. F1-F8 are 0x101-0x108, Setup,
+    /// Break and Discon 0x110-0x112, Up, Down, Left, Right, Home,
+    /// Home Down and Clear 0x120-0x126, keypad 0-9 0x130-0x139, keypad
+    /// "." and "-" 0x13a and 0x13b, and Num Lock 0x13c.
+    pub fn from_id(id: u32) -> Option<Key> {
+        let key = match id {
+            0x0d => Key::Return,
+            0x09 => Key::Tab,
+            0x08 => Key::Backspace,
+            0x1b => Key::Escape,
+            0x7f => Key::Delete,
+            0x0a => Key::LineFeed,
+            0..=0x7f => Key::Char(id as u8),
+            KEY_F1..=KEY_F8 => Key::F((id - KEY_F1 + 1) as u8),
+            KEY_SETUP => Key::Setup,
+            KEY_BREAK => Key::Break,
+            KEY_DISCON => Key::Discon,
+            KEY_UP => Key::Up,
+            KEY_DOWN => Key::Down,
+            KEY_LEFT => Key::Left,
+            KEY_RIGHT => Key::Right,
+            KEY_HOME => Key::Home,
+            KEY_HOME_DOWN => Key::HomeDown,
+            KEY_CLEAR => Key::Clear,
+            KEY_KEYPAD..=0x139 => Key::Keypad(b'0' + (id - KEY_KEYPAD) as u8),
+            KEY_KEYPAD_DOT => Key::Keypad(b'.'),
+            KEY_KEYPAD_MINUS => Key::Keypad(b'-'),
+            KEY_NUM_LOCK => Key::NumLock,
+            _ => return None,
+        };
+        Some(key)
+    }
+}
+
+/// The character a US keyboard produces for `c` with Shift held.
+fn shifted(c: u8) -> u8 {
+    match c {
+        b'a'..=b'z' => c.to_ascii_uppercase(),
+        b'1' => b'!',
+        b'2' => b'@',
+        b'3' => b'#',
+        b'4' => b'$',
+        b'5' => b'%',
+        b'6' => b'^',
+        b'7' => b'&',
+        b'8' => b'*',
+        b'9' => b'(',
+        b'0' => b')',
+        b'-' => b'_',
+        b'=' => b'+',
+        b'[' => b'{',
+        b']' => b'}',
+        b'\\' => b'|',
+        b';' => b':',
+        b'\'' => b'"',
+        b',' => b'<',
+        b'.' => b'>',
+        b'/' => b'?',
+        b'`' => b'~',
+        _ => c,
+    }
+}
+
+/// Translates keys into the bytes the keyboard sends for them.
+#[derive(Clone, Debug)]
+pub struct Keymap {
+    keys: HashMap<Key, [Vec<u8>; 2]>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Keymap::new()
+    }
+}
+
+impl Keymap {
+    /// The keymap of the 5620 keyboard.
+    pub fn new() -> Keymap {
+        let mut keymap = Keymap::empty();
+
+        for n in 0..8 {
+            keymap.bind(Key::F(n + 1), &[0xe8 + n], &[0xc8 + n]);
+        }
+
+        keymap.bind(Key::Setup, &[0xae], &[0x8e]);
+        keymap.bind(Key::Break, &[0xaf], &[0x8f]);
+        keymap.bind(Key::Discon, &[0x8f], &[0x8f]);
+        keymap.bind(Key::Up, &[0x92], &[0x92]);
+        keymap.bind(Key::Down, &[0x90], &[0x90]);
+        keymap.bind(Key::Left, &[0x9a], &[0x9a]);
+        keymap.bind(Key::Right, &[0x9b], &[0x9b]);
+        keymap.bind(Key::Home, &[0x91], &[0x91]);
+        keymap.bind(Key::HomeDown, &[0x93], &[0x93]);
+        keymap.bind(Key::Clear, &[0xe5], &[0xe5]);
+        keymap.bind(Key::Return, &[0xe7], &[0xc7]);
+        keymap.bind(Key::Tab, &[0xf0], &[0xd0]);
+        keymap.bind(Key::Backspace, &[0xf1], &[0xd1]);
+        keymap.bind(Key::Escape, &[0xf3], &[0xe3]);
+        keymap.bind(Key::Delete, &[0xfe], &[0xde]);
+        keymap.bind(Key::LineFeed, &[0xf6], &[0xb6]);
+        keymap.bind(Key::NumLock, &[0xb2], &[0xf2]);
+
+        for (c, code) in [(b'0', 0xc2), (b'1', 0xc0), (b'2', 0xc1), (b'3', 0xc6), (b'.', 0xc3), (b'-', 0xc4)].iter() {
+            keymap.bind(Key::Keypad(*c), &[*code], &[*code]);
+        }
+        for n in 0..6 {
+            keymap.bind(Key::Keypad(b'4' + n), &[0x94 + n], &[0x94 + n]);
+        }
+
+        keymap
+    }
+
+    /// A keymap with no special keys, which sends ASCII only.
+    pub fn empty() -> Keymap {
+        Keymap {
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Make `key` send `plain`, or `shifted` with Shift held. Binding
+    /// a `Key::Char` overrides its ASCII translation.
+    pub fn bind(&mut self, key: Key, plain: &[u8], shifted: &[u8]) {
+        self.keys.insert(key, [plain.to_vec(), shifted.to_vec()]);
+    }
+
+    pub fn unbind(&mut self, key: Key) {
+        self.keys.remove(&key);
+    }
+
+    /// The bytes the keyboard sends for `key` with the MOD_* bits in
+    /// `modifiers`, or None if the key is not mapped.
+    pub fn translate(&self, key: Key, modifiers: u8) -> Option<Vec<u8>> {
+        let shift = modifiers & MOD_SHIFT != 0;
+
+        if let Some(codes) = self.keys.get(&key) {
+            return Some(codes[shift as usize].clone());
+        }
+
+        match key {
+            Key::Char(c) if c < 0x80 => {
+                let mut c = if shift {
+                    shifted(c)
+                } else {
+                    c
+                };
+                if modifiers & MOD_CAPS != 0 {
+                    c = c.to_ascii_uppercase();
+                }
+                if modifiers & MOD_CTRL != 0 && (0x40..0x80).contains(&c) {
+                    c &= 0x1f;
+                }
+                Some(vec![c])
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_status_requests() {
+        let mut kb = Keyboard::new();
+        assert_eq!(Some(DEFAULT_STATUS), kb.control(CTL_STATUS));
+
+        kb.set_status(0x04);
+        assert_eq!(Some(0x04), kb.control(CTL_STATUS | CTL_CLICK));
+        assert_eq!(None, kb.control(CTL_CLICK));
+    }
+
+    #[test]
+    fn reports_state_changes() {
+        let mut kb = Keyboard::new();
+        kb.control(CTL_CLICK);
+        kb.control(CTL_CLICK | CTL_ALARM);
+        kb.control(0x01);
+
+        assert!(!kb.click());
+        assert_eq!(0x01, kb.lights());
+        assert_eq!(Some(KeyboardEvent::Click(true)), kb.poll());
+        assert_eq!(Some(KeyboardEvent::Alarm), kb.poll());
+        assert_eq!(Some(KeyboardEvent::Click(false)), kb.poll());
+        assert_eq!(Some(KeyboardEvent::Lights(0x01)), kb.poll());
+        assert_eq!(None, kb.poll());
+    }
+
+    #[test]
+    fn translates_ascii_with_modifiers() {
+        let keymap = Keymap::new();
+        assert_eq!(Some(vec![b'a']), keymap.translate(Key::Char(b'a'), 0));
+        assert_eq!(Some(vec![b'A']), keymap.translate(Key::Char(b'a'), MOD_SHIFT));
+        assert_eq!(Some(vec![b'A']), keymap.translate(Key::Char(b'a'), MOD_CAPS));
+        assert_eq!(Some(vec![b'1']), keymap.translate(Key::Char(b'1'), MOD_CAPS));
+        assert_eq!(Some(vec![b'!']), keymap.translate(Key::Char(b'1'), MOD_SHIFT));
+        assert_eq!(Some(vec![0x03]), keymap.translate(Key::Char(b'c'), MOD_CTRL));
+        assert_eq!(Some(vec![0x1e]), keymap.translate(Key::Char(b'6'), MOD_CTRL | MOD_SHIFT));
+    }
+
+    #[test]
+    fn translates_special_keys() {
+        let keymap = Keymap::new();
+        assert_eq!(Some(vec![0xe8]), keymap.translate(Key::F(1), 0));
+        assert_eq!(Some(vec![0xcf]), keymap.translate(Key::F(8), MOD_SHIFT));
+        assert_eq!(Some(vec![0x92]), keymap.translate(Key::Up, MOD_CTRL));
+        assert_eq!(Some(vec![0x97]), keymap.translate(Key::Keypad(b'7'), 0));
+        assert_eq!(Some(vec![0xc3]), keymap.translate(Key::Keypad(b'.'), 0));
+        assert_eq!(None, keymap.translate(Key::F(9), 0));
+        assert_eq!(None, Keymap::empty().translate(Key::Setup, 0));
+
+        assert_eq!(Some(Key::Return), Key::from_id(0x0d));
+        assert_eq!(Some(Key::Char(b'q')), Key::from_id(u32::from(b'q')));
+        assert_eq!(Some(Key::F(3)), Key::from_id(0x103));
+        assert_eq!(Some(Key::Keypad(b'9')), Key::from_id(0x139));
+        assert_eq!(None, Key::from_id(0x80));
+    }
+
+    #[test]
+    fn bindings_override_the_table() {
+        let mut keymap = Keymap::new();
+        keymap.bind(Key::Char(b'`'), &[0x1b], &[0x1b, b'~']);
+        keymap.bind(Key::F(9), &[0x80], &[0x80]);
+        keymap.unbind(Key::Up);
+
+        assert_eq!(Some(vec![0x1b]), keymap.translate(Key::Char(b'`'), 0));
+        assert_eq!(Some(vec![0x1b, b'~']), keymap.translate(Key::Char(b'`'), MOD_SHIFT));
+        assert_eq!(Some(vec![0x80]), keymap.translate(Key::F(9), 0));
+        assert_eq!(None, keymap.translate(Key::Up, 0));
+    }
+}
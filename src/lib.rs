@@ -1,12 +1,27 @@
 pub mod bus;
+pub mod coff;
 pub mod cpu;
+pub mod debug;
+pub mod disasm;
 pub mod dmd;
 pub mod err;
+pub mod gdb;
+#[cfg(unix)]
+pub mod host;
 pub mod instr;
+pub mod keyboard;
 pub mod mem;
 pub mod duart;
 pub mod mouse;
+pub mod bbram;
+pub mod nvram;
+pub mod profile;
+pub mod rom;
+pub mod symbols;
+pub mod trace;
+#[cfg(feature = "embedded-rom")]
 pub mod rom_hi;
+#[cfg(feature = "embedded-rom")]
 pub mod rom_lo;
 
 #[macro_use]
@@ -58,6 +58,18 @@ impl Device for Mem {
 
     /// Read from memory at the specified absolute address.
     fn read_byte(&mut self, address: usize, _: AccessCode) -> Result<u8, BusError> {
+        self.peek_byte(address)
+    }
+
+    fn read_half(&mut self, address: usize, _: AccessCode) -> Result<u16, BusError> {
+        self.peek_half(address)
+    }
+
+    fn read_word(&mut self, address: usize, _: AccessCode) -> Result<u32, BusError> {
+        self.peek_word(address)
+    }
+
+    fn peek_byte(&self, address: usize) -> Result<u8, BusError> {
         let offset = address.wrapping_sub(self.address_range().start);
 
         if address >= self.address_range().end {
@@ -67,7 +79,7 @@ impl Device for Mem {
         }
     }
 
-    fn read_half(&mut self, address: usize, _: AccessCode) -> Result<u16, BusError> {
+    fn peek_half(&self, address: usize) -> Result<u16, BusError> {
         let offset = address.wrapping_sub(self.address_range().start);
 
         if address >= self.address_range().end {
@@ -80,7 +92,7 @@ impl Device for Mem {
         }
     }
 
-    fn read_word(&mut self, address: usize, _: AccessCode) -> Result<u32, BusError> {
+    fn peek_word(&self, address: usize) -> Result<u32, BusError> {
         let offset = address.wrapping_sub(self.address_range().start);
 
         if address >= self.address_range().end {
@@ -40,14 +40,27 @@ impl Device for Mouse {
     }
 
     fn is_read_only(&self) -> bool {
-        false
+        true
     }
 
-    fn read_byte(&mut self, _address: usize, _access: AccessCode) -> Result<u8, BusError> {
-        unimplemented!()
+    fn read_byte(&mut self, address: usize, _access: AccessCode) -> Result<u8, BusError> {
+        self.peek_byte(address)
     }
 
     fn read_half(&mut self, address: usize, _access: AccessCode) -> Result<u16, BusError> {
+        self.peek_half(address)
+    }
+
+    fn read_word(&mut self, address: usize, _access: AccessCode) -> Result<u32, BusError> {
+        self.peek_word(address)
+    }
+
+    // The mouse position can only be read as halfwords.
+    fn peek_byte(&self, address: usize) -> Result<u8, BusError> {
+        Err(BusError::NoDevice(address as u32))
+    }
+
+    fn peek_half(&self, address: usize) -> Result<u16, BusError> {
         match address-START_ADDRESS {
             0 => Ok(self.y),
             2 => Ok(self.x),
@@ -55,23 +68,24 @@ impl Device for Mouse {
         }
     }
 
-    fn read_word(&mut self, _address: usize, _access: AccessCode) -> Result<u32, BusError> {
-        unimplemented!()
+    fn peek_word(&self, address: usize) -> Result<u32, BusError> {
+        Err(BusError::NoDevice(address as u32))
     }
 
+    // The mouse position is read-only.
     fn write_byte(&mut self, _address: usize, _val: u8, _access: AccessCode) -> Result<(), BusError> {
-        unimplemented!()
+        Err(BusError::Permission)
     }
 
     fn write_half(&mut self, _address: usize, _val: u16, _access: AccessCode) -> Result<(), BusError> {
-        unimplemented!()
+        Err(BusError::Permission)
     }
 
     fn write_word(&mut self, _address: usize, _val: u32, _access: AccessCode) -> Result<(), BusError> {
-        unimplemented!()
+        Err(BusError::Permission)
     }
 
     fn load(&mut self, _address: usize, _data: &[u8]) -> Result<(), BusError> {
-        unimplemented!()
+        Err(BusError::Permission)
     }
 }
\ No newline at end of file
@@ -0,0 +1,474 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::bbram::NVRAM_SIZE;
+use crate::err::NvramError;
+
+//
+// NVRAM Layout
+//
+// The firmware's setup screens are driven by a table of option
+// records in ROM, each naming the BBRAM cell that holds the option
+// and the labels for its values. A setting is stored as the index of
+// its selected label. All offsets below are offsets into the NVRAM
+// image, and so always fall on the connected byte lane.
+//
+
+const RETURN_KEY: usize = 0x006;
+const NEWLINE: usize = 0x00a;
+const DUPLEX: usize = 0x00e;
+const KEY_TONE: usize = 0x012;
+const SCREEN: usize = 0x016;
+const ENCODING: usize = 0x6b6;
+const MOUSE: usize = 0x6ba;
+const REPEAT: usize = 0x6be;
+const CURSOR: usize = 0x6c2;
+const CONTROLS: usize = 0x6ca;
+const HOST_PORT: usize = 0x6e2;
+const PRINTER_PORT: usize = 0x6e6;
+const AUX1_PORT: usize = 0x6ea;
+const AUX2_PORT: usize = 0x6ee;
+const GEN_FLOW: usize = 0x6f6;
+const PASS_FLOW: usize = 0x70a;
+
+// Speed, parity, bits/char, type and receive flow control
+const PORT_A: [usize; 5] = [0x002, 0x68a, 0x68e, 0x692, 0x6fa];
+const PORT_C: [usize; 5] = [0x6a6, 0x6aa, 0x6ae, 0x6b2, 0x6fe];
+const PORT_D: [usize; 5] = [0x6ce, 0x6d2, 0x6d6, 0x6da, 0x702];
+
+// Each of the eight PF keys holds a NUL terminated string
+const PF_KEYS: usize = 0x01a;
+const PF_KEY_STRIDE: usize = 0xcc;
+const PF_KEY_CELLS: usize = PF_KEY_STRIDE / 4;
+
+/// The longest string that can be stored in a PF key.
+pub const PF_KEY_MAX_LEN: usize = PF_KEY_CELLS - 1;
+
+// The checksum is the 16-bit sum of every cell below it
+const CHECKSUM_HI: usize = 0x1ffa;
+const CHECKSUM_LO: usize = 0x1ffe;
+
+macro_rules! setting {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident = $val:expr),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum $name {
+            $($variant = $val),+
+        }
+
+        impl $name {
+            fn from_u8(val: u8) -> Option<$name> {
+                match val {
+                    $($val => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+setting!(
+    /// Line speed of a serial port.
+    BaudRate { B1200 = 0, B2400 = 1, B4800 = 2, B9600 = 3, B19200 = 4, B300 = 5 }
+);
+
+setting!(Parity { None = 0, Odd = 1, Even = 2 });
+
+setting!(BitsPerChar { Eight = 0, Seven = 1 });
+
+setting!(PortType { Rs232 = 0, Rs422 = 1 });
+
+setting!(
+    /// A serial port that the host, printer or AUX connections can be
+    /// assigned to. Port B is the keyboard, and serves as "none".
+    Port { A = 0, C = 1, D = 2, B = 3 }
+);
+
+setting!(Duplex { Full = 0, Half = 1 });
+
+setting!(
+    /// Characters sent by the RETURN key.
+    ReturnKey { Cr = 0, Lf = 1, CrLf = 2 }
+);
+
+setting!(
+    /// Action taken on a received newline.
+    Newline { Index = 0, NewLine = 1 }
+);
+
+setting!(Screen { Dark = 0, Light = 1 });
+
+setting!(
+    /// Display of received control characters.
+    ControlChars { Visible = 0, Invisible = 1, Spaces = 2 }
+);
+
+setting!(
+    /// Keyboard auto-repeat rate.
+    RepeatRate { Cps15 = 0, Cps20 = 1, Cps30 = 2, Cps60 = 3 }
+);
+
+setting!(
+    /// Which hand the mouse buttons are arranged for.
+    MouseHand { Right = 0, Left = 1 }
+);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PortSettings {
+    pub speed: BaudRate,
+    pub parity: Parity,
+    pub bits_per_char: BitsPerChar,
+    pub port_type: PortType,
+    pub receive_flow: bool,
+}
+
+///
+/// The terminal options kept by the firmware in NVRAM, as edited on
+/// the setup screens. The firmware reads them at reset, so changes
+/// made to a running terminal take effect on the next reset.
+///
+/// On a terminal without the optional extra ports, the firmware
+/// reassigns the host, printer and AUX connections at every reset
+/// without updating the checksum, so these should be left at their
+/// defaults there.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NvramSettings {
+    pub port_a: PortSettings,
+    pub port_c: PortSettings,
+    pub port_d: PortSettings,
+    pub duplex: Duplex,
+    pub encoding: bool,
+    pub generate_flow: bool,
+    pub pass_flow: bool,
+    pub host_port: Port,
+    pub printer_port: Port,
+    pub aux1_port: Port,
+    pub aux2_port: Port,
+    pub screen: Screen,
+    pub controls: ControlChars,
+    pub key_tone: bool,
+    pub cursor_blink: bool,
+    pub repeat_rate: RepeatRate,
+    pub mouse: MouseHand,
+    pub newline: Newline,
+    pub return_key: ReturnKey,
+    pub pf_keys: [Vec<u8>; 8],
+}
+
+fn field<T>(image: &[u8], offset: usize, from_u8: fn(u8) -> Option<T>) -> Result<T, NvramError> {
+    from_u8(image[offset]).ok_or(NvramError::Value(offset))
+}
+
+/// Decode an option whose two values are labelled Off/On or On/Off.
+fn flag(image: &[u8], offset: usize, on: u8) -> Result<bool, NvramError> {
+    match image[offset] {
+        0 | 1 => Ok(image[offset] == on),
+        _ => Err(NvramError::Value(offset)),
+    }
+}
+
+fn flag_value(set: bool, on: u8) -> u8 {
+    if set {
+        on
+    } else {
+        on ^ 1
+    }
+}
+
+fn check_size(image: &[u8]) -> Result<(), NvramError> {
+    if image.len() == NVRAM_SIZE {
+        Ok(())
+    } else {
+        Err(NvramError::Size(image.len()))
+    }
+}
+
+/// Compute the checksum the firmware expects for an NVRAM image.
+pub fn checksum(image: &[u8]) -> u16 {
+    image[..CHECKSUM_HI - 2].iter().skip(2).step_by(4).fold(0u16, |sum, b| sum.wrapping_add(u16::from(*b)))
+}
+
+/// Return the checksum stored in an NVRAM image.
+pub fn stored_checksum(image: &[u8]) -> u16 {
+    u16::from(image[CHECKSUM_HI]) << 8 | u16::from(image[CHECKSUM_LO])
+}
+
+/// Recompute and store the checksum of an NVRAM image.
+pub fn update_checksum(image: &mut [u8]) {
+    let sum = checksum(image);
+    image[CHECKSUM_HI] = (sum >> 8) as u8;
+    image[CHECKSUM_LO] = sum as u8;
+}
+
+impl PortSettings {
+    fn decode(image: &[u8], offsets: &[usize; 5]) -> Result<PortSettings, NvramError> {
+        Ok(PortSettings {
+            speed: field(image, offsets[0], BaudRate::from_u8)?,
+            parity: field(image, offsets[1], Parity::from_u8)?,
+            bits_per_char: field(image, offsets[2], BitsPerChar::from_u8)?,
+            port_type: field(image, offsets[3], PortType::from_u8)?,
+            receive_flow: flag(image, offsets[4], 1)?,
+        })
+    }
+
+    fn encode(&self, image: &mut [u8], offsets: &[usize; 5]) {
+        image[offsets[0]] = self.speed as u8;
+        image[offsets[1]] = self.parity as u8;
+        image[offsets[2]] = self.bits_per_char as u8;
+        image[offsets[3]] = self.port_type as u8;
+        image[offsets[4]] = flag_value(self.receive_flow, 1);
+    }
+}
+
+impl Default for PortSettings {
+    fn default() -> Self {
+        PortSettings {
+            speed: BaudRate::B1200,
+            parity: Parity::None,
+            bits_per_char: BitsPerChar::Eight,
+            port_type: PortType::Rs232,
+            receive_flow: false,
+        }
+    }
+}
+
+/// The settings the firmware restores when the NVRAM checksum is bad.
+/// They match a cleared NVRAM, except that the printer and AUX
+/// connections are unassigned.
+impl Default for NvramSettings {
+    fn default() -> Self {
+        NvramSettings {
+            port_a: PortSettings::default(),
+            port_c: PortSettings::default(),
+            port_d: PortSettings::default(),
+            duplex: Duplex::Full,
+            encoding: false,
+            generate_flow: true,
+            pass_flow: false,
+            host_port: Port::A,
+            printer_port: Port::B,
+            aux1_port: Port::B,
+            aux2_port: Port::B,
+            screen: Screen::Dark,
+            controls: ControlChars::Visible,
+            key_tone: true,
+            cursor_blink: false,
+            repeat_rate: RepeatRate::Cps15,
+            mouse: MouseHand::Right,
+            newline: Newline::Index,
+            return_key: ReturnKey::Cr,
+            pf_keys: Default::default(),
+        }
+    }
+}
+
+impl NvramSettings {
+    /// Decode the settings from an NVRAM image. The image must carry a
+    /// valid checksum, as the firmware discards it otherwise.
+    pub fn decode(image: &[u8]) -> Result<NvramSettings, NvramError> {
+        check_size(image)?;
+
+        if checksum(image) != stored_checksum(image) {
+            return Err(NvramError::Checksum);
+        }
+
+        let mut pf_keys: [Vec<u8>; 8] = Default::default();
+
+        for (i, key) in pf_keys.iter_mut().enumerate() {
+            let start = PF_KEYS + i * PF_KEY_STRIDE;
+            *key = image[start..start + PF_KEY_STRIDE]
+                .iter()
+                .step_by(4)
+                .take(PF_KEY_MAX_LEN)
+                .take_while(|b| **b != 0)
+                .cloned()
+                .collect();
+        }
+
+        Ok(NvramSettings {
+            port_a: PortSettings::decode(image, &PORT_A)?,
+            port_c: PortSettings::decode(image, &PORT_C)?,
+            port_d: PortSettings::decode(image, &PORT_D)?,
+            duplex: field(image, DUPLEX, Duplex::from_u8)?,
+            encoding: flag(image, ENCODING, 1)?,
+            generate_flow: flag(image, GEN_FLOW, 0)?,
+            pass_flow: flag(image, PASS_FLOW, 1)?,
+            host_port: field(image, HOST_PORT, Port::from_u8)?,
+            printer_port: field(image, PRINTER_PORT, Port::from_u8)?,
+            aux1_port: field(image, AUX1_PORT, Port::from_u8)?,
+            aux2_port: field(image, AUX2_PORT, Port::from_u8)?,
+            screen: field(image, SCREEN, Screen::from_u8)?,
+            controls: field(image, CONTROLS, ControlChars::from_u8)?,
+            key_tone: flag(image, KEY_TONE, 0)?,
+            cursor_blink: flag(image, CURSOR, 1)?,
+            repeat_rate: field(image, REPEAT, RepeatRate::from_u8)?,
+            mouse: field(image, MOUSE, MouseHand::from_u8)?,
+            newline: field(image, NEWLINE, Newline::from_u8)?,
+            return_key: field(image, RETURN_KEY, ReturnKey::from_u8)?,
+            pf_keys,
+        })
+    }
+
+    /// Write the settings into an NVRAM image and update its checksum.
+    /// Bytes that do not belong to a setting are left untouched.
+    pub fn encode(&self, image: &mut [u8]) -> Result<(), NvramError> {
+        check_size(image)?;
+
+        for (i, key) in self.pf_keys.iter().enumerate() {
+            if key.len() > PF_KEY_MAX_LEN || key.contains(&0) {
+                return Err(NvramError::PfKeyLength(i));
+            }
+        }
+
+        self.port_a.encode(image, &PORT_A);
+        self.port_c.encode(image, &PORT_C);
+        self.port_d.encode(image, &PORT_D);
+        image[DUPLEX] = self.duplex as u8;
+        image[ENCODING] = flag_value(self.encoding, 1);
+        image[GEN_FLOW] = flag_value(self.generate_flow, 0);
+        image[PASS_FLOW] = flag_value(self.pass_flow, 1);
+        image[HOST_PORT] = self.host_port as u8;
+        image[PRINTER_PORT] = self.printer_port as u8;
+        image[AUX1_PORT] = self.aux1_port as u8;
+        image[AUX2_PORT] = self.aux2_port as u8;
+        image[SCREEN] = self.screen as u8;
+        image[CONTROLS] = self.controls as u8;
+        image[KEY_TONE] = flag_value(self.key_tone, 0);
+        image[CURSOR] = flag_value(self.cursor_blink, 1);
+        image[REPEAT] = self.repeat_rate as u8;
+        image[MOUSE] = self.mouse as u8;
+        image[NEWLINE] = self.newline as u8;
+        image[RETURN_KEY] = self.return_key as u8;
+
+        for (i, key) in self.pf_keys.iter().enumerate() {
+            let start = PF_KEYS + i * PF_KEY_STRIDE;
+            for cell in 0..PF_KEY_CELLS {
+                image[start + cell * 4] = *key.get(cell).unwrap_or(&0);
+            }
+        }
+
+        update_checksum(image);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blank_image() -> Vec<u8> {
+        vec![0; NVRAM_SIZE]
+    }
+
+    #[test]
+    fn decodes_blank_image() {
+        let settings = NvramSettings::decode(&blank_image()).unwrap();
+
+        assert_eq!(BaudRate::B1200, settings.port_a.speed);
+        assert_eq!(Parity::None, settings.port_a.parity);
+        assert_eq!(Duplex::Full, settings.duplex);
+        assert_eq!(Screen::Dark, settings.screen);
+        assert_eq!(ReturnKey::Cr, settings.return_key);
+        assert!(settings.key_tone);
+        assert!(settings.generate_flow);
+        assert!(!settings.cursor_blink);
+        assert!(settings.pf_keys.iter().all(|k| k.is_empty()));
+        assert_eq!(Port::A, settings.printer_port);
+    }
+
+    #[test]
+    fn checksum_matches_firmware() {
+        // The image left behind by the firmware on first boot
+        let mut image = blank_image();
+        image[0x686] = 1;
+        image[0x6e6] = 3;
+        image[0x6ea] = 3;
+        image[0x6ee] = 3;
+        image[0x1ffe] = 0x0a;
+
+        assert_eq!(0x0a, checksum(&image));
+        assert_eq!(0x0a, stored_checksum(&image));
+        assert_eq!(NvramSettings::default(), NvramSettings::decode(&image).unwrap());
+    }
+
+    #[test]
+    fn checksum_ignores_unconnected_bytes() {
+        let mut image = blank_image();
+        image[0x101] = 0xff;
+        assert_eq!(0, checksum(&image));
+    }
+
+    #[test]
+    fn round_trips_settings() {
+        let mut image = blank_image();
+        image[0x686] = 1;
+
+        let mut settings = NvramSettings::decode(&blank_image()).unwrap();
+        settings.port_a.speed = BaudRate::B19200;
+        settings.port_a.parity = Parity::Even;
+        settings.port_a.bits_per_char = BitsPerChar::Seven;
+        settings.port_c.port_type = PortType::Rs422;
+        settings.port_d.receive_flow = true;
+        settings.duplex = Duplex::Half;
+        settings.screen = Screen::Light;
+        settings.key_tone = false;
+        settings.return_key = ReturnKey::CrLf;
+        settings.repeat_rate = RepeatRate::Cps60;
+        settings.pf_keys[2] = b"ls -l\r".to_vec();
+        settings.pf_keys[7] = vec![b'x'; PF_KEY_MAX_LEN];
+
+        settings.encode(&mut image).unwrap();
+
+        assert_eq!(4, image[0x002]);
+        assert_eq!(1, image[0x012]);
+        assert_eq!(b'l', image[0x01a + 2 * 0xcc]);
+        assert_eq!(b's', image[0x01a + 2 * 0xcc + 4]);
+        assert_eq!(1, image[0x686]);
+        assert_eq!(checksum(&image), stored_checksum(&image));
+        assert_eq!(settings, NvramSettings::decode(&image).unwrap());
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let mut image = blank_image();
+        image[0x002] = 3;
+
+        match NvramSettings::decode(&image) {
+            Err(NvramError::Checksum) => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_value() {
+        let mut image = blank_image();
+        image[0x016] = 2;
+        update_checksum(&mut image);
+
+        match NvramSettings::decode(&image) {
+            Err(NvramError::Value(0x016)) => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+
+    #[test]
+    fn rejects_long_pf_key() {
+        let mut image = blank_image();
+        let mut settings = NvramSettings::decode(&image).unwrap();
+        settings.pf_keys[4] = vec![b'x'; PF_KEY_MAX_LEN + 1];
+
+        match settings.encode(&mut image) {
+            Err(NvramError::PfKeyLength(4)) => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_image_size() {
+        match NvramSettings::decode(&[0; 16]) {
+            Err(NvramError::Size(16)) => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::cpu::Instruction;
+use crate::instr::{CALL, JSB, RET, RSB};
+use crate::symbols::SymbolTable;
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// Calls nested deeper than this drop their outermost callers.
+const MAX_DEPTH: usize = 256;
+
+//
+// Execution Profile
+//
+// The profiler counts the instructions executed at each address and
+// of each opcode. The emulator does not model instruction timing, so
+// every instruction counts as one.
+//
+// It also keeps a shadow call stack of the entry points of the
+// routines entered by CALL and JSB, and left by RET and RSB, and
+// counts the instructions run under each distinct stack. These are
+// exported in the folded format read by flamegraph tools, one stack
+// per line, outermost routine first:
+//
+//   reset;selftest;splx 1234
+//
+// Interrupts and process switches don't use CALL, so their handlers
+// are counted under whatever stack they interrupted.
+//
+
+/// Counts executed instructions. A disabled Profiler costs the CPU a
+/// single test per step.
+#[derive(Default)]
+pub struct Profiler {
+    enabled: bool,
+    pc: Option<u32>,
+    total: u64,
+    addresses: HashMap<u32, u64>,
+    opcodes: HashMap<u16, (&'static str, u64)>,
+    stack: Vec<u32>,
+    stacks: HashMap<Vec<u32>, u64>,
+}
+
+/// Render a routine's entry point by name, if it has one.
+fn routine_name(address: u32, symbols: Option<&SymbolTable>) -> String {
+    match symbols.and_then(|s| s.lookup(address)) {
+        Some((name, 0)) => name.to_string(),
+        _ => format!("0x{:x}", address),
+    }
+}
+
+fn percent(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
+
+impl Profiler {
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.pc = None;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Discard all counts and the call stack.
+    pub fn clear(&mut self) {
+        self.pc = None;
+        self.total = 0;
+        self.addresses.clear();
+        self.opcodes.clear();
+        self.stack.clear();
+        self.stacks.clear();
+    }
+
+    /// Number of instructions counted.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The number of instructions executed at `address`.
+    pub fn count(&self, address: u32) -> u64 {
+        self.addresses.get(&address).copied().unwrap_or(0)
+    }
+
+    /// The `count` addresses executed most often, with their counts,
+    /// most frequent first.
+    pub fn hot_spots(&self, count: usize) -> Vec<(u32, u64)> {
+        let mut spots: Vec<(u32, u64)> = self.addresses.iter().map(|(a, n)| (*a, *n)).collect();
+        spots.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        spots.truncate(count);
+        spots
+    }
+
+    /// Executed opcodes, with their mnemonics and counts, most
+    /// frequent first.
+    pub fn opcodes(&self) -> Vec<(u16, &'static str, u64)> {
+        let mut ops: Vec<(u16, &'static str, u64)> = self.opcodes.iter().map(|(op, (name, n))| (*op, *name, *n)).collect();
+        ops.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+        ops
+    }
+
+    /// Start a step, once any pending interrupt has been taken.
+    pub(crate) fn begin(&mut self, pc: u32) {
+        self.pc = Some(pc);
+    }
+
+    /// Finish a step. `pc` is the PC after the instruction, which for
+    /// CALL and JSB is the entry point of the routine called.
+    pub(crate) fn end(&mut self, instr: &Instruction, pc: u32) {
+        let start = match self.pc.take() {
+            Some(start) => start,
+            None => return,
+        };
+
+        self.total += 1;
+        *self.addresses.entry(start).or_insert(0) += 1;
+        self.opcodes.entry(instr.opcode).or_insert((instr.name, 0)).1 += 1;
+
+        match self.stacks.get_mut(&self.stack[..]) {
+            Some(n) => *n += 1,
+            None => {
+                self.stacks.insert(self.stack.clone(), 1);
+            }
+        }
+
+        match instr.opcode {
+            CALL | JSB => {
+                if self.stack.len() == MAX_DEPTH {
+                    self.stack.remove(0);
+                }
+                self.stack.push(pc);
+            }
+            RET | RSB => {
+                self.stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// Write a report of the `top` hottest addresses and of the
+    /// opcode histogram.
+    pub fn write_report<W: Write>(&self, w: &mut W, symbols: Option<&SymbolTable>, top: usize) -> io::Result<()> {
+        writeln!(w, "{} instructions", self.total)?;
+        writeln!(w)?;
+        writeln!(w, "{:>12} {:>7}  address", "count", "%")?;
+        for (address, count) in self.hot_spots(top) {
+            write!(w, "{:>12} {:>6.2}%  {:08x}", count, percent(count, self.total), address)?;
+            match symbols.and_then(|s| s.symbolize(address)) {
+                Some(symbol) => writeln!(w, " <{}>", symbol)?,
+                None => writeln!(w)?,
+            }
+        }
+        writeln!(w)?;
+        writeln!(w, "{:>12} {:>7}  opcode", "count", "%")?;
+        for (opcode, name, count) in self.opcodes() {
+            writeln!(w, "{:>12} {:>6.2}%  {:<8} ({:02x})", count, percent(count, self.total), name.to_lowercase(), opcode)?;
+        }
+        Ok(())
+    }
+
+    /// Write the counts per call stack in the folded stack format.
+    /// Instructions run outside any tracked call are under `[top]`.
+    pub fn write_folded<W: Write>(&self, w: &mut W, symbols: Option<&SymbolTable>) -> io::Result<()> {
+        let mut folded: HashMap<String, u64> = HashMap::new();
+
+        for (stack, count) in &self.stacks {
+            let line = if stack.is_empty() {
+                String::from("[top]")
+            } else {
+                stack.iter().map(|a| routine_name(*a, symbols)).collect::<Vec<String>>().join(";")
+            };
+            *folded.entry(line).or_insert(0) += count;
+        }
+
+        let mut lines: Vec<(String, u64)> = folded.into_iter().collect();
+        lines.sort();
+        for (line, count) in lines {
+            writeln!(w, "{} {}", line, count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::decode_instruction;
+
+    fn step(profiler: &mut Profiler, pc: u32, code: &[u8], next: u32) {
+        let instr = decode_instruction(code, 0).unwrap();
+        profiler.begin(pc);
+        profiler.end(&instr, next);
+    }
+
+    #[test]
+    fn counts_addresses_and_opcodes() {
+        let mut profiler = Profiler::new();
+        for _ in 0..3 {
+            step(&mut profiler, 0x1000, &[0x70], 0x1001); // NOP
+        }
+        step(&mut profiler, 0x1001, &[0x9c, 0x01, 0x40], 0x1004); // ADDW2 &1,%r0
+
+        // A step that was never begun is not counted
+        profiler.end(&decode_instruction(&[0x70][..], 0).unwrap(), 0);
+
+        assert_eq!(4, profiler.total());
+        assert_eq!(3, profiler.count(0x1000));
+        assert_eq!(vec![(0x1000, 3), (0x1001, 1)], profiler.hot_spots(5));
+        assert_eq!(vec![(0x70, "NOP", 3), (0x9c, "ADDW2", 1)], profiler.opcodes());
+
+        profiler.clear();
+        assert_eq!(0, profiler.total());
+        assert!(profiler.hot_spots(5).is_empty());
+    }
+
+    #[test]
+    fn folds_call_stacks() {
+        let call = [0x2c, 0x5c, 0x7f, 0x00, 0x20, 0x00, 0x00]; // CALL (%sp),$0x2000
+        let jsb = [0x34, 0x7f, 0x00, 0x30, 0x00, 0x00]; // JSB $0x3000
+
+        let mut profiler = Profiler::new();
+        step(&mut profiler, 0x1000, &call, 0x2000);
+        step(&mut profiler, 0x2000, &[0x70], 0x2001);
+        step(&mut profiler, 0x2001, &jsb, 0x3000);
+        step(&mut profiler, 0x3000, &[0x70], 0x3001);
+        step(&mut profiler, 0x3001, &[0x78], 0x2007); // RSB
+        step(&mut profiler, 0x2007, &[0x08], 0x1007); // RET
+        step(&mut profiler, 0x1007, &[0x70], 0x1008);
+
+        let mut symbols = SymbolTable::new();
+        symbols.insert(0x2000, "draw");
+
+        let mut out = Vec::new();
+        profiler.write_folded(&mut out, Some(&symbols)).unwrap();
+
+        assert_eq!("[top] 2\ndraw 3\ndraw;0x3000 2\n", String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn reports_hot_spots() {
+        let mut profiler = Profiler::new();
+        step(&mut profiler, 0x2000, &[0x70], 0x2001);
+
+        let mut symbols = SymbolTable::new();
+        symbols.insert(0x2000, "draw");
+
+        let mut out = Vec::new();
+        profiler.write_report(&mut out, Some(&symbols), 10).unwrap();
+        let report = String::from_utf8(out).unwrap();
+
+        assert!(report.starts_with("1 instructions\n"));
+        assert!(report.contains("           1 100.00%  00002000 <draw>\n"));
+        assert!(report.contains("           1 100.00%  nop      (70)\n"));
+    }
+}
@@ -0,0 +1,214 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::err::RomError;
+use crate::symbols::ROM_875_SYMBOLS;
+
+use std::fs;
+use std::path::Path;
+
+/// Size of each of the two ROM halves.
+pub const ROM_HALF_SIZE: usize = 0x10000;
+
+/// Size of a complete ROM image.
+pub const ROM_SIZE: usize = 2 * ROM_HALF_SIZE;
+
+//
+// Known Firmware
+//
+// Complete images are identified by their CRC-32, and come with a
+// symbol table of their entry points. Releases that are not in this
+// table can still be told apart by the version string in the
+// firmware's terminal ID response.
+//
+// Only 8;7;5 is listed. No verified dumps of 8;7;3 or of the 2.0
+// "mux" firmware were available to take checksums from, so those
+// images, like patched ones, load with no known release.
+//
+
+const KNOWN_ROMS: [(u32, &str, &str); 1] = [(0xfef7f712, "8;7;5 (Dec 17, 1984)", ROM_875_SYMBOLS)];
+
+const TERMINAL_ID_PREFIX: &[u8] = b"\x1b[?8;";
+
+/// Identification of a ROM image.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RomInfo {
+    /// CRC-32 of the complete image
+    pub crc32: u32,
+    /// The firmware release, if the image is a known one
+    pub release: Option<&'static str>,
+    /// The version in the terminal ID response, e.g. "8;7;5"
+    pub version: Option<String>,
+    /// Text symbol table for the release, if it is a known one
+    pub symbols: Option<&'static str>,
+}
+
+/// A validated, complete ROM image.
+#[derive(Clone)]
+pub struct Rom {
+    image: Vec<u8>,
+    info: RomInfo,
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffffffffu32;
+
+    for b in data {
+        crc ^= u32::from(*b);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+/// Find the version in the firmware's terminal ID response, which has
+/// the form ESC [ ? 8 ; <a> ; <b> c
+fn terminal_id_version(image: &[u8]) -> Option<String> {
+    image.windows(TERMINAL_ID_PREFIX.len()).position(|w| w == TERMINAL_ID_PREFIX).and_then(|start| {
+        let id = &image[start + 3..];
+        let end = id.iter().take(16).position(|b| *b == b'c')?;
+        let version = &id[..end];
+        if version.iter().all(|b| b.is_ascii_digit() || *b == b';') {
+            Some(String::from_utf8_lossy(version).into_owned())
+        } else {
+            None
+        }
+    })
+}
+
+impl Rom {
+    /// Create a ROM from a complete image.
+    pub fn new(image: &[u8]) -> Result<Rom, RomError> {
+        if image.len() != ROM_SIZE {
+            return Err(RomError::Size(image.len()));
+        }
+
+        let crc = crc32(image);
+
+        let known = KNOWN_ROMS.iter().find(|(c, _, _)| *c == crc);
+
+        let info = RomInfo {
+            crc32: crc,
+            release: known.map(|(_, r, _)| *r),
+            version: terminal_id_version(image),
+            symbols: known.map(|(_, _, s)| *s),
+        };
+
+        Ok(Rom {
+            image: image.to_vec(),
+            info,
+        })
+    }
+
+    /// Create a ROM from its low and high halves.
+    pub fn from_halves(lo: &[u8], hi: &[u8]) -> Result<Rom, RomError> {
+        if lo.len() != ROM_HALF_SIZE {
+            return Err(RomError::Size(lo.len()));
+        }
+        if hi.len() != ROM_HALF_SIZE {
+            return Err(RomError::Size(hi.len()));
+        }
+
+        let mut image = Vec::with_capacity(ROM_SIZE);
+        image.extend_from_slice(lo);
+        image.extend_from_slice(hi);
+
+        Rom::new(&image)
+    }
+
+    /// Read a complete image from a file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Rom, RomError> {
+        Rom::new(&fs::read(path)?)
+    }
+
+    /// Read the low and high halves from separate files.
+    pub fn from_files<P: AsRef<Path>>(lo: P, hi: P) -> Result<Rom, RomError> {
+        Rom::from_halves(&fs::read(lo)?, &fs::read(hi)?)
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.image
+    }
+
+    pub fn info(&self) -> &RomInfo {
+        &self.info
+    }
+}
+
+/// Build a ROM image whose reset PCB starts `program` at 0x200.
+#[cfg(test)]
+pub(crate) fn image_with_program(program: &[u8]) -> Vec<u8> {
+    let mut image = vec![0; ROM_SIZE];
+    image[0x80..0x84].copy_from_slice(&[0x00, 0x00, 0x01, 0x00]);
+    image[0x104..0x10c].copy_from_slice(&[0x00, 0x00, 0x02, 0x00, 0x00, 0x70, 0x00, 0x00]);
+    image[0x200..0x200 + program.len()].copy_from_slice(program);
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "embedded-rom")]
+    use crate::rom_hi::HI_ROM;
+    #[cfg(feature = "embedded-rom")]
+    use crate::rom_lo::LO_ROM;
+
+    #[test]
+    fn computes_crc32() {
+        assert_eq!(0xcbf43926, crc32(b"123456789"));
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn identifies_compiled_in_rom() {
+        let rom = Rom::from_halves(&LO_ROM, &HI_ROM).unwrap();
+
+        assert_eq!(Some("8;7;5 (Dec 17, 1984)"), rom.info().release);
+        assert_eq!(Some("8;7;5"), rom.info().version.as_deref());
+        assert_eq!(Some(ROM_875_SYMBOLS), rom.info().symbols);
+        assert_eq!(&LO_ROM[..], &rom.as_slice()[..ROM_HALF_SIZE]);
+        assert_eq!(&HI_ROM[..], &rom.as_slice()[ROM_HALF_SIZE..]);
+    }
+
+    #[test]
+    fn identifies_unknown_release_by_terminal_id() {
+        let mut image = vec![0; ROM_SIZE];
+        image[0x100..0x109].copy_from_slice(b"\x1b[?8;7;3c");
+
+        let rom = Rom::new(&image).unwrap();
+
+        assert_eq!(None, rom.info().release);
+        assert_eq!(Some("8;7;3"), rom.info().version.as_deref());
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-rom")]
+    fn patched_rom_is_not_a_known_release() {
+        let mut image = LO_ROM.to_vec();
+        image.extend_from_slice(&HI_ROM);
+        image[0x8000] ^= 0xff;
+
+        let rom = Rom::new(&image).unwrap();
+
+        assert_eq!(None, rom.info().release);
+        assert_eq!(Some("8;7;5"), rom.info().version.as_deref());
+    }
+
+    #[test]
+    fn rejects_wrong_sizes() {
+        match Rom::new(&[0; 0x1000]) {
+            Err(RomError::Size(0x1000)) => {}
+            _ => panic!("expected size error"),
+        }
+
+        match Rom::from_halves(&[0; ROM_HALF_SIZE], &[0; 0x8000]) {
+            Err(RomError::Size(0x8000)) => {}
+            _ => panic!("expected size error"),
+        }
+    }
+}
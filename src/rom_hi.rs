@@ -1,3124 +1,4 @@
-// Auto-generated. Do not edit.
-pub const HI_ROM: [u8; 0x10000] = [
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x03, 0xfc, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x0c, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80, 0x03, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x7f, 0xc2, 0x21, 0x10, 0x20, 0x0c, 0x48,
-    0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x90, 0x10, 0x0e, 0x00, 0x10,
-    0x43, 0x80, 0x60, 0x60, 0x30, 0x18, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x01, 0xf8, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xc0, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf9, 0x8c, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x00, 0x04, 0x7f,
-    0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7f, 0x0e, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x6c, 0x00, 0x00, 0x00,
-    0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x12, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00,
-    0x00, 0x1b, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x24, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x0d, 0x00,
-    0x09, 0x00, 0x00, 0x00, 0x36, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x3f, 0x02, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x00, 0x48,
-    0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x51, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x5a, 0x00, 0x0d, 0x00, 0x09, 0x00,
-    0x00, 0x00, 0x63, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x6c, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x75, 0x00, 0x0d,
-    0x00, 0x09, 0x00, 0x00, 0x00, 0x7e, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x87, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00,
-    0x90, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0x99, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xa2, 0x00, 0x0d, 0x00, 0x09,
-    0x00, 0x00, 0x00, 0xab, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xb4, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xbd, 0x00,
-    0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xc6, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xcf, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00,
-    0x00, 0xd8, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xe1, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xea, 0x00, 0x0d, 0x00,
-    0x09, 0x00, 0x00, 0x00, 0xf3, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x00, 0xfc, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x01, 0x05,
-    0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x01, 0x0e, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x01, 0x17, 0x00, 0x0d, 0x00, 0x09, 0x00,
-    0x00, 0x01, 0x20, 0x0d, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x01, 0x29, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0x32, 0x00, 0x04,
-    0x00, 0x09, 0x00, 0x00, 0x01, 0x3b, 0x02, 0x0a, 0x00, 0x09, 0x00, 0x00, 0x01, 0x44, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01,
-    0x4d, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0x56, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0x5f, 0x00, 0x04, 0x00, 0x09,
-    0x00, 0x00, 0x01, 0x68, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0x71, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0x7a, 0x02,
-    0x09, 0x00, 0x09, 0x00, 0x00, 0x01, 0x83, 0x02, 0x0a, 0x00, 0x09, 0x00, 0x00, 0x01, 0x8b, 0x07, 0x0b, 0x00, 0x09, 0x00, 0x00,
-    0x01, 0x94, 0x05, 0x07, 0x00, 0x09, 0x00, 0x00, 0x01, 0x9d, 0x08, 0x0a, 0x00, 0x09, 0x00, 0x00, 0x01, 0xa6, 0x00, 0x0c, 0x00,
-    0x09, 0x00, 0x00, 0x01, 0xaf, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0xb8, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0xc1,
-    0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0xca, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0xd3, 0x00, 0x0b, 0x00, 0x09, 0x00,
-    0x00, 0x01, 0xdc, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0xe5, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x01, 0xee, 0x00, 0x0b,
-    0x00, 0x09, 0x00, 0x00, 0x01, 0xf7, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x00, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02,
-    0x09, 0x04, 0x0a, 0x00, 0x09, 0x00, 0x00, 0x02, 0x12, 0x03, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x1b, 0x00, 0x0b, 0x00, 0x09,
-    0x00, 0x00, 0x02, 0x24, 0x04, 0x09, 0x00, 0x09, 0x00, 0x00, 0x02, 0x2d, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x36, 0x00,
-    0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x3f, 0x03, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x48, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00,
-    0x02, 0x51, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x5a, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x63, 0x00, 0x0b, 0x00,
-    0x09, 0x00, 0x00, 0x02, 0x6c, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x75, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x7e,
-    0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x87, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0x90, 0x00, 0x0b, 0x00, 0x09, 0x00,
-    0x00, 0x02, 0x99, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xa2, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xab, 0x00, 0x0b,
-    0x00, 0x09, 0x00, 0x00, 0x02, 0xb4, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xbd, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02,
-    0xc6, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xcf, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xd8, 0x00, 0x0b, 0x00, 0x09,
-    0x00, 0x00, 0x02, 0xe1, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xea, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xf3, 0x00,
-    0x0b, 0x00, 0x09, 0x00, 0x00, 0x02, 0xfc, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x05, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00,
-    0x03, 0x0e, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x17, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x20, 0x00, 0x0b, 0x00,
-    0x09, 0x00, 0x00, 0x03, 0x29, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x32, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x3b,
-    0x00, 0x0c, 0x00, 0x09, 0x00, 0x00, 0x03, 0x44, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x4d, 0x00, 0x04, 0x00, 0x09, 0x00,
-    0x00, 0x03, 0x56, 0x0b, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x03, 0x5f, 0x00, 0x04, 0x00, 0x09, 0x00, 0x00, 0x03, 0x68, 0x04, 0x0b,
-    0x00, 0x09, 0x00, 0x00, 0x03, 0x71, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x7a, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03,
-    0x83, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x8c, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0x95, 0x00, 0x0b, 0x00, 0x09,
-    0x00, 0x00, 0x03, 0x9e, 0x04, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x03, 0xa7, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0xb0, 0x01,
-    0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0xb9, 0x01, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x03, 0xc2, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00,
-    0x03, 0xcb, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0xd4, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0xdd, 0x04, 0x0b, 0x00,
-    0x09, 0x00, 0x00, 0x03, 0xe6, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x03, 0xef, 0x04, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x03, 0xf8,
-    0x04, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x04, 0x01, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x04, 0x0a, 0x04, 0x0b, 0x00, 0x09, 0x00,
-    0x00, 0x04, 0x13, 0x01, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x04, 0x1c, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x04, 0x25, 0x04, 0x0b,
-    0x00, 0x09, 0x00, 0x00, 0x04, 0x2e, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x04, 0x37, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x04,
-    0x40, 0x04, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x04, 0x49, 0x04, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x04, 0x52, 0x00, 0x0b, 0x00, 0x09,
-    0x00, 0x00, 0x04, 0x5b, 0x00, 0x0d, 0x00, 0x09, 0x00, 0x00, 0x04, 0x64, 0x00, 0x0b, 0x00, 0x09, 0x00, 0x00, 0x04, 0x6d, 0x03,
-    0x07, 0x00, 0x09, 0x00, 0x00, 0x04, 0x76, 0x00, 0x0c, 0x00, 0x09, 0x00, 0x00, 0x04, 0x7f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xc1, 0xa9, 0x00, 0x00, 0xc1, 0x47, 0x00, 0x00, 0xc0, 0xde, 0x00, 0x00, 0xc0, 0x7d, 0x00, 0x00, 0x00, 0x00, 0x80,
-    0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0xe0, 0x00, 0x00, 0x00, 0xf0, 0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00, 0xfc, 0x00,
-    0x00, 0x00, 0xfe, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0xff, 0x80, 0x00, 0x00, 0xff, 0xc0, 0x00, 0x00, 0xff, 0xe0, 0x00,
-    0x00, 0xff, 0xf0, 0x00, 0x00, 0xff, 0xf8, 0x00, 0x00, 0xff, 0xfc, 0x00, 0x00, 0xff, 0xfe, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00,
-    0xff, 0xff, 0x80, 0x00, 0xff, 0xff, 0xc0, 0x00, 0xff, 0xff, 0xe0, 0x00, 0xff, 0xff, 0xf0, 0x00, 0xff, 0xff, 0xf8, 0x00, 0xff,
-    0xff, 0xfc, 0x00, 0xff, 0xff, 0xfe, 0x00, 0xff, 0xff, 0xff, 0x00, 0xff, 0xff, 0xff, 0x80, 0xff, 0xff, 0xff, 0xc0, 0xff, 0xff,
-    0xff, 0xe0, 0xff, 0xff, 0xff, 0xf0, 0xff, 0xff, 0xff, 0xf8, 0xff, 0xff, 0xff, 0xfc, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff,
-    0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x0f,
-    0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x7f, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x01, 0xff, 0x00,
-    0x00, 0x03, 0xff, 0x00, 0x00, 0x07, 0xff, 0x00, 0x00, 0x0f, 0xff, 0x00, 0x00, 0x1f, 0xff, 0x00, 0x00, 0x3f, 0xff, 0x00, 0x00,
-    0x7f, 0xff, 0x00, 0x00, 0xff, 0xff, 0x00, 0x01, 0xff, 0xff, 0x00, 0x03, 0xff, 0xff, 0x00, 0x07, 0xff, 0xff, 0x00, 0x0f, 0xff,
-    0xff, 0x00, 0x1f, 0xff, 0xff, 0x00, 0x3f, 0xff, 0xff, 0x00, 0x7f, 0xff, 0xff, 0x00, 0xff, 0xff, 0xff, 0x01, 0xff, 0xff, 0xff,
-    0x03, 0xff, 0xff, 0xff, 0x07, 0xff, 0xff, 0xff, 0x0f, 0xff, 0xff, 0xff, 0x1f, 0xff, 0xff, 0xff, 0x3f, 0xff, 0xff, 0xff, 0x7f,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0xd1, 0xd5, 0x00, 0x00, 0xd1, 0xd5, 0x00, 0x00, 0xd2, 0x1b, 0x00, 0x00,
-    0xd2, 0x5e, 0x00, 0x00, 0xd4, 0xf7, 0x00, 0x00, 0xd4, 0xf7, 0x00, 0x00, 0xd5, 0x25, 0x00, 0x00, 0xd5, 0x57, 0x00, 0x00, 0xd5,
-    0x8b, 0x00, 0x00, 0xd5, 0x8b, 0x00, 0x00, 0xd5, 0xbd, 0x00, 0x00, 0xd5, 0xf2, 0x00, 0x00, 0xd6, 0x15, 0x00, 0x00, 0xd6, 0x15,
-    0x00, 0x00, 0xd3, 0xab, 0x00, 0x00, 0xd6, 0x2b, 0x00, 0x71, 0xee, 0x80, 0x00, 0x01, 0x69, 0xcc, 0x00, 0x71, 0xd7, 0x00, 0x00,
-    0x00, 0x03, 0xfc, 0x00, 0x01, 0x6e, 0x0c, 0x10, 0x43, 0x9c, 0x18, 0x4c, 0xa0, 0x04, 0x2c, 0xcc, 0xfc, 0xef, 0xac, 0xd7, 0x71,
-    0x00, 0xa0, 0x4f, 0x08, 0x6b, 0x01, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x28, 0xd7, 0x71, 0x00, 0x2c, 0x5c, 0xef, 0xc8, 0xd8, 0x71,
-    0x00, 0x84, 0x40, 0x48, 0x2c, 0x5c, 0xef, 0xc8, 0xd8, 0x71, 0x00, 0x84, 0x40, 0x43, 0xdc, 0x01, 0x48, 0x40, 0xd0, 0x02, 0x40,
-    0x40, 0x9c, 0x43, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x10, 0xd7, 0x71, 0x00, 0x84, 0x40, 0x47, 0x77, 0x02, 0x2c, 0x5c,
-    0xef, 0xc8, 0xd8, 0x71, 0x00, 0x84, 0x40, 0x59, 0x70, 0x2c, 0x5c, 0xef, 0xc8, 0xd8, 0x71, 0x00, 0x84, 0x40, 0x64, 0x70, 0x2c,
-    0x5c, 0xef, 0xc8, 0xd8, 0x71, 0x00, 0x84, 0x40, 0x68, 0x70, 0xdc, 0x64, 0x59, 0x45, 0xdc, 0x68, 0x45, 0x40, 0xa0, 0x40, 0x2c,
-    0xcc, 0xfc, 0xef, 0x10, 0xd7, 0x71, 0x00, 0x84, 0x40, 0x46, 0xdc, 0x5f, 0x54, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x28,
-    0x50, 0x77, 0x12, 0xdc, 0x5f, 0x54, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x5f, 0x00, 0x08, 0x50, 0x70, 0xdc, 0x5f,
-    0x54, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x3c, 0x5f, 0x90, 0x01, 0x50, 0x43, 0x12, 0xdc, 0x5f, 0x54, 0x01, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x40, 0x84, 0x5f, 0x90, 0x01, 0x50, 0x70, 0xdc, 0x5f, 0x54, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc,
-    0x10, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xdc, 0x0c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x42, 0xfc, 0x52, 0x51, 0x41, 0x3c, 0x41,
-    0x50, 0x77, 0x1e, 0xdc, 0x5f, 0x50, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x80, 0x50, 0x70, 0xdc, 0x5f, 0x54, 0x01, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x40, 0x80, 0x50, 0x70, 0x7b, 0x2a, 0xdc, 0x5f, 0x54, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0,
-    0x50, 0xa0, 0x4f, 0x58, 0xdc, 0x71, 0x00, 0x2c, 0xcc, 0xf8, 0x7f, 0xfc, 0xa8, 0x00, 0x00, 0xdc, 0x5f, 0x50, 0x01, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x41, 0x84, 0x40, 0x51, 0x70, 0x28, 0x46, 0x7f, 0x1e, 0xdc, 0x5f, 0x50, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0x28, 0x50, 0x77, 0x2d, 0xdc, 0x5f, 0x54, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x28, 0x50, 0x7f, 0x1f, 0xa0, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xdc, 0xa9, 0x00, 0x00, 0xdc, 0x5f, 0x54, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0x80, 0x50, 0x70, 0x80, 0x46, 0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x46, 0x50, 0x70, 0xdc, 0x6f,
-    0x48, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc, 0x59, 0x46, 0x50, 0x70, 0xdc, 0x6f, 0x4c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40,
-    0xdc, 0x6f, 0x48, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xdc, 0x64, 0x51, 0x50, 0x70, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x2c,
-    0xcc, 0xfc, 0xef, 0x0c, 0xd9, 0x71, 0x00, 0xa0, 0x04, 0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x40, 0x2c,
-    0xcc, 0xf8, 0xef, 0xbc, 0xd7, 0x71, 0x00, 0x28, 0x46, 0x77, 0x0c, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00,
-    0xa0, 0x48, 0xa0, 0x47, 0x2c, 0xcc, 0xf8, 0xef, 0x68, 0xd8, 0x71, 0x00, 0x80, 0x44, 0x24, 0x7f, 0xb5, 0x09, 0x01, 0x00, 0x2c,
-    0x5c, 0xef, 0xc4, 0xd8, 0x71, 0x00, 0x87, 0x40, 0x56, 0x70, 0x90, 0x46, 0x28, 0x44, 0x77, 0x08, 0x24, 0x7f, 0xb3, 0x09, 0x01,
-    0x00, 0xdc, 0x6f, 0x5e, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc, 0x6f, 0x5e, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xdc, 0x6f,
-    0x5a, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x42, 0xfe, 0x52, 0x51, 0x41, 0xa8, 0x44, 0x41, 0xac, 0xe0, 0x45, 0x41, 0xfe, 0x41, 0x50,
-    0x40, 0x86, 0x40, 0x6e, 0x70, 0xdc, 0x6f, 0x5e, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc, 0x6f, 0x5e, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x41, 0xdc, 0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x42, 0xfe, 0x52, 0x51, 0x41, 0xfc, 0x01, 0x44, 0x42, 0xa8, 0x42,
-    0x41, 0xac, 0xe0, 0x45, 0x41, 0xfe, 0x41, 0x50, 0x40, 0x86, 0x40, 0x6c, 0x70, 0x3e, 0x6c, 0x6e, 0x7f, 0x5e, 0xdc, 0x6f, 0x54,
-    0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x40,
-    0xa0, 0x40, 0x86, 0x6e, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x6f, 0x5c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x40,
-    0xa0, 0x40, 0x86, 0x6c, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0,
-    0xef, 0xf4, 0xd7, 0x71, 0x00, 0x90, 0x44, 0x3c, 0x45, 0x44, 0x5a, 0x23, 0xff, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x2c, 0xcc,
-    0xfc, 0xef, 0x0c, 0xd9, 0x71, 0x00, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00,
-    0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xac, 0xd7, 0x71, 0x00, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb0, 0x08,
-    0x50, 0x70, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x54, 0xd9, 0x71, 0x00, 0xdc, 0x6f, 0x48, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x40, 0x84, 0x48, 0xc0, 0x0c, 0x70, 0xdc, 0x6f, 0x48, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40,
-    0x84, 0x50, 0x40, 0x84, 0x47, 0xc0, 0x10, 0x70, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc, 0x6f, 0x7a, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x41, 0x87, 0x00, 0xe0, 0x42, 0x86, 0x42, 0x51, 0x70, 0x86, 0x42, 0x50, 0x70, 0xdc, 0x6f, 0x50, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x5f, 0x00, 0x10, 0x7f, 0x33, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40,
-    0xb8, 0x5f, 0xff, 0xef, 0x50, 0x70, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb0, 0x5f, 0x00, 0x04, 0x50, 0x70,
-    0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xfc, 0xef, 0x80, 0xd9, 0x71, 0x00, 0xdc, 0x6f,
-    0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xfc, 0xef, 0xb4, 0xd8, 0x71, 0x00, 0x18, 0x43, 0x08, 0x70,
-    0x10, 0x45, 0x84, 0x5a, 0x45, 0x84, 0x74, 0x48, 0xd0, 0x02, 0x45, 0x40, 0x9c, 0x48, 0x40, 0x9c, 0x04, 0x40, 0x84, 0x40, 0x46,
-    0x80, 0x47, 0x7b, 0x1f, 0x84, 0x46, 0x58, 0x70, 0x9c, 0x04, 0x48, 0x2c, 0x5c, 0xef, 0xc4, 0xd8, 0x71, 0x00, 0x87, 0x40, 0x56,
-    0x70, 0x84, 0x46, 0x40, 0x90, 0x46, 0x2b, 0x50, 0x77, 0xee, 0x90, 0x47, 0x3c, 0x45, 0x47, 0x4b, 0xe0, 0x84, 0x48, 0x40, 0x80,
-    0x50, 0x70, 0x18, 0x45, 0x08, 0x10, 0x48, 0x84, 0x5a, 0x42, 0x84, 0xc2, 0x48, 0x48, 0xdc, 0x6f, 0x58, 0x42, 0x40, 0x84, 0x48,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0xc2, 0x54, 0xc8, 0x08, 0x70, 0x18, 0x48, 0x08, 0x10,
-    0x47, 0x84, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x47, 0x7b, 0x19, 0x18, 0x47, 0x08, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x0c, 0xd9,
-    0x71, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0x2a, 0xa7, 0x82, 0x00, 0x7f, 0xe8, 0x84, 0xa7, 0x48,
-    0x01, 0x40, 0x87, 0x50, 0xe0, 0x48, 0x90, 0xa7, 0x48, 0x01, 0x70, 0xdc, 0x5f, 0x44, 0x01, 0x47, 0x40, 0x3c, 0x40, 0xa7, 0x48,
-    0x01, 0x5b, 0x0b, 0xdc, 0x5f, 0x84, 0x00, 0x47, 0xa7, 0x48, 0x01, 0x70, 0x96, 0xa7, 0x82, 0x00, 0x70, 0x86, 0xa7, 0x82, 0x00,
-    0xe4, 0x40, 0x2a, 0x40, 0x77, 0x0c, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x0c, 0xd9, 0x71, 0x00, 0x84, 0x48, 0x40, 0x18, 0x47,
-    0x08, 0x70, 0x10, 0x47, 0x9c, 0x04, 0x4c, 0x04, 0x59, 0x48, 0x84, 0x00, 0x47, 0x7b, 0x17, 0x18, 0x47, 0x08, 0x2c, 0x5c, 0xef,
-    0xc4, 0xd8, 0x71, 0x00, 0x84, 0x48, 0x41, 0x87, 0x40, 0x51, 0x70, 0x90, 0x48, 0x90, 0x47, 0x3c, 0x04, 0x47, 0x4b, 0xeb, 0x84,
-    0x59, 0x40, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x48, 0xa0, 0xc8, 0x54, 0x2c, 0xcc, 0xfc, 0x7f, 0xf0,
-    0x59, 0x00, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0x7f, 0xf0, 0x28, 0x01, 0x00, 0x3c, 0x48, 0x7f, 0xa4, 0xd9, 0x71, 0x00, 0x77,
-    0x09, 0x80, 0x7f, 0xa4, 0xd9, 0x71, 0x00, 0x70, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x0c, 0xd9, 0x71, 0x00, 0xb8, 0x4f, 0x87,
-    0xff, 0xbf, 0xff, 0xc8, 0x50, 0x70, 0x80, 0xc8, 0x74, 0x70, 0xdc, 0x6f, 0x60, 0x48, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f,
-    0x14, 0x71, 0x00, 0x00, 0x38, 0xc8, 0x50, 0x5f, 0x00, 0x04, 0x77, 0x0c, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0xbc, 0xd8, 0x71,
-    0x00, 0x18, 0x48, 0x08, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x40, 0x3c, 0x7f, 0x8c, 0xd9, 0x71, 0x00, 0xc0, 0x44, 0x5f, 0x0c, 0xa0,
-    0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xdc, 0xa9, 0x00, 0x00, 0x18, 0x49, 0x08, 0x10, 0x46, 0x90, 0x7f, 0xb0, 0xd9, 0x71, 0x00, 0x70,
-    0x96, 0x7f, 0xac, 0xd9, 0x71, 0x00, 0x70, 0x2a, 0x7f, 0xac, 0xd9, 0x71, 0x00, 0x47, 0x12, 0x86, 0x3c, 0x7f, 0xac, 0xd9, 0x71,
-    0x00, 0x70, 0x86, 0x01, 0x7f, 0xa8, 0xd9, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0xaf, 0x40, 0x02, 0x84, 0x4f, 0xb0, 0xdd, 0x71, 0x00,
-    0x48, 0x24, 0x7f, 0x42, 0x0d, 0x01, 0x00, 0x3c, 0x42, 0x11, 0x7f, 0x33, 0x3c, 0x42, 0x13, 0x7f, 0x1f, 0x3c, 0x42, 0x6f, 0x7f,
-    0x7f, 0x40, 0x3c, 0x42, 0x5f, 0x80, 0x00, 0x7f, 0x39, 0x3c, 0x42, 0x5f, 0x81, 0x00, 0x7f, 0x5b, 0x3c, 0x42, 0x5f, 0x8c, 0x00,
-    0x7f, 0x54, 0x7b, 0x43, 0x2b, 0x7f, 0x0a, 0x07, 0x60, 0x00, 0x77, 0x3b, 0xb0, 0x04, 0xc8, 0x50, 0x70, 0x7b, 0x43, 0x2b, 0x7f,
-    0x0a, 0x07, 0x60, 0x00, 0x77, 0x2c, 0xb8, 0x5f, 0xfb, 0xfd, 0xc8, 0x50, 0x70, 0xb0, 0x5f, 0x80, 0x00, 0xc8, 0x50, 0x70, 0x7b,
-    0x2b, 0x84, 0x6f, 0x7f, 0x42, 0x2b, 0x7f, 0x0a, 0x07, 0x60, 0x00, 0x77, 0x10, 0xb8, 0x5f, 0xfb, 0xfd, 0xc8, 0x50, 0x70, 0xb0,
-    0x5f, 0x80, 0x00, 0xc8, 0x50, 0x70, 0xa0, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd9, 0x71, 0x00, 0x3c, 0xff, 0x40, 0x7f, 0x36,
-    0xdc, 0x6f, 0x60, 0x48, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x28, 0xc8, 0x64, 0x7f, 0x0d, 0x84,
-    0xc8, 0x64, 0x40, 0x86, 0xc0, 0x04, 0xe4, 0x42, 0x7b, 0x05, 0x84, 0xff, 0x42, 0x3c, 0xff, 0x42, 0x7f, 0x0f, 0x3c, 0x7f, 0x6c,
-    0xd8, 0x71, 0x00, 0xc8, 0x44, 0x7f, 0xd1, 0x7a, 0x58, 0xff, 0x2c, 0x5c, 0xef, 0x04, 0xd9, 0x71, 0x00, 0xa0, 0x48, 0xa0, 0x03,
-    0x2c, 0xcc, 0xf8, 0xef, 0xcc, 0xd8, 0x71, 0x00, 0x18, 0x46, 0x08, 0x28, 0xc8, 0x74, 0x4f, 0x0f, 0x94, 0xc8, 0x74, 0x70, 0x77,
-    0x09, 0xb0, 0x5f, 0x81, 0x00, 0xc8, 0x50, 0x70, 0x9c, 0x5f, 0x58, 0x01, 0x48, 0x3c, 0x4f, 0xc0, 0xe5, 0x71, 0x00, 0x48, 0x5b,
-    0xe2, 0xb0, 0x01, 0x7f, 0xa8, 0xdc, 0x71, 0x00, 0x70, 0x38, 0x7f, 0x8c, 0x90, 0x71, 0x00, 0x5f, 0x00, 0x02, 0x7f, 0x33, 0x38,
-    0x7f, 0x8c, 0x90, 0x71, 0x00, 0x5f, 0x00, 0x01, 0x7f, 0x28, 0x3f, 0x04, 0x7f, 0xbe, 0x06, 0x60, 0x00, 0x7f, 0x18, 0x86, 0x7f,
-    0xac, 0xd9, 0x71, 0x00, 0xe4, 0x40, 0xff, 0x7f, 0xbe, 0x06, 0x60, 0x00, 0x04, 0x41, 0xa4, 0xe0, 0x41, 0x40, 0x77, 0x09, 0x2c,
-    0x5c, 0x7f, 0x70, 0x95, 0x00, 0x00, 0x84, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x48, 0x7f, 0x08, 0x24, 0x7f, 0x73, 0x0e, 0x01, 0x00,
-    0x18, 0x46, 0x08, 0xa0, 0x4f, 0x38, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x84, 0x40, 0x42, 0x38,
-    0xc8, 0x50, 0x10, 0x7f, 0x09, 0xb0, 0x5f, 0x80, 0x00, 0xc8, 0x50, 0x70, 0x38, 0xc8, 0x50, 0x10, 0x7f, 0x3a, 0x3c, 0x0d, 0x42,
-    0x77, 0x35, 0x87, 0x7f, 0x06, 0x00, 0x60, 0x00, 0xe0, 0x40, 0x3c, 0x40, 0x00, 0x7f, 0x4e, 0x3c, 0x40, 0x01, 0x7f, 0x1e, 0x3c,
-    0x40, 0x02, 0x77, 0x44, 0xdc, 0x6f, 0x60, 0x48, 0x40, 0xa0, 0x40, 0xa0, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00,
-    0x2b, 0x7f, 0x0e, 0x00, 0x60, 0x00, 0x84, 0x0a, 0x42, 0x7b, 0x28, 0x3c, 0x5f, 0x82, 0x00, 0x42, 0x4b, 0x21, 0x3c, 0x5f, 0x89,
-    0x00, 0x42, 0x47, 0x1a, 0x38, 0xc8, 0x50, 0x4f, 0x00, 0x00, 0x40, 0x00, 0x77, 0x10, 0xf8, 0x0f, 0x42, 0x40, 0xbc, 0x02, 0x40,
-    0x84, 0x40, 0x46, 0x80, 0x47, 0x7b, 0x30, 0xdc, 0x6f, 0x60, 0x48, 0x40, 0xa0, 0x40, 0xa0, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8,
-    0x6f, 0x00, 0x00, 0x7b, 0x3f, 0xdc, 0x6f, 0x60, 0x48, 0x40, 0xa0, 0x40, 0xa0, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00,
-    0x00, 0x28, 0x40, 0x7f, 0x08, 0x2b, 0x7f, 0x0e, 0x00, 0x60, 0x00, 0xe8, 0x5f, 0xcc, 0x00, 0x46, 0x40, 0x9c, 0x4f, 0x18, 0x00,
-    0x60, 0x00, 0x40, 0xd0, 0x02, 0x47, 0x41, 0x9c, 0x41, 0x40, 0x87, 0xc0, 0x02, 0xe0, 0x42, 0x7f, 0x09, 0x90, 0x47, 0x3c, 0x32,
-    0x47, 0x4f, 0xc5, 0x2a, 0x7f, 0x40, 0x90, 0x71, 0x00, 0x4f, 0x0b, 0x28, 0x7f, 0x90, 0x90, 0x71, 0x00, 0x7e, 0x20, 0xff, 0x38,
-    0xc8, 0x50, 0x10, 0x76, 0x8d, 0xfe, 0x28, 0x7f, 0x90, 0x90, 0x71, 0x00, 0x76, 0x84, 0xfe, 0x7a, 0x5c, 0xfe, 0x70, 0x10, 0x48,
-    0x9c, 0x08, 0x4c, 0x87, 0x7f, 0x84, 0xa7, 0x71, 0x00, 0xe0, 0x40, 0xd0, 0x03, 0x40, 0x40, 0x9c, 0x7f, 0x8c, 0xa7, 0x71, 0x00,
-    0x40, 0xde, 0xc0, 0x04, 0x7f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0x86, 0x40, 0x59, 0x70, 0x87, 0x7f, 0x84, 0xa7, 0x71, 0x00, 0xe0,
-    0x40, 0xd0, 0x03, 0x40, 0x40, 0x9c, 0x7f, 0x8c, 0xa7, 0x71, 0x00, 0x40, 0xde, 0xc0, 0x06, 0x7f, 0xaa, 0xa7, 0x71, 0x00, 0x40,
-    0x86, 0x40, 0x62, 0x70, 0xde, 0x10, 0x59, 0x40, 0x86, 0x40, 0x64, 0x70, 0xde, 0x10, 0x62, 0x40, 0x86, 0x40, 0x66, 0x70, 0x84,
-    0x7f, 0xa4, 0xa7, 0x71, 0x00, 0x48, 0x24, 0x7f, 0x7b, 0x0f, 0x01, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0xa4, 0xd7, 0x71, 0x00, 0x28, 0x40,
-    0x7f, 0x32, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd9, 0x71, 0x00, 0xa8, 0x5f, 0x58, 0x01, 0x40, 0x2a, 0x80, 0x80, 0xdb,
-    0x71, 0x00, 0x7f, 0x1b, 0x2a, 0x7f, 0x88, 0xa7, 0x71, 0x00, 0x7f, 0x10, 0x92, 0x7f, 0xc0, 0xe5, 0x71, 0x00, 0x70, 0x2c, 0x5c,
-    0xef, 0x0c, 0xd8, 0x71, 0x00, 0x18, 0x48, 0x08, 0x3e, 0xc8, 0x08, 0x59, 0x4b, 0x14, 0x3e, 0xc8, 0x0a, 0x62, 0x4b, 0x0e, 0x3e,
-    0xc8, 0x0c, 0x64, 0x47, 0x08, 0x3e, 0xc8, 0x0e, 0x66, 0x4f, 0x0c, 0x84, 0xc8, 0x18, 0x48, 0x3c, 0x00, 0x48, 0x76, 0x7c, 0xff,
-    0x2a, 0x7f, 0xc0, 0xe5, 0x71, 0x00, 0x7f, 0xd5, 0x82, 0x7f, 0xc0, 0xe5, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0xef, 0x10, 0xd8, 0x71,
-    0x00, 0x7b, 0xc5, 0x70, 0x70, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x42, 0x84, 0x74, 0x48, 0xf8, 0x28, 0xc2, 0x50, 0x40, 0x3c, 0x28,
-    0x40, 0x77, 0x63, 0xdc, 0x14, 0xc2, 0x48, 0x40, 0x70, 0x84, 0x40, 0x47, 0x38, 0x48, 0x02, 0x7f, 0x47, 0x84, 0x47, 0x41, 0x84,
-    0x4f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0xfe, 0xc2, 0x58, 0x7f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0xa8, 0x5f,
-    0x20, 0x03, 0x40, 0xfe, 0xc2, 0x58, 0xc2, 0x5c, 0x41, 0xac, 0x41, 0x40, 0x86, 0x40, 0xc7, 0x04, 0x70, 0xfe, 0xc2, 0x5a, 0x7f,
-    0xaa, 0xa7, 0x71, 0x00, 0x40, 0xd0, 0x0a, 0x40, 0x40, 0xfe, 0xc2, 0x5a, 0xc2, 0x5e, 0x41, 0xac, 0x41, 0x40, 0x86, 0x40, 0xc7,
-    0x06, 0x70, 0x38, 0x48, 0x01, 0x7f, 0x0b, 0x86, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0xc7, 0x08, 0x70, 0x18, 0x47, 0x08, 0x70, 0x70,
-    0x10, 0x46, 0x84, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x48, 0xf8, 0x5f, 0x00, 0x20, 0xc8, 0x50, 0x47, 0xdc, 0xc8, 0x74, 0x7f, 0xb0,
-    0xd9, 0x71, 0x00, 0x46, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef, 0xec, 0xd7, 0x71, 0x00, 0xa0, 0x20, 0x2c, 0xcc, 0xfc, 0xef, 0xd4,
-    0xd7, 0x71, 0x00, 0x28, 0x47, 0x7f, 0x36, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d, 0x00, 0x00, 0x3c, 0x7f, 0xb0, 0xd9, 0x71, 0x00, 0x46,
-    0x4f, 0x1d, 0xfc, 0x7f, 0xb0, 0xd9, 0x71, 0x00, 0x46, 0xc8, 0x74, 0x70, 0x2c, 0x5c, 0x7f, 0xa5, 0x1d, 0x00, 0x00, 0xb0, 0x5f,
-    0x00, 0x20, 0xc8, 0x50, 0x70, 0x18, 0x46, 0x08, 0xb0, 0x5f, 0x80, 0x00, 0xc8, 0x50, 0x70, 0x7b, 0xe8, 0xb8, 0x5f, 0xff, 0xdf,
-    0xc8, 0x50, 0x70, 0x18, 0x46, 0x08, 0x70, 0x10, 0x49, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb0, 0x5f, 0x00,
-    0x20, 0x50, 0x70, 0x28, 0x5a, 0x4f, 0x0f, 0xdc, 0x6f, 0x74, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x5a, 0x50, 0x70, 0x18,
-    0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x80, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x70, 0x82, 0x7f, 0xc8, 0xd9, 0x71, 0x00, 0x70,
-    0x84, 0x4f, 0x4c, 0xe9, 0x71, 0x00, 0x7f, 0xd0, 0xd9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x50, 0xd9, 0x71, 0x00, 0x7f, 0x04, 0xe9,
-    0x71, 0x00, 0x70, 0x84, 0x7f, 0x44, 0xd9, 0x71, 0x00, 0x7f, 0x08, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x44, 0xd9, 0x71, 0x00,
-    0x7f, 0x0c, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x44, 0xd8, 0x71, 0x00, 0x7f, 0x18, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x50,
-    0xd8, 0x71, 0x00, 0x7f, 0x1c, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x40, 0xd8, 0x71, 0x00, 0x7f, 0x20, 0xe9, 0x71, 0x00, 0x70,
-    0x84, 0x7f, 0x5c, 0xd8, 0x71, 0x00, 0x7f, 0x24, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x2c, 0xd8, 0x71, 0x00, 0x7f, 0x28, 0xe9,
-    0x71, 0x00, 0x70, 0x84, 0x7f, 0x34, 0xd8, 0x71, 0x00, 0x7f, 0x2c, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x38, 0xd8, 0x71, 0x00,
-    0x7f, 0x30, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x3c, 0xd8, 0x71, 0x00, 0x7f, 0x34, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x4f, 0xa8,
-    0x6b, 0x01, 0x00, 0x7f, 0x3c, 0xe9, 0x71, 0x00, 0x70, 0x82, 0x7f, 0x00, 0x00, 0x50, 0x00, 0x70, 0xb3, 0x02, 0x7f, 0x82, 0x06,
-    0x60, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0x87, 0x02, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x2c, 0x5c, 0x7f,
-    0xac, 0x6f, 0x00, 0x00, 0x87, 0x7f, 0x02, 0x00, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0x0c, 0xed, 0x00, 0x00, 0xe0, 0x40, 0xa0,
-    0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0x18, 0x83, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xe0, 0x61, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0x20, 0x8f,
-    0x00, 0x00, 0x84, 0x4f, 0xd0, 0xd8, 0x00, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0x7f, 0x6c, 0x5a, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xc8, 0x66, 0x00, 0x00, 0x2b,
-    0x7f, 0x06, 0x07, 0x60, 0x00, 0x7f, 0x0c, 0x87, 0x7f, 0x06, 0x07, 0x60, 0x00, 0xe0, 0x40, 0x7b, 0x05, 0x84, 0x05, 0x40, 0x87,
-    0x7f, 0x86, 0x06, 0x60, 0x00, 0xe0, 0x41, 0xd0, 0x02, 0x41, 0x41, 0xfc, 0x7f, 0x8c, 0xd9, 0x71, 0x00, 0x81, 0x04, 0xed, 0x00,
-    0x00, 0x41, 0xa8, 0x41, 0x40, 0xac, 0xe0, 0x0a, 0x40, 0x70, 0x84, 0x40, 0x42, 0xa0, 0x7f, 0x8c, 0xd9, 0x71, 0x00, 0xdc, 0x42,
-    0x7f, 0x8c, 0xd9, 0x71, 0x00, 0x40, 0xb8, 0xfc, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xb4, 0xa8, 0x00, 0x00, 0x2c, 0x5c,
-    0x7f, 0xbc, 0x97, 0x00, 0x00, 0xa0, 0x08, 0x2c, 0xcc, 0xfc, 0xef, 0x28, 0xd9, 0x71, 0x00, 0x3c, 0xff, 0x40, 0x77, 0x16, 0xa0,
-    0x4f, 0xcc, 0x6b, 0x01, 0x00, 0xa0, 0x4f, 0xd2, 0x6b, 0x01, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0xa4, 0xd8, 0x71, 0x00, 0x2c, 0x5c,
-    0x7f, 0xa5, 0x1d, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x88, 0xd9, 0x71, 0x00, 0xa0, 0x7f, 0x98, 0xd8, 0x71, 0x00, 0x2c, 0xcc, 0xfc,
-    0xef, 0x18, 0xd8, 0x71, 0x00, 0x84, 0x40, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x70, 0xa0, 0x7f, 0x84, 0xd8, 0x71, 0x00, 0x2c, 0xcc,
-    0xfc, 0xef, 0x18, 0xd8, 0x71, 0x00, 0xb0, 0x01, 0xc0, 0x50, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00,
-    0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x41, 0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x07, 0x7f, 0x07, 0x84,
-    0x01, 0x40, 0x7b, 0x04, 0x80, 0x40, 0x3c, 0x41, 0x40, 0x77, 0xed, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x2a, 0x7f,
-    0xa8, 0xd9, 0x71, 0x00, 0x7f, 0x18, 0x82, 0x7f, 0xa8, 0xd9, 0x71, 0x00, 0x70, 0x2b, 0x7f, 0x17, 0xe9, 0x71, 0x00, 0x7f, 0x09,
-    0x2c, 0x5c, 0xef, 0x38, 0xd9, 0x71, 0x00, 0x3c, 0x4f, 0x58, 0xdc, 0x71, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x7f, 0x07, 0x84,
-    0x01, 0x40, 0x7b, 0x04, 0x80, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70,
-    0x70, 0x10, 0x47, 0x2c, 0x5c, 0xef, 0x0c, 0xd8, 0x71, 0x00, 0x7b, 0x0c, 0x18, 0x47, 0x08, 0x2c, 0x5c, 0xef, 0x58, 0xd8, 0x71,
-    0x00, 0x2a, 0x7f, 0xd4, 0xd9, 0x71, 0x00, 0x7f, 0xf3, 0x2c, 0x5c, 0xef, 0x10, 0xd8, 0x71, 0x00, 0x80, 0x47, 0x2c, 0x5c, 0xef,
-    0x6c, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x48, 0x84, 0x4f, 0xb0, 0xdd, 0x71, 0x00, 0x41, 0x7b, 0x28, 0x38, 0xc1, 0x50, 0x5f, 0x80,
-    0x00, 0x7f, 0x0e, 0xb8, 0x5f, 0x7f, 0xff, 0xc1, 0x50, 0x70, 0xb0, 0x01, 0xc1, 0x50, 0x70, 0x28, 0x48, 0x7f, 0x0b, 0x3c, 0x48,
-    0xc1, 0x54, 0x77, 0x05, 0x84, 0x41, 0x47, 0x9c, 0x5f, 0x58, 0x01, 0x41, 0x3c, 0x4f, 0xc0, 0xe5, 0x71, 0x00, 0x41, 0x5b, 0xd3,
-    0x2a, 0x7f, 0xc8, 0xd9, 0x71, 0x00, 0x7f, 0x2c, 0x3c, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x47, 0x77, 0x09, 0x38, 0xc7, 0x50, 0x6f,
-    0x40, 0x77, 0x1c, 0x82, 0x7f, 0xc8, 0xd9, 0x71, 0x00, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00, 0x00, 0x2c,
-    0x5c, 0xef, 0x10, 0xd8, 0x71, 0x00, 0x7b, 0x50, 0x2a, 0x7f, 0xc8, 0xd9, 0x71, 0x00, 0x77, 0x48, 0x28, 0x47, 0x7f, 0x44, 0x7b,
-    0x12, 0xa0, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0xa0, 0x03, 0x2c, 0xcc, 0xf8, 0xef, 0xcc, 0xd8, 0x71, 0x00, 0x3c, 0x7f, 0xa0, 0xd9,
-    0x71, 0x00, 0x47, 0x77, 0x29, 0x38, 0xc7, 0x50, 0x6f, 0x40, 0x7f, 0x22, 0x86, 0x01, 0x7f, 0xc8, 0xd9, 0x71, 0x00, 0x70, 0xa0,
-    0xc7, 0x7c, 0x2c, 0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00, 0x00, 0x2a, 0xa7, 0x80, 0x00, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x0c, 0xd8,
-    0x71, 0x00, 0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x07, 0x7f, 0x3d, 0x28, 0x48, 0x7f, 0x09, 0x38, 0xc7, 0x50, 0x6f, 0x40, 0x77,
-    0x1b, 0x2c, 0x5c, 0xef, 0x68, 0xd9, 0x71, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x9c, 0xd8, 0x71, 0x00, 0x28, 0x7f, 0xa0,
-    0xd9, 0x71, 0x00, 0x77, 0x95, 0x28, 0x47, 0x7f, 0x15, 0x38, 0xc7, 0x50, 0x6f, 0x40, 0x7f, 0x0e, 0xa0, 0x47, 0xa0, 0x03, 0x2c,
-    0xcc, 0xf8, 0xef, 0xcc, 0xd8, 0x71, 0x00, 0x84, 0xef, 0x24, 0x90, 0x71, 0x00, 0x40, 0x2a, 0xc0, 0x08, 0x7f, 0x0a, 0xb0, 0x01,
-    0x7f, 0x50, 0xdb, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0xef, 0x58, 0xd8, 0x71, 0x00, 0x7a, 0xdd, 0xfe, 0x70, 0x70, 0x10, 0x49, 0xe8,
-    0x5f, 0x58, 0x01, 0x5a, 0x40, 0xb0, 0x01, 0x80, 0x50, 0xdb, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0xa0, 0x7f,
-    0x78, 0xd9, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x20, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x45, 0x84, 0x5a, 0x48,
-    0x84, 0x74, 0x47, 0x84, 0x78, 0x46, 0x84, 0x7c, 0x45, 0x7b, 0x09, 0x2c, 0x5c, 0xef, 0x58, 0xd8, 0x71, 0x00, 0xa0, 0x48, 0xa0,
-    0x47, 0xa0, 0x46, 0xa0, 0x45, 0x2c, 0xcc, 0xf0, 0xef, 0x30, 0xd9, 0x71, 0x00, 0x3c, 0xff, 0x40, 0x7f, 0xe6, 0x18, 0x45, 0x08,
-    0x70, 0x10, 0x49, 0x7b, 0x18, 0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x06, 0x7f, 0x0f, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x70,
-    0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x07, 0x77, 0xe3, 0xa0, 0x00, 0xa0, 0x00, 0xa0, 0x00,
-    0xa0, 0x05, 0x2c, 0xcc, 0xf0, 0xef, 0x48, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x47, 0x2c, 0x5c, 0xef, 0x6c,
-    0xd9, 0x71, 0x00, 0x84, 0x40, 0x48, 0x7f, 0x55, 0xdc, 0x6f, 0x54, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x40, 0x3c, 0x48, 0x50, 0x7f,
-    0x47, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x41, 0xe8, 0x5f, 0x58, 0x01, 0x41, 0x40, 0x9c,
-    0x4f, 0x00, 0xdb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x47, 0x38, 0xc7, 0x50, 0x4f, 0x00, 0x80, 0x00, 0x00, 0x77, 0x23, 0xa0, 0x41,
-    0x2c, 0xcc, 0xfc, 0xef, 0xfc, 0xd8, 0x71, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x94, 0xd8, 0x71, 0x00, 0xa0, 0x48, 0x2c,
-    0xcc, 0xfc, 0xef, 0x2c, 0xd7, 0x71, 0x00, 0x18, 0x47, 0x08, 0x18, 0x47, 0x08, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x48, 0x80, 0xc8,
-    0x50, 0x70, 0x80, 0xc8, 0x74, 0x70, 0xdc, 0x6f, 0x60, 0x48, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0x14, 0x71, 0x00, 0x00,
-    0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0xbc, 0xd8, 0x71, 0x00, 0xa0, 0xa8, 0x4c, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0x3c, 0xd7, 0x71,
-    0x00, 0x80, 0xc8, 0x54, 0x70, 0x18, 0x48, 0x08, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0x6c, 0xd9, 0x71, 0x00, 0xa0, 0x40, 0x2c,
-    0xcc, 0xfc, 0xef, 0xd0, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0x6c, 0xd9, 0x71, 0x00,
-    0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xda, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0x6c,
-    0xd9, 0x71, 0x00, 0x84, 0x40, 0x41, 0x7f, 0x0c, 0xa0, 0x41, 0x2c, 0xcc, 0xfc, 0xef, 0x24, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08,
-    0x70, 0x10, 0x49, 0x9c, 0x0c, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x86, 0x5a, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x74, 0xe4, 0x40, 0xa0,
-    0x40, 0x2c, 0xcc, 0xf8, 0xef, 0xf0, 0xd8, 0x71, 0x00, 0x86, 0x40, 0x64, 0x70, 0x86, 0x72, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x76,
-    0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0xf0, 0xd8, 0x71, 0x00, 0x86, 0x40, 0x66, 0x70, 0x86, 0x5a, 0xe4, 0x40, 0xa0,
-    0x40, 0x86, 0x74, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0xec, 0xd8, 0x71, 0x00, 0x86, 0x40, 0x68, 0x70, 0x86, 0x72,
-    0xe4, 0x40, 0xa0, 0x40, 0x86, 0x76, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0xec, 0xd8, 0x71, 0x00, 0x86, 0x40, 0x6a,
-    0x70, 0x84, 0x59, 0x41, 0x04, 0x64, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18,
-    0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x1c, 0x4c, 0x84, 0x42, 0x59, 0x70, 0xa0, 0xff, 0x2c, 0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00,
-    0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd8, 0x71, 0x00, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd8, 0x71, 0x00,
-    0x04, 0x6c, 0x41, 0x84, 0x4f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10, 0x41, 0x04, 0x6c, 0x40,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x74,
-    0xd8, 0x71, 0x00, 0x04, 0x64, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x24, 0xd9,
-    0x71, 0x00, 0x24, 0x7f, 0x5d, 0x17, 0x01, 0x00, 0x04, 0x64, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x24, 0xd9, 0x71, 0x00, 0x04, 0xc9, 0x10, 0x41, 0x84, 0x4f,
-    0xa8, 0xa7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x04, 0xc9, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x14, 0x42,
-    0x2c, 0xcc, 0xf8, 0xef, 0x74, 0xd8, 0x71, 0x00, 0x04, 0x64, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x64, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c,
-    0xcc, 0xf8, 0xef, 0x24, 0xd9, 0x71, 0x00, 0xa0, 0x03, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd7, 0x71, 0x00, 0x3a, 0x7f, 0xb0, 0xa7,
-    0x71, 0x00, 0x01, 0x76, 0x72, 0xff, 0x04, 0x64, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x24, 0xd9, 0x71, 0x00, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0x38, 0x50, 0x08, 0x7f, 0x10, 0xdc, 0x6f, 0x7c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x40, 0x7b, 0x04, 0x80,
-    0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00, 0x00, 0x84, 0x59, 0x41, 0x04, 0x64, 0x40, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x49, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x18, 0x4c, 0x2c, 0x5c, 0xef,
-    0x6c, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x48, 0x7f, 0x59, 0x04, 0x68, 0x42, 0x2c, 0x5c, 0xef, 0xe8, 0xd7, 0x71, 0x00, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x04, 0xc9, 0x10, 0x42,
-    0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x04, 0x59, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x48, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x48, 0xd9, 0x71, 0x00, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x10, 0x47, 0x9c, 0x10, 0x4c,
-    0x84, 0x5a, 0x48, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd9, 0x71, 0x00, 0xa8, 0x5f, 0x58, 0x01, 0x40, 0x9c, 0x4f, 0x00,
-    0xdb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x47, 0xfe, 0x74, 0x78, 0x40, 0x3c, 0x1c, 0x40, 0x43, 0x08, 0x24, 0x7f, 0xed, 0x19, 0x01,
-    0x00, 0xfe, 0x76, 0x7a, 0x40, 0x3c, 0x1c, 0x40, 0x43, 0x08, 0x24, 0x7f, 0xed, 0x19, 0x01, 0x00, 0xdc, 0x08, 0x48, 0x40, 0x04,
-    0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x2c, 0xd7, 0x71, 0x00, 0xb8, 0x5f, 0xff, 0xfe, 0xc7,
-    0x50, 0x70, 0xb0, 0x5f, 0x00, 0x08, 0xc7, 0x50, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xfe, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef,
-    0x90, 0xd7, 0x71, 0x00, 0x84, 0x40, 0x48, 0x7f, 0x08, 0x24, 0x7f, 0xac, 0x19, 0x01, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xfe, 0xa0, 0xfe, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3,
-    0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0xc8,
-    0xd7, 0x71, 0x00, 0x04, 0x74, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x1c, 0xa0, 0x1c, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7, 0x71, 0x00, 0x04, 0x78,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0xfe, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x90, 0xd7, 0x71, 0x00,
-    0x84, 0x40, 0x48, 0x77, 0x26, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x94, 0xd8, 0x71, 0x00, 0xfc, 0x4f, 0x00, 0xdb, 0x71, 0x00,
-    0x47, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0xfc, 0xd8, 0x71, 0x00, 0x18, 0x47, 0x08, 0x84,
-    0x48, 0xc7, 0x54, 0x70, 0xdc, 0x6f, 0x58, 0x47, 0x64, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71,
-    0x00, 0x84, 0x64, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xaf, 0x09,
-    0x0f, 0x38, 0xc7, 0x50, 0x08, 0x7f, 0x0c, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x54, 0xd9, 0x71, 0x00, 0xa0, 0x47, 0xa0, 0x08,
-    0x2c, 0xcc, 0xf8, 0xef, 0x1c, 0xd8, 0x71, 0x00, 0x18, 0x47, 0x08, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x1c, 0x4c, 0x2c, 0x5c, 0xef,
-    0x6c, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x48, 0x77, 0x08, 0x24, 0x7f, 0xb7, 0x1a, 0x01, 0x00, 0xdc, 0x0c, 0x48, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0xc8, 0xd7, 0x71, 0x00, 0x04, 0x6c, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x4f, 0x88, 0x6b, 0x01, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00, 0x00, 0xdc, 0x08, 0x48, 0x40, 0x04, 0x64,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10, 0x42, 0x2c,
-    0xcc, 0xf8, 0xef, 0xc8, 0xd7, 0x71, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x06,
-    0x77, 0x08, 0x24, 0x7f, 0x53, 0x1c, 0x01, 0x00, 0x24, 0x7f, 0x75, 0x1c, 0x01, 0x00, 0x18, 0x48, 0x08, 0x3a, 0x7f, 0xb0, 0xa7,
-    0x71, 0x00, 0x06, 0x7f, 0x08, 0x24, 0x7f, 0x75, 0x1c, 0x01, 0x00, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7,
-    0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf8, 0x7f,
-    0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0x2c, 0xcc, 0xf8, 0xef, 0x24, 0xd9, 0x71, 0x00, 0xa0, 0x03, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd7, 0x71, 0x00, 0x04, 0x68, 0x41,
-    0x04, 0x64, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10,
-    0x42, 0x2c, 0xcc, 0xf8, 0xef, 0xc8, 0xd7, 0x71, 0x00, 0x04, 0x64, 0x41, 0x84, 0x50, 0x51, 0x70, 0xde, 0x6c, 0x64, 0x40, 0x3c,
-    0x5f, 0x1a, 0x03, 0x40, 0x4b, 0x0c, 0xfe, 0x6c, 0x5f, 0x1a, 0x03, 0x40, 0x86, 0x40, 0x64, 0x70, 0xde, 0x6e, 0x66, 0x40, 0x3c,
-    0x5f, 0xfa, 0x03, 0x40, 0x4b, 0x0c, 0xfe, 0x6e, 0x5f, 0xfa, 0x03, 0x40, 0x86, 0x40, 0x66, 0x70, 0x3e, 0x06, 0x64, 0x43, 0x06,
-    0x86, 0x06, 0x64, 0x70, 0x3e, 0x06, 0x66, 0x43, 0x06, 0x86, 0x06, 0x66, 0x70, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04,
-    0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf8, 0xef,
-    0x08, 0xd7, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc,
-    0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x24, 0xd9, 0x71, 0x00, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10, 0x42,
-    0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xfc, 0x7f, 0x28, 0x66, 0x00, 0x00, 0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x01, 0x76, 0x60, 0xfe, 0xa0, 0x48, 0x04, 0x64, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0xf4, 0xd8, 0x71, 0x00, 0x84, 0x4f,
-    0xa8, 0xa7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0x7f, 0x28,
-    0x66, 0x00, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x46, 0x9c, 0x10, 0x4c, 0x84, 0x5a, 0x46, 0xdc, 0x0c, 0x46, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x46, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0xc8, 0xd7, 0x71, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0xde, 0x59, 0x74, 0x40, 0x3c, 0x5f, 0x1a, 0x03, 0x40, 0x4b, 0x0c, 0xfe, 0x59, 0x5f, 0x1a, 0x03, 0x40, 0x86, 0x40, 0x74,
-    0x70, 0xde, 0x62, 0x76, 0x40, 0x3c, 0x5f, 0xfa, 0x03, 0x40, 0x4b, 0x0c, 0xfe, 0x62, 0x5f, 0xfa, 0x03, 0x40, 0x86, 0x40, 0x76,
-    0x70, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd9, 0x71, 0x00, 0xa8, 0x5f, 0x58, 0x01, 0x40, 0x9c, 0x4f, 0x00, 0xdb, 0x71,
-    0x00, 0x40, 0x84, 0x40, 0x47, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x90, 0xd7, 0x71, 0x00,
-    0x84, 0x40, 0x48, 0x77, 0x08, 0x24, 0x7f, 0x2b, 0x1e, 0x01, 0x00, 0x2c, 0x5c, 0xef, 0x0c, 0xd8, 0x71, 0x00, 0xa0, 0x46, 0xdc,
-    0x08, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x48, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xe8, 0x7f,
-    0xa4, 0x49, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x10, 0xd8, 0x71, 0x00, 0x84, 0x48, 0xc7, 0x54, 0x70, 0xdc, 0x6f, 0x58, 0x47, 0x64,
-    0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x04, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x84, 0x64, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x2c, 0xd7, 0x71, 0x00, 0x38, 0xc7, 0x50, 0x08, 0x7f,
-    0x0c, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x54, 0xd9, 0x71, 0x00, 0x38, 0xc7, 0x50, 0x5f, 0x00, 0x08, 0x77, 0x09, 0xb0, 0x5f,
-    0x00, 0x09, 0xc7, 0x50, 0x70, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xaf, 0xce, 0x0a, 0x18, 0x46, 0x08, 0x18, 0x46, 0x08, 0x18, 0x46,
-    0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x7f, 0xa4, 0xd9, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0xef,
-    0x68, 0xda, 0x71, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x47, 0x80, 0x48, 0xa0, 0x4f, 0x28, 0x6b, 0x01, 0x00,
-    0x2c, 0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00, 0x00, 0x84, 0x40, 0x47, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd8, 0x71, 0x00,
-    0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x01, 0x7f, 0x27, 0x2c, 0x5c, 0xef, 0x6c, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x41, 0x7f, 0x1b,
-    0xa0, 0x41, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd9, 0x71, 0x00, 0xa8, 0x5f, 0x58, 0x01, 0x40, 0x9c, 0x4f, 0x00, 0xdb, 0x71, 0x00,
-    0x40, 0x84, 0x40, 0x48, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd8, 0x71, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0x7f, 0xa0,
-    0x67, 0x00, 0x00, 0x84, 0x48, 0x40, 0x18, 0x47, 0x08, 0x18, 0x47, 0x08, 0x70, 0x70, 0x10, 0x49, 0xe8, 0x5f, 0x58, 0x01, 0x5a,
-    0x40, 0x9c, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x47, 0x9c, 0x04,
-    0x4c, 0x84, 0x5a, 0x40, 0x3c, 0x40, 0x01, 0x7f, 0x3a, 0x3c, 0x40, 0x02, 0x77, 0x08, 0x24, 0x7f, 0x87, 0x1f, 0x01, 0x00, 0x3c,
-    0x40, 0x03, 0x7f, 0x08, 0x24, 0x7f, 0x87, 0x1f, 0x01, 0x00, 0xa0, 0x4f, 0x3c, 0xe9, 0x71, 0x00, 0xa0, 0x03, 0x2c, 0xcc, 0xf8,
-    0xef, 0x88, 0xd7, 0x71, 0x00, 0x84, 0x40, 0x48, 0x4b, 0x7d, 0x28, 0x48, 0x77, 0x2d, 0x2c, 0x5c, 0xef, 0x44, 0xd8, 0x71, 0x00,
-    0x7b, 0x70, 0x2c, 0x5c, 0xef, 0x6c, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x47, 0x7f, 0x64, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0xd0,
-    0xd7, 0x71, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x24, 0xd8, 0x71, 0x00, 0x7b, 0x4e, 0x3c, 0x07, 0x48, 0x77, 0x0b, 0x84,
-    0x4f, 0x48, 0x6b, 0x01, 0x00, 0x40, 0x7b, 0x09, 0x84, 0x4f, 0x28, 0x6b, 0x01, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f,
-    0xa0, 0x67, 0x00, 0x00, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd8, 0x71, 0x00, 0x3a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x01,
-    0x7f, 0x12, 0xd0, 0x02, 0x48, 0x40, 0x84, 0x80, 0x18, 0xe9, 0x71, 0x00, 0x59, 0x70, 0x2c, 0x5c, 0xd9, 0x00, 0xa0, 0x00, 0x2c,
-    0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd8, 0x71, 0x00, 0x18, 0x47, 0x08, 0x10,
-    0x49, 0x84, 0x5a, 0x41, 0x84, 0x4f, 0xb0, 0xdd, 0x71, 0x00, 0x42, 0x7b, 0x26, 0x18, 0x49, 0x08, 0x3c, 0x41, 0xc2, 0x54, 0x77,
-    0x18, 0x38, 0xc2, 0x50, 0x02, 0x7f, 0x12, 0xfc, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x42, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0x18,
-    0x49, 0x08, 0x9c, 0x5f, 0x58, 0x01, 0x42, 0x3c, 0x4f, 0xc0, 0xe5, 0x71, 0x00, 0x42, 0x5b, 0xd8, 0x84, 0x02, 0x40, 0x18, 0x49,
-    0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x86, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0xe4, 0x41, 0x38, 0x41, 0x04, 0x7f, 0x08, 0x84, 0x01,
-    0x40, 0x18, 0x49, 0x08, 0x38, 0x41, 0x02, 0x7f, 0x08, 0x84, 0x02, 0x40, 0x18, 0x49, 0x08, 0x38, 0x41, 0x01, 0x7f, 0x08, 0x84,
-    0x03, 0x40, 0x18, 0x49, 0x08, 0x80, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x18, 0x4c, 0x04, 0x68,
-    0x42, 0x2c, 0x5c, 0xef, 0xe8, 0xd7, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x04, 0x59, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xfe, 0x59, 0x64, 0x40, 0x3c, 0x1c, 0x40, 0x43, 0x08, 0x24, 0x7f,
-    0x1e, 0x21, 0x01, 0x00, 0xfe, 0x62, 0x66, 0x40, 0x3c, 0x1c, 0x40, 0x43, 0x08, 0x24, 0x7f, 0x1e, 0x21, 0x01, 0x00, 0xa0, 0x5a,
-    0x2c, 0xcc, 0xfc, 0xef, 0x18, 0xd8, 0x71, 0x00, 0x84, 0x40, 0x48, 0x77, 0x08, 0x24, 0x7f, 0x1e, 0x21, 0x01, 0x00, 0xdc, 0x6f,
-    0x58, 0x48, 0x68, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x02, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x84, 0x68, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xfe, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x90,
-    0xd7, 0x71, 0x00, 0x84, 0x40, 0xc8, 0x54, 0x70, 0x7f, 0x21, 0xa0, 0x48, 0xa0, 0x02, 0x2c, 0xcc, 0xf8, 0xef, 0x1c, 0xd8, 0x71,
-    0x00, 0xa0, 0xc8, 0x54, 0x2c, 0xcc, 0xfc, 0xef, 0x24, 0xd8, 0x71, 0x00, 0xb0, 0x01, 0xc8, 0x50, 0x70, 0x18, 0x48, 0x08, 0x80,
-    0xc8, 0x50, 0x70, 0xa0, 0xa8, 0x4c, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0x3c, 0xd7, 0x71, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x46, 0x84, 0x74, 0x47, 0x84, 0x5a, 0x46, 0x70, 0x84, 0x46, 0x48, 0x3c, 0x1f, 0x48, 0x5f, 0x05, 0x84, 0x1f, 0x48, 0xfc,
-    0x4f, 0x00, 0xdb, 0x71, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0xa0, 0x40, 0xa0, 0x47, 0xa0,
-    0x48, 0xa0, 0x07, 0x2c, 0xcc, 0xf0, 0xef, 0x48, 0xd8, 0x71, 0x00, 0x9c, 0x48, 0x47, 0xbc, 0x48, 0x46, 0x47, 0xcd, 0x18, 0x46,
-    0x08, 0x10, 0x44, 0x9c, 0x0c, 0x4c, 0x84, 0x5a, 0x48, 0x84, 0x74, 0x47, 0x7b, 0x0f, 0x18, 0x44, 0x08, 0xa0, 0x01, 0x2c, 0xcc,
-    0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x40, 0x3e, 0x20, 0xc0, 0x08,
-    0x47, 0xe7, 0x7b, 0x0c, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0x2a, 0x7f, 0xe8, 0xd9, 0x71, 0x00, 0x77,
-    0xf0, 0x86, 0x01, 0x7f, 0xe8, 0xd9, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d, 0x00, 0x00, 0x84, 0x40, 0x46, 0x2b, 0x7f,
-    0xc6, 0x06, 0x60, 0x00, 0x77, 0x08, 0x24, 0x7f, 0xe8, 0x22, 0x01, 0x00, 0x82, 0x45, 0x24, 0x7f, 0xb8, 0x22, 0x01, 0x00, 0x87,
-    0x58, 0x44, 0x9c, 0x01, 0x48, 0xde, 0x01, 0x45, 0x40, 0x3c, 0x47, 0x40, 0x43, 0x0a, 0x87, 0x58, 0x61, 0x70, 0x90, 0x48, 0x7b,
-    0x05, 0x83, 0x61, 0x70, 0xde, 0x02, 0x45, 0x40, 0x3c, 0x47, 0x40, 0x43, 0x0a, 0x87, 0x58, 0x62, 0x70, 0x90, 0x48, 0x7b, 0x05,
-    0x83, 0x62, 0x70, 0x2a, 0x45, 0x77, 0x18, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xfb, 0x5f, 0xc0, 0x00,
-    0x44, 0x40, 0xd4, 0x02, 0x40, 0x40, 0x7b, 0x1a, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xfb, 0x5f, 0xc0,
-    0x00, 0x44, 0x40, 0xd4, 0x02, 0x40, 0x40, 0xb0, 0x6f, 0x40, 0x40, 0xfb, 0x5f, 0xc0, 0x00, 0x61, 0x41, 0xd4, 0x04, 0x41, 0x41,
-    0xb0, 0x41, 0x40, 0xfb, 0x5f, 0xc0, 0x00, 0x62, 0x41, 0xd4, 0x06, 0x41, 0x41, 0xb0, 0x41, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8,
-    0x7f, 0xd8, 0x6f, 0x00, 0x00, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xfb, 0x3f, 0x44, 0x40, 0xb0, 0x6f,
-    0x40, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0xde, 0x01, 0x45, 0x40, 0x3c, 0x47, 0x40, 0x43, 0x1e,
-    0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xfb, 0x3f, 0x61, 0x40, 0xb0, 0x6f, 0x40, 0x40, 0xa0, 0x40, 0x2c,
-    0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0xde, 0x02, 0x45, 0x40, 0x3c, 0x47, 0x40, 0x43, 0x1e, 0xdc, 0x04, 0x7f, 0x24, 0x90,
-    0x71, 0x00, 0x40, 0xa0, 0x50, 0xfb, 0x3f, 0x62, 0x40, 0xb0, 0x6f, 0x40, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f,
-    0x00, 0x00, 0x9e, 0x03, 0x45, 0x86, 0x45, 0xe4, 0x40, 0x3c, 0x47, 0x40, 0x4a, 0x0c, 0xff, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0x7f,
-    0xcc, 0x1d, 0x00, 0x00, 0x82, 0x7f, 0xe8, 0xd9, 0x71, 0x00, 0x70, 0xdc, 0x08, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50,
-    0x68, 0x70, 0x2c, 0x5c, 0xd9, 0x08, 0x80, 0x40, 0x18, 0x44, 0x08, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x50,
-    0x87, 0x58, 0xe0, 0x40, 0x9c, 0x01, 0x48, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0x94, 0x47, 0x77, 0xe3,
-    0x7b, 0xbb, 0x70, 0x10, 0x46, 0x9c, 0x08, 0x4c, 0x84, 0x5a, 0x42, 0x04, 0x59, 0x46, 0xfe, 0xc2, 0x58, 0xc2, 0x5c, 0x40, 0x70,
-    0x84, 0x40, 0x48, 0xfe, 0xc2, 0x5a, 0xc2, 0x5e, 0x40, 0x70, 0x84, 0x40, 0x47, 0x84, 0x46, 0x40, 0x90, 0x46, 0xfc, 0x06, 0x48,
-    0x41, 0xac, 0x09, 0x41, 0x87, 0x41, 0x50, 0x70, 0x84, 0x46, 0x40, 0x90, 0x46, 0xfc, 0x06, 0x47, 0x41, 0xac, 0x10, 0x41, 0x87,
-    0x41, 0x50, 0x70, 0x84, 0x46, 0x40, 0xf8, 0x5f, 0xff, 0x00, 0x48, 0xe3, 0x41, 0x87, 0x41, 0x50, 0x70, 0x90, 0x46, 0x84, 0x46,
-    0x40, 0x90, 0x46, 0xd4, 0x08, 0x48, 0x41, 0x87, 0x41, 0x50, 0x70, 0x84, 0x46, 0x40, 0xf8, 0x5f, 0xff, 0x00, 0x47, 0xe3, 0x41,
-    0x87, 0x41, 0x50, 0x70, 0x90, 0x46, 0x84, 0x46, 0x40, 0xd4, 0x08, 0x47, 0x41, 0x87, 0x41, 0x50, 0x70, 0xfc, 0x4f, 0x00, 0xdb,
-    0x71, 0x00, 0x42, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0xa0, 0x40, 0xe0, 0x59, 0xa0, 0x06, 0x87, 0x77, 0xe0, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xf0, 0xef, 0x48, 0xd8, 0x71, 0x00, 0x18, 0x46, 0x08, 0x70, 0x10, 0x49, 0x2a, 0x7f, 0xe8, 0xd9, 0x71, 0x00, 0x77,
-    0x13, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x40, 0x3e, 0x20, 0xc0, 0x08, 0x4b, 0x08, 0x84, 0xff, 0x40,
-    0x18, 0x49, 0x08, 0xfc, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x74, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0xa0, 0x40, 0xe0, 0x73, 0xa0,
-    0x01, 0xa0, 0x01, 0x2c, 0xcc, 0xf0, 0xef, 0x30, 0xd9, 0x71, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x10, 0x49, 0x3c, 0x4f,
-    0x8c, 0xe9, 0x71, 0x00, 0x7f, 0xd0, 0xd9, 0x71, 0x00, 0x53, 0x3f, 0x3c, 0x4f, 0x4c, 0xe9, 0x71, 0x00, 0x7f, 0xd0, 0xd9, 0x71,
-    0x00, 0x77, 0x25, 0x84, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x7f, 0x48, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x7f, 0xd0, 0xd9, 0x71, 0x00,
-    0x40, 0x87, 0x73, 0x50, 0x70, 0x90, 0x7f, 0xd0, 0xd9, 0x71, 0x00, 0x70, 0x80, 0x40, 0x18, 0x49, 0x08, 0x3c, 0x7f, 0xa0, 0xd9,
-    0x71, 0x00, 0x7f, 0x48, 0xe9, 0x71, 0x00, 0x7f, 0xde, 0x84, 0xff, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x45, 0x3c, 0x4f, 0x4c, 0xe9, 0x71, 0x00, 0x7f, 0xd0, 0xd9, 0x71, 0x00, 0x57, 0x08, 0x24, 0x7f, 0x06, 0x25, 0x01, 0x00,
-    0x2a, 0x7f, 0xe8, 0xd9, 0x71, 0x00, 0x7f, 0x08, 0x24, 0x7f, 0x06, 0x25, 0x01, 0x00, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00,
-    0x40, 0x84, 0x50, 0x40, 0x3e, 0x20, 0xc0, 0x08, 0x4b, 0x08, 0x24, 0x7f, 0x06, 0x25, 0x01, 0x00, 0xfc, 0x4f, 0x4c, 0xe9, 0x71,
-    0x00, 0x7f, 0xd0, 0xd9, 0x71, 0x00, 0x47, 0x70, 0x84, 0x47, 0x48, 0x3c, 0x1f, 0x48, 0x5f, 0x05, 0x84, 0x1f, 0x48, 0xfc, 0x4f,
-    0x00, 0xdb, 0x71, 0x00, 0x7f, 0x48, 0xe9, 0x71, 0x00, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0xa0, 0x40, 0xa0, 0x4f, 0x4c, 0xe9,
-    0x71, 0x00, 0xa0, 0x48, 0xa0, 0x07, 0x2c, 0xcc, 0xf0, 0xef, 0x30, 0xd9, 0x71, 0x00, 0x3c, 0xff, 0x40, 0x7f, 0x4a, 0x3c, 0x48,
-    0x47, 0x77, 0x11, 0x84, 0x4f, 0x4c, 0xe9, 0x71, 0x00, 0x7f, 0xd0, 0xd9, 0x71, 0x00, 0x70, 0x18, 0x45, 0x08, 0xfc, 0x48, 0x47,
-    0x42, 0x84, 0x4f, 0x4c, 0xe9, 0x71, 0x00, 0x46, 0xdc, 0x4f, 0x4c, 0xe9, 0x71, 0x00, 0x48, 0x40, 0x84, 0x40, 0x45, 0x7b, 0x12,
-    0x84, 0x46, 0x40, 0x84, 0x45, 0x41, 0x87, 0x51, 0x50, 0x70, 0x90, 0x46, 0x90, 0x45, 0x94, 0x42, 0x28, 0x42, 0x47, 0xee, 0x84,
-    0x46, 0x7f, 0xd0, 0xd9, 0x71, 0x00, 0x70, 0x18, 0x45, 0x08, 0x70, 0x10, 0x49, 0xa0, 0x5a, 0xa0, 0x00, 0xa0, 0x00, 0xa0, 0x04,
-    0x2c, 0xcc, 0xf0, 0xef, 0x48, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x41, 0xfc, 0x4f,
-    0x00, 0xdb, 0x71, 0x00, 0x41, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0x70, 0x84, 0x40, 0x48, 0x7b, 0x1e, 0xe8, 0x6f, 0x68, 0x48,
-    0x40, 0x97, 0x80, 0x2b, 0xe6, 0x71, 0x00, 0x70, 0xa0, 0x48, 0xa0, 0x00, 0xa0, 0x00, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x48,
-    0xd8, 0x71, 0x00, 0xe8, 0x6f, 0x68, 0x48, 0x40, 0x2b, 0x80, 0x2b, 0xe6, 0x71, 0x00, 0x77, 0xd9, 0x18, 0x48, 0x08, 0x10, 0x49,
-    0xa0, 0x5a, 0xa0, 0x00, 0xa0, 0x00, 0xa0, 0x06, 0x2c, 0xcc, 0xf0, 0xef, 0x48, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70,
-    0x70, 0x10, 0x47, 0x9c, 0x04, 0x4c, 0xfe, 0x5a, 0x74, 0x40, 0xbc, 0x01, 0x40, 0x84, 0x40, 0x48, 0xfe, 0x72, 0x76, 0x40, 0xbc,
-    0x01, 0x40, 0x84, 0x40, 0x47, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xfc, 0x7f, 0x70, 0xc9, 0x00, 0x00, 0xa0, 0x48, 0xa0, 0x00, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf8, 0x7f, 0xe0, 0xc9, 0x00, 0x00,
-    0xa0, 0x00, 0xa0, 0x47, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf8, 0x7f, 0xe0, 0xc9, 0x00, 0x00, 0x8c, 0x48, 0x40, 0xa0, 0x40, 0xa0,
-    0x00, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf8, 0x7f, 0xe0, 0xc9, 0x00, 0x00, 0xa0, 0x00, 0x8c, 0x47, 0x40, 0xa0, 0x40, 0x04, 0x59,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x03, 0x2c, 0xcc, 0xf8, 0x7f, 0xe0, 0xc9, 0x00, 0x00, 0x18, 0x47, 0x08, 0x70, 0x70, 0x10, 0x49, 0x3c, 0x74, 0x5a, 0x43, 0x08,
-    0x84, 0x5a, 0x40, 0x18, 0x49, 0x08, 0x84, 0x74, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x3c, 0x74,
-    0x5a, 0x4f, 0x08, 0x84, 0x5a, 0x40, 0x18, 0x49, 0x08, 0x84, 0x74, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10,
-    0x48, 0x3e, 0x08, 0x7f, 0xa8, 0xa7, 0x71, 0x00, 0x4f, 0x6f, 0x3e, 0x08, 0x7f, 0xaa, 0xa7, 0x71, 0x00, 0x4f, 0x66, 0x3e, 0x5f,
-    0x17, 0x03, 0x7f, 0xa8, 0xa7, 0x71, 0x00, 0x43, 0x5b, 0x3e, 0x5f, 0xf7, 0x03, 0x7f, 0xaa, 0xa7, 0x71, 0x00, 0x43, 0x50, 0x84,
-    0x7f, 0xa4, 0xa7, 0x71, 0x00, 0x48, 0x7b, 0x42, 0x18, 0x48, 0x08, 0x84, 0x4f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x98, 0xd7, 0x71, 0x00, 0x28, 0x40, 0x7f, 0x08, 0x84,
-    0x48, 0x40, 0x18, 0x48, 0x08, 0x84, 0xc8, 0x18, 0x48, 0x3c, 0x00, 0x48, 0x77, 0xc0, 0x80, 0x40, 0x18, 0x48, 0x08, 0x70, 0x70,
-    0x70, 0x10, 0x47, 0x84, 0x5a, 0x41, 0x84, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x48, 0x7b, 0x6f, 0x38, 0xc8, 0x50, 0x02, 0x7f, 0x64,
-    0x3c, 0xc8, 0x54, 0x41, 0x77, 0x5e, 0x3c, 0x48, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x7f, 0x41, 0x84, 0x7f, 0xa0, 0xd9, 0x71, 0x00,
-    0x47, 0x84, 0x48, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x70, 0x28, 0x47, 0x7f, 0x10, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xaf, 0xc0, 0x01,
-    0xb8, 0x6f, 0xbf, 0xc7, 0x50, 0x70, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xaf, 0xb2, 0x01, 0x28, 0x47, 0x7f, 0x14, 0xf8, 0x28, 0xc7,
-    0x50, 0x40, 0x3c, 0x28, 0x40, 0x77, 0x0a, 0x84, 0xc7, 0x48, 0x40, 0x82, 0xc0, 0x1c, 0x70, 0x38, 0xc8, 0x50, 0x20, 0x7f, 0x0d,
-    0xb0, 0x6f, 0x40, 0xc8, 0x50, 0x70, 0xb0, 0x01, 0xc8, 0x50, 0x70, 0x18, 0x47, 0x08, 0x9c, 0x5f, 0x58, 0x01, 0x48, 0x3c, 0x4f,
-    0xc0, 0xe5, 0x71, 0x00, 0x48, 0x5b, 0x8c, 0x7b, 0xef, 0x70, 0x70, 0x10, 0x49, 0x28, 0x78, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x0c,
-    0xd8, 0x71, 0x00, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7,
-    0x71, 0x00, 0x28, 0x78, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x10, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x10, 0x47, 0x9c, 0x08, 0x4c,
-    0x84, 0x5a, 0x48, 0x84, 0x7c, 0x47, 0xa0, 0x48, 0x86, 0x74, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x76, 0xe4, 0x40, 0xa0, 0x40, 0x86,
-    0x78, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x76, 0xe4, 0x40, 0x9c, 0x47, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf0, 0x7f,
-    0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0xa0, 0x48, 0x86, 0x74, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7a,
-    0xe4, 0x40, 0xbc, 0x47, 0x40, 0xa0, 0x40, 0x86, 0x78, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7a, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59,
-    0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0xa0, 0x48, 0x86, 0x74, 0xe4,
-    0x40, 0xa0, 0x40, 0x86, 0x76, 0xe4, 0x40, 0x9c, 0x47, 0x40, 0xa0, 0x40, 0x86, 0x74, 0xe4, 0x40, 0x9c, 0x47, 0x40, 0xa0, 0x40,
-    0x86, 0x7a, 0xe4, 0x40, 0xbc, 0x47, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0x2c, 0xcc,
-    0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0xa0, 0x48, 0x86, 0x78, 0xe4, 0x40, 0xbc, 0x47, 0x40, 0xa0, 0x40, 0x86, 0x76, 0xe4, 0x40,
-    0x9c, 0x47, 0x40, 0xa0, 0x40, 0x86, 0x78, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7a, 0xe4, 0x40, 0xbc, 0x47, 0x40, 0xa0, 0x40, 0x04,
-    0x59, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0x18, 0x47, 0x08, 0x70,
-    0x70, 0x10, 0x48, 0x9c, 0x08, 0x4c, 0x84, 0x5a, 0x48, 0x2c, 0x5c, 0xef, 0x0c, 0xd8, 0x71, 0x00, 0xa0, 0xc8, 0x54, 0xdc, 0x08,
-    0xc8, 0x54, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x04, 0xa0, 0x01, 0x2c, 0xcc, 0xec, 0xaf, 0xa8, 0xfe, 0x2c, 0x5c, 0xef, 0x10, 0xd8, 0x71, 0x00, 0x3c, 0x7f, 0xa0, 0xd9, 0x71,
-    0x00, 0x48, 0x7f, 0x43, 0xa0, 0xc8, 0x54, 0xdc, 0x08, 0xc8, 0x54, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0xa0, 0x03,
-    0x2c, 0xcc, 0xec, 0xaf, 0x57, 0xfe, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x49, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49,
-    0x9c, 0x08, 0x4c, 0x84, 0x06, 0x59, 0x70, 0x24, 0x7f, 0x34, 0x2a, 0x01, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7,
-    0x71, 0x00, 0x84, 0xef, 0x24, 0x90, 0x71, 0x00, 0x40, 0x2a, 0xc0, 0x08, 0x7f, 0xec, 0x2b, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x77,
-    0x20, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x87, 0x40, 0xe0, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xfc, 0xef, 0x2c, 0xd9, 0x71, 0x00, 0x7b, 0x6b, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac,
-    0x70, 0x00, 0x00, 0x87, 0x40, 0x42, 0x87, 0x42, 0xe0, 0x40, 0x3c, 0xff, 0x40, 0x77, 0x12, 0x87, 0x42, 0xe0, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xfc, 0xef, 0x2c, 0xd9, 0x71, 0x00, 0x7b, 0x41, 0xfb, 0x5f, 0xe0, 0x00, 0x42, 0x40, 0x3c, 0x20, 0x40, 0x7f, 0x0b,
-    0x9c, 0x02, 0x59, 0x70, 0x3c, 0x08, 0x59, 0x77, 0x0b, 0x80, 0x59, 0x70, 0x87, 0x42, 0x65, 0x70, 0x7b, 0x24, 0xfb, 0x3f, 0x42,
-    0x40, 0x87, 0x65, 0xe0, 0x41, 0xd0, 0x59, 0x41, 0x41, 0xbb, 0x5f, 0xc0, 0x00, 0x41, 0xb3, 0x41, 0x40, 0x87, 0x40, 0xe0, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x2c, 0xd9, 0x71, 0x00, 0x2a, 0x7f, 0xf0, 0xd9, 0x71, 0x00, 0x7e, 0x5f, 0xff, 0xa0, 0x3c,
-    0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd7, 0x71, 0x00, 0x2c, 0x5c, 0x7f, 0xcc, 0x1c, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x46,
-    0x28, 0x5a, 0x77, 0x1b, 0xa0, 0x74, 0xa0, 0x78, 0x2c, 0xcc, 0xf8, 0xef, 0xa0, 0xd8, 0x71, 0x00, 0x87, 0x03, 0x7f, 0x15, 0xe9,
-    0x71, 0x00, 0x70, 0x80, 0x40, 0x18, 0x46, 0x08, 0xe8, 0x5f, 0x58, 0x01, 0x5a, 0x40, 0x9c, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x40,
-    0x84, 0x40, 0x42, 0x38, 0xc2, 0x50, 0x4f, 0x00, 0x00, 0x3f, 0x00, 0x7f, 0x19, 0xd4, 0x10, 0xc2, 0x50, 0x40, 0xb8, 0x3f, 0x40,
-    0xa8, 0x5f, 0x58, 0x01, 0x40, 0x9c, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x42, 0x38, 0xc2, 0x50, 0x02, 0x7f, 0x2f,
-    0x28, 0xc2, 0x6c, 0x77, 0x09, 0xb0, 0x01, 0xc2, 0x50, 0x70, 0x7b, 0x28, 0xf8, 0x6f, 0x7b, 0xd2, 0x70, 0x40, 0x3c, 0x0b, 0x40,
-    0x7f, 0x1d, 0xf8, 0x6f, 0x7b, 0xd2, 0x70, 0x40, 0x3c, 0x6f, 0x73, 0x40, 0x7f, 0x11, 0x7b, 0x0a, 0x87, 0x03, 0x7f, 0x15, 0xe9,
-    0x71, 0x00, 0x70, 0x80, 0x40, 0x18, 0x46, 0x08, 0x84, 0x78, 0x46, 0x4f, 0x4b, 0xfe, 0xa2, 0x82, 0x00, 0x5f, 0xc0, 0x00, 0x40,
-    0x3c, 0x40, 0x46, 0x5f, 0x08, 0x84, 0x01, 0x40, 0x18, 0x46, 0x08, 0x84, 0xa2, 0x44, 0x01, 0x47, 0x84, 0x74, 0x48, 0x92, 0xa2,
-    0x82, 0x00, 0x70, 0x84, 0x47, 0x40, 0x84, 0x48, 0x41, 0x87, 0x51, 0x50, 0x70, 0x90, 0x47, 0x90, 0x48, 0xdc, 0x5f, 0x44, 0x01,
-    0x42, 0x40, 0x3c, 0x40, 0x47, 0x5b, 0x08, 0xdc, 0x5f, 0x84, 0x00, 0x42, 0x47, 0x94, 0x46, 0x47, 0xda, 0x84, 0x47, 0xa2, 0x44,
-    0x01, 0x70, 0x3e, 0x5f, 0x80, 0x00, 0xa2, 0x82, 0x00, 0x47, 0x08, 0x38, 0xc2, 0x50, 0x04, 0x7f, 0x98, 0xe8, 0x6f, 0x68, 0x5a,
-    0x40, 0x93, 0x80, 0x2b, 0xe6, 0x71, 0x00, 0x70, 0x87, 0x80, 0x2b, 0xe6, 0x71, 0x00, 0xe0, 0x40, 0x3f, 0x02, 0x40, 0x4f, 0x87,
-    0xe8, 0x6f, 0x68, 0x5a, 0x40, 0x87, 0x02, 0x80, 0x2b, 0xe6, 0x71, 0x00, 0x70, 0x7a, 0x78, 0xff, 0x18, 0x46, 0x08, 0x70, 0x70,
-    0x70, 0x10, 0x49, 0xdc, 0x5f, 0x82, 0x00, 0x5a, 0x40, 0xfe, 0x50, 0x5f, 0xc0, 0x00, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08,
-    0x10, 0x49, 0x84, 0x74, 0x42, 0xdc, 0x5f, 0x44, 0x01, 0x42, 0x40, 0x84, 0x50, 0x41, 0xdc, 0x5f, 0x82, 0x00, 0x42, 0x40, 0x92,
-    0x50, 0x70, 0x87, 0x73, 0x51, 0x70, 0x90, 0x41, 0xdc, 0x5f, 0x44, 0x01, 0x42, 0x40, 0x3c, 0x40, 0x41, 0x5b, 0x08, 0xdc, 0x5f,
-    0x84, 0x00, 0x42, 0x41, 0xdc, 0x5f, 0x44, 0x01, 0x42, 0x40, 0x84, 0x41, 0x50, 0x70, 0xdc, 0x6f, 0x50, 0x42, 0x40, 0xb0, 0x01,
-    0x50, 0x70, 0x18, 0x49, 0x08, 0x10, 0x46, 0x9c, 0x3c, 0x4c, 0x84, 0x5a, 0x48, 0x87, 0x58, 0x46, 0x87, 0xc8, 0x01, 0xe0, 0x40,
-    0xa8, 0x5f, 0x58, 0x01, 0x40, 0x9c, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x47, 0x87, 0x46, 0xe0, 0x40, 0x94, 0x40,
-    0x4b, 0x15, 0x3c, 0x08, 0x40, 0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0x08, 0x6c, 0x01, 0x00,
-    0xa0, 0x4f, 0x2c, 0x6c, 0x01, 0x00, 0x87, 0x46, 0xe0, 0x40, 0xa0, 0x40, 0xe0, 0x61, 0x2c, 0xcc, 0xf8, 0xef, 0xd4, 0xd8, 0x71,
-    0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0xa4, 0xd8, 0x71, 0x00, 0x18, 0x46, 0x08, 0x3c, 0x03, 0x74, 0x77, 0x23, 0x87, 0xc8,
-    0x01, 0x7f, 0x14, 0xe9, 0x71, 0x00, 0x70, 0x87, 0xc8, 0x02, 0xe2, 0x40, 0x86, 0x40, 0x7f, 0x12, 0xe9, 0x71, 0x00, 0x70, 0x86,
-    0x01, 0x7f, 0xd4, 0xd9, 0x71, 0x00, 0x70, 0x18, 0x46, 0x08, 0x18, 0x46, 0x08, 0x3c, 0x05, 0x74, 0x77, 0x61, 0xa0, 0x02, 0x87,
-    0xc8, 0x01, 0xe0, 0x40, 0x87, 0xc8, 0x02, 0xe0, 0x41, 0xd0, 0x08, 0x41, 0x41, 0xb0, 0x41, 0x40, 0xac, 0x5f, 0xe8, 0x03, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0xec, 0xd8, 0x71, 0x00, 0x87, 0x40, 0x7f, 0x14, 0xe9, 0x71, 0x00, 0x70, 0xa0, 0x03, 0x87,
-    0xc8, 0x03, 0xe0, 0x40, 0x87, 0xc8, 0x04, 0xe0, 0x41, 0xd0, 0x08, 0x41, 0x41, 0xb0, 0x41, 0x40, 0xac, 0x5f, 0xe8, 0x03, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0xec, 0xd8, 0x71, 0x00, 0x86, 0x40, 0x7f, 0x12, 0xe9, 0x71, 0x00, 0x70, 0x86, 0x01, 0x7f,
-    0xd4, 0xd9, 0x71, 0x00, 0x70, 0x18, 0x46, 0x08, 0x18, 0x46, 0x08, 0x2b, 0xc8, 0x01, 0x77, 0x0c, 0x92, 0x7f, 0xf0, 0xd9, 0x71,
-    0x00, 0x70, 0x18, 0x46, 0x08, 0x38, 0xc7, 0x50, 0x02, 0x7f, 0x55, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0xbc, 0xd8, 0x71, 0x00,
-    0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x5c, 0xd9, 0x71, 0x00, 0x82, 0xa7, 0x82, 0x00, 0x70, 0x84, 0xa7, 0x44, 0x01, 0xa7, 0x48,
-    0x01, 0x70, 0xa0, 0x47, 0x3f, 0x02, 0x46, 0x77, 0x0b, 0x84, 0x7f, 0x78, 0xd9, 0x71, 0x00, 0x40, 0x7b, 0x09, 0x84, 0x7f, 0x6c,
-    0xd8, 0x71, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x4c, 0xd9, 0x71, 0x00, 0x3f, 0x07, 0x46, 0x77, 0x09, 0xb0, 0x5f,
-    0x00, 0x10, 0xc7, 0x50, 0x70, 0xb0, 0x01, 0xc7, 0x50, 0x70, 0x18, 0x46, 0x08, 0xfb, 0x5f, 0xff, 0x00, 0xc8, 0x01, 0x40, 0x84,
-    0x40, 0x6c, 0x70, 0x84, 0x48, 0xc9, 0x10, 0x70, 0x84, 0x02, 0x42, 0x7b, 0x13, 0xfc, 0x02, 0x42, 0x40, 0x9c, 0x48, 0x40, 0xdc,
-    0x42, 0x48, 0x41, 0x87, 0x51, 0x50, 0x70, 0x90, 0x42, 0x3c, 0x74, 0x42, 0x4f, 0xec, 0xe0, 0x6c, 0x2c, 0xcc, 0xfc, 0xef, 0x64,
-    0xd8, 0x71, 0x00, 0x3c, 0x01, 0x6c, 0x4b, 0x07, 0x3c, 0x20, 0x6c, 0x5f, 0x06, 0x84, 0x20, 0x6c, 0x70, 0x87, 0xc9, 0x0f, 0xc9,
-    0x18, 0x70, 0x84, 0x01, 0x42, 0x7b, 0x14, 0x04, 0xc9, 0x18, 0x40, 0x9c, 0x42, 0x40, 0x87, 0xd9, 0x14, 0x50, 0x70, 0x90, 0xc9,
-    0x14, 0x70, 0x90, 0x42, 0x3c, 0x6c, 0x42, 0x4b, 0xeb, 0xa0, 0x00, 0xe0, 0xc9, 0x18, 0xdc, 0x01, 0x6c, 0x40, 0xa0, 0x40, 0xa0,
-    0x09, 0x2c, 0xcc, 0xf0, 0xef, 0x48, 0xd8, 0x71, 0x00, 0x18, 0x46, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x84, 0x00, 0x00, 0x00,
-    0x4c, 0x84, 0x4f, 0x00, 0xe1, 0x81, 0x02, 0x59, 0x70, 0x84, 0x7f, 0xb8, 0xd8, 0x71, 0x00, 0x64, 0x70, 0x04, 0xc9, 0x44, 0x68,
-    0x70, 0x04, 0xc9, 0x44, 0x6c, 0x70, 0x04, 0xa9, 0x84, 0x00, 0xc9, 0x10, 0x70, 0x04, 0xc9, 0x44, 0xc9, 0x14, 0x70, 0x04, 0xc9,
-    0x44, 0xc9, 0x18, 0x70, 0x80, 0xc9, 0x40, 0x70, 0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x5a, 0x50, 0x70,
-    0x84, 0x49, 0x40, 0x30, 0xac, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x84, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x4f, 0x00,
-    0xe1, 0x81, 0x02, 0x59, 0x70, 0x84, 0x7f, 0x84, 0xd9, 0x71, 0x00, 0x64, 0x70, 0x04, 0xc9, 0x44, 0x68, 0x70, 0x04, 0xc9, 0x44,
-    0x6c, 0x70, 0x04, 0xa9, 0x84, 0x00, 0xc9, 0x10, 0x70, 0x04, 0xc9, 0x44, 0xc9, 0x14, 0x70, 0x04, 0xc9, 0x44, 0xc9, 0x18, 0x70,
-    0x80, 0xc9, 0x40, 0x70, 0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x5a, 0x50, 0x70, 0x84, 0x49, 0x40, 0x30,
-    0xac, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0x74, 0xd9, 0x71, 0x00, 0x18, 0x49, 0x08, 0x10, 0x49, 0x9c, 0x4f,
-    0x00, 0x00, 0x00, 0x00, 0x4c, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0,
-    0x50, 0x2c, 0xcc, 0xf8, 0xef, 0x4c, 0xd9, 0x71, 0x00, 0x30, 0xc8, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x00, 0x00,
-    0x00, 0x00, 0x4c, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x2c,
-    0xcc, 0xf8, 0xef, 0x4c, 0xd9, 0x71, 0x00, 0xbc, 0x04, 0x4e, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0x18,
-    0x49, 0x08, 0x70, 0x70, 0x10, 0x48, 0x80, 0x41, 0x84, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x48, 0x7b, 0x75, 0x18, 0x48, 0x08, 0x38,
-    0xc8, 0x50, 0x02, 0x77, 0x65, 0x80, 0xa8, 0x4c, 0x01, 0x70, 0xa0, 0x5f, 0x00, 0x08, 0xa0, 0x4f, 0x58, 0xdc, 0x71, 0x00, 0x2c,
-    0xcc, 0xf8, 0x7f, 0xfc, 0xa8, 0x00, 0x00, 0x84, 0x40, 0xa8, 0x50, 0x01, 0x70, 0x7f, 0x52, 0x84, 0x5f, 0x00, 0x08, 0xa8, 0x54,
-    0x01, 0x70, 0xb0, 0x5f, 0x02, 0x08, 0xc8, 0x50, 0x70, 0xa0, 0x48, 0xa0, 0x5a, 0x2c, 0xcc, 0xf8, 0xef, 0x4c, 0xd9, 0x71, 0x00,
-    0x80, 0xc8, 0x54, 0x70, 0x82, 0xa8, 0x82, 0x00, 0x70, 0xdc, 0x5f, 0x84, 0x00, 0x48, 0xa8, 0x44, 0x01, 0x70, 0xdc, 0x5f, 0x84,
-    0x00, 0x48, 0xa8, 0x48, 0x01, 0x70, 0x80, 0xc8, 0x70, 0x70, 0x80, 0xc8, 0x6c, 0x70, 0x84, 0x48, 0x40, 0x18, 0x48, 0x08, 0x90,
-    0x41, 0x9c, 0x5f, 0x58, 0x01, 0x48, 0x3c, 0x08, 0x41, 0x4b, 0x8d, 0x80, 0x40, 0x18, 0x48, 0x08, 0x10, 0x47, 0x84, 0x5a, 0x48,
-    0x84, 0x74, 0x47, 0x84, 0x4f, 0x00, 0xa1, 0x81, 0x02, 0x58, 0x70, 0x84, 0x47, 0xc8, 0x04, 0x70, 0x28, 0xa8, 0x50, 0x01, 0x7f,
-    0x1c, 0x28, 0xa8, 0x4c, 0x01, 0x7f, 0x0e, 0xa0, 0xa8, 0x4c, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0x3c, 0xd7, 0x71, 0x00, 0x84, 0xa8,
-    0x50, 0x01, 0xa8, 0x4c, 0x01, 0x70, 0x84, 0xa8, 0x4c, 0x01, 0xc8, 0x08, 0x70, 0x84, 0xc8, 0x08, 0xc8, 0x0c, 0x70, 0x28, 0xa8,
-    0x50, 0x01, 0x7f, 0x09, 0x84, 0xa8, 0x54, 0x01, 0x40, 0x7b, 0x07, 0x84, 0x5f, 0x00, 0x08, 0x40, 0x9c, 0xc8, 0x0c, 0x40, 0x84,
-    0x40, 0xc8, 0x10, 0x70, 0x84, 0xc8, 0x08, 0xc8, 0x14, 0x70, 0x84, 0xc8, 0x08, 0xc8, 0x18, 0x70, 0x84, 0x08, 0x42, 0x7b, 0x12,
-    0xdc, 0x1c, 0x48, 0x40, 0xd0, 0x02, 0x42, 0x41, 0x9c, 0x41, 0x40, 0x80, 0x50, 0x70, 0x94, 0x42, 0x28, 0x42, 0x43, 0xee, 0x80,
-    0xc8, 0x40, 0x70, 0x87, 0x00, 0xe0, 0x40, 0x84, 0x40, 0xa8, 0x54, 0x01, 0x70, 0x84, 0x40, 0xa8, 0x50, 0x01, 0x70, 0x84, 0x47,
-    0xc8, 0x44, 0x70, 0x18, 0x47, 0x08, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x28, 0x5a, 0x77, 0x0f,
-    0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb8, 0xfe, 0x50, 0x70, 0x04, 0x7f, 0x4c, 0xea, 0x71, 0x00, 0x40, 0x30,
-    0xac, 0x7b, 0x02, 0x18, 0x49, 0x08, 0x10, 0x47, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x7f, 0xf4, 0xd9, 0x71, 0x00,
-    0x48, 0x84, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x47, 0x3c, 0x47, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x7f, 0x32, 0xf8, 0x5f, 0x01, 0x04,
-    0xc7, 0x50, 0x40, 0x3c, 0x01, 0x40, 0x77, 0x1f, 0x84, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x7f, 0xf4, 0xd9, 0x71, 0x00, 0x70, 0x84,
-    0x47, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x70, 0x84, 0x47, 0xce, 0xfc, 0x70, 0x30, 0xc8, 0x7b, 0x09, 0x84, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x48, 0x9c, 0x5f, 0x58, 0x01, 0x48, 0x3c, 0x4f, 0xc0, 0xe5, 0x71, 0x00, 0x48, 0x5b, 0x09, 0x84, 0x4f, 0x00, 0xdb, 0x71,
-    0x00, 0x48, 0xf8, 0x5f, 0x01, 0x04, 0xc8, 0x50, 0x40, 0x3c, 0x01, 0x40, 0x77, 0x11, 0x84, 0x48, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x70, 0x84, 0x48, 0xce, 0xfc, 0x70, 0x30, 0xc8, 0x7b, 0xd0, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x4f, 0x4c,
-    0xea, 0x71, 0x00, 0x40, 0x84, 0x4f, 0x80, 0xa1, 0x81, 0x02, 0x50, 0x70, 0x84, 0x7f, 0x60, 0xd9, 0x71, 0x00, 0xc0, 0x04, 0x70,
-    0x84, 0x4f, 0x0c, 0xea, 0x71, 0x00, 0xc0, 0x08, 0x70, 0x84, 0x4f, 0x00, 0xa1, 0x81, 0x02, 0xc0, 0x0c, 0x70, 0x84, 0x7f, 0x60,
-    0xd9, 0x71, 0x00, 0xc0, 0x10, 0x70, 0x84, 0x4f, 0x0c, 0xea, 0x71, 0x00, 0xc0, 0x14, 0x70, 0x84, 0x4f, 0x0c, 0xea, 0x71, 0x00,
-    0xc0, 0x18, 0x70, 0x84, 0x4f, 0x48, 0xea, 0x71, 0x00, 0xc0, 0x1c, 0x70, 0x84, 0x4f, 0x0c, 0xea, 0x71, 0x00, 0xc0, 0x20, 0x70,
-    0x84, 0x4f, 0x0c, 0xea, 0x71, 0x00, 0xc0, 0x24, 0x70, 0x80, 0xc0, 0x4c, 0x70, 0x84, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x7f, 0xf4,
-    0xd9, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x14, 0x00, 0x00, 0x00, 0x4c, 0xd4, 0x03, 0x7c, 0x40,
-    0xb8, 0x0f, 0x40, 0x84, 0x40, 0x59, 0x70, 0x3c, 0x04, 0x5a, 0x77, 0x08, 0x24, 0x7f, 0x01, 0x33, 0x01, 0x00, 0x28, 0x7f, 0xf8,
-    0xd9, 0x71, 0x00, 0x7f, 0x15, 0x87, 0x7f, 0xfc, 0xd9, 0x71, 0x00, 0xef, 0xf8, 0xd9, 0x71, 0x00, 0x70, 0x80, 0x7f, 0xf8, 0xd9,
-    0x71, 0x00, 0x70, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb0, 0x5f, 0x00, 0x04, 0x50, 0x70, 0xdc, 0x6f, 0x6c,
-    0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x74, 0x50, 0x70, 0xdc, 0x6f, 0x70, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x78,
-    0x50, 0x70, 0x28, 0x7f, 0xa4, 0xd9, 0x71, 0x00, 0x7f, 0x28, 0x3c, 0x03, 0x5a, 0x77, 0x23, 0x3c, 0x01, 0x59, 0x7f, 0x07, 0x3c,
-    0x0e, 0x59, 0x77, 0x19, 0x2c, 0x5c, 0x7f, 0xa5, 0x1d, 0x00, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00,
-    0x24, 0x7f, 0x01, 0x33, 0x01, 0x00, 0x84, 0x4f, 0x9c, 0xea, 0x71, 0x00, 0x7f, 0x1c, 0xeb, 0x71, 0x00, 0x70, 0xd0, 0x02, 0x5a,
-    0x40, 0xa0, 0x80, 0x34, 0x6c, 0x01, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00, 0xa0, 0x4f, 0xfc, 0x6c, 0x01, 0x00,
-    0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00, 0xa0, 0x59, 0xe0, 0x68, 0x2c, 0xcc, 0xf8, 0xef, 0xd4, 0xd8, 0x71, 0x00, 0xa0,
-    0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00, 0x3c, 0x01, 0x5a, 0x7f, 0x74, 0xa0, 0x4f, 0x09, 0x6d, 0x01, 0x00, 0x2c,
-    0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00, 0xa0, 0xda, 0x04, 0xe0, 0x68, 0x2c, 0xcc, 0xf8, 0xef, 0xd4, 0xd8, 0x71, 0x00, 0xa0,
-    0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00, 0xdc, 0x6f, 0x44, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xfc, 0x50, 0xda,
-    0x04, 0x40, 0x84, 0x40, 0x64, 0x70, 0x3c, 0x4f, 0x00, 0x00, 0x05, 0x00, 0x64, 0x57, 0x34, 0xa0, 0x4f, 0x0e, 0x6d, 0x01, 0x00,
-    0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00, 0xa0, 0x64, 0xe0, 0x68, 0x2c, 0xcc, 0xf8, 0xef, 0xd4, 0xd8, 0x71, 0x00, 0xa0,
-    0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00, 0xa0, 0x4f, 0x17, 0x6d, 0x01, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8,
-    0x71, 0x00, 0x3c, 0x03, 0x5a, 0x7f, 0x27, 0xa0, 0x4f, 0x19, 0x6d, 0x01, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8, 0x71, 0x00,
-    0xa0, 0xca, 0x10, 0xe0, 0x68, 0x2c, 0xcc, 0xf8, 0xef, 0xd4, 0xd8, 0x71, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x88, 0xd8,
-    0x71, 0x00, 0x83, 0xef, 0x1c, 0xeb, 0x71, 0x00, 0x70, 0xfc, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40,
-    0xac, 0x5f, 0x58, 0x01, 0x40, 0x3c, 0x01, 0x40, 0x47, 0x35, 0xfc, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0x77, 0x0b, 0x84, 0x4f, 0x2c, 0x6d, 0x01, 0x00, 0x40, 0x7b, 0x09, 0x84, 0x4f, 0x32, 0x6d,
-    0x01, 0x00, 0x40, 0xa0, 0x40, 0xa0, 0x4f, 0x9c, 0xea, 0x71, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0xa4, 0xd8, 0x71, 0x00, 0x7b, 0x09,
-    0x2c, 0x5c, 0xef, 0x10, 0xd8, 0x71, 0x00, 0x2a, 0x7f, 0x86, 0xa7, 0x71, 0x00, 0x47, 0xf3, 0xfc, 0x4f, 0x00, 0xdb, 0x71, 0x00,
-    0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xac, 0x5f, 0x58, 0x01, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0xf8, 0xd8, 0x71, 0x00,
-    0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb0, 0x5f, 0x00, 0x04, 0x50, 0x70, 0x2c, 0x5c, 0x7f, 0xa5, 0x1d, 0x00,
-    0x00, 0x2c, 0x5c, 0xaf, 0x12, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x10, 0x49,
-    0x9c, 0x4f, 0x20, 0x00, 0x00, 0x00, 0x4c, 0xa0, 0x4f, 0x9c, 0xea, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0x64, 0xd6, 0x00, 0x00,
-    0x84, 0x40, 0x6c, 0x70, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x86, 0x7f, 0xd4, 0x6c, 0x01, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x0a, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc,
-    0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x18, 0x42,
-    0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7, 0x71, 0x00, 0x04, 0xc9, 0x10, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x6f, 0x5c, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x40, 0xfe, 0xc9, 0x10, 0x50, 0x40, 0x87, 0x7f, 0x91, 0x02, 0x01, 0x00, 0xe0, 0x41, 0xac, 0xe0, 0x41, 0x40,
-    0x84, 0x40, 0x68, 0x70, 0xe4, 0x68, 0x6c, 0x40, 0x7f, 0x07, 0x84, 0x01, 0x40, 0x7b, 0x04, 0x80, 0x40, 0xec, 0x68, 0x6c, 0x41,
-    0x9c, 0x41, 0x40, 0x94, 0x40, 0x87, 0x7f, 0x82, 0x01, 0x01, 0x00, 0xe0, 0x41, 0xa8, 0x41, 0x40, 0x86, 0x7f, 0xd6, 0x6c, 0x01,
-    0x00, 0xe4, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x64, 0x70, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50,
-    0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc,
-    0x6f, 0x5c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x40, 0x86, 0x50, 0xe4, 0x40, 0x9c, 0x64, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3,
-    0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x18, 0x42, 0x2c, 0xcc, 0xf8, 0x7f,
-    0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x01, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0xa0, 0x4f, 0xc8, 0x6c, 0x01, 0x00, 0x84, 0x4f, 0xd0, 0x6c, 0x01,
-    0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x6f,
-    0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xe8, 0x7f, 0xa4, 0x49, 0x00, 0x00, 0x80, 0x64, 0x70,
-    0x24, 0x7f, 0x23, 0x35, 0x01, 0x00, 0xdc, 0x68, 0x64, 0x40, 0x87, 0x80, 0x9c, 0xea, 0x71, 0x00, 0x59, 0x70, 0xdc, 0x68, 0x64,
-    0x40, 0x83, 0x80, 0x9c, 0xea, 0x71, 0x00, 0x70, 0xa0, 0x4f, 0x80, 0x01, 0x01, 0x00, 0xdc, 0x4f, 0x9c, 0xea, 0x71, 0x00, 0x64,
-    0x40, 0xa0, 0x40, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0xc9, 0x10, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xc0, 0xd7, 0x71,
-    0x00, 0x04, 0xc9, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0xa0, 0x0e, 0x04,
-    0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0xc9, 0x18, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7, 0x71, 0x00, 0x04, 0xc9, 0x10, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xdc, 0x68, 0x64, 0x40, 0x87, 0x59, 0x80, 0x9c, 0xea, 0x71, 0x00, 0x70, 0x9c, 0x68, 0x64, 0x70, 0x3c, 0x6c, 0x64, 0x43, 0x1e,
-    0xde, 0x0e, 0xc9, 0x12, 0x40, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x84, 0x51, 0x41, 0x86, 0xc1, 0x0e, 0xe4,
-    0x41, 0x3c, 0x41, 0x40, 0x4e, 0x45, 0xff, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84,
-    0x7f, 0x1c, 0xeb, 0x71, 0x00, 0x40, 0x90, 0x7f, 0x1c, 0xeb, 0x71, 0x00, 0x70, 0x87, 0xda, 0x00, 0x50, 0x70, 0x84, 0x5a, 0x40,
-    0x90, 0x5a, 0x70, 0x2b, 0x50, 0x77, 0xe5, 0x94, 0x7f, 0x1c, 0xeb, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x10, 0x44, 0x9c, 0x4f,
-    0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x5a, 0x47, 0x84, 0x74, 0x48, 0x70, 0x84, 0x48, 0x45, 0x84, 0x45, 0x46, 0x84, 0x46, 0x40,
-    0x90, 0x46, 0x83, 0x50, 0x70, 0x28, 0x47, 0x7f, 0x2c, 0x84, 0x46, 0x40, 0x90, 0x46, 0xf8, 0x5f, 0xff, 0x00, 0x47, 0xe3, 0x41,
-    0xbb, 0x0f, 0x41, 0x9f, 0x30, 0x41, 0x87, 0x41, 0x50, 0x70, 0x3f, 0x39, 0x41, 0x4f, 0x07, 0x9f, 0x07, 0xc6, 0xff, 0x70, 0xd4,
-    0x04, 0x47, 0x47, 0x28, 0x47, 0x77, 0xda, 0x7b, 0x0b, 0x84, 0x46, 0x40, 0x90, 0x46, 0x87, 0x30, 0x50, 0x70, 0x84, 0x46, 0x40,
-    0x90, 0x46, 0x87, 0x6f, 0x78, 0x50, 0x70, 0x87, 0x30, 0x56, 0x70, 0x7b, 0x11, 0x87, 0x56, 0x44, 0x87, 0x55, 0x56, 0x70, 0x87,
-    0x44, 0x55, 0x70, 0x90, 0x45, 0x94, 0x46, 0x3c, 0x46, 0x45, 0x5b, 0xee, 0x84, 0x48, 0x40, 0x7b, 0x02, 0x18, 0x44, 0x08, 0x70,
-    0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x0c, 0x00, 0x00, 0x00, 0x4c, 0xa0, 0x00, 0xa0, 0x5f, 0xd0, 0x03, 0xa0, 0x5f, 0x20, 0x03,
-    0xa0, 0x5f, 0x00, 0x04, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0x7f, 0x64, 0xaa, 0x00, 0x00,
-    0xa0, 0x0a, 0xa0, 0x5f, 0xe0, 0x03, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0x7f, 0x70, 0xc9, 0x00, 0x00, 0xa0, 0x5a, 0x04, 0x64, 0x42, 0x2c,
-    0xcc, 0xfc, 0xef, 0xd8, 0xd8, 0x71, 0x00, 0xa0, 0x4f, 0x3a, 0x6d, 0x01, 0x00, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0xd8,
-    0xd8, 0x71, 0x00, 0xa0, 0x74, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0xd8, 0xd8, 0x71, 0x00, 0xa0, 0x0a, 0xa0, 0x5f, 0xf0,
-    0x03, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x2c, 0xcc, 0xfc, 0x7f, 0x70, 0xc9, 0x00, 0x00, 0xa0, 0x4f, 0x3d, 0x6d, 0x01, 0x00, 0x04, 0x64, 0x42, 0x2c, 0xcc,
-    0xfc, 0xef, 0xd8, 0xd8, 0x71, 0x00, 0x7b, 0x02, 0x2c, 0x5c, 0x7f, 0x24, 0x97, 0x00, 0x00, 0x84, 0x40, 0x59, 0x70, 0x3c, 0x5f,
-    0xaf, 0x00, 0x40, 0x7f, 0x09, 0x3c, 0x5f, 0x8f, 0x00, 0x59, 0x77, 0xe9, 0x2c, 0x5c, 0x7f, 0xcc, 0x1c, 0x00, 0x00, 0x18, 0x49,
-    0x08, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0x84, 0x42, 0x59, 0x70, 0xdc, 0x6f, 0x5c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40,
-    0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xfe, 0x51, 0x50, 0x40, 0x86, 0x5a, 0xe4, 0x41, 0xa8, 0x41, 0x40, 0xac,
-    0x5f, 0x20, 0x03, 0x40, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x9e, 0x51, 0x40, 0x86, 0x40, 0x5a, 0x70, 0xdc,
-    0x6f, 0x5e, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc, 0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xfe, 0x51, 0x50, 0x40,
-    0x86, 0x72, 0xe4, 0x41, 0xa8, 0x41, 0x40, 0xac, 0x5f, 0x00, 0x04, 0x40, 0xdc, 0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41,
-    0x9e, 0x51, 0x40, 0x86, 0x40, 0x72, 0x70, 0x84, 0x59, 0x41, 0x04, 0x5a, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18,
-    0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x08, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x04, 0x5a, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42,
-    0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x04, 0x74, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x41, 0x04, 0x5a, 0x40,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0xa0, 0x5a,
-    0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x2c, 0xcc, 0xf8, 0x7f, 0xfc, 0xa8, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70,
-    0x70, 0x10, 0x49, 0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x2c, 0xcc, 0xf4, 0x7f, 0xd4, 0xa6, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49,
-    0x08, 0x10, 0x49, 0xdc, 0x5f, 0x80, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x82, 0x50, 0x70, 0xdc, 0x6f, 0x7c, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x40, 0x80, 0x50, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x08, 0x4c, 0xdc, 0x6f, 0x78, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7, 0x71, 0x00, 0x04,
-    0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x74,
-    0x2c, 0xcc, 0xf4, 0xef, 0x60, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x10, 0x49, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x74, 0x2c, 0xcc, 0xf4, 0xef, 0x60, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x49, 0x9c, 0x08, 0x4c, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x59, 0x70, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7,
-    0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd7, 0x71, 0x00, 0x84, 0x59, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x41, 0x84, 0x41, 0x59, 0x70, 0x04, 0x5a, 0x40, 0x84, 0x50, 0x51, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49, 0x9c, 0x04, 0x4c,
-    0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x74, 0x2c, 0xcc, 0xf4, 0xef, 0x74, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x49, 0x9c, 0x08, 0x4c, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x5a, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8,
-    0xef, 0x04, 0xd8, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x78, 0x2c, 0xcc, 0xf0, 0xef, 0x78, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x10,
-    0x4c, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xfc,
-    0xef, 0x00, 0xd8, 0x71, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x04, 0x64, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0x7f, 0xe6, 0xc2, 0x00, 0x00, 0x28, 0x40, 0x77, 0x5f,
-    0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c,
-    0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x78, 0x2c, 0xcc, 0xf0, 0xef, 0x7c, 0xd7, 0x71, 0x00, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x84, 0x41, 0x68,
-    0x70, 0x04, 0x74, 0x40, 0x84, 0x50, 0x51, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x14, 0x4c, 0x84, 0x42, 0x59, 0x70,
-    0xa0, 0x4f, 0x80, 0x01, 0x01, 0x00, 0xa0, 0x5a, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xdc, 0x6f,
-    0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42,
-    0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03,
-    0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xc0, 0xd7, 0x71, 0x00, 0x04, 0x68, 0x41, 0x84, 0x50, 0x51, 0x70, 0x86, 0x68,
-    0xe4, 0x48, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef, 0x28, 0xd8, 0x71, 0x00, 0xa8, 0x5f, 0x20, 0x03, 0x40, 0xdc, 0x6f, 0x5c, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x41, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x42, 0xfe, 0x52, 0x51, 0x41, 0xac, 0x41, 0x40,
-    0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x9e, 0x40, 0x51, 0x70, 0x7b, 0x0e, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7,
-    0x71, 0x00, 0x40, 0x92, 0x50, 0x70, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0xd8, 0x71, 0x00, 0x86, 0x50, 0xe4, 0x40, 0x3c,
-    0x48, 0x40, 0x4b, 0xce, 0xdc, 0x6f, 0x78, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84,
-    0x59, 0x40, 0x18, 0x48, 0x08, 0x10, 0x49, 0x9c, 0x08, 0x4c, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50,
-    0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04,
-    0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x04, 0xd8, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x78, 0xa0, 0x7c, 0x2c, 0xcc, 0xec, 0xef, 0x84, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08,
-    0x70, 0x10, 0x49, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x5c, 0xd9, 0x71, 0x00, 0xa0, 0x7f, 0x78, 0xd9,
-    0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xb4, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x84, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x48, 0x38, 0x5a, 0x01, 0x7f, 0x09, 0xb0, 0x10, 0xc8, 0x50, 0x70, 0x7b, 0x08, 0xb8, 0x6f, 0xef, 0xc8, 0x50,
-    0x70, 0x38, 0x5a, 0x04, 0x7f, 0x2e, 0xb0, 0x20, 0xc8, 0x50, 0x70, 0x3c, 0x48, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x77, 0x14, 0xb0,
-    0x6f, 0x40, 0xc8, 0x50, 0x70, 0xa0, 0x48, 0xa0, 0x03, 0x2c, 0xcc, 0xf8, 0xef, 0xcc, 0xd8, 0x71, 0x00, 0xa0, 0x01, 0x2c, 0xcc,
-    0xfc, 0xef, 0x80, 0xd7, 0x71, 0x00, 0x7b, 0x12, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x28, 0xd7, 0x71, 0x00, 0xb8, 0x6f, 0x9f,
-    0xc8, 0x50, 0x70, 0x38, 0x5a, 0x20, 0x7f, 0x0f, 0xb0, 0x5f, 0x00, 0x20, 0xc8, 0x50, 0x70, 0x84, 0x5a, 0x40, 0x18, 0x48, 0x08,
-    0xb8, 0x5f, 0xff, 0xdf, 0xc8, 0x50, 0x70, 0x7b, 0xf3, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x49, 0xdc, 0x5f, 0x82, 0x00, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x40, 0x2a, 0x50, 0x77, 0x08, 0x84, 0xff, 0x40, 0x18, 0x49, 0x08, 0x2c, 0x5c, 0xef, 0xc4, 0xd8, 0x71,
-    0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50,
-    0x10, 0x77, 0x08, 0x84, 0xff, 0x40, 0x18, 0x49, 0x08, 0xdc, 0x6f, 0x60, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x40, 0x2c,
-    0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x6f, 0x52,
-    0x41, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x6f, 0x40, 0x7f, 0x05, 0xb0, 0x04, 0x41, 0xdc, 0x6f,
-    0x68, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x2a, 0x50, 0x4f, 0x05, 0xb0, 0x01, 0x41, 0xdc, 0x5f, 0x82, 0x00, 0x7f, 0x00, 0xd7,
-    0x71, 0x00, 0x40, 0x2a, 0x50, 0x4f, 0x05, 0xb0, 0x08, 0x41, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50,
-    0x5f, 0x00, 0x20, 0x7f, 0x12, 0xdc, 0x6f, 0x74, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x28, 0x50, 0x77, 0x05, 0xb0, 0x20, 0x41,
-    0x84, 0x41, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x48, 0x38, 0x48, 0x08, 0x7f, 0x1e,
-    0xdc, 0x5f, 0x82, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x2a, 0x50, 0x77, 0x10, 0xa0, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x2c,
-    0xcc, 0xfc, 0xef, 0x0c, 0xd9, 0x71, 0x00, 0xa0, 0x01, 0x7b, 0x0e, 0x18, 0x47, 0x08, 0x2c, 0x5c, 0x7f, 0xa5, 0x1d, 0x00, 0x00,
-    0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x94,
-    0xd7, 0x71, 0x00, 0xb8, 0x48, 0x40, 0x84, 0x40, 0x47, 0x77, 0x07, 0x38, 0x48, 0x10, 0x7f, 0xd6, 0x2c, 0x5c, 0x7f, 0xa5, 0x1d,
-    0x00, 0x00, 0x84, 0x47, 0x40, 0x18, 0x47, 0x08, 0x10, 0x49, 0xdc, 0x5f, 0x80, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x2a,
-    0x50, 0x4f, 0x0f, 0xdc, 0x5f, 0x80, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x96, 0x50, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49,
-    0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x6f, 0x40, 0x7f, 0x28, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0x7f, 0x28, 0x66, 0x00, 0x00, 0xa0, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0xa0, 0x03, 0x2c, 0xcc, 0xf8, 0xef, 0xcc, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x10, 0x48, 0xdc, 0x5f, 0x80, 0x00, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x40, 0x86, 0x50, 0x41, 0x92, 0x50, 0x70, 0x2a, 0x41, 0x77, 0x1d, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d, 0x00,
-    0x00, 0x84, 0x40, 0x48, 0x2c, 0x5c, 0x7f, 0x98, 0x0e, 0x01, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00,
-    0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x48, 0x84, 0x00, 0x47, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0x38, 0x50, 0x20, 0x7f, 0x49, 0xdc, 0x6f, 0x7c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x47, 0x2c, 0x5c, 0xef,
-    0x6c, 0xd9, 0x71, 0x00, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x3c, 0x51, 0x40, 0x77, 0x1b, 0xdc, 0x6f, 0x50,
-    0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x6f, 0x40, 0x7f, 0x0c, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0x7f, 0xa0, 0x67, 0x00,
-    0x00, 0xdc, 0x6f, 0x7c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x48, 0x50, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0x80,
-    0xd7, 0x71, 0x00, 0x84, 0x47, 0x40, 0x18, 0x47, 0x08, 0x18, 0x47, 0x08, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x14, 0x4c, 0x84, 0x5a,
-    0x48, 0x84, 0x78, 0x47, 0x84, 0x42, 0x59, 0x70, 0x28, 0xca, 0x10, 0x77, 0x07, 0x84, 0x01, 0x40, 0x7b, 0x04, 0x80, 0x40, 0x84,
-    0x40, 0x43, 0x80, 0x44, 0x3c, 0x03, 0xca, 0x10, 0x7f, 0x1d, 0xdc, 0x4f, 0x00, 0x90, 0x01, 0x00, 0x7f, 0xc8, 0xd8, 0x00, 0x00,
-    0x40, 0x3c, 0x40, 0x57, 0x57, 0x0c, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x84, 0x01, 0x44, 0x28, 0x43, 0x77, 0x08, 0x24,
-    0x7f, 0xb7, 0x3f, 0x01, 0x00, 0x82, 0x6e, 0x70, 0x87, 0xc8, 0x02, 0xe2, 0xc9, 0x12, 0x70, 0x24, 0x7f, 0xb7, 0x3f, 0x01, 0x00,
-    0xdc, 0x0c, 0x48, 0x40, 0xd0, 0x03, 0x46, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x45, 0x28, 0x43, 0x77, 0x0f, 0x87, 0xc5, 0x02,
-    0xe2, 0x6e, 0x70, 0x87, 0xc5, 0x03, 0xe2, 0xc9, 0x12, 0x70, 0x86, 0x55, 0x6c, 0x70, 0x86, 0xc5, 0x08, 0xc9, 0x10, 0x70, 0x3b,
-    0xc5, 0x04, 0x5f, 0x80, 0x00, 0x7f, 0x0d, 0x87, 0xc5, 0x04, 0xe0, 0x40, 0xb0, 0x6f, 0x80, 0x40, 0x7b, 0x07, 0x87, 0xc5, 0x04,
-    0xe0, 0x40, 0x9e, 0x7c, 0x40, 0x86, 0x40, 0x68, 0x70, 0xde, 0x6e, 0x7e, 0x40, 0x86, 0x40, 0x6a, 0x70, 0x28, 0xc7, 0x10, 0x77,
-    0x36, 0xa0, 0xc8, 0x08, 0x04, 0x6c, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x47, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca,
-    0x10, 0x2c, 0xcc, 0xe8, 0x7f, 0xe8, 0xac, 0x00, 0x00, 0x7b, 0x34, 0xa0, 0xc8, 0x08, 0x04, 0x6c, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x47, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xe8, 0x7f, 0xac, 0x4c, 0x00, 0x00, 0x86, 0xe2,
-    0x7c, 0xe0, 0x40, 0x87, 0xc5, 0x05, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0x86, 0x40, 0x7c, 0x70, 0x84, 0x74, 0x40, 0x90, 0x74, 0x70,
-    0x87, 0x50, 0xe0, 0x46, 0x76, 0x2a, 0xff, 0x28, 0x44, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x84, 0x59, 0x41,
-    0x04, 0x7c, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x43, 0x08, 0x70, 0x10, 0x48, 0x87, 0x73, 0x48, 0x7b, 0x0f,
-    0x18, 0x48, 0x08, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef, 0xcc, 0xd7, 0x71, 0x00, 0x87, 0x48, 0xe0, 0x40, 0xa0, 0x40, 0xa0, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0x08, 0xd9, 0x71, 0x00, 0x3c, 0xff, 0x40, 0x7f, 0xdf, 0x84, 0x01, 0x40, 0x18,
-    0x48, 0x08, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x48, 0x84, 0x00, 0x47, 0x3c, 0x03, 0x78, 0x7f, 0x1d, 0xdc, 0x4f, 0x00, 0x90, 0x01,
-    0x00, 0x7f, 0xc8, 0xd8, 0x00, 0x00, 0x40, 0x3c, 0x40, 0x58, 0x57, 0x0c, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x84, 0x01,
-    0x47, 0xa0, 0x48, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x78, 0x2c, 0xcc,
-    0xf4, 0xef, 0xf0, 0xd7, 0x71, 0x00, 0x28, 0x47, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x18, 0x47, 0x08, 0x10,
-    0x48, 0x80, 0x48, 0x3c, 0x03, 0xca, 0x14, 0x7f, 0x14, 0xdc, 0x4f, 0x00, 0x90, 0x01, 0x00, 0x7f, 0xc8, 0xd8, 0x00, 0x00, 0x40,
-    0x3c, 0x40, 0xda, 0x0c, 0x5f, 0x14, 0xdc, 0x4f, 0x00, 0x90, 0x01, 0x00, 0x7f, 0xc8, 0xd8, 0x00, 0x00, 0x40, 0x3c, 0x40, 0xda,
-    0x00, 0x57, 0x0c, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x84, 0x01, 0x48, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0x04, 0xca, 0x10, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xe8, 0x7f, 0xa4, 0x49, 0x00, 0x00,
-    0x28, 0x48, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x48, 0x80, 0x48, 0x3c,
-    0x03, 0x7c, 0x7f, 0x1e, 0xdc, 0x4f, 0x00, 0x90, 0x01, 0x00, 0x7f, 0xc8, 0xd8, 0x00, 0x00, 0x40, 0x3c, 0x40, 0xda, 0x00, 0x57,
-    0x0c, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x84, 0x01, 0x48, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71,
-    0x00, 0x28, 0x48, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x18, 0x48, 0x08, 0x70, 0x10, 0x48, 0x80, 0x48, 0x3c,
-    0x03, 0x7c, 0x7f, 0x1e, 0xdc, 0x4f, 0x00, 0x90, 0x01, 0x00, 0x7f, 0xc8, 0xd8, 0x00, 0x00, 0x40, 0x3c, 0x40, 0xda, 0x00, 0x57,
-    0x0c, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x84, 0x01, 0x48, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x78, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x7c, 0x2c, 0xcc, 0xf0, 0xef, 0xf8, 0xd7, 0x71, 0x00, 0x28, 0x48, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x18,
-    0x48, 0x08, 0x70, 0x10, 0x48, 0x80, 0x48, 0x3c, 0x03, 0xca, 0x10, 0x7f, 0x1e, 0xdc, 0x4f, 0x00, 0x90, 0x01, 0x00, 0x7f, 0xc8,
-    0xd8, 0x00, 0x00, 0x40, 0x3c, 0x40, 0xda, 0x00, 0x57, 0x0c, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x84, 0x01, 0x48, 0xdc,
-    0x10, 0x5a, 0x40, 0x28, 0x50, 0x7f, 0x27, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xec, 0xef, 0xfc, 0xd7, 0x71, 0x00,
-    0x7b, 0x25, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x7c, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xec, 0x7f, 0xbc, 0xbf, 0x00, 0x00, 0x28, 0x48, 0x7f, 0x09, 0x2c,
-    0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0x0c, 0xd8, 0x71, 0x00, 0xa0,
-    0x5a, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x7c, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c,
-    0xcc, 0xec, 0x7f, 0x54, 0xab, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x10, 0xd8, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x48, 0x80,
-    0x48, 0x3c, 0x03, 0xca, 0x10, 0x7f, 0x1e, 0xdc, 0x4f, 0x00, 0x90, 0x01, 0x00, 0x7f, 0xc8, 0xd8, 0x00, 0x00, 0x40, 0x3c, 0x40,
-    0xda, 0x00, 0x57, 0x0c, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x84, 0x01, 0x48, 0xa0, 0x5a, 0xa0, 0x74, 0x86, 0x7a, 0xe4,
-    0x40, 0xa0, 0x40, 0xa0, 0x7c, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xec, 0x7f, 0x34, 0xc3, 0x00, 0x00, 0x28, 0x48, 0x7f, 0x09, 0x2c,
-    0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x40, 0x38, 0x50, 0x6f, 0x40, 0x7f, 0x2a, 0x2a, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x7f, 0x22, 0x2c, 0x5c, 0xef, 0x6c, 0xd9,
-    0x71, 0x00, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x3c, 0x40, 0x51, 0x77, 0x0d, 0x86, 0x7f, 0xb0, 0xa7, 0x71,
-    0x00, 0xe4, 0x40, 0x18, 0x49, 0x08, 0x80, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x9c, 0x6f,
-    0x7c, 0x4c, 0x04, 0xc9, 0x18, 0x46, 0xdc, 0x10, 0x46, 0x40, 0xdc, 0x14, 0x46, 0x41, 0x87, 0x00, 0xe0, 0x42, 0x84, 0x42, 0x51,
-    0x70, 0x84, 0x42, 0x50, 0x70, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb8, 0x5f, 0xff, 0xfe, 0x50, 0x70, 0xdc,
-    0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb0, 0x5f, 0x00, 0x08, 0x50, 0x70, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x3c,
-    0xd9, 0x71, 0x00, 0x87, 0x40, 0x48, 0x87, 0x48, 0xe0, 0x40, 0x3c, 0x40, 0x07, 0x7f, 0x74, 0x3c, 0x40, 0x08, 0x77, 0x08, 0x24,
-    0x7f, 0x7d, 0x4c, 0x01, 0x00, 0x3c, 0x40, 0x09, 0x7f, 0x71, 0x3c, 0x40, 0x0a, 0x77, 0x08, 0x24, 0x7f, 0x00, 0x44, 0x01, 0x00,
-    0x3c, 0x40, 0x0d, 0x77, 0x08, 0x24, 0x7f, 0x10, 0x44, 0x01, 0x00, 0x3c, 0x40, 0x1b, 0x77, 0x08, 0x24, 0x7f, 0x1c, 0x44, 0x01,
-    0x00, 0x3f, 0x20, 0x48, 0x4b, 0x08, 0x3f, 0x6f, 0x7f, 0x48, 0x77, 0x1d, 0x3f, 0x01, 0x7f, 0xca, 0x06, 0x60, 0x00, 0x77, 0x08,
-    0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3f, 0x02, 0x7f, 0xca, 0x06, 0x60, 0x00, 0x77, 0x05, 0x87, 0x20, 0x48, 0x87, 0x48, 0xe0,
-    0x40, 0xa0, 0x40, 0xa0, 0x46, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0xef, 0x7c, 0xd9, 0x71, 0x00, 0x92, 0x56, 0x70, 0x24, 0x7f, 0x80,
-    0x4c, 0x01, 0x00, 0x2c, 0x5c, 0xef, 0x94, 0xd9, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xb2, 0x07, 0x56, 0x70, 0x92,
-    0x56, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xdc, 0x10, 0x46, 0x40, 0xdc, 0x14, 0x46, 0x41, 0x87, 0x00, 0xe0, 0x42, 0x84,
-    0x42, 0x51, 0x70, 0x84, 0x42, 0x50, 0x70, 0x24, 0x7f, 0xb3, 0x4d, 0x01, 0x00, 0xdc, 0x14, 0x46, 0x40, 0x84, 0x01, 0x50, 0x70,
-    0x24, 0x7f, 0xb3, 0x4d, 0x01, 0x00, 0xdc, 0x10, 0x46, 0x40, 0x84, 0x01, 0x50, 0x70, 0x24, 0x7f, 0xb3, 0x4d, 0x01, 0x00, 0xa0,
-    0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x14, 0xd9, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x82, 0x56, 0x70, 0x24, 0x7f, 0x80,
-    0x4c, 0x01, 0x00, 0x18, 0x46, 0x08, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x3c, 0xd9, 0x71, 0x00, 0x87, 0x40, 0x48, 0x3f, 0x1b,
-    0x48, 0x7f, 0xf0, 0x87, 0x48, 0xe0, 0x40, 0x3c, 0x40, 0x37, 0x77, 0x08, 0x24, 0x7f, 0xe6, 0x44, 0x01, 0x00, 0x3c, 0x40, 0x38,
-    0x77, 0x08, 0x24, 0x7f, 0xf9, 0x44, 0x01, 0x00, 0x3c, 0x40, 0x6f, 0x5b, 0x77, 0x08, 0x24, 0x7f, 0x0a, 0x45, 0x01, 0x00, 0x3c,
-    0x40, 0x6f, 0x63, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xdc, 0x02, 0x46, 0x40, 0x87, 0x00, 0xe0, 0x41, 0x86, 0x41,
-    0x50, 0x70, 0x86, 0x41, 0x56, 0x70, 0xdc, 0x04, 0x46, 0x41, 0x84, 0x41, 0xc9, 0x30, 0x70, 0x84, 0x56, 0x51, 0x70, 0x2c, 0x5c,
-    0xef, 0x94, 0xd9, 0x71, 0x00, 0xa0, 0x00, 0xa0, 0x00, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0xdc, 0x0c,
-    0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x30, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x38, 0x42, 0x2c,
-    0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xdc, 0x04,
-    0x46, 0x41, 0x84, 0x41, 0xc9, 0x30, 0x70, 0x84, 0x56, 0x51, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x84, 0x46, 0x41, 0xdc,
-    0x04, 0x41, 0x40, 0x84, 0x50, 0x51, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xe0, 0x64, 0xe0, 0x59, 0xa0, 0x46, 0x2c, 0xcc,
-    0xf4, 0xef, 0x1c, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x6c, 0x70, 0x7f, 0x09, 0x84, 0x64, 0xc9, 0x10, 0x70, 0x7b, 0x07, 0x84, 0x01,
-    0xc9, 0x10, 0x70, 0x84, 0xc9, 0x10, 0x40, 0x77, 0x06, 0x90, 0xc9, 0x10, 0x70, 0x87, 0x59, 0xe0, 0x40, 0x3c, 0x6f, 0x50, 0x40,
-    0x77, 0x08, 0x24, 0x7f, 0x1a, 0x46, 0x01, 0x00, 0x4f, 0x08, 0x24, 0x7f, 0x38, 0x4a, 0x01, 0x00, 0x3c, 0x6f, 0x48, 0x40, 0x77,
-    0x08, 0x24, 0x7f, 0x86, 0x4a, 0x01, 0x00, 0x4f, 0x08, 0x24, 0x7f, 0x39, 0x47, 0x01, 0x00, 0x3c, 0x6f, 0x42, 0x40, 0x7f, 0x29,
-    0x4f, 0x08, 0x24, 0x7f, 0xaf, 0x46, 0x01, 0x00, 0x3c, 0x6f, 0x40, 0x40, 0x7f, 0x28, 0x4f, 0x08, 0x24, 0x7f, 0x96, 0x46, 0x01,
-    0x00, 0x3c, 0x1b, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x87, 0x59, 0x48, 0x7a, 0xab, 0xfd, 0xdc, 0x02, 0x46,
-    0x40, 0x92, 0x50, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x86, 0x56, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x02, 0x46, 0x40, 0x86,
-    0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xbc, 0xc9, 0x10, 0x40, 0xa0, 0x40, 0xdc, 0x02,
-    0x46, 0x40, 0xde, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x86, 0x56, 0xe4, 0x40, 0x9c,
-    0xc9, 0x10, 0x40, 0xa0, 0x40, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x74, 0x42, 0x2c, 0xcc,
-    0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46, 0x2c, 0xcc,
-    0xf0, 0xef, 0xe0, 0xd8, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x86, 0x56, 0xe4, 0x40, 0x9c, 0xc9, 0x10, 0x40, 0xa0,
-    0x40, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x02, 0x50, 0x40, 0xa0, 0x40,
-    0xdc, 0x02, 0x46, 0x40, 0xde, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00,
-    0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x86, 0x56, 0xe4,
-    0x40, 0xa0, 0x40, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x74, 0x42, 0x2c, 0xcc, 0xf8, 0x7f,
-    0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46, 0x2c, 0xcc, 0xf0, 0xef,
-    0xe0, 0xd8, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x41, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01,
-    0x00, 0xdc, 0x02, 0x46, 0x40, 0x96, 0x50, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x44, 0x40, 0x77, 0x08, 0x24,
-    0x7f, 0x7d, 0x4c, 0x01, 0x00, 0x47, 0x32, 0x3c, 0x6f, 0x43, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x92, 0x56,
-    0x70, 0x86, 0x56, 0xe4, 0x40, 0xdc, 0x08, 0x46, 0x41, 0x3c, 0x51, 0x40, 0x47, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xdc,
-    0x0a, 0x46, 0x40, 0x86, 0x50, 0x56, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x46, 0x40, 0x7f, 0x08, 0x24, 0x7f,
-    0x80, 0x4c, 0x01, 0x00, 0x3c, 0x01, 0x6c, 0x77, 0x0c, 0x28, 0x64, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x87, 0x1b,
-    0xc9, 0x30, 0x70, 0x87, 0x6f, 0x5b, 0xc9, 0x31, 0x70, 0x87, 0x6f, 0x46, 0xc9, 0x33, 0x70, 0xdf, 0x30, 0x7f, 0xb6, 0x06, 0x60,
-    0x00, 0x40, 0x87, 0x40, 0xc9, 0x32, 0x70, 0xa0, 0x04, 0xe0, 0xc9, 0x30, 0x2c, 0xcc, 0xf8, 0xef, 0xbc, 0xd7, 0x71, 0x00, 0x24,
-    0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x4c, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x2e, 0x49, 0x01, 0x00, 0x4f, 0x08, 0x24, 0x7f,
-    0xb8, 0x49, 0x01, 0x00, 0x3c, 0x6f, 0x4b, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x49, 0x48, 0x01, 0x00, 0x4f, 0x08, 0x24, 0x7f, 0x80,
-    0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x4a, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x28, 0x64, 0x7f, 0x08, 0x24, 0x7f,
-    0xc1, 0x48, 0x01, 0x00, 0x86, 0x56, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc,
-    0x08, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0xdc, 0x02, 0x46, 0x40, 0xde, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9,
-    0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x74, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0xef, 0xd8,
-    0xd7, 0x71, 0x00, 0xa0, 0x00, 0xdc, 0x02, 0x46, 0x40, 0xde, 0x01, 0x50, 0x40, 0xa0, 0x40, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x01,
-    0x50, 0x40, 0xa0, 0x40, 0xdc, 0x0c, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0,
-    0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0xc9, 0x74, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00, 0x24, 0x7f,
-    0x80, 0x4c, 0x01, 0x00, 0x28, 0x64, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x86, 0x56, 0xe4, 0x40, 0xa0, 0x40, 0xdc,
-    0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0xdc, 0x02,
-    0x46, 0x40, 0xde, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x74, 0x42, 0x2c,
-    0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x02,
-    0x64, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xa0, 0x00, 0xa0, 0x00, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40,
-    0xa0, 0x40, 0xdc, 0x0c, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00,
-    0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04,
-    0xc9, 0x74, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c,
-    0x01, 0x00, 0xdc, 0x0c, 0x46, 0x40, 0x3c, 0x50, 0xc9, 0x10, 0x4f, 0x06, 0x80, 0x47, 0x7b, 0x12, 0xdc, 0x0c, 0x46, 0x40, 0xdc,
-    0x01, 0x50, 0x40, 0xbc, 0xc9, 0x10, 0x40, 0x70, 0x84, 0x40, 0x47, 0xa0, 0x00, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40,
-    0xa0, 0x40, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x02, 0x50, 0x40, 0xa0, 0x40, 0xa0, 0x47, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0,
-    0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x00, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0x9c, 0xc9, 0x10, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x74, 0x42,
-    0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46,
-    0x2c, 0xcc, 0xf0, 0xef, 0xe0, 0xd8, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x4d, 0x40, 0x7f, 0x08, 0x24,
-    0x7f, 0x80, 0x4c, 0x01, 0x00, 0xa0, 0x00, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0x9c, 0xc9, 0x10, 0x40, 0xa0, 0x40,
-    0xdc, 0x08, 0x46, 0x40, 0xdc, 0x02, 0x50, 0x40, 0xa0, 0x40, 0xdc, 0x0c, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04,
-    0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9,
-    0x74, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x46, 0x2c, 0xcc, 0xf0, 0xef, 0xe0, 0xd8, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x66, 0x40, 0x7f,
-    0x4a, 0x4f, 0x08, 0x24, 0x7f, 0x14, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x54, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x62, 0x4b, 0x01, 0x00,
-    0x4f, 0x08, 0x24, 0x7f, 0xd9, 0x4b, 0x01, 0x00, 0x3c, 0x6f, 0x53, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xa0,
-    0x00, 0xdc, 0x0c, 0x46, 0x40, 0x3c, 0x50, 0xc9, 0x10, 0x47, 0x08, 0x24, 0x7f, 0x00, 0x4b, 0x01, 0x00, 0xdc, 0x0c, 0x46, 0x40,
-    0xdc, 0x01, 0x50, 0x40, 0x24, 0x7f, 0x04, 0x4b, 0x01, 0x00, 0x28, 0x6c, 0x77, 0x18, 0xdc, 0x02, 0x46, 0x40, 0x87, 0x00, 0xe0,
-    0x41, 0x86, 0x41, 0x50, 0x70, 0x86, 0x41, 0x56, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x28, 0x64, 0x7f, 0x05, 0x94, 0x64,
-    0x70, 0xdc, 0x0c, 0x46, 0x40, 0x3c, 0x50, 0x64, 0x4f, 0x10, 0xdc, 0x02, 0x46, 0x40, 0xdc, 0x0e, 0x46, 0x41, 0x86, 0x51, 0xe4,
-    0x41, 0x7b, 0x0a, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x66, 0xe4, 0x41, 0x86, 0x41, 0x50, 0x70, 0x3c, 0x02, 0x6c, 0x77, 0x2a, 0x28,
-    0x68, 0x7f, 0x05, 0x94, 0x68, 0x70, 0xdc, 0x08, 0x46, 0x40, 0x3c, 0x50, 0x68, 0x4f, 0x10, 0xdc, 0x0a, 0x46, 0x40, 0x86, 0x50,
-    0x56, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x86, 0x6a, 0x56, 0x70, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x82, 0x56, 0x70,
-    0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x84, 0xc9, 0x10, 0x40, 0xa0, 0x40, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0,
-    0x40, 0xdc, 0x0c, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3,
-    0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00,
-    0xa0, 0x00, 0x04, 0xc9, 0x74, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x46, 0x2c, 0xcc, 0xf0, 0xef, 0xe0, 0xd8, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xdc,
-    0x0c, 0x46, 0x40, 0x3c, 0x50, 0xc9, 0x10, 0x4f, 0x06, 0x80, 0x47, 0x7b, 0x12, 0xdc, 0x0c, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40,
-    0xbc, 0xc9, 0x10, 0x40, 0x70, 0x84, 0x40, 0x47, 0xa0, 0x00, 0xa0, 0x00, 0xdc, 0x08, 0x46, 0x40, 0xdc, 0x01, 0x50, 0x40, 0xa0,
-    0x40, 0xa0, 0x47, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0xa0, 0xc9, 0x10, 0x04, 0xc9, 0x74, 0x42, 0x2c,
-    0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46, 0x2c,
-    0xcc, 0xf0, 0xef, 0xe0, 0xd8, 0x71, 0x00, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0x3c, 0x6f, 0x63, 0x40, 0x7f, 0x08, 0x24, 0x7f,
-    0x80, 0x4c, 0x01, 0x00, 0x3c, 0x01, 0x6c, 0x77, 0x0c, 0x28, 0x64, 0x7f, 0x08, 0x24, 0x7f, 0x80, 0x4c, 0x01, 0x00, 0xa0, 0x7f,
-    0x78, 0xd8, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0x64, 0xd6, 0x00, 0x00, 0xa0, 0x40, 0xa0, 0x7f, 0x78, 0xd8, 0x00, 0x00, 0x2c,
-    0xcc, 0xf8, 0xef, 0xbc, 0xd7, 0x71, 0x00, 0x7b, 0x6e, 0x3c, 0x6f, 0x6d, 0x40, 0x7f, 0x37, 0x47, 0x3d, 0x3c, 0x6f, 0x67, 0x40,
-    0x77, 0x60, 0x87, 0x1b, 0xc9, 0x30, 0x70, 0x87, 0x6f, 0x5b, 0xc9, 0x31, 0x70, 0x87, 0x6f, 0x67, 0xc9, 0x33, 0x70, 0xdf, 0x30,
-    0x7f, 0x86, 0x06, 0x60, 0x00, 0x40, 0x87, 0x40, 0xc9, 0x32, 0x70, 0xa0, 0x04, 0xe0, 0xc9, 0x30, 0x2c, 0xcc, 0xf8, 0xef, 0xbc,
-    0xd7, 0x71, 0x00, 0x7b, 0x33, 0x80, 0x47, 0x24, 0x7f, 0xb5, 0x4d, 0x01, 0x00, 0x3c, 0x6f, 0x71, 0x40, 0x77, 0x25, 0x3c, 0x02,
-    0x6c, 0x77, 0x20, 0x3c, 0x01, 0x64, 0x4b, 0x1b, 0x3c, 0x08, 0x64, 0x47, 0x16, 0x3c, 0x32, 0x68, 0x4f, 0x04, 0x7b, 0x0f, 0x80,
-    0xc9, 0x64, 0x70, 0x24, 0x7f, 0x1a, 0x4d, 0x01, 0x00, 0x96, 0x56, 0x70, 0x86, 0x56, 0xe4, 0x40, 0xdc, 0x08, 0x46, 0x41, 0x3c,
-    0x51, 0x40, 0x4f, 0x0f, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x14, 0xd9, 0x71, 0x00, 0x82, 0x56, 0x70, 0x86, 0x56, 0xe4, 0x40,
-    0xdc, 0x08, 0x46, 0x41, 0xdc, 0x01, 0x51, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x10, 0xdc, 0x0a, 0x46, 0x40, 0xde, 0x01, 0x50, 0x40,
-    0x86, 0x40, 0x56, 0x70, 0x7b, 0x09, 0x2a, 0x56, 0x43, 0x05, 0x82, 0x56, 0x70, 0xdc, 0x02, 0x46, 0x40, 0x86, 0x50, 0xe4, 0x40,
-    0xdc, 0x0c, 0x46, 0x41, 0x3c, 0x51, 0x40, 0x4f, 0x10, 0xdc, 0x02, 0x46, 0x40, 0xdc, 0x0e, 0x46, 0x41, 0x86, 0x51, 0x50, 0x70,
-    0x7b, 0x11, 0xdc, 0x02, 0x46, 0x40, 0x2a, 0x50, 0x43, 0x09, 0xdc, 0x02, 0x46, 0x40, 0x82, 0x50, 0x70, 0xdc, 0x5f, 0x82, 0x00,
-    0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x2a, 0x50, 0x4e, 0x2e, 0xf6, 0x7a, 0x2b, 0xf6, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x3c,
-    0xd9, 0x71, 0x00, 0x04, 0xc9, 0x30, 0x41, 0x9c, 0xc9, 0x64, 0x41, 0x87, 0x40, 0x51, 0x70, 0x90, 0xc9, 0x64, 0x70, 0x3c, 0x68,
-    0xc9, 0x64, 0x4b, 0xe2, 0x94, 0x64, 0x70, 0x84, 0x64, 0xc9, 0x68, 0x70, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d, 0x00, 0x00, 0x2c, 0x5c,
-    0x7f, 0xfc, 0x43, 0x00, 0x00, 0x80, 0xc9, 0x64, 0x70, 0x7b, 0x29, 0xe8, 0x5f, 0xcc, 0x00, 0xc9, 0x68, 0x40, 0x9c, 0x4f, 0x18,
-    0x00, 0x60, 0x00, 0x40, 0xd0, 0x02, 0xc9, 0x64, 0x41, 0x9c, 0x41, 0x40, 0x04, 0xc9, 0x30, 0x41, 0x9c, 0xc9, 0x64, 0x41, 0x87,
-    0x51, 0xc0, 0x02, 0x70, 0x90, 0xc9, 0x64, 0x70, 0x3c, 0x68, 0xc9, 0x64, 0x4b, 0xd5, 0xe8, 0x5f, 0xcc, 0x00, 0xc9, 0x68, 0x40,
-    0x9c, 0x4f, 0x18, 0x00, 0x60, 0x00, 0x40, 0xd0, 0x02, 0x68, 0x41, 0x9c, 0x41, 0x40, 0x83, 0xc0, 0x02, 0x70, 0x2c, 0x5c, 0x7f,
-    0xee, 0x44, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xa5, 0x1d, 0x00, 0x00, 0x7a, 0xf0, 0xfe, 0x04, 0x64, 0x40, 0xd0, 0x02, 0x47, 0x41,
-    0x9c, 0x41, 0x40, 0x84, 0x50, 0x40, 0x3c, 0x07, 0x40, 0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90,
-    0x80, 0x6d, 0x01, 0x00, 0x90, 0x47, 0x3c, 0x6c, 0x47, 0x4b, 0xdb, 0x7a, 0xc6, 0xfe, 0x70, 0x70, 0x70, 0x10, 0x45, 0x84, 0x74,
-    0x47, 0x84, 0x78, 0x46, 0x84, 0x5a, 0x45, 0x84, 0x00, 0x48, 0x7b, 0x14, 0x28, 0x48, 0x77, 0x07, 0x80, 0x40, 0x18, 0x45, 0x08,
-    0xdc, 0x01, 0x48, 0x40, 0x18, 0x45, 0x08, 0x90, 0x48, 0x3c, 0x02, 0x48, 0x43, 0x2f, 0xd0, 0x02, 0x48, 0x40, 0x9c, 0x45, 0x40,
-    0xa0, 0x40, 0xa0, 0x47, 0xa0, 0x46, 0x2c, 0xcc, 0xf4, 0xef, 0xc0, 0xd8, 0x71, 0x00, 0xbc, 0xff, 0x40, 0x4b, 0x15, 0x3c, 0x03,
-    0x40, 0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0xa0, 0x6d, 0x01, 0x00, 0x84, 0xff, 0x40, 0x18,
-    0x45, 0x08, 0x18, 0x45, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x84, 0x78, 0x46, 0x84, 0x00, 0x48, 0x84, 0x48, 0x47, 0x7b, 0x16,
-    0x18, 0x46, 0x08, 0xe8, 0x0a, 0x48, 0x40, 0xff, 0x30, 0x42, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x48, 0x84, 0x01, 0x47, 0xa0,
-    0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x3c, 0xd9, 0x71, 0x00, 0x87, 0x40, 0x42, 0x3f, 0x39, 0x42, 0x47, 0x07, 0x3f, 0x30, 0x42, 0x43,
-    0xda, 0x84, 0x48, 0xda, 0x00, 0x70, 0x3f, 0x3b, 0x42, 0x77, 0x08, 0x84, 0x01, 0x40, 0x18, 0x46, 0x08, 0x87, 0x42, 0xda, 0x04,
-    0x70, 0x28, 0x47, 0x7f, 0x07, 0x80, 0x40, 0x18, 0x46, 0x08, 0x84, 0x02, 0x40, 0x18, 0x46, 0x08, 0x10, 0x49, 0x9c, 0x10, 0x4c,
-    0x84, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xfc, 0xef,
-    0x34, 0xd9, 0x71, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84,
-    0x50, 0x40, 0x28, 0xc0, 0x10, 0x77, 0x66, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x59, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xde, 0x09, 0x59, 0x40, 0xa0, 0x40, 0xde, 0x10, 0x62, 0x40,
-    0xa0, 0x40, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0x7f, 0x90, 0xaa, 0x00, 0x00, 0x18,
-    0x49, 0x08, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xde, 0x09, 0x59, 0x40, 0xa0, 0x40, 0xde, 0x10, 0x62, 0x40, 0xa0, 0x40, 0x04, 0x64, 0x42,
-    0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x10, 0x49, 0x9c,
-    0x14, 0x4c, 0x84, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc,
-    0xfc, 0xef, 0x34, 0xd9, 0x71, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0x84, 0x50, 0x40, 0x28, 0xc0, 0x10, 0x77, 0x7a, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xde,
-    0x01, 0x59, 0x40, 0xa0, 0x40, 0xde, 0x01, 0x62, 0x40, 0xa0, 0x40, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00,
-    0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xde, 0x08, 0x59, 0x40, 0xa0, 0x40, 0xde, 0x0f, 0x62,
-    0x40, 0xa0, 0x40, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0x7f, 0x90, 0xaa, 0x00, 0x00,
-    0x18, 0x49, 0x08, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xde, 0x01, 0x59, 0x40, 0xa0, 0x40, 0xde,
-    0x01, 0x62, 0x40, 0xa0, 0x40, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xde, 0x08, 0x59, 0x40, 0xa0, 0x40, 0xde, 0x0f, 0x62, 0x40, 0xa0, 0x40, 0x04, 0x68, 0x42,
-    0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x10, 0x43, 0x80,
-    0x43, 0x84, 0x43, 0x47, 0x84, 0x01, 0x46, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x5f, 0x00, 0x20,
-    0x7f, 0x1c, 0x84, 0x01, 0x45, 0xdc, 0x6f, 0x74, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc, 0x7f, 0xb0, 0xd9, 0x71, 0x00, 0x50,
-    0x44, 0x24, 0x7f, 0xb9, 0x52, 0x01, 0x00, 0x80, 0x45, 0x24, 0x7f, 0xb9, 0x52, 0x01, 0x00, 0x18, 0x43, 0x08, 0xdc, 0x6f, 0x50,
-    0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x5f, 0x00, 0x01, 0x7f, 0x11, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0xb8, 0x5f, 0xff, 0xf6, 0x50, 0x70, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x5f, 0x00, 0x08,
-    0x77, 0x08, 0x24, 0x7f, 0xc1, 0x51, 0x01, 0x00, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb8, 0x5f, 0xff, 0xf7,
-    0x50, 0x70, 0x82, 0xda, 0x00, 0x70, 0xdc, 0x02, 0x5a, 0x40, 0x82, 0x50, 0x70, 0xdc, 0x04, 0x5a, 0x40, 0xdc, 0x06, 0x5a, 0x41,
-    0x87, 0x00, 0xe0, 0x42, 0x86, 0x42, 0x51, 0x70, 0x86, 0x42, 0x50, 0x70, 0xdc, 0x08, 0x5a, 0x40, 0xdc, 0x6f, 0x5c, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x41, 0x86, 0x51, 0xe4, 0x41, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x42, 0xde, 0x06, 0x52, 0x42,
-    0xbc, 0x42, 0x41, 0xac, 0x09, 0x41, 0xbc, 0x01, 0x41, 0x84, 0x41, 0x50, 0x70, 0xdc, 0x0c, 0x5a, 0x40, 0xdc, 0x6f, 0x5e, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x41, 0x86, 0x51, 0xe4, 0x41, 0xdc, 0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x42, 0xde, 0x06, 0x52,
-    0x42, 0xbc, 0x42, 0x41, 0xac, 0x10, 0x41, 0xbc, 0x01, 0x41, 0x84, 0x41, 0x50, 0x70, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef, 0x90,
-    0xd8, 0x71, 0x00, 0x84, 0x01, 0x47, 0x3c, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x77, 0x07, 0x84, 0x01,
-    0x46, 0x7b, 0x0c, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xaf, 0xc1, 0xfd, 0x80, 0x46, 0x28, 0x47, 0x77, 0x0f, 0xa0, 0x5a, 0x2c, 0xcc,
-    0xfc, 0xef, 0x90, 0xd8, 0x71, 0x00, 0x84, 0x01, 0x47, 0x3c, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x77,
-    0x08, 0x24, 0x7f, 0x9b, 0x52, 0x01, 0x00, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xaf, 0x93, 0xfd, 0xfc, 0x46, 0x01, 0x46, 0x24, 0x7f,
-    0x9b, 0x52, 0x01, 0x00, 0xa0, 0x1e, 0x2c, 0xcc, 0xfc, 0xef, 0xec, 0xd7, 0x71, 0x00, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x40, 0x38, 0x50, 0x04, 0x7f, 0x09, 0x84, 0x01, 0x43, 0xa0, 0x20, 0x7b, 0x04, 0xa0, 0x28, 0x2c, 0xcc, 0xfc, 0xef, 0xd4,
-    0xd7, 0x71, 0x00, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x5f, 0x00, 0x08, 0x7f, 0x08, 0x24, 0x7f,
-    0xb9, 0x52, 0x01, 0x00, 0x3c, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x7f, 0x24, 0x28, 0x47, 0x77, 0x10,
-    0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0xd8, 0x71, 0x00, 0xfc, 0x47, 0x01, 0x47, 0x28, 0x46, 0x7f, 0x44, 0xa0, 0x5a, 0x2c,
-    0xcc, 0xfc, 0xaf, 0x1f, 0xfd, 0x80, 0x46, 0x7b, 0x38, 0x28, 0x46, 0x77, 0x0d, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xaf, 0x0f, 0xfd,
-    0x84, 0x01, 0x46, 0x2b, 0x7f, 0xc2, 0x06, 0x60, 0x00, 0x77, 0x06, 0x28, 0x47, 0x77, 0x1d, 0xdc, 0x6f, 0x74, 0x7f, 0x00, 0xd7,
-    0x71, 0x00, 0x40, 0x28, 0x50, 0x77, 0x10, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0xd8, 0x71, 0x00, 0xfc, 0x47, 0x01, 0x47,
-    0xdc, 0x5f, 0x82, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x2a, 0x50, 0x7e, 0x50, 0xff, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7,
-    0x71, 0x00, 0x40, 0x38, 0x50, 0x04, 0x76, 0x41, 0xff, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x5f,
-    0x00, 0x08, 0x76, 0x18, 0xfe, 0xdc, 0x5f, 0x82, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x2a, 0x50, 0x7e, 0x09, 0xfe, 0xdc,
-    0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x38, 0x50, 0x04, 0x76, 0xfa, 0xfd, 0x28, 0x43, 0x7f, 0x10, 0xa0, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x0c, 0xd9, 0x71, 0x00, 0x28, 0x47, 0x7f, 0x0c, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef,
-    0x90, 0xd8, 0x71, 0x00, 0x28, 0x46, 0x77, 0x0a, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xaf, 0x6c, 0xfc, 0x28, 0x45, 0x7f, 0x35, 0x3c,
-    0x7f, 0xb0, 0xd9, 0x71, 0x00, 0x44, 0x4f, 0x19, 0xdc, 0x6f, 0x74, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xfc, 0x7f, 0xb0, 0xd9,
-    0x71, 0x00, 0x44, 0x41, 0x84, 0x41, 0x50, 0x70, 0x7b, 0x30, 0xdc, 0x6f, 0x74, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x87, 0x00,
-    0xe0, 0x41, 0x84, 0x41, 0x50, 0x70, 0x7b, 0x1d, 0xdc, 0x6f, 0x50, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xb8, 0x5f, 0xff, 0xdf,
-    0x50, 0x70, 0xdc, 0x6f, 0x74, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x80, 0x50, 0x70, 0xdc, 0x5f, 0x48, 0x01, 0x7f, 0x00, 0xd7,
-    0x71, 0x00, 0x40, 0x84, 0x50, 0x41, 0x90, 0x50, 0x70, 0xfb, 0x6f, 0x7f, 0x51, 0x40, 0x87, 0x40, 0x48, 0xdc, 0x5f, 0x82, 0x00,
-    0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x96, 0x50, 0x70, 0xdc, 0x5f, 0x48, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xdc, 0x5f,
-    0x44, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x3c, 0x41, 0x50, 0x5b, 0x1a, 0xdc, 0x5f, 0x48, 0x01, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x40, 0xdc, 0x5f, 0x84, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0x84, 0x41, 0x50, 0x70, 0x87, 0x48, 0xe0, 0x40, 0x18,
-    0x43, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x04, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x04, 0x5a, 0x48, 0xaa, 0x09, 0x58, 0x70, 0x84, 0x48,
-    0x40, 0x9c, 0x02, 0x48, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xde, 0x03, 0x51, 0x41, 0x9e, 0x41, 0x50, 0x70,
-    0x86, 0x58, 0xe4, 0x40, 0x87, 0x04, 0xe0, 0x41, 0x86, 0x41, 0xe4, 0x41, 0xd0, 0x41, 0x40, 0x40, 0x86, 0x40, 0x58, 0x70, 0xdc,
-    0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xde, 0x03, 0x50, 0x40, 0x9e, 0x40, 0x58, 0x70, 0x84, 0x59, 0x41, 0x04, 0x5a,
-    0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x48, 0x08, 0x10, 0x48, 0x9c, 0x04, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x04,
-    0x5a, 0x48, 0xaa, 0x09, 0x58, 0x70, 0x84, 0x48, 0x40, 0x9c, 0x02, 0x48, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41,
-    0xde, 0x03, 0x51, 0x41, 0x9e, 0x41, 0x50, 0x70, 0x86, 0x58, 0xe4, 0x40, 0x87, 0x04, 0xe0, 0x41, 0x86, 0x41, 0xe4, 0x41, 0xd0,
-    0x41, 0x40, 0x40, 0x86, 0x40, 0x58, 0x70, 0x84, 0x48, 0x40, 0x9c, 0x02, 0x48, 0xdc, 0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x41, 0xde, 0x03, 0x51, 0x41, 0x9e, 0x41, 0x50, 0x70, 0xaa, 0x09, 0x58, 0x70, 0x84, 0x48, 0x40, 0x9c, 0x02, 0x48, 0xdc, 0x6f,
-    0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xde, 0x03, 0x51, 0x41, 0x9e, 0x41, 0x50, 0x70, 0x86, 0x58, 0xe4, 0x40, 0x87, 0x04,
-    0xe0, 0x41, 0x86, 0x41, 0xe4, 0x41, 0xd0, 0x41, 0x40, 0x40, 0x86, 0x40, 0x58, 0x70, 0xdc, 0x6f, 0x5a, 0x7f, 0x00, 0xd7, 0x71,
-    0x00, 0x40, 0xde, 0x03, 0x50, 0x40, 0x9e, 0x40, 0x58, 0x70, 0x84, 0x59, 0x41, 0x04, 0x5a, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x48, 0x08, 0x10, 0x49, 0x9c, 0x14, 0x4c, 0xe0, 0x5a, 0xa0, 0x7c, 0x2c,
-    0xcc, 0xf8, 0xef, 0x7c, 0xd8, 0x71, 0x00, 0xe0, 0x74, 0xa0, 0x7c, 0x2c, 0xcc, 0xf8, 0xef, 0x7c, 0xd8, 0x71, 0x00, 0xe0, 0x78,
-    0xa0, 0x7c, 0x2c, 0xcc, 0xf8, 0xef, 0x7c, 0xd8, 0x71, 0x00, 0x04, 0x78, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0x7f,
-    0xe6, 0xc2, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x2f, 0x56, 0x01, 0x00, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00,
-    0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x6f, 0x54, 0x7f,
-    0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x78, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x68, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x34, 0xd9, 0x71, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xe8, 0x7f, 0xa4, 0x49, 0x00, 0x00, 0x3e, 0x5a, 0x78, 0x7f, 0x08, 0x24, 0x7f, 0xaa, 0x56, 0x01,
-    0x00, 0x3e, 0x72, 0x7a, 0x4b, 0x08, 0x24, 0x7f, 0x32, 0x56, 0x01, 0x00, 0x86, 0x78, 0xe4, 0x40, 0xa0, 0x40, 0xfe, 0x72, 0x76,
-    0x40, 0x86, 0x7a, 0xe4, 0x41, 0x9c, 0x41, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71,
-    0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c,
-    0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00, 0x24, 0x7f, 0x9c, 0x57, 0x01, 0x00, 0x18, 0x49, 0x08, 0x04, 0x5a, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x86, 0x74, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7a, 0xe4, 0x40, 0xa0, 0x40,
-    0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xf4, 0xef,
-    0xd8, 0xd7, 0x71, 0x00, 0x24, 0x7f, 0x9c, 0x57, 0x01, 0x00, 0x3e, 0x5a, 0x78, 0x43, 0x7d, 0xfe, 0x5a, 0x74, 0x40, 0x86, 0x78,
-    0xe4, 0x41, 0x9c, 0x41, 0x40, 0xa0, 0x40, 0x86, 0x7a, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17,
-    0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef,
-    0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x00, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00, 0x7b, 0x74, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x86, 0x78, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x76, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c,
-    0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42,
-    0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0xd9, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00,
-    0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x42, 0x2a, 0x52, 0x43, 0x05,
-    0x82, 0x52, 0x70, 0x2a, 0xc2, 0x02, 0x43, 0x06, 0x82, 0xc2, 0x02, 0x70, 0x86, 0x52, 0xe4, 0x40, 0xdc, 0x08, 0x74, 0x41, 0xdc,
-    0x01, 0x51, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x0e, 0xdc, 0x0a, 0x74, 0x40, 0xde, 0x01, 0x50, 0x40, 0x86, 0x40, 0x52, 0x70, 0x86,
-    0xc2, 0x02, 0xe4, 0x40, 0xdc, 0x0c, 0x74, 0x41, 0xdc, 0x01, 0x51, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x0f, 0xdc, 0x0e, 0x74, 0x40,
-    0xde, 0x01, 0x50, 0x40, 0x86, 0x40, 0xc2, 0x02, 0x70, 0x18, 0x49, 0x08, 0x10, 0x46, 0x9c, 0x1c, 0x4c, 0x87, 0x73, 0x48, 0x84,
-    0x00, 0x47, 0x87, 0x48, 0xe0, 0x40, 0xd0, 0x03, 0x40, 0x40, 0x9c, 0x4f, 0x8c, 0x01, 0x01, 0x00, 0x40, 0x84, 0x40, 0x46, 0x86,
-    0xda, 0x04, 0x59, 0x70, 0xdc, 0x02, 0x74, 0x40, 0x86, 0x50, 0x62, 0x70, 0x86, 0x56, 0x68, 0x70, 0x86, 0xc6, 0x08, 0x6c, 0x70,
-    0x82, 0x6a, 0x70, 0x87, 0x7f, 0x82, 0x01, 0x01, 0x00, 0xe2, 0x6e, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0x34, 0xd9, 0x71, 0x00, 0x04, 0x64, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x3c, 0x03, 0x78, 0x7f, 0x0e, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0x80, 0x78, 0x70, 0x90, 0x47, 0xdc,
-    0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x40, 0x28, 0xc0, 0x10, 0x77, 0x41, 0xa0, 0x7f, 0x88, 0x01, 0x01,
-    0x00, 0x04, 0x68, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x78, 0x2c, 0xcc, 0xe8, 0x7f, 0xe8, 0xac, 0x00, 0x00, 0x7b, 0x3f, 0xa0, 0x7f, 0x88, 0x01, 0x01,
-    0x00, 0x04, 0x68, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x78, 0x2c, 0xcc, 0xe8, 0x7f, 0xac, 0x4c, 0x00, 0x00, 0xdc, 0x10, 0x74, 0x40, 0x28, 0x50, 0x7f,
-    0x5d, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x86, 0x64, 0xe4, 0x40, 0xa0, 0x40, 0xde, 0x0c, 0x66,
-    0x40, 0xa0, 0x40, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xde, 0x09, 0x64, 0x40, 0xa0, 0x40, 0xde, 0x0c, 0x66, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x14, 0x42,
-    0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00,
-    0x2c, 0xcc, 0xf0, 0xef, 0xf8, 0xd7, 0x71, 0x00, 0xdc, 0x14, 0x74, 0x40, 0x28, 0x50, 0x7f, 0x65, 0xdc, 0x6f, 0x54, 0x7f, 0x00,
-    0xd7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xde,
-    0x09, 0x64, 0x40, 0xa0, 0x40, 0xde, 0x0e, 0x66, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3,
-    0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf8, 0x7f,
-    0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0xf4, 0xd7, 0x71, 0x00, 0x28, 0x47, 0x7f, 0x09, 0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00,
-    0x18, 0x46, 0x08, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x08, 0x4c, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0xd9, 0x71, 0x00, 0x84,
-    0x40, 0x48, 0xdc, 0x02, 0x5a, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xdc, 0x0c, 0x5a, 0x41, 0x3c, 0x51, 0x40, 0x43, 0x08, 0x24, 0x7f,
-    0xa8, 0x5a, 0x01, 0x00, 0x2c, 0x5c, 0xef, 0x24, 0xd7, 0x71, 0x00, 0xdc, 0x6f, 0x54, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0xa0,
-    0x50, 0xa0, 0x48, 0x2c, 0xcc, 0xf8, 0xef, 0xe4, 0xd8, 0x71, 0x00, 0xdc, 0x6f, 0x58, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x86,
-    0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x6f, 0x5e, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xd0, 0x04, 0x48,
-    0x41, 0xbc, 0x41, 0x40, 0xa0, 0x40, 0xdc, 0x6f, 0x5c, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40,
-    0xdc, 0x6f, 0x5e, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf0,
-    0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xf4, 0xef, 0xd8, 0xd7, 0x71, 0x00, 0x2c, 0x5c, 0xef, 0x20, 0xd7, 0x71, 0x00, 0xdc, 0x02, 0x5a,
-    0x40, 0xdc, 0x0e, 0x5a, 0x41, 0xde, 0x01, 0x51, 0x41, 0x84, 0x48, 0x42, 0x86, 0x42, 0x42, 0xbe, 0x42, 0x41, 0x86, 0x41, 0x50,
-    0x70, 0x7b, 0x09, 0xdc, 0x02, 0x5a, 0x40, 0x92, 0x50, 0x70, 0x2b, 0x7f, 0x0a, 0x00, 0x60, 0x00, 0x7f, 0x06, 0x82, 0xda, 0x00,
-    0x70, 0x18, 0x48, 0x08, 0x10, 0x47, 0x9c, 0x10, 0x4c, 0x84, 0x5a, 0x48, 0x84, 0x74, 0x47, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x04, 0x04, 0x68, 0x42, 0x2c,
-    0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x04, 0x59, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xd0,
-    0x04, 0x47, 0x40, 0x9e, 0x03, 0x40, 0x9e, 0x40, 0x62, 0x70, 0xa0, 0x48, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x48, 0x86, 0x59, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x62,
-    0xe4, 0x40, 0xd0, 0x04, 0x47, 0x41, 0xbc, 0x41, 0x40, 0xa0, 0x40, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00,
-    0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xe8, 0x7f, 0xa4, 0x49, 0x00,
-    0x00, 0x18, 0x47, 0x08, 0x70, 0x10, 0x47, 0xdc, 0x5f, 0x48, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x41, 0xdc,
-    0x5f, 0x44, 0x01, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x42, 0x84, 0x00, 0x48, 0xdc, 0x5f, 0x82, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00,
-    0x40, 0x86, 0x50, 0xe4, 0x47, 0x7f, 0x33, 0x7b, 0x23, 0x18, 0x47, 0x08, 0x3f, 0x0a, 0x51, 0x77, 0x04, 0x90, 0x48, 0x84, 0x41,
-    0x40, 0x90, 0x41, 0x3c, 0x42, 0x40, 0x77, 0x0c, 0xdc, 0x5f, 0x84, 0x00, 0x7f, 0x00, 0xd7, 0x71, 0x00, 0x41, 0xbc, 0x01, 0x47,
-    0x28, 0x47, 0x4f, 0x0c, 0x3f, 0x1b, 0x51, 0x7f, 0x07, 0x3f, 0x0b, 0x51, 0x77, 0xd6, 0x28, 0x48, 0x4f, 0x1b, 0xdc, 0x0c, 0x5a,
-    0x40, 0x3c, 0x50, 0x48, 0x43, 0x08, 0x84, 0x48, 0x40, 0x18, 0x47, 0x08, 0xdc, 0x0c, 0x5a, 0x40, 0x84, 0x50, 0x40, 0x18, 0x47,
-    0x08, 0x84, 0x01, 0x40, 0x18, 0x47, 0x08, 0x70, 0x70, 0x10, 0x45, 0x9c, 0x1c, 0x4c, 0x84, 0x5a, 0x48, 0x84, 0xc8, 0x04, 0x59,
-    0x70, 0x84, 0xc8, 0x04, 0x45, 0x86, 0x55, 0xe4, 0x40, 0xbc, 0x01, 0x40, 0x4b, 0x15, 0x3c, 0x14, 0x40, 0x47, 0x10, 0xc0, 0x02,
-    0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0xb8, 0x6d, 0x01, 0x00, 0x86, 0xff, 0xd9, 0x00, 0x70, 0x84, 0xc8, 0x04, 0xc8,
-    0x08, 0x70, 0x84, 0x01, 0x58, 0x70, 0x18, 0x45, 0x08, 0x80, 0x47, 0xdc, 0x08, 0x59, 0x40, 0x2a, 0x50, 0x77, 0x4a, 0xdc, 0x04,
-    0x59, 0x40, 0x2a, 0x50, 0x77, 0x42, 0xdc, 0x0a, 0x59, 0x40, 0x2a, 0x50, 0x77, 0x3a, 0xdc, 0x06, 0x59, 0x40, 0x2a, 0x50, 0x77,
-    0x32, 0xdc, 0x04, 0x59, 0x68, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0x5c, 0xef, 0xe8, 0xd7, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf4,
-    0xef, 0x40, 0xd7, 0x71, 0x00, 0x7b, 0x27, 0xdc, 0x04, 0x59, 0x68, 0x70, 0xdc, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x78, 0xd8,
-    0x71, 0x00, 0x84, 0x68, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x59, 0x40, 0xdc, 0x04,
-    0x59, 0x41, 0xfe, 0x51, 0x50, 0x40, 0x3c, 0x1c, 0x40, 0x43, 0x08, 0x24, 0x7f, 0x86, 0x5d, 0x01, 0x00, 0xdc, 0x0a, 0x59, 0x40,
-    0xdc, 0x06, 0x59, 0x41, 0xfe, 0x51, 0x50, 0x40, 0x3c, 0x1c, 0x40, 0x43, 0x08, 0x24, 0x7f, 0x86, 0x5d, 0x01, 0x00, 0xa0, 0x7f,
-    0x78, 0xd9, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0xd8, 0x71, 0x00, 0x84, 0x40, 0x47, 0x77, 0x08, 0x24, 0x7f, 0x86, 0x5d,
-    0x01, 0x00, 0xdc, 0x6f, 0x58, 0x47, 0x68, 0x70, 0xdc, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00,
-    0x84, 0x68, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xfe, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf4,
-    0xef, 0x40, 0xd7, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x90, 0xd7, 0x71, 0x00, 0x84, 0x40, 0xc7, 0x54, 0x70, 0x7f, 0x2d, 0xa0, 0x47, 0x2c, 0xcc, 0xfc,
-    0x7f, 0xf0, 0x28, 0x01, 0x00, 0x3e, 0x08, 0x55, 0x77, 0x10, 0xa0, 0x47, 0xa0, 0x02, 0x2c, 0xcc, 0xf8, 0xef, 0x1c, 0xd8, 0x71,
-    0x00, 0x7b, 0x16, 0xa0, 0x47, 0xa0, 0x08, 0x2c, 0xcc, 0xf8, 0xef, 0x1c, 0xd8, 0x71, 0x00, 0x7b, 0x08, 0x80, 0xc7, 0x50, 0x70,
-    0x80, 0x47, 0x28, 0x47, 0x77, 0x08, 0x24, 0x7f, 0xb7, 0x5f, 0x01, 0x00, 0x82, 0xd9, 0x00, 0x70, 0xdc, 0x02, 0x59, 0x40, 0xfc,
-    0x4f, 0x00, 0xdb, 0x71, 0x00, 0x47, 0x41, 0xac, 0x5f, 0x58, 0x01, 0x41, 0x86, 0x41, 0x50, 0x70, 0x84, 0x59, 0xc8, 0x08, 0x70,
-    0x18, 0x45, 0x08, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0x28, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x77, 0x08,
-    0x24, 0x7f, 0xb7, 0x5f, 0x01, 0x00, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0xa0, 0x80, 0x54, 0xdb, 0x71,
-    0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x24, 0xd8, 0x71, 0x00, 0x82, 0xd9, 0x00, 0x70, 0x7b, 0xc4, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f,
-    0x58, 0x01, 0x50, 0x40, 0x28, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x77, 0x08, 0x24, 0x7f, 0xb7, 0x5f, 0x01, 0x00, 0xdc, 0x6f, 0x54,
-    0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x40, 0xdc, 0x02, 0x59, 0x41, 0xea, 0x5f, 0x58, 0x01, 0x51, 0x41, 0x3c, 0x81, 0x54, 0xdb, 0x71,
-    0x00, 0x50, 0x77, 0x08, 0x24, 0x7f, 0xb7, 0x5f, 0x01, 0x00, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0xa0,
-    0x80, 0x54, 0xdb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xd9, 0x71, 0x00, 0x84, 0x40, 0x46, 0xe8, 0x5f, 0x58, 0x01, 0x46,
-    0x40, 0x9c, 0x4f, 0x00, 0xdb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x47, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0xfc, 0xd8, 0x71, 0x00,
-    0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0xa0, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x2c,
-    0xd7, 0x71, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x94, 0xd8, 0x71, 0x00, 0x82, 0xd9, 0x00, 0x70, 0x7a, 0x2d, 0xff, 0xdc,
-    0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0x28, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x77, 0x08, 0x24, 0x7f, 0xb7, 0x5f,
-    0x01, 0x00, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0xa0, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x2c, 0xcc, 0xfc,
-    0xef, 0xd0, 0xd7, 0x71, 0x00, 0x82, 0xd9, 0x00, 0x70, 0x7a, 0xf6, 0xfe, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50,
-    0x40, 0x28, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x77, 0x08, 0x24, 0x7f, 0xb7, 0x5f, 0x01, 0x00, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f,
-    0x58, 0x01, 0x50, 0x40, 0xa0, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x70, 0xda, 0x71, 0x00, 0x82, 0xd9, 0x00,
-    0x70, 0x7a, 0xbf, 0xfe, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0x28, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x77,
-    0x08, 0x24, 0x7f, 0xb7, 0x5f, 0x01, 0x00, 0xdc, 0x04, 0x59, 0x40, 0x3e, 0x06, 0x50, 0x43, 0x0a, 0xdc, 0x04, 0x59, 0x40, 0x86,
-    0x06, 0x50, 0x70, 0xdc, 0x06, 0x59, 0x40, 0x3e, 0x06, 0x50, 0x43, 0x0a, 0xdc, 0x06, 0x59, 0x40, 0x86, 0x06, 0x50, 0x70, 0xdc,
-    0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0xa0, 0x80, 0x54, 0xdb, 0x71, 0x00, 0xdc, 0x04, 0x59, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0xf4, 0xd8, 0x71, 0x00, 0x82, 0xd9, 0x00, 0x70,
-    0x7a, 0x57, 0xfe, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0x28, 0x80, 0x54, 0xdb, 0x71, 0x00, 0x7f, 0x52,
-    0xdc, 0x08, 0x59, 0x40, 0x2a, 0x50, 0x77, 0x52, 0xdc, 0x04, 0x59, 0x40, 0x2a, 0x50, 0x77, 0x4a, 0xdc, 0x0a, 0x59, 0x40, 0x2a,
-    0x50, 0x77, 0x42, 0xdc, 0x06, 0x59, 0x40, 0x2a, 0x50, 0x77, 0x3a, 0xdc, 0x04, 0x59, 0x68, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0x5c,
-    0xef, 0xe8, 0xd7, 0x71, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x02, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x40, 0xd7, 0x71, 0x00, 0x7b, 0x2f, 0x86, 0xff, 0xd9, 0x00,
-    0x70, 0x7a, 0xed, 0xfd, 0xdc, 0x04, 0x59, 0x68, 0x70, 0xdc, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x78, 0xd8, 0x71, 0x00, 0x84,
-    0x68, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50,
-    0x40, 0xa0, 0x80, 0x54, 0xdb, 0x71, 0x00, 0xdc, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x48, 0xd9, 0x71, 0x00, 0x82, 0xd9, 0x00, 0x70, 0x7a, 0x87,
-    0xfd, 0x82, 0xd9, 0x00, 0x70, 0xdc, 0x04, 0x59, 0x7f, 0x24, 0xeb, 0x71, 0x00, 0x70, 0xdc, 0x03, 0x7f, 0x78, 0xd8, 0x00, 0x00,
-    0x40, 0x84, 0x40, 0x7f, 0x20, 0xeb, 0x71, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x28, 0xeb, 0x71, 0x00, 0x70, 0x24, 0x7f, 0xdc, 0x60,
-    0x01, 0x00, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0xdc, 0x06, 0x59, 0x41, 0x86, 0x51, 0xe4, 0x41, 0xd0,
-    0x10, 0x41, 0x41, 0xdc, 0x04, 0x59, 0x42, 0x86, 0x52, 0xe4, 0x42, 0xb0, 0x42, 0x41, 0x84, 0x41, 0x80, 0x54, 0xdc, 0x71, 0x00,
-    0x70, 0xdc, 0x02, 0x59, 0x40, 0xea, 0x5f, 0x58, 0x01, 0x50, 0x40, 0x80, 0x80, 0x50, 0xdc, 0x71, 0x00, 0x70, 0x82, 0xd9, 0x00,
-    0x70, 0x84, 0x59, 0xc8, 0x08, 0x70, 0x18, 0x45, 0x08, 0xa0, 0x00, 0xa0, 0x00, 0xa0, 0x00, 0xa0, 0x05, 0x2c, 0xcc, 0xf0, 0xef,
-    0x48, 0xd8, 0x71, 0x00, 0x82, 0xd9, 0x00, 0x70, 0x84, 0x59, 0xc8, 0x08, 0x70, 0x18, 0x45, 0x08, 0x18, 0x45, 0x08, 0x84, 0x7f,
-    0x24, 0xeb, 0x71, 0x00, 0x40, 0x90, 0x7f, 0x24, 0xeb, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x20, 0xeb, 0x71, 0x00, 0x41, 0x90, 0x7f,
-    0x20, 0xeb, 0x71, 0x00, 0x70, 0x87, 0x51, 0x50, 0x70, 0x90, 0x7f, 0x28, 0xeb, 0x71, 0x00, 0x70, 0x3c, 0x05, 0x7f, 0x28, 0xeb,
-    0x71, 0x00, 0x4f, 0xd2, 0x7a, 0xc4, 0xfc, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x3e, 0x08, 0x5a,
-    0x43, 0x11, 0xfe, 0x5a, 0x08, 0x40, 0x9e, 0x74, 0x40, 0x86, 0x40, 0x74, 0x70, 0x86, 0x08, 0x5a, 0x70, 0x3e, 0x08, 0x72, 0x43,
-    0x11, 0xfe, 0x72, 0x08, 0x40, 0x9e, 0x76, 0x40, 0x86, 0x40, 0x76, 0x70, 0x86, 0x08, 0x72, 0x70, 0xe0, 0x5a, 0x84, 0x4f, 0xb0,
-    0x6d, 0x01, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0x2c, 0xcc, 0xf4, 0xef, 0xa8, 0xd7, 0x71, 0x00, 0x84, 0x59, 0x41, 0x04, 0x5a, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x49, 0x08, 0x70, 0x70, 0xb0, 0x4f, 0x00, 0xe0, 0x81, 0x02, 0x4b, 0x70, 0x28, 0x40,
-    0xa0, 0x40, 0xa0, 0x41, 0xa0, 0x42, 0xa0, 0x03, 0xe0, 0xcc, 0xe8, 0xe0, 0xcc, 0xe8, 0xa0, 0xcc, 0xe4, 0xa0, 0x4d, 0x2c, 0xcc,
-    0xec, 0xef, 0x64, 0xd9, 0x71, 0x00, 0x20, 0x42, 0x20, 0x41, 0x20, 0x40, 0x30, 0x45, 0x84, 0xce, 0xfc, 0x40, 0xa0, 0x02, 0xe0,
-    0xc0, 0x04, 0xe0, 0x50, 0xa0, 0x50, 0xa0, 0x40, 0x2c, 0xcc, 0xec, 0xef, 0x64, 0xd9, 0x71, 0x00, 0x30, 0xc8, 0xa0, 0x04, 0x24,
-    0x7f, 0xa5, 0x61, 0x01, 0x00, 0xa0, 0x01, 0xa0, 0x00, 0xa0, 0x00, 0x70, 0xa0, 0x4b, 0xa0, 0xce, 0xfc, 0x2c, 0xcc, 0xec, 0xef,
-    0x64, 0xd9, 0x71, 0x00, 0x30, 0xc8, 0x70, 0x70, 0x70, 0x10, 0x46, 0x84, 0x5a, 0x42, 0x82, 0x48, 0x9c, 0x02, 0x42, 0x84, 0x74,
-    0x46, 0x4f, 0x59, 0x86, 0xe2, 0x48, 0xe0, 0x40, 0x87, 0x52, 0xe0, 0x41, 0x9c, 0x01, 0x42, 0xb4, 0x41, 0x40, 0x84, 0x40, 0x47,
-    0xf8, 0x0f, 0x47, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x86, 0xe2, 0x80, 0xdc, 0xd8, 0x00, 0x00, 0xe0, 0x40, 0xd4, 0x04, 0x47, 0x41,
-    0xb8, 0x0f, 0x41, 0xd0, 0x01, 0x41, 0x41, 0x86, 0xe2, 0x81, 0xfc, 0xd8, 0x00, 0x00, 0xe0, 0x41, 0xb4, 0x41, 0x40, 0x86, 0xe2,
-    0x40, 0xe0, 0x40, 0x86, 0xe2, 0x48, 0xe0, 0x41, 0xd4, 0x08, 0x41, 0x41, 0x86, 0xe2, 0x41, 0xe0, 0x41, 0xb4, 0x41, 0x40, 0x86,
-    0x40, 0x48, 0x94, 0x46, 0x47, 0xab, 0x86, 0xe2, 0x48, 0xe0, 0x40, 0xb8, 0x5f, 0xff, 0x00, 0x40, 0x87, 0x52, 0xe0, 0x41, 0x3c,
-    0x41, 0x40, 0x7f, 0x04, 0x90, 0x46, 0x84, 0x42, 0x40, 0xfa, 0x5f, 0xff, 0x00, 0x48, 0xe3, 0x41, 0x90, 0x42, 0xbb, 0x5f, 0xff,
-    0x00, 0x41, 0x87, 0x41, 0x50, 0x70, 0x86, 0xe2, 0x48, 0xe0, 0x40, 0xd4, 0x08, 0x40, 0x40, 0xb8, 0x5f, 0xff, 0x00, 0x40, 0x87,
-    0x52, 0xe0, 0x41, 0x3c, 0x41, 0x40, 0x7f, 0x04, 0x90, 0x46, 0x84, 0x42, 0x40, 0x86, 0xe2, 0x48, 0xe0, 0x41, 0xd4, 0x08, 0x41,
-    0x41, 0xbb, 0x5f, 0xff, 0x00, 0x41, 0x87, 0x41, 0x50, 0x70, 0x84, 0x46, 0x40, 0x18, 0x46, 0x08, 0x18, 0x46, 0x08, 0x70, 0x10,
-    0x47, 0x9c, 0x04, 0x4c, 0x84, 0x5a, 0x42, 0x2b, 0x7f, 0x16, 0xe9, 0x71, 0x00, 0x7f, 0x12, 0x2b, 0x7f, 0x14, 0xe9, 0x71, 0x00,
-    0x7f, 0x0a, 0x2a, 0x7f, 0x12, 0xe9, 0x71, 0x00, 0x77, 0x1a, 0x87, 0x01, 0x7f, 0x16, 0xe9, 0x71, 0x00, 0x70, 0x87, 0x02, 0x7f,
-    0x14, 0xe9, 0x71, 0x00, 0x70, 0x86, 0x09, 0x7f, 0x12, 0xe9, 0x71, 0x00, 0x70, 0xe8, 0x6f, 0x68, 0x42, 0x40, 0x9c, 0x4f, 0xc4,
-    0xe5, 0x71, 0x00, 0x40, 0x84, 0x40, 0x7f, 0x2c, 0xeb, 0x71, 0x00, 0x70, 0x7b, 0x58, 0x18, 0x47, 0x08, 0xe8, 0x6f, 0x68, 0x42,
-    0x40, 0x9c, 0x4f, 0xc4, 0xe5, 0x71, 0x00, 0x40, 0x84, 0x40, 0x47, 0x84, 0x47, 0xc7, 0x58, 0x70, 0x87, 0x02, 0xc7, 0x66, 0x70,
-    0x80, 0x48, 0x7b, 0x33, 0xe8, 0x2c, 0x48, 0x40, 0x9c, 0x47, 0x40, 0x83, 0xc0, 0x2a, 0x70, 0xe8, 0x2c, 0x48, 0x40, 0x9c, 0x47,
-    0x40, 0x82, 0xc0, 0x28, 0x70, 0xe8, 0x2c, 0x48, 0x40, 0x9c, 0x47, 0x40, 0x84, 0x40, 0x59, 0x70, 0x84, 0x40, 0x41, 0x84, 0x4f,
-    0x40, 0xeb, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x90, 0x48, 0x3c, 0x02, 0x48, 0x4b, 0xcc, 0x84, 0x42, 0x40, 0x94, 0x42,
-    0x28, 0x40, 0x47, 0xa6, 0x80, 0x48, 0x7b, 0x10, 0xe4, 0x08, 0x48, 0x40, 0x87, 0x40, 0x88, 0x30, 0xeb, 0x71, 0x00, 0x70, 0x90,
-    0x48, 0x3c, 0x10, 0x48, 0x4b, 0xef, 0x80, 0x40, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x87, 0x7f, 0x6e, 0xeb, 0x71,
-    0x00, 0xe0, 0x40, 0x3c, 0x40, 0x00, 0x7f, 0x7f, 0x3c, 0x40, 0x01, 0x77, 0x08, 0x24, 0x7f, 0x22, 0x64, 0x01, 0x00, 0x3c, 0x40,
-    0x02, 0x7f, 0x08, 0x24, 0x7f, 0x79, 0x65, 0x01, 0x00, 0x84, 0x7f, 0x74, 0xeb, 0x71, 0x00, 0x40, 0x87, 0x73, 0x50, 0x70, 0x90,
-    0x7f, 0x74, 0xeb, 0x71, 0x00, 0x70, 0x96, 0x7f, 0x7c, 0xeb, 0x71, 0x00, 0x70, 0x2a, 0x7f, 0x7c, 0xeb, 0x71, 0x00, 0x4f, 0x08,
-    0x24, 0x7f, 0x8d, 0x64, 0x01, 0x00, 0xa0, 0x7f, 0x70, 0xeb, 0x71, 0x00, 0xde, 0x02, 0x7f, 0x7e, 0xeb, 0x71, 0x00, 0x40, 0xa0,
-    0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x8c, 0xd8, 0x71, 0x00, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x79, 0x65, 0x01, 0x00, 0xcc, 0x00,
-    0x0e, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x90, 0x64, 0x01, 0x00, 0x2c, 0x5c, 0xef, 0x30, 0xd8, 0x71,
-    0x00, 0x24, 0x7f, 0x79, 0x65, 0x01, 0x00, 0x87, 0x73, 0x7f, 0x46, 0xeb, 0x71, 0x00, 0x70, 0xcc, 0x00, 0x0f, 0x7f, 0x44, 0xeb,
-    0x71, 0x00, 0x40, 0x3c, 0x01, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x79, 0x65, 0x01, 0x00, 0x87, 0x7f, 0x14, 0xe9, 0x71, 0x00, 0xe2,
-    0x40, 0x86, 0x40, 0x7f, 0x6c, 0xeb, 0x71, 0x00, 0x70, 0x2b, 0x7f, 0x17, 0xe9, 0x71, 0x00, 0x77, 0x09, 0x93, 0x7f, 0x17, 0xe9,
-    0x71, 0x00, 0x70, 0x87, 0x01, 0x7f, 0x6e, 0xeb, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x87, 0x73, 0x7f, 0x47, 0xeb, 0x71, 0x00,
-    0x70, 0xcc, 0x07, 0x00, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0x86, 0x40, 0x7f, 0x7e, 0xeb, 0x71, 0x00, 0x70, 0x3e, 0x20, 0x7f,
-    0x7e, 0xeb, 0x71, 0x00, 0x5f, 0x08, 0x24, 0x7f, 0x79, 0x65, 0x01, 0x00, 0xde, 0x02, 0x7f, 0x7e, 0xeb, 0x71, 0x00, 0x40, 0x86,
-    0x40, 0x7f, 0x7c, 0xeb, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x44, 0xeb, 0x71, 0x00, 0x7f, 0x70, 0xeb, 0x71, 0x00, 0x70, 0xdc, 0x04,
-    0x7f, 0x70, 0xeb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x7f, 0x78, 0xeb, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x78, 0xeb, 0x71, 0x00, 0x7f,
-    0x74, 0xeb, 0x71, 0x00, 0x70, 0x87, 0x02, 0x7f, 0x6e, 0xeb, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x83, 0x7f,
-    0x15, 0xe9, 0x71, 0x00, 0x70, 0xcc, 0x02, 0x08, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0xcc, 0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71,
-    0x00, 0x41, 0xa8, 0x6f, 0x68, 0x41, 0x87, 0x81, 0x28, 0xe6, 0x71, 0x00, 0xe0, 0x41, 0x3c, 0x41, 0x40, 0x7f, 0x08, 0x24, 0x7f,
-    0x58, 0x65, 0x01, 0x00, 0xcc, 0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0xa0, 0x40, 0xa0, 0x7f, 0x78, 0xeb, 0x71, 0x00,
-    0x86, 0x7f, 0x7e, 0xeb, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf4, 0xef, 0x08, 0xe9, 0x71, 0x00, 0x28, 0x40, 0x7f,
-    0x12, 0xa0, 0x15, 0x2c, 0xcc, 0xfc, 0xef, 0x4c, 0xd8, 0x71, 0x00, 0x24, 0x7f, 0x79, 0x65, 0x01, 0x00, 0xcc, 0x02, 0x0b, 0x7f,
-    0x44, 0xeb, 0x71, 0x00, 0x40, 0xa8, 0x6f, 0x68, 0x40, 0xcc, 0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x41, 0xa8, 0x6f, 0x68,
-    0x41, 0xdf, 0x01, 0x81, 0x28, 0xe6, 0x71, 0x00, 0x41, 0xbb, 0x07, 0x41, 0x87, 0x41, 0x80, 0x28, 0xe6, 0x71, 0x00, 0x70, 0xcc,
-    0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0xa8, 0x6f, 0x68, 0x40, 0x9c, 0x4f, 0x20, 0xe6, 0x71, 0x00, 0x40, 0xcc, 0x02,
-    0x08, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x41, 0x9c, 0x41, 0x40, 0x87, 0x7f, 0x15, 0xe9, 0x71, 0x00, 0x50, 0x70, 0xa0, 0x06, 0x2c,
-    0xcc, 0xfc, 0xef, 0x4c, 0xd8, 0x71, 0x00, 0x7b, 0x23, 0x2c, 0x5c, 0xef, 0x54, 0xd8, 0x71, 0x00, 0x28, 0x40, 0x7f, 0x0e, 0xa0,
-    0x06, 0x2c, 0xcc, 0xfc, 0xef, 0x4c, 0xd8, 0x71, 0x00, 0x7b, 0x0c, 0xa0, 0x15, 0x2c, 0xcc, 0xfc, 0xef, 0x4c, 0xd8, 0x71, 0x00,
-    0x82, 0x7f, 0x6c, 0xeb, 0x71, 0x00, 0x70, 0x83, 0x7f, 0x6e, 0xeb, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x43,
-    0xcc, 0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0xa8, 0x6f, 0x68, 0x40, 0x9c, 0x4f, 0xc4, 0xe5, 0x71, 0x00, 0x40, 0x84,
-    0x40, 0x48, 0x84, 0xc8, 0x58, 0x47, 0xcc, 0x02, 0x08, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0x9c, 0x4f, 0x38, 0xeb, 0x71, 0x00,
-    0x40, 0x84, 0x40, 0x46, 0xfc, 0x01, 0x46, 0x45, 0x80, 0x44, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d, 0x00, 0x00, 0x84, 0x40, 0x43, 0x2a,
-    0x7f, 0x7e, 0xeb, 0x71, 0x00, 0x7f, 0x30, 0xdc, 0x04, 0x7f, 0x70, 0xeb, 0x71, 0x00, 0x40, 0x87, 0x50, 0xe0, 0x40, 0x3c, 0x40,
-    0x02, 0x77, 0x08, 0x24, 0x7f, 0x9f, 0x66, 0x01, 0x00, 0x3c, 0x40, 0x06, 0x7f, 0x14, 0x3c, 0x40, 0x15, 0x7f, 0x5c, 0xa0, 0x43,
-    0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00, 0x18, 0x43, 0x08, 0x87, 0x55, 0xe0, 0x40, 0xcc, 0x02, 0x08, 0x57, 0x41, 0x3c,
-    0x41, 0x40, 0x77, 0x23, 0x3f, 0x01, 0xc7, 0x2a, 0x77, 0x0d, 0x87, 0x02, 0xc7, 0x2a, 0x70, 0x82, 0xc7, 0x28, 0x70, 0x90, 0x44,
-    0x9c, 0x2c, 0x47, 0xdc, 0x6f, 0x58, 0x48, 0x40, 0x3c, 0x40, 0x47, 0x5b, 0x05, 0x84, 0x48, 0x47, 0x90, 0x45, 0x3c, 0x46, 0x45,
-    0x5f, 0xcc, 0x28, 0x44, 0x7f, 0xbb, 0x84, 0x47, 0xc8, 0x58, 0x70, 0xf8, 0x5f, 0xff, 0x00, 0x44, 0xe3, 0x40, 0x9f, 0x40, 0xc8,
-    0x66, 0x70, 0x7b, 0x52, 0x87, 0x55, 0xe0, 0x40, 0xcc, 0x02, 0x08, 0x57, 0x41, 0x3c, 0x41, 0x40, 0x77, 0x38, 0x3f, 0x01, 0xc7,
-    0x2a, 0x77, 0x22, 0x86, 0x7f, 0x12, 0xe9, 0x71, 0x00, 0xc7, 0x28, 0x70, 0xdc, 0x02, 0x47, 0x40, 0xa0, 0x40, 0x87, 0xc7, 0x2b,
-    0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x04, 0xe9, 0x71, 0x00, 0x90, 0x44, 0x9c, 0x2c, 0x47, 0xdc, 0x6f, 0x58, 0x48,
-    0x40, 0x3c, 0x40, 0x47, 0x5b, 0x05, 0x84, 0x48, 0x47, 0x90, 0x45, 0x3c, 0x46, 0x45, 0x5f, 0xb7, 0x28, 0x44, 0x7e, 0x59, 0xff,
-    0xa0, 0x43, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00, 0x96, 0x7f, 0x7e, 0xeb, 0x71, 0x00, 0x70, 0x2a, 0x7f, 0x7e, 0xeb,
-    0x71, 0x00, 0x4f, 0x29, 0xcc, 0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0xa0, 0x40, 0xdc, 0x01, 0x7f, 0x78, 0xeb, 0x71,
-    0x00, 0x40, 0xa0, 0x40, 0x86, 0x7f, 0x7e, 0xeb, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf4, 0xef, 0x0c, 0xe9, 0x71,
-    0x00, 0x18, 0x43, 0x08, 0x10, 0x48, 0x87, 0x01, 0xe0, 0x40, 0xc8, 0x00, 0x0e, 0x40, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x70, 0x2b,
-    0x7f, 0x15, 0xe9, 0x71, 0x00, 0x7f, 0x1b, 0x84, 0x02, 0x48, 0x87, 0x7f, 0x15, 0xe9, 0x71, 0x00, 0x7f, 0x49, 0xeb, 0x71, 0x00,
-    0x70, 0x87, 0x73, 0x7f, 0x48, 0xeb, 0x71, 0x00, 0x70, 0x7b, 0x16, 0x3f, 0x06, 0x73, 0x7f, 0x0f, 0x84, 0x01, 0x48, 0x87, 0x73,
-    0x7f, 0x48, 0xeb, 0x71, 0x00, 0x70, 0x7b, 0x04, 0x80, 0x48, 0xc8, 0x07, 0x00, 0x48, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x70, 0x9c,
-    0x02, 0x48, 0xa0, 0x4f, 0x44, 0xeb, 0x71, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xf8, 0xef, 0x8c, 0xd8, 0x71, 0x00, 0x9c, 0x02, 0x48,
-    0xa0, 0x4f, 0x46, 0xeb, 0x71, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xf8, 0xef, 0x04, 0xe9, 0x71, 0x00, 0x18, 0x48, 0x08, 0x10, 0x48,
-    0xcc, 0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0xa8, 0x6f, 0x68, 0x40, 0x87, 0x80, 0x28, 0xe6, 0x71, 0x00, 0xe0, 0x40,
-    0x9c, 0x4f, 0x37, 0xeb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x42, 0xfc, 0x01, 0x42, 0x48, 0x87, 0x58, 0xe0, 0x40, 0xcc, 0x02, 0x08,
-    0x7f, 0x44, 0xeb, 0x71, 0x00, 0x41, 0x3c, 0x41, 0x40, 0x77, 0x30, 0xcc, 0x02, 0x0b, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x40, 0xa8,
-    0x6f, 0x68, 0x40, 0x9c, 0x4f, 0x20, 0xe6, 0x71, 0x00, 0x40, 0xcc, 0x02, 0x08, 0x7f, 0x44, 0xeb, 0x71, 0x00, 0x41, 0x9c, 0x41,
-    0x40, 0x87, 0x50, 0x7f, 0x15, 0xe9, 0x71, 0x00, 0x70, 0x84, 0x01, 0x40, 0x18, 0x48, 0x08, 0x90, 0x48, 0x3c, 0x42, 0x48, 0x5f,
-    0xbb, 0x80, 0x40, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x04, 0x4c, 0x84, 0x78, 0x43, 0x84, 0x74,
-    0x48, 0xe8, 0x6f, 0x68, 0x5a, 0x40, 0x9c, 0x4f, 0xc4, 0xe5, 0x71, 0x00, 0x40, 0x84, 0x40, 0x45, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d,
-    0x00, 0x00, 0x84, 0x40, 0x59, 0x70, 0x83, 0xc5, 0x66, 0x70, 0x80, 0x46, 0x84, 0xc5, 0x58, 0x44, 0x84, 0x02, 0x42, 0x7b, 0x45,
-    0x18, 0x43, 0x08, 0x3f, 0x01, 0xc4, 0x2a, 0x7f, 0x2c, 0x28, 0x46, 0x7f, 0x08, 0x93, 0xc5, 0x66, 0x70, 0x7b, 0x22, 0x84, 0x44,
-    0x46, 0x87, 0x01, 0xc4, 0x2a, 0x70, 0x86, 0x7f, 0x12, 0xe9, 0x71, 0x00, 0xc4, 0x28, 0x70, 0x2b, 0x7f, 0x17, 0xe9, 0x71, 0x00,
-    0x77, 0x09, 0x93, 0x7f, 0x17, 0xe9, 0x71, 0x00, 0x70, 0x9c, 0x2c, 0x44, 0xdc, 0x6f, 0x58, 0x45, 0x40, 0x3c, 0x40, 0x44, 0x5b,
-    0x05, 0x84, 0x45, 0x44, 0x84, 0x42, 0x40, 0x94, 0x42, 0x28, 0x40, 0x77, 0xb9, 0x28, 0x46, 0x77, 0x12, 0xa0, 0x59, 0x2c, 0xcc,
-    0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00, 0x84, 0xff, 0x40, 0x18, 0x43, 0x08, 0x87, 0x01, 0xe0, 0x40, 0xc8, 0x00, 0x0f, 0x40, 0x56,
-    0x70, 0x87, 0x00, 0xe0, 0x40, 0xc8, 0x00, 0x0e, 0x40, 0x56, 0x70, 0x87, 0xc5, 0x65, 0x40, 0x93, 0xc5, 0x65, 0x70, 0x87, 0x40,
-    0xe0, 0x41, 0xc8, 0x02, 0x08, 0x41, 0x56, 0x70, 0xc8, 0x02, 0x0b, 0x5a, 0x56, 0x70, 0xa0, 0x59, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc,
-    0x1d, 0x00, 0x00, 0xdc, 0x04, 0x46, 0x47, 0x87, 0xca, 0x0f, 0x57, 0x70, 0x90, 0x47, 0x84, 0x43, 0x42, 0x7f, 0x0e, 0x87, 0x58,
-    0x57, 0x70, 0x90, 0x47, 0x90, 0x48, 0x94, 0x42, 0x77, 0xf6, 0x90, 0x43, 0xc8, 0x07, 0x00, 0x43, 0x56, 0x70, 0x9c, 0x02, 0x43,
-    0xa0, 0x46, 0xa0, 0x43, 0x2c, 0xcc, 0xf8, 0xef, 0x8c, 0xd8, 0x71, 0x00, 0x9c, 0x02, 0x43, 0x87, 0x43, 0xc6, 0x2b, 0x70, 0xdc,
-    0x02, 0x46, 0x40, 0xa0, 0x40, 0xa0, 0x43, 0x2c, 0xcc, 0xf8, 0xef, 0x04, 0xe9, 0x71, 0x00, 0x18, 0x43, 0x08, 0x10, 0x45, 0x9c,
-    0x04, 0x4c, 0x83, 0x7f, 0x17, 0xe9, 0x71, 0x00, 0x70, 0x2a, 0x7f, 0x6c, 0xeb, 0x71, 0x00, 0x4f, 0x39, 0x93, 0x7f, 0x17, 0xe9,
-    0x71, 0x00, 0x70, 0x7f, 0x30, 0x86, 0xe2, 0x7f, 0x6c, 0xeb, 0x71, 0x00, 0xe0, 0x40, 0x87, 0x7f, 0x16, 0xe9, 0x71, 0x00, 0xe0,
-    0x41, 0xbc, 0x41, 0x40, 0x86, 0x40, 0x7f, 0x6c, 0xeb, 0x71, 0x00, 0x70, 0x86, 0xe2, 0x7f, 0x6c, 0xeb, 0x71, 0x00, 0xe0, 0x40,
-    0x77, 0x09, 0x83, 0x7f, 0x6e, 0xeb, 0x71, 0x00, 0x70, 0x84, 0x4f, 0xc4, 0xe5, 0x71, 0x00, 0x48, 0x80, 0x45, 0x7b, 0x7d, 0x84,
-    0xc8, 0x58, 0x47, 0x84, 0x02, 0x46, 0x7b, 0x67, 0x2a, 0xc7, 0x28, 0x4f, 0x52, 0x93, 0x7f, 0x17, 0xe9, 0x71, 0x00, 0x70, 0x7f,
-    0x49, 0xdc, 0x28, 0x47, 0x59, 0x70, 0x86, 0xe2, 0xd9, 0x00, 0xe0, 0x40, 0x87, 0x7f, 0x16, 0xe9, 0x71, 0x00, 0xe0, 0x41, 0xbc,
-    0x41, 0x40, 0x86, 0x40, 0xd9, 0x00, 0x70, 0x86, 0xe2, 0x40, 0xe0, 0x40, 0x77, 0x27, 0x86, 0x7f, 0x12, 0xe9, 0x71, 0x00, 0xc7,
-    0x28, 0x70, 0xdc, 0x02, 0x47, 0x40, 0xa0, 0x40, 0x87, 0xc7, 0x2b, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x04, 0xe9,
-    0x71, 0x00, 0x90, 0x45, 0x3c, 0x01, 0x45, 0x43, 0x1b, 0x9c, 0x2c, 0x47, 0xdc, 0x6f, 0x58, 0x48, 0x40, 0x3c, 0x40, 0x47, 0x5b,
-    0x05, 0x84, 0x48, 0x47, 0x84, 0x46, 0x40, 0x94, 0x46, 0x28, 0x40, 0x77, 0x94, 0x9c, 0x6f, 0x68, 0x48, 0x3c, 0x7f, 0x2c, 0xeb,
-    0x71, 0x00, 0x48, 0x53, 0x08, 0x3c, 0x01, 0x45, 0x4a, 0x79, 0xff, 0x18, 0x45, 0x08, 0x70, 0x04, 0x7f, 0x80, 0xeb, 0x71, 0x00,
-    0x4c, 0x04, 0x7f, 0x80, 0xeb, 0x71, 0x00, 0x7f, 0xa8, 0xc3, 0x71, 0x00, 0x70, 0x84, 0x4c, 0x7f, 0xb8, 0xc3, 0x71, 0x00, 0x70,
-    0xdc, 0x5f, 0x54, 0x02, 0x4c, 0x7f, 0xbc, 0xc3, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xa3, 0x61, 0x01, 0x00, 0x7f, 0x54, 0xc0, 0x71,
-    0x00, 0x70, 0x04, 0x7f, 0x82, 0x61, 0x01, 0x00, 0x7f, 0xa4, 0xc0, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xed, 0x6a, 0x01, 0x00, 0x7f,
-    0xf4, 0xc0, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xf6, 0x6a, 0x01, 0x00, 0x7f, 0x44, 0xc1, 0x71, 0x00, 0x70, 0x04, 0x7f, 0x78, 0x80,
-    0x00, 0x00, 0x7f, 0x94, 0xc1, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xe4, 0x6a, 0x01, 0x00, 0x7f, 0xe4, 0xc1, 0x71, 0x00, 0x70, 0x04,
-    0x7f, 0x83, 0x6a, 0x01, 0x00, 0x7f, 0x34, 0xc2, 0x71, 0x00, 0x70, 0x04, 0x7f, 0x9b, 0x61, 0x01, 0x00, 0x7f, 0x84, 0xc2, 0x71,
-    0x00, 0x70, 0x04, 0x7f, 0x9b, 0x61, 0x01, 0x00, 0x7f, 0xd4, 0xc2, 0x71, 0x00, 0x70, 0x04, 0x7f, 0x24, 0xc3, 0x71, 0x00, 0x41,
-    0x87, 0x10, 0x42, 0x04, 0x7f, 0x54, 0x61, 0x01, 0x00, 0x43, 0x84, 0x43, 0x51, 0x70, 0x9c, 0x08, 0x41, 0x97, 0x42, 0x77, 0xf7,
-    0x2c, 0x5c, 0xef, 0xe8, 0xd8, 0x71, 0x00, 0x30, 0xc8, 0x10, 0x48, 0x87, 0x7f, 0x13, 0x00, 0x20, 0x00, 0x45, 0x3b, 0x6f, 0x40,
-    0x45, 0x7f, 0x15, 0x3b, 0x04, 0x45, 0x77, 0x10, 0x2c, 0x5c, 0xef, 0x20, 0x02, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x80, 0xd8, 0x71,
-    0x00, 0x3b, 0x5f, 0xb0, 0x00, 0x45, 0x7f, 0x36, 0x2c, 0x5c, 0xef, 0x28, 0x02, 0x00, 0x00, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xef,
-    0x98, 0xd9, 0x71, 0x00, 0x86, 0x7f, 0xb0, 0xa7, 0x71, 0x00, 0x43, 0x84, 0x7f, 0xa0, 0xd9, 0x71, 0x00, 0x44, 0x7f, 0x15, 0xf8,
-    0x28, 0xc4, 0x50, 0x42, 0x3c, 0x28, 0x42, 0x77, 0x0b, 0x84, 0xc4, 0x48, 0x44, 0xba, 0x43, 0xc4, 0x1c, 0x70, 0x18, 0x48, 0x30,
-    0xc8, 0x2c, 0x5c, 0xef, 0x24, 0x02, 0x00, 0x00, 0x30, 0xc8, 0x2c, 0x5c, 0xef, 0x64, 0x05, 0x00, 0x00, 0x30, 0xc8, 0x2c, 0x5c,
-    0xef, 0x0c, 0x02, 0x00, 0x00, 0x30, 0xc8, 0x84, 0x7f, 0xb0, 0xd9, 0x71, 0x00, 0x40, 0x08, 0x70, 0x01, 0x00, 0x00, 0xe0, 0x00,
-    0x10, 0x03, 0xe0, 0x04, 0x00, 0x0f, 0xe0, 0x12, 0x3c, 0x1f, 0xe2, 0x10, 0x1a, 0x10, 0x1a, 0x10, 0x02, 0x10, 0x3c, 0x18, 0x10,
-    0x6f, 0xec, 0x40, 0x04, 0x3f, 0xf8, 0x07, 0xe0, 0x1f, 0xf8, 0x39, 0x9c, 0x63, 0xc6, 0x6f, 0xf6, 0xcd, 0xb3, 0xd9, 0x9b, 0xff,
-    0xff, 0xff, 0xff, 0xd9, 0x9b, 0xcd, 0xb3, 0x6f, 0xf6, 0x63, 0xc6, 0x39, 0x9c, 0x1f, 0xf8, 0x07, 0xe0, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xc0, 0x03, 0xe7, 0xe7, 0x3f, 0xfc, 0x0f, 0xf0, 0x0d, 0xb0, 0x07, 0xe0, 0x06, 0x60, 0x37, 0xec, 0xe4, 0x27, 0xc3,
-    0xc3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0xff, 0xe0, 0x01, 0x70, 0x01, 0x38, 0x01, 0x1d, 0x01, 0x0f, 0x01, 0x87, 0x01,
-    0x8f, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0xff, 0xff, 0xfc, 0x3f, 0xfc,
-    0x3f, 0xe0, 0x0f, 0xd8, 0x1b, 0xcc, 0x33, 0xc6, 0x63, 0x02, 0xc0, 0x03, 0x80, 0x03, 0x80, 0xc6, 0x40, 0xcc, 0x63, 0xd8, 0x33,
-    0xf0, 0x1b, 0xe0, 0x07, 0xfc, 0x3f, 0xfc, 0x3f, 0x00, 0x01, 0x6b, 0xd5, 0x00, 0x01, 0x6b, 0xd9, 0x00, 0x01, 0x6b, 0xe1, 0x00,
-    0x01, 0x6b, 0xe6, 0x00, 0x01, 0x6b, 0xea, 0x00, 0x01, 0x6b, 0xf1, 0x00, 0x01, 0x6b, 0xf9, 0x00, 0x01, 0x6c, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x70, 0x69, 0x6e, 0x69, 0x74, 0x00, 0x2d, 0x31, 0x00, 0x4e, 0x65, 0x77, 0x00, 0x52, 0x65, 0x73, 0x68, 0x61, 0x70,
-    0x65, 0x00, 0x4d, 0x6f, 0x76, 0x65, 0x00, 0x54, 0x6f, 0x70, 0x00, 0x42, 0x6f, 0x74, 0x74, 0x6f, 0x6d, 0x00, 0x43, 0x75, 0x72,
-    0x72, 0x65, 0x6e, 0x74, 0x00, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x00, 0x45, 0x78, 0x69, 0x74, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x01, 0x2c, 0xab, 0x00, 0x01, 0x2c, 0xab, 0x00, 0x01, 0x2b, 0xf8, 0x00, 0x01, 0x2c, 0x1b, 0x00, 0x01, 0x2b, 0xf8, 0x00, 0x01,
-    0x2c, 0x44, 0x00, 0x01, 0x2c, 0xab, 0x00, 0x01, 0x2b, 0xf8, 0x00, 0x01, 0x2d, 0x16, 0x75, 0x6e, 0x6b, 0x20, 0x63, 0x74, 0x6c,
-    0x00, 0x00, 0x01, 0x6c, 0xdc, 0x00, 0x01, 0x6c, 0xdd, 0x00, 0x01, 0x6c, 0xe5, 0x00, 0x01, 0x6c, 0xeb, 0x00, 0x01, 0x6c, 0xf2,
-    0x00, 0x08, 0x10, 0x00, 0x00, 0x3c, 0x38, 0x00, 0x01, 0xff, 0xff, 0x40, 0x17, 0xff, 0xff, 0xe0, 0x1f, 0xff, 0xff, 0xf0, 0x1f,
-    0xff, 0xff, 0xf0, 0x1f, 0xff, 0xff, 0xf0, 0x3f, 0xff, 0xff, 0xf8, 0x7f, 0xff, 0xff, 0xfc, 0x7f, 0xff, 0xff, 0xfe, 0xff, 0xff,
-    0xff, 0xfe, 0xff, 0xff, 0xff, 0xfc, 0x7f, 0xff, 0xff, 0xfc, 0x7f, 0xff, 0xff, 0xfc, 0x3f, 0xff, 0xff, 0xfa, 0x1f, 0xff, 0xff,
-    0xf0, 0x1f, 0xff, 0xff, 0xe0, 0x3f, 0xe7, 0xcf, 0xe0, 0x03, 0x83, 0x83, 0x80, 0x00, 0x03, 0x80, 0x00, 0x00, 0x03, 0x80, 0x00,
-    0x00, 0x03, 0x80, 0x00, 0x00, 0x83, 0x88, 0x00, 0x22, 0x43, 0x80, 0x00, 0x00, 0x03, 0x82, 0x00, 0x00, 0x03, 0x91, 0x12, 0x4a,
-    0x13, 0x90, 0x40, 0x10, 0x43, 0x99, 0x08, 0x11, 0x2b, 0x83, 0x60, 0x24, 0x9c, 0xfd, 0x00, 0x01, 0x76, 0xce, 0x10, 0x00, 0xff,
-    0xfb, 0x02, 0x00, 0x01, 0x6c, 0x48, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x20, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x00, 0x53, 0x74, 0x61, 0x63, 0x6b, 0x00, 0x4e, 0x6f, 0x72, 0x6d, 0x61,
-    0x6c, 0x00, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x72, 0x75, 0x70, 0x74, 0x00, 0x20, 0x65, 0x78, 0x63, 0x65, 0x70, 0x74, 0x69, 0x6f,
-    0x6e, 0x20, 0x23, 0x00, 0x20, 0x61, 0x74, 0x20, 0x00, 0x20, 0x5b, 0x2e, 0x74, 0x65, 0x78, 0x74, 0x2b, 0x00, 0x5d, 0x00, 0x2c,
-    0x20, 0x46, 0x61, 0x75, 0x6c, 0x74, 0x65, 0x64, 0x20, 0x50, 0x43, 0x42, 0x50, 0x20, 0x69, 0x73, 0x20, 0x00, 0x64, 0x65, 0x6d,
-    0x75, 0x78, 0x00, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x00, 0x3a, 0x20, 0x00, 0x2a, 0x20, 0x53, 0x59, 0x53, 0x54, 0x45,
-    0x4d, 0x20, 0x45, 0x58, 0x43, 0x45, 0x50, 0x54, 0x49, 0x4f, 0x4e, 0x20, 0x2a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
-    0x20, 0x20, 0x20, 0x20, 0x20, 0x50, 0x72, 0x65, 0x73, 0x73, 0x20, 0x42, 0x52, 0x45, 0x41, 0x4b, 0x20, 0x74, 0x6f, 0x20, 0x72,
-    0x65, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x2e, 0x00, 0x00, 0x00, 0x01, 0x43,
-    0xca, 0x00, 0x01, 0x4d, 0xb3, 0x00, 0x01, 0x43, 0xe4, 0x00, 0x01, 0x4d, 0xb3, 0x00, 0x01, 0x43, 0xf2, 0x00, 0x01, 0x43, 0xe4,
-    0x00, 0x01, 0x4d, 0xb3, 0x00, 0x01, 0x43, 0xe4, 0x00, 0x01, 0x4e, 0x14, 0x00, 0x01, 0x4d, 0xd9, 0x00, 0x01, 0x4d, 0xe0, 0x00,
-    0x01, 0x4d, 0xd0, 0x00, 0x08, 0x00, 0x08, 0x03, 0x18, 0x03, 0xf8, 0x00, 0x01, 0x5c, 0x13, 0x00, 0x01, 0x5d, 0xb1, 0x00, 0x01,
-    0x5d, 0xe7, 0x00, 0x01, 0x5e, 0x7f, 0x00, 0x01, 0x5e, 0xb6, 0x00, 0x01, 0x5e, 0xed, 0x00, 0x01, 0x5f, 0x55, 0x00, 0x01, 0x5c,
-    0x13, 0x00, 0x01, 0x60, 0x96, 0x00, 0x01, 0x5c, 0x01, 0x00, 0x01, 0x5c, 0x01, 0x00, 0x01, 0x5c, 0x01, 0x00, 0x01, 0x5c, 0x01,
-    0x00, 0x01, 0x5c, 0x01, 0x00, 0x01, 0x5c, 0x01, 0x00, 0x01, 0x5c, 0x01, 0x00, 0x01, 0x5c, 0x01, 0x00, 0x01, 0x5c, 0x01, 0x00,
-    0x01, 0x5c, 0x01, 0x00, 0x01, 0x60, 0x25, 0x00, 0x01, 0x60, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x80, 0x00, 0x00,
-    0xc2, 0x3c, 0x00, 0x00, 0xc2, 0x18, 0x00, 0x01, 0x37, 0xc4, 0x00, 0x01, 0x37, 0xde, 0x00, 0x00, 0xa7, 0xc8, 0x00, 0x01, 0x40,
-    0x64, 0x00, 0x01, 0x3d, 0x8e, 0x00, 0x01, 0x3d, 0xe8, 0x00, 0x01, 0x3e, 0x1e, 0x00, 0x00, 0x4d, 0xac, 0x00, 0x00, 0xc2, 0x78,
-    0x00, 0x00, 0xc2, 0xf0, 0x00, 0x01, 0x3b, 0xce, 0x00, 0x00, 0xa9, 0xc4, 0x00, 0x00, 0xc2, 0x5a, 0x00, 0x01, 0x38, 0x08, 0x00,
-    0x01, 0x38, 0x28, 0x00, 0x01, 0x38, 0x90, 0x00, 0x01, 0x38, 0xc4, 0x00, 0x01, 0x39, 0x0c, 0x00, 0x01, 0x39, 0x28, 0x00, 0x01,
-    0x39, 0x6c, 0x00, 0x01, 0x39, 0xbc, 0x00, 0x01, 0x3a, 0x9e, 0x00, 0x01, 0x3b, 0x7e, 0x00, 0x01, 0x3c, 0x92, 0x00, 0x00, 0x67,
-    0x7c, 0x00, 0x01, 0x40, 0x14, 0x00, 0x01, 0x40, 0xde, 0x00, 0x01, 0x41, 0x32, 0x00, 0x01, 0x10, 0x14, 0x00, 0x01, 0x41, 0x8e,
-    0x00, 0x00, 0x9a, 0x7c, 0x00, 0x00, 0xc2, 0x94, 0x00, 0x00, 0x50, 0xc4, 0x00, 0x01, 0x3c, 0xc4, 0x00, 0x00, 0xa8, 0x90, 0x00,
-    0x00, 0xc2, 0xcb, 0x00, 0x01, 0x3c, 0x6e, 0x00, 0x00, 0xaa, 0x40, 0x00, 0x00, 0xaa, 0xe8, 0x00, 0x01, 0x3b, 0xf2, 0x00, 0x00,
-    0xc2, 0xb0, 0x00, 0x01, 0x42, 0x14, 0x00, 0x01, 0x3f, 0xe0, 0x00, 0x01, 0x21, 0x24, 0x00, 0x01, 0x3e, 0x90, 0x00, 0x00, 0xbf,
-    0x8c, 0x00, 0x00, 0xc2, 0x4b, 0x00, 0x01, 0x2f, 0xc6, 0x00, 0x00, 0x58, 0x30, 0x00, 0x01, 0x3d, 0x28, 0x00, 0x01, 0x27, 0x80,
-    0x00, 0x01, 0x1e, 0x34, 0x00, 0x00, 0x97, 0xec, 0x00, 0x00, 0x98, 0xa8, 0x00, 0x01, 0x16, 0x3e, 0x00, 0x01, 0x10, 0x84, 0x00,
-    0x00, 0x4e, 0xa4, 0x00, 0x00, 0x4d, 0x7c, 0x00, 0x00, 0x4f, 0xfa, 0x00, 0x00, 0x4f, 0x80, 0x00, 0x01, 0x36, 0xe4, 0x00, 0x01,
-    0x37, 0x64, 0x00, 0x01, 0x6a, 0xff, 0x00, 0x00, 0x63, 0x54, 0x00, 0x00, 0x63, 0xb0, 0x00, 0x01, 0x3d, 0xae, 0x00, 0x01, 0x2e,
-    0x9c, 0x00, 0x01, 0x23, 0x0a, 0x00, 0x01, 0x20, 0x0c, 0x00, 0x01, 0x26, 0xf8, 0x00, 0x00, 0xbf, 0x74, 0x00, 0x01, 0x15, 0x8a,
-    0x00, 0x01, 0x65, 0x8c, 0x00, 0x01, 0x15, 0xa2, 0x00, 0x01, 0x14, 0xd0, 0x00, 0x01, 0x14, 0x98, 0x00, 0x01, 0x1a, 0x0e, 0x00,
-    0x01, 0x14, 0x54, 0x00, 0x01, 0x14, 0x68, 0x00, 0x01, 0x66, 0xe2, 0x00, 0x01, 0x17, 0xc2, 0x00, 0x01, 0x67, 0x5a, 0x00, 0x01,
-    0x12, 0xb0, 0x00, 0x01, 0x15, 0x72, 0x00, 0x01, 0x69, 0xcc, 0x00, 0x01, 0x5b, 0xd4, 0x00, 0x01, 0x0a, 0xaa, 0x00, 0x01, 0x07,
-    0x00, 0x00, 0x01, 0x12, 0x90, 0x00, 0x01, 0x15, 0xbe, 0x00, 0x01, 0x60, 0xea, 0x00, 0x01, 0x57, 0xa8, 0x00, 0x01, 0x0c, 0x30,
-    0x00, 0x01, 0x12, 0xf4, 0x00, 0x01, 0x35, 0x48, 0x00, 0x01, 0x61, 0xbc, 0x00, 0x01, 0x4e, 0x7c, 0x00, 0x01, 0x15, 0x38, 0x00,
-    0x01, 0x29, 0x80, 0x00, 0x01, 0x1e, 0xd4, 0x00, 0x01, 0x2b, 0xbe, 0x00, 0x01, 0x35, 0xfc, 0x00, 0x01, 0x61, 0x54, 0x00, 0x01,
-    0x61, 0xa3, 0x00, 0x01, 0x61, 0x82, 0x00, 0x01, 0x2d, 0x94, 0x00, 0x01, 0x2e, 0x40, 0x00, 0x01, 0x0c, 0x14, 0x00, 0x01, 0x4e,
-    0x20, 0x00, 0x01, 0x0b, 0x12, 0x00, 0x01, 0x0b, 0x7e, 0x00, 0x01, 0x0f, 0x9c, 0x00, 0x01, 0x6a, 0xed, 0x00, 0x01, 0x35, 0x78,
-    0x00, 0x00, 0x9a, 0x0c, 0x00, 0x01, 0x6a, 0xe4, 0x00, 0x01, 0x54, 0xc8, 0x00, 0x01, 0x5a, 0xbe, 0x00, 0x01, 0x10, 0xac, 0x00,
-    0x01, 0x26, 0x60, 0x00, 0x01, 0x26, 0x48, 0x00, 0x01, 0x1c, 0x94, 0x00, 0x01, 0x25, 0x66, 0x00, 0x01, 0x25, 0x0a, 0x00, 0x01,
-    0x23, 0xea, 0x00, 0x01, 0x24, 0x42, 0x00, 0x01, 0x23, 0xa6, 0x00, 0x01, 0x25, 0x22, 0x00, 0x01, 0x6a, 0x83, 0x00, 0x01, 0x59,
-    0xd8, 0x00, 0x01, 0x5b, 0x52, 0x00, 0x01, 0x4d, 0xc0, 0x00, 0x01, 0x6a, 0xf6, 0x00, 0x01, 0x25, 0x7e, 0x00, 0x01, 0x62, 0x84,
-    0x00, 0x01, 0x63, 0x50, 0x00, 0x01, 0x67, 0xd0, 0x00, 0x01, 0x53, 0xc4, 0x00, 0x01, 0x68, 0xe8, 0x00, 0x01, 0x50, 0xa0, 0x00,
-    0x01, 0x54, 0x20, 0x00, 0x01, 0x2a, 0x52, 0x00, 0x01, 0x18, 0x2e, 0x00, 0x01, 0x2f, 0x26, 0x00, 0x01, 0x21, 0x64, 0x00, 0x01,
-    0x0a, 0xee, 0x00, 0x01, 0x29, 0x78, 0x00, 0x01, 0x0b, 0xae, 0x00, 0x01, 0x2f, 0xee, 0x00, 0x01, 0x30, 0xec, 0x00, 0x01, 0x1f,
-    0xd8, 0x00, 0x01, 0x26, 0x78, 0x00, 0x01, 0x1f, 0x94, 0x00, 0x01, 0x42, 0xec, 0x00, 0x01, 0x2e, 0x34, 0x00, 0x01, 0x57, 0xfc,
-    0x00, 0x01, 0x2d, 0xe4, 0x00, 0x01, 0x2e, 0x68, 0x00, 0x01, 0x30, 0x76, 0x00, 0x71, 0xee, 0x80, 0x00, 0x00, 0x05, 0x74, 0x00,
-    0x00, 0x8f, 0xb0, 0x00, 0x01, 0x14, 0x40, 0x00, 0x01, 0x6b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x71, 0xdb, 0x00, 0x00, 0x71, 0xe5, 0xc4, 0x00, 0x71, 0xe9,
-    0x04, 0x00, 0x71, 0xe9, 0x18, 0x00, 0x71, 0xe9, 0x3c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x71, 0xe9, 0x4c, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x6b, 0x28, 0x00, 0x01, 0x6b, 0x48, 0x00, 0x01, 0x6b, 0x68, 0x00, 0x01, 0x6b, 0xa8, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x71, 0xe9, 0x8c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x6e, 0x00, 0x00, 0x00, 0xf5, 0x2c, 0x00, 0x00, 0xf4, 0x6c, 0x00, 0x01, 0x6b, 0x88, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf4, 0x8c, 0x00, 0x00, 0xf4, 0xac, 0x00, 0x00, 0xf4, 0xcc, 0x00, 0x00, 0xf5, 0x6c,
-    0x00, 0x00, 0xf4, 0xec, 0x00, 0x00, 0xf5, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0xf3, 0xec, 0x00, 0x00, 0xf6, 0x8c, 0x00, 0x00, 0xf5, 0x8c, 0x00, 0x00, 0xf6, 0x0c, 0x00, 0x00, 0xf7, 0x8c, 0x00, 0x00,
-    0xf8, 0x0c, 0x00, 0x00, 0xf7, 0x0c, 0x00, 0x01, 0x42, 0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc3, 0x70, 0x00, 0x01, 0x42,
-    0xa8, 0x00, 0x01, 0x1e, 0x50, 0x00, 0x01, 0x1e, 0xbc, 0x00, 0x00, 0x5a, 0xac, 0x00, 0x01, 0x2b, 0x66, 0x00, 0x01, 0x2b, 0x7a,
-    0x00, 0x00, 0xd6, 0x44, 0x00, 0x00, 0x45, 0x4e, 0x00, 0x00, 0xcb, 0x56, 0x00, 0x00, 0xc2, 0xe6, 0x00, 0x00, 0xcb, 0x12, 0x00,
-    0x00, 0xd6, 0x64, 0x00, 0x00, 0xc3, 0x17, 0x00, 0x00, 0xc3, 0x00, 0x00, 0x00, 0xc3, 0x24, 0x00, 0x01, 0x06, 0x28, 0x00, 0x01,
-    0x05, 0xa4, 0x00, 0x00, 0xd8, 0xc8, 0x00, 0x00, 0xd6, 0x8c, 0x00, 0x00, 0x43, 0xfc, 0x00, 0x00, 0x67, 0xa0, 0x00, 0x00, 0x48,
-    0x98, 0x00, 0x00, 0x49, 0xa4, 0x00, 0x00, 0x4c, 0xac, 0x00, 0x00, 0x71, 0x14, 0x00, 0x00, 0x70, 0xac, 0x00, 0x00, 0x6f, 0xd8,
-    0x00, 0x00, 0x44, 0xee, 0x00, 0x00, 0x1d, 0xa5, 0x00, 0x00, 0x1d, 0xb3, 0x00, 0x00, 0x1d, 0xbe, 0x00, 0x00, 0x1d, 0xcc, 0x00,
-    0x71, 0x97, 0x6c, 0x00, 0x71, 0x97, 0x70, 0x00, 0x71, 0xa7, 0x94, 0x00, 0x00, 0xed, 0x04, 0x00, 0x71, 0xa7, 0xa8, 0x00, 0x71,
-    0xa7, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x1e, 0x00, 0x00, 0xdf, 0x80, 0x10, 0x01, 0x00, 0x00, 0x01, 0x03, 0x08, 0x0a, 0x0c,
-    0x0d, 0x21, 0x21, 0x32, 0x2f, 0x1c, 0x08, 0x06, 0x00, 0xff, 0x01, 0x02, 0x65, 0x08, 0x61, 0x63, 0x69, 0x70, 0x72, 0x73, 0x74,
-    0x04, 0x2e, 0x62, 0x64, 0x66, 0x6c, 0x6e, 0x6f, 0x75, 0xfe, 0x03, 0x0c, 0x0d, 0x18, 0x50, 0x5f, 0x67, 0x68, 0x6b, 0x6d, 0x71,
-    0x78, 0x05, 0x06, 0x07, 0x09, 0x10, 0x14, 0x15, 0x1c, 0x24, 0x34, 0x54, 0x77, 0x79, 0x0a, 0x0b, 0x11, 0x12, 0x1b, 0x20, 0x23,
-    0x28, 0x2c, 0x31, 0x32, 0x3a, 0x40, 0x41, 0x42, 0x43, 0x44, 0x46, 0x49, 0x4c, 0x4d, 0x4f, 0x52, 0x55, 0x56, 0x58, 0x6a, 0x76,
-    0x80, 0x83, 0x90, 0xbb, 0xf0, 0x0e, 0x0f, 0x1d, 0x1e, 0x25, 0x30, 0x33, 0x38, 0x39, 0x3b, 0x3c, 0x3d, 0x4e, 0x53, 0x57, 0x7a,
-    0x8c, 0x94, 0x97, 0xa7, 0xb0, 0xc0, 0xc1, 0xc2, 0xd6, 0xd9, 0xdb, 0xde, 0xe6, 0xe8, 0xeb, 0xec, 0xf9, 0x13, 0x16, 0x1f, 0x21,
-    0x26, 0x27, 0x2a, 0x2b, 0x2d, 0x2f, 0x35, 0x36, 0x37, 0x3e, 0x45, 0x48, 0x4a, 0x4b, 0x5a, 0x5b, 0x5c, 0x60, 0x7c, 0x84, 0x85,
-    0x98, 0x9c, 0xa0, 0xa3, 0xa4, 0xa8, 0xaa, 0xac, 0xbc, 0xbe, 0xc3, 0xc4, 0xc8, 0xc9, 0xcc, 0xd0, 0xd4, 0xd8, 0xe0, 0xe4, 0xf3,
-    0xf4, 0xf6, 0xf8, 0xfc, 0x17, 0x1a, 0x22, 0x29, 0x3f, 0x7d, 0x7e, 0x7f, 0x81, 0x82, 0x86, 0x88, 0x8a, 0x8e, 0x8f, 0x95, 0x96,
-    0x9a, 0x9d, 0x9e, 0xa2, 0xa5, 0xa6, 0xa9, 0xab, 0xae, 0xaf, 0xb4, 0xb8, 0xc6, 0xc7, 0xca, 0xcb, 0xce, 0xd7, 0xdc, 0xdf, 0xe1,
-    0xe5, 0xe9, 0xea, 0xed, 0xee, 0xf1, 0xf2, 0xf5, 0xfa, 0x19, 0x47, 0x51, 0x59, 0x5d, 0x5e, 0x89, 0x8b, 0x92, 0x9f, 0xb1, 0xb2,
-    0xb3, 0xb5, 0xb6, 0xb7, 0xb9, 0xbf, 0xc5, 0xcd, 0xd1, 0xd2, 0xd5, 0xda, 0xdd, 0xe3, 0xef, 0xf7, 0x7b, 0x91, 0x99, 0x9b, 0xcf,
-    0xd3, 0xe2, 0xe7, 0x87, 0x8d, 0x93, 0xba, 0xbd, 0xfb, 0xfd, 0x5a, 0x51, 0x28, 0xa8, 0x56, 0x03, 0x00, 0x0f, 0x6b, 0x0b, 0x71,
-    0x90, 0x32, 0xd8, 0x25, 0x26, 0x5d, 0x13, 0x04, 0x07, 0x8f, 0x36, 0xb1, 0x20, 0x48, 0xac, 0x48, 0x12, 0x38, 0xa3, 0xff, 0xff,
-    0x86, 0x24, 0xd5, 0x69, 0x1a, 0xbe, 0xb1, 0x2e, 0xb1, 0x2f, 0x2a, 0x77, 0xff, 0xff, 0x0a, 0x89, 0x95, 0x3d, 0x53, 0xfd, 0x64,
-    0x07, 0x56, 0x40, 0x76, 0x25, 0x25, 0xff, 0xff, 0xc3, 0x12, 0x69, 0x20, 0x51, 0x4f, 0x22, 0x07, 0xc3, 0x92, 0x20, 0x7c, 0x39,
-    0x81, 0x08, 0x3d, 0xff, 0xff, 0xe1, 0x89, 0x32, 0xe2, 0x03, 0x50, 0x53, 0x91, 0x00, 0xcf, 0x22, 0x01, 0x9f, 0x25, 0xff, 0xff,
-    0xe1, 0x89, 0x32, 0x8a, 0x29, 0xf2, 0x20, 0x87, 0x91, 0x04, 0x3e, 0x04, 0x02, 0x0f, 0xff, 0xff, 0x10, 0x49, 0x95, 0x34, 0x42,
-    0x42, 0x2f, 0xe4, 0x40, 0x3b, 0x10, 0x64, 0x40, 0x3b, 0x10, 0x7f, 0xff, 0xff, 0xc9, 0x96, 0x44, 0xbb, 0x7e, 0xb0, 0x54, 0xe6,
-    0xf1, 0xe5, 0x94, 0x32, 0x65, 0x52, 0x68, 0x7f, 0xf9, 0x53, 0x47, 0xa6, 0xaf, 0xf7, 0x37, 0x38, 0x25, 0x5f, 0xf0, 0xc7, 0xf8,
-    0xa7, 0x21, 0x4c, 0x2e, 0x1d, 0x0b, 0xcf, 0xf8, 0xc3, 0xbc, 0x64, 0xe9, 0xe3, 0xaa, 0x26, 0x3a, 0x82, 0x89, 0xf1, 0x2b, 0xbc,
-    0x64, 0xe9, 0xe3, 0xa8, 0x10, 0x98, 0x2b, 0xf0, 0x99, 0xde, 0x32, 0x74, 0xf1, 0xd2, 0xa7, 0xaa, 0x7f, 0xc1, 0x53, 0xbc, 0x64,
-    0xea, 0x29, 0x20, 0x3a, 0x7a, 0x09, 0xf8, 0x10, 0x3b, 0xc6, 0x4e, 0xa2, 0xa0, 0xea, 0x24, 0x29, 0xfc, 0x02, 0x2e, 0xf1, 0x93,
-    0xa8, 0x2a, 0x69, 0x3f, 0xe1, 0x4b, 0xbc, 0x64, 0xea, 0x0a, 0x97, 0x4f, 0xf8, 0x11, 0x3b, 0xc6, 0x4e, 0x90, 0xa6, 0x17, 0x0e,
-    0x85, 0xe5, 0x3e, 0x15, 0xbb, 0xc6, 0x4e, 0x94, 0x1d, 0x51, 0x31, 0xd4, 0x14, 0x4f, 0x81, 0x2b, 0xbc, 0x64, 0xe9, 0x41, 0xd4,
-    0x08, 0x4c, 0x15, 0xf8, 0x13, 0xbb, 0xc6, 0x4e, 0x94, 0x1d, 0x2a, 0x7a, 0xa7, 0xfc, 0x0a, 0x5d, 0xe3, 0x27, 0x51, 0x48, 0x74,
-    0xca, 0x48, 0x27, 0xe0, 0x58, 0xef, 0x19, 0x3a, 0xa2, 0x08, 0x0e, 0xa8, 0x65, 0x22, 0x9c, 0x19, 0x3b, 0xc6, 0x4e, 0xa8, 0x82,
-    0x03, 0xa9, 0x99, 0x48, 0xa7, 0x00, 0xab, 0xbc, 0x64, 0xe9, 0x36, 0x99, 0x53, 0xf8, 0x62, 0xef, 0x96, 0x5f, 0x67, 0x0c, 0x7f,
-    0xf1, 0xc3, 0x28, 0x98, 0x57, 0xfb, 0x9b, 0x9c, 0x12, 0xaf, 0xf9, 0x2f, 0xe1, 0x4e, 0x47, 0xff, 0xdd, 0xe3, 0x07, 0x45, 0xff,
-    0xfb, 0x1d, 0xe3, 0x07, 0x49, 0xb4, 0xca, 0x9f, 0xc9, 0x3b, 0xe5, 0x97, 0xc5, 0x3c, 0x97, 0xfe, 0x1d, 0xb5, 0x05, 0x4f, 0x30,
-    0x3c, 0x5f, 0xdc, 0xdc, 0xe0, 0x95, 0x7f, 0xce, 0xfe, 0x15, 0xe6, 0x53, 0x44, 0x79, 0x13, 0x1f, 0xbb, 0xce, 0x39, 0x7c, 0x53,
-    0xc9, 0x7f, 0xe8, 0x19, 0x4c, 0xc3, 0x53, 0xf9, 0x27, 0x79, 0xc7, 0x2f, 0x8a, 0x79, 0x2f, 0xfc, 0x9b, 0x4c, 0xa9, 0xfc, 0xe7,
-    0x7c, 0xb2, 0xf8, 0x53, 0xce, 0xff, 0xc3, 0x34, 0x55, 0x21, 0x3d, 0x2f, 0xdc, 0xdc, 0xe0, 0x95, 0x7f, 0xc5, 0x1f, 0xc2, 0xdc,
-    0xa1, 0xea, 0x37, 0xfd, 0xde, 0x28, 0x3a, 0x2e, 0x44, 0xaa, 0xa3, 0xff, 0x92, 0x77, 0x82, 0x93, 0xa9, 0xb5, 0x05, 0x7f, 0x9c,
-    0xef, 0x38, 0xe5, 0xf0, 0xa7, 0x9d, 0xff, 0x8e, 0x98, 0x34, 0x5c, 0xbf, 0xe2, 0x77, 0x78, 0x50, 0x74, 0x9b, 0x4c, 0xa9, 0xfc,
-    0x50, 0xef, 0x96, 0x5f, 0x0a, 0xf8, 0xa3, 0xff, 0x0e, 0x5a, 0x60, 0xd7, 0xfb, 0x9b, 0x9c, 0x12, 0xaf, 0xf8, 0xcf, 0xf1, 0x66,
-    0x8a, 0xad, 0x21, 0xff, 0x77, 0x87, 0x67, 0x52, 0x4d, 0xa2, 0x00, 0xfa, 0x2a, 0xf9, 0x27, 0x78, 0xc1, 0xd4, 0x93, 0x68, 0x80,
-    0xa9, 0x94, 0xbc, 0x48, 0xef, 0x18, 0x3a, 0x3c, 0xd3, 0x0d, 0x4c, 0xf5, 0x4c, 0xf3, 0x9d, 0xd6, 0x00, 0x71, 0xd2, 0x6d, 0x32,
-    0xa7, 0xf1, 0x97, 0x7c, 0xb2, 0xf8, 0x5b, 0xc6, 0x7f, 0xf1, 0x6b, 0x48, 0xd5, 0x34, 0x9b, 0x7e, 0xe6, 0xe7, 0x04, 0xab, 0xfe,
-    0x20, 0xff, 0x0c, 0x32, 0x88, 0xaa, 0xa7, 0xfb, 0xbc, 0x58, 0x72, 0xff, 0x88, 0x30, 0xab, 0xfc, 0x9b, 0x4c, 0xa9, 0xfc, 0x41,
-    0x77, 0xcb, 0x2f, 0x8b, 0x38, 0x83, 0xff, 0x8b, 0x5a, 0x46, 0xa9, 0xa4, 0xda, 0x17, 0x7b, 0x9b, 0x9c, 0x12, 0xaf, 0xf8, 0x55,
-    0xfc, 0x31, 0xca, 0x22, 0xaa, 0x9f, 0xee, 0xf0, 0x5e, 0x72, 0xff, 0x85, 0x51, 0x3f, 0xf9, 0x36, 0x99, 0x53, 0xf8, 0x54, 0xef,
-    0x96, 0x5f, 0x0c, 0x38, 0x55, 0xff, 0x87, 0x26, 0x4d, 0x28, 0xdf, 0xee, 0x6e, 0x70, 0x4a, 0xbf, 0xe3, 0x3f, 0xc3, 0x7c, 0x8c,
-    0x5f, 0xff, 0xbb, 0xce, 0x39, 0x7c, 0x53, 0xc9, 0x7f, 0xe1, 0xf9, 0x18, 0xbf, 0xfc, 0x93, 0xbc, 0xe3, 0x97, 0xc5, 0x3c, 0x97,
-    0xfe, 0x50, 0xd2, 0xaa, 0x99, 0x30, 0xa7, 0x9c, 0xef, 0x18, 0x3a, 0x4d, 0xa6, 0x54, 0xfe, 0x32, 0xef, 0x96, 0x5f, 0x0c, 0x78,
-    0xcf, 0xfe, 0x1d, 0x14, 0x51, 0x03, 0x49, 0xb4, 0xaf, 0xb9, 0xb9, 0xc1, 0x2a, 0xff, 0x8d, 0xff, 0x0f, 0x74, 0xda, 0x82, 0xbf,
-    0xee, 0xf3, 0x8e, 0x5f, 0x0a, 0x79, 0xdf, 0xf9, 0x42, 0x13, 0xd2, 0xfe, 0x73, 0xbc, 0x6c, 0xe5, 0xf0, 0xaf, 0x8a, 0x3f, 0xf2,
-    0xe6, 0x4a, 0x29, 0xfc, 0x65, 0xde, 0x36, 0x74, 0xc3, 0x48, 0xd5, 0xfe, 0x27, 0x77, 0x8d, 0x9c, 0xbe, 0x1b, 0xf1, 0xbf, 0xfd,
-    0x24, 0xda, 0x20, 0x7f, 0x14, 0x3b, 0xc6, 0xce, 0x5f, 0x0d, 0xf8, 0xdf, 0xfe, 0x4d, 0xa6, 0x54, 0xfe, 0x36, 0xef, 0x96, 0x5f,
-    0x0d, 0xf8, 0xdf, 0xfe, 0x1c, 0x1e, 0x2f, 0x6a, 0x7f, 0xdc, 0xdc, 0xe0, 0x95, 0x7f, 0xc5, 0x5f, 0xca, 0xe5, 0x0f, 0x51, 0xbf,
-    0xee, 0xf1, 0x41, 0xd1, 0x72, 0x25, 0x55, 0x1f, 0xfc, 0x93, 0xbc, 0x14, 0x9d, 0x4d, 0xa8, 0x2b, 0xfc, 0xe7, 0x79, 0xc7, 0x2f,
-    0x85, 0x3c, 0xef, 0xfc, 0xc9, 0x45, 0x3f, 0xc4, 0xee, 0xf1, 0xb3, 0x97, 0xc3, 0x7e, 0x37, 0xff, 0x96, 0x4c, 0xc9, 0x85, 0x7f,
-    0x14, 0x3b, 0xc6, 0xce, 0x5f, 0x0f, 0x78, 0xab, 0xff, 0x28, 0x7a, 0x04, 0x1f, 0xc6, 0xdd, 0xe3, 0x67, 0x2f, 0x87, 0xbc, 0x55,
-    0xff, 0x93, 0x69, 0x95, 0x3f, 0x8a, 0x9d, 0xf2, 0xcb, 0xe1, 0xef, 0x15, 0x7f, 0xe8, 0x14, 0x34, 0x5b, 0xfd, 0xcd, 0xce, 0x09,
-    0x57, 0xfc, 0xef, 0xe4, 0x19, 0x86, 0x91, 0xab, 0xfe, 0xef, 0x1b, 0x39, 0x7c, 0xaf, 0x3b, 0xff, 0x17, 0x19, 0x4c, 0xaf, 0xf2,
-    0x4e, 0xf1, 0x83, 0xa4, 0xda, 0x65, 0x4f, 0xe7, 0x3b, 0xe5, 0x97, 0xca, 0xf3, 0xbf, 0xf4, 0x0b, 0xa2, 0xa2, 0xbf, 0xdc, 0xdc,
-    0xe0, 0x95, 0x7f, 0xc6, 0x7f, 0x9a, 0xd0, 0x1d, 0x54, 0xf4, 0x4b, 0xfe, 0xef, 0x1b, 0x39, 0x7c, 0xaf, 0x3b, 0xff, 0x40, 0x74,
-    0x7d, 0xa7, 0x95, 0xf9, 0x27, 0x78, 0xd9, 0xcb, 0xe5, 0x79, 0xdf, 0xfa, 0x03, 0xa8, 0x21, 0xfc, 0xe7, 0x78, 0xc1, 0xd5, 0x15,
-    0x3d, 0x56, 0xfe, 0x12, 0xbb, 0xc6, 0x0e, 0x93, 0x69, 0x95, 0x3f, 0x8c, 0xbb, 0xe5, 0x97, 0xc8, 0x38, 0xcf, 0xfe, 0x92, 0x05,
-    0x3f, 0xee, 0x6e, 0x70, 0x4a, 0xbf, 0xe1, 0xaf, 0xf1, 0x0b, 0x49, 0x41, 0x77, 0xfd, 0xde, 0x48, 0xea, 0x48, 0x7f, 0xc9, 0x3b,
-    0xc0, 0x0e, 0x3a, 0xa2, 0x5f, 0xf3, 0x9d, 0xe4, 0x8e, 0xa8, 0x90, 0xe9, 0x73, 0x22, 0xe6, 0xa7, 0xc6, 0x5d, 0xe4, 0x8e, 0xa8,
-    0x90, 0xe9, 0xa4, 0x92, 0x6a, 0x7c, 0x4e, 0xef, 0x24, 0x74, 0xf4, 0xbf, 0xe2, 0x87, 0x79, 0x23, 0xa5, 0x92, 0xff, 0x8d, 0xbb,
-    0xc9, 0x1d, 0x4d, 0xa3, 0xdf, 0xe2, 0xa7, 0x78, 0xb0, 0xe5, 0xff, 0x15, 0xc4, 0xdf, 0xe5, 0x0b, 0x90, 0x10, 0x88, 0x1f, 0x86,
-    0x2e, 0xf2, 0x47, 0x49, 0xb4, 0xca, 0x9f, 0xc3, 0x57, 0x7c, 0xb2, 0xf9, 0xae, 0x1a, 0xff, 0xe3, 0x89, 0x99, 0x43, 0xfd, 0xcd,
-    0xce, 0x09, 0x57, 0xfa, 0xc3, 0xef, 0xe0, 0x79, 0xa4, 0x81, 0x4f, 0xfb, 0xbc, 0xe3, 0x97, 0xcd, 0x70, 0xd7, 0xff, 0x55, 0xa4,
-    0x6a, 0xff, 0x0d, 0x5d, 0xe1, 0x41, 0xd2, 0xa7, 0xaa, 0x7f, 0xf0, 0x2f, 0x77, 0x85, 0x07, 0x4a, 0x28, 0xa7, 0xf8, 0x70, 0xef,
-    0x0a, 0x0e, 0xa8, 0xa9, 0xea, 0xb7, 0xf1, 0xc7, 0x79, 0x23, 0xa5, 0xcf, 0x17, 0xb5, 0x3f, 0xc5, 0xae, 0xf1, 0xb3, 0x97, 0xc3,
-    0xde, 0x2a, 0xff, 0xd3, 0x6a, 0x0a, 0xff, 0x0f, 0x9d, 0xe7, 0x1c, 0xbe, 0x14, 0xf3, 0xbf, 0xf2, 0x02, 0x85, 0x48, 0x8d, 0x34,
-    0xd3, 0x78, 0x1a, 0xbb, 0xce, 0x39, 0x7c, 0x83, 0x8c, 0xff, 0xea, 0xa6, 0x7a, 0x45, 0xcc, 0xa1, 0xe5, 0xdd, 0xe1, 0xd9, 0xd5,
-    0x53, 0x3d, 0x25, 0x45, 0xe9, 0x37, 0xa4, 0xef, 0x14, 0x1d, 0x30, 0xaa, 0x28, 0x10, 0x29, 0xf5, 0x5d, 0xe4, 0x8e, 0xa0, 0x69,
-    0x34, 0x95, 0xfc, 0x8d, 0xde, 0x71, 0xcb, 0xe2, 0x9e, 0x4b, 0xff, 0x40, 0xd2, 0x6a, 0x19, 0x4f, 0xe0, 0x6c, 0xef, 0x1b, 0x39,
-    0x7c, 0x30, 0xe1, 0x57, 0xfe, 0x89, 0x81, 0xf4, 0x4a, 0x22, 0xab, 0x78, 0x82, 0xef, 0x18, 0x3a, 0x61, 0x01, 0xf3, 0xd3, 0x53,
-    0xe0, 0x1a, 0x77, 0x8c, 0x1d, 0x40, 0xa1, 0xa2, 0xdf, 0xc0, 0xdd, 0xde, 0x0c, 0xce, 0x5f, 0xf0, 0x7d, 0x07, 0xdf, 0xe8, 0x14,
-    0x34, 0x59, 0x24, 0x4c, 0x75, 0x86, 0xae, 0xf1, 0x51, 0xd4, 0x0a, 0x1a, 0x2c, 0x91, 0x93, 0x4a, 0xeb, 0x02, 0xf7, 0x78, 0xa8,
-    0xea, 0x8a, 0x9e, 0x81, 0x07, 0xd6, 0x1c, 0x3b, 0xc5, 0x07, 0x4c, 0x34, 0x5c, 0xa2, 0xa7, 0xa0, 0x41, 0xac, 0x71, 0xde, 0x28,
-    0x3a, 0x61, 0xa2, 0xe5, 0x15, 0x20, 0x51, 0x1d, 0x62, 0xd7, 0x79, 0x23, 0xa4, 0xda, 0x65, 0x4f, 0xd6, 0x1f, 0x3b, 0xe5, 0x97,
-    0xc4, 0x2d, 0x61, 0xf7, 0xfe, 0x68, 0xa9, 0xea, 0x9f, 0xfd, 0xcd, 0xce, 0x09, 0x57, 0xfc, 0x2a, 0xfe, 0x02, 0xbc, 0x35, 0x4d,
-    0xa8, 0x2b, 0xfd, 0xde, 0x71, 0xcb, 0xe1, 0x4f, 0x3b, 0xff, 0x03, 0x02, 0xa8, 0xa8, 0x91, 0x73, 0xc5, 0xfc, 0xe7, 0x78, 0xd9,
-    0xcb, 0xe1, 0xef, 0x15, 0x7f, 0xe7, 0xa6, 0x3c, 0x87, 0xf1, 0x97, 0x79, 0x23, 0xa7, 0xa6, 0x3c, 0x20, 0x7f, 0x13, 0xbb, 0xc3,
-    0xb3, 0xa4, 0x26, 0x4d, 0x28, 0xdf, 0xc5, 0x0e, 0xf3, 0x8e, 0x5f, 0x0c, 0x78, 0xcf, 0xfe, 0x4d, 0xa6, 0x54, 0xfe, 0x15, 0x3b,
-    0xe5, 0x97, 0xc0, 0xf3, 0x85, 0x5f, 0xf9, 0x43, 0x26, 0x55, 0xfa, 0xc4, 0xbb, 0x22, 0xb6, 0x2f, 0xf1, 0x80, 0x14, 0x7f, 0x07,
-    0xd9, 0x32, 0x85, 0xbf, 0x58, 0x94, 0x47, 0x66, 0xd5, 0xff, 0xff, 0xe7, 0xa6, 0x3c, 0x87, 0xf3, 0x9d, 0xe4, 0x89, 0x4f, 0x4c,
-    0x78, 0x40, 0xfe, 0x25, 0x77, 0x87, 0x64, 0xa7, 0x95, 0x2a, 0x9b, 0x54, 0x53, 0xc4, 0x8e, 0xf0, 0xa0, 0x94, 0xc1, 0x41, 0x7a,
-    0xad, 0x53, 0xe2, 0x37, 0x78, 0x29, 0x25, 0x47, 0xff, 0x24, 0xef, 0x05, 0x24, 0xa6, 0x0f, 0x4c, 0x79, 0x01, 0xf3, 0xd3, 0xe3,
-    0x0e, 0xf0, 0x52, 0x4a, 0xab, 0x48, 0xd5, 0xff, 0x77, 0x92, 0x5c, 0xa9, 0xea, 0x9f, 0xfc, 0x93, 0xbc, 0x92, 0xe5, 0x14, 0x53,
-    0xfc, 0xe7, 0x79, 0x25, 0xcc, 0x8b, 0xd2, 0x32, 0xa7, 0xe3, 0x2e, 0xf1, 0x85, 0xcc, 0x0b, 0xd2, 0x32, 0xa7, 0xe0, 0xa5, 0xde,
-    0x30, 0xb9, 0x36, 0x96, 0xfd, 0x61, 0x28, 0x09, 0xec, 0xda, 0xbf, 0xff, 0xfc, 0xa1, 0x72, 0xa7, 0xa6, 0x3c, 0xa6, 0xb0, 0x94,
-    0x0f, 0xec, 0x8a, 0xd8, 0xbf, 0xe1, 0xaf, 0xf0, 0x18, 0x64, 0xca, 0x16, 0xfd, 0x61, 0x28, 0x0a, 0xec, 0xda, 0xbf, 0xff, 0xfc,
-    0xf4, 0xc7, 0x90, 0xfe, 0x23, 0x77, 0x92, 0x09, 0xe7, 0xa6, 0x3c, 0x20, 0x7f, 0x39, 0xde, 0x1d, 0x84, 0xf4, 0x7f, 0xf1, 0x2b,
-    0xbc, 0x91, 0x2a, 0x5f, 0xf8, 0x91, 0xde, 0x14, 0x12, 0x93, 0x69, 0x6f, 0xd6, 0x12, 0x84, 0x7b, 0x36, 0xaf, 0xff, 0xff, 0x51,
-    0xaa, 0x95, 0x3d, 0x53, 0xf5, 0x84, 0xa0, 0x3b, 0xb2, 0x2b, 0x62, 0xff, 0x8a, 0xff, 0x82, 0x4e, 0x4c, 0xa1, 0x6f, 0xd6, 0x12,
-    0x88, 0xb6, 0x6d, 0x5f, 0xff, 0xfe, 0x97, 0xfe, 0xc7, 0x78, 0xd8, 0x4e, 0xbe, 0x21, 0x6b, 0x0f, 0xbf, 0xf3, 0x5f, 0xf9, 0xce,
-    0xf1, 0xb2, 0x4b, 0xe0, 0x79, 0xc2, 0xaf, 0xfc, 0x9b, 0x4b, 0x7e, 0xb0, 0x98, 0x15, 0x59, 0xb5, 0x7f, 0xff, 0xf8, 0xf3, 0x55,
-    0x40, 0x7c, 0xf4, 0xf5, 0x84, 0xc1, 0x45, 0x91, 0x5b, 0x17, 0xfc, 0x83, 0xf8, 0x03, 0xd9, 0x32, 0x85, 0xbf, 0x58, 0x4c, 0x28,
-    0xb3, 0x6a, 0xff, 0xff, 0xf4, 0x3f, 0xf3, 0x9d, 0xe4, 0x89, 0x52, 0xff, 0xc4, 0xae, 0xf1, 0xb2, 0x4b, 0xe2, 0x16, 0xb0, 0xfb,
-    0xff, 0x26, 0xd2, 0xdf, 0xac, 0x26, 0x05, 0x76, 0x6d, 0x5f, 0xff, 0xfe, 0x3c, 0xd5, 0x4b, 0x99, 0x30, 0x3d, 0xac, 0x26, 0x00,
-    0xbd, 0x91, 0x5b, 0x17, 0xfc, 0x0a, 0x3f, 0x84, 0x5c, 0x99, 0x42, 0xdf, 0xac, 0x26, 0x10, 0xac, 0xda, 0xbf, 0xff, 0xfc, 0xbf,
-    0xff, 0x77, 0x92, 0x5d, 0x2f, 0xfc, 0xe7, 0x78, 0x50, 0x4a, 0x8f, 0xfe, 0x25, 0x77, 0x92, 0x25, 0x26, 0xd2, 0xdf, 0xac, 0x26,
-    0x00, 0x36, 0xcd, 0xab, 0xff, 0xff, 0xd4, 0x1f, 0x34, 0xa9, 0x53, 0x22, 0xe3, 0x15, 0x84, 0xc0, 0x2a, 0xb2, 0x2b, 0x62, 0xff,
-    0xb7, 0xf8, 0x0e, 0xf2, 0x65, 0x0b, 0x7e, 0xb0, 0x98, 0x1e, 0xd9, 0xb5, 0x7f, 0xff, 0xfa, 0x5f, 0xf9, 0xce, 0xf1, 0xb0, 0x9d,
-    0x7c, 0x42, 0xd6, 0x1f, 0x7f, 0xe4, 0xda, 0x5b, 0xf5, 0x8c, 0x89, 0xec, 0xda, 0xbf, 0xff, 0xfc, 0xb2, 0x6d, 0x69, 0x0b, 0x48,
-    0x75, 0x8c, 0x8a, 0x2c, 0x8a, 0xd8, 0xbf, 0xe2, 0xaf, 0xe0, 0x9f, 0x93, 0x28, 0x5b, 0xf5, 0x8c, 0x81, 0x0d, 0x9b, 0x57, 0xff,
-    0xff, 0xa5, 0xff, 0xdd, 0xe3, 0x61, 0x3a, 0xf8, 0x85, 0xac, 0x3e, 0xff, 0xc9, 0xb4, 0xb7, 0xeb, 0x19, 0x02, 0x8b, 0x36, 0xaf,
-    0xff, 0xff, 0x06, 0xe6, 0x53, 0x2b, 0xfe, 0xe6, 0xe4, 0x86, 0xa1, 0xe0, 0x37, 0x32, 0x99, 0x5f, 0xee, 0x6e, 0x0a, 0x46, 0xa3,
-    0x86, 0x51, 0x30, 0xaf, 0xf7, 0x37, 0x38, 0x69, 0x7c, 0x53, 0xc9, 0x7f, 0xe1, 0xdb, 0x50, 0x54, 0xf3, 0x03, 0xc5, 0xfd, 0xcd,
-    0xce, 0x1a, 0x5f, 0x0a, 0x79, 0xdf, 0xf8, 0x66, 0x8a, 0xa4, 0x27, 0xa5, 0xfb, 0x9b, 0x9c, 0x34, 0xbe, 0x15, 0xf1, 0x47, 0xfe,
-    0x1c, 0xb4, 0xc1, 0xaf, 0xf7, 0x37, 0x38, 0x69, 0x7c, 0x2d, 0xe3, 0x3f, 0xf8, 0xb5, 0xa4, 0x6a, 0x9a, 0x4d, 0xbf, 0x73, 0x73,
-    0x86, 0x97, 0xc5, 0x9c, 0x41, 0xff, 0xc5, 0xad, 0x23, 0x54, 0xd2, 0x6d, 0x0b, 0xbd, 0xcd, 0xce, 0x1a, 0x5f, 0x0c, 0x38, 0x55,
-    0xff, 0x86, 0x86, 0x4a, 0xb7, 0xfd, 0xcd, 0xc9, 0x0d, 0x43, 0xa2, 0x8a, 0x20, 0x69, 0x36, 0x95, 0xf7, 0x37, 0x38, 0x69, 0x7c,
-    0x37, 0xe3, 0x7f, 0xf8, 0x70, 0x78, 0xbd, 0xa9, 0xff, 0x73, 0x73, 0x86, 0x97, 0xc3, 0xde, 0x2a, 0xff, 0xc9, 0xaa, 0xd2, 0x35,
-    0x7d, 0x62, 0x5d, 0x98, 0xc2, 0xf1, 0x18, 0x2e, 0x87, 0xbf, 0xfe, 0x4c, 0xa9, 0xea, 0x9f, 0xeb, 0x20, 0x3a, 0x31, 0x8c, 0x2f,
-    0x85, 0x5f, 0xff, 0x93, 0x28, 0xa2, 0x9f, 0x22, 0x08, 0x78, 0x93, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xb2, 0x25, 0xdb, 0xf5, 0x80,
-    0x2a, 0xe6, 0xf1, 0xe5, 0xa0, 0x64, 0x84, 0x84, 0x9a, 0x1f, 0xfe, 0x54, 0xd1, 0xe9, 0xab, 0xfb, 0x1c, 0xdc, 0xe4, 0x63, 0x86,
-    0x51, 0x30, 0xaf, 0xe2, 0x97, 0x37, 0x39, 0x18, 0x76, 0xd4, 0x15, 0x3c, 0xc0, 0xf1, 0x7e, 0x14, 0xb9, 0xb9, 0xc8, 0xc3, 0x34,
-    0x55, 0x21, 0x3d, 0x2f, 0x85, 0x6e, 0x6e, 0x72, 0x30, 0xe5, 0xa6, 0x0d, 0x7f, 0x0b, 0x5c, 0xdc, 0xe4, 0x62, 0xd6, 0x91, 0xaa,
-    0x69, 0x36, 0xf8, 0xb1, 0xcd, 0xce, 0x46, 0x2d, 0x69, 0x1a, 0xa6, 0x93, 0x68, 0x5d, 0xc3, 0x07, 0x37, 0x39, 0x18, 0x72, 0x64,
-    0xd2, 0x8d, 0xfc, 0x31, 0x73, 0x73, 0x91, 0x87, 0x45, 0x14, 0x40, 0xd2, 0x6d, 0x2b, 0xc3, 0x77, 0x37, 0x39, 0x18, 0x70, 0x78,
-    0xbd, 0xa9, 0xfe, 0x1e, 0xb9, 0xb9, 0xc8, 0xd0, 0x28, 0x68, 0xb7, 0xf2, 0xae, 0x6e, 0x72, 0x34, 0x0b, 0xa2, 0xa2, 0xbf, 0x90,
-    0x39, 0xb9, 0xc8, 0xd2, 0x1f, 0x17, 0xa8, 0x65, 0x25, 0x3e, 0xe6, 0xe7, 0x04, 0xab, 0xfe, 0x37, 0xfa, 0xc0, 0x29, 0xa6, 0x80,
-    0x80, 0x45, 0xfe, 0xef, 0x1b, 0x39, 0x7c, 0x83, 0x8c, 0xff, 0xe6, 0x4d, 0x2a, 0x45, 0xfc, 0x93, 0xbc, 0x6c, 0xe5, 0xf2, 0x0e,
-    0x33, 0xff, 0x91, 0xa4, 0x64, 0x81, 0xbf, 0x9c, 0xef, 0x07, 0x47, 0x53, 0x48, 0xc9, 0x72, 0xff, 0x8c, 0xbb, 0xc1, 0xd1, 0xd2,
-    0x34, 0x8c, 0x97, 0x2f, 0xf8, 0x9d, 0xde, 0x0e, 0x8e, 0xa2, 0x65, 0x05, 0x4b, 0xfe, 0x28, 0x77, 0x83, 0xa3, 0xa4, 0xda, 0x65,
-    0x4f, 0xe3, 0x6e, 0xf9, 0x65, 0xeb, 0x05, 0x7c, 0x6f, 0xff, 0x49, 0x02, 0x9f, 0xe6, 0x9c, 0xdc, 0xe4, 0x63, 0x89, 0x99, 0x43,
-    0xf8, 0x84, 0xe6, 0xe7, 0x23, 0x34, 0x54, 0xf5, 0x4f, 0xfc, 0x0f, 0x1c, 0xdc, 0xe4, 0x65, 0x13, 0x3c, 0x84, 0x74, 0x85, 0xa4,
-    0x3e, 0xe6, 0xe7, 0x04, 0xab, 0xfe, 0x4b, 0xf5, 0x85, 0xd9, 0x41, 0x7a, 0xad, 0xfe, 0xc7, 0x78, 0xc9, 0xd2, 0x6d, 0x32, 0xa7,
-    0xf2, 0x4e, 0xf9, 0x65, 0xeb, 0x0b, 0x79, 0x2f, 0xfc, 0x40, 0x3c, 0xba, 0x9f, 0xee, 0x6e, 0x70, 0x4a, 0xbf, 0xe3, 0x7f, 0xac,
-    0x19, 0x65, 0x0f, 0x34, 0x57, 0xfb, 0x1d, 0xe3, 0x27, 0x53, 0x6a, 0xa4, 0x0d, 0x17, 0xfc, 0x48, 0xef, 0x19, 0x3a, 0x61, 0xa2,
-    0xe2, 0xe8, 0x98, 0x6f, 0x84, 0xae, 0xf1, 0x93, 0xa5, 0x4d, 0x24, 0x5d, 0xa4, 0x7f, 0x05, 0x2e, 0xf1, 0x93, 0xa4, 0x0d, 0x17,
-    0xaa, 0x64, 0xc3, 0x7c, 0x28, 0x77, 0x8c, 0x9d, 0x51, 0x04, 0xda, 0xd3, 0x08, 0x19, 0xe0, 0x42, 0xef, 0x19, 0x3a, 0x4d, 0xa6,
-    0x54, 0xfe, 0x36, 0xef, 0x96, 0x5e, 0xb0, 0xbb, 0x8d, 0xff, 0xe5, 0x0b, 0xa2, 0x97, 0xfb, 0x9b, 0x89, 0x86, 0x57, 0xfc, 0x6f,
-    0xf5, 0x86, 0x9a, 0x26, 0x0a, 0xa2, 0x20, 0x22, 0x57, 0xee, 0xf3, 0x82, 0x65, 0xeb, 0x0b, 0xb8, 0xdf, 0xfe, 0x65, 0x25, 0x47,
-    0x4f, 0x4d, 0x33, 0xfd, 0xde, 0x0c, 0x02, 0x65, 0xeb, 0x0b, 0x78, 0xdc, 0x49, 0xfe, 0x4d, 0xa6, 0x54, 0xfe, 0x36, 0xef, 0x96,
-    0x5e, 0xb0, 0x65, 0xc6, 0xff, 0xf2, 0x85, 0xcc, 0xa5, 0xfe, 0xe6, 0xe7, 0x04, 0xab, 0xfc, 0x4a, 0x33, 0xfa, 0xc3, 0xcc, 0xca,
-    0x4a, 0xa9, 0xfe, 0xef, 0x13, 0x1c, 0xbd, 0x60, 0xcb, 0x8d, 0xff, 0xe9, 0x16, 0x20, 0x68, 0xbd, 0x4f, 0x8d, 0xbb, 0xc1, 0x40,
-    0xe5, 0xeb, 0x0b, 0x71, 0x20, 0x1a, 0xce, 0x82, 0xff, 0xcc, 0x1a, 0x21, 0x2e, 0x65, 0x02, 0x0e, 0x24, 0x23, 0x77, 0x9c, 0x72,
-    0xf5, 0x85, 0xbc, 0x97, 0xfe, 0x81, 0xe9, 0x28, 0x5c, 0xca, 0x04, 0x18, 0x90, 0x0d, 0x9d, 0xe7, 0x1c, 0xbd, 0x61, 0x6f, 0x25,
-    0xff, 0x95, 0x17, 0x18, 0x2e, 0x54, 0xb1, 0x73, 0xf1, 0x20, 0x82, 0xef, 0x38, 0xe5, 0xeb, 0x0b, 0x79, 0x2f, 0xfc, 0xa1, 0x09,
-    0xe4, 0x67, 0x95, 0x2a, 0x9e, 0x24, 0x03, 0x77, 0x79, 0xc7, 0x2f, 0x58, 0x5b, 0xc9, 0x7f, 0xed, 0x23, 0x32, 0x92, 0xaa, 0x78,
-    0x90, 0x03, 0x8e, 0xf0, 0x60, 0x72, 0xf5, 0x85, 0xbc, 0x0d, 0xe0, 0x47, 0xfe, 0x4d, 0xa6, 0x54, 0xfc, 0x4a, 0x32, 0xef, 0x96,
-    0x5e, 0xb0, 0xd3, 0x12, 0x8c, 0xff, 0xe8, 0x17, 0x32, 0x81, 0x02, 0x66, 0x4d, 0x56, 0x32, 0x0b, 0xac, 0x8a, 0xd8, 0xbf, 0xd8,
-    0x3d, 0xfa, 0xc0, 0xd7, 0x26, 0x50, 0xb7, 0xeb, 0x19, 0x0b, 0xec, 0xda, 0xbf, 0xff, 0xfd, 0x0f, 0xfd, 0x8e, 0xf2, 0x44, 0xa9,
-    0x7f, 0xe7, 0x3b, 0xc6, 0xc9, 0x2f, 0x88, 0x5a, 0xc3, 0xef, 0xfd, 0x1f, 0xfc, 0x4a, 0xef, 0x24, 0x4a, 0x1f, 0xff, 0xe2, 0x47,
-    0x79, 0x22, 0x52, 0x6d, 0x2d, 0xfa, 0xc6, 0x82, 0xcb, 0x36, 0xaf, 0xff, 0xff, 0x40, 0xd2, 0x6a, 0x9e, 0x53, 0xf7, 0x37, 0x38,
-    0x25, 0x5f, 0xf3, 0xbf, 0x58, 0xf6, 0x42, 0x7a, 0x5f, 0xf7, 0x78, 0x10, 0x1d, 0x2a, 0x47, 0xff, 0x24, 0xef, 0x18, 0x3a, 0x54,
-    0x5f, 0xff, 0x12, 0x3b, 0xc6, 0x0e, 0x93, 0x69, 0x95, 0x3f, 0x9c, 0xef, 0x96, 0x5e, 0xb0, 0x35, 0xe7, 0x7f, 0xe1, 0xa1, 0xa4,
-    0xd4, 0x32, 0x9f, 0xee, 0x6e, 0x70, 0x4a, 0xbf, 0xe3, 0x7f, 0xad, 0x4c, 0x8f, 0xff, 0xbb, 0xc1, 0x49, 0xd1, 0x7f, 0xfe, 0x49,
-    0xde, 0x0a, 0x4e, 0x99, 0x2e, 0x54, 0x8f, 0xf9, 0xce, 0xf0, 0x52, 0x74, 0xc9, 0x72, 0xa2, 0xff, 0xe3, 0x2e, 0xf0, 0x52, 0x74,
-    0x88, 0xd1, 0xe5, 0x7f, 0x89, 0xdd, 0xec, 0x3a, 0x64, 0xb9, 0x52, 0x23, 0x47, 0x95, 0xf0, 0x9d, 0xde, 0xc3, 0xa8, 0x98, 0x1f,
-    0x44, 0xa2, 0x2a, 0xf8, 0x50, 0xef, 0x18, 0x3a, 0x69, 0x2f, 0xf8, 0xa1, 0xde, 0x30, 0x74, 0x9b, 0x4c, 0xa9, 0xfc, 0x6d, 0xdf,
-    0x2c, 0xbd, 0x63, 0xdc, 0x6f, 0xff, 0x40, 0xba, 0x29, 0x20, 0x69, 0x35, 0x2b, 0x05, 0x20, 0x73, 0x64, 0x2a, 0x62, 0xff, 0x5a,
-    0xff, 0x58, 0x05, 0xb2, 0x65, 0x0b, 0x7e, 0xb0, 0x52, 0x02, 0x3b, 0x36, 0xaf, 0xff, 0xff, 0x21, 0x4f, 0xff, 0x77, 0x9c, 0xb5,
-    0xf0, 0xa7, 0x9d, 0xff, 0x97, 0xff, 0xce, 0x77, 0x8d, 0x92, 0x5f, 0x0f, 0x78, 0xab, 0xff, 0x26, 0xd2, 0xdf, 0xac, 0x15, 0x01,
-    0xa5, 0x9b, 0x57, 0xff, 0xff, 0x8f, 0x22, 0x20, 0x34, 0x84, 0xc9, 0xa5, 0x2b, 0x05, 0x40, 0x75, 0x64, 0x56, 0xc5, 0xff, 0x10,
-    0x3f, 0x58, 0x39, 0xc9, 0x94, 0x2d, 0xfa, 0xc1, 0x50, 0x09, 0x2c, 0xda, 0xbf, 0xff, 0xfd, 0x2f, 0xfd, 0x8e, 0xf1, 0xb0, 0x9d,
-    0x7c, 0x42, 0xd6, 0x1f, 0x7f, 0xe6, 0x92, 0x2a, 0x7a, 0xad, 0xf9, 0xce, 0xf2, 0x41, 0x3c, 0x99, 0x42, 0x9f, 0xac, 0x15, 0x00,
-    0xaa, 0xcc, 0xaa, 0xff, 0xff, 0x58, 0x07, 0x72, 0x1f, 0xfc, 0x4a, 0xef, 0x1b, 0x24, 0xbe, 0x18, 0xc6, 0x23, 0x3f, 0xf9, 0x36,
-    0x94, 0xfd, 0x62, 0x70, 0x55, 0x66, 0x55, 0x7f, 0xff, 0xf9, 0x36, 0x96, 0xfd, 0x62, 0x70, 0x55, 0x66, 0xd5, 0xff, 0xff, 0xea,
-    0x17, 0x6b, 0x52, 0xf5, 0x89, 0xc5, 0x16, 0x42, 0xa6, 0x2f, 0xf9, 0x9f, 0xd6, 0x0e, 0xf2, 0x65, 0x0b, 0x7e, 0xb1, 0x38, 0x10,
-    0xd9, 0xb5, 0x7f, 0xff, 0xfa, 0x9f, 0xfd, 0xde, 0x48, 0x4d, 0x4b, 0xff, 0x39, 0xde, 0x36, 0x49, 0x7c, 0x42, 0xd6, 0x1f, 0x7f,
-    0xe7, 0x97, 0x3d, 0x32, 0x16, 0x95, 0xf1, 0x2b, 0xbc, 0x91, 0x29, 0x85, 0x51, 0x40, 0x81, 0x4f, 0x89, 0x1d, 0xe4, 0x89, 0x49,
-    0xb4, 0xb7, 0xeb, 0x13, 0x88, 0x36, 0x6d, 0x5f, 0xff, 0xfe, 0x79, 0x73, 0xd3, 0x21, 0xf5, 0x89, 0xc0, 0xde, 0xc8, 0x54, 0xc5,
-    0xff, 0x05, 0xbf, 0xac, 0x04, 0x99, 0x32, 0x85, 0xbf, 0x58, 0x9c, 0x03, 0x76, 0x6d, 0x5f, 0xff, 0xfe, 0xa7, 0xff, 0x77, 0x92,
-    0x13, 0x49, 0xb4, 0xb7, 0xeb, 0x13, 0x80, 0x9e, 0xcd, 0xab, 0xff, 0xff, 0xc1, 0xb9, 0x94, 0xca, 0xff, 0xb9, 0xb9, 0x21, 0xa8,
-    0x78, 0x0d, 0xcc, 0xa6, 0x57, 0xfb, 0x9b, 0x82, 0x91, 0xa8, 0xe1, 0x94, 0x4c, 0x2b, 0xfd, 0xcd, 0xce, 0x1a, 0x5f, 0x14, 0xf2,
-    0x5f, 0xf8, 0x76, 0xd4, 0x15, 0x3c, 0xc0, 0xf1, 0x7f, 0x73, 0x73, 0x86, 0x97, 0xc2, 0x9e, 0x77, 0xfe, 0x19, 0xa2, 0xa9, 0x09,
-    0xe9, 0x7e, 0xe6, 0xe7, 0x0d, 0x2f, 0x85, 0x7c, 0x51, 0xff, 0x87, 0x2d, 0x30, 0x6b, 0xfd, 0xcd, 0xce, 0x1a, 0x5f, 0x0b, 0x78,
-    0xcf, 0xfe, 0x2d, 0x69, 0x1a, 0xa6, 0x93, 0x6f, 0xdc, 0xdc, 0xe1, 0xa5, 0xf1, 0x67, 0x10, 0x7f, 0xf1, 0x6b, 0x48, 0xd5, 0x34,
-    0x9b, 0x42, 0xef, 0x73, 0x73, 0x86, 0x97, 0xc3, 0x0e, 0x15, 0x7f, 0xe1, 0xa1, 0x92, 0xad, 0xff, 0x73, 0x72, 0x43, 0x50, 0xe8,
-    0xa2, 0x88, 0x1a, 0x4d, 0xa5, 0x7d, 0xcd, 0xce, 0x1a, 0x5f, 0x0d, 0xf8, 0xdf, 0xfe, 0x1c, 0x1e, 0x2f, 0x6a, 0x7f, 0xdc, 0xdc,
-    0xe1, 0xa5, 0xf0, 0xf7, 0x8a, 0xbf, 0xf2, 0x6a, 0xb4, 0x8d, 0x5f, 0x58, 0xc8, 0x2e, 0xb3, 0x18, 0x5e, 0x48, 0x0d, 0xa0, 0xcb,
-    0xff, 0xc9, 0x95, 0x3d, 0x53, 0xfd, 0x64, 0x01, 0x64, 0x63, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xa2, 0x8a, 0x7c, 0x88, 0x21, 0xe2,
-    0x4c, 0x61, 0x7f, 0xff, 0xf9, 0x32, 0xc8, 0x97, 0x6f, 0xc6, 0x00, 0x68, 0xe6, 0xf1, 0xe5, 0xa0, 0x64, 0xc2, 0xa9, 0x99, 0x2e,
-    0x4d, 0x0f, 0xf9, 0x53, 0x47, 0xa6, 0xaf, 0xec, 0x73, 0x73, 0x91, 0x8e, 0x19, 0x44, 0xc2, 0xbf, 0x8a, 0x5c, 0xdc, 0xe4, 0x61,
-    0xdb, 0x50, 0x54, 0xf3, 0x03, 0xc5, 0xf8, 0x52, 0xe6, 0xe7, 0x23, 0x0c, 0xd1, 0x54, 0x84, 0xf4, 0xbe, 0x15, 0xb9, 0xb9, 0xc8,
-    0xc3, 0x96, 0x98, 0x35, 0xfc, 0x2d, 0x73, 0x73, 0x91, 0x8b, 0x5a, 0x46, 0xa9, 0xa4, 0xdb, 0xe2, 0xc7, 0x37, 0x39, 0x18, 0xb5,
-    0xa4, 0x6a, 0x9a, 0x4d, 0xa1, 0x77, 0x0c, 0x1c, 0xdc, 0xe4, 0x61, 0xc9, 0x93, 0x4a, 0x37, 0xf0, 0xc5, 0xcd, 0xce, 0x46, 0x1d,
-    0x14, 0x51, 0x03, 0x49, 0xb4, 0xaf, 0x0d, 0xdc, 0xdc, 0xe4, 0x61, 0xc1, 0xe2, 0xf6, 0xa7, 0xf8, 0x7a, 0xe6, 0xe7, 0x23, 0x40,
-    0xa1, 0xa2, 0xdf, 0xca, 0xb9, 0xb9, 0xc8, 0xd0, 0x2e, 0x8a, 0x8a, 0xfe, 0x40, 0xe6, 0xe7, 0x23, 0x48, 0x7c, 0x5e, 0xa1, 0x94,
-    0x94, 0xeb, 0x05, 0x6e, 0x6e, 0x72, 0x34, 0x90, 0x29, 0xfe, 0x69, 0xcd, 0xce, 0x46, 0x38, 0x99, 0x94, 0x3f, 0x88, 0x4e, 0x6e,
-    0x72, 0x33, 0x45, 0x4f, 0x54, 0xff, 0xc0, 0xf1, 0xcd, 0xce, 0x46, 0x38, 0x81, 0x93, 0x05, 0x91, 0x1e, 0xfb, 0x9b, 0x9c, 0x12,
-    0xaf, 0xf8, 0xa3, 0xf5, 0x84, 0x1c, 0x8c, 0xb1, 0xa3, 0x08, 0x25, 0xfb, 0xbc, 0x1d, 0x1d, 0x4c, 0xb1, 0xa3, 0x08, 0x25, 0xf2,
-    0x4e, 0xf0, 0x74, 0x75, 0x34, 0x0b, 0x1a, 0x30, 0x82, 0x5e, 0x73, 0xbc, 0x1e, 0x9d, 0x23, 0x2c, 0x55, 0xaa, 0x21, 0xf1, 0x97,
-    0x78, 0xc1, 0xd2, 0x35, 0x51, 0x21, 0x33, 0xf8, 0x29, 0x77, 0x8c, 0x1d, 0x4d, 0x54, 0x48, 0x4c, 0xfe, 0x27, 0x77, 0x8c, 0x9d,
-    0x40, 0xa9, 0xea, 0x9f, 0xf8, 0x4e, 0xef, 0x19, 0x3a, 0xaa, 0x88, 0x1e, 0x63, 0xf0, 0xa1, 0xde, 0x32, 0x75, 0x52, 0xc5, 0xcf,
-    0x1e, 0xfc, 0x08, 0x1d, 0xe3, 0x27, 0x49, 0xb4, 0xca, 0x9f, 0xc5, 0x0e, 0xf9, 0x65, 0xeb, 0x04, 0x6e, 0x28, 0xff, 0xc7, 0x07,
-    0x47, 0xda, 0x79, 0x56, 0xa7, 0xee, 0x6e, 0x70, 0x4a, 0xbf, 0xe4, 0xbf, 0x58, 0x45, 0xcc, 0x1a, 0x2e, 0x5f, 0xfd, 0xde, 0x48,
-    0x28, 0x5f, 0xf1, 0x3f, 0xfe, 0x92, 0xa2, 0xf4, 0xbf, 0x89, 0xdd, 0xe4, 0x82, 0x85, 0xff, 0x5f, 0xfe, 0x81, 0x85, 0x4b, 0xff,
-    0x09, 0xdd, 0xe4, 0x82, 0x85, 0xff, 0x5f, 0xfe, 0x80, 0xf9, 0xe6, 0x0c, 0x34, 0xbf, 0x85, 0x0e, 0xf2, 0x41, 0x42, 0xff, 0x8c,
-    0x7f, 0xea, 0x34, 0x8b, 0xfc, 0x52, 0xef, 0x24, 0x14, 0x2f, 0xf8, 0xc7, 0xfe, 0x55, 0x44, 0x41, 0xc3, 0x7f, 0x1b, 0x77, 0x92,
-    0x0a, 0x17, 0xfc, 0xef, 0xfc, 0x9b, 0x4c, 0xa9, 0xfc, 0x93, 0xbe, 0x59, 0x7a, 0xc2, 0x0f, 0x25, 0xff, 0x8e, 0x1e, 0x81, 0x03,
-    0x55, 0xfd, 0xcd, 0xce, 0x09, 0x57, 0xfc, 0x2c, 0xfd, 0x62, 0x2e, 0x3e, 0xd3, 0xca, 0xb5, 0x3f, 0xdd, 0xe7, 0x1c, 0xbd, 0x61,
-    0x07, 0x92, 0xff, 0xca, 0x9e, 0xa9, 0xff, 0xc9, 0x3b, 0xc1, 0x99, 0xcb, 0xfe, 0x15, 0x42, 0xaf, 0xf6, 0x95, 0x29, 0xfe, 0x2b,
-    0x77, 0x83, 0x33, 0x97, 0xfd, 0x96, 0x7f, 0x93, 0x69, 0x95, 0x3f, 0x85, 0x8e, 0xf9, 0x65, 0xeb, 0x08, 0xbc, 0x2c, 0xff, 0xc7,
-    0x08, 0x15, 0x51, 0x53, 0xd5, 0x6f, 0xb9, 0xb9, 0xc1, 0x2a, 0xff, 0x85, 0xbf, 0xac, 0x24, 0xe9, 0x10, 0x2b, 0xff, 0x77, 0x9c,
-    0x72, 0xf5, 0x84, 0x5e, 0x16, 0x7f, 0xea, 0xa2, 0x42, 0x67, 0xf8, 0x58, 0xef, 0x18, 0x3a, 0xa2, 0xa7, 0xaa, 0xdf, 0xc0, 0x99,
-    0xde, 0x32, 0x75, 0x44, 0x41, 0xc3, 0x7f, 0x81, 0x3b, 0xbc, 0x64, 0xe9, 0x36, 0x99, 0x53, 0xf8, 0x5a, 0xef, 0x96, 0x5e, 0xb1,
-    0x17, 0x85, 0xbf, 0xf8, 0xe2, 0x03, 0xe7, 0x98, 0x30, 0xd2, 0xfe, 0xe6, 0xe7, 0x04, 0xab, 0xfe, 0x3f, 0xfb, 0x04, 0xba, 0x44,
-    0x0a, 0xa9, 0xfe, 0xef, 0x06, 0x07, 0x2f, 0x58, 0x8b, 0xc3, 0xeb, 0x3f, 0xcc, 0x34, 0x8d, 0x54, 0x88, 0x15, 0xf0, 0xf9, 0xde,
-    0x36, 0x72, 0xf5, 0x88, 0xbc, 0x2d, 0xff, 0xd0, 0x2a, 0x7a, 0xa7, 0xfe, 0x06, 0x8e, 0xf0, 0x66, 0x72, 0xff, 0x9d, 0x3b, 0xfd,
-    0x35, 0x1a, 0x45, 0xfc, 0xab, 0xbc, 0x64, 0xe9, 0x1a, 0x8d, 0x22, 0xfe, 0xd7, 0x78, 0xc9, 0xd2, 0xc9, 0xb5, 0xa9, 0x10, 0x2a,
-    0xa7, 0x2c, 0xef, 0x61, 0xd3, 0x4a, 0x35, 0x3f, 0xe3, 0xce, 0xf6, 0x1d, 0x26, 0xd3, 0x2a, 0x7f, 0x1f, 0x77, 0xcb, 0x2f, 0x58,
-    0x49, 0xe3, 0xff, 0xfa, 0xa4, 0x69, 0x10, 0x2a, 0xa2, 0xa7, 0xfb, 0x9b, 0x84, 0xa0, 0xa9, 0x7f, 0xc9, 0x7e, 0xc0, 0x53, 0xa4,
-    0x46, 0x3a, 0x60, 0xd1, 0x72, 0xff, 0x77, 0x84, 0xc2, 0x7a, 0x44, 0x63, 0xa2, 0xe3, 0xd1, 0x57, 0xd6, 0xef, 0x09, 0x84, 0xf4,
-    0x88, 0xc7, 0x4c, 0x90, 0x7e, 0xc7, 0x78, 0x4c, 0x27, 0x93, 0x69, 0x95, 0x3f, 0x92, 0x77, 0xcb, 0x2f, 0x60, 0x97, 0x92, 0xff,
-    0xd3, 0x41, 0x22, 0x05, 0x54, 0x54, 0xff, 0x73, 0x70, 0x94, 0x15, 0x2f, 0xf9, 0x2f, 0xd8, 0x29, 0xd2, 0x4c, 0x74, 0xc1, 0xa2,
-    0xe5, 0xfe, 0xef, 0x09, 0x84, 0xf4, 0x93, 0x1d, 0x51, 0x10, 0x70, 0xdf, 0x5b, 0xbc, 0x26, 0x13, 0xd2, 0x4c, 0x74, 0xa9, 0xea,
-    0x9f, 0xec, 0x77, 0x84, 0xc2, 0x79, 0x36, 0x99, 0x53, 0xf9, 0x27, 0x7c, 0xb2, 0xf6, 0x02, 0x9e, 0x4b, 0xff, 0x28, 0x99, 0xe4,
-    0x23, 0xa4, 0x2d, 0x21, 0xd6, 0x16, 0xb9, 0xb9, 0xc8, 0xc4, 0x03, 0xcb, 0xa9, 0xfa, 0xc2, 0xe7, 0x37, 0x39, 0x19, 0x42, 0xe8,
-    0xa5, 0xfa, 0xc1, 0x93, 0x9b, 0x89, 0x91, 0x94, 0x2e, 0x65, 0x2f, 0xd6, 0x1a, 0x39, 0xb9, 0xc8, 0xc8, 0x4f, 0x44, 0xc7, 0xd6,
-    0x27, 0x04, 0x0b, 0x22, 0xb6, 0x2f, 0xf5, 0x80, 0xdb, 0xf6, 0x05, 0x59, 0x32, 0x85, 0xbf, 0x58, 0x9c, 0x05, 0x56, 0x6d, 0x5f,
-    0xff, 0xfe, 0x7a, 0x88, 0x83, 0x86, 0xfe, 0xc7, 0x78, 0x29, 0x25, 0x26, 0xd2, 0xdf, 0xac, 0x28, 0x00, 0xed, 0x9b, 0x57, 0xff,
-    0xff, 0x94, 0x55, 0x53, 0x0a, 0x7a, 0xc2, 0x81, 0x0e, 0xc8, 0x54, 0xc5, 0xff, 0x05, 0x7f, 0xb0, 0x09, 0x72, 0x65, 0x0b, 0x7e,
-    0xb0, 0xa0, 0x04, 0x16, 0x6d, 0x5f, 0xff, 0xfe, 0x69, 0x22, 0xa6, 0x45, 0xc6, 0x3e, 0xb7, 0x79, 0x20, 0x9e, 0x4d, 0xa5, 0xbf,
-    0x58, 0x50, 0x07, 0xf6, 0x6d, 0x5f, 0xff, 0xfe, 0x0d, 0x85, 0xdf, 0xd6, 0x14, 0x03, 0xdb, 0x22, 0xb6, 0x2f, 0xf8, 0x65, 0xfb,
-    0x00, 0xa3, 0x26, 0x50, 0xb7, 0xeb, 0x0a, 0x00, 0x11, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0x6d, 0x2d, 0xfa, 0xc2, 0x80, 0x1d, 0xd9,
-    0xb5, 0x7f, 0xff, 0xfa, 0x06, 0x4c, 0x2a, 0x99, 0x92, 0xfa, 0xc2, 0x80, 0x4e, 0xb2, 0x2b, 0x62, 0xff, 0x58, 0x18, 0x7e, 0xc0,
-    0x63, 0x93, 0x28, 0x5b, 0xf5, 0x85, 0x00, 0x9f, 0x66, 0xd5, 0xff, 0xff, 0xe5, 0xd2, 0xff, 0x9c, 0xef, 0x1b, 0x24, 0xbe, 0x1e,
-    0xf1, 0x57, 0xfe, 0x97, 0xfe, 0xb7, 0x78, 0xd9, 0x25, 0xf1, 0x0b, 0x58, 0x7d, 0xff, 0xa4, 0x97, 0xfc, 0x4a, 0xef, 0x1b, 0x24,
-    0xbe, 0x21, 0x6b, 0x0f, 0xbf, 0xf2, 0x6d, 0x2d, 0xfa, 0xc2, 0x87, 0xb3, 0x6a, 0xff, 0xff, 0xf5, 0x05, 0xea, 0x26, 0x68, 0xc7,
-    0x58, 0xa0, 0x31, 0xb2, 0x2b, 0x62, 0xff, 0x81, 0x07, 0xec, 0x0c, 0x72, 0x65, 0x0b, 0x7e, 0xb1, 0x40, 0x67, 0x66, 0xd5, 0xff,
-    0xff, 0xe2, 0xe1, 0xf3, 0x3f, 0xf7, 0x79, 0x21, 0x34, 0x9b, 0x4b, 0x7e, 0xb1, 0x40, 0xe5, 0x9b, 0x57, 0xff, 0xff, 0x83, 0x56,
-    0x8b, 0xbf, 0x58, 0xa0, 0x5b, 0x64, 0x56, 0xc5, 0xff, 0x02, 0x0f, 0xd8, 0x1b, 0x64, 0xca, 0x16, 0xfd, 0x62, 0x80, 0xf6, 0xcd,
-    0xab, 0xff, 0xff, 0xc9, 0xb4, 0xb7, 0xeb, 0x14, 0x15, 0xb3, 0x6a, 0xff, 0xff, 0xf1, 0xc5, 0x1a, 0x60, 0xaf, 0xac, 0x50, 0x3f,
-    0x64, 0x56, 0xc5, 0xff, 0x02, 0x9f, 0xd8, 0x39, 0x93, 0x28, 0x5b, 0xf5, 0x8a, 0x04, 0x0b, 0x36, 0xaf, 0xff, 0xff, 0x3f, 0xff,
-    0x39, 0xde, 0x48, 0x27, 0x94, 0xff, 0xc4, 0xae, 0xf0, 0xa0, 0x27, 0xa1, 0xff, 0x89, 0x1d, 0xe4, 0x82, 0x79, 0x5f, 0xfc, 0x46,
-    0xef, 0x24, 0x13, 0xc9, 0xb4, 0xb7, 0xeb, 0x14, 0x03, 0xab, 0x36, 0xaf, 0xff, 0xff, 0x02, 0xe2, 0x34, 0x55, 0xfa, 0xc5, 0x00,
-    0x73, 0x64, 0x56, 0xc5, 0xff, 0x02, 0xbf, 0xd8, 0x1e, 0xe4, 0xca, 0x16, 0xfd, 0x62, 0x80, 0x11, 0x59, 0xb5, 0x7f, 0xff, 0xf9,
-    0x36, 0x96, 0xfd, 0x62, 0x80, 0x18, 0xd9, 0xb5, 0x7f, 0xff, 0xf8, 0x6a, 0xd2, 0xed, 0x55, 0xbd, 0x62, 0x80, 0x48, 0xb2, 0x2b,
-    0x62, 0xff, 0x8f, 0x7e, 0xc3, 0xf2, 0x65, 0x0b, 0x7e, 0xb1, 0x40, 0x03, 0x76, 0x6d, 0x5f, 0xff, 0xfe, 0x5f, 0xff, 0x39, 0xde,
-    0x36, 0x49, 0x7c, 0x3d, 0xe2, 0xaf, 0xfd, 0x2f, 0xfc, 0x4a, 0xef, 0x1b, 0x24, 0xbe, 0x21, 0x6b, 0x0f, 0xbf, 0xf1, 0x77, 0xfe,
-    0xb7, 0x79, 0x22, 0x52, 0x6d, 0x2d, 0xfa, 0xc5, 0x21, 0x75, 0x9b, 0x57, 0xff, 0xff, 0x95, 0x69, 0x74, 0x93, 0x32, 0x86, 0xb1,
-    0x48, 0x30, 0xb2, 0x2b, 0x62, 0xff, 0x83, 0x1f, 0xd8, 0x8e, 0x4c, 0xa1, 0x6f, 0xd6, 0x29, 0x0c, 0x2c, 0xda, 0xbf, 0xff, 0xfd,
-    0x2f, 0xfc, 0xe7, 0x78, 0xd8, 0x4e, 0xbe, 0x21, 0x6b, 0x0f, 0xbf, 0xf2, 0x6d, 0x2d, 0xfa, 0xc5, 0x26, 0x2c, 0xda, 0xbf, 0xff,
-    0xfc, 0x5a, 0x65, 0x2f, 0xd6, 0x29, 0x4e, 0xc8, 0xad, 0x8b, 0xfe, 0x04, 0x3f, 0xb0, 0xce, 0x4c, 0xa1, 0x6f, 0xd6, 0x29, 0x56,
-    0xcd, 0xab, 0xff, 0xff, 0xc9, 0xb4, 0xb7, 0xeb, 0x14, 0x81, 0xc5, 0x9b, 0x57, 0xff, 0xff, 0x86, 0x66, 0x55, 0x54, 0xc9, 0x0f,
-    0x58, 0xa4, 0x03, 0xb6, 0x45, 0x6c, 0x5f, 0xf0, 0x21, 0xfd, 0x86, 0xb2, 0x65, 0x0b, 0x7e, 0xb1, 0x48, 0x39, 0xb3, 0x6a, 0xff,
-    0xff, 0xf2, 0x6d, 0x2d, 0xfa, 0xc5, 0x20, 0x23, 0xb3, 0x6a, 0xff, 0xff, 0xf0, 0xd0, 0xd2, 0x69, 0xb4, 0xc2, 0xba, 0xc5, 0x20,
-    0x25, 0xb2, 0x2b, 0x62, 0xff, 0x85, 0x3f, 0xb0, 0x02, 0xd9, 0x32, 0x85, 0xbf, 0x58, 0xa4, 0x0f, 0x6c, 0xda, 0xbf, 0xff, 0xfc,
-    0xbf, 0xfe, 0xb7, 0x78, 0xd9, 0x25, 0xf0, 0xf7, 0x8a, 0xbf, 0xf2, 0x6d, 0x2d, 0xfa, 0xc5, 0x20, 0x00, 0x76, 0x6d, 0x5f, 0xff,
-    0xfe, 0x81, 0xe6, 0x0c, 0x98, 0xf5, 0x8a, 0x41, 0x0a, 0xc8, 0x58, 0xc5, 0xf0, 0xa7, 0x80, 0x63, 0xf6, 0x00, 0x19, 0x93, 0x28,
-    0x5b, 0xf5, 0x8a, 0x41, 0x0e, 0xcd, 0xab, 0xff, 0xff, 0xd2, 0x0b, 0xbf, 0xf7, 0x79, 0xc2, 0x65, 0xf1, 0x4f, 0x25, 0xff, 0xa4,
-    0x17, 0xff, 0xc9, 0x3b, 0xce, 0x13, 0x2f, 0x8a, 0x79, 0x2f, 0xfd, 0x3f, 0xfc, 0x93, 0xbc, 0xe5, 0xaf, 0x85, 0x3c, 0xef, 0xfc,
-    0x9b, 0x4b, 0x7e, 0xb0, 0x21, 0x02, 0xcb, 0x36, 0xaf, 0xff, 0xff, 0x1e, 0x6a, 0xa9, 0xb5, 0x05, 0x75, 0x81, 0x08, 0x16, 0xd9,
-    0x0b, 0x18, 0xbe, 0x14, 0xeb, 0x10, 0xbf, 0x60, 0x08, 0x32, 0x65, 0x0b, 0x7e, 0xb0, 0x21, 0x0d, 0x2c, 0xda, 0xbf, 0xff, 0xfd,
-    0x3f, 0xfc, 0x93, 0xbc, 0xe5, 0xaf, 0x85, 0x3c, 0xef, 0xfd, 0x20, 0xbb, 0xfe, 0x32, 0xef, 0x39, 0x6b, 0xe2, 0x9e, 0x4b, 0xff,
-    0x48, 0x2f, 0xff, 0x89, 0xdd, 0xe7, 0x2d, 0x7c, 0x53, 0xc9, 0x7f, 0xe4, 0xda, 0x5b, 0xf5, 0x80, 0x44, 0x44, 0xb3, 0x6a, 0xff,
-    0xff, 0xf0, 0xed, 0xaa, 0x0f, 0x9e, 0x93, 0x75, 0x80, 0x44, 0x20, 0x59, 0x15, 0xb1, 0x7f, 0xc8, 0x3f, 0x60, 0x01, 0xf9, 0x32,
-    0x85, 0xbf, 0x58, 0x04, 0x40, 0x5f, 0x66, 0xd5, 0xff, 0xff, 0xe5, 0xff, 0xf3, 0x9d, 0xe3, 0x64, 0x97, 0xc3, 0xde, 0x2a, 0xff,
-    0xd3, 0xff, 0xee, 0xf3, 0x96, 0xbe, 0x14, 0xf3, 0xbf, 0xf2, 0x6d, 0x2d, 0xfa, 0xc6, 0xc0, 0x96, 0xcd, 0xab, 0xff, 0xff, 0xd3,
-    0x6a, 0x83, 0xe7, 0xa4, 0xdd, 0x63, 0x64, 0xec, 0x8a, 0xd8, 0xbf, 0xd6, 0x11, 0x3f, 0x60, 0x02, 0x19, 0x32, 0x85, 0xbf, 0x58,
-    0xd8, 0x2f, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0xff, 0xf9, 0xce, 0xf1, 0xb0, 0x9d, 0x7c, 0x3d, 0xe2, 0xaf, 0xfd, 0x3f, 0xfc, 0x93,
-    0xbc, 0xe1, 0x32, 0xf8, 0x53, 0xce, 0xff, 0xd2, 0xff, 0xc4, 0xae, 0xf1, 0xb2, 0x4b, 0xe2, 0x16, 0xb0, 0xfb, 0xff, 0x26, 0x50,
-    0xa7, 0xeb, 0x1b, 0x21, 0xb3, 0x2a, 0xbf, 0xff, 0xd8, 0x02, 0xbd, 0x43, 0xc4, 0x06, 0xff, 0xbb, 0xce, 0x5a, 0xf8, 0xa6, 0x24,
-    0x92, 0xff, 0xc9, 0xb4, 0xa7, 0xeb, 0x00, 0x18, 0x07, 0x56, 0x65, 0x57, 0xff, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x01, 0x21, 0x35,
-    0x9b, 0x57, 0xff, 0xff, 0x87, 0x26, 0x44, 0x06, 0xfd, 0x60, 0x12, 0x05, 0x36, 0x45, 0x6c, 0x5f, 0xec, 0x03, 0x7f, 0xd8, 0x0f,
-    0xf2, 0x65, 0x0b, 0x7e, 0xb0, 0x09, 0x01, 0x05, 0x9b, 0x57, 0xff, 0xff, 0x97, 0xff, 0xce, 0x77, 0x8d, 0x92, 0x5f, 0x0f, 0x78,
-    0xab, 0xff, 0x55, 0x2f, 0xfd, 0xde, 0x72, 0xd7, 0xc5, 0x3c, 0x97, 0xfe, 0x97, 0xfe, 0x49, 0xde, 0x72, 0xd7, 0xc5, 0x3c, 0x97,
-    0xfe, 0x65, 0x2f, 0xf9, 0xce, 0xf3, 0x96, 0xbe, 0x29, 0xe4, 0xbf, 0xf2, 0xa9, 0x7f, 0xc6, 0x5d, 0xe7, 0x2d, 0x7c, 0x53, 0xc9,
-    0x7f, 0xe4, 0xda, 0x5b, 0xf5, 0x8a, 0x80, 0x3f, 0x66, 0xd5, 0xff, 0xff, 0xe4, 0x26, 0x44, 0x06, 0xfd, 0x62, 0xa0, 0x75, 0x64,
-    0x56, 0xc5, 0xfe, 0xb0, 0x11, 0xfe, 0xc0, 0x06, 0xb2, 0x65, 0x0b, 0x7e, 0xb1, 0x50, 0x1d, 0x59, 0xb5, 0x7f, 0xff, 0xf9, 0x7f,
-    0xfc, 0x48, 0xef, 0x1b, 0x09, 0xd7, 0xc3, 0xde, 0x2a, 0xff, 0xd2, 0xff, 0xc9, 0x3b, 0xce, 0x13, 0x2f, 0x8a, 0x79, 0x2f, 0xfc,
-    0xaa, 0x5f, 0xfb, 0xbc, 0xe5, 0xaf, 0x8a, 0x79, 0x2f, 0xfc, 0xc1, 0x7f, 0xf9, 0xce, 0xf1, 0xb2, 0x4b, 0xe1, 0xef, 0x15, 0x7f,
-    0xe9, 0x26, 0x65, 0x04, 0xbf, 0x12, 0xbb, 0xc6, 0xc9, 0x2f, 0x88, 0x5a, 0xc3, 0xef, 0xfc, 0x9b, 0x4b, 0x7e, 0xb0, 0x58, 0x4e,
-    0xcd, 0xab, 0xff, 0xff, 0xca, 0xb4, 0xa1, 0xa1, 0xef, 0x58, 0x2c, 0x16, 0x59, 0x1f, 0x62, 0xf8, 0x85, 0xc2, 0x9f, 0xd8, 0x03,
-    0x3c, 0x99, 0x42, 0xdf, 0xac, 0x16, 0x01, 0x5d, 0x9b, 0x57, 0xff, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x0b, 0x03, 0x8b, 0x36, 0xaf,
-    0xff, 0xff, 0x48, 0xca, 0x26, 0x15, 0x0b, 0xcb, 0x9f, 0x58, 0x2c, 0x1c, 0xb2, 0x3e, 0xc5, 0xf1, 0x0b, 0x87, 0xff, 0xb0, 0x01,
-    0xfc, 0x99, 0x42, 0xdf, 0xac, 0x16, 0x07, 0x76, 0x6d, 0x5f, 0xff, 0xfe, 0x5f, 0xff, 0x5b, 0xbc, 0x6c, 0x92, 0xf8, 0x7b, 0xc5,
-    0x5f, 0xf8, 0x38, 0xff, 0xce, 0x77, 0x8d, 0x92, 0x5f, 0x10, 0xb5, 0x87, 0xdf, 0xf9, 0x92, 0xe5, 0x47, 0x48, 0x50, 0x34, 0x9f,
-    0x12, 0xbb, 0xc6, 0xc9, 0x2f, 0x86, 0x1c, 0x2a, 0xff, 0xc9, 0xb4, 0xb7, 0xeb, 0x05, 0x80, 0x0a, 0xd9, 0xb5, 0x7f, 0xff, 0xf8,
-    0xb8, 0x7d, 0x14, 0x07, 0xcf, 0x2a, 0x56, 0xb0, 0x58, 0x08, 0x36, 0x47, 0xd8, 0xbe, 0x21, 0x71, 0x4f, 0xec, 0x01, 0xce, 0x4c,
-    0xa1, 0x6f, 0xd6, 0x0b, 0x01, 0x0a, 0xcd, 0xab, 0xff, 0xff, 0xd2, 0x4c, 0xca, 0x06, 0x0d, 0x10, 0xfd, 0xde, 0x48, 0x4d, 0x26,
-    0xd2, 0xdf, 0xac, 0x16, 0x00, 0xca, 0xcd, 0xab, 0xff, 0xff, 0xca, 0x99, 0x28, 0x69, 0x55, 0x4c, 0x98, 0xac, 0x16, 0x02, 0x4d,
-    0x91, 0x5b, 0x17, 0xfc, 0x1f, 0x7e, 0xc0, 0x4e, 0xc9, 0x94, 0x2d, 0xfa, 0xc1, 0x60, 0x42, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0x9f,
-    0xfd, 0xde, 0x48, 0x4d, 0x1f, 0x45, 0x5f, 0xf3, 0x9d, 0xe4, 0x89, 0x4b, 0xff, 0xe2, 0x57, 0x78, 0xd9, 0x25, 0xf0, 0xf7, 0x8a,
-    0xbf, 0xf2, 0x6d, 0x2d, 0xfa, 0xc0, 0x88, 0x00, 0x2d, 0x9b, 0x57, 0xff, 0xff, 0x8b, 0x87, 0xd1, 0x40, 0x7d, 0x24, 0xcc, 0xd6,
-    0x04, 0x40, 0xea, 0xc8, 0xad, 0x8b, 0xfe, 0x19, 0x7e, 0xc3, 0x79, 0x32, 0x85, 0xbf, 0x58, 0x11, 0x00, 0x86, 0xcd, 0xab, 0xff,
-    0xff, 0xcb, 0xff, 0xeb, 0x77, 0x8d, 0x84, 0xeb, 0xe1, 0xef, 0x15, 0x7f, 0xe9, 0x7f, 0xec, 0x77, 0x8d, 0x92, 0x5f, 0x10, 0xb5,
-    0x87, 0xdf, 0xf9, 0x36, 0x96, 0xfd, 0x60, 0x44, 0x02, 0x5b, 0x36, 0xaf, 0xff, 0xff, 0x17, 0x0f, 0xa2, 0x80, 0xf9, 0x43, 0x4a,
-    0xd6, 0x04, 0x40, 0x95, 0x64, 0x56, 0xc5, 0xff, 0x05, 0xff, 0x8c, 0x08, 0xf2, 0x65, 0x0b, 0x7e, 0xb0, 0x22, 0x00, 0x76, 0xcd,
-    0xab, 0xff, 0xff, 0xca, 0x7f, 0xeb, 0x77, 0x92, 0x25, 0x26, 0xd2, 0xdf, 0xac, 0x2a, 0x3a, 0xcd, 0xab, 0xff, 0xff, 0xcc, 0x34,
-    0x5c, 0x2e, 0x44, 0xc1, 0x53, 0x35, 0x85, 0x43, 0x36, 0x45, 0x6c, 0x5f, 0xeb, 0x14, 0xfe, 0x30, 0x27, 0xc9, 0x94, 0x2d, 0xfa,
-    0xc2, 0xa0, 0x9e, 0xcd, 0xab, 0xff, 0xff, 0xcb, 0x18, 0xff, 0xdd, 0xe0, 0xe8, 0x4d, 0x4b, 0xff, 0x39, 0xde, 0x36, 0x49, 0x7c,
-    0x42, 0xd6, 0x1f, 0x7f, 0xe9, 0xff, 0xf7, 0x79, 0xcb, 0x5f, 0x0a, 0x79, 0xdf, 0xf9, 0x36, 0x96, 0xfd, 0x60, 0x46, 0x0b, 0x2c,
-    0xda, 0xbf, 0xff, 0xfd, 0x46, 0x98, 0x2a, 0x61, 0x01, 0xf3, 0xeb, 0x02, 0x31, 0x5d, 0x90, 0xa9, 0x8b, 0xfe, 0x18, 0xfe, 0x30,
-    0x00, 0x36, 0x4c, 0xa1, 0x6f, 0xd6, 0x04, 0x61, 0x6d, 0x9b, 0x57, 0xff, 0xff, 0x98, 0xff, 0xc4, 0x8e, 0xf2, 0x41, 0x3d, 0x2f,
-    0xfc, 0x4a, 0xef, 0x0a, 0x02, 0x7a, 0x08, 0x7f, 0xce, 0x77, 0x92, 0x25, 0x26, 0xd2, 0xdf, 0xac, 0x08, 0xcf, 0xb3, 0x6a, 0xff,
-    0xff, 0xf5, 0x1a, 0x60, 0xaa, 0x44, 0x0a, 0xeb, 0x02, 0x32, 0xb6, 0x45, 0x6c, 0x5f, 0xeb, 0x01, 0x37, 0xe3, 0x00, 0x49, 0x93,
-    0x28, 0x5b, 0xf5, 0x81, 0x1a, 0x36, 0x6d, 0x5f, 0xff, 0xfe, 0x97, 0xfe, 0x73, 0xbc, 0x28, 0x09, 0xe6, 0x3f, 0xf1, 0x2b, 0xbc,
-    0x90, 0x4f, 0x51, 0x3f, 0xf8, 0x91, 0xde, 0x48, 0x95, 0x00, 0xbb, 0xfe, 0x49, 0xde, 0x32, 0x4a, 0x80, 0x5f, 0xff, 0x5b, 0xbc,
-    0x65, 0x74, 0x01, 0x7f, 0xfd, 0x8e, 0xf1, 0x95, 0xd1, 0xff, 0xc4, 0x6e, 0xf1, 0x82, 0x52, 0x6d, 0x2d, 0xfa, 0xc0, 0x8c, 0x8a,
-    0xcd, 0xab, 0xff, 0xff, 0xc8, 0x52, 0x23, 0x30, 0xd1, 0x70, 0xb9, 0x1a, 0xc2, 0xb0, 0x96, 0xc8, 0xad, 0x8b, 0xfe, 0x00, 0x1f,
-    0xf1, 0x81, 0x66, 0x4c, 0xa1, 0x6f, 0xd6, 0x15, 0x82, 0xab, 0x36, 0xaf, 0xff, 0xff, 0x4b, 0xff, 0x63, 0xbc, 0x6c, 0x27, 0x5f,
-    0x10, 0xb5, 0x87, 0xdf, 0xfa, 0x1f, 0xf8, 0x95, 0xde, 0xc1, 0x34, 0x85, 0xaa, 0x0f, 0x7f, 0xbb, 0xc2, 0xf5, 0xaf, 0xf8, 0x92,
-    0x24, 0xff, 0x2a, 0x47, 0xff, 0x39, 0xde, 0x48, 0x94, 0xa8, 0xbf, 0xfe, 0x25, 0x77, 0x92, 0x25, 0x41, 0x2f, 0xf8, 0x91, 0xde,
-    0x14, 0x12, 0x93, 0x69, 0x6f, 0xd6, 0x15, 0x80, 0x96, 0xcd, 0xab, 0xff, 0xff, 0xc8, 0x52, 0x23, 0x51, 0xa6, 0x0a, 0xa1, 0x58,
-    0x56, 0x02, 0x7b, 0x22, 0xb6, 0x2f, 0xf8, 0x6b, 0xf8, 0xc0, 0x34, 0xc9, 0x94, 0x2d, 0xfa, 0xc2, 0xb0, 0x3e, 0xb3, 0x6a, 0xff,
-    0xff, 0xf4, 0x3f, 0xf1, 0x87, 0x7b, 0x04, 0xd4, 0xbf, 0xf2, 0x4e, 0xf1, 0xb1, 0x32, 0xf8, 0x85, 0xac, 0x3e, 0xff, 0xc9, 0xb4,
-    0xb7, 0xeb, 0x0a, 0xc0, 0x05, 0xd9, 0xb5, 0x7f, 0xff, 0xf9, 0x0a, 0x44, 0x64, 0x05, 0x0a, 0xa0, 0x3f, 0x58, 0x56, 0x03, 0x9b,
-    0x22, 0xb6, 0x2f, 0xf8, 0x79, 0xf8, 0xc0, 0x6b, 0x93, 0x28, 0x5b, 0xf5, 0x85, 0x61, 0x22, 0xcd, 0xab, 0xff, 0xff, 0xd0, 0xff,
-    0xee, 0xf2, 0x42, 0x69, 0x36, 0x96, 0xfd, 0x62, 0xb0, 0x67, 0x66, 0xd5, 0xff, 0xff, 0xe4, 0x29, 0x11, 0x90, 0x14, 0x2a, 0x58,
-    0xbd, 0x62, 0xb0, 0xce, 0xc8, 0xad, 0x8b, 0xfe, 0x02, 0xff, 0xc6, 0x03, 0xac, 0x99, 0x42, 0xdf, 0xac, 0x56, 0x1a, 0xd9, 0xb5,
-    0x7f, 0xff, 0xfa, 0x08, 0x7f, 0xce, 0x77, 0x92, 0x25, 0x31, 0xff, 0x89, 0x5d, 0xe4, 0x89, 0x51, 0xff, 0xd8, 0xef, 0x24, 0x4a,
-    0x5d, 0xa5, 0x95, 0xfe, 0x24, 0x77, 0x85, 0x04, 0xa9, 0xa2, 0x3c, 0x3e, 0xaf, 0xe2, 0x37, 0x78, 0x50, 0x4a, 0x4d, 0xa5, 0xbf,
-    0x58, 0x2d, 0x12, 0x59, 0xb5, 0x7f, 0xff, 0xf9, 0x0a, 0x44, 0x65, 0x5a, 0x5c, 0x5c, 0x8d, 0x60, 0xb4, 0x25, 0xb2, 0x2b, 0x62,
-    0xff, 0x8a, 0x7f, 0x18, 0x0f, 0x72, 0x65, 0x0b, 0x7e, 0xb0, 0x5a, 0x33, 0x66, 0xd5, 0xff, 0xff, 0xe2, 0xef, 0xfe, 0xef, 0x24,
-    0x26, 0x93, 0x69, 0x6f, 0xd6, 0x0b, 0x45, 0x56, 0x6d, 0x5f, 0xff, 0xfe, 0x42, 0x91, 0x19, 0xa2, 0x85, 0xc8, 0x35, 0x82, 0xd0,
-    0x09, 0xd9, 0x15, 0xb1, 0x7f, 0xc3, 0x5f, 0xc6, 0x07, 0x72, 0x65, 0x0b, 0x7e, 0xb0, 0x5a, 0x2b, 0xb3, 0x6a, 0xff, 0xff, 0xf4,
-    0xbf, 0xf5, 0xbb, 0xc6, 0xc2, 0x75, 0xf1, 0x0b, 0x58, 0x7d, 0xff, 0x97, 0xff, 0xce, 0x77, 0x92, 0x25, 0x26, 0xd2, 0xdf, 0xac,
-    0x16, 0xa1, 0x66, 0xd5, 0xff, 0xff, 0xe4, 0x29, 0x11, 0xa0, 0x65, 0x36, 0xeb, 0x05, 0xa5, 0xac, 0x8a, 0xd8, 0xbf, 0xe2, 0x9f,
-    0xc6, 0x0b, 0x64, 0xca, 0x16, 0xfd, 0x60, 0xb4, 0x7e, 0xcd, 0xab, 0xff, 0xff, 0xc5, 0xdf, 0xfd, 0xde, 0x48, 0x4d, 0x26, 0xd2,
-    0xdf, 0xac, 0x16, 0x91, 0xd9, 0xb5, 0x7f, 0xff, 0xf9, 0x93, 0x4a, 0x97, 0x44, 0xc3, 0x75, 0x82, 0xd0, 0x0b, 0xd9, 0x0a, 0x98,
-    0xbf, 0xe0, 0x8b, 0xf8, 0xc2, 0x59, 0x32, 0x85, 0xbf, 0x58, 0x2d, 0x10, 0xac, 0xda, 0xbf, 0xff, 0xfd, 0x3f, 0xfe, 0xef, 0x38,
-    0x4c, 0xbe, 0x14, 0xf3, 0xbf, 0xf2, 0xa4, 0x7f, 0xf3, 0x9d, 0xe4, 0x89, 0x4a, 0x8b, 0xff, 0xe2, 0x57, 0x79, 0x22, 0x52, 0x6d,
-    0x2d, 0xfa, 0xc0, 0x90, 0x34, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0x14, 0x4c, 0x7e, 0xb0, 0x24, 0x02, 0xfb, 0x22, 0xb6, 0x2f, 0xf8,
-    0x10, 0xfe, 0x30, 0x47, 0x93, 0x28, 0x5b, 0xf5, 0x81, 0x20, 0x18, 0x59, 0xb5, 0x7f, 0xff, 0xf9, 0xff, 0xfd, 0xde, 0x48, 0x4d,
-    0x29, 0xff, 0x92, 0x77, 0x92, 0x13, 0x49, 0xb4, 0xb7, 0xeb, 0x02, 0x40, 0x33, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0x13, 0xc8, 0xff,
-    0x58, 0x12, 0x01, 0xad, 0x91, 0x5b, 0x17, 0xfc, 0x08, 0x7f, 0x18, 0x10, 0x72, 0x65, 0x0b, 0x7e, 0xb0, 0x24, 0x29, 0x66, 0xd5,
-    0xff, 0xff, 0xe7, 0xff, 0xf7, 0x79, 0x21, 0x34, 0xa7, 0xfe, 0x49, 0xde, 0x48, 0x4d, 0x26, 0xd2, 0xdf, 0xac, 0x09, 0x14, 0xb3,
-    0x6a, 0xff, 0xff, 0xf1, 0x70, 0xfa, 0x28, 0x0f, 0x97, 0x3c, 0x5f, 0x58, 0x12, 0x11, 0xd9, 0x1f, 0x62, 0xf8, 0x7b, 0xc0, 0x2d,
-    0xf8, 0xc0, 0x07, 0x32, 0x65, 0x0b, 0x7e, 0xb0, 0x24, 0x07, 0x16, 0x6d, 0x5f, 0xff, 0xfe, 0x5d, 0x2f, 0xf9, 0xce, 0xf1, 0xb2,
-    0x4b, 0xe1, 0xef, 0x15, 0x7f, 0xe4, 0xda, 0x5b, 0xf5, 0x81, 0x20, 0x0a, 0x6c, 0xda, 0xbf, 0xff, 0xfd, 0x53, 0x25, 0xcf, 0x17,
-    0xb5, 0x3d, 0x60, 0x48, 0x0a, 0x16, 0x45, 0x6c, 0x5f, 0xf0, 0x0d, 0xfe, 0x30, 0x0e, 0xb2, 0x65, 0x0b, 0x7e, 0xb0, 0x24, 0x01,
-    0xfd, 0x9b, 0x57, 0xff, 0xff, 0x97, 0xff, 0xd6, 0xef, 0x1b, 0x09, 0xd7, 0xc3, 0xde, 0x2a, 0xff, 0xd2, 0xff, 0xce, 0x77, 0x8d,
-    0x92, 0x5f, 0x10, 0xb5, 0x87, 0xdf, 0xfa, 0x49, 0x7f, 0xc4, 0xae, 0xf1, 0xb2, 0x4b, 0xe2, 0x16, 0xb0, 0xfb, 0xff, 0x26, 0xd2,
-    0xdf, 0xac, 0x09, 0x48, 0x2c, 0xda, 0xbf, 0xff, 0xfd, 0x02, 0xed, 0x3d, 0x3f, 0x58, 0x12, 0x88, 0x36, 0x45, 0x6c, 0x5f, 0xf0,
-    0xd7, 0xf1, 0x80, 0x11, 0xe4, 0xca, 0x16, 0xfd, 0x60, 0x4a, 0x01, 0xab, 0x36, 0xaf, 0xff, 0xff, 0x4f, 0xff, 0xbb, 0xce, 0x13,
-    0x2f, 0x85, 0x3c, 0xef, 0xfd, 0x13, 0x03, 0xff, 0xe7, 0x3b, 0xc9, 0x09, 0xa4, 0xda, 0x5b, 0xf5, 0x81, 0x28, 0x3f, 0xb3, 0x6a,
-    0xff, 0xff, 0xf2, 0x86, 0x53, 0x2a, 0xd4, 0xfa, 0xc0, 0x94, 0x11, 0x2c, 0x8a, 0xd8, 0xbf, 0xd6, 0x04, 0xdf, 0x8c, 0x00, 0xa3,
-    0x26, 0x50, 0xb7, 0xeb, 0x02, 0x50, 0x46, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0xff, 0xf9, 0xce, 0xf1, 0xb0, 0x9d, 0x7c, 0x3d, 0xe2,
-    0xaf, 0xfd, 0x3f, 0xfc, 0x93, 0xbc, 0xe1, 0x32, 0xf8, 0x53, 0xce, 0xff, 0xd1, 0xff, 0xc4, 0xae, 0xf2, 0x41, 0x3d, 0x0f, 0xfc,
-    0x4e, 0xef, 0x24, 0x26, 0x93, 0x69, 0x6f, 0xd6, 0x16, 0x04, 0x7b, 0x36, 0xaf, 0xff, 0xff, 0x51, 0xaa, 0x94, 0x32, 0x99, 0x56,
-    0xd6, 0x16, 0x08, 0xb6, 0x45, 0x6c, 0x5f, 0xf0, 0x0d, 0xfe, 0x30, 0x00, 0x86, 0x4c, 0xa1, 0x6f, 0xd6, 0x16, 0x00, 0xfa, 0xcd,
-    0xab, 0xff, 0xff, 0xd2, 0xff, 0xce, 0x77, 0x8d, 0x84, 0xeb, 0xe2, 0x16, 0xb0, 0xfb, 0xff, 0x26, 0xd2, 0xdf, 0xac, 0x02, 0x8a,
-    0x59, 0xb5, 0x7f, 0xff, 0xfa, 0x83, 0xe7, 0x95, 0x6f, 0xac, 0x02, 0x84, 0x76, 0x45, 0x6c, 0x5f, 0xf1, 0x1f, 0xe3, 0x00, 0x0b,
-    0x64, 0xca, 0x16, 0xfd, 0x60, 0x14, 0x07, 0x16, 0x6d, 0x5f, 0xff, 0xfe, 0x5f, 0xff, 0xbb, 0xc6, 0xc4, 0xcb, 0xe1, 0xef, 0x15,
-    0x7f, 0xe4, 0xda, 0x5b, 0xf5, 0x80, 0x50, 0x1c, 0x59, 0xb5, 0x7f, 0xff, 0xf8, 0x37, 0x32, 0x99, 0x5f, 0xf7, 0x37, 0x24, 0x35,
-    0x0f, 0x01, 0xb9, 0x94, 0xca, 0xff, 0x73, 0x70, 0x52, 0x35, 0x1c, 0x32, 0x89, 0x85, 0x7f, 0xb9, 0xb9, 0xc3, 0x4b, 0xe2, 0x9e,
-    0x4b, 0xff, 0x0e, 0xda, 0x82, 0xa7, 0x98, 0x1e, 0x2f, 0xee, 0x6e, 0x70, 0xd2, 0xf8, 0x53, 0xce, 0xff, 0xc3, 0x34, 0x55, 0x21,
-    0x3d, 0x2f, 0xdc, 0xdc, 0xe1, 0xa5, 0xf0, 0xaf, 0x8a, 0x3f, 0xf0, 0xe5, 0xa6, 0x0d, 0x7f, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1, 0x6f,
-    0x19, 0xff, 0xc5, 0xad, 0x23, 0x54, 0xd2, 0x6d, 0xfb, 0x9b, 0x9c, 0x34, 0xbe, 0x2c, 0xe2, 0x0f, 0xfe, 0x2d, 0x69, 0x1a, 0xa6,
-    0x93, 0x68, 0x5d, 0xee, 0x6e, 0x70, 0xd2, 0xf8, 0x61, 0xc2, 0xaf, 0xfc, 0x34, 0x32, 0x55, 0xbf, 0xee, 0x6e, 0x48, 0x6a, 0x1d,
-    0x14, 0x51, 0x03, 0x49, 0xb4, 0xaf, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1, 0xbf, 0x1b, 0xff, 0xc3, 0x83, 0xc5, 0xed, 0x4f, 0xfb, 0x9b,
-    0x9c, 0x34, 0xbe, 0x1e, 0xf1, 0x57, 0xfe, 0x69, 0x01, 0xf3, 0xd3, 0xfe, 0xe6, 0xe3, 0x23, 0x51, 0xc2, 0x82, 0xf5, 0x5b, 0xfd,
-    0xcd, 0xc6, 0x46, 0xa3, 0x83, 0xe3, 0xaa, 0xff, 0xb9, 0xb9, 0xc3, 0x4b, 0xd6, 0x10, 0x79, 0x2f, 0xfc, 0x70, 0x81, 0x51, 0xd4,
-    0xbf, 0xb9, 0xb8, 0xd8, 0xd2, 0xf5, 0x84, 0x5e, 0x16, 0x7f, 0xe3, 0x84, 0x0a, 0x0e, 0xa5, 0xfd, 0xcd, 0xc6, 0xc6, 0x97, 0xac,
-    0x45, 0xe1, 0x6f, 0xfe, 0x38, 0x80, 0xf8, 0xea, 0x5f, 0xdc, 0xdc, 0x6c, 0x69, 0x7a, 0xc2, 0x4f, 0x1f, 0xff, 0xc9, 0xaa, 0xd2,
-    0x35, 0x7d, 0x62, 0x70, 0x40, 0xb3, 0x18, 0x5e, 0x36, 0x09, 0x2b, 0x03, 0x3f, 0xff, 0x26, 0x54, 0xf5, 0x4f, 0xf5, 0x90, 0x05,
-    0x91, 0x8c, 0x61, 0x7c, 0x12, 0xe7, 0x7f, 0xf9, 0x32, 0x8a, 0x29, 0xf2, 0x20, 0x87, 0x89, 0x31, 0x85, 0xff, 0xff, 0xe4, 0xcb,
-    0x22, 0x5d, 0xbf, 0x24, 0x01, 0x67, 0x37, 0x8f, 0x2c, 0xab, 0x48, 0x4d, 0x11, 0x93, 0x43, 0xff, 0x95, 0x34, 0x7a, 0x6a, 0xfe,
-    0xc7, 0x37, 0x39, 0x18, 0xe1, 0x94, 0x4c, 0x2b, 0xf8, 0xa5, 0xcd, 0xce, 0x46, 0x1d, 0xb5, 0x05, 0x4f, 0x30, 0x3c, 0x5f, 0x85,
-    0x2e, 0x6e, 0x72, 0x30, 0xcd, 0x15, 0x48, 0x4f, 0x4b, 0xe1, 0x5b, 0x9b, 0x9c, 0x8c, 0x39, 0x69, 0x83, 0x5f, 0xc2, 0xd7, 0x37,
-    0x39, 0x18, 0xb5, 0xa4, 0x6a, 0x9a, 0x4d, 0xbe, 0x2c, 0x73, 0x73, 0x91, 0x8b, 0x5a, 0x46, 0xa9, 0xa4, 0xda, 0x17, 0x70, 0xc1,
-    0xcd, 0xce, 0x46, 0x1c, 0x99, 0x34, 0xa3, 0x7f, 0x0c, 0x5c, 0xdc, 0xe4, 0x61, 0xd1, 0x45, 0x10, 0x34, 0x9b, 0x4a, 0xf0, 0xdd,
-    0xcd, 0xce, 0x46, 0x1c, 0x1e, 0x2f, 0x6a, 0x7f, 0x87, 0xae, 0x6e, 0x72, 0x30, 0xfc, 0x5c, 0x89, 0x85, 0x11, 0x07, 0x0d, 0xf7,
-    0x37, 0x38, 0x25, 0x5f, 0xf3, 0xbf, 0x18, 0x11, 0x72, 0x82, 0xf5, 0x5a, 0xa1, 0x1f, 0xee, 0xf6, 0x1d, 0x28, 0x2f, 0x55, 0xaa,
-    0x0b, 0xfe, 0xb7, 0x7b, 0x0e, 0x94, 0x45, 0x55, 0x08, 0xff, 0x63, 0xbc, 0x60, 0xe9, 0x44, 0x55, 0x50, 0x5f, 0xf9, 0x27, 0x78,
-    0xc1, 0xd2, 0x6d, 0x32, 0xa7, 0xf3, 0x9d, 0xf2, 0xcb, 0xc6, 0x02, 0x37, 0x3b, 0xff, 0x0f, 0xda, 0x99, 0x11, 0x0b, 0x54, 0x1e,
-    0xf7, 0x37, 0x38, 0x25, 0x5f, 0xf2, 0x5f, 0x8c, 0x02, 0x7e, 0x81, 0x09, 0x5f, 0xfb, 0xbd, 0x87, 0x50, 0x1f, 0x3c, 0xc7, 0xf5,
-    0xbb, 0xd8, 0x74, 0x9b, 0x4c, 0xa9, 0xfc, 0x93, 0xbe, 0x59, 0x78, 0xc0, 0x8b, 0xc9, 0x7f, 0xe5, 0x0f, 0x1e, 0x69, 0x85, 0x7f,
-    0x73, 0x73, 0x82, 0x55, 0xff, 0x19, 0xfc, 0x60, 0x00, 0x1b, 0x51, 0x10, 0x70, 0xdf, 0xf7, 0x79, 0x23, 0xaa, 0x26, 0x87, 0xf9,
-    0x27, 0x78, 0x50, 0x74, 0xab, 0x54, 0x57, 0xf9, 0xce, 0xf0, 0xa0, 0xe9, 0x36, 0x99, 0x53, 0xf8, 0xcb, 0xbe, 0x59, 0x78, 0xc0,
-    0x27, 0xf1, 0x9f, 0xfd, 0x02, 0x86, 0x8b, 0x7f, 0x2a, 0xe6, 0xe7, 0x23, 0x40, 0xba, 0x2a, 0x2b, 0xf9, 0x03, 0x9b, 0x9c, 0x8d,
-    0x21, 0xf1, 0x7a, 0x86, 0x52, 0x53, 0xac, 0x15, 0xb9, 0xb9, 0xc8, 0xd2, 0x40, 0xa7, 0xf9, 0xa7, 0x37, 0x39, 0x18, 0xe2, 0x66,
-    0x50, 0xfe, 0x21, 0x39, 0xb9, 0xc8, 0xcd, 0x15, 0x3d, 0x53, 0xff, 0x03, 0xc7, 0x37, 0x39, 0x18, 0xe2, 0x06, 0x4c, 0x16, 0x44,
-    0x7b, 0xac, 0x11, 0x9c, 0xdc, 0xe4, 0x63, 0x83, 0xa3, 0xed, 0x3c, 0xab, 0x53, 0xd6, 0x10, 0x5c, 0xdc, 0xe4, 0x63, 0x87, 0xa0,
-    0x40, 0xd5, 0x7d, 0x61, 0x15, 0xcd, 0xce, 0x46, 0x38, 0x40, 0xaa, 0x8a, 0x9e, 0xab, 0x75, 0x88, 0xae, 0x6e, 0x72, 0x31, 0xc4,
-    0x07, 0xcf, 0x30, 0x61, 0xa5, 0xf5, 0x84, 0x97, 0x37, 0x39, 0x1a, 0xa4, 0x69, 0x10, 0x2a, 0xa2, 0xa7, 0xfb, 0x9b, 0x84, 0xa0,
-    0xa9, 0x7f, 0xc9, 0x7e, 0x48, 0x15, 0x69, 0x11, 0x8e, 0x98, 0x34, 0x5c, 0xbf, 0xdd, 0xe1, 0x30, 0x9e, 0x91, 0x18, 0xe8, 0xb8,
-    0xf4, 0x55, 0xf5, 0xbb, 0xc2, 0x61, 0x3d, 0x22, 0x31, 0xd3, 0x24, 0x1f, 0xb1, 0xde, 0x13, 0x09, 0xe4, 0xda, 0x65, 0x4f, 0xe4,
-    0x9d, 0xf2, 0xcb, 0xc9, 0x1d, 0xc9, 0x7f, 0xe9, 0xa0, 0x91, 0x02, 0xaa, 0x2a, 0x7f, 0xb9, 0xb8, 0x4a, 0x0a, 0x97, 0xfc, 0x97,
-    0xe4, 0x80, 0x87, 0x49, 0x31, 0xd3, 0x06, 0x8b, 0x97, 0xfb, 0xbc, 0x26, 0x13, 0xd2, 0x4c, 0x75, 0x44, 0x41, 0xc3, 0x7d, 0x6e,
-    0xf0, 0x98, 0x4f, 0x49, 0x31, 0xd2, 0xa7, 0xaa, 0x7f, 0xb1, 0xde, 0x13, 0x09, 0xe4, 0xda, 0x65, 0x4f, 0xe4, 0x9d, 0xf2, 0xcb,
-    0xc9, 0x02, 0xae, 0x4b, 0xff, 0x28, 0x99, 0xe4, 0x23, 0xa4, 0x2d, 0x21, 0xd6, 0x16, 0xb9, 0xb9, 0xc8, 0xc4, 0x03, 0xcb, 0xa9,
-    0xfa, 0xc2, 0xe7, 0x37, 0x39, 0x19, 0x42, 0xe8, 0xa5, 0xfa, 0xc1, 0x93, 0x9b, 0x89, 0x91, 0x94, 0x2e, 0x65, 0x2f, 0xd6, 0x1a,
-    0x39, 0xb9, 0xc8, 0xca, 0xb4, 0x84, 0xd1, 0x1f, 0xac, 0x02, 0x82, 0x0d, 0x91, 0x5b, 0x17, 0xfc, 0x01, 0xaf, 0xc9, 0x05, 0x79,
-    0x32, 0x85, 0xbf, 0x58, 0x05, 0x00, 0xe2, 0xcd, 0xab, 0xff, 0xff, 0xd0, 0x32, 0x68, 0xc2, 0xbf, 0xdd, 0xe4, 0x97, 0x43, 0xff,
-    0x63, 0xbc, 0x64, 0x95, 0x56, 0x90, 0xa5, 0xfc, 0x46, 0xef, 0x19, 0x5c, 0x9b, 0x4b, 0x7e, 0xb0, 0x26, 0x06, 0xb6, 0x6d, 0x5f,
-    0xff, 0xfe, 0x9b, 0x50, 0x10, 0x10, 0x1f, 0x3d, 0x3a, 0xc0, 0x98, 0x3b, 0xb2, 0x2b, 0x62, 0xff, 0x58, 0x4f, 0xf9, 0x20, 0xbb,
-    0x26, 0x50, 0xb7, 0xeb, 0x02, 0x61, 0x6d, 0x9b, 0x57, 0xff, 0xff, 0x97, 0xff, 0xee, 0xf2, 0x42, 0x6a, 0x5f, 0xf9, 0x27, 0x78,
-    0x50, 0x26, 0x98, 0xff, 0xce, 0x77, 0x92, 0x13, 0x52, 0x4b, 0xfe, 0xc7, 0x78, 0xd9, 0x25, 0xf1, 0x0b, 0x58, 0x7d, 0xff, 0xa9,
-    0xff, 0x9c, 0xef, 0x0a, 0x09, 0x50, 0x4b, 0xfe, 0x25, 0x77, 0x8a, 0x89, 0x51, 0xff, 0xc4, 0x8e, 0xf2, 0x44, 0xa4, 0xda, 0x5b,
-    0xf5, 0x81, 0x38, 0x1a, 0xd9, 0xb5, 0x7f, 0xff, 0xfa, 0x05, 0x0d, 0x16, 0x3c, 0x40, 0x3d, 0x1a, 0xc0, 0x9c, 0xb5, 0x91, 0x5b,
-    0x17, 0xfc, 0x51, 0xf9, 0x20, 0x63, 0x93, 0x28, 0x5b, 0xf5, 0x81, 0x38, 0xfd, 0x9b, 0x57, 0xff, 0xff, 0xaa, 0x7a, 0x63, 0xcd,
-    0x57, 0xf7, 0x78, 0xd8, 0x99, 0x7c, 0x42, 0xd6, 0x1f, 0x7f, 0xe4, 0xda, 0x5b, 0xf5, 0x81, 0x38, 0xba, 0xcd, 0xab, 0xff, 0xff,
-    0xd2, 0x3d, 0x45, 0x10, 0x1f, 0x3d, 0x3a, 0xc0, 0x9c, 0x1c, 0x59, 0x15, 0xb1, 0x7f, 0xc3, 0x5f, 0xc9, 0x06, 0x99, 0x32, 0x85,
-    0xbf, 0x58, 0x13, 0x80, 0x62, 0xcd, 0xab, 0xff, 0xff, 0xd0, 0xff, 0xee, 0xf2, 0x42, 0x6a, 0xa7, 0xa6, 0x3c, 0xd5, 0x7e, 0xc7,
-    0x78, 0xd8, 0x4e, 0xbe, 0x21, 0x6b, 0x0f, 0xbf, 0xf4, 0x12, 0xff, 0xad, 0xde, 0x2a, 0x25, 0x26, 0xd2, 0xdf, 0xac, 0x09, 0xc4,
-    0x4b, 0x36, 0xaf, 0xff, 0xff, 0x2a, 0x65, 0x05, 0x4b, 0xfa, 0xc0, 0x9c, 0x10, 0xac, 0x8a, 0xd8, 0xbf, 0xd6, 0x00, 0xe7, 0xe4,
-    0x80, 0xcb, 0x26, 0x50, 0xb7, 0xeb, 0x02, 0x70, 0x43, 0xb3, 0x6a, 0xff, 0xff, 0xf5, 0x3f, 0xf3, 0x9d, 0xe1, 0x40, 0x4f, 0x31,
-    0xff, 0x92, 0x77, 0x92, 0x13, 0x50, 0x21, 0x2b, 0xfe, 0x24, 0x77, 0xb0, 0x95, 0x2f, 0xfc, 0x4a, 0xef, 0x1b, 0x24, 0xbe, 0x21,
-    0x6b, 0x0f, 0xbf, 0xf1, 0xf6, 0x91, 0xff, 0xad, 0xde, 0x17, 0xad, 0x7f, 0xc2, 0x68, 0x4d, 0xfe, 0x7a, 0x5f, 0xf1, 0x97, 0x79,
-    0xcb, 0x5e, 0x30, 0x09, 0xfc, 0x67, 0xff, 0x26, 0x50, 0xa7, 0xeb, 0x02, 0x80, 0x21, 0xb3, 0x2a, 0xbf, 0xff, 0xc9, 0x07, 0xd9,
-    0x01, 0x73, 0x45, 0x47, 0x9b, 0xf1, 0xb7, 0x78, 0x5e, 0xb5, 0xff, 0x02, 0x38, 0x11, 0xff, 0xa3, 0xff, 0xb1, 0xde, 0x48, 0x92,
-    0xfe, 0x1a, 0x7f, 0xf9, 0x36, 0x94, 0xfd, 0x60, 0x4e, 0x0a, 0x16, 0x65, 0x57, 0xff, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x05, 0x00,
-    0x41, 0x66, 0xd5, 0xff, 0xff, 0xe0, 0xdc, 0xca, 0x65, 0x7f, 0xdc, 0xdc, 0x90, 0xd4, 0x3c, 0x06, 0xe6, 0x53, 0x2b, 0xfd, 0xcd,
-    0xc1, 0x48, 0xd4, 0x70, 0xca, 0x26, 0x15, 0xfe, 0xe6, 0xe7, 0x0d, 0x2f, 0x8a, 0x79, 0x2f, 0xfc, 0x3b, 0x6a, 0x0a, 0x9e, 0x60,
-    0x78, 0xbf, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1, 0x4f, 0x3b, 0xff, 0x0c, 0xd1, 0x54, 0x84, 0xf4, 0xbf, 0x73, 0x73, 0x86, 0x97, 0xc2,
-    0xbe, 0x28, 0xff, 0xc3, 0x96, 0x98, 0x35, 0xfe, 0xe6, 0xe7, 0x0d, 0x2f, 0x85, 0xbc, 0x67, 0xff, 0x16, 0xb4, 0x8d, 0x53, 0x49,
-    0xb7, 0xee, 0x6e, 0x70, 0xd2, 0xf8, 0xb3, 0x88, 0x3f, 0xf8, 0xb5, 0xa4, 0x6a, 0x9a, 0x4d, 0xa1, 0x77, 0xb9, 0xb9, 0xc3, 0x4b,
-    0xe1, 0x87, 0x0a, 0xbf, 0xf0, 0xd0, 0xc9, 0x56, 0xff, 0xb9, 0xb9, 0x21, 0xa8, 0x74, 0x51, 0x44, 0x0d, 0x26, 0xd2, 0xbe, 0xe6,
-    0xe7, 0x0d, 0x2f, 0x86, 0xfc, 0x6f, 0xff, 0x0e, 0x0f, 0x17, 0xb5, 0x3f, 0xee, 0x6e, 0x70, 0xd2, 0xf8, 0x7b, 0xc5, 0x5f, 0xf9,
-    0xa4, 0x07, 0xcf, 0x4f, 0xfb, 0x9b, 0x8c, 0x8d, 0x47, 0x0a, 0x0b, 0xd5, 0x6f, 0xf7, 0x37, 0x19, 0x1a, 0x8e, 0x0f, 0x8e, 0xab,
-    0xfe, 0xe6, 0xe7, 0x0d, 0x2f, 0x58, 0x41, 0xe4, 0xbf, 0xf1, 0xc2, 0x05, 0x47, 0x52, 0xfe, 0xe6, 0xe3, 0x63, 0x4b, 0xd6, 0x11,
-    0x78, 0x59, 0xff, 0x8e, 0x10, 0x28, 0x3a, 0x97, 0xf7, 0x37, 0x1b, 0x1a, 0x5e, 0xb1, 0x17, 0x85, 0xbf, 0xf8, 0xe2, 0x03, 0xe3,
-    0xa9, 0x7f, 0x73, 0x71, 0xb1, 0xa5, 0xeb, 0x09, 0x3c, 0x7f, 0xff, 0x26, 0xab, 0x48, 0xd5, 0xf5, 0x80, 0x50, 0x41, 0xb3, 0x18,
-    0x5e, 0x48, 0x51, 0x02, 0x6f, 0xff, 0x26, 0x54, 0xf5, 0x4f, 0xf5, 0x97, 0x3a, 0x31, 0x8c, 0x2f, 0x85, 0xb1, 0x37, 0xff, 0x93,
-    0x28, 0xa2, 0x9f, 0x22, 0x08, 0x78, 0x93, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xb2, 0x25, 0xdb, 0xf2, 0x41, 0x05, 0xcd, 0xe3, 0xcb,
-    0x69, 0x1b, 0x50, 0x26, 0x87, 0xff, 0x95, 0x34, 0x7a, 0x6a, 0xfe, 0xc7, 0x37, 0x39, 0x18, 0xe1, 0x94, 0x4c, 0x2b, 0xf8, 0xa5,
-    0xcd, 0xce, 0x46, 0x1d, 0xb5, 0x05, 0x4f, 0x30, 0x3c, 0x5f, 0x85, 0x2e, 0x6e, 0x72, 0x30, 0xcd, 0x15, 0x48, 0x4f, 0x4b, 0xe1,
-    0x5b, 0x9b, 0x9c, 0x8c, 0x39, 0x69, 0x83, 0x5f, 0xc2, 0xd7, 0x37, 0x39, 0x18, 0xb5, 0xa4, 0x6a, 0x9a, 0x4d, 0xbe, 0x2c, 0x73,
-    0x73, 0x91, 0x8b, 0x5a, 0x46, 0xa9, 0xa4, 0xda, 0x17, 0x70, 0xc1, 0xcd, 0xce, 0x46, 0x1c, 0x99, 0x34, 0xa3, 0x7f, 0x0c, 0x5c,
-    0xdc, 0xe4, 0x68, 0x14, 0x34, 0x5b, 0xf9, 0x57, 0x37, 0x39, 0x1a, 0x05, 0xd1, 0x51, 0x5f, 0xc8, 0x1c, 0xdc, 0xe4, 0x69, 0x0f,
-    0x8b, 0xd4, 0x32, 0x92, 0x9d, 0x60, 0xad, 0xcd, 0xce, 0x46, 0x1d, 0x14, 0x51, 0x03, 0x49, 0xb4, 0xaf, 0x0d, 0xdc, 0xdc, 0xe4,
-    0x61, 0xc1, 0xe2, 0xf6, 0xa7, 0xf8, 0x7a, 0xe6, 0xe7, 0x23, 0x49, 0x02, 0x9f, 0xe6, 0x9c, 0xdc, 0xe4, 0x63, 0x89, 0x99, 0x43,
-    0xf8, 0x84, 0xe6, 0xe7, 0x23, 0x34, 0x54, 0xf5, 0x4f, 0xfc, 0x0f, 0x1c, 0xdc, 0xe4, 0x6d, 0x23, 0x6a, 0x1f, 0x58, 0x14, 0x03,
-    0xab, 0x22, 0xb6, 0x2f, 0xf8, 0x75, 0xf9, 0x20, 0x11, 0x64, 0xca, 0x16, 0xfd, 0x60, 0x50, 0x02, 0x3b, 0x36, 0xaf, 0xff, 0xff,
-    0x2d, 0xff, 0xdd, 0xe0, 0xe8, 0x4d, 0x69, 0x1b, 0x50, 0x49, 0x02, 0x9f, 0x77, 0x9c, 0xb5, 0xf3, 0x5c, 0x35, 0xff, 0xda, 0x46,
-    0xd4, 0x14, 0x54, 0x83, 0xc3, 0x57, 0x78, 0xb1, 0x6b, 0xfe, 0x18, 0xc4, 0xff, 0xe4, 0xda, 0x5b, 0xf5, 0x81, 0x40, 0x25, 0xd9,
-    0xb5, 0x7f, 0xff, 0xf8, 0x38, 0x32, 0x42, 0x51, 0xa4, 0x6d, 0x42, 0xb0, 0x28, 0x04, 0xcb, 0x22, 0xb6, 0x2f, 0xf8, 0x75, 0xf9,
-    0x20, 0x13, 0x64, 0xca, 0x16, 0xfd, 0x60, 0x50, 0x03, 0xab, 0x36, 0xaf, 0xff, 0xff, 0x2d, 0xff, 0xdd, 0xe0, 0xe8, 0x4d, 0x07,
-    0x06, 0x48, 0x4a, 0x24, 0x81, 0x4f, 0xbb, 0xce, 0x5a, 0xf9, 0xae, 0x1a, 0xff, 0xe0, 0xe0, 0xc9, 0x09, 0x45, 0x15, 0x20, 0xf0,
-    0xd5, 0xde, 0x2c, 0x5a, 0xff, 0x86, 0x31, 0x3f, 0xf9, 0x36, 0x96, 0xfd, 0x64, 0xc1, 0x75, 0x9b, 0x57, 0xff, 0xff, 0x8b, 0x91,
-    0x30, 0x54, 0xc8, 0xb9, 0x45, 0x6b, 0x26, 0x2c, 0xb2, 0x2b, 0x62, 0xff, 0x8c, 0xfe, 0x48, 0x05, 0x39, 0x32, 0x85, 0xbf, 0x59,
-    0x30, 0x2b, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0x6d, 0x2d, 0xfa, 0xc9, 0x83, 0x4b, 0x36, 0xaf, 0xff, 0xff, 0x69, 0x1b, 0x50, 0x50,
-    0x5d, 0xd6, 0x4c, 0x31, 0xb2, 0x2b, 0x62, 0xff, 0x81, 0x2f, 0xe4, 0x80, 0x10, 0xc9, 0x94, 0x2d, 0xfa, 0xc9, 0x86, 0xf6, 0x6d,
-    0x5f, 0xff, 0xfe, 0x4d, 0xa5, 0xbf, 0x59, 0x32, 0xb6, 0x6d, 0x5f, 0xff, 0xfe, 0x0e, 0x0c, 0x90, 0x94, 0x50, 0x5d, 0xd6, 0x4c,
-    0x7e, 0xc8, 0xad, 0x8b, 0xfe, 0x17, 0xfe, 0x48, 0x01, 0x5c, 0x99, 0x42, 0xdf, 0xac, 0x99, 0x15, 0x9b, 0x57, 0xff, 0xff, 0x93,
-    0x69, 0x6f, 0xd6, 0x4c, 0x1d, 0xd9, 0xb5, 0x7f, 0xff, 0xf8, 0x37, 0x32, 0x99, 0x5f, 0xf7, 0x37, 0x24, 0x35, 0x0f, 0x01, 0xb9,
-    0x94, 0xca, 0xff, 0x73, 0x70, 0x52, 0x35, 0x1c, 0x32, 0x89, 0x85, 0x7f, 0xb9, 0xb9, 0xc3, 0x4b, 0xe2, 0x9e, 0x4b, 0xff, 0x0e,
-    0xda, 0x82, 0xa7, 0x98, 0x1e, 0x2f, 0xee, 0x6e, 0x70, 0xd2, 0xf8, 0x53, 0xce, 0xff, 0xc3, 0x34, 0x55, 0x21, 0x3d, 0x2f, 0xdc,
-    0xdc, 0xe1, 0xa5, 0xf0, 0xaf, 0x8a, 0x3f, 0xf0, 0xe5, 0xa6, 0x0d, 0x7f, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1, 0x6f, 0x19, 0xff, 0xc5,
-    0xad, 0x23, 0x54, 0xd2, 0x6d, 0xfb, 0x9b, 0x9c, 0x34, 0xbe, 0x2c, 0xe2, 0x0f, 0xfe, 0x2d, 0x69, 0x1a, 0xa6, 0x93, 0x68, 0x5d,
-    0xee, 0x6e, 0x70, 0xd2, 0xf8, 0x61, 0xc2, 0xaf, 0xfc, 0x34, 0x32, 0x55, 0xbf, 0xee, 0x6e, 0x48, 0x6a, 0x1d, 0x14, 0x51, 0x03,
-    0x49, 0xb4, 0xaf, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1, 0xbf, 0x1b, 0xff, 0xc3, 0x83, 0xc5, 0xed, 0x4f, 0xfb, 0x9b, 0x9c, 0x34, 0xbe,
-    0x1e, 0xf1, 0x57, 0xfe, 0x4d, 0x56, 0x91, 0xab, 0xeb, 0x02, 0x80, 0x75, 0x66, 0x30, 0xbd, 0x67, 0x46, 0x7f, 0xfc, 0x99, 0x53,
-    0xd5, 0x3f, 0xd6, 0x5c, 0x59, 0x18, 0xc6, 0x17, 0xff, 0xff, 0x93, 0x28, 0xa2, 0x9f, 0x22, 0x08, 0x78, 0x93, 0x18, 0x5f, 0xff,
-    0xfe, 0x4d, 0x56, 0x91, 0xab, 0xeb, 0x26, 0x07, 0x56, 0x63, 0x0b, 0xff, 0xff, 0xc9, 0x95, 0x3d, 0x53, 0xfd, 0x62, 0x40, 0x91,
-    0x5e, 0x30, 0xbe, 0x28, 0x88, 0xff, 0xfc, 0x99, 0x45, 0x14, 0xf9, 0x10, 0x43, 0xc4, 0x98, 0xc2, 0xff, 0xff, 0xf2, 0x65, 0x91,
-    0x2e, 0xdf, 0x88, 0xc2, 0xd7, 0x37, 0x8f, 0x2d, 0x53, 0xd4, 0x20, 0x26, 0x87, 0xff, 0x95, 0x34, 0x7a, 0x6a, 0xfe, 0xc7, 0x37,
-    0x39, 0x18, 0xe1, 0x94, 0x4c, 0x2b, 0xf8, 0xa5, 0xcd, 0xce, 0x46, 0x1d, 0xb5, 0x05, 0x4f, 0x30, 0x3c, 0x5f, 0x85, 0x2e, 0x6e,
-    0x72, 0x30, 0xcd, 0x15, 0x48, 0x4f, 0x4b, 0xe1, 0x5b, 0x9b, 0x9c, 0x8c, 0x39, 0x69, 0x83, 0x5f, 0xc2, 0xd7, 0x37, 0x39, 0x18,
-    0xb5, 0xa4, 0x6a, 0x9a, 0x4d, 0xbe, 0x2c, 0x73, 0x73, 0x91, 0x8b, 0x5a, 0x46, 0xa9, 0xa4, 0xda, 0x17, 0x70, 0xc1, 0xcd, 0xce,
-    0x46, 0x1c, 0x99, 0x34, 0xa3, 0x7f, 0x0c, 0x5c, 0xdc, 0xe4, 0x61, 0xd1, 0x45, 0x10, 0x34, 0x9b, 0x4a, 0xf0, 0xdd, 0xcd, 0xce,
-    0x46, 0x1c, 0x1e, 0x2f, 0x6a, 0x7f, 0x87, 0xae, 0x6e, 0x72, 0x34, 0x0a, 0x1a, 0x2d, 0xfc, 0xab, 0x9b, 0x9c, 0x8d, 0x02, 0xe8,
-    0xa8, 0xaf, 0xe4, 0x0e, 0x6e, 0x72, 0x34, 0x90, 0x29, 0xfe, 0x69, 0xcd, 0xce, 0x46, 0x38, 0x99, 0x94, 0x3f, 0x88, 0x4e, 0x6e,
-    0x72, 0x33, 0x45, 0x4f, 0x54, 0xff, 0xc0, 0xf1, 0xcd, 0xce, 0x46, 0x61, 0xa2, 0xe4, 0x93, 0x32, 0x86, 0xb2, 0x60, 0x75, 0x64,
-    0x7d, 0x8b, 0xe2, 0x17, 0x00, 0xef, 0xe4, 0x80, 0x79, 0x93, 0x28, 0x5b, 0xf5, 0x93, 0x01, 0x25, 0x9b, 0x57, 0xff, 0xff, 0x96,
-    0xff, 0xee, 0xf0, 0x74, 0x26, 0x9a, 0xff, 0xce, 0x77, 0x8d, 0x92, 0x5f, 0x10, 0xb5, 0x87, 0xdf, 0xfa, 0x3f, 0xfa, 0xdd, 0xe4,
-    0x89, 0x49, 0xb4, 0xb7, 0xeb, 0x26, 0x02, 0x8b, 0x36, 0xaf, 0xff, 0xff, 0x4d, 0xaa, 0x2a, 0x7a, 0x6a, 0xeb, 0x02, 0x90, 0x24,
-    0xb2, 0x2b, 0x62, 0xff, 0x80, 0x93, 0xf2, 0x40, 0x00, 0xb9, 0x32, 0x85, 0xbf, 0x58, 0x14, 0x85, 0x96, 0x6d, 0x5f, 0xff, 0xfe,
-    0x97, 0xfe, 0x73, 0xbc, 0x6c, 0x27, 0x5f, 0x10, 0xb5, 0x87, 0xdf, 0xf9, 0x73, 0x28, 0x7f, 0x89, 0x5d, 0xe0, 0xe8, 0x27, 0xa3,
-    0xff, 0xb1, 0xde, 0x48, 0x94, 0x9b, 0x4b, 0x7e, 0xb0, 0x29, 0x07, 0xf6, 0x6d, 0x5f, 0xff, 0xfe, 0xa0, 0xbb, 0xfa, 0xc0, 0xa4,
-    0x05, 0xd6, 0x42, 0xa6, 0x2f, 0xf8, 0x59, 0xf8, 0x8d, 0x99, 0x32, 0x85, 0xbf, 0x58, 0x14, 0x80, 0x08, 0xb3, 0x6a, 0xff, 0xff,
-    0xf4, 0xcd, 0x18, 0xff, 0xbb, 0xc9, 0x09, 0xa4, 0xda, 0x5b, 0xf5, 0x81, 0x48, 0x47, 0xb3, 0x6a, 0xff, 0xff, 0xf5, 0x05, 0xc8,
-    0xaa, 0x80, 0xfb, 0x53, 0xac, 0x0a, 0x40, 0x77, 0x64, 0x56, 0xc5, 0xff, 0x03, 0x8f, 0xc4, 0x63, 0x39, 0x32, 0x85, 0xbf, 0x58,
-    0x14, 0x80, 0x43, 0x66, 0xd5, 0xff, 0xff, 0xe9, 0x7f, 0xe7, 0x3b, 0xc6, 0xc9, 0x2f, 0x88, 0x5a, 0xc3, 0xef, 0xfc, 0x35, 0x69,
-    0x09, 0xa2, 0x3f, 0xc4, 0xae, 0xf1, 0xb2, 0x4b, 0xe2, 0x16, 0xb0, 0xfb, 0xff, 0x26, 0xd2, 0xdf, 0xac, 0x17, 0x25, 0x66, 0xd5,
-    0xff, 0xff, 0xea, 0x0b, 0x91, 0x30, 0x8a, 0xbd, 0x60, 0xb8, 0x40, 0xb2, 0x2b, 0x62, 0xff, 0x9a, 0xfc, 0x46, 0x04, 0x19, 0x32,
-    0x85, 0xbf, 0x58, 0x2e, 0x23, 0xb3, 0x6a, 0xff, 0xff, 0xf4, 0x90, 0x28, 0x92, 0xa9, 0xfe, 0xef, 0x14, 0x12, 0x93, 0x69, 0x6f,
-    0xd6, 0x0b, 0x82, 0x35, 0x9b, 0x57, 0xff, 0xff, 0x83, 0x73, 0x29, 0x95, 0xff, 0x73, 0x72, 0x43, 0x50, 0xf0, 0x1b, 0x99, 0x4c,
-    0xaf, 0xf7, 0x37, 0x05, 0x23, 0x51, 0xc3, 0x28, 0x98, 0x57, 0xfb, 0x9b, 0x9c, 0x34, 0xbe, 0x29, 0xe4, 0xbf, 0xf0, 0xed, 0xa8,
-    0x2a, 0x79, 0x81, 0xe2, 0xfe, 0xe6, 0xe7, 0x0d, 0x2f, 0x85, 0x3c, 0xef, 0xfc, 0x33, 0x45, 0x52, 0x13, 0xd2, 0xfd, 0xcd, 0xce,
-    0x1a, 0x5f, 0x0a, 0xf8, 0xa3, 0xff, 0x0e, 0x5a, 0x60, 0xd7, 0xfb, 0x9b, 0x9c, 0x34, 0xbe, 0x16, 0xf1, 0x9f, 0xfc, 0x5a, 0xd2,
-    0x35, 0x4d, 0x26, 0xdf, 0xb9, 0xb9, 0xc3, 0x4b, 0xe2, 0xce, 0x20, 0xff, 0xe2, 0xd6, 0x91, 0xaa, 0x69, 0x36, 0x85, 0xde, 0xe6,
-    0xe7, 0x0d, 0x2f, 0x86, 0x1c, 0x2a, 0xff, 0xc3, 0x43, 0x25, 0x5b, 0xfe, 0xe6, 0xe4, 0x86, 0xa1, 0xd1, 0x45, 0x10, 0x34, 0x9b,
-    0x4a, 0xfb, 0x9b, 0x9c, 0x34, 0xbe, 0x1b, 0xf1, 0xbf, 0xfc, 0x38, 0x3c, 0x5e, 0xd4, 0xff, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1, 0xef,
-    0x15, 0x7f, 0xe4, 0xd5, 0x69, 0x1a, 0xbe, 0xb2, 0x60, 0x75, 0x66, 0x30, 0xbd, 0x83, 0x91, 0x57, 0xff, 0x93, 0x2a, 0x7a, 0xa7,
-    0xfa, 0xcb, 0x8b, 0x23, 0x18, 0xc2, 0xff, 0xff, 0xf2, 0x65, 0x14, 0x53, 0xe4, 0x41, 0x0f, 0x12, 0x63, 0x0b, 0xff, 0xff, 0xc9,
-    0x96, 0x44, 0xbb, 0x7e, 0x23, 0x01, 0x3b, 0x9b, 0xc7, 0x96, 0xaa, 0x67, 0xa4, 0x4d, 0x0f, 0xff, 0x2a, 0x68, 0xf4, 0xd5, 0xfd,
-    0x8e, 0x6e, 0x72, 0x31, 0xc3, 0x28, 0x98, 0x57, 0xf1, 0x4b, 0x9b, 0x9c, 0x8c, 0x3b, 0x6a, 0x0a, 0x9e, 0x60, 0x78, 0xbf, 0x0a,
-    0x5c, 0xdc, 0xe4, 0x61, 0x9a, 0x2a, 0x90, 0x9e, 0x97, 0xc2, 0xb7, 0x37, 0x39, 0x18, 0x72, 0xd3, 0x06, 0xbf, 0x85, 0xae, 0x6e,
-    0x72, 0x31, 0x6b, 0x48, 0xd5, 0x34, 0x9b, 0x7c, 0x58, 0xe6, 0xe7, 0x23, 0x16, 0xb4, 0x8d, 0x53, 0x49, 0xb4, 0x2e, 0xe1, 0x83,
-    0x9b, 0x9c, 0x8c, 0x39, 0x32, 0x69, 0x46, 0xfe, 0x18, 0xb9, 0xb9, 0xc8, 0xc3, 0xa2, 0x8a, 0x20, 0x69, 0x36, 0x95, 0xe1, 0xbb,
-    0x9b, 0x9c, 0x8c, 0x38, 0x3c, 0x5e, 0xd4, 0xff, 0x0f, 0x5c, 0xdc, 0xe4, 0x68, 0x14, 0x34, 0x5b, 0xf9, 0x57, 0x37, 0x39, 0x1a,
-    0x05, 0xd1, 0x51, 0x5f, 0xc8, 0x1c, 0xdc, 0xe4, 0x69, 0x20, 0x53, 0xfc, 0xd3, 0x9b, 0x9c, 0x8c, 0x71, 0x33, 0x28, 0x7f, 0x10,
-    0x9c, 0xdc, 0xe4, 0x66, 0x8a, 0x9e, 0xa9, 0xff, 0x81, 0xe3, 0x9b, 0x9c, 0x8c, 0x36, 0x32, 0x61, 0x54, 0x07, 0xcf, 0x4f, 0xdc,
-    0xdc, 0xe0, 0x95, 0x7f, 0xce, 0xfc, 0x46, 0x1b, 0x64, 0x7f, 0xfd, 0xde, 0x30, 0x75, 0x53, 0x29, 0x7f, 0xb1, 0xde, 0x32, 0x74,
-    0xa1, 0x95, 0x55, 0x32, 0x43, 0xf1, 0x87, 0x78, 0xc9, 0xd2, 0xed, 0x2c, 0xaf, 0xf2, 0x4e, 0xf6, 0x1d, 0x17, 0x22, 0x55, 0x51,
-    0xff, 0xc4, 0x6e, 0xf1, 0x93, 0xa4, 0xda, 0x65, 0x4f, 0xe7, 0x3b, 0xe5, 0x97, 0x88, 0xc1, 0xa7, 0x3b, 0xff, 0x0d, 0x8c, 0x98,
-    0x57, 0xfd, 0xcd, 0xce, 0x09, 0x57, 0xfc, 0x51, 0xf8, 0x8c, 0x3b, 0xcc, 0x7f, 0xf7, 0x78, 0xc1, 0xd1, 0xf6, 0xa2, 0x3c, 0x3e,
-    0xaf, 0xd8, 0xef, 0x61, 0xd3, 0xd4, 0x41, 0xa6, 0x15, 0xf8, 0xc3, 0xbd, 0x87, 0x4d, 0x18, 0x34, 0xa3, 0x4a, 0xfc, 0x93, 0xbc,
-    0x91, 0xd2, 0x88, 0xaa, 0xa7, 0xf3, 0x9d, 0xe3, 0x67, 0x2f, 0x85, 0x7c, 0x51, 0xff, 0xa2, 0x60, 0xb1, 0x9f, 0xe3, 0x2e, 0xf0,
-    0x60, 0x72, 0xf1, 0x18, 0x34, 0xe7, 0x57, 0xfe, 0x4d, 0xa6, 0x54, 0xfe, 0x28, 0x77, 0xcb, 0x2f, 0x11, 0x86, 0xdc, 0x51, 0xff,
-    0x86, 0x86, 0x93, 0x50, 0xca, 0x7e, 0xb1, 0xe7, 0x37, 0x39, 0x1a, 0xa9, 0x9e, 0x97, 0xd6, 0x0b, 0x82, 0x45, 0x91, 0x5b, 0x17,
-    0xfb, 0x06, 0xff, 0x11, 0x94, 0xc9, 0x94, 0x2d, 0xfa, 0xc1, 0x70, 0x0f, 0xac, 0xda, 0xbf, 0xff, 0xfd, 0x51, 0x7a, 0x4d, 0xff,
-    0x77, 0x92, 0x13, 0x4a, 0x1e, 0x54, 0x75, 0x24, 0x3e, 0x49, 0xde, 0x1d, 0x89, 0xa5, 0x0f, 0x2a, 0x3a, 0x92, 0x82, 0xef, 0x39,
-    0xde, 0x28, 0x13, 0x52, 0x50, 0x5c, 0x3a, 0x50, 0xf2, 0xbe, 0x32, 0xef, 0x24, 0x26, 0x94, 0x3c, 0xa8, 0xea, 0x48, 0x14, 0x4b,
-    0x89, 0xdd, 0xe4, 0x84, 0xd4, 0x54, 0x43, 0xfe, 0xef, 0x24, 0xba, 0x6d, 0x2e, 0x3a, 0x92, 0x1f, 0x24, 0xef, 0x05, 0x2b, 0x8f,
-    0xb4, 0x8f, 0xfc, 0xe7, 0x78, 0x5e, 0xb5, 0xff, 0x09, 0xa1, 0x37, 0xf9, 0x36, 0x96, 0xfd, 0x60, 0xbd, 0x76, 0x6d, 0x5f, 0xff,
-    0xfe, 0xab, 0x4b, 0x97, 0x1d, 0x69, 0x1a, 0x15, 0x82, 0xf2, 0x56, 0x42, 0xa6, 0x2f, 0xf6, 0x06, 0x9f, 0x88, 0xcc, 0x64, 0xca,
-    0x16, 0xfd, 0x60, 0xbc, 0x6a, 0xcd, 0xab, 0xff, 0xff, 0xd0, 0xff, 0xee, 0xf6, 0x2e, 0x8f, 0xfe, 0x49, 0xde, 0x49, 0x73, 0x06,
-    0x88, 0x50, 0xfe, 0x73, 0xbc, 0x92, 0xe5, 0xda, 0x63, 0xfc, 0x65, 0xde, 0x49, 0x74, 0x12, 0xff, 0x89, 0xdd, 0xe7, 0x2d, 0x7c,
-    0x53, 0xc9, 0x7f, 0xe4, 0xda, 0x5b, 0xf5, 0x81, 0x50, 0x6b, 0x66, 0xd5, 0xff, 0xff, 0xe8, 0x19, 0x48, 0x5f, 0xf5, 0x81, 0x50,
-    0x17, 0xd9, 0x15, 0xb1, 0x7f, 0xc1, 0x77, 0xe2, 0x33, 0x59, 0x32, 0x85, 0xbf, 0x58, 0x15, 0x00, 0x11, 0x66, 0xd5, 0xff, 0xff,
-    0xea, 0x7f, 0xf7, 0x78, 0x50, 0x26, 0x93, 0x69, 0x6f, 0xd6, 0x05, 0x46, 0xac, 0xda, 0xbf, 0xff, 0xfd, 0x15, 0x4c, 0x91, 0xfd,
-    0x60, 0x54, 0x47, 0x64, 0xa3, 0x17, 0xfc, 0x03, 0x3f, 0x88, 0xc4, 0x1c, 0x99, 0x42, 0xdf, 0xac, 0x0a, 0x80, 0x33, 0x66, 0xd5,
-    0xff, 0xff, 0xe6, 0x3f, 0xf1, 0x2b, 0xbc, 0x14, 0x84, 0xf1, 0xf6, 0x91, 0xff, 0x9c, 0xef, 0x0a, 0x02, 0x78, 0xfa, 0x5f, 0xf1,
-    0x23, 0xbc, 0x28, 0x25, 0x1f, 0x48, 0x2f, 0xff, 0x11, 0xbb, 0xc2, 0x82, 0x55, 0x5a, 0x42, 0x97, 0xf2, 0x4e, 0xf6, 0x12, 0x93,
-    0x69, 0x6f, 0xd6, 0x05, 0x40, 0x9f, 0x66, 0xd5, 0xff, 0xff, 0xed, 0x4d, 0x33, 0x29, 0xfa, 0xc0, 0xa8, 0x14, 0x6c, 0x8a, 0xd8,
-    0xbf, 0xe1, 0x17, 0xf1, 0x18, 0x01, 0x99, 0x32, 0x85, 0xbf, 0x58, 0x15, 0x88, 0xec, 0xda, 0xbf, 0xff, 0xfd, 0x40, 0xbb, 0xff,
-    0x77, 0x85, 0x02, 0x6a, 0x81, 0x7f, 0xfc, 0x93, 0xbc, 0x28, 0x13, 0x50, 0xff, 0xee, 0xf2, 0x4b, 0x93, 0x69, 0x6f, 0xd6, 0x05,
-    0x60, 0x35, 0xb3, 0x6a, 0xff, 0xff, 0xf0, 0x6e, 0x65, 0x32, 0xbf, 0xee, 0x6e, 0x48, 0x6a, 0x1e, 0x03, 0x73, 0x29, 0x95, 0xfe,
-    0xe6, 0xe0, 0xa4, 0x6a, 0x38, 0x65, 0x13, 0x0a, 0xff, 0x73, 0x73, 0x86, 0x97, 0xc5, 0x3c, 0x97, 0xfe, 0x1d, 0xb5, 0x05, 0x4f,
-    0x30, 0x3c, 0x5f, 0xdc, 0xdc, 0xe1, 0xa5, 0xf0, 0xa7, 0x9d, 0xff, 0x86, 0x68, 0xaa, 0x42, 0x7a, 0x5f, 0xb9, 0xb9, 0xc3, 0x4b,
-    0xe1, 0x5f, 0x14, 0x7f, 0xe1, 0xcb, 0x4c, 0x1a, 0xff, 0x73, 0x73, 0x86, 0x97, 0xc2, 0xde, 0x33, 0xff, 0x8b, 0x5a, 0x46, 0xa9,
-    0xa4, 0xdb, 0xf7, 0x37, 0x38, 0x69, 0x7c, 0x59, 0xc4, 0x1f, 0xfc, 0x5a, 0xd2, 0x35, 0x4d, 0x26, 0xd0, 0xbb, 0xdc, 0xdc, 0xe1,
-    0xa5, 0xf0, 0xc3, 0x85, 0x5f, 0xf8, 0x68, 0x64, 0xab, 0x7f, 0xdc, 0xdc, 0x90, 0xd4, 0x3a, 0x28, 0xa2, 0x06, 0x93, 0x69, 0x5f,
-    0x73, 0x73, 0x86, 0x97, 0xc3, 0x7e, 0x37, 0xff, 0x87, 0x07, 0x8b, 0xda, 0x9f, 0xf7, 0x37, 0x38, 0x69, 0x7c, 0x3d, 0xe2, 0xaf,
-    0xfc, 0x36, 0x32, 0x61, 0x54, 0x07, 0xcf, 0x4f, 0xdc, 0xdc, 0xe1, 0xa5, 0xe2, 0x30, 0x69, 0xce, 0xff, 0xc3, 0x63, 0x26, 0x15,
-    0xff, 0x73, 0x73, 0x86, 0x97, 0x88, 0xc3, 0x6e, 0x28, 0xff, 0xc9, 0xaa, 0xd2, 0x35, 0x7d, 0x60, 0xb8, 0x24, 0x59, 0x8c, 0x2f,
-    0x11, 0x82, 0x84, 0xb7, 0xff, 0x93, 0x2a, 0x7a, 0xa7, 0xfa, 0xcb, 0x8b, 0x23, 0x18, 0xc2, 0xf5, 0x87, 0x11, 0x27, 0xff, 0x93,
-    0x28, 0xa2, 0x9f, 0x22, 0x08, 0x78, 0x93, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xb2, 0x25, 0xdb, 0xf1, 0x28, 0x11, 0xb9, 0xbc, 0x79,
-    0x66, 0x94, 0x6a, 0x64, 0xd0, 0xff, 0xf2, 0xa6, 0x8f, 0x4d, 0x5f, 0xd8, 0xe6, 0xe7, 0x23, 0x1c, 0x32, 0x89, 0x85, 0x7f, 0x14,
-    0xb9, 0xb9, 0xc8, 0xc3, 0xb6, 0xa0, 0xa9, 0xe6, 0x07, 0x8b, 0xf0, 0xa5, 0xcd, 0xce, 0x46, 0x19, 0xa2, 0xa9, 0x09, 0xe9, 0x7c,
-    0x2b, 0x73, 0x73, 0x91, 0x87, 0x2d, 0x30, 0x6b, 0xf8, 0x5a, 0xe6, 0xe7, 0x23, 0x16, 0xb4, 0x8d, 0x53, 0x49, 0xb7, 0xc5, 0x8e,
-    0x6e, 0x72, 0x31, 0x6b, 0x48, 0xd5, 0x34, 0x9b, 0x42, 0xee, 0x18, 0x39, 0xb9, 0xc8, 0xc3, 0x93, 0x26, 0x94, 0x6f, 0xe1, 0x8b,
-    0x9b, 0x9c, 0x8c, 0x3a, 0x28, 0xa2, 0x06, 0x93, 0x69, 0x5e, 0x1b, 0xb9, 0xb9, 0xc8, 0xc3, 0x83, 0xc5, 0xed, 0x4f, 0xf0, 0xf5,
-    0xcd, 0xce, 0x46, 0x81, 0x43, 0x45, 0xbf, 0x95, 0x73, 0x73, 0x91, 0xa0, 0x5d, 0x15, 0x15, 0xfc, 0x81, 0xcd, 0xce, 0x46, 0x92,
-    0x05, 0x3f, 0xcd, 0x39, 0xb9, 0xc8, 0xc7, 0x13, 0x32, 0x87, 0xf1, 0x09, 0xcd, 0xce, 0x46, 0x68, 0xa9, 0xea, 0x9f, 0xf8, 0x1e,
-    0x39, 0xb9, 0xc8, 0xc3, 0x63, 0x26, 0x15, 0x40, 0x7c, 0xf4, 0xf1, 0x18, 0x34, 0x73, 0x73, 0x91, 0x86, 0xc6, 0x4c, 0x2b, 0xf8,
-    0x8c, 0x36, 0x73, 0x73, 0x91, 0xaa, 0x99, 0xe6, 0x14, 0x2c, 0x65, 0x3a, 0xc0, 0xac, 0x13, 0x2c, 0x85, 0x8c, 0x5f, 0x14, 0xf0,
-    0x0b, 0x7e, 0x23, 0x07, 0xf9, 0x32, 0x85, 0xbf, 0x58, 0x15, 0x80, 0xe2, 0xcd, 0xab, 0xff, 0xff, 0xd2, 0xff, 0xee, 0xf3, 0x84,
-    0xcb, 0xe2, 0x9e, 0x4b, 0xff, 0x26, 0xd2, 0xdf, 0xac, 0x0b, 0x00, 0xce, 0xcd, 0xab, 0xff, 0xff, 0xd3, 0x55, 0x33, 0xcc, 0x28,
-    0x58, 0xcd, 0x60, 0x58, 0x56, 0xc8, 0x58, 0xc5, 0xf0, 0xa7, 0x8e, 0xfe, 0x23, 0x04, 0x6c, 0x99, 0x42, 0xdf, 0xac, 0x0b, 0x11,
-    0xb3, 0x6a, 0xff, 0xff, 0xf4, 0xff, 0xfb, 0xbc, 0xe1, 0x32, 0xf8, 0x53, 0xce, 0xff, 0xc9, 0xb4, 0xb7, 0xeb, 0x02, 0xc0, 0x00,
-    0x4b, 0x36, 0xaf, 0xff, 0xff, 0x0f, 0x0f, 0x2e, 0x5c, 0xca, 0x1d, 0x60, 0x58, 0x08, 0x96, 0x4a, 0x31, 0x7f, 0xc6, 0xff, 0x11,
-    0x82, 0x46, 0x4c, 0xa1, 0x6f, 0xd6, 0x05, 0x80, 0x2e, 0xb3, 0x6a, 0xff, 0xff, 0xf3, 0x1f, 0xfd, 0xde, 0x0a, 0x44, 0xd2, 0x6d,
-    0x2d, 0xfa, 0xc0, 0xb0, 0x21, 0x59, 0xb5, 0x7f, 0xff, 0xf8, 0x78, 0x50, 0xf2, 0xe5, 0xcc, 0xa1, 0xac, 0x0b, 0x02, 0x25, 0x91,
-    0xf6, 0x2f, 0x85, 0x7c, 0x09, 0xbf, 0x11, 0x82, 0x56, 0x4c, 0xa1, 0x6f, 0xd6, 0x05, 0x80, 0x97, 0x66, 0xd5, 0xff, 0xff, 0xe9,
-    0xff, 0xf7, 0x79, 0xc2, 0x65, 0xf0, 0xa7, 0x9d, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x0c, 0x04, 0x76, 0x6d, 0x5f, 0xff, 0xfe, 0x1e,
-    0x07, 0xe8, 0x98, 0x45, 0x5e, 0xb0, 0x60, 0x75, 0x91, 0x5b, 0x17, 0xfc, 0x16, 0x7e, 0x23, 0x08, 0x99, 0x32, 0x85, 0xbf, 0x58,
-    0x30, 0x09, 0x6c, 0xda, 0xbf, 0xff, 0xfc, 0x9b, 0x4b, 0x7e, 0xb0, 0x60, 0x15, 0xd9, 0xb5, 0x7f, 0xff, 0xf8, 0x78, 0x1f, 0x97,
-    0x44, 0xc3, 0x7a, 0xc1, 0x80, 0x59, 0x64, 0x56, 0xc5, 0xff, 0x1f, 0xfc, 0x46, 0x03, 0x8c, 0x99, 0x42, 0xdf, 0xac, 0x18, 0x01,
-    0x45, 0x9b, 0x57, 0xff, 0xff, 0xa5, 0xff, 0xdd, 0xe7, 0x09, 0x97, 0xc5, 0x3c, 0x97, 0xfe, 0x5b, 0xff, 0x24, 0xef, 0x24, 0x26,
-    0x98, 0x69, 0x1a, 0xbf, 0xee, 0xf3, 0x96, 0xbe, 0x29, 0xe4, 0xbf, 0xf2, 0x6d, 0x2d, 0xfa, 0xc1, 0x80, 0x00, 0x0d, 0x9b, 0x57,
-    0xff, 0xff, 0x87, 0x81, 0xf9, 0x74, 0x4c, 0x35, 0x53, 0x35, 0x83, 0x01, 0x0e, 0xc8, 0xad, 0x8b, 0xfe, 0x17, 0x7e, 0x23, 0x01,
-    0xee, 0x4c, 0xa1, 0x6f, 0xd6, 0x0c, 0x00, 0x0e, 0xb3, 0x6a, 0xff, 0xff, 0xf4, 0xbf, 0xfb, 0xbc, 0xe1, 0x32, 0xf8, 0xa7, 0x92,
-    0xff, 0xcb, 0x7f, 0xe4, 0x9d, 0xe4, 0x84, 0xd2, 0x6d, 0x2d, 0xfa, 0xc1, 0x80, 0x21, 0x59, 0xb5, 0x7f, 0xff, 0xf8, 0x78, 0x1f,
-    0x90, 0x99, 0x10, 0x1b, 0xd6, 0x0c, 0x01, 0x12, 0xc8, 0xad, 0x8b, 0xfe, 0x1b, 0x7e, 0x23, 0x01, 0xfe, 0x4c, 0xa1, 0x6f, 0xd6,
-    0x0c, 0x01, 0x1a, 0xcd, 0xab, 0xff, 0xff, 0xd2, 0xff, 0xee, 0xf3, 0x84, 0xcb, 0xe2, 0x9e, 0x4b, 0xff, 0x26, 0xd2, 0xdf, 0xac,
-    0x18, 0x89, 0x6c, 0xda, 0xbf, 0xff, 0xfc, 0x3c, 0x0f, 0xc8, 0x4c, 0x88, 0x0d, 0x54, 0xcd, 0x60, 0xc4, 0x66, 0xc8, 0xad, 0x8b,
-    0xfe, 0x2a, 0xfc, 0x48, 0xcc, 0x99, 0x42, 0xdf, 0xac, 0x18, 0x84, 0xf6, 0x6d, 0x5f, 0xff, 0xfe, 0x97, 0xff, 0x77, 0x9c, 0x26,
-    0x5f, 0x14, 0xf2, 0x5f, 0xf9, 0x36, 0x96, 0xfd, 0x60, 0xc4, 0x16, 0xd9, 0xb5, 0x7f, 0xff, 0xf8, 0x78, 0x1f, 0xa4, 0x65, 0x13,
-    0x0a, 0xeb, 0x06, 0x21, 0x65, 0x91, 0x5b, 0x17, 0xfc, 0x32, 0xfc, 0x48, 0x13, 0x64, 0xca, 0x16, 0xfd, 0x60, 0xc4, 0x0a, 0x2c,
-    0xda, 0xbf, 0xff, 0xfd, 0x2f, 0xfe, 0xef, 0x38, 0x4c, 0xbe, 0x29, 0xe4, 0xbf, 0xf2, 0xdf, 0xf9, 0x27, 0x79, 0x21, 0x34, 0x9b,
-    0x4b, 0x7e, 0xb0, 0x62, 0x5a, 0xcd, 0xab, 0xff, 0xff, 0xc3, 0xc0, 0xfd, 0x36, 0xa0, 0xa9, 0x6d, 0x60, 0xc4, 0xbd, 0x91, 0x5b,
-    0x17, 0xfc, 0x39, 0xfc, 0x48, 0x28, 0xc9, 0x94, 0x2d, 0xfa, 0xc1, 0x89, 0x15, 0x9b, 0x57, 0xff, 0xff, 0xa7, 0xff, 0xdd, 0xe7,
-    0x09, 0x97, 0xc2, 0x9e, 0x77, 0xfe, 0x5b, 0xff, 0x39, 0xde, 0x48, 0x4d, 0x26, 0xd2, 0xdf, 0xac, 0x18, 0x80, 0x29, 0x66, 0xd5,
-    0xff, 0xff, 0xe1, 0xe0, 0x7e, 0xa3, 0x47, 0x88, 0x5a, 0x62, 0xb0, 0x62, 0x08, 0x36, 0x45, 0x6c, 0x5f, 0xf0, 0x1b, 0x7e, 0x24,
-    0x0a, 0xf2, 0x65, 0x0b, 0x7e, 0xb0, 0x62, 0x0f, 0xec, 0xda, 0xbf, 0xff, 0xfd, 0x2f, 0xfe, 0xef, 0x38, 0x4c, 0xbe, 0x29, 0xe4,
-    0xbf, 0xf2, 0x2f, 0xfc, 0x93, 0xbc, 0xe1, 0x32, 0xf8, 0xa7, 0x92, 0xff, 0xcb, 0x7f, 0xe7, 0x3b, 0xc9, 0x09, 0xa9, 0x25, 0xff,
-    0xbb, 0xce, 0x5a, 0xf8, 0xa7, 0x92, 0xff, 0xc8, 0x88, 0xbf, 0xe4, 0x9d, 0xe7, 0x2d, 0x7c, 0x53, 0xc9, 0x7f, 0xe4, 0xda, 0x5b,
-    0xf5, 0x86, 0x00, 0x22, 0xb3, 0x6a, 0xff, 0xff, 0xf0, 0xf0, 0x3f, 0x51, 0x54, 0xd1, 0x30, 0x3d, 0x58, 0x60, 0x02, 0x4b, 0x21,
-    0x63, 0x17, 0xc5, 0x3c, 0x12, 0xff, 0x12, 0x01, 0x46, 0x4c, 0xa1, 0x6f, 0xd6, 0x18, 0x01, 0xe5, 0x9b, 0x57, 0xff, 0xff, 0xa9,
-    0xff, 0xdd, 0xe1, 0x40, 0x9a, 0x47, 0xff, 0x9c, 0xef, 0x24, 0x4a, 0x97, 0xfe, 0x73, 0xbc, 0xe5, 0xaf, 0x8a, 0x79, 0x2f, 0xfc,
-    0x9b, 0x4b, 0x7e, 0xb0, 0x64, 0x47, 0x66, 0xd5, 0xff, 0xff, 0xe1, 0xe0, 0x7e, 0xab, 0x48, 0xd5, 0x34, 0x9d, 0x60, 0xc8, 0x02,
-    0xf6, 0x45, 0x6c, 0x5f, 0xf0, 0xeb, 0xf1, 0x20, 0x30, 0xc9, 0x94, 0x2d, 0xfa, 0xc1, 0x90, 0x85, 0x66, 0xd5, 0xff, 0xff, 0xe9,
-    0xff, 0xf7, 0x79, 0xc2, 0x65, 0xf0, 0xa7, 0x9d, 0xff, 0xab, 0xff, 0x9c, 0xef, 0x1b, 0x13, 0x2f, 0x8b, 0x38, 0x83, 0xff, 0x96,
-    0xff, 0xc6, 0x5d, 0xe4, 0x84, 0xd2, 0x6d, 0x2d, 0xfa, 0xc1, 0x90, 0x0c, 0x2c, 0xda, 0xbf, 0xff, 0xfc, 0x3c, 0x69, 0x1a, 0x2a,
-    0xfa, 0xc1, 0x90, 0x0c, 0xac, 0x8a, 0xd8, 0xbf, 0xe0, 0x47, 0xf8, 0x90, 0x0b, 0x72, 0x65, 0x0b, 0x7e, 0xb0, 0x64, 0x09, 0x16,
-    0x6d, 0x5f, 0xff, 0xfe, 0x4d, 0xa5, 0xbf, 0x58, 0x32, 0x09, 0x16, 0x6d, 0x5f, 0xff, 0xfe, 0x1e, 0x26, 0xd2, 0x23, 0x4d, 0x51,
-    0x5a, 0xc1, 0x90, 0x0f, 0x6c, 0x8a, 0xd8, 0xbf, 0xe0, 0xe3, 0xf1, 0x20, 0x00, 0xf9, 0x32, 0x85, 0xbf, 0x58, 0x32, 0x04, 0xeb,
-    0x36, 0xaf, 0xff, 0xff, 0x4f, 0xff, 0xbb, 0xc9, 0x09, 0xa9, 0x7f, 0xe7, 0x3b, 0xc6, 0xc9, 0x2f, 0x88, 0x5a, 0xc3, 0xef, 0xfc,
-    0x9b, 0x4b, 0x7e, 0xb0, 0x66, 0x8d, 0x9b, 0x57, 0xff, 0xff, 0x87, 0x89, 0xa0, 0x20, 0x20, 0x3e, 0x7a, 0x75, 0x83, 0x33, 0x16,
-    0x45, 0x6c, 0x5f, 0xf0, 0xaf, 0xf1, 0x20, 0x73, 0x93, 0x28, 0x5b, 0xf5, 0x83, 0x34, 0xac, 0xda, 0xbf, 0xff, 0xfc, 0x9b, 0x4b,
-    0x7e, 0xb0, 0x66, 0x01, 0xeb, 0x36, 0xaf, 0xff, 0xff, 0x0f, 0x08, 0x0a, 0x15, 0x40, 0x7c, 0xf4, 0xeb, 0x06, 0x60, 0x07, 0x59,
-    0x15, 0xb1, 0x7f, 0xc0, 0xa7, 0xf1, 0x20, 0x36, 0xc9, 0x94, 0x2d, 0xfa, 0xc1, 0x98, 0x3a, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0x6d,
-    0x2d, 0xfa, 0xc1, 0x98, 0x21, 0x59, 0xb5, 0x7f, 0xff, 0xf8, 0x78, 0x64, 0x5c, 0x63, 0xeb, 0x06, 0x60, 0x89, 0x64, 0x56, 0xc5,
-    0xff, 0x29, 0xf8, 0x90, 0x0c, 0xb2, 0x65, 0x0b, 0x7e, 0xb0, 0x66, 0x02, 0xeb, 0x36, 0xaf, 0xff, 0xff, 0x1e, 0x32, 0xaf, 0xfa,
-    0xdd, 0xe4, 0x89, 0x49, 0xb4, 0xb7, 0xeb, 0x06, 0x81, 0x5d, 0x9b, 0x57, 0xff, 0xff, 0x87, 0x82, 0xe3, 0xd1, 0x57, 0xd6, 0x0d,
-    0x02, 0xcb, 0x22, 0xb6, 0x2f, 0xf9, 0x6f, 0xc4, 0x85, 0x32, 0x65, 0x0b, 0x7e, 0xb0, 0x68, 0x04, 0xd6, 0x6d, 0x5f, 0xff, 0xfe,
-    0x9f, 0xfe, 0x73, 0xbc, 0x90, 0x4f, 0x35, 0xff, 0x89, 0x5d, 0xe4, 0x89, 0x49, 0xb4, 0xb7, 0xeb, 0x06, 0x81, 0xdd, 0x9b, 0x57,
-    0xff, 0xff, 0x87, 0x88, 0x1a, 0x4d, 0x43, 0xcb, 0x97, 0xac, 0x1a, 0x00, 0x7a, 0xc8, 0xad, 0x8b, 0xfe, 0x15, 0x7e, 0x24, 0x1f,
-    0xc9, 0x94, 0x2d, 0xfa, 0xc1, 0xa0, 0x87, 0x66, 0xd5, 0xff, 0xff, 0xe4, 0xda, 0x5b, 0xf5, 0x83, 0x40, 0x14, 0xd9, 0xb5, 0x7f,
-    0xff, 0xf8, 0x78, 0x81, 0xa4, 0xd4, 0x51, 0xaa, 0xd6, 0x0d, 0x00, 0x55, 0x64, 0x56, 0xc5, 0xff, 0x0c, 0x3f, 0x12, 0x25, 0x93,
-    0x28, 0x5b, 0xf5, 0x83, 0x40, 0x7b, 0x66, 0xd5, 0xff, 0xff, 0xe9, 0x7f, 0xf7, 0x79, 0xc2, 0x65, 0xf1, 0x4f, 0x25, 0xff, 0x93,
-    0x69, 0x6f, 0xd6, 0x0d, 0x00, 0x6f, 0x66, 0xd5, 0xff, 0xff, 0xe1, 0xe2, 0x06, 0x93, 0x51, 0x13, 0x03, 0xf5, 0x83, 0x40, 0x8d,
-    0x64, 0x56, 0xc5, 0xff, 0x16, 0x7e, 0x24, 0x17, 0x64, 0xca, 0x16, 0xfd, 0x60, 0xd0, 0x07, 0x36, 0x6d, 0x5f, 0xff, 0xfe, 0x47,
-    0xff, 0x9c, 0xef, 0x24, 0x4a, 0x4d, 0xa5, 0xbf, 0x58, 0x16, 0x80, 0x0d, 0x66, 0xd5, 0xff, 0xff, 0xe1, 0xe2, 0x06, 0x93, 0x51,
-    0x41, 0x72, 0x35, 0x81, 0x68, 0x2c, 0xb2, 0x3e, 0xc5, 0xf0, 0xc3, 0xa5, 0xf8, 0x90, 0x03, 0x39, 0x32, 0x85, 0xbf, 0x58, 0x16,
-    0x85, 0x56, 0x6d, 0x5f, 0xff, 0xfe, 0xaf, 0xfe, 0x73, 0xbc, 0x6c, 0x27, 0x5f, 0x0c, 0x38, 0x55, 0xff, 0x99, 0x57, 0xfe, 0x25,
-    0x77, 0x8d, 0x92, 0x5f, 0x0c, 0x38, 0x55, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x05, 0xa0, 0x06, 0xd9, 0xb5, 0x7f, 0xff, 0xfa, 0x8a,
-    0xa6, 0x89, 0x81, 0xee, 0xb0, 0x2d, 0x10, 0xec, 0x85, 0x8c, 0x5f, 0x14, 0xeb, 0x0e, 0xbf, 0x12, 0x01, 0xce, 0x4c, 0xa1, 0x6f,
-    0xd6, 0x05, 0xa0, 0x20, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0xdf, 0xf9, 0xce, 0xf1, 0xb0, 0x9d, 0x78, 0x8c, 0x36, 0xe2, 0x8f, 0xfd,
-    0x4f, 0xfc, 0x93, 0xbc, 0x28, 0x13, 0x4a, 0x7f, 0xe2, 0x57, 0x78, 0xd8, 0x4e, 0xbe, 0x1e, 0xf1, 0x57, 0xfe, 0x97, 0xfe, 0x32,
-    0xef, 0x38, 0x4c, 0xbe, 0x29, 0xe4, 0xbf, 0xf2, 0xc8, 0x7f, 0xc4, 0xee, 0xf2, 0x42, 0x6a, 0x1f, 0xf8, 0x91, 0xde, 0x48, 0x94,
-    0xb1, 0xa2, 0xe5, 0xff, 0x8c, 0x3b, 0xc9, 0x12, 0x91, 0x7f, 0xe7, 0x3b, 0xce, 0x5a, 0xf8, 0xa7, 0x92, 0xff, 0xd3, 0xff, 0xc6,
-    0x5d, 0xe7, 0x2d, 0x7c, 0x29, 0xe7, 0x7f, 0xe8, 0xff, 0xe2, 0x37, 0x78, 0xd9, 0x25, 0xe2, 0x30, 0x69, 0xce, 0xff, 0xd1, 0x30,
-    0x3e, 0x89, 0x44, 0x55, 0x6f, 0x24, 0xef, 0x24, 0x4a, 0x4d, 0xa5, 0xbf, 0x58, 0x05, 0x42, 0x15, 0x9b, 0x57, 0xff, 0xff, 0x87,
-    0x8a, 0x34, 0xc1, 0x54, 0x07, 0xcf, 0xac, 0x02, 0xa0, 0x97, 0x64, 0x56, 0xc5, 0xff, 0x05, 0xff, 0x89, 0x00, 0x0f, 0xc9, 0x94,
-    0x2d, 0xfa, 0xc0, 0x2a, 0x03, 0x7b, 0x36, 0xaf, 0xff, 0xff, 0x43, 0xff, 0x39, 0xde, 0xc0, 0x9e, 0x4d, 0xa5, 0xbf, 0x58, 0x05,
-    0x40, 0x05, 0xd9, 0xb5, 0x7f, 0xff, 0xf8, 0x78, 0x91, 0x94, 0x4c, 0x2b, 0xd6, 0x18, 0x8a, 0x2c, 0x8a, 0xd8, 0xbf, 0xe3, 0x9f,
-    0x89, 0x00, 0x53, 0x93, 0x28, 0x5b, 0xf5, 0x86, 0x20, 0x43, 0x66, 0xd5, 0xff, 0xff, 0xe5, 0xff, 0xf3, 0x9d, 0xe3, 0x61, 0x3a,
-    0xf8, 0x7b, 0xc5, 0x5f, 0xfa, 0x5f, 0xf9, 0x27, 0x79, 0xc2, 0x65, 0xf1, 0x4f, 0x25, 0xff, 0x96, 0xff, 0xce, 0x77, 0x92, 0x13,
-    0x51, 0x30, 0x3e, 0x89, 0x44, 0x55, 0x6f, 0x12, 0xbb, 0xc9, 0x12, 0x93, 0x69, 0x6f, 0xd6, 0x18, 0x9f, 0x66, 0xd5, 0xff, 0xff,
-    0xe1, 0xe1, 0x44, 0x55, 0x28, 0x5d, 0x5d, 0x61, 0x89, 0x5b, 0x22, 0xb6, 0x2f, 0xf9, 0x1f, 0xe2, 0x41, 0x13, 0x26, 0x50, 0xb7,
-    0xeb, 0x0c, 0x4b, 0x59, 0xb5, 0x7f, 0xff, 0xfa, 0x9f, 0xfd, 0xde, 0x36, 0x26, 0x5f, 0x0f, 0x78, 0xab, 0xff, 0x4f, 0xff, 0x24,
-    0xef, 0x38, 0x4c, 0xbe, 0x14, 0xf3, 0xbf, 0xf2, 0xbf, 0xf8, 0xcb, 0xbc, 0x6c, 0x4c, 0xbe, 0x1e, 0xf1, 0x57, 0xfe, 0x97, 0xfe,
-    0x27, 0x77, 0x9c, 0x26, 0x5f, 0x14, 0xf2, 0x5f, 0xf9, 0x6f, 0xfc, 0x50, 0xef, 0x24, 0x26, 0xa2, 0x60, 0x7d, 0x12, 0x88, 0xaa,
-    0xde, 0x73, 0xbc, 0x91, 0x29, 0x36, 0x96, 0xfd, 0x61, 0x88, 0x42, 0xb3, 0x6a, 0xff, 0xff, 0xf0, 0xf1, 0x36, 0xa0, 0xa9, 0x6e,
-    0xb0, 0xc4, 0x22, 0x59, 0x15, 0xb1, 0x7f, 0xc1, 0xb7, 0xe2, 0x40, 0x17, 0xe4, 0xca, 0x16, 0xfd, 0x61, 0x88, 0x25, 0xd9, 0xb5,
-    0x7f, 0xff, 0xf9, 0x7f, 0xfe, 0xef, 0x1b, 0x13, 0x2f, 0x87, 0xbc, 0x55, 0xff, 0xa7, 0xff, 0x92, 0x77, 0x9c, 0x26, 0x5f, 0x0a,
-    0x79, 0xdf, 0xf9, 0x6f, 0xfc, 0x65, 0xde, 0x48, 0x4d, 0x44, 0xc0, 0xfa, 0x25, 0x11, 0x55, 0xbc, 0xe7, 0x79, 0x22, 0x52, 0x6d,
-    0x2d, 0xfa, 0xc3, 0x22, 0x76, 0x6d, 0x5f, 0xff, 0xfe, 0x1e, 0x28, 0xd1, 0xe2, 0x16, 0x98, 0x56, 0xb0, 0xc8, 0x2f, 0xb2, 0x2b,
-    0x62, 0xff, 0x81, 0x9f, 0xe2, 0x40, 0x07, 0x32, 0x65, 0x0b, 0x7e, 0xb0, 0xc8, 0x59, 0x66, 0xd5, 0xff, 0xff, 0xe5, 0xff, 0xfb,
-    0xbc, 0x6c, 0x4c, 0xbe, 0x1e, 0xf1, 0x57, 0xfe, 0x97, 0xfe, 0x49, 0xde, 0x70, 0x99, 0x7c, 0x53, 0xc9, 0x7f, 0xe4, 0x5f, 0xf9,
-    0xce, 0xf3, 0x84, 0xcb, 0xe2, 0x9e, 0x4b, 0xff, 0x2d, 0xff, 0x8c, 0xbb, 0xc9, 0x09, 0xa8, 0x98, 0x1f, 0x44, 0xa2, 0x2a, 0xb7,
-    0x9c, 0xef, 0x24, 0x4a, 0x4d, 0xa5, 0xbf, 0x58, 0x64, 0x01, 0xdb, 0x36, 0xaf, 0xff, 0xff, 0x0f, 0x15, 0x69, 0x1a, 0xa6, 0x93,
-    0x6d, 0x61, 0x90, 0x07, 0xac, 0x8a, 0xd8, 0xbf, 0xe0, 0x6f, 0xf8, 0x90, 0x01, 0xfc, 0x99, 0x42, 0xdf, 0xac, 0x32, 0x10, 0xec,
-    0xda, 0xbf, 0xff, 0xfc, 0xbf, 0xff, 0x77, 0x8d, 0x89, 0x97, 0xc3, 0xde, 0x2a, 0xff, 0xd3, 0xff, 0xc9, 0x3b, 0xce, 0x13, 0x2f,
-    0x85, 0x3c, 0xef, 0xfd, 0x5f, 0xfc, 0x65, 0xde, 0x36, 0x26, 0x5f, 0x16, 0x71, 0x07, 0xff, 0x2d, 0xff, 0x89, 0xdd, 0xe4, 0x84,
-    0xd4, 0x4c, 0x0f, 0xa2, 0x51, 0x15, 0x5b, 0xce, 0x77, 0x92, 0x25, 0x26, 0xd2, 0xdf, 0xac, 0x33, 0x05, 0x56, 0x6d, 0x5f, 0xff,
-    0xfe, 0x1e, 0x28, 0x82, 0x6d, 0x69, 0x85, 0x2b, 0x0c, 0xc5, 0x16, 0x45, 0x6c, 0x5f, 0xf0, 0x2e, 0xfc, 0x48, 0x01, 0x06, 0x4c,
-    0xa1, 0x6f, 0xd6, 0x19, 0x81, 0x0d, 0x9b, 0x57, 0xff, 0xff, 0x94, 0xff, 0xee, 0xf1, 0xb1, 0x32, 0xf8, 0x57, 0xc5, 0x1f, 0xfa,
-    0x7f, 0xf9, 0x27, 0x79, 0xc2, 0x65, 0xf0, 0xa7, 0x9d, 0xff, 0xa9, 0xff, 0x8c, 0xbb, 0xce, 0x13, 0x2f, 0x85, 0x3c, 0xef, 0xfc,
-    0x9b, 0x4b, 0x7e, 0xb0, 0xcc, 0x3d, 0xb3, 0x6a, 0xff, 0xff, 0xf0, 0xf1, 0x23, 0x25, 0xc5, 0xe5, 0xba, 0xc3, 0x30, 0x32, 0xb2,
-    0x2b, 0x62, 0xff, 0x81, 0x8f, 0xe2, 0x43, 0x79, 0x32, 0x85, 0xbf, 0x58, 0x66, 0x06, 0x96, 0x6d, 0x5f, 0xff, 0xfe, 0x5f, 0xff,
-    0xbb, 0xc6, 0xc4, 0xcb, 0xe1, 0xef, 0x15, 0x7f, 0xe9, 0x1f, 0xff, 0x24, 0xef, 0x1b, 0x13, 0x2f, 0x8a, 0x79, 0x2f, 0xfc, 0xc7,
-    0xfe, 0x12, 0xbb, 0xc6, 0x04, 0xd5, 0x7f, 0xf1, 0x97, 0x78, 0xd8, 0x99, 0x7c, 0x59, 0xc4, 0x1f, 0xfc, 0xb7, 0xfe, 0x27, 0x77,
-    0x92, 0x13, 0x51, 0x30, 0x3e, 0x89, 0x44, 0x55, 0x6f, 0x39, 0xde, 0x48, 0x94, 0x9b, 0x4b, 0x7e, 0xb0, 0xcc, 0x04, 0xb6, 0x6d,
-    0x5f, 0xff, 0xfe, 0x1e, 0x20, 0x5d, 0x14, 0x8a, 0x2a, 0xad, 0x61, 0x98, 0x1f, 0x59, 0x15, 0xb1, 0x7f, 0xc3, 0x2f, 0xc4, 0xa4,
-    0xb2, 0x65, 0x0b, 0x7e, 0xb0, 0xcc, 0x0f, 0xec, 0xda, 0xbf, 0xff, 0xfc, 0x9b, 0x4b, 0x7e, 0xb0, 0xcc, 0x22, 0xd9, 0xb5, 0x7f,
-    0xff, 0xf8, 0x37, 0x32, 0x99, 0x5f, 0xf7, 0x37, 0x24, 0x35, 0x0f, 0x01, 0xb9, 0x94, 0xca, 0xff, 0x73, 0x70, 0x52, 0x35, 0x1c,
-    0x32, 0x89, 0x85, 0x7f, 0xb9, 0xb9, 0xc3, 0x4b, 0xe2, 0x9e, 0x4b, 0xff, 0x0e, 0xda, 0x82, 0xa7, 0x98, 0x1e, 0x2f, 0xee, 0x6e,
-    0x70, 0xd2, 0xf8, 0x53, 0xce, 0xff, 0xc3, 0x34, 0x55, 0x21, 0x3d, 0x2f, 0xdc, 0xdc, 0xe1, 0xa5, 0xf0, 0xaf, 0x8a, 0x3f, 0xf0,
-    0xe5, 0xa6, 0x0d, 0x7f, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1, 0x6f, 0x19, 0xff, 0xc5, 0xad, 0x23, 0x54, 0xd2, 0x6d, 0xfb, 0x9b, 0x9c,
-    0x34, 0xbe, 0x2c, 0xe2, 0x0f, 0xfe, 0x2d, 0x69, 0x1a, 0xa6, 0x93, 0x68, 0x5d, 0xee, 0x6e, 0x70, 0xd2, 0xf8, 0x61, 0xc2, 0xaf,
-    0xfc, 0x34, 0x32, 0x55, 0xbf, 0xee, 0x6e, 0x48, 0x6a, 0x1d, 0x14, 0x51, 0x03, 0x49, 0xb4, 0xaf, 0xb9, 0xb9, 0xc3, 0x4b, 0xe1,
-    0xbf, 0x1b, 0xff, 0xc3, 0x83, 0xc5, 0xed, 0x4f, 0xfb, 0x9b, 0x9c, 0x34, 0xbe, 0x1e, 0xf1, 0x57, 0xfe, 0x1b, 0x19, 0x30, 0xaa,
-    0x03, 0xe7, 0xa7, 0xee, 0x6e, 0x70, 0xd2, 0xf1, 0x18, 0x34, 0xe7, 0x7f, 0xe1, 0xb1, 0x93, 0x0a, 0xff, 0xb9, 0xb9, 0xc3, 0x4b,
-    0xc4, 0x61, 0xb7, 0x14, 0x7f, 0xe4, 0xd5, 0x69, 0x1a, 0xbe, 0xb0, 0x2b, 0x04, 0xcb, 0x31, 0x85, 0xe3, 0x27, 0x40, 0x3f, 0xff,
-    0xe4, 0xca, 0x9e, 0xa9, 0xfe, 0xb2, 0x11, 0x06, 0x31, 0x8c, 0x2f, 0xff, 0xff, 0x26, 0x51, 0x45, 0x3e, 0x44, 0x10, 0xf1, 0x26,
-    0x30, 0xbf, 0xff, 0xfc, 0x99, 0x64, 0x4b, 0xb7, 0xe7, 0x02, 0xc7, 0x37, 0x8f, 0x2c, 0x5c, 0x89, 0x82, 0xa6, 0x45, 0xc9, 0x26,
-    0x65, 0x02, 0x68, 0x7e, 0x54, 0xd1, 0xe9, 0xab, 0xfb, 0x1c, 0xdc, 0xe4, 0x63, 0x86, 0x51, 0x30, 0xaf, 0xe2, 0x97, 0x37, 0x39,
-    0x18, 0x76, 0xd4, 0x15, 0x3c, 0xc0, 0xf1, 0x7e, 0x14, 0xb9, 0xb9, 0xc8, 0xc3, 0x34, 0x55, 0x21, 0x3d, 0x2f, 0x85, 0x6e, 0x6e,
-    0x72, 0x30, 0xe5, 0xa6, 0x0d, 0x7f, 0x0b, 0x5c, 0xdc, 0xe4, 0x62, 0xd6, 0x91, 0xaa, 0x69, 0x36, 0xf8, 0xb1, 0xcd, 0xce, 0x46,
-    0x2d, 0x69, 0x1a, 0xa6, 0x93, 0x68, 0x5d, 0xc3, 0x07, 0x37, 0x39, 0x18, 0x72, 0x64, 0xd2, 0x8d, 0xfc, 0x31, 0x73, 0x73, 0x91,
-    0x87, 0x45, 0x14, 0x40, 0xd2, 0x6d, 0x2b, 0xc3, 0x77, 0x37, 0x39, 0x18, 0x70, 0x78, 0xbd, 0xa9, 0xfe, 0x1e, 0xb9, 0xb9, 0xc8,
-    0xd0, 0x28, 0x68, 0xb7, 0xf2, 0xae, 0x6e, 0x72, 0x34, 0x0b, 0xa2, 0xa2, 0xbf, 0x90, 0x39, 0xb9, 0xc8, 0xd2, 0x1f, 0x17, 0xa8,
-    0x65, 0x25, 0x3a, 0xc1, 0x5b, 0x9b, 0x9c, 0x8d, 0x24, 0x0a, 0x7f, 0x9a, 0x73, 0x73, 0x91, 0x8e, 0x26, 0x65, 0x0f, 0xe2, 0x13,
-    0x9b, 0x9c, 0x8c, 0xd1, 0x53, 0xd5, 0x3f, 0xf0, 0x3c, 0x73, 0x73, 0x91, 0x86, 0xc6, 0x4c, 0x2a, 0x80, 0xf9, 0xe9, 0xe2, 0x30,
-    0x68, 0xe6, 0xe7, 0x23, 0x0d, 0x8c, 0x98, 0x57, 0xf1, 0x18, 0x6c, 0xe6, 0xe7, 0x23, 0x28, 0x99, 0xe4, 0x23, 0xa4, 0x2d, 0x21,
-    0xd6, 0x16, 0xb9, 0xb9, 0xc8, 0xc4, 0x03, 0xcb, 0xa9, 0xfa, 0xc2, 0xe7, 0x37, 0x39, 0x19, 0x42, 0xe8, 0xa5, 0xfa, 0xc1, 0x93,
-    0x9b, 0x89, 0x91, 0x94, 0x2e, 0x65, 0x2f, 0xd6, 0x1a, 0x39, 0xb9, 0xc8, 0xc7, 0x11, 0x30, 0x58, 0xcf, 0xf7, 0x37, 0x38, 0x25,
-    0x5f, 0xf1, 0xbf, 0xc4, 0xa0, 0x5d, 0xa0, 0x69, 0x3f, 0xfb, 0xbc, 0xe3, 0x97, 0xc5, 0x3c, 0x97, 0xfe, 0xa1, 0xe2, 0x03, 0x50,
-    0x34, 0x9f, 0x92, 0x77, 0x9c, 0x72, 0xf8, 0xa7, 0x92, 0xff, 0xc8, 0xc8, 0x4f, 0x23, 0xfe, 0x73, 0xbc, 0x91, 0xd1, 0x79, 0x09,
-    0xe4, 0x7f, 0xc6, 0x5d, 0xe4, 0x8e, 0xa4, 0x3a, 0x6b, 0xfc, 0x4e, 0xef, 0x24, 0x75, 0x21, 0xd4, 0xda, 0x20, 0x7e, 0x28, 0x77,
-    0x92, 0x3a, 0x4d, 0xa6, 0x54, 0xfe, 0x36, 0xef, 0x96, 0x5e, 0x25, 0x06, 0x3c, 0x6f, 0xff, 0x17, 0x22, 0x60, 0xa9, 0x91, 0x72,
-    0x49, 0xd6, 0x19, 0x84, 0x8b, 0x22, 0xb6, 0x2f, 0xf0, 0x94, 0x01, 0xaf, 0xc4, 0xa5, 0xf2, 0x65, 0x0b, 0x7e, 0xb0, 0xcc, 0x07,
-    0xb6, 0x6d, 0x5f, 0xff, 0xfe, 0x87, 0xfe, 0x73, 0xbd, 0x84, 0xa8, 0x14, 0x34, 0x5b, 0xfd, 0xde, 0xc5, 0xd5, 0x32, 0x40, 0xd3,
-    0x1f, 0x92, 0x77, 0x8b, 0x16, 0xbf, 0xe7, 0x59, 0xfe, 0x60, 0xd1, 0x0a, 0xa6, 0x48, 0x1a, 0x63, 0x8c, 0xbb, 0xc9, 0x2e, 0xa9,
-    0x92, 0x06, 0x98, 0x05, 0xdf, 0x13, 0xbb, 0xc9, 0x2e, 0x8f, 0xfe, 0x25, 0x77, 0x92, 0x25, 0x49, 0x25, 0x53, 0xfe, 0x24, 0x77,
-    0x8d, 0x92, 0x5e, 0x25, 0x06, 0x3c, 0x6f, 0xff, 0x49, 0x13, 0x05, 0x8c, 0xfe, 0x36, 0xef, 0x39, 0x6b, 0xc4, 0xa0, 0xc7, 0x8d,
-    0xff, 0xe4, 0xca, 0x14, 0xfd, 0x61, 0xc0, 0x01, 0x56, 0x65, 0x57, 0xff, 0xf8, 0x94, 0x00, 0xbd, 0x22, 0xc5, 0x0d, 0x16, 0xfc,
-    0x17, 0x3b, 0xc2, 0xf5, 0xaf, 0xf8, 0x5f, 0x0b, 0xff, 0xd1, 0xff, 0xca, 0xbb, 0xc9, 0x2d, 0x7f, 0x00, 0xaf, 0xff, 0x87, 0xff,
-    0xf8, 0xfb, 0xbc, 0x92, 0xd7, 0xf0, 0x0a, 0xff, 0xf9, 0x36, 0x94, 0xfd, 0x61, 0xb0, 0x49, 0xb3, 0x2a, 0xbf, 0xff, 0xfc, 0x99,
-    0x42, 0x9f, 0xac, 0x36, 0x09, 0x36, 0x65, 0x57, 0xff, 0xf8, 0x94, 0xae, 0x50, 0xd1, 0x6f, 0xf0, 0x5c, 0xef, 0x0b, 0xd6, 0xbf,
-    0xe4, 0xa4, 0xbf, 0xc9, 0xb4, 0xa7, 0xeb, 0x0d, 0x80, 0x4b, 0x66, 0x55, 0x7f, 0xff, 0xf9, 0x32, 0x85, 0x3f, 0x58, 0x70, 0x01,
-    0x3b, 0x32, 0xab, 0xff, 0xfc, 0x4a, 0x1f, 0xe5, 0x0d, 0x16, 0xff, 0x05, 0xce, 0xf0, 0xbd, 0x6b, 0xfe, 0x4a, 0x4b, 0xfc, 0x9b,
-    0x4a, 0x7e, 0xb0, 0x2e, 0x06, 0x16, 0x65, 0x57, 0xff, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x1a, 0x80, 0x0d, 0x66, 0xd5, 0xff, 0xff,
-    0xe6, 0x0d, 0x10, 0x94, 0x6a, 0x6a, 0x6b, 0x0e, 0x41, 0xf5, 0x91, 0x5b, 0x17, 0xfc, 0x00, 0xaf, 0xc4, 0xa2, 0xec, 0x99, 0x42,
-    0xdf, 0xac, 0x39, 0x01, 0x8d, 0x9b, 0x57, 0xff, 0xff, 0x98, 0xff, 0xc4, 0x6e, 0xf1, 0x40, 0x4f, 0x43, 0xff, 0x12, 0xbb, 0xc2,
-    0x80, 0x9e, 0x92, 0x4a, 0xa7, 0xfc, 0x48, 0xef, 0x1b, 0x09, 0xd7, 0x89, 0x41, 0x8f, 0x1b, 0xff, 0xcc, 0x1a, 0x21, 0x54, 0xc9,
-    0x03, 0x4c, 0x73, 0x9d, 0xe4, 0x89, 0x49, 0xb4, 0xb7, 0xeb, 0x06, 0xa0, 0x12, 0xb3, 0x6a, 0xff, 0xff, 0xf1, 0xe6, 0xaa, 0x79,
-    0x83, 0x44, 0x3a, 0xc1, 0xa8, 0x55, 0x64, 0x56, 0xc5, 0xff, 0x03, 0x4f, 0xc4, 0xa0, 0x6f, 0x93, 0x28, 0x5b, 0xf5, 0x83, 0x50,
-    0x5b, 0x66, 0xd5, 0xff, 0xff, 0xe6, 0x3f, 0xfb, 0xbc, 0x50, 0x26, 0xa1, 0xff, 0x92, 0x77, 0x85, 0x02, 0x6a, 0x49, 0x2a, 0x9f,
-    0xf1, 0x23, 0xbc, 0x6c, 0x27, 0x5e, 0x25, 0x06, 0x3c, 0x6f, 0xff, 0x54, 0x85, 0x24, 0x3f, 0xb1, 0xde, 0xc2, 0x53, 0x06, 0x88,
-    0x7f, 0xce, 0x77, 0x92, 0x25, 0x2c, 0x64, 0xd1, 0x82, 0xbf, 0x89, 0x5d, 0xe4, 0x89, 0x49, 0xb4, 0xb7, 0xeb, 0x06, 0xa0, 0x51,
-    0x66, 0xd5, 0xff, 0xff, 0xe8, 0x1a, 0x4d, 0x46, 0xfa, 0xc1, 0xa8, 0x1b, 0x59, 0x15, 0xb1, 0x7f, 0xcd, 0xfe, 0x25, 0x00, 0xf6,
-    0x4c, 0xa1, 0x6f, 0xd6, 0x0d, 0x40, 0x33, 0x66, 0xd5, 0xff, 0xff, 0xe9, 0x24, 0xaa, 0x7f, 0xee, 0xf1, 0xb1, 0x32, 0xf1, 0x28,
-    0x31, 0xe3, 0x7f, 0xfa, 0x5f, 0xfd, 0xde, 0x72, 0xd7, 0xc5, 0x3c, 0x97, 0xfe, 0x4d, 0xa5, 0xbf, 0x58, 0x74, 0x2e, 0xb3, 0x6a,
-    0xff, 0xff, 0xf3, 0x06, 0x50, 0x34, 0x9a, 0x8d, 0xd6, 0x1d, 0x03, 0x8b, 0x22, 0xb6, 0x2f, 0xf5, 0x81, 0x27, 0xe2, 0x50, 0x39,
-    0xc9, 0x94, 0x2d, 0xfa, 0xc3, 0xa0, 0x0c, 0x59, 0xb5, 0x7f, 0xff, 0xfa, 0x49, 0x2a, 0x9f, 0xfb, 0xbc, 0x6c, 0x4c, 0xbc, 0x4a,
-    0x0c, 0x78, 0xdf, 0xfe, 0x97, 0xff, 0x77, 0x9c, 0xb5, 0xf1, 0x4f, 0x25, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x38, 0x02, 0x3b, 0x36,
-    0xaf, 0xff, 0xff, 0x4d, 0xa7, 0x95, 0x40, 0x7c, 0xf4, 0xeb, 0x1c, 0x03, 0xbb, 0x22, 0xb6, 0x2f, 0xf1, 0x80, 0xaf, 0xf1, 0x28,
-    0x09, 0xf2, 0x65, 0x0b, 0x7e, 0xb1, 0xc0, 0x12, 0xd9, 0xb5, 0x7f, 0xff, 0xfa, 0x49, 0x2a, 0x9f, 0xfb, 0xbc, 0x6c, 0x4c, 0xbc,
-    0x4a, 0x0c, 0x78, 0xdf, 0xfe, 0x87, 0xfe, 0x73, 0xbd, 0x84, 0xa8, 0x1a, 0x4d, 0x43, 0x26, 0x3e, 0x25, 0x77, 0x92, 0x25, 0x40,
-    0xd2, 0x68, 0x1a, 0x4f, 0xe2, 0x47, 0x79, 0x22, 0x53, 0xcb, 0x9e, 0x99, 0x0b, 0x4a, 0xf8, 0x8d, 0xde, 0x48, 0x95, 0x54, 0x50,
-    0x20, 0x53, 0xf2, 0x4e, 0xf2, 0x44, 0xa2, 0xe3, 0xd4, 0x28, 0x5c, 0xca, 0x04, 0x1c, 0x61, 0xde, 0x48, 0x94, 0x9b, 0x4b, 0x7e,
-    0xb1, 0xc0, 0x1a, 0x59, 0xb5, 0x7f, 0xff, 0xfa, 0x4a, 0xff, 0x58, 0x36, 0x04, 0x4b, 0x21, 0x63, 0x17, 0xc5, 0x3c, 0x0d, 0x3f,
-    0x12, 0x80, 0xaf, 0x26, 0x50, 0xb7, 0xeb, 0x06, 0xc0, 0x8d, 0x66, 0xd5, 0xff, 0xff, 0xe9, 0x7f, 0xf7, 0x79, 0xc2, 0x65, 0xf1,
-    0x4f, 0x25, 0xff, 0x9f, 0xff, 0x9c, 0xef, 0x02, 0x02, 0x52, 0x6d, 0x2d, 0xfa, 0xc5, 0xa0, 0x12, 0xb3, 0x6a, 0xff, 0xff, 0xf0,
-    0xec, 0x0b, 0x83, 0x41, 0x6f, 0xd6, 0x2d, 0x0a, 0xac, 0x85, 0x8c, 0x5f, 0x0a, 0x78, 0x1f, 0x7e, 0x25, 0x01, 0x6e, 0x4c, 0xa1,
-    0x6f, 0xd6, 0x2d, 0x05, 0xb6, 0x6d, 0x5f, 0xff, 0xfe, 0x9f, 0xff, 0x77, 0x9c, 0x26, 0x5f, 0x0a, 0x79, 0xdf, 0xf9, 0xff, 0xf9,
-    0xce, 0xf0, 0x20, 0x25, 0x26, 0xd2, 0xdf, 0xac, 0x5a, 0x10, 0x2c, 0xda, 0xbf, 0xff, 0xfc, 0xba, 0x88, 0x26, 0x64, 0xb9, 0x7d,
-    0x62, 0xd0, 0x45, 0xb2, 0x2b, 0x62, 0xff, 0x60, 0x44, 0xfc, 0x4a, 0x08, 0x99, 0x32, 0x85, 0xbf, 0x58, 0xb4, 0x01, 0x9b, 0x36,
-    0xaf, 0xff, 0xff, 0x4f, 0xff, 0xbb, 0xce, 0x13, 0x2f, 0x85, 0x3c, 0xef, 0xfd, 0x2f, 0xfc, 0xe7, 0x79, 0xc2, 0x65, 0xf1, 0x4f,
-    0x25, 0xff, 0xa4, 0x92, 0xa9, 0xff, 0x19, 0x77, 0x8d, 0x89, 0x97, 0x89, 0x41, 0x8f, 0x1b, 0xff, 0xc9, 0xb4, 0xb7, 0xeb, 0x06,
-    0xe0, 0x79, 0x66, 0xd5, 0xff, 0xff, 0xe8, 0x17, 0x45, 0x24, 0x95, 0xeb, 0x06, 0xe0, 0x7d, 0x64, 0x56, 0xc5, 0xff, 0x16, 0xfe,
-    0x25, 0x01, 0x96, 0x4c, 0xa1, 0x6f, 0xd6, 0x0d, 0xc0, 0xfe, 0xcd, 0xab, 0xff, 0xff, 0xd2, 0xff, 0xd8, 0xef, 0x1b, 0x09, 0xd7,
-    0xc5, 0x3c, 0x97, 0xfe, 0x92, 0x4a, 0xa7, 0xfc, 0x93, 0xbc, 0x6c, 0x4c, 0xbc, 0x4a, 0x0c, 0x78, 0xdf, 0xfe, 0x4d, 0xa5, 0xbf,
-    0x58, 0x37, 0x09, 0x36, 0x6d, 0x5f, 0xff, 0xfe, 0x2e, 0x4d, 0x15, 0x5a, 0x87, 0x58, 0x37, 0x05, 0x1b, 0x22, 0xb6, 0x2f, 0xf5,
-    0x80, 0x3b, 0xf8, 0x94, 0x06, 0xd9, 0x32, 0x85, 0xbf, 0x58, 0x7c, 0xbb, 0x36, 0xaf, 0xff, 0xff, 0x43, 0xff, 0x39, 0xde, 0xc0,
-    0x9e, 0x92, 0x4a, 0xa7, 0xfc, 0x93, 0xbc, 0x6c, 0x4c, 0xbc, 0x4a, 0x0c, 0x78, 0xdf, 0xfe, 0x42, 0x64, 0xab, 0x7f, 0x9c, 0xef,
-    0x24, 0x26, 0xa0, 0x69, 0x35, 0x0c, 0xa7, 0xfb, 0xbc, 0xe5, 0xaf, 0x8a, 0x79, 0x2f, 0xfd, 0x2f, 0xfc, 0x93, 0xbc, 0xe5, 0xaf,
-    0x8a, 0x79, 0x2f, 0xfd, 0x3f, 0xfc, 0xe7, 0x79, 0xcb, 0x5f, 0x0a, 0x79, 0xdf, 0xfa, 0x06, 0x93, 0x50, 0xc9, 0x62, 0xde, 0x25,
-    0x77, 0x92, 0x25, 0x2c, 0x97, 0xfc, 0x48, 0xef, 0x1b, 0x24, 0xbc, 0x46, 0x0d, 0x39, 0xdf, 0xf9, 0x36, 0x96, 0xfd, 0x60, 0x04,
-    0x80, 0x09, 0xb3, 0x6a, 0xff, 0xff, 0xf3, 0x0d, 0x17, 0x17, 0x44, 0xc3, 0x75, 0x80, 0x12, 0x09, 0x56, 0x45, 0x6c, 0x5f, 0xf0,
-    0x8b, 0xf8, 0x94, 0x07, 0x39, 0x32, 0x85, 0xbf, 0x58, 0x01, 0x20, 0x0f, 0x59, 0xb5, 0x7f, 0xff, 0xfa, 0x49, 0x2a, 0x9f, 0xfb,
-    0xbc, 0x6c, 0x4c, 0xbc, 0x4a, 0x0c, 0x78, 0xdf, 0xfe, 0x60, 0xbf, 0xfc, 0xe7, 0x79, 0x22, 0x52, 0x6d, 0x2d, 0xfa, 0xc0, 0xc8,
-    0x44, 0xb3, 0x6a, 0xff, 0xff, 0xf2, 0xea, 0x20, 0x99, 0x92, 0xe4, 0x7a, 0xc0, 0xc8, 0x10, 0xac, 0x8a, 0xd8, 0xbf, 0xe0, 0x00,
-    0x5f, 0x89, 0x40, 0x7d, 0x93, 0x28, 0x5b, 0xf5, 0x81, 0x90, 0x21, 0xd9, 0xb5, 0x7f, 0xff, 0xf9, 0x7f, 0xfc, 0xe7, 0x78, 0xd8,
-    0x4e, 0xbe, 0x1e, 0xf1, 0x57, 0xfe, 0x5d, 0x13, 0x0d, 0x53, 0xf1, 0x2b, 0xbc, 0x90, 0x4f, 0x4f, 0xff, 0xbb, 0xce, 0x5a, 0xf8,
-    0x53, 0xce, 0xff, 0xc9, 0xb4, 0xb7, 0xeb, 0x03, 0x30, 0x6b, 0x66, 0xd5, 0xff, 0xff, 0xe6, 0x0b, 0xa0, 0x64, 0xd1, 0x85, 0x75,
-    0x81, 0x98, 0x77, 0x64, 0x56, 0xc5, 0xff, 0x10, 0x7f, 0x39, 0x79, 0x32, 0x85, 0xbf, 0x58, 0x19, 0x8b, 0x6c, 0xda, 0xbf, 0xff,
-    0xfd, 0x24, 0x95, 0x4f, 0xfd, 0xde, 0x36, 0x26, 0x5e, 0x25, 0x06, 0x3c, 0x6f, 0xff, 0x4b, 0xff, 0x5b, 0xbc, 0x54, 0x4a, 0x92,
-    0x5f, 0xf6, 0x3b, 0xc5, 0x44, 0xa8, 0xff, 0xe7, 0x3b, 0xc9, 0x12, 0x87, 0xff, 0xf8, 0x95, 0xde, 0x48, 0x94, 0x9b, 0x4b, 0x7e,
-    0xb0, 0x33, 0x10, 0x6c, 0xda, 0xbf, 0xff, 0xfc, 0x1b, 0x99, 0x4c, 0xaf, 0xfb, 0x9b, 0x92, 0x1a, 0x87, 0x80, 0xdc, 0xca, 0x65,
-    0x7f, 0xb9, 0xb8, 0x29, 0x1a, 0x8e, 0x19, 0x44, 0xc2, 0xbf, 0xdc, 0xdc, 0xe1, 0xa5, 0xf1, 0x4f, 0x25, 0xff, 0x87, 0x6d, 0x41,
-    0x53, 0xcc, 0x0f, 0x17, 0xf7, 0x37, 0x38, 0x69, 0x7c, 0x29, 0xe7, 0x7f, 0xe1, 0x9a, 0x2a, 0x90, 0x9e, 0x97, 0xee, 0x6e, 0x70,
-    0xd2, 0xf8, 0x57, 0xc5, 0x1f, 0xf8, 0x72, 0xd3, 0x06, 0xbf, 0xdc, 0xdc, 0xe1, 0xa5, 0xf0, 0xb7, 0x8c, 0xff, 0xe2, 0xd6, 0x91,
-    0xaa, 0x69, 0x36, 0xfd, 0xcd, 0xce, 0x1a, 0x5f, 0x16, 0x71, 0x07, 0xff, 0x16, 0xb4, 0x8d, 0x53, 0x49, 0xb4, 0x2e, 0xf7, 0x37,
-    0x38, 0x69, 0x7c, 0x30, 0xe1, 0x57, 0xfe, 0x1a, 0x19, 0x2a, 0xdf, 0xf7, 0x37, 0x24, 0x35, 0x0e, 0x8a, 0x28, 0x81, 0xa4, 0xda,
-    0x57, 0xdc, 0xdc, 0xe1, 0xa5, 0xf0, 0xdf, 0x8d, 0xff, 0xe1, 0xc1, 0xe2, 0xf6, 0xa7, 0xfd, 0xcd, 0xce, 0x1a, 0x5f, 0x0f, 0x78,
-    0xab, 0xff, 0x0d, 0x8c, 0x98, 0x55, 0x01, 0xf3, 0xd3, 0xf7, 0x37, 0x38, 0x69, 0x78, 0x8c, 0x1a, 0x73, 0xbf, 0xf0, 0xd8, 0xc9,
-    0x85, 0x7f, 0xdc, 0xdc, 0xe1, 0xa5, 0xe2, 0x30, 0xdb, 0x8a, 0x3f, 0xf2, 0x6a, 0xb4, 0x8d, 0x5f, 0x58, 0x66, 0x12, 0x2c, 0xc6,
-    0x17, 0x8d, 0x84, 0x65, 0x80, 0x47, 0xff, 0xc9, 0x95, 0x3d, 0x53, 0xfd, 0x64, 0x22, 0x0c, 0x63, 0x18, 0x5f, 0x05, 0xd1, 0x9f,
-    0xff, 0x26, 0x51, 0x45, 0x3e, 0x44, 0x10, 0xf1, 0x26, 0x30, 0xbf, 0xff, 0xfc, 0x99, 0x64, 0x4b, 0xb7, 0xe7, 0x11, 0xb9, 0xbc,
-    0x79, 0x67, 0x8f, 0x34, 0xc2, 0xa4, 0xd0, 0xff, 0xe5, 0x4d, 0x1e, 0x9a, 0xbf, 0xb1, 0xcd, 0xce, 0x46, 0x38, 0x65, 0x13, 0x0a,
-    0xfe, 0x29, 0x73, 0x73, 0x91, 0x87, 0x6d, 0x41, 0x53, 0xcc, 0x0f, 0x17, 0xe1, 0x4b, 0x9b, 0x9c, 0x8c, 0x33, 0x45, 0x52, 0x13,
-    0xd2, 0xf8, 0x56, 0xe6, 0xe7, 0x23, 0x0e, 0x5a, 0x60, 0xd7, 0xf0, 0xb5, 0xcd, 0xce, 0x46, 0x2d, 0x69, 0x1a, 0xa6, 0x93, 0x6f,
-    0x8b, 0x1c, 0xdc, 0xe4, 0x62, 0xd6, 0x91, 0xaa, 0x69, 0x36, 0x85, 0xdc, 0x30, 0x73, 0x73, 0x91, 0x87, 0x26, 0x4d, 0x28, 0xdf,
-    0xc3, 0x17, 0x37, 0x39, 0x18, 0x7e, 0x2e, 0x44, 0xc2, 0x88, 0x83, 0x86, 0xe3, 0x01, 0x19, 0xcd, 0xce, 0x46, 0x1f, 0xb5, 0x32,
-    0x22, 0x16, 0xa8, 0x3d, 0x8c, 0x08, 0xae, 0x6e, 0x72, 0x32, 0x87, 0x8f, 0x34, 0xc2, 0xbe, 0x30, 0x09, 0xee, 0x6e, 0x72, 0x30,
-    0xe8, 0xa2, 0x88, 0x1a, 0x4d, 0xa5, 0x78, 0x6e, 0xe6, 0xe7, 0x23, 0x0e, 0x0f, 0x17, 0xb5, 0x3f, 0xc3, 0xd7, 0x37, 0x39, 0x1a,
-    0x05, 0x0d, 0x16, 0xfe, 0x55, 0xcd, 0xce, 0x46, 0x81, 0x74, 0x54, 0x57, 0xf2, 0x07, 0x37, 0x39, 0x1a, 0x43, 0xe2, 0xf5, 0x0c,
-    0xa4, 0xa7, 0x58, 0x2b, 0x73, 0x73, 0x91, 0xa4, 0x81, 0x4f, 0xf3, 0x4e, 0x6e, 0x72, 0x31, 0xc4, 0xcc, 0xa1, 0xfc, 0x42, 0x73,
-    0x73, 0x91, 0x9a, 0x2a, 0x7a, 0xa7, 0xfe, 0x07, 0x8e, 0x6e, 0x72, 0x31, 0xc1, 0xd1, 0xf6, 0x9e, 0x55, 0xa9, 0xeb, 0x08, 0x2e,
-    0x6e, 0x72, 0x31, 0xc3, 0xd0, 0x20, 0x6a, 0xbe, 0xb0, 0x8a, 0xe6, 0xe7, 0x23, 0x3c, 0x79, 0xa6, 0x15, 0x4d, 0xa8, 0x7b, 0x9b,
-    0x9c, 0x12, 0xaf, 0xf8, 0xcf, 0xe7, 0x03, 0x4d, 0x03, 0x24, 0x24, 0x27, 0x98, 0x2b, 0xf7, 0x78, 0xc1, 0xd4, 0x07, 0xcf, 0x31,
-    0xfd, 0x8e, 0xf1, 0x83, 0xa9, 0xff, 0xe4, 0x9d, 0xe7, 0x1c, 0xbe, 0x14, 0xf3, 0xbf, 0xf2, 0x6d, 0x32, 0xa7, 0xf1, 0x97, 0x7c,
-    0xb2, 0xf3, 0x80, 0xab, 0x8c, 0xff, 0xe7, 0x8f, 0x34, 0xc2, 0xbe, 0xb0, 0x33, 0x04, 0x9b, 0x22, 0xb6, 0x2f, 0xf1, 0x18, 0xa3,
-    0xf3, 0x80, 0xc7, 0x26, 0x50, 0xb7, 0xeb, 0x03, 0x30, 0x85, 0x66, 0xd5, 0xff, 0xff, 0xe7, 0xa5, 0xff, 0x39, 0xde, 0x36, 0x13,
-    0xaf, 0x18, 0x04, 0xfe, 0x33, 0xff, 0xa5, 0xff, 0x89, 0x5d, 0xe3, 0x64, 0x97, 0xc4, 0x2d, 0x61, 0xf7, 0xfe, 0x7a, 0x69, 0x7f,
-    0xdd, 0xe3, 0x6b, 0x5e, 0x70, 0x15, 0x71, 0x9f, 0xfd, 0x02, 0x12, 0xbf, 0xe2, 0x37, 0x78, 0x10, 0x12, 0x8b, 0xbf, 0xf1, 0x23,
-    0xbc, 0x91, 0x29, 0x36, 0x96, 0xfd, 0x60, 0x6a, 0x00, 0x8d, 0x9b, 0x57, 0xff, 0xff, 0xa0, 0x3e, 0xd4, 0x08, 0x13, 0x6a, 0x15,
-    0x81, 0xa8, 0x0e, 0x6c, 0x85, 0x8c, 0x5f, 0x0a, 0x7a, 0x3f, 0x9c, 0x0d, 0xb2, 0x65, 0x0b, 0x7e, 0xb0, 0x35, 0x00, 0x82, 0xcd,
-    0xab, 0xff, 0xff, 0xd3, 0xff, 0xee, 0xf3, 0x84, 0xcb, 0xe1, 0x4f, 0x3b, 0xff, 0x26, 0xd2, 0xdf, 0xac, 0xf0, 0xe6, 0xcd, 0xab,
-    0xff, 0xff, 0xc1, 0xb9, 0x94, 0xca, 0xff, 0xb9, 0xb9, 0x21, 0xa8, 0x78, 0x0d, 0xcc, 0xa6, 0x57, 0xfb, 0x9b, 0x82, 0x91, 0xa8,
-    0xe1, 0x94, 0x4c, 0x2b, 0xfd, 0xcd, 0xce, 0x1a, 0x5f, 0x14, 0xf2, 0x5f, 0xf8, 0x76, 0xd4, 0x15, 0x3c, 0xc0, 0xf1, 0x7f, 0x73,
-    0x73, 0x86, 0x97, 0xc2, 0x9e, 0x77, 0xfe, 0x19, 0xa2, 0xa9, 0x09, 0xe9, 0x7e, 0xe6, 0xe7, 0x0d, 0x2f, 0x85, 0x7c, 0x51, 0xff,
-    0x87, 0x2d, 0x30, 0x6b, 0xfd, 0xcd, 0xce, 0x1a, 0x5f, 0x0b, 0x78, 0xcf, 0xfe, 0x2d, 0x69, 0x1a, 0xa6, 0x93, 0x6f, 0xdc, 0xdc,
-    0xe1, 0xa5, 0xf1, 0x67, 0x10, 0x7f, 0xf1, 0x6b, 0x48, 0xd5, 0x34, 0x9b, 0x42, 0xef, 0x73, 0x73, 0x86, 0x97, 0xc3, 0x0e, 0x15,
-    0x7f, 0xe1, 0xa1, 0x92, 0xad, 0xff, 0x73, 0x72, 0x43, 0x50, 0xe8, 0xa2, 0x88, 0x1a, 0x4d, 0xa5, 0x7d, 0xcd, 0xce, 0x1a, 0x5f,
-    0x0d, 0xf8, 0xdf, 0xfe, 0x1c, 0x1e, 0x2f, 0x6a, 0x7f, 0xdc, 0xdc, 0xe1, 0xa5, 0xf0, 0xf7, 0x8a, 0xbf, 0xf1, 0xc2, 0x82, 0xf5,
-    0x5b, 0xfd, 0xcd, 0xc6, 0x46, 0xa3, 0x83, 0xe3, 0xaa, 0xff, 0xb9, 0xb9, 0xc3, 0x4b, 0xd6, 0x10, 0x79, 0x2f, 0xfc, 0x70, 0x81,
-    0x51, 0xd4, 0xbf, 0xb9, 0xb8, 0xd8, 0xd2, 0xf5, 0x84, 0x5e, 0x16, 0x7f, 0xe4, 0xd5, 0x69, 0x1a, 0xbe, 0xb0, 0x33, 0x04, 0x9b,
-    0x31, 0x85, 0xe2, 0x31, 0x06, 0x1a, 0x7f, 0xf9, 0x32, 0xa7, 0xaa, 0x7f, 0xac, 0x84, 0x1e, 0xc6, 0x31, 0x85, 0xf0, 0x34, 0x8a,
-    0x7f, 0xfc, 0x99, 0x45, 0x14, 0xf9, 0x10, 0x43, 0xc4, 0x98, 0xc2, 0xff, 0xff, 0xf2, 0x6a, 0xb4, 0x8d, 0x5f, 0x59, 0xe2, 0xdb,
-    0x31, 0x85, 0xf1, 0xf9, 0x2f, 0xff, 0x26, 0x54, 0xf5, 0x4f, 0xf5, 0x98, 0x19, 0x8c, 0x63, 0x0b, 0xff, 0xff, 0xc9, 0x94, 0x51,
-    0x4f, 0x91, 0x04, 0x3c, 0x49, 0x8c, 0x2f, 0xff, 0xff, 0x40, 0xc9, 0x0f, 0xeb, 0x3c, 0x04, 0xd6, 0x63, 0x12, 0x65, 0x91, 0x2e,
-    0xdf, 0x9c, 0x01, 0xe7, 0x37, 0x8f, 0x2d, 0x31, 0x01, 0xa8, 0x2a, 0x65, 0x32, 0x6a, 0x7f, 0xc9, 0xaa, 0xd2, 0x35, 0x7d, 0x67,
-    0x82, 0x0d, 0x98, 0xc2, 0xff, 0xff, 0xf2, 0x65, 0x4f, 0x54, 0xff, 0x59, 0x81, 0x98, 0xc6, 0x30, 0xbc, 0x96, 0x11, 0xff, 0xfc,
-    0x99, 0x45, 0x14, 0xf9, 0x10, 0x43, 0xc4, 0x98, 0xc2, 0xff, 0xff, 0xf2, 0x02, 0x85, 0x52, 0x4c, 0xca, 0x1a, 0xd2, 0x04, 0x08,
-    0xc6, 0x31, 0x2a, 0xd2, 0x86, 0x87, 0x87, 0x9a, 0x9d, 0x69, 0x03, 0xd8, 0xc6, 0x31, 0x51, 0xa8, 0x19, 0x30, 0x57, 0xad, 0x20,
-    0x16, 0x46, 0x31, 0x8a, 0xa8, 0xa0, 0x40, 0xa7, 0xad, 0x20, 0x16, 0xc6, 0x31, 0x8a, 0xa8, 0xa0, 0x40, 0xa0, 0x2e, 0xeb, 0x48,
-    0x10, 0x63, 0x18, 0xc4, 0xd2, 0x8d, 0x4c, 0x84, 0xc9, 0xa5, 0x2b, 0x48, 0x12, 0x63, 0x18, 0xc4, 0x80, 0xa1, 0x52, 0xe8, 0xa8,
-    0xaa, 0x55, 0xa4, 0x03, 0x48, 0xc6, 0x31, 0x1f, 0x51, 0x51, 0xd4, 0x4c, 0x22, 0xad, 0x69, 0x02, 0x5c, 0x63, 0x18, 0xa8, 0xd3,
-    0x05, 0x4a, 0x1a, 0x50, 0x5f, 0x5a, 0x40, 0x9d, 0x18, 0xc6, 0x29, 0xb4, 0xa1, 0x93, 0x2a, 0x96, 0x2f, 0x5a, 0x40, 0xa3, 0x18,
-    0xc6, 0x25, 0xcf, 0x51, 0x5f, 0xac, 0x8b, 0x18, 0xc6, 0x29, 0x1e, 0xaa, 0x03, 0xed, 0x2a, 0xa5, 0x64, 0x44, 0xa3, 0x18, 0xc5,
-    0x23, 0xd5, 0x40, 0x7f, 0xd6, 0x44, 0x74, 0x63, 0x18, 0xb4, 0xc1, 0x51, 0xd1, 0x01, 0x5e, 0xb4, 0xce, 0x8c, 0x63, 0x12, 0x65,
-    0x91, 0x2e, 0xdf, 0x9c, 0x02, 0x17, 0x37, 0x8f, 0x2c, 0x40, 0x6a, 0x0a, 0x99, 0x4c, 0x9a, 0x9f, 0xf9, 0x35, 0x5a, 0x46, 0xaf,
-    0xac, 0xf0, 0x41, 0xb3, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xa9, 0xea, 0x9f, 0xe4, 0x40, 0x33, 0xc4, 0x78, 0xc2, 0xf2, 0x58, 0x47,
-    0xff, 0xf2, 0x65, 0x14, 0x53, 0xe4, 0x41, 0x0f, 0x12, 0x63, 0x0b, 0xff, 0xff, 0xc9, 0x96, 0x44, 0xbb, 0x7e, 0x70, 0x0a, 0x5c,
-    0xde, 0x3c, 0xb4, 0x90, 0x1f, 0x6a, 0x04, 0x04, 0xd0, 0xff, 0xd0, 0x4d, 0x0f, 0xd6, 0x78, 0x20, 0xd9, 0x15, 0xb1, 0x7f, 0xc0,
-    0x5f, 0xf9, 0xc0, 0x79, 0x93, 0x28, 0x5b, 0xf5, 0x9e, 0x08, 0x56, 0x6d, 0x5f, 0xff, 0xfe, 0x50, 0xd1, 0x62, 0xcd, 0x4f, 0xec,
-    0x77, 0x8a, 0x82, 0x79, 0x82, 0x82, 0xf5, 0x5a, 0xa7, 0xc9, 0x3b, 0xc9, 0x09, 0xaa, 0xa0, 0x9a, 0x1f, 0xce, 0x77, 0x8d, 0x12,
-    0xaa, 0xd2, 0x14, 0xbf, 0x89, 0x5d, 0xe4, 0x89, 0x51, 0xff, 0xc4, 0x8e, 0xf2, 0x44, 0xa4, 0xda, 0x5b, 0xf5, 0x94, 0x10, 0x6c,
-    0xda, 0xbf, 0xff, 0xfc, 0xd2, 0x03, 0xe7, 0xa7, 0xfd, 0xcd, 0xc6, 0x46, 0xa6, 0x94, 0x1f, 0x32, 0x9a, 0xbf, 0xb9, 0xb8, 0xd0,
-    0xd4, 0x9a, 0xad, 0x23, 0x57, 0xd6, 0x78, 0x20, 0xd9, 0x8c, 0x2f, 0x82, 0x2d, 0x9f, 0xfe, 0x4c, 0xa9, 0xea, 0x9f, 0xeb, 0x4c,
-    0x66, 0x31, 0x8c, 0x2f, 0xff, 0xff, 0x26, 0x51, 0x45, 0x3e, 0x44, 0x10, 0xf1, 0x26, 0x30, 0xbf, 0xff, 0xfc, 0x99, 0x64, 0x4b,
-    0xb7, 0xe7, 0x04, 0x47, 0x37, 0x8f, 0x2d, 0x24, 0x4c, 0x22, 0xa9, 0x34, 0x3f, 0xf8, 0xe2, 0x06, 0x4c, 0x16, 0x44, 0x7b, 0xac,
-    0x11, 0x9c, 0xdc, 0xe4, 0x63, 0x83, 0xa3, 0xed, 0x3c, 0xab, 0x53, 0xd6, 0x10, 0x5c, 0xdc, 0xe4, 0x63, 0x87, 0xa0, 0x40, 0xd5,
-    0x7d, 0x61, 0x15, 0xcd, 0xce, 0x46, 0x38, 0x40, 0xaa, 0x8a, 0x9e, 0xab, 0x75, 0x88, 0xae, 0x6e, 0x72, 0x31, 0xc4, 0x07, 0xcf,
-    0x30, 0x61, 0xa5, 0xf5, 0x84, 0x97, 0x37, 0x39, 0x1a, 0xa4, 0x69, 0x10, 0x2a, 0xa2, 0xa7, 0xfb, 0x9b, 0x84, 0xa0, 0xa9, 0x7f,
-    0xc9, 0x7e, 0x70, 0x02, 0xda, 0x44, 0x63, 0xa6, 0x0d, 0x17, 0x2f, 0xf7, 0x78, 0x4c, 0x27, 0xa4, 0x46, 0x3a, 0x2e, 0x3d, 0x15,
-    0x7d, 0x6e, 0xf0, 0x98, 0x4f, 0x48, 0x8c, 0x74, 0xc9, 0x07, 0xec, 0x77, 0x84, 0xc2, 0x79, 0x36, 0x99, 0x53, 0xf9, 0x27, 0x7c,
-    0xb2, 0xf3, 0x80, 0x11, 0xe4, 0xbf, 0xf4, 0xd0, 0x48, 0x81, 0x55, 0x15, 0x3f, 0xdc, 0xdc, 0x25, 0x05, 0x4b, 0xfe, 0x4b, 0xf3,
-    0x81, 0xf6, 0x92, 0x63, 0xa6, 0x0d, 0x17, 0x2f, 0xf7, 0x78, 0x4c, 0x27, 0xa4, 0x98, 0xea, 0x88, 0x83, 0x86, 0xfa, 0xdd, 0xe1,
-    0x30, 0x9e, 0x92, 0x63, 0xa5, 0x4f, 0x54, 0xff, 0x63, 0xbc, 0x26, 0x13, 0xc9, 0xb4, 0xca, 0x9f, 0xc9, 0x3b, 0xe5, 0x97, 0x9c,
-    0x00, 0xb7, 0x25, 0xff, 0xa4, 0x89, 0x84, 0x55, 0xf5, 0x94, 0x03, 0x7b, 0x22, 0xb6, 0x2f, 0xf8, 0x23, 0x7e, 0x70, 0x0c, 0xb2,
-    0x65, 0x0b, 0x7e, 0xb2, 0x80, 0x06, 0x59, 0xb5, 0x7f, 0xff, 0xfa, 0x03, 0xe7, 0x98, 0x30, 0xd2, 0xea, 0x76, 0x3b, 0xc9, 0x04,
-    0xf4, 0x7f, 0xf3, 0x9d, 0xe4, 0x89, 0x49, 0x94, 0x29, 0xfa, 0xca, 0x00, 0x39, 0x66, 0x55, 0x7f, 0xff, 0x9c, 0x08, 0xfa, 0x48,
-    0x25, 0xfe, 0x25, 0x77, 0x8d, 0x92, 0x5e, 0xb0, 0x93, 0x0a, 0x23, 0xff, 0xf9, 0x36, 0x94, 0xfd, 0x68, 0x05, 0x96, 0x65, 0x57,
-    0xff, 0xff, 0x93, 0x69, 0x6f, 0xd6, 0x50, 0x01, 0xbb, 0x36, 0xaf, 0xff, 0xff, 0x34, 0x80, 0xf9, 0xe9, 0xff, 0x73, 0x71, 0x91,
-    0xa8, 0xe1, 0x41, 0x7a, 0xad, 0xfe, 0xe6, 0xe3, 0x23, 0x51, 0xc1, 0xf1, 0xd5, 0x7f, 0xdc, 0xdc, 0xe1, 0xa5, 0xeb, 0x08, 0x3c,
-    0x97, 0xfe, 0x38, 0x40, 0xa8, 0xea, 0x5f, 0xdc, 0xdc, 0x6c, 0x69, 0x7a, 0xc2, 0x2f, 0x0b, 0x3f, 0xf1, 0xc2, 0x05, 0x07, 0x52,
-    0xfe, 0xe6, 0xe3, 0x63, 0x4b, 0xd6, 0x22, 0xf0, 0xb7, 0xff, 0x1c, 0x40, 0x7c, 0x75, 0x2f, 0xee, 0x6e, 0x36, 0x34, 0xbd, 0x61,
-    0x27, 0x8f, 0xff, 0xe4, 0xd5, 0x69, 0x1a, 0xbe, 0xb2, 0x80, 0x6f, 0x66, 0x30, 0xbe, 0x08, 0xf0, 0x9b, 0xff, 0xc9, 0x95, 0x3d,
-    0x53, 0xfd, 0x69, 0x8c, 0xc6, 0x31, 0x85, 0xff, 0xff, 0xe4, 0xca, 0x28, 0xa7, 0xc8, 0x82, 0x1e, 0x24, 0xc6, 0x17, 0xff, 0xff,
-    0x93, 0x2c, 0x89, 0x76, 0xfc, 0x4c, 0x16, 0x39, 0xbc, 0x79, 0x69, 0x26, 0xd4, 0x04, 0x02, 0x68, 0x7f, 0xf1, 0xc4, 0x0c, 0x98,
-    0x2c, 0x88, 0xf7, 0x58, 0x23, 0x39, 0xb9, 0xc8, 0xc7, 0x07, 0x47, 0xda, 0x79, 0x56, 0xa7, 0xac, 0x20, 0xb9, 0xb9, 0xc8, 0xc7,
-    0x0f, 0x40, 0x81, 0xaa, 0xfa, 0xc2, 0x2b, 0x9b, 0x9c, 0x8c, 0x70, 0x81, 0x55, 0x15, 0x3d, 0x56, 0xeb, 0x11, 0x5c, 0xdc, 0xe4,
-    0x63, 0x88, 0x0f, 0x9e, 0x60, 0xc3, 0x4b, 0xeb, 0x09, 0x2e, 0x6e, 0x72, 0x35, 0x48, 0xd2, 0x20, 0x55, 0x45, 0x4f, 0xf7, 0x37,
-    0x09, 0x41, 0x52, 0xff, 0x92, 0xfc, 0xe0, 0x91, 0xa4, 0x46, 0x3a, 0x60, 0xd1, 0x72, 0xff, 0x77, 0x84, 0xc2, 0x7a, 0x44, 0x63,
-    0xa2, 0xe3, 0xd1, 0x57, 0xd6, 0xef, 0x09, 0x84, 0xf4, 0x88, 0xc7, 0x4c, 0x90, 0x7e, 0xc7, 0x78, 0x4c, 0x27, 0x93, 0x69, 0x95,
-    0x3f, 0x92, 0x77, 0xcb, 0x2f, 0x38, 0x06, 0xfc, 0x97, 0xfe, 0x9a, 0x09, 0x10, 0x2a, 0xa2, 0xa7, 0xfb, 0x9b, 0x84, 0xa0, 0xa9,
-    0x7f, 0xc9, 0x7e, 0x70, 0x26, 0xe9, 0x26, 0x3a, 0x60, 0xd1, 0x72, 0xff, 0x77, 0x84, 0xc2, 0x7a, 0x49, 0x8e, 0xa8, 0x88, 0x38,
-    0x6f, 0xad, 0xde, 0x13, 0x09, 0xe9, 0x26, 0x3a, 0x54, 0xf5, 0x4f, 0xf6, 0x3b, 0xc2, 0x61, 0x3c, 0x9b, 0x4c, 0xa9, 0xfc, 0x93,
-    0xbe, 0x59, 0x79, 0xc1, 0x23, 0x92, 0xff, 0xd2, 0x4d, 0xa8, 0x08, 0x1e, 0xb4, 0x07, 0x2c, 0x85, 0x4c, 0x5f, 0xec, 0x0c, 0x3f,
-    0x38, 0x07, 0xf9, 0x32, 0x85, 0xbf, 0x5a, 0x01, 0xdd, 0x9b, 0x57, 0xff, 0xff, 0xa1, 0xff, 0x8c, 0x3b, 0xd8, 0x26, 0x93, 0x69,
-    0x6f, 0xd6, 0xd0, 0x00, 0x0b, 0x36, 0xaf, 0xff, 0xff, 0x0d, 0x0c, 0x98, 0x55, 0x33, 0x25, 0xf5, 0xb4, 0x1c, 0xd9, 0x0a, 0x98,
-    0xbf, 0xd6, 0x1e, 0xfe, 0x26, 0x3b, 0x26, 0x50, 0xb7, 0xeb, 0x68, 0x07, 0xac, 0xda, 0xbf, 0xff, 0xfd, 0x24, 0x12, 0xff, 0x39,
-    0xde, 0x36, 0x49, 0x7a, 0xc2, 0x4f, 0x1f, 0xff, 0xd2, 0x51, 0x2f, 0xf1, 0x2b, 0xbc, 0x6c, 0x92, 0xf5, 0x88, 0xbc, 0x2d, 0xff,
-    0xcb, 0x9e, 0xa2, 0xaa, 0x34, 0x89, 0x2e, 0x24, 0x77, 0x8a, 0x89, 0x54, 0x69, 0x12, 0x5f, 0xc4, 0x6e, 0xf1, 0x51, 0x28, 0xfa,
-    0x2a, 0xff, 0x92, 0x77, 0x92, 0x25, 0x23, 0xff, 0xc6, 0x1d, 0xe4, 0x89, 0x49, 0xb4, 0xb7, 0xeb, 0x2c, 0x03, 0x5b, 0x36, 0xaf,
-    0xff, 0xff, 0x0e, 0xda, 0x91, 0x71, 0x7f, 0xac, 0xb0, 0x00, 0xab, 0x21, 0x53, 0x17, 0xfc, 0x8f, 0xf1, 0x30, 0x9f, 0x26, 0x50,
-    0xb7, 0xeb, 0x2c, 0x09, 0x96, 0x6d, 0x5f, 0xff, 0xfe, 0x82, 0xa5, 0xff, 0xc6, 0x1d, 0xe3, 0x22, 0x6a, 0x06, 0x4d, 0x18, 0x57,
-    0xf3, 0x9d, 0xe4, 0x89, 0x49, 0xb4, 0xb7, 0xeb, 0x1e, 0x06, 0xf6, 0x6d, 0x5f, 0xff, 0xfe, 0x1d, 0xb5, 0x54, 0xc5, 0xfe, 0xb1,
-    0xe0, 0x32, 0xb2, 0x2b, 0x62, 0xff, 0xab, 0xf8, 0x98, 0x6f, 0x26, 0x50, 0xb7, 0xeb, 0x1e, 0x03, 0x4b, 0x36, 0xaf, 0xff, 0xff,
-    0x2e, 0x7a, 0x8a, 0xa8, 0xd2, 0x24, 0xbb, 0x1d, 0xe2, 0xa2, 0x55, 0x1a, 0x44, 0x97, 0xf3, 0x9d, 0xe2, 0xa2, 0x52, 0x6d, 0x2d,
-    0xfa, 0xc7, 0x80, 0x63, 0x66, 0xd5, 0xff, 0xff, 0xe6, 0x90, 0x1f, 0x3d, 0x3f, 0xee, 0x6e, 0x32, 0x35, 0x1c, 0x28, 0x2f, 0x55,
-    0xbf, 0xdc, 0xdc, 0x64, 0x6a, 0x38, 0x3e, 0x3a, 0xaf, 0xfb, 0x9b, 0x9c, 0x34, 0xbd, 0x61, 0x07, 0x92, 0xff, 0xc7, 0x08, 0x15,
-    0x1d, 0x4b, 0xfb, 0x9b, 0x8d, 0x8d, 0x2f, 0x58, 0x45, 0xe1, 0x67, 0xfe, 0x38, 0x40, 0xa0, 0xea, 0x5f, 0xdc, 0xdc, 0x6c, 0x69,
-    0x7a, 0xc4, 0x5e, 0x16, 0xff, 0xe3, 0x88, 0x0f, 0x8e, 0xa5, 0xfd, 0xcd, 0xc6, 0xc6, 0x97, 0xac, 0x24, 0xf1, 0xff, 0xfc, 0x9a,
-    0xad, 0x23, 0x57, 0xd6, 0x80, 0xe5, 0x98, 0xc2, 0xf2, 0x42, 0x0c, 0xaf, 0xff, 0x93, 0x2a, 0x7a, 0xa7, 0xfa, 0xd3, 0x19, 0x8c,
-    0x63, 0x0b, 0xff, 0xff, 0xc9, 0x94, 0x51, 0x4f, 0x91, 0x04, 0x3c, 0x49, 0x8c, 0x2f, 0xff, 0xff, 0x26, 0x59, 0x12, 0xed, 0xf8,
-    0x98, 0x0d, 0x5c, 0xde, 0x3c, 0xb4, 0x94, 0x69, 0x82, 0xa4, 0xd0, 0xff, 0xe3, 0x88, 0x19, 0x30, 0x59, 0x11, 0xee, 0xb0, 0x46,
-    0x73, 0x73, 0x91, 0x8e, 0x0e, 0x8f, 0xb4, 0xf2, 0xad, 0x4f, 0x58, 0x41, 0x73, 0x73, 0x91, 0x8e, 0x1e, 0x81, 0x03, 0x55, 0xf5,
-    0x84, 0x57, 0x37, 0x39, 0x18, 0xe1, 0x02, 0xaa, 0x2a, 0x7a, 0xad, 0xd6, 0x22, 0xb9, 0xb9, 0xc8, 0xc7, 0x10, 0x1f, 0x3c, 0xc1,
-    0x86, 0x97, 0xd6, 0x12, 0x5c, 0xdc, 0xe4, 0x6a, 0x91, 0xa4, 0x40, 0xaa, 0x8a, 0x9f, 0xee, 0x6e, 0x12, 0x82, 0xa5, 0xff, 0x25,
-    0xf8, 0x98, 0x0a, 0xf4, 0x88, 0xc7, 0x4c, 0x1a, 0x2e, 0x5f, 0xee, 0xf0, 0x98, 0x4f, 0x48, 0x8c, 0x74, 0x5c, 0x7a, 0x2a, 0xfa,
-    0xdd, 0xe1, 0x30, 0x9e, 0x91, 0x18, 0xe9, 0x92, 0x0f, 0xd8, 0xef, 0x09, 0x84, 0xf2, 0x6d, 0x32, 0xa7, 0xf2, 0x4e, 0xf9, 0x65,
-    0xe2, 0x60, 0x29, 0xe4, 0xbf, 0xf4, 0xd0, 0x48, 0x81, 0x55, 0x15, 0x3f, 0xdc, 0xdc, 0x25, 0x05, 0x4b, 0xfe, 0x4b, 0xf1, 0x30,
-    0x34, 0xd2, 0x4c, 0x74, 0xc1, 0xa2, 0xe5, 0xfe, 0xef, 0x09, 0x84, 0xf4, 0x93, 0x1d, 0x51, 0x10, 0x70, 0xdf, 0x5b, 0xbc, 0x26,
-    0x13, 0xd2, 0x4c, 0x74, 0xa9, 0xea, 0x9f, 0xec, 0x77, 0x84, 0xc2, 0x79, 0x36, 0x99, 0x53, 0xf9, 0x27, 0x7c, 0xb2, 0xf1, 0x30,
-    0x15, 0xf2, 0x5f, 0xfa, 0x4a, 0x34, 0xc1, 0x5f, 0x58, 0xf0, 0x24, 0x59, 0x15, 0xb1, 0x7f, 0xac, 0x6f, 0xf1, 0x30, 0xe6, 0x4c,
-    0xa1, 0x6f, 0xd6, 0x3c, 0x09, 0x56, 0x6d, 0x5f, 0xff, 0xfe, 0x80, 0xf9, 0xe6, 0x0c, 0x34, 0xbf, 0xdd, 0xe4, 0x84, 0xd2, 0x86,
-    0x8b, 0x25, 0xfc, 0xe7, 0x78, 0x50, 0x13, 0xd0, 0x32, 0x68, 0xc2, 0xbf, 0x8c, 0x3b, 0xc9, 0x04, 0xf5, 0x45, 0xe9, 0x37, 0xf8,
-    0x2a, 0x77, 0xb0, 0x4d, 0x47, 0xff, 0x63, 0xbc, 0x91, 0x2a, 0x09, 0x7f, 0xc4, 0xae, 0xf1, 0x51, 0x2a, 0x44, 0x09, 0x7f, 0x89,
-    0x1d, 0xe3, 0x64, 0x97, 0xac, 0x22, 0xf0, 0xb3, 0xff, 0x49, 0x04, 0xbf, 0xc4, 0x6e, 0xf1, 0xb2, 0x4b, 0xd6, 0x12, 0x78, 0xff,
-    0xfe, 0x92, 0x89, 0x7f, 0x92, 0x77, 0x8d, 0x92, 0x5e, 0xb1, 0x17, 0x85, 0xbf, 0xf9, 0x1f, 0xff, 0x77, 0x92, 0x5c, 0x9b, 0x4b,
-    0x7e, 0xb1, 0xf2, 0x56, 0x6d, 0x5f, 0xff, 0xfe, 0x69, 0x01, 0xf3, 0xd3, 0xfe, 0xe6, 0xe3, 0x23, 0x51, 0xc2, 0x82, 0xf5, 0x5b,
-    0xfd, 0xcd, 0xc6, 0x46, 0xa3, 0x83, 0xe3, 0xaa, 0xff, 0xb9, 0xb9, 0xc3, 0x4b, 0xd6, 0x10, 0x79, 0x2f, 0xfc, 0x70, 0x81, 0x51,
-    0xd4, 0xbf, 0xb9, 0xb8, 0xd8, 0xd2, 0xf5, 0x84, 0x5e, 0x16, 0x7f, 0xe3, 0x84, 0x0a, 0x0e, 0xa5, 0xfd, 0xcd, 0xc6, 0xc6, 0x97,
-    0xac, 0x45, 0xe1, 0x6f, 0xfe, 0x38, 0x80, 0xf8, 0xea, 0x5f, 0xdc, 0xdc, 0x6c, 0x69, 0x7a, 0xc2, 0x4f, 0x1f, 0xff, 0xc9, 0xaa,
-    0xd2, 0x35, 0x7d, 0x63, 0xc0, 0x91, 0x66, 0x30, 0xbd, 0x63, 0x71, 0x37, 0xff, 0x93, 0x2a, 0x7a, 0xa7, 0xfa, 0xd3, 0x19, 0x8c,
-    0x63, 0x0b, 0xff, 0xff, 0xc9, 0x94, 0x51, 0x4f, 0x91, 0x04, 0x3c, 0x49, 0x8c, 0x2f, 0xff, 0xff, 0x26, 0x59, 0x12, 0xed, 0xf8,
-    0x98, 0x0e, 0x5c, 0xde, 0x3c, 0xb4, 0x95, 0x44, 0x85, 0xa6, 0x4d, 0x2a, 0x4d, 0x0f, 0xf1, 0xc4, 0x0c, 0x98, 0x2c, 0x88, 0xf7,
-    0x58, 0x23, 0x39, 0xb9, 0xc8, 0xc7, 0x07, 0x47, 0xda, 0x79, 0x56, 0xa7, 0xac, 0x20, 0xb9, 0xb9, 0xc8, 0xc7, 0x0f, 0x40, 0x81,
-    0xaa, 0xfa, 0xc2, 0x2b, 0x9b, 0x9c, 0x8c, 0x70, 0x81, 0x55, 0x15, 0x3d, 0x56, 0xeb, 0x11, 0x5c, 0xdc, 0xe4, 0x63, 0x88, 0x0f,
-    0x9e, 0x60, 0xc3, 0x4b, 0xeb, 0x09, 0x2e, 0x6e, 0x72, 0x35, 0x48, 0xd2, 0x20, 0x55, 0x45, 0x4f, 0xf7, 0x37, 0x09, 0x41, 0x52,
-    0xff, 0x92, 0xfc, 0x4c, 0x63, 0x48, 0x8c, 0x74, 0xc1, 0xa2, 0xe5, 0xfe, 0xef, 0x09, 0x84, 0xf4, 0x88, 0xc7, 0x45, 0xc7, 0xa2,
-    0xaf, 0xad, 0xde, 0x13, 0x09, 0xe9, 0x11, 0x8e, 0x99, 0x20, 0xfd, 0x8e, 0xf0, 0x98, 0x4f, 0x26, 0xd3, 0x2a, 0x7f, 0x24, 0xef,
-    0x96, 0x5e, 0x26, 0x1e, 0xe4, 0xbf, 0xf4, 0xd0, 0x48, 0x81, 0x55, 0x15, 0x3f, 0xdc, 0xdc, 0x25, 0x05, 0x4b, 0xfe, 0x4b, 0xf1,
-    0x31, 0xad, 0x24, 0xc7, 0x4c, 0x1a, 0x2e, 0x5f, 0xee, 0xf0, 0x98, 0x4f, 0x49, 0x31, 0xd5, 0x11, 0x07, 0x0d, 0xf5, 0xbb, 0xc2,
-    0x61, 0x3d, 0x24, 0xc7, 0x4a, 0x9e, 0xa9, 0xfe, 0xc7, 0x78, 0x4c, 0x27, 0x93, 0x69, 0x95, 0x3f, 0x92, 0x77, 0xcb, 0x2f, 0x13,
-    0x18, 0xe4, 0xbf, 0xf4, 0x95, 0x44, 0x85, 0xa6, 0x4d, 0x2b, 0x58, 0xf8, 0x46, 0xb2, 0x15, 0x31, 0x7f, 0xc0, 0x1f, 0xfc, 0x4c,
-    0x01, 0xac, 0x99, 0x42, 0xdf, 0xac, 0x7c, 0x07, 0x56, 0x6d, 0x5f, 0xff, 0xfe, 0xa2, 0x23, 0xdf, 0xf7, 0x79, 0x21, 0x35, 0x24,
-    0x12, 0xff, 0x39, 0xde, 0x36, 0x49, 0x7a, 0xc2, 0x4f, 0x1f, 0xff, 0xd2, 0x51, 0x2f, 0xf1, 0x2b, 0xbc, 0x6c, 0x92, 0xf5, 0x88,
-    0xbc, 0x2d, 0xff, 0xd1, 0xff, 0xc4, 0x8e, 0xf2, 0x44, 0xa9, 0xb5, 0x54, 0xc5, 0xea, 0x7c, 0x46, 0xef, 0x24, 0x4a, 0x4d, 0xa5,
-    0xbf, 0x5a, 0x20, 0x8b, 0x66, 0xd5, 0xff, 0xff, 0xe6, 0x90, 0x1f, 0x3d, 0x3f, 0xee, 0x6e, 0x32, 0x35, 0x1c, 0x28, 0x2f, 0x55,
-    0xbf, 0xdc, 0xdc, 0x64, 0x6a, 0x38, 0x3e, 0x3a, 0xaf, 0xfb, 0x9b, 0x9c, 0x34, 0xbd, 0x61, 0x07, 0x92, 0xff, 0xc7, 0x08, 0x15,
-    0x1d, 0x4b, 0xfb, 0x9b, 0x8d, 0x8d, 0x2f, 0x58, 0x45, 0xe1, 0x67, 0xfe, 0x38, 0x40, 0xa0, 0xea, 0x5f, 0xdc, 0xdc, 0x6c, 0x69,
-    0x7a, 0xc4, 0x5e, 0x16, 0xff, 0xe3, 0x88, 0x0f, 0x8e, 0xa5, 0xfd, 0xcd, 0xc6, 0xc6, 0x97, 0xac, 0x24, 0xf1, 0xff, 0xfc, 0x9a,
-    0xad, 0x23, 0x57, 0xd6, 0x3e, 0x11, 0xac, 0xc6, 0x17, 0xc1, 0x32, 0x0a, 0x7f, 0xfc, 0x99, 0x53, 0xd5, 0x3f, 0xd6, 0x98, 0xcc,
-    0x63, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xa2, 0x8a, 0x7c, 0x88, 0x21, 0xe2, 0x4c, 0x61, 0x7f, 0xff, 0xf9, 0x35, 0x5a, 0x46, 0xaf,
-    0xad, 0x10, 0x47, 0xb3, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xa9, 0xea, 0x9f, 0xeb, 0x4c, 0x66, 0x31, 0x8c, 0x2f, 0xff, 0xff, 0x26,
-    0x51, 0x45, 0x3e, 0x44, 0x10, 0xf1, 0x26, 0x30, 0xbf, 0xff, 0xfc, 0x99, 0x64, 0x4b, 0xb7, 0xe2, 0x60, 0x3b, 0x73, 0x78, 0xf2,
-    0xcb, 0xa4, 0x81, 0x45, 0x09, 0xa1, 0xff, 0xc9, 0xaa, 0xd2, 0x35, 0x7d, 0x68, 0x82, 0x3d, 0x98, 0xc2, 0xff, 0xff, 0xf2, 0x65,
-    0x4f, 0x54, 0xff, 0x22, 0x07, 0xc3, 0x92, 0x58, 0xc2, 0xf1, 0x80, 0x3b, 0x83, 0x0f, 0xff, 0x26, 0x51, 0x45, 0x3e, 0x44, 0x08,
-    0x62, 0x2c, 0x96, 0x30, 0xbf, 0xff, 0xfc, 0x99, 0x64, 0x4b, 0xb7, 0xe2, 0x60, 0x14, 0xb9, 0xbc, 0x79, 0x69, 0x17, 0x26, 0x87,
-    0xff, 0xe4, 0xd5, 0x69, 0x1a, 0xbe, 0xb4, 0x41, 0x1e, 0xcc, 0x61, 0x7a, 0xc1, 0x9c, 0x08, 0xbf, 0xfc, 0x99, 0x53, 0xd5, 0x3f,
-    0xd6, 0x98, 0xcc, 0x63, 0x18, 0x5f, 0xff, 0xfe, 0x4c, 0xa2, 0x8a, 0x7c, 0x88, 0x21, 0xe2, 0x4c, 0x61, 0x7f, 0xff, 0xf9, 0x43,
-    0xd0, 0x20, 0xfa, 0xc3, 0xf3, 0x36, 0x63, 0x12, 0x13, 0x26, 0x94, 0x1d, 0x40, 0x7c, 0x82, 0xb0, 0xfc, 0x04, 0xd6, 0x63, 0x12,
-    0x15, 0x04, 0x04, 0x4a, 0x8e, 0xb4, 0x8e, 0xb0, 0xfc, 0x12, 0xec, 0xc6, 0x24, 0xcb, 0x22, 0x5d, 0xbf, 0x13, 0x00, 0x24, 0xe6,
-    0xf1, 0xe5, 0x90, 0x9e, 0x89, 0x85, 0x15, 0x20, 0x26, 0x87, 0xfc, 0x9a, 0xad, 0x23, 0x57, 0xd6, 0x40, 0x75, 0x98, 0xc2, 0xff,
-    0xff, 0xf2, 0x65, 0x4f, 0x54, 0xff, 0x5a, 0x63, 0x31, 0x8c, 0x61, 0x7f, 0xff, 0xf9, 0x32, 0x8a, 0x29, 0xf2, 0x20, 0x87, 0x89,
-    0x31, 0x85, 0xff, 0xff, 0xe8, 0x1a, 0x4b, 0xf5, 0x90, 0x1d, 0x18, 0x9c, 0xc5, 0xf0, 0xc3, 0x85, 0x5f, 0xf8, 0x36, 0x17, 0xa9,
-    0xf9, 0x10, 0x0c, 0xf1, 0x1e, 0xc8, 0xe7, 0xf9, 0x10, 0x0c, 0xf1, 0x1e, 0xca, 0x49, 0x99, 0x41, 0x53, 0xca, 0x64, 0x41, 0x0f,
-    0x12, 0x41, 0x83, 0x17, 0xc4, 0x2c, 0x25, 0x07, 0xd3, 0xbf, 0xd3, 0x69, 0xe5, 0xcf, 0x2e, 0x5c, 0xce, 0x07, 0xc0, 0xa2, 0xef,
-    0xb2, 0x59, 0x36, 0xb4, 0xf2, 0xe5, 0xf8, 0x0a, 0x00, 0x68, 0xef, 0xb2, 0x1d, 0xb5, 0x05, 0x7f, 0x04, 0x3b, 0xbe, 0xc8, 0x70,
-    0x50, 0xc9, 0x1f, 0xe0, 0x04, 0x88, 0xae, 0xfb, 0x25, 0x5a, 0x50, 0xd0, 0xf0, 0xf3, 0x53, 0x91, 0x04, 0x20, 0x3d, 0x88, 0xf6,
-    0x48, 0x90, 0x2e, 0xd3, 0xd3, 0xf2, 0x21, 0x43, 0xbe, 0xca, 0x06, 0x93, 0x51, 0x02, 0xe8, 0xa5, 0x22, 0x01, 0xb8, 0x3e, 0x89,
-    0x23, 0x0c, 0xa0, 0x69, 0x35, 0x0c, 0xa7, 0xc8, 0x81, 0xe2, 0xae, 0xfb, 0x24, 0x26, 0x4d, 0x28, 0xdf, 0x22, 0x07, 0x80, 0x7c,
-    0xef, 0xb2, 0xaa, 0x28, 0x10, 0x28, 0x0b, 0xb9, 0x10, 0x42, 0x07, 0xb1, 0x1e, 0xca, 0xa8, 0xa0, 0x40, 0xa7, 0x91, 0x04, 0x20,
-    0x40, 0x88, 0xf6, 0x54, 0x6a, 0x06, 0x4c, 0x15, 0xe4, 0x41, 0x08, 0x0f, 0xa2, 0x3d, 0x92, 0x21, 0xe6, 0xaa, 0x87, 0xd2, 0x04,
-    0x07, 0x7d, 0x92, 0x02, 0x85, 0x53, 0x6a, 0x4d, 0x3e, 0x44, 0x21, 0x83, 0x97, 0x7d, 0x92, 0x02, 0x85, 0x53, 0x49, 0x5f, 0x01,
-    0x02, 0x4e, 0xfb, 0x24, 0x05, 0x0a, 0xa4, 0x99, 0x94, 0x32, 0x20, 0x84, 0x07, 0x71, 0x1e, 0xc9, 0x11, 0xa6, 0x9a, 0x6a, 0x9c,
-    0x88, 0x43, 0x0b, 0x5d, 0xf6, 0x48, 0x92, 0x34, 0xaa, 0x1f, 0x32, 0x09, 0x4e, 0xfb, 0x20, 0xd9, 0xaa, 0x9a, 0x48, 0x36, 0x2e,
-    0x7c, 0x88, 0x43, 0x10, 0xdd, 0xf6, 0x50, 0x34, 0x9a, 0xa7, 0x94, 0x4b, 0x22, 0x07, 0x80, 0xe5, 0xdf, 0x64, 0xb9, 0x64, 0xcc,
-    0x98, 0x57, 0x91, 0x03, 0xc0, 0x3d, 0x77, 0xd9, 0x51, 0x22, 0xe1, 0x77, 0xe0, 0xac, 0x01, 0x27, 0x7d, 0x95, 0x12, 0x2e, 0x0b,
-    0xbf, 0x05, 0x60, 0x26, 0x77, 0xd9, 0x49, 0x03, 0x26, 0x04, 0x05, 0x39, 0x10, 0x0d, 0xc1, 0x12, 0x24, 0x83, 0x06, 0x2f, 0x58,
-    0x49, 0xc6, 0x03, 0x19, 0xdf, 0xe9, 0x20, 0x64, 0xc1, 0x64, 0x47, 0xb2, 0x20, 0x1c, 0x12, 0x89, 0x27, 0x31, 0x7a, 0xc1, 0x1b,
-    0x8a, 0x3f, 0xf2, 0x16, 0x98, 0x34, 0x58, 0xc7, 0x22, 0x01, 0xc0, 0xdc, 0x48, 0xc5, 0x58, 0xbf, 0xe2, 0xb8, 0x9b, 0xfc, 0x5c,
-    0x89, 0x82, 0xa6, 0x45, 0xc4, 0x2d, 0x91, 0x00, 0xe0, 0x19, 0xc4, 0x93, 0x98, 0xbe, 0x16, 0xf1, 0x9f, 0xfc, 0x88, 0xd3, 0x4d,
-    0x35, 0x24, 0xcc, 0xc8, 0x80, 0x70, 0x05, 0xf1, 0x24, 0x6d, 0x8b, 0xe2, 0x16, 0xb0, 0xfb, 0xff, 0x20, 0x28, 0x54, 0xba, 0x2a,
-    0x2b, 0x91, 0x00, 0xe0, 0x38, 0x89, 0x21, 0x7b, 0x17, 0xfc, 0x31, 0x86, 0x3f, 0xe5, 0x0d, 0x17, 0x2e, 0xa3, 0x45, 0xed, 0xac,
-    0x80, 0x2c, 0x8c, 0x4e, 0x62, 0xf8, 0x61, 0xc2, 0xaf, 0xfd, 0x42, 0x03, 0x45, 0xcb, 0xfa, 0xc8, 0x00, 0xbe, 0x31, 0x39, 0x8b,
-    0xe1, 0x87, 0x0a, 0xbf, 0xf2, 0x86, 0x48, 0xd0, 0x34, 0x9a, 0x9a, 0xc8, 0x07, 0xe3, 0x13, 0x98, 0xbe, 0x18, 0x70, 0xab, 0xff,
-    0x3d, 0x34, 0xcc, 0x8b, 0x94, 0xeb, 0x20, 0x00, 0xe4, 0x62, 0x73, 0x17, 0xc3, 0x0e, 0x15, 0x7f, 0xe4, 0x2d, 0x30, 0x69, 0x56,
-    0x91, 0xab, 0x59, 0x00, 0x1f, 0x46, 0x29, 0xb1, 0x7f, 0xff, 0xfa, 0x83, 0xe7, 0x95, 0x69, 0x09, 0xe9, 0x48, 0x80, 0x70, 0x0e,
-    0x62, 0x49, 0xcc, 0x5f, 0x16, 0x71, 0x07, 0xff, 0x34, 0xa3, 0x53, 0x21, 0x32, 0x69, 0x49, 0x10, 0x42, 0x04, 0x58, 0x8f, 0x64,
-    0x80, 0xa1, 0x52, 0xe8, 0xa8, 0xaa, 0x52, 0x20, 0x84, 0x09, 0x11, 0x1e, 0xca, 0x8d, 0x54, 0xa2, 0x67, 0x90, 0xf0, 0xd4, 0x07,
-    0x6e, 0xfb, 0x24, 0x48, 0x98, 0x45, 0x5f, 0x99, 0x04, 0x07, 0x7d, 0x92, 0x87, 0x9a, 0x2a, 0x3a, 0xa2, 0x4d, 0xc0, 0x74, 0x32,
-    0xef, 0xb2, 0x7a, 0x08, 0x9e, 0x89, 0x84, 0x55, 0xc4, 0x21, 0xb7, 0x7d, 0x92, 0x88, 0x98, 0x45, 0x5f, 0x9e, 0x09, 0x6e, 0xfb,
-    0x24, 0x05, 0x0a, 0xa2, 0x61, 0x15, 0x78, 0x07, 0xc2, 0xa7, 0x7d, 0x92, 0xa8, 0xa8, 0x91, 0x73, 0xc5, 0xfc, 0x12, 0x81, 0x15,
-    0xdf, 0x64, 0x38, 0x1e, 0x4d, 0xa2, 0xff, 0x81, 0x91, 0x77, 0x7d, 0x94, 0x0d, 0x26, 0xa2, 0x26, 0x11, 0x57, 0x2c, 0x08, 0xae,
-    0xfb, 0x24, 0x27, 0x91, 0x9e, 0x54, 0xaa, 0x7c, 0x07, 0x44, 0x9d, 0xf6, 0x4f, 0x2e, 0x5c, 0xca, 0x08, 0x98, 0x47, 0x03, 0xe0,
-    0x16, 0x3b, 0xec, 0x8f, 0x20, 0x89, 0x84, 0x55, 0xf0, 0x76, 0x08, 0x2e, 0xfb, 0x23, 0xea, 0x2a, 0x3a, 0x89, 0x84, 0x55, 0x91,
-    0x04, 0x20, 0x49, 0x88, 0xf6, 0x50, 0x34, 0x9a, 0x8a, 0x0b, 0x91, 0x57, 0x1e, 0x03, 0xb7, 0x7d, 0x91, 0xf3, 0x2a, 0x2a, 0x65,
-    0x25, 0x32, 0x21, 0x0c, 0x56, 0xef, 0xb2, 0x38, 0xaf, 0xf8, 0x21, 0x80, 0x45, 0xdf, 0x64, 0x3b, 0x49, 0x5f, 0xe0, 0x86, 0x2b,
-    0x77, 0xd9, 0x40, 0xd2, 0x6a, 0x28, 0xd5, 0x79, 0x60, 0xb1, 0xdf, 0x64, 0xb9, 0x44, 0x55, 0x28, 0x5d, 0x5e, 0x1b, 0x81, 0xeb,
-    0xbe, 0xca, 0x8d, 0x30, 0x54, 0xa1, 0xa5, 0x05, 0xf2, 0x20, 0x84, 0x11, 0xa2, 0x3d, 0x95, 0x12, 0x2e, 0x47, 0xf8, 0x2b, 0x04,
-    0x77, 0x7d, 0x90, 0xfc, 0x84, 0xc8, 0x80, 0xd5, 0x4c, 0xf0, 0x46, 0x49, 0xdf, 0x64, 0x3f, 0x2e, 0x89, 0x86, 0xfc, 0x11, 0x81,
-    0x2d, 0xdf, 0x65, 0x36, 0x94, 0x32, 0x65, 0x52, 0xc5, 0xe4, 0x41, 0x08, 0x45, 0x88, 0xf6, 0x53, 0x69, 0x43, 0x26, 0x55, 0xf1,
-    0x50, 0x23, 0xbb, 0xec, 0x86, 0xc9, 0xb5, 0xa3, 0xa1, 0xd8, 0x64, 0x1c, 0xc8, 0x80, 0x76, 0x20, 0xc4, 0xbb, 0x23, 0xaa, 0x2a,
-    0x7a, 0x6a, 0xf5, 0xa2, 0x08, 0xf6, 0x6c, 0x9e, 0x5d, 0xa6, 0x04, 0x05, 0x4f, 0x29, 0x8c, 0x02, 0x8b, 0xbe, 0xc8, 0x76, 0x0d,
-    0x85, 0xea, 0x7d, 0x66, 0x06, 0x63, 0x1b, 0x2a, 0x0b, 0x94, 0x54, 0x83, 0xc8, 0x80, 0x72, 0x33, 0x12, 0x45, 0x8c, 0x5f, 0xf0,
-    0xc6, 0x27, 0xff, 0x50, 0x5c, 0x92, 0x05, 0x12, 0xe4, 0x40, 0x39, 0x0e, 0x22, 0x48, 0xb1, 0x8b, 0xfe, 0x39, 0x14, 0x7f, 0x97,
-    0x3d, 0x45, 0x7e, 0x44, 0x10, 0x81, 0x3a, 0x23, 0xd9, 0x55, 0x33, 0xd2, 0x21, 0x50, 0x7b, 0x59, 0x71, 0x64, 0x62, 0x9b, 0x17,
-    0xff, 0xff, 0xa4, 0x64, 0xc9, 0x61, 0xd2, 0x88, 0xab, 0x59, 0x70, 0x2f, 0x8c, 0x45, 0x8c, 0x5f, 0xff, 0xfe, 0x91, 0x93, 0x25,
-    0xbe, 0xb2, 0xe0, 0x8b, 0x18, 0x9c, 0xc5, 0xf0, 0xaf, 0x8a, 0x3f, 0xf5, 0x55, 0x21, 0x50, 0x7b, 0xc8, 0x80, 0x72, 0x07, 0x51,
-    0x24, 0x2f, 0x62, 0xff, 0x88, 0x31, 0x07, 0xfd, 0x55, 0x52, 0xfc, 0x88, 0x23, 0x8a, 0xa2, 0x48, 0x50, 0xca, 0x47, 0xaa, 0x80,
-    0xfb, 0x4a, 0xa9, 0x22, 0x08, 0x40, 0xa1, 0x11, 0xec, 0xa4, 0x7a, 0xa8, 0x0f, 0xf9, 0x10, 0x42, 0x05, 0x18, 0x8f, 0x65, 0x45,
-    0x53, 0x2e, 0xd3, 0x1e, 0x10, 0x4a, 0xbb, 0xec, 0x95, 0x69, 0x63, 0x26, 0x15, 0xeb, 0x58, 0x82, 0xef, 0xb2, 0x1f, 0xa6, 0xd4,
-    0x15, 0x2d, 0xe0, 0x7e, 0x55, 0xdf, 0x64, 0x80, 0x79, 0xaa, 0xa1, 0xf0, 0x76, 0x2b, 0x77, 0xd9, 0x4d, 0xa7, 0x97, 0x28, 0x79,
-    0x72, 0xf8, 0x09, 0xc1, 0x25, 0xdf, 0x65, 0xa4, 0x49, 0x2b, 0xf8, 0x40, 0x08, 0xae, 0xfb, 0x2a, 0x24, 0x5c, 0x59, 0xf8, 0x2b,
-    0x00, 0x49, 0xdf, 0x64, 0xa2, 0x2a, 0x94, 0x2e, 0xaf, 0x82, 0x00, 0x46, 0x77, 0xd9, 0x2e, 0x50, 0xba, 0xbf, 0x87, 0x00, 0x80,
-    0xef, 0xb2, 0xab, 0x48, 0xd5, 0x34, 0x9b, 0x78, 0x02, 0xe0, 0x82, 0xef, 0xb2, 0xa2, 0x09, 0xb5, 0xa6, 0x14, 0x17, 0x60, 0x29,
-    0x16, 0xbb, 0xec, 0xa4, 0x64, 0xb8, 0xbc, 0xb7, 0xc1, 0x0c, 0x58, 0xef, 0xb2, 0x20, 0x35, 0x31, 0xd5, 0x15, 0x4f, 0x82, 0x51,
-    0x1b, 0xbe, 0xca, 0x03, 0xed, 0x40, 0x80, 0xa2, 0x67, 0xe1, 0xa0, 0x28, 0xbb, 0xec, 0xa6, 0xd4, 0x15, 0x2d, 0xf0, 0x3f, 0x10,
-    0xdd, 0xf6, 0x51, 0x30, 0xa2, 0x25, 0x5a, 0x54, 0x7a, 0xb2, 0x10, 0x7b, 0x18, 0x9c, 0xc5, 0xf0, 0xa7, 0x9d, 0xff, 0xa6, 0x64,
-    0x85, 0x12, 0xaa, 0x26, 0x84, 0x88, 0x23, 0x85, 0x51, 0x24, 0x28, 0x65, 0x33, 0x24, 0x28, 0x95, 0x2a, 0xd5, 0x24, 0x41, 0x1c,
-    0x57, 0x12, 0x42, 0x86, 0x4c, 0x98, 0x6a, 0xa6, 0x4b, 0x22, 0x20, 0x48, 0x82, 0x38, 0x59, 0x12, 0x49, 0x32, 0xd2, 0x34, 0x1a,
-    0x90, 0xe9, 0x83, 0x35, 0x9e, 0x2d, 0xb3, 0x65, 0xa4, 0x68, 0x35, 0x21, 0xd5, 0x15, 0xac, 0xf0, 0x0d, 0x59, 0xb2, 0xd2, 0x34,
-    0x1a, 0x90, 0xea, 0x26, 0x2b, 0x3c, 0x00, 0x3d, 0x9b, 0x2d, 0x23, 0x41, 0xa9, 0x0e, 0xa4, 0x9d, 0x67, 0x81, 0xe5, 0x9b, 0x27,
-    0x95, 0x2b, 0xfc, 0x20, 0x03, 0x37, 0x7d, 0x93, 0xca, 0x95, 0x4f, 0xf0, 0x80, 0x36, 0xef, 0xb2, 0x50, 0xb2, 0x6d, 0x6f, 0xc1,
-    0xe0, 0x22, 0xbb, 0xec, 0x95, 0x69, 0x72, 0xe7, 0x8b, 0xda, 0x9e, 0x1c, 0x82, 0x03, 0xbe, 0xc9, 0x54, 0x44, 0x0f, 0xe1, 0x00,
-    0x8d, 0xdf, 0x65, 0xa4, 0x49, 0xb5, 0x05, 0x7c, 0x20, 0x08, 0xae, 0xfb, 0x25, 0x93, 0x6b, 0x7f, 0x01, 0x40, 0x22, 0x3b, 0xec,
-    0xa2, 0x61, 0x46, 0xab, 0xf0, 0x80, 0x06, 0x4e, 0xfb, 0x26, 0x0f, 0x4b, 0xf8, 0xf0, 0x1b, 0x3b, 0xec, 0x90, 0xb4, 0xc1, 0xa1,
-    0xf4, 0x55, 0xe0, 0x22, 0x03, 0x67, 0x7d, 0x92, 0x13, 0x45, 0xff, 0x84, 0x00, 0x74, 0xef, 0xb2, 0x61, 0xa2, 0xe2, 0xe7, 0x8b,
-    0xda, 0x9e, 0x38, 0x08, 0x8e, 0xfb, 0x29, 0x2a, 0x89, 0x84, 0xda, 0x82, 0xb8, 0x1f, 0x08, 0x6e, 0xfb, 0x29, 0x9e, 0x54, 0xaa,
-    0x5f, 0x08, 0x00, 0x31, 0x77, 0xd9, 0x4d, 0xa8, 0x2a, 0x1f, 0x26, 0xd4, 0x30, 0x3f, 0x0c, 0x5d, 0xf6, 0x53, 0x6a, 0x0a, 0xa0,
-    0x5d, 0x14, 0xb0, 0x3f, 0x08, 0xce, 0xfb, 0x29, 0xa8, 0x68, 0xa2, 0x5f, 0x08, 0x00, 0xf5, 0xdf, 0x65, 0x45, 0x53, 0x17, 0x22,
-    0x55, 0x51, 0xfc, 0x01, 0x70, 0x72, 0xef, 0xb2, 0xa1, 0xa2, 0x9f, 0xc2, 0x00, 0x18, 0xbb, 0xec, 0x9a, 0x48, 0xb2, 0x66, 0x4c,
-    0x2b, 0xc3, 0xe0, 0x5c, 0xef, 0xb2, 0x9b, 0x4f, 0x2e, 0x3c, 0x81, 0xe5, 0xf0, 0x76, 0x12, 0x1d, 0xf6, 0x47, 0x90, 0x2c, 0x9b,
-    0x5b, 0xe0, 0x72, 0x07, 0xce, 0xfb, 0x25, 0xd2, 0x32, 0x89, 0x85, 0x7c, 0x1a, 0x81, 0xeb, 0xbe, 0xc9, 0x74, 0xda, 0x82, 0xa5,
-    0xbc, 0x39, 0x03, 0x67, 0x7d, 0x92, 0xea, 0x34, 0x78, 0x85, 0xa6, 0x15, 0xc3, 0xa0, 0x1f, 0xbb, 0xec, 0x97, 0x55, 0xa4, 0x6a,
-    0x9a, 0x4d, 0xb8, 0x74, 0x20, 0xbb, 0xec, 0xa6, 0xd3, 0xcb, 0xaa, 0x89, 0x0b, 0x6b, 0x0f, 0xde, 0xcd, 0x94, 0x0d, 0x26, 0xa2,
-    0x26, 0x07, 0xd1, 0xd0, 0x16, 0xbb, 0xec, 0xa0, 0x69, 0x35, 0x0f, 0x2e, 0x5c, 0xce, 0x80, 0x3d, 0x77, 0xd9, 0x0f, 0xd4, 0x55,
-    0x31, 0x72, 0x25, 0x55, 0xc0, 0x17, 0x55, 0xdf, 0x64, 0x7c, 0xca, 0x8a, 0x8e, 0xa2, 0x61, 0x5a, 0xc3, 0xf0, 0x1d, 0x59, 0xb2,
-    0x1f, 0xa8, 0xaa, 0x68, 0x98, 0x1e, 0xe0, 0x22, 0x19, 0x77, 0xd9, 0x20, 0x68, 0xbc, 0x75, 0x13, 0x0a, 0xeb, 0x0f, 0xc1, 0x32,
-    0xcd, 0x92, 0x15, 0x04, 0x04, 0x4a, 0x8e, 0xa2, 0x62, 0xb0, 0xfc, 0x42, 0xb3, 0x64, 0xc9, 0xa5, 0x47, 0x51, 0x30, 0xae, 0xb0,
-    0xfc, 0x13, 0xec, 0xd9, 0x10, 0x1a, 0x9a, 0x88, 0x99, 0x31, 0xc4, 0x6a, 0xbb, 0xec, 0xa6, 0x89, 0x81, 0xe2, 0x8d, 0x2e, 0x5f,
-    0x00, 0xf8, 0x3d, 0x77, 0xd9, 0x49, 0x46, 0x98, 0x2a, 0x80, 0xf9, 0xf9, 0x8b, 0xbe, 0xc8, 0x68, 0x3a, 0x82, 0x66, 0x54, 0x50,
-    0x7f, 0x01, 0xf0, 0x5a, 0xef, 0xb2, 0x1c, 0x86, 0x86, 0x93, 0x1d, 0x40, 0xb9, 0x9c, 0x13, 0x8b, 0xbb, 0xec, 0x87, 0x21, 0xa1,
-    0xa4, 0xc7, 0x54, 0x20, 0x35, 0x82, 0x70, 0x39, 0x77, 0xd9, 0x0e, 0x43, 0x43, 0x49, 0x8e, 0xa8, 0x2e, 0x6e, 0x09, 0xc0, 0x80,
-    0xef, 0xb2, 0x1c, 0x86, 0x86, 0x93, 0x1d, 0x54, 0xf4, 0xf0, 0x4e, 0x04, 0x77, 0x7d, 0x90, 0xd0, 0x75, 0x03, 0x26, 0x0b, 0x22,
-    0x9e, 0x03, 0xe2, 0xee, 0xfb, 0x21, 0xc8, 0x68, 0x69, 0x31, 0xd4, 0x0d, 0x25, 0x82, 0x70, 0x48, 0x77, 0xd9, 0x0e, 0x43, 0x43,
-    0x49, 0x8e, 0x95, 0x69, 0xf8, 0x0f, 0x86, 0x5d, 0xf6, 0x45, 0xa3, 0xa5, 0x0f, 0x40, 0x80, 0x79, 0x3c, 0x13, 0x42, 0x43, 0xbe,
-    0xc8, 0xb4, 0x74, 0xa9, 0xe9, 0x90, 0x0f, 0x27, 0x82, 0x78, 0x39, 0x77, 0xd9, 0x16, 0x8e, 0x8f, 0x26, 0xd1, 0x7f, 0x80, 0xf8,
-    0x1c, 0xbb, 0xec, 0x8b, 0x47, 0x4b, 0xa2, 0x3c, 0x3e, 0xa8, 0xf6, 0x09, 0xe3, 0x2e, 0xfb, 0x22, 0xd1, 0xd1, 0x70, 0xfa, 0x2a,
-    0xb7, 0x80, 0x42, 0x0e, 0x5d, 0xf6, 0x45, 0xa3, 0xa5, 0x0b, 0x9e, 0x81, 0x07, 0x05, 0x01, 0x97, 0x7d, 0x91, 0x68, 0xea, 0x03,
-    0xed, 0x40, 0x81, 0x4c, 0x02, 0x11, 0x97, 0x7d, 0x94, 0x95, 0x44, 0xc2, 0x46, 0x4b, 0x8b, 0xf0, 0x43, 0x49, 0xdf, 0x64, 0xa9,
-    0x91, 0x71, 0x82, 0x87, 0xa0, 0x41, 0x81, 0x90, 0x20, 0x3b, 0xec, 0x9e, 0x51, 0x4f, 0xe1, 0x04, 0x35, 0x77, 0xd9, 0x28, 0x99,
-    0xe4, 0x23, 0xcd, 0x55, 0x4c, 0x0b, 0x81, 0xab, 0xbe, 0xca, 0x0d, 0x44, 0xbf, 0xe0, 0x31, 0x0f, 0x5d, 0xf6, 0x4b, 0x17, 0x32,
-    0x65, 0x3f, 0x80, 0xc4, 0x28, 0x77, 0xd9, 0x28, 0x65, 0x52, 0x88, 0xaa, 0xa6, 0xb1, 0x20, 0x58, 0xef, 0xb2, 0xa9, 0x94, 0x8a,
-    0x22, 0xaa, 0x9a, 0xc4, 0x60, 0x7a, 0xef, 0xb2, 0x44, 0xa1, 0x94, 0xd5, 0xf8, 0x41, 0x07, 0x2e, 0xfb, 0x25, 0xcf, 0x17, 0xb5,
-    0x33, 0x29, 0x70, 0x2f, 0x03, 0x97, 0x7d, 0x95, 0x12, 0x2e, 0x05, 0x9f, 0x82, 0xb0, 0x42, 0x77, 0xd9, 0x3c, 0xb9, 0x73, 0x28,
-    0x34, 0xc1, 0x59, 0x10, 0x3b, 0x2e, 0xef, 0xb2, 0x79, 0x72, 0xe6, 0x50, 0x51, 0x53, 0xe4, 0x40, 0xed, 0x27, 0x7d, 0x90, 0x2e,
-    0x06, 0xa1, 0xa8, 0x64, 0x1d, 0x81, 0x70, 0x65, 0x91, 0x03, 0xc0, 0x74, 0xef, 0xb2, 0x61, 0xa4, 0x6a, 0x97, 0x32, 0x60, 0x7a,
-    0x44, 0x0f, 0x00, 0x5a, 0xef, 0xb2, 0xd3, 0x05, 0x47, 0x44, 0x05, 0x79, 0x10, 0x03, 0xa0, 0xa3, 0x11, 0xec, 0xa0, 0x9a, 0x01,
-    0x70, 0x15, 0xaa, 0x3a, 0x0b, 0xf0, 0x4a, 0x01, 0xa3, 0xbe, 0xca, 0x48, 0x19, 0x30, 0x20, 0x28, 0xd3, 0x12, 0x20, 0x8e, 0x16,
-    0xc4, 0x91, 0xb6, 0x2f, 0x58, 0x49, 0xe3, 0xff, 0xfa, 0x4a, 0x34, 0x89, 0x53, 0xca, 0x17, 0x91, 0x04, 0x70, 0x5d, 0x12, 0x41,
-    0x9b, 0x17, 0xfc, 0x4f, 0x13, 0xff, 0x81, 0x90, 0xfb, 0x4f, 0x2a, 0xd4, 0xf2, 0x20, 0x8e, 0x18, 0xc4, 0x93, 0x98, 0xbd, 0x61,
-    0x07, 0x92, 0xff, 0xd2, 0x4d, 0xa8, 0x08, 0x09, 0x10, 0x2b, 0x22, 0x08, 0xe1, 0xac, 0x49, 0x39, 0x8b, 0xd6, 0x22, 0xf0, 0xb7,
-    0xff, 0x1c, 0x20, 0x4b, 0xf2, 0x20, 0x8e, 0x94, 0x49, 0x1b, 0x62, 0xf5, 0x84, 0x5e, 0x16, 0x7f, 0xe9, 0x14, 0x34, 0x59, 0x2f,
-    0x22, 0x08, 0xea, 0xc4, 0x90, 0xa1, 0x90, 0x6c, 0x50, 0xd1, 0x64, 0xbc, 0x88, 0x23, 0x91, 0xc4, 0x90, 0xa1, 0x92, 0xa8, 0x19,
-    0x34, 0x61, 0x5e, 0x44, 0x11, 0xc0, 0xda, 0x24, 0x8c, 0x32, 0x0d, 0x90, 0x32, 0x68, 0xc2, 0xbc, 0x88, 0x23, 0x80, 0x5e, 0x24,
-    0x8c, 0x32, 0x3e, 0x79, 0x81, 0xe0, 0xb8, 0x17, 0x78, 0x52, 0x26, 0x77, 0xd9, 0x30, 0x64, 0x74, 0xaa, 0x27, 0x8f, 0x29, 0x85,
-    0x24, 0xdd, 0xf6, 0x54, 0x6a, 0xa2, 0x03, 0x50, 0x54, 0xce, 0x2a, 0x01, 0x9b, 0xbe, 0xca, 0x8d, 0x55, 0x24, 0x0a, 0x25, 0xc2,
-    0xe1, 0x11, 0xdf, 0x64, 0x78, 0xc8, 0xe9, 0x09, 0xe8, 0x98, 0xe1, 0x48, 0x1c, 0xbb, 0xec, 0x95, 0x2e, 0x54, 0x2e, 0xfc, 0x29,
-    0x01, 0x9b, 0xbe, 0xc8, 0xea, 0x05, 0xd3, 0xfc, 0x54, 0x15, 0x3b, 0xec, 0x95, 0x2e, 0x57, 0xf8, 0xa8, 0x03, 0xae, 0xfb, 0x23,
-    0xc6, 0x47, 0x8c, 0xfe, 0x2a, 0x04, 0x37, 0x7d, 0x92, 0xa5, 0xca, 0x85, 0xff, 0x8a, 0x80, 0x48, 0xef, 0xb2, 0x54, 0xb9, 0x50,
-    0x5f, 0xf8, 0xa8, 0x1e, 0x3b, 0xec, 0xb4, 0x8d, 0x15, 0x7f, 0x15, 0x02, 0x3b, 0xbe, 0xc8, 0x80, 0xd4, 0x15, 0x32, 0x9a, 0x9c,
-    0x2e, 0x01, 0x43, 0xbe, 0xc9, 0x09, 0x93, 0x4a, 0x0e, 0xa0, 0x3e, 0x41, 0x82, 0xb0, 0xad, 0xdf, 0x64, 0x85, 0x41, 0x01, 0x12,
-    0xa3, 0xad, 0x23, 0xc1, 0x58, 0x5c, 0xef, 0xb2, 0x3c, 0xd5, 0x50, 0x0e, 0x80, 0xc7, 0xc1, 0x58, 0x3a, 0x77, 0xd9, 0x30, 0xd2,
-    0x35, 0x52, 0x20, 0x57, 0x82, 0xb0, 0x8c, 0xef, 0xb2, 0x0d, 0x47, 0x9a, 0xaa, 0x1f, 0x05, 0x80, 0x7a, 0xef, 0xb2, 0x82, 0x68,
-    0x14, 0x17, 0xaa, 0xde, 0x0b, 0x05, 0xee, 0xfb, 0x25, 0x4f, 0x54, 0xf4, 0xca, 0xf8, 0x2c, 0x05, 0x2e, 0xfb, 0x25, 0x4f, 0x54,
-    0xf1, 0x72, 0x7e, 0x0b, 0x00, 0x08, 0x77, 0xd9, 0x06, 0xa9, 0x1a, 0x55, 0x0f, 0x82, 0xc0, 0x06, 0x5d, 0xf6, 0x45, 0xc9, 0x97,
-    0x32, 0x65, 0x2f, 0x05, 0x80, 0xdd, 0xdf, 0x64, 0x1a, 0x8f, 0x35, 0x54, 0x02, 0xef, 0x05, 0x80, 0xf5, 0xdf, 0x64, 0x1a, 0xa4,
-    0x69, 0x54, 0x02, 0xef, 0x05, 0x80, 0x34, 0x77, 0xd9, 0x50, 0xf1, 0x01, 0xa9, 0x20, 0x53, 0x81, 0x10, 0x18, 0x3b, 0xec, 0xa8,
-    0xd5, 0x52, 0x40, 0xa7, 0x80, 0x4c, 0x01, 0xf7, 0x7d, 0x90, 0xec, 0x0b, 0x83, 0xe0, 0xdc, 0x5b, 0xf0, 0xac, 0x07, 0x8e, 0xfb,
-    0x20, 0x5e, 0x1b, 0x8e, 0x06, 0x42, 0xdf, 0x8a, 0xc1, 0x73, 0xbe, 0xc8, 0x66, 0x19, 0x06, 0x80, 0x62, 0x1c, 0x0e, 0x05, 0xdc,
-    0x56, 0x0c, 0x5d, 0xf6, 0x43, 0x30, 0xcc, 0x32, 0x0d, 0x00, 0xc4, 0x2e, 0xf1, 0x58, 0x7c, 0xef, 0xb2, 0x05, 0xe1, 0xb8, 0xe0,
-    0x64, 0x2d, 0x0b, 0xfc, 0x56, 0x93, 0xbe, 0xc8, 0x66, 0x19, 0x86, 0x41, 0xa0, 0x18, 0x85, 0xfe, 0x2b, 0x00, 0x35, 0xdf, 0x64,
-    0x1b, 0x07, 0x01, 0xd0, 0x37, 0x1c, 0x0c, 0x85, 0xbc, 0x56, 0x02, 0x27, 0x7d, 0x90, 0xe0, 0x39, 0x0e, 0x03, 0xa0, 0xe8, 0x73,
-    0xc5, 0x60, 0x76, 0xef, 0xb2, 0x1c, 0x07, 0x40, 0xdc, 0x70, 0x32, 0x16, 0xf8, 0xac, 0x01, 0x47, 0x7d, 0x90, 0xcc, 0x32, 0x0d,
-    0x00, 0xc4, 0x38, 0x1c, 0x0b, 0xf8, 0xac, 0x01, 0x77, 0x7d, 0x90, 0xcc, 0x33, 0x0c, 0x83, 0x40, 0x31, 0x05, 0xfe, 0x2b, 0x08,
-    0x8e, 0xfb, 0x21, 0xc0, 0x74, 0x0d, 0xc7, 0x03, 0x21, 0x68, 0x5f, 0xc5, 0x60, 0x9e, 0xef, 0xb2, 0x19, 0x86, 0x61, 0x90, 0x68,
-    0x06, 0x22, 0xcf, 0x05, 0xa1, 0x3b, 0xbe, 0xc8, 0x17, 0x87, 0x22, 0xd0, 0xe8, 0x38, 0x0e, 0x7c, 0x16, 0x85, 0x4e, 0xfb, 0x20,
-    0x5e, 0x1c, 0x87, 0x01, 0xd0, 0x74, 0x39, 0xe0, 0xb4, 0x09, 0x1d, 0xf6, 0x40, 0xb8, 0x3e, 0x0d, 0xc5, 0xbf, 0x82, 0xd0, 0x66,
-    0xef, 0xb2, 0x1c, 0x01, 0x70, 0x64, 0x1e, 0x81, 0x77, 0xc1, 0x69, 0xb7, 0x7d, 0x90, 0xd4, 0x37, 0x1c, 0x0c, 0x85, 0xbf, 0x05,
-    0xa4, 0x6e, 0xfb, 0x21, 0xa8, 0x64, 0x1a, 0x01, 0x88, 0x70, 0x38, 0x17, 0x70, 0x5a, 0x01, 0x97, 0x7d, 0x90, 0xd4, 0x33, 0x0c,
-    0x83, 0x40, 0x31, 0x0b, 0xbc, 0x16, 0x81, 0xdb, 0xbe, 0xc8, 0x6a, 0x1e, 0x0e, 0x05, 0xdf, 0x82, 0xd0, 0x04, 0x5d, 0xf6, 0x43,
-    0x50, 0xf0, 0x70, 0x2f, 0xfc, 0x16, 0x80, 0x26, 0xef, 0xb2, 0x1a, 0x86, 0xe3, 0x81, 0x90, 0xb4, 0x2f, 0xf0, 0x5a, 0x02, 0xe7,
-    0x7d, 0x90, 0xd4, 0x33, 0x0c, 0x83, 0x40, 0x31, 0x0b, 0xfc, 0x16, 0x80, 0xd9, 0xdf, 0x64, 0x35, 0x0f, 0x07, 0x01, 0x7f, 0xe0,
-    0xb4, 0x13, 0x5d, 0xf6, 0x43, 0x50, 0xf0, 0x70, 0x59, 0xf8, 0x2d, 0x04, 0xf7, 0x7d, 0x92, 0x86, 0x4a, 0x20, 0x5d, 0x3f, 0x0b,
-    0x81, 0xdb, 0xbe, 0xc8, 0x36, 0x0e, 0xc3, 0x20, 0xe4, 0x2e, 0xf8, 0x31, 0x51, 0xdf, 0x64, 0x1b, 0x07, 0x61, 0x90, 0x72, 0x17,
-    0xfc, 0x18, 0x80, 0x59, 0xdf, 0x64, 0x1b, 0x07, 0x61, 0x90, 0x72, 0x0b, 0xfe, 0x0c, 0x40, 0x0f, 0x77, 0xd9, 0x1c, 0x3d, 0x45,
-    0x3f, 0x02, 0xf0, 0x90, 0xef, 0xb2, 0x5d, 0xaa, 0x28, 0xa8, 0xf9, 0xe6, 0x30, 0x31, 0x04, 0xe7, 0x7d, 0x90, 0xe0, 0xa1, 0x75,
-    0x7f, 0x0e, 0x03, 0x57, 0x7d, 0x90, 0xe1, 0x36, 0xa0, 0xa9, 0x6f, 0x0e, 0x45, 0xae, 0xfb, 0x21, 0xc2, 0xad, 0x23, 0x54, 0xd2,
-    0x6d, 0xc3, 0xa1, 0x6b, 0xbe, 0xc8, 0x70, 0xa3, 0x47, 0x88, 0x5a, 0x61, 0x5c, 0x3a, 0x07, 0xae, 0xfb, 0x20, 0xd5, 0xa2, 0xe2,
-    0xe7, 0x8b, 0xda, 0x9e, 0x38, 0x0e, 0x5d, 0xf6, 0x43, 0x40, 0xcc, 0x74, 0x32, 0x0e, 0x03, 0x70, 0x01, 0xc1, 0xae, 0x50, 0x05,
-    0x8e, 0xfb, 0x21, 0xa0, 0x66, 0x3a, 0x1a, 0x81, 0x70, 0x36, 0x16, 0x8e, 0xe5, 0x01, 0x15, 0xdf, 0x64, 0x34, 0x0c, 0xc7, 0x41,
-    0xa8, 0x74, 0x3a, 0x0d, 0x85, 0xb9, 0x40, 0x3a, 0x77, 0xd9, 0x34, 0x91, 0x53, 0xd5, 0x6f, 0xa0, 0x08, 0xee, 0xfb, 0x2a, 0x35,
-    0x52, 0x31, 0x7f, 0xca, 0x85, 0x8e, 0xfb, 0x25, 0x8b, 0xa2, 0x92, 0x06, 0x93, 0x53, 0x2c, 0x2d, 0x77, 0xd9, 0x40, 0x74, 0xd1,
-    0x7f, 0xc0, 0x1e, 0x04, 0xb7, 0x7d, 0x94, 0x07, 0x4b, 0x97, 0xfc, 0x22, 0x5d, 0xf6, 0x50, 0x1d, 0x34, 0x9f, 0xe1, 0x10, 0x2a,
-    0x77, 0xd9, 0x40, 0x74, 0xba, 0x7f, 0x84, 0x40, 0xc5, 0xdf, 0x64, 0xa1, 0x92, 0x30, 0x5f, 0xf8, 0x44, 0x03, 0x57, 0x7d, 0x92,
-    0x86, 0x48, 0xc5, 0x9f, 0x84, 0x41, 0x05, 0xdf, 0x64, 0xa1, 0x92, 0x30, 0xbb, 0xf0, 0x88, 0x07, 0x6e, 0xfb, 0x25, 0x0c, 0x91,
-    0x85, 0xff, 0x84, 0x40, 0x7c, 0xef, 0xb2, 0x81, 0xea, 0xa0, 0x3e, 0x58, 0xf3, 0x59, 0x70, 0xf9, 0xdf, 0x65, 0x44, 0x10, 0x1f,
-    0x44, 0xa1, 0xe6, 0xb0, 0x1e, 0x08, 0x6e, 0xfb, 0x2a, 0x20, 0x81, 0x73, 0x25, 0x0f, 0x35, 0x80, 0xf0, 0x0e, 0x5d, 0xf6, 0x54,
-    0x41, 0x01, 0xf3, 0xd3, 0x07, 0x1c, 0x07, 0x80, 0x76, 0xef, 0xb2, 0xa2, 0x08, 0x24, 0x7a, 0x7e, 0x03, 0xc0, 0x3d, 0x77, 0xd9,
-    0x40, 0xc9, 0x0a, 0x47, 0xa0, 0xaf, 0x00, 0x0c, 0x27, 0x77, 0xd9, 0x54, 0xf2, 0x85, 0xda, 0x3c, 0xd3, 0x18, 0x08, 0x8a, 0xbb,
-    0xec, 0xb5, 0x33, 0xd4, 0x69, 0x0b, 0x4c, 0x60, 0x78, 0x0a, 0x2e, 0xfb, 0x25, 0x53, 0x3c, 0x5c, 0x42, 0xd3, 0x06, 0xb0, 0x3d,
-    0x04, 0x77, 0x7d, 0x92, 0xc5, 0xd1, 0x4b, 0xf0, 0x13, 0x80, 0x75, 0xdf, 0x64, 0xc9, 0x86, 0xa9, 0xf8, 0x28, 0x03, 0x97, 0x7d,
-    0x90, 0xcc, 0x1b, 0x8e, 0x87, 0xc1, 0xd0, 0x76, 0x1c, 0x07, 0x58, 0x02, 0x60, 0x24, 0x77, 0xd9, 0x0c, 0xc1, 0xb8, 0xe8, 0x7c,
-    0x1d, 0x07, 0x61, 0xb8, 0x35, 0xc0, 0x13, 0x01, 0xb3, 0xbe, 0xc8, 0x66, 0x3a, 0x1b, 0x03, 0x61, 0xd0, 0x6b, 0xe0, 0x0a, 0x80,
-    0x69, 0xdf, 0x64, 0xa2, 0x2a, 0xa9, 0xf8, 0x49, 0x07, 0x2e, 0xfb, 0x2a, 0x2a, 0x9a, 0x24, 0x0d, 0xeb, 0x59, 0x77, 0x7d, 0x93,
-    0x06, 0x55, 0x30, 0x7a, 0x69, 0x99, 0xc1, 0xf0, 0x19, 0x3b, 0xec, 0xa6, 0xd5, 0x15, 0x40, 0xc9, 0x56, 0xe0, 0xf9, 0xae, 0xfb,
-    0x21, 0xf9, 0x0a, 0x4a, 0x94, 0x2f, 0xd6, 0x23, 0x07, 0x4e, 0xfb, 0x21, 0xb0, 0x36, 0x16, 0x87, 0x41, 0xd8, 0x17, 0x78, 0x3f,
-    0x01, 0x43, 0xbe, 0xc8, 0x6c, 0x1d, 0x07, 0x7f, 0xc1, 0xf8, 0x00, 0xee, 0xfb, 0x21, 0xb0, 0x68, 0x1c, 0x01, 0x70, 0x64, 0x1d,
-    0xf8, 0x3e, 0x08, 0x8e, 0xfb, 0x21, 0xb0, 0x7c, 0x1d, 0x07, 0x7f, 0x83, 0xe0, 0x0b, 0x3b, 0xec, 0xa6, 0xd5, 0x15, 0x2e, 0x64,
-    0xca, 0x58, 0x3e, 0x08, 0x4e, 0xfb, 0x24, 0x27, 0xa8, 0x40, 0x17, 0x99, 0x20, 0xe0, 0xf8, 0x01, 0xa7, 0x7d, 0x92, 0xed, 0x51,
-    0x41, 0x67, 0xc1, 0xf0, 0x0b, 0x9d, 0xf6, 0x48, 0x4c, 0xa6, 0xd1, 0x67, 0xc1, 0xf0, 0x3c, 0x77, 0xd9, 0x0d, 0x83, 0x40, 0xe0,
-    0x3b, 0xfc, 0x1f, 0x04, 0x57, 0x7d, 0x94, 0xda, 0xa2, 0xa8, 0x17, 0x4f, 0x83, 0xf0, 0xcd, 0xdf, 0x64, 0x85, 0x42, 0x00, 0xbd,
-    0x02, 0xe9, 0xf0, 0x7e, 0x18, 0x3b, 0xec, 0x97, 0x6a, 0x8a, 0x0b, 0x10, 0x2e, 0x9e, 0x0f, 0xc0, 0xa5, 0xdf, 0x64, 0x36, 0x40,
-    0xba, 0x63, 0xa2, 0xcf, 0x07, 0xe2, 0x77, 0x7d, 0x94, 0xda, 0xa2, 0xa3, 0xa6, 0x53, 0xe0, 0xfc, 0x0f, 0x5d, 0xf6, 0x48, 0x4f,
-    0x50, 0x80, 0x2f, 0x32, 0x9f, 0x07, 0xe0, 0x74, 0xef, 0xb2, 0x5d, 0xaa, 0x28, 0x2c, 0x32, 0x9f, 0x07, 0xe0, 0x01, 0x5d, 0xf6,
-    0x43, 0x63, 0x29, 0x8e, 0x8b, 0x3e, 0x0f, 0xd3, 0x77, 0xd9, 0x51, 0x53, 0x25, 0xcc, 0x99, 0x4b, 0x84, 0x01, 0x3b, 0xbe, 0xca,
-    0x8a, 0x99, 0x0b, 0xcc, 0x90, 0x78, 0x40, 0x11, 0xbb, 0xec, 0xa8, 0xa8, 0xb0, 0xbb, 0x54, 0x53, 0x83, 0xf0, 0x1f, 0xbb, 0xec,
-    0xa8, 0xa9, 0x94, 0xda, 0x2c, 0xf0, 0x7e, 0x10, 0xdd, 0xf6, 0x4c, 0x2a, 0xd2, 0x24, 0x95, 0xf0, 0x80, 0x01, 0x03, 0xbe, 0xc9,
-    0x86, 0x91, 0x26, 0x82, 0xbe, 0x10, 0x0d, 0xbb, 0xec, 0xa0, 0x64, 0xab, 0x7f, 0x01, 0x91, 0x27, 0x7d, 0x92, 0x25, 0x02, 0xef,
-    0xe1, 0x04, 0x10, 0x5d, 0xf6, 0x48, 0x94, 0x69, 0x1a, 0x1f, 0x01, 0x98, 0x28, 0xbb, 0xec, 0x91, 0x28, 0xab, 0x48, 0xd0, 0xf0,
-    0x4a, 0x16, 0x3b, 0xec, 0x86, 0x69, 0xb4, 0xf2, 0x01, 0xd4, 0x0c, 0x48, 0x84, 0x31, 0xb7, 0x7d, 0x90, 0xd0, 0x3b, 0x0f, 0xbf,
-    0x22, 0x07, 0x62, 0xc7, 0x7d, 0x90, 0xd0, 0x74, 0x1b, 0x07, 0x61, 0xf7, 0x91, 0x03, 0xb0, 0x66, 0xef, 0xb2, 0x1a, 0x0e, 0x9e,
-    0x9a, 0x66, 0x45, 0xca, 0x60, 0x3e, 0x0e, 0x1d, 0xf6, 0x43, 0x56, 0xa8, 0xaf, 0xc8, 0x81, 0xe0, 0x8a, 0xef, 0xb2, 0x1b, 0x22,
-    0x99, 0x0f, 0xec, 0xbb, 0xec, 0x81, 0x82, 0x05, 0xd1, 0x48, 0xba, 0x26, 0x30, 0x19, 0x06, 0xae, 0xfb, 0x20, 0x60, 0x54, 0x84,
-    0xf0, 0xfc, 0xca, 0x79, 0x10, 0x88, 0x07, 0xee, 0xfb, 0x20, 0x60, 0x54, 0x85, 0x13, 0x06, 0x53, 0xc8, 0x84, 0x40, 0x3e, 0x77,
-    0xd9, 0x03, 0x02, 0x13, 0xc3, 0xf3, 0x29, 0xf8, 0x0c, 0x4d, 0xbb, 0xec, 0x81, 0x81, 0x0a, 0x26, 0x0c, 0xa7, 0xe0, 0x31, 0x01,
-    0x13, 0xbe, 0xc8, 0x18, 0x26, 0xd4, 0x15, 0xf8, 0x48, 0x08, 0xce, 0xfb, 0x20, 0x60, 0xa3, 0x55, 0x2a, 0x54, 0xfe, 0x00, 0xc8,
-    0x1f, 0xbb, 0xec, 0x81, 0x82, 0x8d, 0x54, 0xba, 0x26, 0x1b, 0x82, 0x38, 0x05, 0xdd, 0xf6, 0x40, 0xc1, 0x42, 0xe6, 0x52, 0x69,
-    0x86, 0xc8, 0x84, 0x40, 0x40, 0x77, 0xd9, 0x03, 0x02, 0x32, 0x13, 0xc3, 0xf3, 0x29, 0xe4, 0x42, 0x20, 0x0a, 0x9d, 0xf6, 0x43,
-    0x90, 0xec, 0x2e, 0x1d, 0x0b, 0xc3, 0xee, 0x44, 0x0e, 0xc1, 0x83, 0xbe, 0xc8, 0x72, 0xd3, 0x06, 0x87, 0x4b, 0xb4, 0xc4, 0x88,
-    0x44, 0x1f, 0x77, 0xd9, 0x0e, 0x5a, 0x60, 0xd0, 0xe9, 0x0a, 0x79, 0x10, 0x88, 0x93, 0xbe, 0xc8, 0x72, 0xd3, 0x06, 0x87, 0x54,
-    0x4f, 0x22, 0x11, 0x08, 0xdd, 0xf6, 0x43, 0x96, 0x98, 0x34, 0x3a, 0xa9, 0xe5, 0x24, 0x42, 0x20, 0x86, 0xef, 0xb2, 0x1c, 0xb4,
-    0xc1, 0xa1, 0xd5, 0x53, 0xc8, 0x84, 0x41, 0x05, 0xdf, 0x64, 0x39, 0x69, 0x83, 0x43, 0xa2, 0xe4, 0x4a, 0xc8, 0x84, 0x42, 0xee,
-    0xfb, 0x21, 0xcb, 0x4c, 0x1a, 0x28, 0x7a, 0x68, 0x48, 0x84, 0x40, 0x72, 0xef, 0xb2, 0x1c, 0xb4, 0xc1, 0xa2, 0x87, 0xa6, 0x66,
-    0x44, 0x22, 0x00, 0x71, 0xdf, 0x64, 0x39, 0x1e, 0x69, 0x86, 0xa6, 0x7a, 0xb2, 0x21, 0x10, 0x1d, 0x3b, 0xec, 0x83, 0x50, 0xc8,
-    0x3d, 0x0c, 0x83, 0x70, 0xe3, 0x91, 0x03, 0xc0, 0x16, 0x3b, 0xec, 0x83, 0x50, 0xe0, 0x3a, 0x06, 0xa0, 0x03, 0x83, 0x6e, 0x44,
-    0x0f, 0x01, 0x05, 0xdf, 0x64, 0x3a, 0x1c, 0x0d, 0x03, 0xb1, 0xd0, 0xdf, 0x91, 0x03, 0xb0, 0xd5, 0xdf, 0x64, 0x3a, 0x1f, 0x69,
-    0xe5, 0x7c, 0x88, 0x05, 0x60, 0xa2, 0xef, 0xb2, 0x1d, 0x2a, 0x7a, 0x25, 0xfc, 0x88, 0x1e, 0xdd, 0xf6, 0x47, 0x15, 0x0d, 0x0d,
-    0x26, 0x9b, 0x4c, 0x48, 0x81, 0xe0, 0x3e, 0x77, 0xd9, 0x0e, 0xcb, 0x9e, 0x2f, 0x6a, 0x66, 0x52, 0xc3, 0x61, 0x6b, 0xbe, 0xc8,
-    0x36, 0x6a, 0xa6, 0x92, 0x16, 0xa6, 0xdc, 0x06, 0xe3, 0xee, 0xfb, 0x20, 0xd9, 0xaa, 0x9a, 0x48, 0x3d, 0x3c, 0xbe, 0x03, 0x51,
-    0x15, 0xdf, 0x64, 0x1b, 0x26, 0x87, 0xe4, 0x40, 0x29, 0x11, 0x5d, 0xf6, 0x45, 0xa1, 0x90, 0x36, 0xfe, 0x21, 0x02, 0x63, 0xbe,
-    0xc8, 0xb4, 0x74, 0x3b, 0x02, 0xf0, 0xec, 0x3e, 0xe4, 0x40, 0xec, 0x31, 0x77, 0xd9, 0x0f, 0x83, 0x70, 0xe8, 0x75, 0x13, 0x0a,
-    0xf0, 0xd0, 0x0d, 0x9d, 0xf6, 0x43, 0xe0, 0xfb, 0xf9, 0x10, 0x3b, 0x03, 0x47, 0x7d, 0x91, 0xd0, 0xbc, 0x0a, 0xc1, 0x80, 0x5c,
-    0x51, 0x15, 0x70, 0x1e, 0x9c, 0xef, 0xb2, 0x3a, 0x17, 0x81, 0x58, 0x30, 0x0b, 0x92, 0x3d, 0x3c, 0x07, 0xa4, 0x9d, 0xf6, 0x47,
-    0x4b, 0xa2, 0x61, 0xbf, 0x04, 0x81, 0x0d, 0xdf, 0x64, 0xf2, 0x86, 0x44, 0x06, 0x97, 0xe4, 0x40, 0xf4, 0x93, 0xbe, 0xc9, 0xe8,
-    0x22, 0x79, 0x45, 0x05, 0xf9, 0x10, 0x88, 0x1d, 0xbb, 0xec, 0x9e, 0x82, 0x27, 0x8f, 0x35, 0x54, 0x30, 0x56, 0xd7, 0x7d, 0x93,
-    0xd0, 0x44, 0xf4, 0x8f, 0x17, 0x29, 0x81, 0xc0, 0x25, 0xbb, 0xec, 0x9e, 0x82, 0x27, 0xa4, 0x64, 0x81, 0xb8, 0x84, 0x08, 0x2e,
-    0xfb, 0x27, 0xa0, 0x89, 0xe9, 0x1a, 0x55, 0x0c, 0x15, 0x86, 0xae, 0xfb, 0x27, 0xa0, 0x89, 0xe9, 0x91, 0xa2, 0x63, 0x10, 0x81,
-    0x41, 0xdf, 0x64, 0xf4, 0x11, 0x3d, 0x45, 0x4c, 0xa5, 0x22, 0x11, 0x01, 0xb3, 0xbe, 0xc9, 0xe8, 0x22, 0x7a, 0xa9, 0xa2, 0x63,
-    0x03, 0x70, 0x49, 0x77, 0xd9, 0x3d, 0x04, 0x4c, 0xa0, 0xa9, 0x7e, 0x07, 0x01, 0x19, 0xdf, 0x64, 0xf2, 0xa5, 0x43, 0xb6, 0xa0,
-    0xaf, 0x06, 0xe1, 0x8b, 0xbe, 0xc9, 0xe5, 0x4a, 0x99, 0x28, 0xa7, 0x83, 0x65, 0x5d, 0xf6, 0x4f, 0x2e, 0x5c, 0xca, 0x1f, 0x01,
-    0x40, 0x3d, 0x77, 0xd9, 0x3c, 0xb9, 0x73, 0x28, 0x14, 0xe4, 0x40, 0xec, 0x7d, 0xdf, 0x64, 0xf2, 0xe2, 0xe3, 0xc5, 0xea, 0x78,
-    0x2f, 0x2e, 0xef, 0xb2, 0x79, 0xa5, 0x4c, 0x85, 0xdf, 0x1e, 0xbb, 0xec, 0x9e, 0x69, 0x53, 0x21, 0x7f, 0xc4, 0x30, 0x4e, 0x77,
-    0xd9, 0x3c, 0xd2, 0xa6, 0x45, 0x9f, 0x3c, 0x14, 0x5d, 0xf6, 0x4f, 0x34, 0x46, 0x17, 0x22, 0x61, 0x01, 0xfc, 0xc0, 0x07, 0x9d,
-    0xf6, 0x4f, 0x34, 0x46, 0x17, 0x19, 0x49, 0x4c, 0x88, 0x1e, 0x04, 0x67, 0x7d, 0x93, 0xcd, 0x11, 0x85, 0xc6, 0x4d, 0x2a, 0x86,
-    0x60, 0x5a, 0xef, 0xb2, 0x79, 0xa2, 0x30, 0xbd, 0x13, 0x08, 0x0f, 0xe6, 0x02, 0x23, 0xbe, 0xc9, 0xe6, 0x88, 0xc2, 0xf3, 0x29,
-    0x29, 0x91, 0x03, 0xc0, 0x90, 0xef, 0xb2, 0x79, 0xa2, 0x30, 0xbc, 0xc9, 0xa5, 0x50, 0xcc, 0x01, 0xeb, 0xbe, 0xc9, 0x43, 0xcb,
-    0x97, 0x32, 0x87, 0x83, 0xc0, 0x3b, 0x77, 0xd9, 0x28, 0x54, 0x2e, 0xfc, 0x88, 0x1d, 0x80, 0x14, 0xef, 0xb2, 0x50, 0xa8, 0x5f,
-    0xf9, 0x10, 0x3b, 0x00, 0x2d, 0xdf, 0x64, 0xa1, 0x73, 0xcc, 0x10, 0x7c, 0x0b, 0x09, 0xbb, 0xec, 0x94, 0x2e, 0x65, 0x02, 0x06,
-    0x95, 0xc8, 0x84, 0x31, 0x3b, 0xbe, 0xc9, 0x43, 0x27, 0xa6, 0x57, 0xc8, 0x81, 0xd8, 0x2e, 0x77, 0xd9, 0x28, 0x64, 0xf4, 0xca,
-    0x85, 0xdc, 0x88, 0x1d, 0x85, 0xce, 0xfb, 0x25, 0x0c, 0x9e, 0x99, 0x50, 0xbf, 0x91, 0x03, 0xb0, 0xbd, 0xdf, 0x64, 0xa1, 0x92,
-    0x34, 0x0d, 0x26, 0xa9, 0xf8, 0x0d, 0x6e, 0xfb, 0x25, 0x13, 0x3c, 0x87, 0xf0, 0x24, 0x0a, 0x9d, 0xf6, 0x4a, 0x26, 0x79, 0x0a,
-    0x46, 0x95, 0x53, 0x02, 0xe0, 0x3f, 0x77, 0xd9, 0x28, 0xaa, 0x24, 0x2d, 0xf2, 0x21, 0x0c, 0x65, 0xdf, 0x64, 0xa1, 0xa4, 0x4b,
-    0x95, 0x1d, 0x2a, 0x7e, 0x00, 0xf0, 0x3e, 0x77, 0xd9, 0x28, 0x69, 0x33, 0x01, 0x77, 0x91, 0x03, 0xb1, 0x5b, 0xbe, 0xca, 0x07,
-    0x97, 0x40, 0xc9, 0x45, 0x38, 0x01, 0x60, 0x74, 0xef, 0xb2, 0x81, 0xe9, 0x28, 0x3a, 0x8a, 0x06, 0x70, 0x4d, 0x01, 0x6b, 0xbe,
-    0xca, 0x07, 0xa4, 0xa0, 0xe9, 0x73, 0x28, 0x60, 0x9a, 0x5d, 0xdf, 0x65, 0x03, 0xd2, 0x50, 0x74, 0x85, 0x41, 0xec, 0x04, 0x22,
-    0xc7, 0x7d, 0x94, 0x0f, 0x49, 0x41, 0xd4, 0x95, 0xe0, 0x9a, 0x08, 0xee, 0xfb, 0x28, 0x14, 0x34, 0x59, 0x4f, 0x22, 0x07, 0x6a,
-    0xbb, 0xec, 0xa0, 0x64, 0xb9, 0x94, 0xfe, 0x0c, 0x0a, 0x3b, 0xec, 0xa0, 0x64, 0xb9, 0x94, 0xd1, 0x30, 0x8e, 0x06, 0xcc, 0x77,
-    0xd9, 0x40, 0xc9, 0x82, 0xc8, 0xa6, 0x43, 0xc0, 0x7a, 0x18, 0xbb, 0xec, 0xa0, 0x92, 0x48, 0x14, 0x53, 0xc2, 0xe1, 0x05, 0xdf,
-    0x65, 0x04, 0xda, 0x3d, 0xf8, 0x60, 0x16, 0xbb, 0xec, 0xa0, 0xaa, 0x65, 0xfe, 0x44, 0x0e, 0xc0, 0xd5, 0xdf, 0x65, 0x03, 0x49,
-    0xfe, 0x44, 0x21, 0xdd, 0xf6, 0x50, 0x34, 0x9a, 0x85, 0x0b, 0xab, 0xca, 0x0f, 0xbb, 0xec, 0xa0, 0x69, 0x35, 0x0a, 0x10, 0xf2,
-    0x20, 0x7a, 0x15, 0x3b, 0xec, 0x95, 0x41, 0xa9, 0xb4, 0x7b, 0xc3, 0x01, 0xf7, 0x7d, 0x92, 0xad, 0x2e, 0xa9, 0xfe, 0x44, 0x02,
-    0xb1, 0x15, 0xdf, 0x64, 0xab, 0x44, 0x05, 0x4f, 0x29, 0xe0, 0x35, 0x10, 0xdd, 0xf6, 0x4a, 0xa2, 0xa2, 0x03, 0xa6, 0x4c, 0x64,
-    0x42, 0x18, 0xa9, 0xdf, 0x64, 0xaa, 0x2a, 0x20, 0x64, 0xc1, 0x86, 0xe6, 0x43, 0x87, 0x7d, 0x92, 0xa8, 0xa8, 0x92, 0x8a, 0x9e,
-    0xac, 0x88, 0x44, 0x2a, 0xef, 0xb2, 0x54, 0xc8, 0xe9, 0x09, 0x93, 0x4a, 0x37, 0x17, 0x83, 0xe7, 0x7d, 0x92, 0xa9, 0x9e, 0x2e,
-    0xfc, 0x05, 0xe0, 0x6c, 0xef, 0xb2, 0x55, 0x33, 0xc5, 0xc3, 0xa2, 0x03, 0x53, 0xc0, 0x8c, 0xbb, 0xbe, 0xc9, 0x54, 0xcf, 0x17,
-    0x20, 0x3e, 0x7a, 0x78, 0x2f, 0x03, 0x77, 0x7d, 0x92, 0xa9, 0x9e, 0x2e, 0x2e, 0x79, 0x46, 0xe2, 0x00, 0x22, 0xbb, 0xec, 0x95,
-    0x4c, 0xf1, 0x72, 0x47, 0x95, 0x53, 0x10, 0x01, 0x83, 0xbe, 0xc9, 0x55, 0x53, 0xfc, 0x88, 0x1e, 0x08, 0x6e, 0xfb, 0x25, 0x55,
-    0x4d, 0x05, 0x4b, 0xf8, 0x1c, 0x0b, 0x1d, 0xf6, 0x4a, 0x9a, 0x3d, 0x35, 0x7e, 0x04, 0xa3, 0x2e, 0xfb, 0x2d, 0x2a, 0x3a, 0xa2,
-    0x25, 0x5a, 0xa6, 0x00, 0xc0, 0x33, 0x77, 0xd9, 0x69, 0x53, 0xd5, 0x3f, 0x88, 0x20, 0x1c, 0x81, 0xf3, 0xbe, 0xcb, 0x51, 0x3c,
-    0x75, 0x13, 0x08, 0xab, 0x80, 0x04, 0x00, 0x14, 0xef, 0xb2, 0xd4, 0xd3, 0x0b, 0xbf, 0x06, 0x40, 0xb9, 0xdf, 0x65, 0xaa, 0xb4,
-    0x8d, 0x5e, 0x20, 0x88, 0x30, 0x3e, 0x77, 0xd9, 0x2c, 0x7a, 0x25, 0xfe, 0x44, 0x02, 0xb0, 0x50, 0x77, 0xd9, 0x2c, 0x8a, 0x6a,
-    0x2a, 0x3a, 0x5d, 0xbe, 0xef, 0xb2, 0x58, 0xba, 0x28, 0x10, 0x35, 0x3e, 0x0c, 0x00, 0x36, 0xef, 0xb2, 0x59, 0x36, 0xb4, 0x3a,
-    0x2e, 0x8a, 0x99, 0xe0, 0x7c, 0xef, 0xb2, 0x59, 0x36, 0xb4, 0xba, 0x2a, 0x2b, 0x22, 0x07, 0x80, 0x72, 0xef, 0xb2, 0x59, 0x36,
-    0xb4, 0xc9, 0x45, 0x38, 0x37, 0x09, 0x0e, 0xfb, 0x23, 0xc8, 0x1e, 0x5c, 0xb9, 0x94, 0x38, 0x1c, 0x88, 0x6e, 0xfb, 0x23, 0xc8,
-    0x16, 0x4d, 0xad, 0x3c, 0xbe, 0x07, 0x20, 0x34, 0x77, 0xd9, 0x1e, 0x6a, 0xa6, 0x0d, 0x10, 0xf8, 0xa0, 0xc3, 0xbe, 0xc8, 0xfa,
-    0x23, 0xc3, 0xe5, 0xd1, 0x1e, 0x1f, 0xc1, 0xc0, 0x23, 0xbb, 0xec, 0x8f, 0x99, 0x51, 0x51, 0xe6, 0xaa, 0x86, 0x64, 0x4e, 0xef,
-    0xb2, 0x3e, 0x65, 0x45, 0x52, 0x34, 0xaa, 0x19, 0x80, 0x4e, 0x77, 0xd9, 0x1f, 0x32, 0xa2, 0xaa, 0x2a, 0x41, 0x91, 0x02, 0x38,
-    0x9d, 0xdf, 0x62, 0xff, 0x58, 0x87, 0x2b, 0xfe, 0x89, 0x84, 0x55, 0x1d, 0x49, 0x02, 0x98, 0x42, 0x15, 0x3b, 0xec, 0xa2, 0x61,
-    0x15, 0x4a, 0x26, 0x79, 0x0e, 0x1a, 0x82, 0x03, 0xbe, 0xca, 0x26, 0x15, 0x40, 0xc2, 0xbc, 0x88, 0x1d, 0x87, 0xce, 0xfb, 0x28,
-    0x98, 0x55, 0xa9, 0xa6, 0x69, 0x29, 0x10, 0x3c, 0x03, 0xa7, 0x7d, 0x94, 0x4c, 0x2a, 0x9f, 0xf0, 0x72, 0xef, 0xb2, 0x8a, 0x8a,
-    0x9e, 0x81, 0x07, 0x22, 0x04, 0x31, 0x15, 0xdf, 0x62, 0xff, 0x5e, 0x18, 0xff, 0x87, 0xe5, 0xd1, 0x30, 0xd5, 0x4c, 0xf0, 0x46,
-    0x03, 0x77, 0x7d, 0x90, 0xfc, 0x84, 0xc8, 0x80, 0xdf, 0x82, 0x30, 0x22, 0x3b, 0xec, 0x87, 0xe9, 0x19, 0x44, 0xc2, 0xbe, 0x00,
-    0x18, 0x23, 0x3b, 0xec, 0x87, 0xea, 0x34, 0x78, 0x85, 0xa6, 0x15, 0xc0, 0x60, 0x2d, 0x77, 0xd9, 0x0f, 0xd5, 0x69, 0x1a, 0xa6,
-    0x93, 0x6e, 0x07, 0xe0, 0x82, 0xef, 0xb2, 0x40, 0x50, 0xaa, 0x03, 0xe7, 0xa7, 0xc0, 0x3e, 0x49, 0xdf, 0x64, 0x80, 0xa1, 0x55,
-    0x15, 0x3d, 0x53, 0x52, 0x21, 0x0c, 0x2c, 0x77, 0xd9, 0x20, 0x40, 0x7c, 0x81, 0x01, 0xf3, 0xd3, 0xc1, 0xd0, 0x54, 0xef, 0xb2,
-    0x40, 0xd1, 0x7a, 0x8a, 0x90, 0x72, 0x20, 0x48, 0x07, 0xce, 0xfb, 0x17, 0xfa, 0xc4, 0x39, 0x5f, 0xf2, 0x06, 0x8b, 0xd5, 0x3c,
-    0xa7, 0x80, 0xf4, 0x0e, 0x9d, 0xf6, 0x48, 0x1a, 0x2f, 0x54, 0xf2, 0x8a, 0x37, 0x01, 0x00, 0x46, 0x77, 0xd9, 0x20, 0x44, 0xb9,
-    0x71, 0xd4, 0xda, 0x84, 0x88, 0x1e, 0x81, 0x7b, 0xbe, 0xc9, 0x02, 0x49, 0xb4, 0xf2, 0xa2, 0xfe, 0x21, 0xa4, 0xef, 0xb2, 0x5c,
-    0x75, 0x13, 0x0a, 0x99, 0x1d, 0x23, 0xc8, 0x05, 0x0e, 0xfb, 0x25, 0xca, 0x1e, 0x81, 0x07, 0x91, 0x03, 0xc0, 0x3b, 0x77, 0xd9,
-    0x2e, 0x8a, 0xa3, 0xa5, 0x53, 0x3c, 0x5d, 0x83, 0x00, 0x88, 0xef, 0xb2, 0x5c, 0xc9, 0xe5, 0x7f, 0x05, 0x60, 0x34, 0x77, 0xd9,
-    0x2e, 0x64, 0x79, 0x23, 0x29, 0xaa, 0xa6, 0x5c, 0x7d, 0xdf, 0x64, 0xba, 0x7f, 0xc8, 0x81, 0xe8, 0xcb, 0xbe, 0xc9, 0x75, 0x07,
-    0x4a, 0x28, 0xa7, 0x58, 0x90, 0x24, 0x3b, 0xec, 0x90, 0x9e, 0x46, 0xab, 0x48, 0x52, 0xc8, 0x81, 0xd8, 0x5a, 0xef, 0xb2, 0x42,
-    0x3e, 0x88, 0xf0, 0xf9, 0x74, 0x47, 0xa4, 0x42, 0x20, 0x62, 0xef, 0xb2, 0x42, 0x40, 0x3a, 0xa2, 0x25, 0x5a, 0xa6, 0x08, 0x85,
-    0xdd, 0xf6, 0x48, 0x4c, 0x9a, 0x51, 0xaa, 0xb5, 0x4c, 0x2f, 0x0c, 0x5d, 0xf6, 0x48, 0x54, 0x10, 0x0a, 0x8e, 0x8f, 0x98, 0x2b,
-    0x82, 0xb1, 0x23, 0xbe, 0xc9, 0x0a, 0x82, 0x02, 0x25, 0x54, 0x54, 0x82, 0x44, 0x00, 0xd8, 0xe3, 0xbe, 0xc5, 0xfe, 0xb1, 0x0e,
-    0x57, 0xfc, 0x84, 0xd1, 0x19, 0x54, 0x53, 0xe4, 0x42, 0x20, 0x1a, 0x3b, 0xec, 0x98, 0x68, 0xb8, 0x88, 0xd1, 0xe5, 0x79, 0x40,
-    0x8c, 0xef, 0xb2, 0x60, 0xd1, 0x08, 0xea, 0x28, 0x19, 0x31, 0x82, 0x68, 0x1e, 0xbb, 0xec, 0x98, 0x34, 0x42, 0x3a, 0x5c, 0xca,
-    0x04, 0x18, 0x26, 0x8b, 0x1d, 0xf6, 0x4c, 0x1a, 0x21, 0x1d, 0x21, 0x50, 0x7b, 0x80, 0x84, 0x10, 0x1d, 0xf6, 0x4c, 0x1a, 0x21,
-    0x1d, 0x49, 0x5f, 0x04, 0xd0, 0x48, 0x77, 0xd9, 0x30, 0x68, 0x84, 0xb9, 0x94, 0x08, 0x15, 0xc1, 0x34, 0x2d, 0x77, 0xd9, 0x32,
-    0x51, 0x4f, 0xc8, 0x80, 0x56, 0x09, 0xce, 0xfb, 0x26, 0x4a, 0x28, 0x81, 0x76, 0x9e, 0x63, 0x03, 0x40, 0x37, 0x77, 0xd9, 0x32,
-    0x5c, 0xaf, 0xe4, 0x40, 0xf4, 0x58, 0xef, 0xb2, 0x64, 0xb9, 0x52, 0x3f, 0x91, 0x03, 0xb0, 0x2f, 0x77, 0xd9, 0x32, 0x5c, 0xa8,
-    0xbf, 0xe4, 0x40, 0xec, 0x38, 0x77, 0xd9, 0x32, 0x69, 0x55, 0x15, 0x20, 0xe4, 0x40, 0x0c, 0x81, 0xdb, 0xbe, 0xc5, 0xfe, 0xb1,
-    0x0e, 0x57, 0xfc, 0xc8, 0xb8, 0xc7, 0xf2, 0x20, 0x34, 0x77, 0xd9, 0x48, 0xa1, 0x95, 0x55, 0x32, 0x43, 0x91, 0x08, 0x80, 0x74,
-    0xef, 0xb2, 0x91, 0x66, 0x95, 0x45, 0x5f, 0x00, 0xd8, 0xfb, 0xbe, 0xca, 0x45, 0x9a, 0x55, 0x15, 0x4b, 0xf0, 0x1e, 0x8a, 0x9d,
-    0xf6, 0x52, 0x2c, 0x89, 0x72, 0xff, 0x04, 0x30, 0x3a, 0x77, 0xd9, 0x48, 0xb1, 0x74, 0xda, 0x82, 0xb9, 0x10, 0x88, 0x1a, 0xbb,
-    0xec, 0xa4, 0x89, 0x90, 0xec, 0x3e, 0x1d, 0x44, 0xc6, 0x1a, 0x04, 0x67, 0x7d, 0x94, 0x91, 0x32, 0x2d, 0x0f, 0x87, 0x51, 0x31,
-    0x86, 0x80, 0x90, 0xef, 0xb2, 0x92, 0x26, 0x47, 0x51, 0x30, 0xaf, 0x0c, 0xc2, 0xc7, 0x7d, 0x94, 0x91, 0x32, 0x3a, 0x42, 0xa3,
-    0x78, 0x16, 0x07, 0x6e, 0xfb, 0x29, 0x22, 0x64, 0x7d, 0x13, 0x0a, 0xf1, 0x04, 0x8d, 0xdf, 0x65, 0x24, 0x4c, 0x8f, 0xa8, 0xa9,
-    0x06, 0x44, 0x00, 0x20, 0x17, 0x3b, 0xec, 0x5f, 0xeb, 0x10, 0xe5, 0x7f, 0xd2, 0x44, 0xc9, 0x75, 0x15, 0x20, 0xc8, 0x80, 0x04,
-    0x82, 0x5b, 0xbe, 0xc5, 0xfe, 0xb1, 0x0e, 0x57, 0xfd, 0x22, 0xe7, 0xa0, 0xdf, 0x80, 0x30, 0x02, 0xd7, 0x7d, 0x94, 0x8c, 0xa2,
-    0x61, 0x5f, 0x81, 0xf1, 0xce, 0xfb, 0x29, 0x26, 0x80, 0x80, 0x80, 0xf9, 0xe9, 0xe6, 0x00, 0xb5, 0xdf, 0x65, 0x24, 0xcc, 0x2a,
-    0x65, 0x25, 0x32, 0x20, 0x78, 0x09, 0x8e, 0xfb, 0x29, 0x26, 0x64, 0xa3, 0x47, 0x51, 0x33, 0x90, 0x01, 0xbb, 0xbe, 0xca, 0x49,
-    0x99, 0x43, 0xf8, 0x1b, 0xbb, 0xec, 0xa4, 0x99, 0x94, 0x14, 0x54, 0x83, 0x22, 0x04, 0x51, 0x15, 0xdf, 0x62, 0xff, 0x58, 0x87,
-    0x2b, 0xfe, 0x91, 0xa5, 0x07, 0xc3, 0xaf, 0x9e, 0x0b, 0x9d, 0xf6, 0x48, 0x92, 0x34, 0xaa, 0x8a, 0xa7, 0xd2, 0x03, 0x67, 0x7d,
-    0x92, 0x25, 0x10, 0x21, 0x4b, 0xc8, 0x84, 0x40, 0x7a, 0xef, 0xb2, 0x44, 0xa3, 0x54, 0xf9, 0x10, 0x88, 0x02, 0xc7, 0x7d, 0x92,
-    0x25, 0x1a, 0xa9, 0x44, 0xcf, 0x21, 0xc0, 0xb8, 0x22, 0x3b, 0xec, 0xa6, 0x79, 0x0f, 0xf0, 0xac, 0x23, 0x3b, 0xec, 0xa6, 0x79,
-    0x09, 0x62, 0xe7, 0x8f, 0x64, 0x40, 0xec, 0x2c, 0x77, 0xd9, 0x4d, 0x01, 0x01, 0x01, 0xf3, 0xd3, 0xe4, 0x21, 0x2b, 0xbe, 0xca,
-    0x68, 0x08, 0x08, 0x19, 0x34, 0x61, 0x59, 0x10, 0x3d, 0x06, 0x6e, 0xfb, 0x29, 0xa0, 0x20, 0x2a, 0xd2, 0x14, 0xb2, 0x20, 0x7a,
-    0x0c, 0x1d, 0xf6, 0x53, 0x69, 0xe5, 0xcb, 0x22, 0x5c, 0xbe, 0x02, 0xe0, 0xb5, 0xdf, 0x65, 0x36, 0x90, 0x99, 0x55, 0xbc, 0x88,
-    0x43, 0x39, 0xdf, 0x65, 0x36, 0xa4, 0x92, 0x05, 0x3c, 0x02, 0x60, 0x8a, 0xef, 0xb2, 0x9b, 0x54, 0x6a, 0xbf, 0x88, 0x2e, 0xfb,
-    0x29, 0xb5, 0x46, 0xaa, 0x3a, 0x92, 0x18, 0x42, 0x15, 0xbb, 0xec, 0xa6, 0xd2, 0x34, 0x1a, 0x90, 0xea, 0xb2, 0x20, 0x43, 0x0a,
-    0x9d, 0xf6, 0x53, 0x1f, 0x32, 0xa2, 0xa3, 0xa9, 0x21, 0x22, 0x07, 0xc2, 0x2b, 0xbe, 0xca, 0x68, 0x98, 0x54, 0x75, 0x24, 0x0a,
-    0x48, 0x82, 0x00, 0x24, 0x3b, 0xec, 0xa6, 0x40, 0xd1, 0x78, 0xea, 0x48, 0x14, 0x91, 0x03, 0xf0, 0x4b, 0x77, 0xd9, 0x4c, 0x84,
-    0xf4, 0x4c, 0x0e, 0xa4, 0x84, 0x88, 0x10, 0xc0, 0xed, 0xdf, 0x65, 0x32, 0x15, 0x04, 0x04, 0x4a, 0x8e, 0xa5, 0x22, 0x08, 0x00,
-    0xb9, 0xdf, 0x65, 0x33, 0x24, 0x3f, 0xc0, 0x26, 0x0a, 0x0e, 0xfb, 0x29, 0x99, 0x34, 0xa8, 0xea, 0x48, 0x14, 0x91, 0x03, 0xf0,
-    0xc5, 0xdf, 0x65, 0x34, 0x91, 0x32, 0x3e, 0x3a, 0x92, 0x12, 0x20, 0x7e, 0x21, 0xbb, 0xec, 0xa6, 0x92, 0x26, 0x4b, 0x8e, 0xa4,
-    0x84, 0x88, 0x20, 0x08, 0x2e, 0xfb, 0x29, 0xa4, 0x99, 0x94, 0x07, 0x52, 0x42, 0x44, 0x0f, 0x87, 0x1d, 0xf6, 0x53, 0x49, 0x51,
-    0xd5, 0x51, 0x21, 0x6c, 0x88, 0x43, 0x0a, 0x9d, 0xf6, 0x53, 0x49, 0x54, 0x0c, 0x9a, 0x30, 0xac, 0x88, 0x44, 0x03, 0x57, 0x7d,
-    0x94, 0xd3, 0x6a, 0x8d, 0x54, 0x75, 0x29, 0x10, 0x3e, 0xbb, 0xec, 0xa6, 0xa3, 0x55, 0x34, 0x90, 0xea, 0x52, 0x21, 0x0c, 0x1d,
-    0x3b, 0xec, 0xa6, 0xa2, 0xa7, 0xa0, 0x40, 0x3a, 0x94, 0x88, 0x1f, 0x01, 0xdb, 0xbe, 0xca, 0x83, 0xa8, 0x24, 0x65, 0x4e, 0x44,
-    0x22, 0x0b, 0x5d, 0xf6, 0x54, 0x1d, 0x41, 0x44, 0xfe, 0x03, 0xd0, 0xf9, 0xdf, 0x65, 0x41, 0xd4, 0x0d, 0x26, 0xa3, 0x78, 0x07,
-    0x8b, 0x3b, 0xec, 0xa8, 0x3a, 0x55, 0x33, 0xc5, 0xde, 0x2e, 0x04, 0x17, 0x7d, 0x95, 0x07, 0x4a, 0xa6, 0x78, 0xb8, 0xca, 0x58,
-    0xb8, 0x1c, 0xbb, 0xec, 0xa8, 0x3a, 0x89, 0x85, 0x1a, 0x9a, 0xb2, 0x21, 0x10, 0x3e, 0x77, 0xd9, 0x50, 0x75, 0x36, 0xa0, 0xaf,
-    0x22, 0x07, 0xa1, 0x8b, 0xbe, 0xca, 0x83, 0xa2, 0xe3, 0xd1, 0x54, 0x81, 0xb9, 0x18, 0x5a, 0xef, 0xb2, 0xa1, 0xe5, 0xca, 0xfe,
-    0x14, 0x80, 0x9d, 0xdf, 0x65, 0x43, 0xc4, 0x06, 0xa0, 0x69, 0x3c, 0x88, 0x43, 0x24, 0xef, 0xb2, 0xa2, 0x08, 0x06, 0x45, 0x14,
-    0x17, 0xe4, 0x42, 0x20, 0x35, 0x77, 0xd9, 0x51, 0x04, 0x03, 0x21, 0xe6, 0xaa, 0x86, 0x01, 0x81, 0xb7, 0x7d, 0x95, 0x10, 0x40,
-    0x32, 0x44, 0xca, 0x0a, 0xe0, 0x18, 0x10, 0x5d, 0xf6, 0x54, 0x41, 0x00, 0xc9, 0x23, 0x24, 0x0d, 0xc0, 0x2e, 0x07, 0xae, 0xfb,
-    0x2a, 0x20, 0x80, 0x64, 0x91, 0xa5, 0x50, 0xc0, 0x30, 0x38, 0xef, 0xb2, 0xa2, 0x08, 0x06, 0x65, 0x14, 0x17, 0xe4, 0x42, 0x20,
-    0x74, 0xef, 0xb2, 0xa2, 0x08, 0x06, 0x63, 0xcd, 0x55, 0x0c, 0x03, 0x02, 0xc7, 0x7d, 0x95, 0x10, 0x40, 0x33, 0x44, 0xca, 0x0a,
-    0xe0, 0x18, 0x05, 0x17, 0x7d, 0x95, 0x10, 0x40, 0x33, 0x48, 0xc9, 0x03, 0x70, 0x0b, 0x84, 0x47, 0x7d, 0x95, 0x10, 0x40, 0x33,
-    0x48, 0xd2, 0xa8, 0x60, 0x18, 0x1f, 0x77, 0xd9, 0x51, 0x04, 0x07, 0x48, 0x4f, 0x44, 0xc6, 0x0c, 0x83, 0x57, 0x7d, 0x95, 0x10,
-    0x40, 0xa9, 0xea, 0x9f, 0x91, 0x03, 0xb2, 0xae, 0xfb, 0x2a, 0x20, 0x82, 0x26, 0x11, 0x57, 0x81, 0xb0, 0xad, 0xdf, 0x65, 0x44,
-    0x10, 0x55, 0xaa, 0x2b, 0xc1, 0x88, 0x0b, 0x1d, 0xf6, 0x54, 0x41, 0x33, 0x25, 0xcb, 0xf8, 0xa4, 0x48, 0xef, 0xb2, 0xa3, 0x47,
-    0x88, 0x5a, 0x61, 0x5e, 0x03, 0x00, 0x74, 0xef, 0xb2, 0xa3, 0x4c, 0x15, 0x28, 0x9b, 0x4f, 0xcc, 0x85, 0xae, 0xfb, 0x2a, 0x34,
-    0xc1, 0x54, 0x07, 0xcf, 0x4f, 0x2e, 0x08, 0xee, 0xfb, 0x2a, 0x35, 0x55, 0x15, 0x20, 0xe4, 0x42, 0x18, 0x26, 0x3b, 0xec, 0xa8,
-    0xd5, 0x4d, 0x25, 0xf2, 0x20, 0x40, 0x77, 0xd9, 0x51, 0xaa, 0x9a, 0x48, 0x75, 0x44, 0xa4, 0x40, 0xf4, 0x71, 0xdf, 0x65, 0x46,
-    0xaa, 0x69, 0x21, 0xd5, 0x05, 0xd8, 0x48, 0x11, 0x5d, 0xf6, 0x54, 0x1e, 0x50, 0x5c, 0x8a, 0xa8, 0x0f, 0xe0, 0x0c, 0x12, 0x77,
-    0xd9, 0x50, 0x7c, 0xca, 0x6a, 0xa6, 0x79, 0x0e, 0x0c, 0x41, 0x73, 0xbe, 0xca, 0x88, 0x98, 0x4b, 0xe4, 0x42, 0x20, 0x1c, 0xbb,
-    0xec, 0xa8, 0x65, 0x22, 0x87, 0x9a, 0x2b, 0xc0, 0x3e, 0x09, 0x2e, 0xfb, 0x2a, 0x19, 0x48, 0xc9, 0x03, 0x7c, 0x03, 0xe1, 0x19,
-    0xdf, 0x65, 0x44, 0x8b, 0x81, 0x57, 0xe0, 0xac, 0x10, 0x9d, 0xf6, 0x54, 0x48, 0xb8, 0x15, 0xfe, 0x0a, 0xc1, 0x09, 0xdf, 0x65,
-    0x45, 0x4f, 0x40, 0x83, 0xf0, 0x0e, 0x3b, 0xec, 0xa8, 0xa9, 0xe8, 0x10, 0x28, 0xa9, 0x04, 0x88, 0x06, 0x02, 0x0b, 0xbe, 0xc5,
-    0xfe, 0xb1, 0x0e, 0x57, 0xfd, 0x45, 0x4a, 0xa0, 0x69, 0x35, 0x4f, 0xc2, 0x20, 0x25, 0xbb, 0xec, 0xa8, 0xa8, 0xf9, 0x95, 0x15,
-    0x1d, 0x47, 0x26, 0x09, 0x8e, 0xfb, 0x2a, 0x2a, 0x40, 0xd1, 0x78, 0xea, 0x26, 0x30, 0x2a, 0x03, 0x67, 0x7d, 0x95, 0x15, 0x21,
-    0x50, 0x40, 0x44, 0xa8, 0xee, 0x0b, 0xc0, 0x14, 0x77, 0xd9, 0x51, 0x53, 0x29, 0x06, 0x46, 0x4d, 0x2b, 0x22, 0x11, 0x07, 0x1d,
-    0xf6, 0x54, 0x54, 0xca, 0x41, 0x99, 0x93, 0x4a, 0xc8, 0x84, 0x40, 0x02, 0x1d, 0xf6, 0x54, 0x54, 0xca, 0x45, 0x13, 0x69, 0xf9,
-    0x92, 0xee, 0xfb, 0x2a, 0x2a, 0x65, 0x22, 0xa8, 0xa8, 0x86, 0x64, 0x1c, 0xbb, 0xec, 0xa8, 0xa9, 0x93, 0x4a, 0x8e, 0xa2, 0x63,
-    0x26, 0x08, 0x0e, 0xfb, 0x2a, 0x2a, 0x9a, 0x8a, 0x90, 0x72, 0x20, 0x07, 0x12, 0x77, 0xd8, 0xbf, 0xd6, 0x21, 0xca, 0xff, 0xa8,
-    0xaa, 0x8a, 0x90, 0x79, 0x10, 0x22, 0x08, 0xae, 0xfb, 0x17, 0xf9, 0x2a, 0xff, 0xea, 0x8e, 0x95, 0x69, 0x73, 0xc5, 0xed, 0xc0,
-    0xa4, 0xe7, 0x7d, 0x95, 0x47, 0x48, 0x0a, 0x15, 0xf8, 0x2e, 0x0c, 0x5d, 0xf6, 0x55, 0x1d, 0x50, 0x7c, 0xf2, 0xad, 0xe0, 0xc0,
-    0x30, 0x77, 0xd9, 0x55, 0xaa, 0x2b, 0xf2, 0x20, 0x76, 0x3a, 0xef, 0xb2, 0xab, 0x54, 0x54, 0x17, 0x85, 0xfe, 0x04, 0x43, 0x2e,
-    0xfb, 0x2a, 0x99, 0x30, 0xd4, 0x4c, 0x22, 0xae, 0x00, 0x10, 0x05, 0x8e, 0xfb, 0x2a, 0xa6, 0x55, 0x15, 0x0f, 0x28, 0x5f, 0x03,
-    0x80, 0x48, 0x77, 0xd9, 0x55, 0x36, 0x9e, 0x54, 0x5f, 0xe4, 0xc0, 0xd1, 0xdf, 0x65, 0x54, 0xda, 0x79, 0x51, 0x7a, 0x26, 0x70,
-    0xc8, 0x1d, 0x3b, 0xec, 0xaa, 0x9b, 0x4c, 0x1e, 0x50, 0xbb, 0x70, 0x38, 0x07, 0xce, 0xfb, 0x2a, 0xa6, 0x2f, 0x51, 0xa6, 0x0a,
-    0xf1, 0x0c, 0x6d, 0xdf, 0x65, 0x55, 0x15, 0x2a, 0x81, 0x5f, 0x03, 0x80, 0x3a, 0x77, 0xd9, 0x54, 0x5e, 0x93, 0x7f, 0x02, 0xa0,
-    0x39, 0x77, 0xd9, 0x34, 0x50, 0xb9, 0x94, 0x08, 0x1a, 0x56, 0x44, 0x21, 0x8a, 0x1d, 0xf6, 0x4d, 0x15, 0xfe, 0x44, 0x0f, 0x4e,
-    0x77, 0xd9, 0x34, 0xa3, 0x53, 0x40, 0xd2, 0x6a, 0xc8, 0x81, 0xe0, 0x22, 0x3b, 0xec, 0x88, 0x0d, 0x4c, 0x75, 0x13, 0x0a, 0xf0,
-    0x4a, 0x03, 0x67, 0x7d, 0x91, 0x71, 0xe8, 0xab, 0xf9, 0x10, 0x39, 0x77, 0xd9, 0x17, 0x1e, 0x5c, 0x80, 0x75, 0x13, 0x21, 0x76,
-    0x19, 0x03, 0x37, 0x7d, 0x91, 0x71, 0xe5, 0xc8, 0x07, 0x48, 0xfc, 0x0a, 0x45, 0x4e, 0xfb, 0x22, 0xe3, 0xcb, 0x90, 0x0e, 0x91,
-    0x85, 0xdc, 0x17, 0x0a, 0x9d, 0xf6, 0x45, 0xc7, 0x97, 0x20, 0x1d, 0x23, 0x44, 0xce, 0x01, 0x50, 0x51, 0x77, 0xd9, 0x17, 0x26,
-    0x05, 0x47, 0xfc, 0x88, 0x44, 0x0e, 0x1d, 0xf6, 0x45, 0xc9, 0x81, 0x51, 0x4f, 0x91, 0x08, 0x81, 0xcb, 0xbe, 0xc8, 0xb9, 0x5f,
-    0xf0, 0x62, 0x16, 0x3b, 0xec, 0x91, 0x82, 0xef, 0xe4, 0x42, 0x20, 0x1d, 0xbb, 0xec, 0x91, 0xa0, 0x69, 0x5f, 0xc1, 0x1a, 0xef,
-    0xb2, 0x46, 0x54, 0xf5, 0x4f, 0xf2, 0x20, 0x7a, 0x27, 0x77, 0xd9, 0x23, 0x21, 0xfe, 0x44, 0x0e, 0xc7, 0x1d, 0xf6, 0x45, 0xe0,
-    0xbb, 0xf9, 0x10, 0x88, 0x07, 0xae, 0xfb, 0x22, 0xf2, 0xa7, 0xaa, 0x7f, 0x91, 0x03, 0xd1, 0xb7, 0x7d, 0x91, 0x79, 0x0f, 0xf2,
-    0x20, 0x76, 0x2d, 0x77, 0xd9, 0x49, 0x33, 0x28, 0x0e, 0xa4, 0x81, 0x49, 0x10, 0x3e, 0x1c, 0x92, 0x8b, 0x18, 0xbf, 0xe3, 0x91,
-    0x47, 0xfa, 0x8a, 0x9e, 0x81, 0x00, 0xea, 0x48, 0x48, 0x81, 0xf0, 0x1d, 0xc9, 0x45, 0x8c, 0x5f, 0xf1, 0xc8, 0xa3, 0xfc, 0x7c,
-    0xca, 0x8a, 0x8e, 0xa4, 0x81, 0x49, 0x10, 0x3e, 0x11, 0x64, 0xa2, 0xc6, 0x2f, 0xf8, 0xe4, 0x51, 0xfe, 0x64, 0xd2, 0xa3, 0xa9,
-    0x20, 0x53, 0x22, 0x07, 0xe1, 0x8c, 0x94, 0x58, 0xc5, 0xff, 0x1c, 0x8a, 0x3f, 0xd2, 0x44, 0xc8, 0xf8, 0xea, 0x48, 0x14, 0x91,
-    0x03, 0xf1, 0x0e, 0x4a, 0x2c, 0x62, 0xff, 0x8e, 0x45, 0x1f, 0xe4, 0x0d, 0x17, 0x8e, 0xa4, 0x81, 0x4c, 0x88, 0x1f, 0x82, 0x5c,
-    0x94, 0x58, 0xc5, 0xff, 0x1c, 0x8a, 0x3f, 0xc8, 0x54, 0x10, 0x11, 0x2a, 0x3a, 0x92, 0x12, 0x20, 0x80, 0x0b, 0xa4, 0xa2, 0xc6,
-    0x2f, 0xf8, 0xe4, 0x51, 0xfe, 0x92, 0x26, 0x4b, 0x8e, 0xa4, 0x81, 0x49, 0x10, 0x40, 0x10, 0x64, 0xa2, 0xc6, 0x2f, 0xf8, 0xe4,
-    0x51, 0xfe, 0x89, 0x85, 0x47, 0x52, 0x40, 0xa6, 0x44, 0x10, 0x01, 0x22, 0x4a, 0x2c, 0x62, 0xff, 0x8e, 0x45, 0x1f, 0xed, 0x23,
-    0x41, 0xa9, 0x0e, 0xaa, 0x7c, 0x88, 0x10, 0xc2, 0xa9, 0x28, 0xb1, 0x8b, 0xfe, 0x20, 0xc2, 0xaf, 0xf2, 0x13, 0xd1, 0x30, 0x3a,
-    0x92, 0x05, 0x24, 0x40, 0x86, 0x07, 0x72, 0x51, 0x63, 0x17, 0xfc, 0x72, 0x28, 0xff, 0x21, 0x3d, 0x13, 0x0a, 0x2a, 0x41, 0x91,
-    0x04, 0x71, 0x06, 0x24, 0x8b, 0x18, 0xbf, 0xd8, 0x1f, 0x40, 0x43, 0xfe, 0xd3, 0x05, 0x7e, 0x20, 0x80, 0x70, 0x02, 0x21, 0xf3,
-    0xbe, 0xca, 0x47, 0x95, 0x1d, 0x48, 0xf2, 0xb9, 0x10, 0x0e, 0x81, 0x2a, 0x24, 0x8b, 0x18, 0xbf, 0xe0, 0x7d, 0x02, 0x6f, 0xf0,
-    0x00, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x80, 0x00, 0x00, 0xc2, 0x3c, 0x00, 0x00, 0xc2, 0x18, 0x00, 0x01, 0x37,
-    0xc4, 0x00, 0x01, 0x37, 0xde, 0x00, 0x00, 0xa7, 0xc8, 0x00, 0x01, 0x40, 0x64, 0x00, 0x01, 0x3d, 0x8e, 0x00, 0x01, 0x3d, 0xe8,
-    0x00, 0x01, 0x3e, 0x1e, 0x00, 0x00, 0x4d, 0xac, 0x00, 0x00, 0xc2, 0x78, 0x00, 0x00, 0xc2, 0xf0, 0x00, 0x01, 0x3b, 0xce, 0x00,
-    0x00, 0xa9, 0xc4, 0x00, 0x00, 0xc2, 0x5a, 0x00, 0x01, 0x38, 0x08, 0x00, 0x01, 0x38, 0x28, 0x00, 0x01, 0x38, 0x90, 0x00, 0x01,
-    0x38, 0xc4, 0x00, 0x01, 0x39, 0x0c, 0x00, 0x01, 0x39, 0x28, 0x00, 0x01, 0x39, 0x6c, 0x00, 0x01, 0x39, 0xbc, 0x00, 0x01, 0x3a,
-    0x9e, 0x00, 0x01, 0x3b, 0x7e, 0x00, 0x01, 0x3c, 0x92, 0x00, 0x00, 0x67, 0x7c, 0x00, 0x01, 0x40, 0x14, 0x00, 0x01, 0x40, 0xde,
-    0x00, 0x01, 0x41, 0x32, 0x00, 0x01, 0x10, 0x14, 0x00, 0x01, 0x41, 0x8e, 0x00, 0x00, 0x9a, 0x7c, 0x00, 0x00, 0xc2, 0x94, 0x00,
-    0x00, 0x50, 0xc4, 0x00, 0x01, 0x3c, 0xc4, 0x00, 0x00, 0xa8, 0x90, 0x00, 0x00, 0xc2, 0xcb, 0x00, 0x01, 0x3c, 0x6e, 0x00, 0x00,
-    0xaa, 0x40, 0x00, 0x00, 0xaa, 0xe8, 0x00, 0x01, 0x3b, 0xf2, 0x00, 0x00, 0xc2, 0xb0, 0x00, 0x01, 0x42, 0x14, 0x00, 0x01, 0x3f,
-    0xe0, 0x00, 0x01, 0x21, 0x24, 0x00, 0x01, 0x3e, 0x90, 0x00, 0x00, 0xbf, 0x8c, 0x00, 0x00, 0xc2, 0x4b, 0x00, 0x01, 0x2f, 0xc6,
-    0x00, 0x00, 0x58, 0x30, 0x00, 0x01, 0x3d, 0x28, 0x00, 0x01, 0x27, 0x80, 0x00, 0x01, 0x1e, 0x34, 0x00, 0x00, 0x97, 0xec, 0x00,
-    0x00, 0x98, 0xa8, 0x00, 0x01, 0x16, 0x3e, 0x00, 0x01, 0x10, 0x84, 0x00, 0x00, 0x4e, 0xa4, 0x00, 0x00, 0x4d, 0x7c, 0x00, 0x00,
-    0x4f, 0xfa, 0x00, 0x00, 0x4f, 0x80, 0x00, 0x01, 0x36, 0xe4, 0x00, 0x01, 0x37, 0x64, 0x00, 0x01, 0x6a, 0xff, 0x00, 0x00, 0x63,
-    0x54, 0x00, 0x00, 0x63, 0xb0, 0x00, 0x01, 0x3d, 0xae, 0x00, 0x01, 0x2e, 0x9c, 0x00, 0x01, 0x23, 0x0a, 0x00, 0x01, 0x20, 0x0c,
-    0x00, 0x01, 0x26, 0xf8, 0x00, 0x00, 0xbf, 0x74, 0x00, 0x01, 0x15, 0x8a, 0x00, 0x01, 0x65, 0x8c, 0x00, 0x01, 0x15, 0xa2, 0x00,
-    0x01, 0x14, 0xd0, 0x00, 0x01, 0x14, 0x98, 0x00, 0x01, 0x1a, 0x0e, 0x00, 0x01, 0x14, 0x54, 0x00, 0x01, 0x14, 0x68, 0x00, 0x01,
-    0x66, 0xe2, 0x00, 0x01, 0x17, 0xc2, 0x00, 0x01, 0x67, 0x5a, 0x00, 0x01, 0x12, 0xb0, 0x00, 0x01, 0x15, 0x72, 0x00, 0x01, 0x69,
-    0xcc, 0x00, 0x01, 0x5b, 0xd4, 0x00, 0x01, 0x0a, 0xaa, 0x00, 0x01, 0x07, 0x00, 0x00, 0x01, 0x12, 0x90, 0x00, 0x01, 0x15, 0xbe,
-    0x00, 0x01, 0x60, 0xea, 0x00, 0x01, 0x57, 0xa8, 0x00, 0x01, 0x0c, 0x30, 0x00, 0x01, 0x12, 0xf4, 0x00, 0x01, 0x35, 0x48, 0x00,
-    0x01, 0x61, 0xbc, 0x00, 0x01, 0x4e, 0x7c, 0x00, 0x01, 0x15, 0x38, 0x00, 0x01, 0x29, 0x80, 0x00, 0x01, 0x1e, 0xd4, 0x00, 0x01,
-    0x2b, 0xbe, 0x00, 0x01, 0x35, 0xfc, 0x00, 0x01, 0x61, 0x54, 0x00, 0x01, 0x61, 0xa3, 0x00, 0x01, 0x61, 0x82, 0x00, 0x01, 0x2d,
-    0x94, 0x00, 0x01, 0x2e, 0x40, 0x00, 0x01, 0x0c, 0x14, 0x00, 0x01, 0x4e, 0x20, 0x00, 0x01, 0x0b, 0x12, 0x00, 0x01, 0x0b, 0x7e,
-    0x00, 0x01, 0x0f, 0x9c, 0x00, 0x01, 0x6a, 0xed, 0x00, 0x01, 0x35, 0x78, 0x00, 0x00, 0x9a, 0x0c, 0x00, 0x01, 0x6a, 0xe4, 0x00,
-    0x01, 0x54, 0xc8, 0x00, 0x01, 0x5a, 0xbe, 0x00, 0x01, 0x10, 0xac, 0x00, 0x01, 0x26, 0x60, 0x00, 0x01, 0x26, 0x48, 0x00, 0x01,
-    0x1c, 0x94, 0x00, 0x01, 0x25, 0x66, 0x00, 0x01, 0x25, 0x0a, 0x00, 0x01, 0x23, 0xea, 0x00, 0x01, 0x24, 0x42, 0x00, 0x01, 0x23,
-    0xa6, 0x00, 0x01, 0x25, 0x22, 0x00, 0x01, 0x6a, 0x83, 0x00, 0x01, 0x59, 0xd8, 0x00, 0x01, 0x5b, 0x52, 0x00, 0x01, 0x4d, 0xc0,
-    0x00, 0x01, 0x6a, 0xf6, 0x00, 0x01, 0x25, 0x7e, 0x00, 0x01, 0x62, 0x84, 0x00, 0x01, 0x63, 0x50, 0x00, 0x01, 0x67, 0xd0, 0x00,
-    0x01, 0x53, 0xc4, 0x00, 0x01, 0x68, 0xe8, 0x00, 0x01, 0x50, 0xa0, 0x00, 0x01, 0x54, 0x20, 0x00, 0x01, 0x2a, 0x52, 0x00, 0x01,
-    0x18, 0x2e, 0x00, 0x01, 0x2f, 0x26, 0x00, 0x01, 0x21, 0x64, 0x00, 0x01, 0x0a, 0xee, 0x00, 0x01, 0x29, 0x78, 0x00, 0x01, 0x0b,
-    0xae, 0x00, 0x01, 0x2f, 0xee, 0x00, 0x01, 0x30, 0xec, 0x00, 0x01, 0x1f, 0xd8, 0x00, 0x01, 0x26, 0x78, 0x00, 0x01, 0x1f, 0x94,
-    0x00, 0x01, 0x42, 0xec, 0x00, 0x01, 0x2e, 0x34, 0x00, 0x01, 0x57, 0xfc, 0x00, 0x01, 0x2d, 0xe4, 0x00, 0x01, 0x2e, 0x68, 0x00,
-    0x01, 0x30, 0x76, 0x00, 0x71, 0xee, 0x80, 0x00, 0x00, 0x05, 0x74, 0x00, 0x00, 0x8f, 0xb0, 0x00, 0x01, 0x14, 0x40, 0x00, 0x01,
-    0x6b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x71, 0xdb, 0x00, 0x00, 0x71, 0xe5, 0xc4, 0x00, 0x71, 0xe9, 0x04, 0x00, 0x71, 0xe9, 0x18, 0x00, 0x71, 0xe9, 0x3c,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x71, 0xe9, 0x4c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x6b, 0x28, 0x00,
-    0x01, 0x6b, 0x48, 0x00, 0x01, 0x6b, 0x68, 0x00, 0x01, 0x6b, 0xa8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x71, 0xe9, 0x8c, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6e, 0x00, 0x00, 0x00, 0xf5,
-    0x2c, 0x00, 0x00, 0xf4, 0x6c, 0x00, 0x01, 0x6b, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf4, 0x8c,
-    0x00, 0x00, 0xf4, 0xac, 0x00, 0x00, 0xf4, 0xcc, 0x00, 0x00, 0xf5, 0x6c, 0x00, 0x00, 0xf4, 0xec, 0x00, 0x00, 0xf5, 0x0c, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf3, 0xec, 0x00, 0x00, 0xf6, 0x8c, 0x00, 0x00,
-    0xf5, 0x8c, 0x00, 0x00, 0xf6, 0x0c, 0x00, 0x00, 0xf7, 0x8c, 0x00, 0x00, 0xf8, 0x0c, 0x00, 0x00, 0xf7, 0x0c, 0x00, 0x01, 0x42,
-    0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc3, 0x70, 0x00, 0x01, 0x42, 0xa8, 0x00, 0x01, 0x1e, 0x50, 0x00, 0x01, 0x1e, 0xbc,
-    0x00, 0x00, 0x5a, 0xac, 0x00, 0x01, 0x2b, 0x66, 0x00, 0x01, 0x2b, 0x7a, 0x00, 0x00, 0xd6, 0x44, 0x00, 0x00, 0x45, 0x4e, 0x00,
-    0x00, 0xcb, 0x56, 0x00, 0x00, 0xc2, 0xe6, 0x00, 0x00, 0xcb, 0x12, 0x00, 0x00, 0xd6, 0x64, 0x00, 0x00, 0xc3, 0x17, 0x00, 0x00,
-    0xc3, 0x00, 0x00, 0x00, 0xc3, 0x24, 0x00, 0x01, 0x06, 0x28, 0x00, 0x01, 0x05, 0xa4, 0x00, 0x00, 0xd8, 0xc8, 0x00, 0x00, 0xd6,
-    0x8c, 0x00, 0x00, 0x43, 0xfc, 0x00, 0x00, 0x67, 0xa0, 0x00, 0x00, 0x48, 0x98, 0x00, 0x00, 0x49, 0xa4, 0x00, 0x00, 0x4c, 0xac,
-    0x00, 0x00, 0x71, 0x14, 0x00, 0x00, 0x70, 0xac, 0x00, 0x00, 0x6f, 0xd8, 0x00, 0x00, 0x44, 0xee, 0x00, 0x00, 0x1d, 0xa5, 0x00,
-    0x00, 0x1d, 0xb3, 0x00, 0x00, 0x1d, 0xbe, 0x00, 0x00, 0x1d, 0xcc, 0x00, 0x71, 0x97, 0x6c, 0x00, 0x71, 0x97, 0x70, 0x00, 0x71,
-    0xa7, 0x94, 0x00, 0x00, 0xed, 0x04, 0x00, 0x71, 0xa7, 0xa8, 0x00, 0x71, 0xa7, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xb1,
-];
+use crate::rom::ROM_HALF_SIZE;
+
+/// High half of the 8;7;5 firmware, mapped at 0x10000.
+pub static HI_ROM: [u8; ROM_HALF_SIZE] = *include_bytes!("../roms/rom_hi.bin");
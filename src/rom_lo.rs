@@ -1,3124 +1,4 @@
-// Auto-generated. Do not edit.
-pub const LO_ROM: [u8; 0x10000] = [
-    0x00, 0x71, 0xc3, 0x20, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00,
-    0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07,
-    0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04,
-    0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80,
-    0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00,
-    0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07, 0x04, 0x80, 0x00, 0x07,
-    0x04, 0x80, 0x00, 0x00, 0xd9, 0x24, 0x00, 0x71, 0xc0, 0x50, 0x00, 0x71, 0xc0, 0xa0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0,
-    0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0,
-    0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00,
-    0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71,
-    0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0,
-    0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0,
-    0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc0, 0xf0, 0x00, 0x71, 0xc1, 0x40, 0x00,
-    0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71,
-    0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1,
-    0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x40, 0x00, 0x71, 0xc1, 0x90,
-    0x00, 0x71, 0xc1, 0x90, 0x00, 0x71, 0xc1, 0x90, 0x00, 0x71, 0xc1, 0x90, 0x00, 0x71, 0xc1, 0x90, 0x00, 0x71, 0xc1, 0x90, 0x00,
-    0x71, 0xc1, 0x90, 0x00, 0x71, 0xc1, 0x90, 0x00, 0x71, 0xc1, 0xe0, 0x00, 0x71, 0xc1, 0xe0, 0x00, 0x71, 0xc1, 0xe0, 0x00, 0x71,
-    0xc1, 0xe0, 0x00, 0x71, 0xc2, 0x30, 0x00, 0x71, 0xc2, 0x30, 0x00, 0x71, 0xc2, 0x80, 0x00, 0x71, 0xc2, 0xd0, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xd6, 0x44, 0x00, 0x00, 0x85, 0xe0, 0x00, 0x00, 0x83, 0x18, 0x00,
-    0x00, 0x84, 0xd4, 0x00, 0x00, 0xc2, 0x3c, 0x00, 0x00, 0xc2, 0x18, 0x00, 0x00, 0xa9, 0xb0, 0x00, 0x00, 0xa8, 0xb4, 0x00, 0x00,
-    0x67, 0x00, 0x00, 0x00, 0x90, 0xf4, 0x00, 0x00, 0x61, 0xfc, 0x00, 0x00, 0xa7, 0xa0, 0x00, 0x00, 0xa7, 0xc8, 0x00, 0x00, 0x61,
-    0xe0, 0x00, 0x00, 0xac, 0xe8, 0x00, 0x00, 0x45, 0x4e, 0x00, 0x00, 0x45, 0xaa, 0x00, 0x00, 0xcb, 0x56, 0x00, 0x00, 0x43, 0xfc,
-    0x00, 0x00, 0x63, 0xb0, 0x00, 0x00, 0x62, 0x68, 0x00, 0x00, 0x63, 0x54, 0x00, 0x00, 0x66, 0xc8, 0x00, 0x00, 0x66, 0x28, 0x00,
-    0x00, 0x67, 0xa0, 0x00, 0x00, 0x4d, 0xac, 0x00, 0x00, 0x6f, 0x4c, 0x00, 0x00, 0xc2, 0x78, 0x00, 0x00, 0xc2, 0xe6, 0x00, 0x00,
-    0xc2, 0xf0, 0x00, 0x00, 0x68, 0xa8, 0x00, 0x00, 0x68, 0x28, 0x00, 0x00, 0x68, 0x80, 0x00, 0x00, 0x68, 0x58, 0x00, 0x00, 0x68,
-    0xd0, 0x00, 0x00, 0xcb, 0x12, 0x00, 0x00, 0xa9, 0xc4, 0x00, 0x00, 0xa9, 0xdc, 0x00, 0x00, 0x98, 0x90, 0x00, 0x00, 0x98, 0xa8,
-    0x00, 0x00, 0x98, 0xdc, 0x00, 0x00, 0x97, 0xbc, 0x00, 0x00, 0x14, 0x6e, 0x00, 0x00, 0xc2, 0x5a, 0x00, 0x00, 0xc9, 0xe0, 0x00,
-    0x00, 0xc9, 0x84, 0x00, 0x00, 0xc9, 0xc4, 0x00, 0x00, 0xc9, 0x70, 0x00, 0x00, 0x99, 0xe8, 0x00, 0x00, 0xaa, 0x64, 0x00, 0x00,
-    0xca, 0x54, 0x00, 0x00, 0x9a, 0x0c, 0x00, 0x00, 0xbf, 0x74, 0x00, 0x00, 0xaa, 0xbc, 0x00, 0x00, 0x8f, 0x04, 0x00, 0x00, 0x8f,
-    0x20, 0x00, 0x00, 0x97, 0x24, 0x00, 0x00, 0x48, 0x98, 0x00, 0x00, 0x49, 0xa4, 0x00, 0x00, 0x4c, 0xac, 0x00, 0x00, 0x59, 0xf0,
-    0x00, 0x00, 0x5a, 0x6c, 0x00, 0x00, 0x1d, 0xdc, 0x00, 0x00, 0x4e, 0xa4, 0x00, 0x00, 0x4d, 0x7c, 0x00, 0x00, 0x4f, 0xfa, 0x00,
-    0x00, 0x4f, 0x80, 0x00, 0x00, 0x05, 0x84, 0x00, 0x00, 0x9a, 0x7c, 0x00, 0x00, 0xc2, 0x94, 0x00, 0x00, 0x67, 0x7c, 0x00, 0x00,
-    0x50, 0xc4, 0x00, 0x00, 0x71, 0x5c, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0xa8, 0x08, 0x00, 0x00, 0xc3, 0x17, 0x00, 0x00, 0xa8,
-    0x90, 0x00, 0x00, 0x71, 0x14, 0x00, 0x00, 0x70, 0xac, 0x00, 0x00, 0x6f, 0xac, 0x00, 0x00, 0x6f, 0xd8, 0x00, 0x00, 0x70, 0x7c,
-    0x00, 0x00, 0xc2, 0xcb, 0x00, 0x00, 0x6d, 0x0a, 0x00, 0x00, 0xa8, 0xfc, 0x00, 0x00, 0xa6, 0xd4, 0x00, 0x00, 0x97, 0xec, 0x00,
-    0x00, 0x1c, 0xcc, 0x00, 0x00, 0xc3, 0x00, 0x00, 0x00, 0xaa, 0xe8, 0x00, 0x00, 0xaa, 0x90, 0x00, 0x00, 0xaa, 0x40, 0x00, 0x00,
-    0x8f, 0xb0, 0x00, 0x00, 0xc3, 0x24, 0x00, 0x00, 0xc2, 0xb0, 0x00, 0x00, 0xab, 0x54, 0x00, 0x00, 0x15, 0x06, 0x00, 0x00, 0xca,
-    0x94, 0x00, 0x00, 0x6f, 0x2c, 0x00, 0x00, 0x6c, 0xcc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0xee, 0x00, 0x00, 0x71, 0xac,
-    0x00, 0x00, 0x1d, 0xa5, 0x00, 0x00, 0x1d, 0xb3, 0x00, 0x00, 0x1d, 0xb3, 0x00, 0x00, 0x1d, 0xbe, 0x00, 0x00, 0x1d, 0xbe, 0x00,
-    0x00, 0x1d, 0xbe, 0x00, 0x00, 0x1d, 0xcc, 0x00, 0x00, 0xab, 0xf8, 0x00, 0x00, 0xd6, 0x64, 0x00, 0x00, 0xbf, 0x8c, 0x00, 0x00,
-    0xc2, 0x4b, 0x00, 0x00, 0x1f, 0x0c, 0x00, 0x00, 0xbf, 0xbc, 0x00, 0x00, 0x85, 0xd0, 0x00, 0x00, 0x85, 0xc0, 0x00, 0x00, 0x85,
-    0x9c, 0x00, 0x00, 0x58, 0x30, 0x00, 0x00, 0x05, 0x74, 0x00, 0x00, 0x71, 0x8c, 0x00, 0x71, 0x97, 0x68, 0x00, 0x71, 0x97, 0x6c,
-    0x00, 0x71, 0x97, 0x70, 0x00, 0x71, 0x90, 0x10, 0x00, 0x71, 0x97, 0x74, 0x00, 0x71, 0x90, 0x00, 0x00, 0x71, 0xa7, 0x74, 0x00,
-    0x71, 0xa7, 0x8c, 0x00, 0x01, 0x01, 0x80, 0x00, 0x00, 0xd8, 0xc8, 0x00, 0x71, 0xa7, 0x90, 0x00, 0x71, 0xa7, 0x94, 0x00, 0x71,
-    0xa7, 0x98, 0x00, 0x71, 0xa7, 0x9c, 0x00, 0x71, 0x90, 0x8c, 0x00, 0x71, 0x90, 0x28, 0x00, 0x71, 0xa7, 0xa0, 0x00, 0x71, 0xa7,
-    0xa4, 0x00, 0x00, 0xed, 0x04, 0x00, 0x71, 0xa7, 0xa8, 0x00, 0x71, 0xa7, 0xb4, 0x00, 0x71, 0xa7, 0xb8, 0x00, 0x71, 0xa7, 0xbc,
-    0x00, 0x71, 0xa7, 0xc0, 0x00, 0x71, 0x90, 0x2c, 0x00, 0x71, 0x90, 0x08, 0x00, 0x71, 0x90, 0x04, 0x00, 0x00, 0xde, 0xe0, 0x00,
-    0x71, 0x90, 0x14, 0x00, 0x71, 0xa7, 0xc4, 0x00, 0x01, 0x06, 0x28, 0x00, 0x00, 0xed, 0x0c, 0x00, 0x01, 0x05, 0xa4, 0x00, 0x00,
-    0xd8, 0x78, 0x00, 0x00, 0x6e, 0x00, 0x00, 0x00, 0x95, 0x70, 0x00, 0x71, 0x90, 0x90, 0x00, 0x00, 0xf5, 0x2c, 0x00, 0x00, 0xf4,
-    0x6c, 0x00, 0x00, 0xf5, 0x4c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf4, 0x8c, 0x00, 0x00, 0xf4, 0xac,
-    0x00, 0x00, 0xf4, 0xcc, 0x00, 0x00, 0xf5, 0x6c, 0x00, 0x00, 0xf4, 0xec, 0x00, 0x00, 0xf5, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf3, 0xec, 0x00, 0x00, 0xf6, 0x8c, 0x00, 0x00, 0xf5, 0x8c, 0x00, 0x00,
-    0xf6, 0x0c, 0x00, 0x00, 0xf7, 0x8c, 0x00, 0x00, 0xf8, 0x0c, 0x00, 0x00, 0xf7, 0x0c, 0x00, 0x00, 0xd6, 0x8c, 0x00, 0x00, 0xc3,
-    0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc3, 0x70, 0x00, 0x00, 0x80, 0x78, 0x00, 0x00, 0x5a, 0xac, 0x00, 0x00, 0x6e, 0x3e,
-    0x00, 0x00, 0x6c, 0x68, 0x00, 0x00, 0x6e, 0xf4, 0x00, 0x00, 0x6f, 0x10, 0x00, 0x00, 0x6f, 0x6c, 0x00, 0x00, 0x6f, 0x8c, 0x00,
-    0x00, 0x6e, 0x8e, 0x00, 0x00, 0x6e, 0x54, 0x00, 0x00, 0x6e, 0xde, 0x00, 0x00, 0x6e, 0xa4, 0x00, 0x00, 0x83, 0xbc, 0x00, 0x00,
-    0x1d, 0x65, 0x00, 0x00, 0x1d, 0x44, 0x00, 0x00, 0x85, 0xe8, 0x00, 0x00, 0x7e, 0xa4, 0x00, 0x00, 0x7f, 0x18, 0x00, 0x00, 0x7f,
-    0x50, 0x00, 0x00, 0x7f, 0x80, 0x00, 0x00, 0x7e, 0xde, 0x00, 0x00, 0x7f, 0x34, 0x00, 0x00, 0x7f, 0x68, 0x00, 0x00, 0x7f, 0xfc,
-    0x00, 0x00, 0x8f, 0xe8, 0x00, 0x00, 0x8f, 0xd4, 0x00, 0x00, 0xdf, 0x90, 0x00, 0x71, 0x90, 0x24, 0x00, 0x71, 0xa7, 0xe4, 0x00,
-    0x71, 0xa7, 0xe8, 0x00, 0x71, 0xa7, 0xec, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x00, 0x6b, 0x84, 0x00, 0x00, 0x83, 0xf8, 0x00, 0x00,
-    0x5c, 0x84, 0x00, 0x00, 0x1d, 0x06, 0x00, 0x00, 0x45, 0xde, 0x10, 0x49, 0x84, 0x7f, 0x7c, 0xd8, 0x00, 0x00, 0x40, 0x18, 0x49,
-    0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x46, 0x9c, 0x4f, 0x7c, 0x00, 0x00, 0x00, 0x4c, 0x80, 0xc9, 0x30, 0x70, 0x80, 0xc9, 0x34,
-    0x70, 0x83, 0x61, 0x70, 0x84, 0x01, 0x7f, 0x08, 0x90, 0x71, 0x00, 0x70, 0x82, 0x7f, 0x00, 0x00, 0x50, 0x00, 0x70, 0x2c, 0x5c,
-    0x7f, 0xfc, 0x43, 0x00, 0x00, 0x83, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0x2b, 0x7f,
-    0x16, 0x00, 0x60, 0x00, 0x7f, 0x0c, 0x87, 0x02, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x7b, 0x0a, 0x87, 0x02, 0x7f, 0x3b, 0x00,
-    0x20, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0xac, 0x6f, 0x00, 0x00, 0x87, 0x7f, 0x02, 0x00, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0x0c,
-    0xed, 0x00, 0x00, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0x18, 0x83, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xe0, 0x61, 0x00,
-    0x00, 0x2c, 0x5c, 0x7f, 0x20, 0x8f, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xc8, 0x66, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xa5, 0x1d, 0x00,
-    0x00, 0x2c, 0x5c, 0x7f, 0x54, 0x63, 0x00, 0x00, 0x82, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x82, 0x7f, 0x02, 0x90, 0x71, 0x00,
-    0x70, 0x2c, 0x5c, 0xaf, 0x88, 0x0d, 0xa0, 0x1e, 0x2c, 0xcc, 0xfc, 0x7f, 0x7c, 0x67, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0x34, 0x96,
-    0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xac, 0x96, 0x00, 0x00, 0x24, 0x7f, 0x12, 0x12, 0x00, 0x00, 0x84, 0x01, 0xc9, 0x2c, 0x70, 0x80,
-    0x7f, 0x0c, 0x90, 0x71, 0x00, 0x70, 0x7b, 0x2e, 0x2b, 0x7f, 0xc2, 0x06, 0x60, 0x00, 0x77, 0x07, 0x28, 0xc9, 0x2c, 0x77, 0x21,
-    0x3c, 0x1e, 0x7f, 0x0c, 0x90, 0x71, 0x00, 0x4b, 0x18, 0x80, 0x7f, 0x0c, 0x90, 0x71, 0x00, 0x70, 0xfc, 0xc9, 0x2c, 0x01, 0x40,
-    0x84, 0x40, 0xc9, 0x2c, 0x70, 0x2c, 0x5c, 0xaf, 0x30, 0x0d, 0x2c, 0x5c, 0x7f, 0x5c, 0x71, 0x00, 0x00, 0x38, 0x40, 0x08, 0x7f,
-    0xca, 0x28, 0xc9, 0x2c, 0x7f, 0x07, 0x2c, 0x5c, 0xaf, 0x1a, 0x0d, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f,
-    0xac, 0x70, 0x00, 0x00, 0xbb, 0x6f, 0x7f, 0x40, 0x87, 0x40, 0x59, 0x70, 0x87, 0x59, 0xe0, 0x40, 0x24, 0x7f, 0x9c, 0x11, 0x00,
-    0x00, 0x2c, 0x5c, 0x7f, 0xb0, 0x8f, 0x00, 0x00, 0x24, 0x7f, 0xc3, 0x11, 0x00, 0x00, 0xb2, 0x07, 0x7f, 0x00, 0x90, 0x71, 0x00,
-    0x70, 0x92, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x24, 0x7f, 0xc3, 0x11, 0x00, 0x00, 0x2c, 0x5c, 0xaf, 0xd3, 0x0c, 0x7b, 0x02,
-    0x2c, 0x5c, 0xaf, 0x56, 0x0d, 0x87, 0x40, 0x62, 0x70, 0x3f, 0x1b, 0x62, 0x7f, 0xf4, 0x2c, 0x5c, 0xaf, 0xbe, 0x0c, 0x87, 0x62,
-    0xe0, 0x40, 0x24, 0x7f, 0x81, 0x0f, 0x00, 0x00, 0xb0, 0x04, 0x7f, 0x28, 0x90, 0x71, 0x00, 0x70, 0x7b, 0x02, 0xa0, 0x00, 0xa0,
-    0x00, 0x2c, 0xcc, 0xf8, 0x7f, 0x18, 0x90, 0x00, 0x00, 0x28, 0x40, 0x7f, 0xf2, 0x24, 0x7f, 0xcc, 0x1c, 0x00, 0x00, 0x84, 0x4f,
-    0x04, 0x90, 0x71, 0x00, 0x41, 0x84, 0x4f, 0x00, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x24, 0x7f, 0x9e, 0x0f, 0x00,
-    0x00, 0x84, 0x4f, 0x00, 0x90, 0x71, 0x00, 0x41, 0x84, 0x4f, 0x04, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x24, 0x7f,
-    0x9e, 0x0f, 0x00, 0x00, 0x24, 0x7f, 0x9e, 0x0f, 0x00, 0x00, 0x2c, 0x5c, 0xaf, 0x59, 0x0c, 0xe0, 0x68, 0xe0, 0x62, 0x2c, 0xcc,
-    0xf8, 0xaf, 0xc0, 0x0a, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x2c, 0x5c, 0xaf, 0x45, 0x0c, 0x28, 0xc9, 0x10, 0x7f, 0x0d, 0x84, 0x68,
-    0xc9, 0x14, 0x70, 0x84, 0xc9, 0x14, 0x40, 0x7b, 0x0b, 0x84, 0x01, 0xc9, 0x14, 0x70, 0x84, 0xc9, 0x14, 0x40, 0x28, 0xc9, 0x14,
-    0x77, 0x06, 0x90, 0xc9, 0x14, 0x70, 0x87, 0x62, 0xe0, 0x40, 0x24, 0x7f, 0xa5, 0x0e, 0x00, 0x00, 0x24, 0x7f, 0x7f, 0x0f, 0x00,
-    0x00, 0x7a, 0x0f, 0xff, 0x2c, 0x5c, 0x7f, 0xfc, 0x43, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xbe, 0x1d, 0x00, 0x00, 0x87, 0x01, 0x7f,
-    0x82, 0x06, 0x60, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0xd0, 0x10, 0x6c, 0x40, 0xb0, 0x68, 0x40, 0x24, 0x7f,
-    0x1b, 0x12, 0x00, 0x00, 0x3c, 0x02, 0xc9, 0x10, 0x77, 0x11, 0x3c, 0x01, 0x68, 0x4b, 0x0c, 0x3c, 0x08, 0x68, 0x47, 0x07, 0x3c,
-    0x32, 0x6c, 0x4f, 0x08, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x80, 0xc9, 0x6c, 0x70, 0x7b, 0x17, 0x2c, 0x5c, 0xaf, 0x4a, 0x0c,
-    0x04, 0xc9, 0x38, 0x41, 0x9c, 0xc9, 0x6c, 0x41, 0x87, 0x40, 0x51, 0x70, 0x90, 0xc9, 0x6c, 0x70, 0x3c, 0x6c, 0xc9, 0x6c, 0x4b,
-    0xe7, 0x94, 0x68, 0x70, 0x84, 0x68, 0x47, 0x2c, 0x5c, 0x7f, 0xb3, 0x1d, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xfc, 0x43, 0x00, 0x00,
-    0x80, 0xc9, 0x6c, 0x70, 0x7b, 0x28, 0xe8, 0x5f, 0xcc, 0x00, 0x47, 0x40, 0x9c, 0x4f, 0x18, 0x00, 0x60, 0x00, 0x40, 0xd0, 0x02,
-    0xc9, 0x6c, 0x41, 0x9c, 0x41, 0x40, 0x04, 0xc9, 0x38, 0x41, 0x9c, 0xc9, 0x6c, 0x41, 0x87, 0x51, 0xc0, 0x02, 0x70, 0x90, 0xc9,
-    0x6c, 0x70, 0x3c, 0x6c, 0xc9, 0x6c, 0x4b, 0xd6, 0xe8, 0x5f, 0xcc, 0x00, 0x47, 0x40, 0x9c, 0x4f, 0x18, 0x00, 0x60, 0x00, 0x40,
-    0xd0, 0x02, 0x6c, 0x41, 0x9c, 0x41, 0x40, 0x83, 0xc0, 0x02, 0x70, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0x2c, 0x5c, 0x7f,
-    0xa5, 0x1d, 0x00, 0x00, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x3c, 0x01, 0xc9, 0x10, 0x77, 0x0c, 0x28, 0x68, 0x7f, 0x08, 0x24,
-    0x7f, 0x7f, 0x0f, 0x00, 0x00, 0xa0, 0x1b, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0xa0, 0x6f, 0x5b, 0x2c, 0xcc, 0xfc,
-    0x7f, 0xcc, 0x6c, 0x00, 0x00, 0xdf, 0x30, 0x7f, 0xb6, 0x06, 0x60, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c,
-    0x00, 0x00, 0xa0, 0x6f, 0x46, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x96, 0x7f,
-    0x02, 0x90, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x92, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x7f,
-    0x0f, 0x00, 0x00, 0x3e, 0x6f, 0x57, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x43, 0x09, 0x92, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x24,
-    0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x24, 0x7f, 0xa4, 0x0f, 0x00, 0x00, 0x28, 0x68, 0x77, 0x46, 0x86, 0x7f, 0x00, 0x90, 0x71, 0x00,
-    0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x6f, 0x58, 0xde, 0x01, 0x7f, 0x02,
-    0x90, 0x71, 0x00, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xd4, 0x0e, 0x24,
-    0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x28, 0x68, 0x77, 0x7d, 0x86, 0x7f, 0x00, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7f,
-    0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x6f, 0x58, 0xde, 0x01, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x40,
-    0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x86, 0x0e, 0xa0, 0x00, 0xde, 0x01, 0x7f, 0x02, 0x90,
-    0x71, 0x00, 0x40, 0xa0, 0x40, 0xa0, 0x6f, 0x58, 0xa0, 0x6f, 0x46, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3,
-    0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xf8, 0xaf, 0x51, 0x0e, 0x7b, 0x34, 0x3c, 0x02, 0x68, 0x77, 0x2f, 0xa0, 0x00, 0xa0, 0x00, 0xa0, 0x6f, 0x58, 0xa0, 0x6f, 0x46,
-    0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x1d, 0x0e, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x3c,
-    0x01, 0xc9, 0x10, 0x77, 0x0c, 0x28, 0x68, 0x7f, 0x08, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x84, 0x7f, 0x78, 0xd8, 0x00, 0x00,
-    0x46, 0x7b, 0x12, 0x87, 0x56, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0x90, 0x46, 0x2b, 0x56,
-    0x77, 0xee, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x28, 0xc9, 0x10, 0x77, 0x17, 0x82, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x70, 0x86,
-    0x7f, 0x02, 0x90, 0x71, 0x00, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x7b, 0x6d, 0x28, 0x68, 0x7f, 0x05, 0x94, 0x68, 0x70, 0x3c,
-    0x6f, 0x45, 0x68, 0x4f, 0x14, 0x86, 0x6f, 0x45, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x70, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x40,
-    0x7b, 0x11, 0x86, 0x6a, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x70, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x40, 0x3c, 0x02, 0xc9, 0x10,
-    0x77, 0x32, 0x28, 0x6c, 0x7f, 0x05, 0x94, 0x6c, 0x70, 0x3c, 0x6f, 0x57, 0x6c, 0x4f, 0x14, 0x86, 0x6f, 0x57, 0x7f, 0x00, 0x90,
-    0x71, 0x00, 0x70, 0x86, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x40, 0x7b, 0x11, 0x86, 0x6e, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x86,
-    0x7f, 0x00, 0x90, 0x71, 0x00, 0x40, 0x7b, 0x09, 0x82, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00,
-    0xa0, 0x00, 0xa0, 0xc9, 0x14, 0xa0, 0x6f, 0x58, 0xa0, 0x6f, 0x46, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3,
-    0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00,
-    0xa0, 0x00, 0x04, 0xc9, 0x78, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x6f, 0x45, 0x2c, 0xcc, 0xf0, 0xaf, 0x01, 0x0a, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x3c, 0x6f,
-    0x45, 0xc9, 0x14, 0x4f, 0x06, 0x80, 0x47, 0x7b, 0x0b, 0xfc, 0xc9, 0x14, 0x6f, 0x46, 0x40, 0x84, 0x40, 0x47, 0xa0, 0x00, 0xa0,
-    0x00, 0xa0, 0x6f, 0x58, 0xa0, 0x47, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0xa0, 0xc9, 0x14, 0x04, 0xc9,
-    0x78, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x6f, 0x45, 0x2c, 0xcc, 0xf0, 0xaf, 0x9c, 0x09, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x80, 0x47, 0x7b, 0x40, 0x04, 0x68,
-    0x40, 0xd0, 0x02, 0x47, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x50, 0x40, 0x7b, 0x1c, 0x80, 0xc9, 0x30, 0x70, 0x84, 0xc9, 0x30, 0xc9,
-    0x34, 0x70, 0x7b, 0x23, 0x84, 0x01, 0xc9, 0x30, 0x70, 0x7b, 0x1c, 0x84, 0x01, 0xc9, 0x34, 0x70, 0x7b, 0x15, 0x3c, 0x07, 0x40,
-    0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0x80, 0xd8, 0x00, 0x00, 0x90, 0x47, 0x3c, 0xc9, 0x10,
-    0x47, 0x4b, 0xbe, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x86, 0x7f, 0x00, 0x90, 0x71, 0x00, 0xe4, 0x40, 0x9c, 0xc9, 0x14, 0x40,
-    0x3c, 0x6f, 0x57, 0x40, 0x4f, 0x48, 0x86, 0x7f, 0x00, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7f, 0x02, 0x90, 0x71,
-    0x00, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x6f, 0x58, 0xde, 0x01, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x70,
-    0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xfe, 0x0b, 0x7b, 0x7d, 0x86, 0x7f, 0x00, 0x90, 0x71, 0x00, 0xe4, 0x40,
-    0xa0, 0x40, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0xfc, 0xc9, 0x14, 0x6f, 0x58, 0x40, 0xa0, 0x40, 0xde,
-    0x01, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x86, 0x7f, 0x00, 0x90,
-    0x71, 0x00, 0xe4, 0x40, 0x9c, 0xc9, 0x14, 0x40, 0xa0, 0x40, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0x04,
-    0xc9, 0x78, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x6f, 0x45, 0x2c, 0xcc, 0xf0, 0xaf, 0x75, 0x08, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x86, 0x7f, 0x00, 0x90, 0x71,
-    0x00, 0xe4, 0x40, 0x9c, 0xc9, 0x14, 0x40, 0xa0, 0x40, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x6f,
-    0x58, 0xde, 0x01, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3,
-    0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x86, 0x7f,
-    0x00, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x78,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x6f, 0x45, 0x2c, 0xcc, 0xf0, 0xaf, 0xf9, 0x07, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4,
-    0x40, 0x9c, 0xc9, 0x14, 0x40, 0x3c, 0x6f, 0x45, 0x40, 0x4f, 0x39, 0xa0, 0x00, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40,
-    0xa0, 0x40, 0xa0, 0x6f, 0x58, 0xa0, 0x6f, 0x46, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xb8,
-    0x0a, 0x7b, 0x66, 0xa0, 0x00, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x6f, 0x58, 0xfc, 0xc9, 0x14,
-    0x6f, 0x46, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00,
-    0xe4, 0x40, 0x9c, 0xc9, 0x14, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x78, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x6f, 0x45, 0x2c, 0xcc, 0xf0, 0xaf, 0x46, 0x07, 0x24, 0x7f,
-    0x7f, 0x0f, 0x00, 0x00, 0xa0, 0x00, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00, 0xe4, 0x40, 0x9c, 0xc9, 0x14, 0x40, 0xa0, 0x40, 0xa0,
-    0x6f, 0x58, 0xa0, 0x6f, 0x46, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf0, 0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x86, 0x7f, 0x02, 0x90, 0x71, 0x00,
-    0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x78, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x6f, 0x45, 0x2c, 0xcc, 0xf0, 0xaf, 0xe1, 0x06, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00,
-    0xa0, 0x4f, 0x44, 0x90, 0x71, 0x00, 0xa0, 0x4f, 0xba, 0xd8, 0x00, 0x00, 0x2c, 0xcc, 0xf8, 0x7f, 0x7c, 0x70, 0x00, 0x00, 0xa0,
-    0x4f, 0x44, 0x90, 0x71, 0x00, 0xdf, 0x30, 0x7f, 0x86, 0x06, 0x60, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f,
-    0x00, 0x00, 0xa0, 0x4f, 0x44, 0x90, 0x71, 0x00, 0xa0, 0x6f, 0x67, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0x2c, 0x5c,
-    0x7f, 0xd4, 0x84, 0x00, 0x00, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xfc, 0x43, 0x00, 0x00, 0x2c, 0x5c, 0x7f,
-    0xbe, 0x1d, 0x00, 0x00, 0x87, 0x6b, 0x7f, 0x06, 0x07, 0x60, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0x2c, 0x5c,
-    0x7f, 0xa5, 0x1d, 0x00, 0x00, 0x24, 0x7f, 0x7f, 0x0f, 0x00, 0x00, 0x3c, 0x6f, 0x54, 0x40, 0x7e, 0x68, 0xfc, 0x4f, 0x08, 0x24,
-    0x7f, 0x39, 0x0f, 0x00, 0x00, 0x3c, 0x6f, 0x48, 0x40, 0x7e, 0x7c, 0xfb, 0x47, 0x44, 0x3c, 0x6f, 0x42, 0x40, 0x7e, 0x11, 0xfa,
-    0x47, 0x1e, 0x3c, 0x6f, 0x40, 0x40, 0x7e, 0xfa, 0xfc, 0x47, 0x0b, 0x3c, 0x1b, 0x40, 0x7e, 0xd0, 0xf8, 0x7a, 0xc7, 0xf8, 0x3c,
-    0x6f, 0x41, 0x40, 0x7e, 0xe9, 0xf9, 0x7a, 0xbd, 0xf8, 0x3c, 0x6f, 0x44, 0x40, 0x7e, 0x10, 0xfa, 0x47, 0x0c, 0x3c, 0x6f, 0x43,
-    0x40, 0x7e, 0xf0, 0xf9, 0x7a, 0xaa, 0xf8, 0x3c, 0x6f, 0x46, 0x40, 0x7e, 0x84, 0xf9, 0x7a, 0xa0, 0xf8, 0x3c, 0x6f, 0x4d, 0x40,
-    0x7e, 0xc9, 0xfe, 0x47, 0x1f, 0x3c, 0x6f, 0x4b, 0x40, 0x7e, 0xf0, 0xf9, 0x47, 0x0c, 0x3c, 0x6f, 0x4a, 0x40, 0x7e, 0x35, 0xfa,
-    0x7a, 0x84, 0xf8, 0x3c, 0x6f, 0x4c, 0x40, 0x7e, 0xfa, 0xfd, 0x7a, 0x7a, 0xf8, 0x3c, 0x6f, 0x53, 0x40, 0x7e, 0x96, 0xfb, 0x46,
-    0x70, 0xf8, 0x3c, 0x6f, 0x50, 0x40, 0x7e, 0x6a, 0xfd, 0x7a, 0x66, 0xf8, 0x3c, 0x6f, 0x68, 0x40, 0x7e, 0x3e, 0xff, 0x47, 0x1f,
-    0x3c, 0x6f, 0x66, 0x40, 0x7e, 0xee, 0xfa, 0x47, 0x0c, 0x3c, 0x6f, 0x63, 0x40, 0x7e, 0xb2, 0xfa, 0x7a, 0x4a, 0xf8, 0x3c, 0x6f,
-    0x67, 0x40, 0x7e, 0xd8, 0xfe, 0x7a, 0x40, 0xf8, 0x3c, 0x6f, 0x71, 0x40, 0x7e, 0x6c, 0xf8, 0x47, 0x0c, 0x3c, 0x6f, 0x6d, 0x40,
-    0x7e, 0x0a, 0xfc, 0x7a, 0x2d, 0xf8, 0x3c, 0x6f, 0x76, 0x40, 0x7e, 0x2f, 0xf8, 0x7a, 0x23, 0xf8, 0x7a, 0x20, 0xf8, 0x7b, 0x1f,
-    0x3c, 0x40, 0x37, 0x7e, 0x9d, 0xf7, 0x3c, 0x40, 0x38, 0x7e, 0xaf, 0xf7, 0x3c, 0x40, 0x6f, 0x5b, 0x7e, 0xc6, 0xf7, 0x3c, 0x40,
-    0x6f, 0x63, 0x7e, 0x69, 0xf7, 0x7a, 0xb6, 0xf7, 0x24, 0x7f, 0xc3, 0x11, 0x00, 0x00, 0x96, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70,
-    0x24, 0x7f, 0xc3, 0x11, 0x00, 0x00, 0x2c, 0x5c, 0xaf, 0x1b, 0x03, 0x24, 0x7f, 0xc3, 0x11, 0x00, 0x00, 0x82, 0x7f, 0x00, 0x90,
-    0x71, 0x00, 0x70, 0x24, 0x7f, 0xc3, 0x11, 0x00, 0x00, 0x87, 0x59, 0x48, 0x3f, 0x20, 0x48, 0x4b, 0x08, 0x3f, 0x6f, 0x7f, 0x48,
-    0x77, 0x1d, 0x3f, 0x01, 0x7f, 0xca, 0x06, 0x60, 0x00, 0x77, 0x08, 0x24, 0x7f, 0xc3, 0x11, 0x00, 0x00, 0x3f, 0x02, 0x7f, 0xca,
-    0x06, 0x60, 0x00, 0x77, 0x05, 0x87, 0x20, 0x48, 0x84, 0x4f, 0x80, 0x01, 0x01, 0x00, 0xc9, 0x24, 0x70, 0x84, 0x4f, 0x00, 0x90,
-    0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xfc,
-    0xaf, 0x6d, 0x04, 0x04, 0xc9, 0x28, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0xc9, 0x24, 0x40, 0x87, 0x48, 0xe0, 0x41, 0xd0,
-    0x03, 0x41, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0xc9, 0x20, 0x70, 0x82, 0xc9, 0x1a, 0x70, 0xdc, 0x02, 0xc9, 0x24, 0x40, 0x87,
-    0x50, 0xe2, 0x40, 0x86, 0x40, 0xc9, 0x1e, 0x70, 0x86, 0xd9, 0x20, 0xc9, 0x18, 0x70, 0xdc, 0x08, 0xc9, 0x20, 0x40, 0x86, 0x50,
-    0xc9, 0x1c, 0x70, 0xdc, 0x04, 0xc9, 0x20, 0x40, 0x3b, 0x50, 0x5f, 0x80, 0x00, 0x7f, 0x1b, 0xdc, 0x04, 0xc9, 0x20, 0x40, 0x87,
-    0x50, 0xe2, 0x40, 0xb2, 0x5f, 0x00, 0xff, 0x40, 0x9e, 0x40, 0xc9, 0x28, 0x70, 0x86, 0xc9, 0x28, 0x40, 0x7b, 0x14, 0xdc, 0x04,
-    0xc9, 0x20, 0x40, 0x87, 0x50, 0xe2, 0x40, 0x9e, 0x40, 0xc9, 0x28, 0x70, 0x86, 0xc9, 0x28, 0x40, 0x9e, 0xc9, 0x1a, 0xc9, 0x2a,
-    0x70, 0xdc, 0x08, 0xc9, 0x24, 0x40, 0xa0, 0x50, 0x04, 0xc9, 0x18, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x04, 0xc9, 0x28, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xe8, 0x7f, 0xe8, 0xac, 0x00, 0x00, 0x28, 0xc9, 0x34,
-    0x7f, 0x5c, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x86, 0xc9, 0x28, 0xe4, 0x40, 0xa0, 0x40, 0xde, 0x0c, 0xc9, 0x2a, 0x40, 0xa0,
-    0x40, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0xde, 0x09, 0xc9, 0x28, 0x40, 0xa0, 0x40, 0xde, 0x0c, 0xc9, 0x2a, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x74, 0x42,
-    0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00,
-    0x2c, 0xcc, 0xf0, 0x7f, 0x94, 0xca, 0x00, 0x00, 0x28, 0xc9, 0x30, 0x7f, 0x63, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x04, 0xc9,
-    0x28, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xde, 0x09, 0xc9, 0x28, 0x40, 0xa0, 0x40, 0xde,
-    0x0e, 0xc9, 0x2a, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x70, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x74, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0,
-    0x7f, 0x90, 0xaa, 0x00, 0x00, 0x92, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x7b, 0x29, 0x3c, 0x40, 0x07, 0x7e, 0x1c, 0xf5, 0x3c,
-    0x40, 0x08, 0x7e, 0xff, 0xfd, 0x3c, 0x40, 0x09, 0x7e, 0x1d, 0xf5, 0x3c, 0x40, 0x0a, 0x7e, 0x00, 0xfe, 0x3c, 0x40, 0x0d, 0x7e,
-    0x05, 0xfe, 0x3c, 0x40, 0x1b, 0x7e, 0x20, 0xf5, 0x7a, 0x09, 0xfe, 0x3e, 0x6f, 0x57, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x4f, 0x0e,
-    0x2c, 0x5c, 0xaf, 0xff, 0x00, 0x82, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0xa0, 0x4f, 0x00, 0x90, 0x71, 0x00, 0xa0, 0x6f, 0x44,
-    0x2c, 0xcc, 0xf8, 0xaf, 0xf4, 0x05, 0x84, 0xef, 0x24, 0x90, 0x71, 0x00, 0x40, 0x2a, 0xc0, 0x08, 0x4f, 0x1b, 0xa0, 0xef, 0x24,
-    0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0xbb, 0x6f, 0x7f, 0x40, 0x87, 0x40, 0x59, 0x70, 0x7a, 0xa7,
-    0xf4, 0x2c, 0x5c, 0xaf, 0xa3, 0x01, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x7e, 0x33, 0xf4, 0x18, 0x46, 0x08, 0x70, 0x70, 0x10,
-    0x46, 0x84, 0x74, 0x47, 0x84, 0x5a, 0x46, 0x84, 0x00, 0x48, 0x7b, 0x14, 0x28, 0x48, 0x77, 0x07, 0x80, 0x40, 0x18, 0x46, 0x08,
-    0xdc, 0x01, 0x48, 0x40, 0x18, 0x46, 0x08, 0x90, 0x48, 0x3c, 0x02, 0x48, 0x43, 0x2b, 0xd0, 0x02, 0x48, 0x40, 0x9c, 0x46, 0x40,
-    0xa0, 0x40, 0xa0, 0x47, 0x2c, 0xcc, 0xf8, 0xaf, 0x29, 0x00, 0xbc, 0xff, 0x40, 0x4b, 0x15, 0x3c, 0x03, 0x40, 0x47, 0x10, 0xc0,
-    0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0xa0, 0xd8, 0x00, 0x00, 0x84, 0xff, 0x40, 0x18, 0x46, 0x08, 0x18, 0x46,
-    0x08, 0x70, 0x70, 0x10, 0x47, 0x80, 0x48, 0x84, 0x48, 0x47, 0x7b, 0x16, 0x18, 0x47, 0x08, 0xe8, 0x0a, 0x48, 0x40, 0xff, 0x30,
-    0x42, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x48, 0x84, 0x01, 0x47, 0x2c, 0x5c, 0xaf, 0xa5, 0x01, 0x87, 0x40, 0x42, 0x3f, 0x39,
-    0x42, 0x47, 0x07, 0x3f, 0x30, 0x42, 0x43, 0xdf, 0x84, 0x48, 0xda, 0x00, 0x70, 0x3f, 0x3b, 0x42, 0x77, 0x08, 0x84, 0x01, 0x40,
-    0x18, 0x47, 0x08, 0x87, 0x42, 0xda, 0x04, 0x70, 0x28, 0x47, 0x7f, 0x07, 0x80, 0x40, 0x18, 0x47, 0x08, 0x84, 0x02, 0x40, 0x18,
-    0x47, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x0c, 0x4c, 0x3e, 0x6f, 0x45, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x4b, 0x68, 0x2c, 0x5c, 0xaf,
-    0x81, 0x00, 0x84, 0x40, 0x48, 0xa0, 0x00, 0xa0, 0x48, 0xa0, 0x6f, 0x58, 0xa0, 0x6f, 0x46, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf0,
-    0x7f, 0x00, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x00, 0xa0, 0x00, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x6f, 0x45, 0x2c, 0xcc, 0xf0, 0xaf, 0xe0, 0x01, 0x84, 0x48, 0x40, 0x86, 0x40, 0x40,
-    0xfe, 0x40, 0x6f, 0x46, 0x40, 0x86, 0x40, 0x7f, 0x02, 0x90, 0x71, 0x00, 0x70, 0x7b, 0x09, 0x92, 0x7f, 0x02, 0x90, 0x71, 0x00,
-    0x70, 0x2b, 0x7f, 0x0a, 0x00, 0x60, 0x00, 0x7f, 0x09, 0x82, 0x7f, 0x00, 0x90, 0x71, 0x00, 0x70, 0x18, 0x48, 0x08, 0x70, 0x70,
-    0x10, 0x48, 0x80, 0x48, 0x84, 0xef, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0xc0, 0x04, 0x41, 0x7f, 0x25, 0x80, 0x48, 0x7b, 0x10,
-    0x18, 0x48, 0x08, 0x3e, 0x0a, 0xc1, 0x04, 0x77, 0x04, 0x90, 0x48, 0x84, 0x42, 0x41, 0x84, 0x51, 0x42, 0x7f, 0x0e, 0x3e, 0x1b,
-    0xc1, 0x04, 0x7f, 0x08, 0x3e, 0x0b, 0xc1, 0x04, 0x77, 0xe6, 0x28, 0x48, 0x4f, 0x15, 0x3c, 0x6f, 0x45, 0x48, 0x43, 0x08, 0x84,
-    0x48, 0x40, 0x18, 0x48, 0x08, 0x84, 0x6f, 0x45, 0x40, 0x18, 0x48, 0x08, 0x84, 0x01, 0x40, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x49, 0x9c, 0x10, 0x4c, 0x84, 0x4f, 0x00, 0x90, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xfc, 0xaf, 0xb4, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x09, 0xa0, 0x0e, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8,
-    0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc,
-    0xf8, 0x7f, 0x3c, 0xc2, 0x00, 0x00, 0x04, 0x64, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x04, 0x59,
-    0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c,
-    0xcc, 0xf0, 0x7f, 0x90, 0xaa, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x7b, 0x0f, 0x18, 0x49, 0x08, 0xa0, 0x08,
-    0x2c, 0xcc, 0xfc, 0x7f, 0x8c, 0x71, 0x00, 0x00, 0x84, 0xef, 0x24, 0x90, 0x71, 0x00, 0x40, 0x2a, 0xc0, 0x08, 0x7f, 0xec, 0xa0,
-    0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0xb8, 0x6f, 0x7f, 0x40, 0x18, 0x49, 0x08, 0x70,
-    0x70, 0x10, 0x49, 0x2c, 0x5c, 0xcf, 0xca, 0xbc, 0x20, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x04,
-    0x4c, 0x84, 0x42, 0x59, 0x70, 0x04, 0x5a, 0x48, 0xaa, 0x09, 0x58, 0x70, 0x84, 0x48, 0x40, 0x9c, 0x02, 0x48, 0x9e, 0x03, 0x50,
-    0x70, 0xaa, 0x0e, 0x58, 0x70, 0x9e, 0x03, 0x58, 0x70, 0x84, 0x59, 0x41, 0x04, 0x5a, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59,
-    0x40, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x04, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x04, 0x5a, 0x48, 0xaa, 0x09, 0x58,
-    0x70, 0x84, 0x48, 0x40, 0x9c, 0x02, 0x48, 0x9e, 0x03, 0x50, 0x70, 0xaa, 0x0e, 0x58, 0x70, 0x84, 0x48, 0x40, 0x9c, 0x02, 0x48,
-    0x9e, 0x03, 0x50, 0x70, 0xaa, 0x09, 0x58, 0x70, 0x84, 0x48, 0x40, 0x9c, 0x02, 0x48, 0x9e, 0x03, 0x50, 0x70, 0xaa, 0x0e, 0x58,
-    0x70, 0x9e, 0x03, 0x58, 0x70, 0x84, 0x59, 0x41, 0x04, 0x5a, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0x84, 0x59, 0x40, 0x18, 0x48, 0x08, 0x10, 0x49, 0x9c, 0x0c, 0x4c, 0xe0, 0x5a, 0xa0, 0x7c, 0x84, 0x6c, 0x42, 0x2a, 0x52, 0x43,
-    0x05, 0x82, 0x52, 0x70, 0x2a, 0xc2, 0x02, 0x43, 0x06, 0x82, 0xc2, 0x02, 0x70, 0x3e, 0x6f, 0x58, 0x52, 0x4f, 0x07, 0x86, 0x6f,
-    0x58, 0x52, 0x70, 0x86, 0xc2, 0x02, 0xe4, 0x40, 0xdc, 0x01, 0xc9, 0x10, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x0c, 0xde, 0x01, 0xc9,
-    0x12, 0x40, 0x86, 0x40, 0xc2, 0x02, 0x70, 0xbc, 0x08, 0x4c, 0xe0, 0x74, 0xa0, 0x7c, 0x84, 0x6c, 0x42, 0x2a, 0x52, 0x43, 0x05,
-    0x82, 0x52, 0x70, 0x2a, 0xc2, 0x02, 0x43, 0x06, 0x82, 0xc2, 0x02, 0x70, 0x3e, 0x6f, 0x58, 0x52, 0x4f, 0x07, 0x86, 0x6f, 0x58,
-    0x52, 0x70, 0x86, 0xc2, 0x02, 0xe4, 0x40, 0xdc, 0x01, 0xc9, 0x10, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x0c, 0xde, 0x01, 0xc9, 0x12,
-    0x40, 0x86, 0x40, 0xc2, 0x02, 0x70, 0xbc, 0x08, 0x4c, 0xe0, 0x78, 0xa0, 0x7c, 0x84, 0x6c, 0x42, 0x2a, 0x52, 0x43, 0x05, 0x82,
-    0x52, 0x70, 0x2a, 0xc2, 0x02, 0x43, 0x06, 0x82, 0xc2, 0x02, 0x70, 0x3e, 0x6f, 0x58, 0x52, 0x4f, 0x07, 0x86, 0x6f, 0x58, 0x52,
-    0x70, 0x86, 0xc2, 0x02, 0xe4, 0x40, 0xdc, 0x01, 0xc9, 0x10, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x0c, 0xde, 0x01, 0xc9, 0x12, 0x40,
-    0x86, 0x40, 0xc2, 0x02, 0x70, 0xbc, 0x08, 0x4c, 0x04, 0x78, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0x7f, 0xe6, 0xc2,
-    0x00, 0x00, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xc5, 0x16, 0x00, 0x00, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x04, 0x5a, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x42, 0x2c,
-    0xcc, 0xf8, 0xaf, 0xa2, 0xfe, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x04, 0x78, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x68, 0x42, 0x2c, 0xcc, 0xfc, 0xaf, 0x40, 0xfe, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x00, 0x2c, 0xcc, 0xe8, 0x7f, 0xe8, 0xac, 0x00, 0x00, 0x3e, 0x5a, 0x78, 0x7f, 0x08, 0x24, 0x7f, 0x1d, 0x17, 0x00, 0x00, 0x3e,
-    0x72, 0x7a, 0x43, 0x61, 0x86, 0x78, 0xe4, 0x40, 0xa0, 0x40, 0xfe, 0x72, 0x76, 0x40, 0x86, 0x7a, 0xe4, 0x41, 0x9c, 0x41, 0x40,
-    0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c,
-    0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x56, 0x01, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x86, 0x74, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x7a, 0xe4, 0x40, 0xa0, 0x40, 0x04,
-    0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xfe, 0x00, 0x18, 0x49, 0x08, 0x3e, 0x5a, 0x78, 0x43, 0x5e,
-    0xfe, 0x5a, 0x74, 0x40, 0x86, 0x78, 0xe4, 0x41, 0x9c, 0x41, 0x40, 0xa0, 0x40, 0x86, 0x7a, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04,
-    0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x24,
-    0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c,
-    0xcc, 0xf8, 0xaf, 0x9d, 0x00, 0x18, 0x49, 0x08, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0x86, 0x78, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x76, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17,
-    0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f,
-    0x24, 0xc3, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0x2c, 0xcc, 0xf8, 0xaf, 0x48, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x42, 0x2a, 0x52, 0x43, 0x05,
-    0x82, 0x52, 0x70, 0x2a, 0xc2, 0x02, 0x43, 0x06, 0x82, 0xc2, 0x02, 0x70, 0x3e, 0x6f, 0x58, 0x52, 0x4f, 0x07, 0x86, 0x6f, 0x58,
-    0x52, 0x70, 0x86, 0xc2, 0x02, 0xe4, 0x40, 0xdc, 0x01, 0x74, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x0b, 0xde, 0x01, 0x76, 0x40, 0x86,
-    0x40, 0xc2, 0x02, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x08, 0x4c, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x04,
-    0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59,
-    0x42, 0x2c, 0xcc, 0xf8, 0xaf, 0x7e, 0xfc, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc, 0xf0, 0x7f, 0x90, 0xaa, 0x00, 0x00, 0x18, 0x49, 0x08, 0x10, 0x46, 0x9c, 0x0c, 0x4c,
-    0x38, 0x7f, 0x8c, 0x90, 0x71, 0x00, 0x5f, 0x00, 0x02, 0x7f, 0x36, 0x38, 0x7f, 0x8c, 0x90, 0x71, 0x00, 0x5f, 0x00, 0x01, 0x7f,
-    0x2b, 0x90, 0x7f, 0x20, 0x90, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x20, 0x90, 0x71, 0x00, 0x40, 0xff, 0x7f, 0xbe, 0x06, 0x60, 0x00,
-    0x04, 0x41, 0x3c, 0x41, 0x40, 0x5b, 0x10, 0x80, 0x7f, 0x20, 0x90, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0x70, 0x95, 0x00, 0x00,
-    0x28, 0x7f, 0x90, 0x90, 0x71, 0x00, 0x77, 0x08, 0x24, 0x7f, 0x22, 0x1a, 0x00, 0x00, 0x18, 0x46, 0x08, 0xa0, 0x4f, 0x38, 0x90,
-    0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x87, 0x40, 0x46, 0x3b, 0x46, 0x5f, 0x80, 0x00, 0x77, 0x08, 0x24,
-    0x7f, 0x59, 0x19, 0x00, 0x00, 0x3f, 0x5f, 0x80, 0x00, 0x46, 0x77, 0x17, 0x2c, 0x5c, 0x7f, 0x2c, 0x6f, 0x00, 0x00, 0x84, 0x18,
-    0x7f, 0x18, 0x90, 0x71, 0x00, 0x70, 0x24, 0x7f, 0xfe, 0x19, 0x00, 0x00, 0x3f, 0x5f, 0x81, 0x00, 0x46, 0x77, 0x1f, 0x2c, 0x5c,
-    0x7f, 0x4c, 0x6f, 0x00, 0x00, 0x84, 0x18, 0x7f, 0x18, 0x90, 0x71, 0x00, 0x70, 0x87, 0x01, 0x7f, 0x1c, 0x90, 0x71, 0x00, 0x70,
-    0x24, 0x7f, 0xfe, 0x19, 0x00, 0x00, 0x3f, 0x5f, 0x8c, 0x00, 0x46, 0x77, 0x1c, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0xa0, 0x4f,
-    0xbd, 0xd8, 0x00, 0x00, 0x2c, 0xcc, 0xf8, 0x7f, 0x7c, 0x70, 0x00, 0x00, 0x24, 0x7f, 0x22, 0x1a, 0x00, 0x00, 0x3f, 0x5f, 0x89,
-    0x00, 0x46, 0x47, 0x14, 0xfb, 0x0f, 0x46, 0x40, 0xbc, 0x02, 0x40, 0x84, 0x40, 0x47, 0x80, 0x48, 0x24, 0x7f, 0xc6, 0x1a, 0x00,
-    0x00, 0x87, 0x46, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0x24, 0x7f, 0xfe, 0x19, 0x00, 0x00,
-    0x3f, 0x13, 0x46, 0x77, 0x3f, 0x28, 0x7f, 0x10, 0x90, 0x71, 0x00, 0x47, 0x37, 0x2c, 0x5c, 0x7f, 0xbe, 0x1d, 0x00, 0x00, 0x84,
-    0x40, 0x64, 0x70, 0x84, 0x7f, 0x10, 0x90, 0x71, 0x00, 0x68, 0x70, 0x84, 0x01, 0x7f, 0x10, 0x90, 0x71, 0x00, 0x70, 0xa0, 0x64,
-    0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00, 0x28, 0x68, 0x77, 0x71, 0xa0, 0x13, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00,
-    0x00, 0x7b, 0x65, 0x3c, 0x01, 0x7f, 0x10, 0x90, 0x71, 0x00, 0x77, 0x09, 0x80, 0x7f, 0x10, 0x90, 0x71, 0x00, 0x70, 0x3f, 0x0d,
-    0x46, 0x77, 0x42, 0x87, 0x7f, 0x06, 0x00, 0x60, 0x00, 0xe0, 0x40, 0x3c, 0x40, 0x00, 0x7f, 0x35, 0x3c, 0x40, 0x01, 0x7f, 0x2d,
-    0x3c, 0x40, 0x02, 0x77, 0x2b, 0xa0, 0x0d, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0x2b, 0x7f, 0x0e, 0x00, 0x60, 0x00,
-    0x7f, 0x16, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x87, 0x46, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00,
-    0x00, 0x87, 0x0a, 0x46, 0x87, 0x46, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0x2b, 0x7f, 0x0e,
-    0x00, 0x60, 0x00, 0x7f, 0x1e, 0x28, 0x7f, 0x18, 0x90, 0x71, 0x00, 0x77, 0x16, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x87, 0x46,
-    0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0x2a, 0x7f, 0x40, 0x90, 0x71, 0x00, 0x4f, 0x0b, 0x28,
-    0x7f, 0x18, 0x90, 0x71, 0x00, 0x7e, 0x7d, 0xfe, 0x90, 0x7f, 0x0c, 0x90, 0x71, 0x00, 0x70, 0x3c, 0xff, 0x7f, 0x10, 0x90, 0x71,
-    0x00, 0x77, 0x21, 0x84, 0xef, 0x24, 0x90, 0x71, 0x00, 0x40, 0x3e, 0x6f, 0x64, 0xc0, 0x08, 0x43, 0x13, 0xa0, 0x11, 0x2c, 0xcc,
-    0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0x80, 0x7f, 0x10, 0x90, 0x71, 0x00, 0x70, 0x28, 0x7f, 0x18, 0x90, 0x71, 0x00, 0x7f, 0x31,
-    0x94, 0x7f, 0x18, 0x90, 0x71, 0x00, 0x70, 0x47, 0x28, 0x2b, 0x7f, 0x1c, 0x90, 0x71, 0x00, 0x7f, 0x12, 0x83, 0x7f, 0x1c, 0x90,
-    0x71, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0x8c, 0x6f, 0x00, 0x00, 0x7b, 0x09, 0x2c, 0x5c, 0x7f, 0x6c, 0x6f, 0x00, 0x00, 0x80, 0x7f,
-    0x18, 0x90, 0x71, 0x00, 0x70, 0x18, 0x46, 0x08, 0x2b, 0x7f, 0x0e, 0x00, 0x60, 0x00, 0x7f, 0x16, 0xa0, 0xef, 0x24, 0x90, 0x71,
-    0x00, 0x87, 0x46, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0x87, 0x46, 0xe0, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x6c, 0x00, 0x00, 0xe8, 0x5f, 0xcc, 0x00, 0x47, 0x40, 0x9c, 0x4f, 0x18, 0x00, 0x60, 0x00, 0x40,
-    0xd0, 0x02, 0x48, 0x41, 0x9c, 0x41, 0x40, 0x87, 0xc0, 0x02, 0x46, 0x7e, 0x44, 0xff, 0x90, 0x48, 0x3c, 0x32, 0x48, 0x4f, 0xb6,
-    0x7a, 0x3a, 0xff, 0x70, 0x70, 0xd4, 0x03, 0x4b, 0x48, 0x04, 0x7f, 0xf0, 0xc3, 0x71, 0x00, 0x4e, 0x70, 0xb0, 0x4f, 0x00, 0x00,
-    0x80, 0x02, 0x4b, 0x70, 0x84, 0x4f, 0x7d, 0x67, 0x03, 0x00, 0x41, 0x70, 0x94, 0x41, 0x70, 0x57, 0xfd, 0x2c, 0x5c, 0x7f, 0xfc,
-    0x43, 0x00, 0x00, 0xb8, 0x0f, 0x48, 0x3c, 0x03, 0x48, 0x77, 0x13, 0x2c, 0x5c, 0x7f, 0x84, 0x6b, 0x00, 0x00, 0xa0, 0x00, 0x2c,
-    0xcc, 0xfc, 0x7f, 0x0c, 0x1f, 0x00, 0x00, 0x83, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x70, 0x83, 0x7f, 0x04, 0x07, 0x60, 0x00, 0x70,
-    0x83, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x70, 0x83, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00,
-    0x84, 0x4f, 0x00, 0xe1, 0x81, 0x02, 0x4b, 0x70, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x40, 0xd3, 0x02, 0x7f, 0x86, 0x06, 0x60,
-    0x00, 0x42, 0x84, 0x82, 0x04, 0xed, 0x00, 0x00, 0x41, 0x70, 0x80, 0x50, 0x70, 0x9c, 0x04, 0x40, 0x3c, 0x41, 0x40, 0x77, 0xf7,
-    0x34, 0x7f, 0xd7, 0x1c, 0x00, 0x00, 0x04, 0x7f, 0x0c, 0xc0, 0x71, 0x00, 0x4d, 0x2b, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x70, 0x7f,
-    0x09, 0x2c, 0x5c, 0x7f, 0x24, 0x7c, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0x68, 0x6c, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0x84, 0x05, 0x00,
-    0x00, 0x84, 0x4f, 0x00, 0xe1, 0x81, 0x02, 0x4b, 0x70, 0xf8, 0x4f, 0xff, 0xff, 0x00, 0x00, 0x40, 0x48, 0xf8, 0x4f, 0x00, 0x00,
-    0xff, 0x0f, 0x40, 0x43, 0xd8, 0x10, 0x43, 0x43, 0x87, 0x00, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x70, 0x3f, 0x02, 0x43, 0x77, 0x0a,
-    0x87, 0x01, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x70, 0xa0, 0x43, 0xa0, 0x48, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0x20, 0x48,
-    0x20, 0x43, 0x87, 0x02, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x40, 0xfc, 0x40, 0x4f, 0x50,
-    0xd2, 0x71, 0x00, 0x41, 0x9c, 0x5f, 0xb0, 0x14, 0x41, 0xc4, 0x02, 0x41, 0x41, 0x86, 0x41, 0x7f, 0x00, 0x00, 0x50, 0x00, 0x70,
-    0xd3, 0x02, 0x7f, 0x86, 0x06, 0x60, 0x00, 0x42, 0x84, 0x82, 0x04, 0xed, 0x00, 0x00, 0x41, 0x70, 0x70, 0x70, 0x80, 0x50, 0x70,
-    0x9c, 0x04, 0x40, 0x3c, 0x41, 0x40, 0x77, 0xf7, 0x2c, 0x5c, 0x7f, 0x68, 0x6c, 0x00, 0x00, 0x34, 0x7f, 0xd7, 0x1c, 0x00, 0x00,
-    0xa0, 0x43, 0x28, 0x48, 0x7e, 0x4e, 0x00, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x40, 0xfc, 0x40, 0x4f, 0x94, 0x2c, 0x72, 0x00,
-    0x41, 0xc4, 0x02, 0x41, 0x41, 0x86, 0x41, 0x7f, 0x00, 0x00, 0x50, 0x00, 0x70, 0x84, 0x7f, 0xf4, 0x06, 0x01, 0x00, 0x41, 0x70,
-    0x84, 0x7f, 0xf8, 0x06, 0x01, 0x00, 0x42, 0xc4, 0x02, 0x42, 0x42, 0x70, 0x84, 0x7f, 0xfc, 0x06, 0x01, 0x00, 0x40, 0x70, 0x30,
-    0x19, 0x70, 0x84, 0x7f, 0xf0, 0x06, 0x01, 0x00, 0x7f, 0xa4, 0xc3, 0x71, 0x00, 0x70, 0x3c, 0x48, 0x01, 0x77, 0x3b, 0x2b, 0x7f,
-    0x16, 0x00, 0x60, 0x00, 0x70, 0x7f, 0x0d, 0x87, 0x02, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x70, 0x7b, 0x0b, 0x87, 0x02, 0x7f,
-    0x3b, 0x00, 0x20, 0x00, 0x70, 0x70, 0xa0, 0x6f, 0x61, 0x84, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x2c, 0xcc, 0xfc, 0xd0, 0x10,
-    0x20, 0x43, 0x2c, 0x5c, 0x7f, 0xdc, 0x1d, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x0b, 0x04, 0x7f, 0xa0, 0xc3, 0x71, 0x00, 0x40, 0x30,
-    0xac, 0x04, 0x7f, 0x24, 0xd9, 0x00, 0x00, 0x40, 0x30, 0xac, 0x7b, 0xf7, 0x04, 0x7f, 0x1c, 0xd9, 0x00, 0x00, 0x40, 0x84, 0x50,
-    0x42, 0x6c, 0x9c, 0x04, 0x40, 0x84, 0x50, 0x41, 0x9c, 0x04, 0x40, 0x30, 0x19, 0x7b, 0xf1, 0x2c, 0x5c, 0x7f, 0xf4, 0x90, 0x00,
-    0x00, 0x30, 0xc8, 0xa0, 0x4f, 0x58, 0x18, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x08, 0x00, 0x30, 0xc8, 0x87, 0x7f, 0x13, 0x00,
-    0x20, 0x00, 0x45, 0x3b, 0x6f, 0x40, 0x45, 0x7f, 0x12, 0x3b, 0x04, 0x45, 0x77, 0x0d, 0x2c, 0x5c, 0x7f, 0x00, 0x67, 0x00, 0x00,
-    0x2c, 0x5c, 0xda, 0x00, 0x3b, 0x5f, 0xb0, 0x00, 0x45, 0x7f, 0x09, 0x2c, 0x5c, 0x7f, 0xfc, 0x61, 0x00, 0x00, 0x08, 0x2c, 0x5c,
-    0x7f, 0xf8, 0x83, 0x00, 0x00, 0x30, 0xc8, 0x2c, 0x5c, 0x7f, 0xd4, 0x84, 0x00, 0x00, 0x30, 0xc8, 0x2c, 0x5c, 0x7f, 0xbc, 0x83,
-    0x00, 0x00, 0x87, 0x00, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x3b, 0x0c, 0x7f, 0x07, 0x00, 0x20, 0x00, 0x7f, 0xea, 0x87, 0x73,
-    0x7f, 0x0f, 0x00, 0x20, 0x00, 0x70, 0x08, 0x87, 0x00, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x7f, 0x07, 0x00, 0x20, 0x00,
-    0x40, 0x3b, 0x6f, 0x71, 0x40, 0x7f, 0xed, 0x3b, 0x6f, 0x70, 0x40, 0x7f, 0x16, 0x87, 0x7f, 0x0f, 0x00, 0x20, 0x00, 0x40, 0x87,
-    0x6f, 0x40, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x84, 0xff, 0x40, 0x08, 0x87, 0x00, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x87,
-    0x7f, 0x0f, 0x00, 0x20, 0x00, 0xe0, 0x40, 0x08, 0x70, 0x84, 0x4b, 0x40, 0x70, 0xb8, 0x4f, 0xff, 0x1f, 0xfe, 0xff, 0x4b, 0x70,
-    0x08, 0x70, 0x84, 0x4b, 0x40, 0xc8, 0x04, 0x0d, 0x0e, 0x4b, 0x70, 0x08, 0x70, 0x84, 0x4b, 0x40, 0x70, 0xb0, 0x4f, 0x00, 0xe0,
-    0x81, 0x02, 0x4b, 0x70, 0x08, 0x70, 0x84, 0x4b, 0x40, 0xd4, 0x0d, 0x5a, 0x41, 0xc8, 0x04, 0x0d, 0x41, 0x4b, 0x70, 0x08, 0x70,
-    0x10, 0x43, 0x9c, 0x5f, 0x84, 0x00, 0x4c, 0x87, 0x5f, 0x80, 0x00, 0x48, 0x36, 0xbc, 0x00, 0x3c, 0x48, 0x40, 0x77, 0xfa, 0x84,
-    0x49, 0x47, 0x80, 0x46, 0x36, 0x84, 0x00, 0x36, 0xac, 0x00, 0xfc, 0x04, 0x40, 0x44, 0x4b, 0xe9, 0x3c, 0x5f, 0x84, 0x00, 0x40,
-    0x47, 0xe2, 0x84, 0x40, 0x45, 0x36, 0x6e, 0x00, 0x36, 0x96, 0x00, 0x28, 0x40, 0x4b, 0xd5, 0x87, 0x40, 0x57, 0x70, 0x90, 0x47,
-    0x36, 0x5e, 0x00, 0x94, 0x45, 0x47, 0xee, 0x36, 0x82, 0x00, 0x28, 0x40, 0x4b, 0xc1, 0x3f, 0x46, 0x40, 0x77, 0xbc, 0x36, 0x76,
-    0x00, 0x28, 0x40, 0x4b, 0xb5, 0xd4, 0x08, 0x46, 0x41, 0x3f, 0x41, 0x40, 0x77, 0xac, 0x28, 0x44, 0x77, 0x11, 0x36, 0x8b, 0x00,
-    0x36, 0x88, 0x00, 0x36, 0x85, 0x00, 0x84, 0x59, 0x40, 0x18, 0x43, 0x08, 0x84, 0x59, 0x40, 0x04, 0x64, 0x41, 0x70, 0x87, 0x51,
-    0x50, 0x70, 0x3f, 0x50, 0x51, 0x77, 0x89, 0x90, 0x41, 0x90, 0x40, 0x94, 0x44, 0x47, 0xf0, 0x36, 0x64, 0x00, 0x90, 0x48, 0xb8,
-    0x3f, 0x48, 0xb0, 0x5f, 0x80, 0x00, 0x48, 0x7a, 0x72, 0xff, 0xf4, 0x40, 0x46, 0x41, 0xf8, 0x0f, 0x41, 0x42, 0x9c, 0x42, 0x42,
-    0xd4, 0x03, 0x41, 0x41, 0xb8, 0x1e, 0x41, 0xf6, 0x81, 0xfc, 0xd8, 0x00, 0x00, 0x82, 0xdc, 0xd8, 0x00, 0x00, 0x40, 0xd4, 0x08,
-    0x46, 0x46, 0xb8, 0x5f, 0xff, 0x00, 0x46, 0xb4, 0x40, 0x46, 0x78, 0x28, 0x43, 0x77, 0x0a, 0x2c, 0x5c, 0x7f, 0x10, 0x6f, 0x00,
-    0x00, 0x78, 0x2c, 0x5c, 0x7f, 0x10, 0x6f, 0x00, 0x00, 0xf8, 0x0f, 0x40, 0x41, 0x2c, 0x5c, 0x7f, 0x10, 0x6f, 0x00, 0x00, 0xb8,
-    0x0f, 0x40, 0xd0, 0x04, 0x40, 0x40, 0xb0, 0x41, 0x40, 0x78, 0x28, 0x43, 0x77, 0x0d, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0x7f, 0xf4,
-    0x6e, 0x00, 0x00, 0x78, 0x84, 0x48, 0x40, 0xb8, 0x0f, 0x40, 0xb0, 0x6f, 0x40, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xf4,
-    0x6e, 0x00, 0x00, 0x84, 0x48, 0x40, 0xd4, 0x04, 0x40, 0x40, 0xb8, 0x0f, 0x40, 0xb0, 0x6f, 0x40, 0x40, 0xa0, 0x40, 0x2c, 0xcc,
-    0xfc, 0x7f, 0xf4, 0x6e, 0x00, 0x00, 0x78, 0x70, 0x70, 0x70, 0x10, 0x47, 0x9c, 0x4f, 0x10, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x4f,
-    0x00, 0x90, 0x71, 0x00, 0x47, 0x70, 0xb0, 0x4f, 0x00, 0xe0, 0x81, 0x02, 0x4b, 0x70, 0x84, 0x4f, 0x00, 0x00, 0x50, 0x00, 0x68,
-    0x70, 0x82, 0xd9, 0x08, 0x70, 0x80, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xf8, 0xc1, 0x71, 0x00, 0x41, 0x04, 0x7f,
-    0xec, 0xc1, 0x71, 0x00, 0x42, 0x70, 0x84, 0x47, 0x40, 0x84, 0x52, 0x50, 0x70, 0x9c, 0x04, 0x42, 0x9c, 0x04, 0x40, 0x3c, 0x42,
-    0x41, 0x77, 0xf3, 0x87, 0x01, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x48, 0x7b, 0x0b, 0x84,
-    0x48, 0x40, 0x9c, 0x04, 0x48, 0x80, 0x50, 0x70, 0x3c, 0x47, 0x48, 0x5b, 0xf4, 0x28, 0x5a, 0x7f, 0x07, 0x2c, 0x5c, 0xaf, 0xf0,
-    0x01, 0xa0, 0x4f, 0xcc, 0xdb, 0x00, 0x00, 0xa0, 0x0a, 0xa0, 0x0a, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00,
-    0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xe3, 0x13, 0x2c, 0x5c, 0xaf,
-    0x51, 0x03, 0xa0, 0x4f, 0xd5, 0xdb, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x2c, 0x19, 0x2c, 0x5c, 0xaf, 0x78, 0x19, 0x28, 0x5a,
-    0x7f, 0x08, 0x24, 0x7f, 0x9a, 0x20, 0x00, 0x00, 0xa0, 0x4f, 0xe3, 0xdb, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x11, 0x19, 0x2c,
-    0x5c, 0xaf, 0x6d, 0x10, 0x3c, 0x03, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x94, 0x20, 0x00, 0x00, 0xa0, 0x4f, 0xff, 0xdb, 0x00, 0x00,
-    0x2c, 0xcc, 0xfc, 0xaf, 0xf5, 0x18, 0x2c, 0x5c, 0xaf, 0xf9, 0x03, 0xa0, 0x4f, 0x08, 0xdc, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf,
-    0xe4, 0x18, 0x2c, 0x5c, 0xaf, 0x20, 0x06, 0xa0, 0x4f, 0x20, 0xdc, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xd3, 0x18, 0xa0, 0x5f,
-    0x80, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xf7, 0x06, 0xa0, 0x01, 0xa0, 0x4f, 0x50, 0xc3, 0x00, 0x00, 0x2c, 0xcc, 0xf8, 0xaf, 0x63,
-    0x18, 0xa0, 0x4f, 0x29, 0xdc, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xaf, 0x18, 0x2c, 0x5c, 0xaf, 0x4b, 0x11, 0xa0, 0x00, 0x2c,
-    0xcc, 0xfc, 0xaf, 0xd0, 0x06, 0xa0, 0x03, 0xa0, 0x5f, 0xa8, 0x61, 0x2c, 0xcc, 0xf8, 0xaf, 0x3e, 0x18, 0x2b, 0x7f, 0xf2, 0x06,
-    0x60, 0x00, 0x7f, 0x15, 0xa0, 0x4f, 0x3d, 0xdc, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x82, 0x18, 0x2c, 0x5c, 0xaf, 0x52, 0x19,
-    0x7b, 0x13, 0xa0, 0x4f, 0x51, 0xdc, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x6f, 0x18, 0x2c, 0x5c, 0xaf, 0xdd, 0x16, 0xa0, 0x04,
-    0xa0, 0x5f, 0xd4, 0x30, 0x2c, 0xcc, 0xf8, 0xaf, 0x06, 0x18, 0x7b, 0x03, 0x70, 0x28, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x77, 0xf9,
-    0x7a, 0xc8, 0xfe, 0x24, 0x7f, 0x69, 0x21, 0x00, 0x00, 0xa0, 0x4f, 0x64, 0xdc, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x3e, 0x18,
-    0x2c, 0x5c, 0xaf, 0x42, 0x03, 0xa0, 0x4f, 0x6d, 0xdc, 0x00, 0x00, 0xa0, 0x5f, 0x43, 0x01, 0xa0, 0x5f, 0x16, 0x03, 0x04, 0x6c,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c,
-    0xcc, 0xf8, 0xaf, 0xb5, 0x12, 0x2b, 0x7f, 0x86, 0x06, 0x60, 0x00, 0x7f, 0x2e, 0xa0, 0x4f, 0x7b, 0xdc, 0x00, 0x00, 0xa0, 0x5f,
-    0xb8, 0x01, 0xa0, 0x5f, 0x16, 0x03, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x83, 0x12, 0x7b, 0x2c, 0xa0, 0x4f, 0x7e, 0xdc, 0x00, 0x00,
-    0xa0, 0x5f, 0xb8, 0x01, 0xa0, 0x5f, 0x16, 0x03, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x57, 0x12, 0xa0, 0x4f, 0x83, 0xdc, 0x00, 0x00,
-    0x2c, 0xcc, 0xfc, 0xaf, 0xa5, 0x17, 0x2c, 0x5c, 0xaf, 0xe1, 0x04, 0xa0, 0x4f, 0x9b, 0xdc, 0x00, 0x00, 0x2c, 0xcc, 0xfc, 0xaf,
-    0x94, 0x17, 0xa0, 0x5f, 0x80, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xb8, 0x05, 0x2c, 0x5c, 0xaf, 0xba, 0x0f, 0x2c, 0x5c, 0xaf, 0x39,
-    0x14, 0x2c, 0x5c, 0xaf, 0x64, 0x01, 0x18, 0x47, 0x08, 0x10, 0x47, 0x9c, 0x6f, 0x70, 0x4c, 0xa0, 0x4f, 0xa4, 0xdc, 0x00, 0x00,
-    0xa0, 0x5f, 0x0d, 0x01, 0xa0, 0x5f, 0x8a, 0x02, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xed, 0x11, 0xa0, 0x4f, 0xc1, 0xdc, 0x00,
-    0x00, 0xa0, 0x5f, 0xee, 0x00, 0xa0, 0x5f, 0xc6, 0x02, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xc2, 0x11, 0xa0, 0x4f, 0xe5, 0xdc,
-    0x00, 0x00, 0xa0, 0x5f, 0x04, 0x01, 0xa0, 0x5f, 0xda, 0x02, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00,
-    0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x97, 0x11, 0xdc, 0x03, 0x7f,
-    0x78, 0xd8, 0x00, 0x00, 0x48, 0x04, 0x68, 0x47, 0x7b, 0x0a, 0x87, 0x58, 0x57, 0x70, 0x90, 0x48, 0x90, 0x47, 0x3f, 0x6f, 0x63,
-    0x58, 0x77, 0xf4, 0x83, 0x57, 0x70, 0xe0, 0x68, 0xa0, 0x5f, 0xe5, 0x01, 0xa0, 0x5f, 0xda, 0x02, 0x04, 0xc9, 0x6c, 0x42, 0x2c,
-    0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8,
-    0xaf, 0x52, 0x11, 0xa0, 0x4f, 0xc0, 0xdd, 0x00, 0x00, 0xa0, 0x5f, 0x0d, 0x01, 0xa0, 0x5f, 0xee, 0x02, 0x04, 0xc9, 0x6c, 0x42,
-    0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xf8, 0xaf, 0x27, 0x11, 0xa0, 0x4f, 0xff, 0xdc, 0x00, 0x00, 0xa0, 0x5f, 0xe0, 0x00, 0xa0, 0x5f, 0x02, 0x03, 0x04, 0xc9, 0x6c,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c,
-    0xcc, 0xf8, 0xaf, 0xfc, 0x10, 0xa0, 0x7f, 0xec, 0x06, 0x01, 0x00, 0xe0, 0x68, 0x2c, 0xcc, 0xf8, 0x7f, 0x14, 0x6b, 0x00, 0x00,
-    0xe0, 0x68, 0xa0, 0x5f, 0x00, 0x02, 0xa0, 0x5f, 0x02, 0x03, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00,
-    0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xc5, 0x10, 0x18, 0x47, 0x08,
-    0x10, 0x49, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x04, 0x7f, 0xf8, 0xc1, 0x71, 0x00, 0x41, 0x04, 0x7f, 0xec, 0xc1, 0x71,
-    0x00, 0x42, 0x70, 0x84, 0x47, 0x40, 0x84, 0x50, 0x52, 0x70, 0x9c, 0x04, 0x42, 0x9c, 0x04, 0x40, 0x3c, 0x42, 0x41, 0x77, 0xf3,
-    0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x44, 0x84, 0x5f, 0xff, 0x7f, 0x44, 0x83, 0x47, 0x80, 0x48, 0x84, 0x48, 0x45, 0x86,
-    0x45, 0x45, 0xfa, 0x5f, 0xff, 0x00, 0x45, 0xe3, 0x42, 0x24, 0x7f, 0x99, 0x23, 0x00, 0x00, 0x87, 0x47, 0xe0, 0x40, 0xd0, 0x10,
-    0x40, 0x40, 0x9c, 0x4f, 0xff, 0xff, 0x00, 0x00, 0x40, 0x84, 0x40, 0x44, 0x86, 0xe2, 0x45, 0xe0, 0x40, 0x88, 0x40, 0x40, 0x86,
-    0x40, 0x45, 0x83, 0x42, 0x93, 0x47, 0x7b, 0x62, 0x2b, 0x42, 0x7f, 0x18, 0x3f, 0x04, 0x47, 0x77, 0x13, 0x84, 0x5f, 0xff, 0x7f,
-    0x44, 0x83, 0x47, 0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x7b, 0xb0, 0x18, 0x44, 0x08, 0x86, 0xe2, 0x45, 0xe0, 0x40,
-    0x87, 0x58, 0xe2, 0x41, 0xba, 0x5f, 0xff, 0x00, 0x41, 0x86, 0xe2, 0x41, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0x86, 0x40, 0x45, 0x86,
-    0xe2, 0x45, 0xe0, 0x40, 0x38, 0x40, 0x4f, 0x00, 0x80, 0x00, 0x00, 0x7f, 0x10, 0x86, 0xe2, 0x45, 0xe0, 0x40, 0xd0, 0x01, 0x40,
-    0x40, 0xb2, 0x01, 0x40, 0x7b, 0x0b, 0x86, 0xe2, 0x45, 0xe0, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x86, 0x40, 0x45, 0x90, 0x48, 0xfc,
-    0x01, 0x44, 0x40, 0x3c, 0x40, 0x48, 0x5b, 0xb6, 0x86, 0xe2, 0x45, 0xe0, 0x40, 0x88, 0x40, 0x40, 0x86, 0x40, 0x45, 0x87, 0xc4,
-    0xff, 0xe0, 0x40, 0x86, 0xe2, 0x45, 0xe0, 0x41, 0xb8, 0x5f, 0xff, 0x00, 0x41, 0x3c, 0x41, 0x40, 0x7f, 0x05, 0x87, 0x01, 0x42,
-    0x87, 0x54, 0xe0, 0x40, 0x86, 0xe2, 0x45, 0xe0, 0x41, 0xd4, 0x08, 0x41, 0x41, 0x3c, 0x41, 0x40, 0x7f, 0x05, 0x87, 0x01, 0x42,
-    0x2b, 0x42, 0x7e, 0x5e, 0xff, 0x3f, 0x04, 0x47, 0x42, 0x58, 0xff, 0x7a, 0x32, 0xff, 0x70, 0x10, 0x43, 0x9c, 0x4f, 0x04, 0x00,
-    0x00, 0x00, 0x4c, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x45, 0x84, 0x4f, 0x00, 0x00, 0x72, 0x00, 0x46, 0x84, 0x4f, 0x00, 0x00,
-    0x74, 0x00, 0x47, 0x84, 0x00, 0x44, 0x84, 0x4f, 0x00, 0x00, 0x50, 0x00, 0x59, 0x70, 0x80, 0x7f, 0x2c, 0x97, 0x71, 0x00, 0x70,
-    0x84, 0x7f, 0x2c, 0x97, 0x71, 0x00, 0x40, 0x86, 0x40, 0x40, 0x86, 0x40, 0xd9, 0x00, 0x70, 0x86, 0x40, 0xe4, 0x7f, 0x28, 0x97,
-    0x71, 0x00, 0x70, 0x84, 0x46, 0x40, 0x84, 0x47, 0x41, 0x84, 0x00, 0x42, 0x84, 0x42, 0x50, 0x70, 0x3c, 0x42, 0x50, 0x7f, 0x18,
-    0x84, 0x01, 0x7f, 0x28, 0x97, 0x71, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x39, 0x24, 0x00,
-    0x00, 0x9c, 0x4f, 0x01, 0x00, 0x01, 0x00, 0x42, 0x9c, 0x04, 0x40, 0x3c, 0x40, 0x41, 0x77, 0xd4, 0x84, 0x46, 0x40, 0x84, 0x47,
-    0x41, 0x84, 0x00, 0x42, 0x3c, 0x42, 0x50, 0x7f, 0x18, 0x84, 0x01, 0x7f, 0x28, 0x97, 0x71, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x24,
-    0x97, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x30, 0x24, 0x00, 0x00, 0x9c, 0x4f, 0x01, 0x00, 0x01, 0x00, 0x42, 0x9c, 0x04, 0x40, 0x3c,
-    0x40, 0x41, 0x77, 0xd8, 0x84, 0x45, 0x40, 0x84, 0x46, 0x41, 0x84, 0x50, 0x51, 0x70, 0x9c, 0x04, 0x40, 0x9c, 0x04, 0x41, 0x3c,
-    0x40, 0x46, 0x77, 0xf3, 0x86, 0x5f, 0x00, 0x80, 0xd9, 0x00, 0x70, 0x84, 0x45, 0x40, 0x84, 0x46, 0x41, 0x84, 0x00, 0x42, 0x84,
-    0x42, 0x50, 0x70, 0x3c, 0x42, 0x50, 0x7f, 0x18, 0x84, 0x01, 0x7f, 0x28, 0x97, 0x71, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x24, 0x97,
-    0x71, 0x00, 0x70, 0x24, 0x7f, 0xbf, 0x24, 0x00, 0x00, 0x9c, 0x4f, 0x01, 0x00, 0x01, 0x00, 0x42, 0x9c, 0x04, 0x40, 0x3c, 0x40,
-    0x41, 0x77, 0xd4, 0x84, 0x45, 0x40, 0x84, 0x46, 0x41, 0x84, 0x00, 0x42, 0x3c, 0x42, 0x50, 0x7f, 0x18, 0x84, 0x01, 0x7f, 0x28,
-    0x97, 0x71, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x24, 0x7f, 0xb6, 0x24, 0x00, 0x00, 0x9c, 0x4f, 0x01,
-    0x00, 0x01, 0x00, 0x42, 0x9c, 0x04, 0x40, 0x3c, 0x40, 0x41, 0x77, 0xd8, 0x84, 0x45, 0x40, 0x84, 0x46, 0x41, 0x3c, 0x41, 0x4f,
-    0x00, 0x00, 0x74, 0x00, 0x7e, 0x0f, 0x00, 0x84, 0x51, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x9c, 0x04, 0x40, 0x7b, 0xec, 0x84, 0x00,
-    0x42, 0x3c, 0x42, 0x44, 0x76, 0xaf, 0xfe, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x46, 0x84, 0x4f, 0x00, 0x00, 0x74, 0x00, 0x47,
-    0x84, 0x00, 0x56, 0x70, 0x84, 0x6f, 0x55, 0x57, 0x70, 0x3c, 0x56, 0x57, 0x7e, 0x9f, 0x00, 0x84, 0x01, 0x7f, 0x2c, 0x97, 0x71,
-    0x00, 0x70, 0x84, 0x4f, 0x00, 0x00, 0x74, 0x00, 0x46, 0x84, 0x4f, 0x00, 0x00, 0x80, 0x00, 0x47, 0x84, 0x46, 0x40, 0x84, 0x47,
-    0x41, 0x84, 0x00, 0x42, 0x84, 0x42, 0x50, 0x70, 0x3c, 0x42, 0x50, 0x7f, 0x18, 0x84, 0x01, 0x7f, 0x28, 0x97, 0x71, 0x00, 0x70,
-    0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x81, 0x25, 0x00, 0x00, 0x3c, 0x42, 0xff, 0x7f, 0x0f, 0x9c, 0x4f,
-    0x01, 0x00, 0x01, 0x00, 0x42, 0x24, 0x7f, 0xb5, 0x25, 0x00, 0x00, 0x84, 0x00, 0x42, 0x9c, 0x04, 0x40, 0x3c, 0x40, 0x41, 0x77,
-    0xc6, 0x84, 0x46, 0x40, 0x84, 0x47, 0x41, 0x84, 0x00, 0x42, 0x3c, 0x42, 0x50, 0x7f, 0x18, 0x84, 0x01, 0x7f, 0x28, 0x97, 0x71,
-    0x00, 0x70, 0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x78, 0x25, 0x00, 0x00, 0x3c, 0x42, 0xff, 0x7f, 0x0f,
-    0x9c, 0x4f, 0x01, 0x00, 0x01, 0x00, 0x42, 0x24, 0x7f, 0xf6, 0x25, 0x00, 0x00, 0x84, 0x00, 0x42, 0x9c, 0x04, 0x40, 0x3c, 0x40,
-    0x41, 0x77, 0xca, 0x2c, 0x5c, 0x7f, 0xfc, 0x43, 0x00, 0x00, 0x87, 0x7f, 0x2f, 0x97, 0x71, 0x00, 0x7f, 0x86, 0x06, 0x60, 0x00,
-    0x70, 0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0x82, 0xd9, 0x00, 0x70, 0x18, 0x43, 0x08, 0x70, 0x10, 0x45, 0x84, 0x4f, 0x00,
-    0x00, 0x72, 0x00, 0x42, 0x84, 0x4f, 0x02, 0x00, 0x60, 0x00, 0x48, 0x80, 0x45, 0x7b, 0x0d, 0x87, 0x58, 0x52, 0x70, 0x90, 0x42,
-    0x9c, 0x04, 0x48, 0x90, 0x45, 0x3c, 0x5f, 0x00, 0x08, 0x45, 0x5b, 0xf0, 0x7b, 0x0a, 0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00,
-    0x70, 0x84, 0x4f, 0x02, 0x00, 0x60, 0x00, 0x45, 0x7b, 0x18, 0x84, 0x45, 0x47, 0xf8, 0x5f, 0xff, 0x00, 0x45, 0xe3, 0x40, 0xbb,
-    0x5f, 0xff, 0x00, 0x40, 0x87, 0x40, 0x57, 0x70, 0x9c, 0x04, 0x45, 0x3c, 0x4f, 0x00, 0x20, 0x60, 0x00, 0x45, 0x5b, 0xe3, 0x84,
-    0x4f, 0x02, 0x00, 0x60, 0x00, 0x45, 0x7b, 0x18, 0xf8, 0x5f, 0xff, 0x00, 0x45, 0xe3, 0x40, 0xbb, 0x5f, 0xff, 0x00, 0x40, 0x8b,
-    0x40, 0x40, 0x87, 0x40, 0x57, 0x70, 0x9c, 0x04, 0x45, 0x3c, 0x4f, 0x00, 0x20, 0x60, 0x00, 0x45, 0x5b, 0x0b, 0x84, 0x4f, 0x02,
-    0x00, 0x60, 0x00, 0x45, 0x7b, 0x19, 0x84, 0x45, 0x47, 0xf8, 0x5f, 0xff, 0x00, 0x45, 0x40, 0x87, 0x57, 0xe0, 0x41, 0x3c, 0x41,
-    0x40, 0x7f, 0xc8, 0x7b, 0x8d, 0x9c, 0x04, 0x45, 0x3c, 0x4f, 0x00, 0x20, 0x60, 0x00, 0x45, 0x5b, 0x14, 0x84, 0x4f, 0x00, 0x00,
-    0x72, 0x00, 0x42, 0x84, 0x4f, 0x02, 0x00, 0x60, 0x00, 0x48, 0x80, 0x45, 0x7b, 0x27, 0x84, 0x45, 0x47, 0xf8, 0x5f, 0xff, 0x00,
-    0x45, 0xe3, 0x40, 0xbb, 0x5f, 0xff, 0x00, 0x40, 0x8b, 0x40, 0x40, 0x3f, 0x57, 0x40, 0x7f, 0xcd, 0x7a, 0x56, 0xff, 0x87, 0x52,
-    0x58, 0x70, 0x90, 0x42, 0x9c, 0x04, 0x48, 0x90, 0x45, 0x3c, 0x5f, 0x00, 0x08, 0x45, 0x5b, 0xf0, 0x18, 0x45, 0x08, 0x70, 0x70,
-    0x10, 0x49, 0x9c, 0x4f, 0x30, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x4f, 0x13, 0x00, 0x20, 0x00, 0xc9, 0x2c, 0x70, 0x84, 0x4f, 0x17,
-    0x00, 0x20, 0x00, 0xc9, 0x20, 0x70, 0x84, 0x4f, 0x37, 0x00, 0x20, 0x00, 0x7f, 0x44, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x3b,
-    0x00, 0x20, 0x00, 0xc9, 0x24, 0x70, 0x84, 0x4f, 0x3f, 0x00, 0x20, 0x00, 0xc9, 0x28, 0x70, 0x87, 0x11, 0xc9, 0x17, 0x70, 0x87,
-    0x6f, 0x44, 0xc9, 0x18, 0x70, 0x87, 0x6f, 0x66, 0xc9, 0x19, 0x70, 0x87, 0x5f, 0x88, 0x00, 0xc9, 0x1a, 0x70, 0x87, 0x5f, 0x99,
-    0x00, 0xc9, 0x1b, 0x70, 0x87, 0x5f, 0xbb, 0x00, 0xc9, 0x1c, 0x70, 0x87, 0x5f, 0xcc, 0x00, 0xc9, 0x1d, 0x70, 0x80, 0x6c, 0x70,
-    0xf8, 0x5f, 0xff, 0x00, 0x6c, 0xe3, 0x40, 0x87, 0x40, 0xc9, 0x15, 0x70, 0x24, 0x7f, 0x4c, 0x2e, 0x00, 0x00, 0x3c, 0x02, 0x6c,
-    0x4b, 0x11, 0x80, 0x6c, 0x70, 0xf8, 0x5f, 0xff, 0x00, 0x6c, 0xe3, 0x40, 0x87, 0x40, 0xc9, 0x15, 0x70, 0x84, 0x6c, 0x40, 0x90,
-    0x6c, 0x70, 0x28, 0x40, 0x77, 0x34, 0x84, 0x4f, 0x03, 0x00, 0x20, 0x00, 0x7f, 0x38, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x07,
-    0x00, 0x20, 0x00, 0x7f, 0x3c, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x0b, 0x00, 0x20, 0x00, 0x7f, 0x34, 0x97, 0x71, 0x00, 0x70,
-    0x84, 0x4f, 0x0f, 0x00, 0x20, 0x00, 0x7f, 0x40, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x32, 0x84, 0x4f, 0x23, 0x00, 0x20, 0x00, 0x7f,
-    0x38, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x27, 0x00, 0x20, 0x00, 0x7f, 0x3c, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x2b, 0x00,
-    0x20, 0x00, 0x7f, 0x34, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x2f, 0x00, 0x20, 0x00, 0x7f, 0x40, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0x22, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x38, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x50, 0xef, 0x34, 0x97,
-    0x71, 0x00, 0x70, 0x87, 0x5f, 0xa3, 0x00, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f, 0xa7, 0x00, 0xef, 0x38, 0x97, 0x71,
-    0x00, 0x70, 0x83, 0xd9, 0x2c, 0x70, 0x83, 0xef, 0x44, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0xd9, 0x28, 0x70, 0x83, 0xd9, 0x20,
-    0x70, 0x87, 0x01, 0xc9, 0x10, 0x70, 0x87, 0x5f, 0xfe, 0x00, 0xc9, 0x12, 0x70, 0x7b, 0x74, 0x87, 0x10, 0xef, 0x34, 0x97, 0x71,
-    0x00, 0x70, 0x87, 0xc9, 0x12, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x3f, 0xc9, 0x10, 0x40, 0x87, 0x40, 0xef, 0x38, 0x97,
-    0x71, 0x00, 0x70, 0x87, 0x10, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x38, 0x97, 0x71, 0x00, 0xc9, 0x13, 0x70, 0x87,
-    0xef, 0x38, 0x97, 0x71, 0x00, 0xc9, 0x11, 0x70, 0x3f, 0xc9, 0x12, 0xc9, 0x13, 0x77, 0x11, 0x87, 0xc9, 0x11, 0xe0, 0x40, 0xfb,
-    0x3f, 0xc9, 0x10, 0x41, 0x3c, 0x41, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x87, 0xc9, 0x10, 0xe0, 0x40, 0xd0,
-    0x01, 0x40, 0x40, 0x87, 0x40, 0xc9, 0x10, 0x70, 0x87, 0xc9, 0x12, 0xe0, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x93, 0x40, 0x87, 0x40,
-    0xc9, 0x12, 0x70, 0x2b, 0xc9, 0x10, 0x77, 0x8b, 0x87, 0x38, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x22, 0xef, 0x34, 0x97,
-    0x71, 0x00, 0x70, 0x87, 0x10, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x03, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0xf3, 0x0f,
-    0x73, 0x40, 0x87, 0x40, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f, 0xcc, 0x00, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0x5f, 0x80, 0x00, 0xd9, 0x2c, 0x70, 0x87, 0x05, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x55, 0xc9, 0x10, 0x70, 0x80,
-    0x68, 0x70, 0x7b, 0x67, 0x87, 0xc9, 0x10, 0xef, 0x40, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x00, 0xa0, 0x01, 0x2c, 0xcc, 0xf8, 0xaf,
-    0x32, 0x05, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef,
-    0x40, 0x97, 0x71, 0x00, 0xc9, 0x11, 0x70, 0x3f, 0xc9, 0x10, 0xc9, 0x11, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x28,
-    0x68, 0x77, 0x26, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x3b, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x01, 0x7f, 0x07, 0x87, 0x01,
-    0x40, 0x7b, 0x04, 0x83, 0x40, 0x87, 0x40, 0xc9, 0x11, 0x70, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x90, 0x68, 0x70,
-    0x3c, 0x02, 0x68, 0x4b, 0x98, 0x80, 0x64, 0x70, 0x7b, 0x30, 0x04, 0xc9, 0x17, 0x40, 0x9c, 0x64, 0x40, 0x87, 0x50, 0xe0, 0x40,
-    0xa0, 0x40, 0xa0, 0x03, 0xa0, 0x0f, 0xa0, 0x00, 0xa0, 0x02, 0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8, 0xaf, 0x75,
-    0x05, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x90, 0x64, 0x70, 0x3c, 0x07, 0x64, 0x4b, 0xcf, 0xa0, 0x5f,
-    0xcc, 0x00, 0xa0, 0x02, 0xa0, 0x0f, 0xa0, 0x00, 0xa0, 0x02, 0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8, 0xaf, 0x4b,
-    0x05, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0xa0, 0x5f, 0xcc, 0x00, 0xa0, 0x01, 0xa0, 0x0f, 0xa0, 0x00,
-    0xa0, 0x02, 0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8, 0xaf, 0x29, 0x05, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40,
-    0x2e, 0x00, 0x00, 0xa0, 0x5f, 0xcc, 0x00, 0xa0, 0x00, 0xa0, 0x0f, 0xa0, 0x00, 0xa0, 0x02, 0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xe8, 0xaf, 0x07, 0x05, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0xa0, 0x5f, 0xcc, 0x00, 0xa0,
-    0x03, 0xa0, 0x0f, 0xa0, 0x10, 0xa0, 0x02, 0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8, 0xaf, 0xe5, 0x04, 0x28, 0x40,
-    0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0xa0, 0x5f, 0xcc, 0x00, 0xa0, 0x03, 0xa0, 0x07, 0xa0, 0x00, 0xa0, 0x04, 0x87,
-    0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8, 0xaf, 0xc3, 0x04, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00,
-    0xa0, 0x5f, 0xcc, 0x00, 0xa0, 0x03, 0xa0, 0x08, 0xa0, 0x00, 0xa0, 0x04, 0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8,
-    0xaf, 0xa1, 0x04, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x87, 0x22, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70,
-    0x87, 0x38, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x10, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x03, 0xef, 0x38, 0x97,
-    0x71, 0x00, 0x70, 0xf3, 0x0f, 0x73, 0x40, 0x87, 0x40, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x66, 0xef, 0x3c, 0x97,
-    0x71, 0x00, 0x70, 0x83, 0xd9, 0x2c, 0x70, 0x87, 0x05, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x6f, 0x55, 0xa0, 0x04, 0x2c,
-    0xcc, 0xf8, 0xaf, 0x8b, 0x03, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x87, 0x5f, 0xaa, 0x00, 0xef, 0x40,
-    0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x0c, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x40, 0x87, 0x40,
-    0xc9, 0x11, 0x70, 0x2b, 0xc9, 0x11, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x80, 0x68, 0x70, 0x7b, 0x22, 0xa0, 0x00,
-    0xa0, 0x01, 0x2c, 0xcc, 0xf8, 0xaf, 0x49, 0x03, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x87, 0xef, 0x40,
-    0x97, 0x71, 0x00, 0xc9, 0x11, 0x70, 0x90, 0x68, 0x70, 0x3c, 0x02, 0x68, 0x4b, 0xdd, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70,
-    0xfb, 0x0c, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x40, 0x87, 0x40, 0xc9, 0x11, 0x70, 0x3f, 0x0c, 0xc9, 0x11, 0x7f, 0x08, 0x24, 0x7f,
-    0x40, 0x2e, 0x00, 0x00, 0x87, 0x22, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x38, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0x10, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x07, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0xf3, 0x0f, 0x73, 0x40, 0x87, 0x40,
-    0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x66, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x70, 0x83, 0xd9, 0x2c, 0x70, 0x87, 0x05,
-    0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x60, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x5f, 0x10, 0x27, 0x2c, 0xcc,
-    0xfc, 0xaf, 0x66, 0x03, 0x87, 0x6f, 0x70, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xfb,
-    0x5f, 0x80, 0x00, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x40, 0x87, 0x40, 0xc9, 0x11, 0x70, 0x3f, 0x5f, 0x80, 0x00, 0xc9, 0x11, 0x7f,
-    0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x40, 0x97, 0x71, 0x00, 0xc9,
-    0x11, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x5f, 0x80, 0x00, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x40, 0x87, 0x40,
-    0xc9, 0x11, 0x70, 0x2b, 0xc9, 0x11, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e, 0x00, 0x00, 0x80, 0x68, 0x70, 0x7b, 0x72, 0x87, 0xc9,
-    0x10, 0xe0, 0x40, 0xa0, 0x40, 0xa0, 0x04, 0x2c, 0xcc, 0xf8, 0xaf, 0x48, 0x02, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e,
-    0x00, 0x00, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x09, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x3b, 0xef, 0x3c,
-    0x97, 0x71, 0x00, 0x01, 0x7f, 0xf2, 0x87, 0xc9, 0x10, 0xe0, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x9f, 0x6b, 0x40, 0x87, 0x40, 0xc9,
-    0x10, 0x70, 0x3c, 0x02, 0x68, 0x77, 0x27, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x02, 0xef, 0x3c, 0x97, 0x71, 0x00,
-    0x40, 0x87, 0x40, 0xc9, 0x14, 0x70, 0x87, 0x40, 0xc9, 0x11, 0x70, 0x3f, 0x02, 0xc9, 0x14, 0x7f, 0x08, 0x24, 0x7f, 0x40, 0x2e,
-    0x00, 0x00, 0x90, 0x68, 0x70, 0x3c, 0x04, 0x68, 0x4b, 0x8d, 0x87, 0x6f, 0x55, 0xef, 0x40, 0x97, 0x71, 0x00, 0x70, 0x80, 0x59,
-    0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x09, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x10, 0xef, 0x3c,
-    0x97, 0x71, 0x00, 0x40, 0x87, 0x40, 0xc9, 0x14, 0x70, 0x87, 0x40, 0xc9, 0x11, 0x70, 0x2b, 0xc9, 0x11, 0x7f, 0xe4, 0x87, 0x6f,
-    0x40, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x5f, 0xf0, 0x00, 0xef, 0x3c, 0x97,
-    0x71, 0x00, 0x40, 0x87, 0x40, 0xc9, 0x14, 0x70, 0x87, 0x40, 0xc9, 0x11, 0x70, 0x2b, 0xc9, 0x14, 0x7f, 0x08, 0x24, 0x7f, 0x40,
-    0x2e, 0x00, 0x00, 0x83, 0xc9, 0x11, 0x70, 0x2b, 0x73, 0x7f, 0x08, 0x24, 0x7f, 0xfb, 0x2d, 0x00, 0x00, 0x3c, 0x01, 0x6c, 0x7f,
-    0x08, 0x24, 0x7f, 0xfb, 0x2d, 0x00, 0x00, 0x87, 0x22, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x38, 0xef, 0x34, 0x97, 0x71,
-    0x00, 0x70, 0x87, 0x10, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x03, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0xf3, 0x07, 0x73,
-    0x40, 0x87, 0x40, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f, 0xbb, 0x00, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f,
-    0x80, 0x00, 0xd9, 0x2c, 0x70, 0x83, 0x7f, 0x32, 0x97, 0x71, 0x00, 0x70, 0x87, 0x7f, 0x32, 0x97, 0x71, 0x00, 0x7f, 0x31, 0x97,
-    0x71, 0x00, 0x70, 0x83, 0xd9, 0x20, 0x70, 0x87, 0x6f, 0x53, 0xef, 0x44, 0x97, 0x71, 0x00, 0x70, 0x87, 0x20, 0xd9, 0x28, 0x70,
-    0x87, 0x05, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x5f, 0xc4, 0x09, 0x2c, 0xcc, 0xfc, 0xaf, 0x9f, 0x01, 0x70, 0xb8, 0x4f,
-    0xff, 0x1f, 0xfe, 0xff, 0x4b, 0x70, 0xa0, 0x5f, 0xc4, 0x09, 0x2c, 0xcc, 0xfc, 0xaf, 0x8c, 0x01, 0x7b, 0x24, 0x83, 0xef, 0x34,
-    0x97, 0x71, 0x00, 0x70, 0x7b, 0x09, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x2b, 0x7f, 0x31, 0x97, 0x71, 0x00, 0x7f, 0xf3,
-    0x87, 0x5f, 0xaa, 0x00, 0xef, 0x40, 0x97, 0x71, 0x00, 0x70, 0x2b, 0x7f, 0x32, 0x97, 0x71, 0x00, 0x7f, 0xd8, 0x87, 0x03, 0xef,
-    0x44, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0xd9, 0x24, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x44, 0x97,
-    0x71, 0x00, 0xc9, 0x16, 0x70, 0x3b, 0xc9, 0x16, 0x6f, 0x70, 0x7f, 0x06, 0x93, 0xc9, 0x15, 0x70, 0x87, 0x01, 0xd9, 0x28, 0x70,
-    0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x44, 0x97, 0x71, 0x00, 0xc9, 0x16, 0x70, 0xfb, 0x6f, 0x70, 0xc9, 0x16,
-    0x40, 0x3c, 0x6f, 0x70, 0x40, 0x7f, 0x06, 0x93, 0xc9, 0x15, 0x70, 0x87, 0x20, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x30,
-    0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x10, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x19, 0x83, 0xef, 0x34, 0x97, 0x71,
-    0x00, 0x70, 0x87, 0xef, 0x40, 0x97, 0x71, 0x00, 0xc9, 0x14, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x01, 0xef,
-    0x3c, 0x97, 0x71, 0x00, 0x40, 0x3c, 0x01, 0x40, 0x7f, 0xde, 0x2b, 0xc9, 0x15, 0x77, 0x04, 0x7b, 0x0e, 0x93, 0xc9, 0x15, 0x70,
-    0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x2b, 0xc9, 0x15, 0x76, 0x38, 0xf9, 0x3c, 0x02, 0x6c, 0x4a, 0x32, 0xf9, 0x18,
-    0x49, 0x08, 0x70, 0x10, 0x48, 0x84, 0x74, 0x48, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x3c, 0x04, 0x48, 0x77, 0x0a, 0x87,
-    0x73, 0xef, 0x40, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x19, 0x18, 0x48, 0x08, 0xa0, 0x5f,
-    0xc4, 0x09, 0x84, 0x59, 0x41, 0x70, 0x84, 0x41, 0x40, 0x94, 0x41, 0x28, 0x40, 0x47, 0xf8, 0xbc, 0x04, 0x4c, 0x87, 0xef, 0x3c,
-    0x97, 0x71, 0x00, 0x41, 0x87, 0x41, 0xe0, 0x40, 0x38, 0x40, 0x48, 0x7f, 0xde, 0x80, 0x40, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10,
-    0x49, 0x9c, 0x4f, 0x04, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x4f, 0x3f, 0x00, 0x20, 0x00, 0x59, 0x70, 0x87, 0x01, 0x7f, 0x31, 0x97,
-    0x71, 0x00, 0x70, 0x87, 0x13, 0xef, 0x44, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x40, 0xd9, 0x00, 0x70, 0xa0, 0x5f, 0xe2, 0x04,
-    0x2c, 0xcc, 0xfc, 0xaf, 0x31, 0x00, 0x30, 0xc8, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x08, 0x00, 0x00, 0x00,
-    0x4c, 0x84, 0x4f, 0x0f, 0x00, 0x20, 0x00, 0x59, 0x70, 0x87, 0xd9, 0x00, 0x64, 0x70, 0x87, 0x01, 0x7f, 0x32, 0x97, 0x71, 0x00,
-    0x70, 0x30, 0xc8, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x41, 0x70, 0x84, 0x41, 0x40, 0x94, 0x41, 0x28, 0x40, 0x47,
-    0xf8, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x46, 0x87, 0x77, 0x46, 0x87, 0x22, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x38,
-    0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x10, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0xf3, 0x00, 0xca, 0x0f, 0x40, 0xb3, 0xca,
-    0x13, 0x40, 0xb3, 0x46, 0x40, 0x87, 0x40, 0xef, 0x38, 0x97, 0x71, 0x00, 0x70, 0xf3, 0x7b, 0xca, 0x17, 0x40, 0x87, 0x40, 0xef,
-    0x38, 0x97, 0x71, 0x00, 0x70, 0x87, 0x73, 0xef, 0x3c, 0x97, 0x71, 0x00, 0x70, 0x3f, 0x5f, 0xcc, 0x00, 0x73, 0x77, 0x0e, 0x87,
-    0x5f, 0x80, 0x00, 0xef, 0x44, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x09, 0x83, 0xef, 0x44, 0x97, 0x71, 0x00, 0x70, 0x87, 0x05, 0xef,
-    0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0x48, 0x83, 0x47, 0x7b, 0x7d, 0x18, 0x46, 0x08, 0xa0, 0x04, 0x84, 0x59, 0x42, 0x84,
-    0x00, 0x41, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x3c, 0x97, 0x71, 0x00, 0xe0, 0x40, 0x38, 0x40, 0x42, 0x7f,
-    0xee, 0x80, 0x40, 0xbc, 0x04, 0x4c, 0x28, 0x40, 0x77, 0x42, 0x87, 0x48, 0xef, 0x40, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x01, 0x84,
-    0x59, 0x42, 0x84, 0x00, 0x41, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x3c, 0x97, 0x71, 0x00, 0xe0, 0x40, 0x38,
-    0x40, 0x42, 0x7f, 0xee, 0x80, 0x40, 0xbc, 0x04, 0x4c, 0x28, 0x40, 0x77, 0x15, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0xef, 0x40, 0x97, 0x71, 0x00, 0x42, 0x3f, 0x48, 0x42, 0x7f, 0x08, 0x84, 0x01, 0x40, 0x18, 0x46, 0x08, 0x87, 0x48, 0xe0, 0x40,
-    0xd0, 0x01, 0x40, 0x40, 0x87, 0x40, 0x48, 0x93, 0x47, 0x87, 0x47, 0xe0, 0x40, 0xdf, 0x05, 0x46, 0x41, 0x3c, 0x41, 0x40, 0x5a,
-    0x7d, 0xff, 0x80, 0x40, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x42, 0x84, 0x00, 0x41, 0x83, 0xef, 0x34,
-    0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x3c, 0x97, 0x71, 0x00, 0xe0, 0x40, 0x38, 0x40, 0x42, 0x7f, 0xee, 0x80, 0x40, 0x18, 0x49,
-    0x08, 0x18, 0x49, 0x08, 0x10, 0x43, 0x9c, 0x20, 0x4c, 0x84, 0x4f, 0x13, 0x00, 0x20, 0x00, 0x43, 0x84, 0x4f, 0x17, 0x00, 0x20,
-    0x00, 0x45, 0x84, 0x4f, 0x23, 0x00, 0x20, 0x00, 0x47, 0x84, 0x4f, 0x27, 0x00, 0x20, 0x00, 0x48, 0x84, 0x4f, 0x2b, 0x00, 0x20,
-    0x00, 0x42, 0x84, 0x4f, 0x2f, 0x00, 0x20, 0x00, 0xc9, 0x14, 0x70, 0x84, 0x4f, 0x3f, 0x00, 0x20, 0x00, 0x44, 0x84, 0x4f, 0x37,
-    0x00, 0x20, 0x00, 0x46, 0x87, 0x38, 0x52, 0x70, 0x87, 0x22, 0x52, 0x70, 0x87, 0x6f, 0x40, 0x52, 0x70, 0x87, 0x10, 0x52, 0x70,
-    0x87, 0x03, 0x57, 0x70, 0x87, 0x07, 0x57, 0x70, 0x87, 0x5f, 0x99, 0x00, 0x58, 0x70, 0x87, 0x5f, 0x80, 0x00, 0x53, 0x70, 0x83,
-    0x56, 0x70, 0x87, 0x5f, 0xff, 0x00, 0x54, 0x70, 0x83, 0x55, 0x70, 0x87, 0x05, 0x52, 0x70, 0x87, 0x02, 0xd9, 0x14, 0x70, 0x83,
-    0x52, 0x70, 0x3b, 0x58, 0x21, 0x7f, 0xfa, 0xfb, 0x21, 0x58, 0x40, 0x3c, 0x21, 0x40, 0x77, 0x23, 0x87, 0x6f, 0x40, 0x52, 0x70,
-    0x83, 0x52, 0x70, 0x87, 0xd9, 0x14, 0xe0, 0x41, 0x83, 0x52, 0x70, 0x38, 0x41, 0x01, 0x77, 0x08, 0x84, 0x01, 0x40, 0x18, 0x43,
-    0x08, 0x84, 0x02, 0x40, 0x18, 0x43, 0x08, 0x83, 0x52, 0x70, 0xfb, 0x01, 0x58, 0x40, 0x3c, 0x01, 0x40, 0x77, 0x1b, 0x83, 0x52,
-    0x70, 0x87, 0xd9, 0x14, 0xe0, 0x41, 0x3c, 0x02, 0x41, 0x77, 0x08, 0x84, 0x03, 0x40, 0x18, 0x43, 0x08, 0x84, 0x04, 0x40, 0x18,
-    0x43, 0x08, 0x18, 0x43, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x08, 0x4c, 0x84, 0x4f, 0x00, 0x00, 0x50, 0x00, 0x48, 0x80, 0x7f, 0x58,
-    0x97, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0xaf, 0x59, 0x00, 0x82, 0x58, 0x70, 0xa0, 0x4f, 0x20, 0xdd, 0x00, 0x00, 0x2c, 0xcc, 0xfc,
-    0xaf, 0xa9, 0x07, 0xa0, 0x4f, 0x40, 0xdd, 0x00, 0x00, 0xa0, 0x0a, 0xa0, 0x1c, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17,
-    0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x29, 0x02, 0x2c,
-    0x5c, 0x7f, 0x20, 0x8f, 0x00, 0x00, 0xa0, 0x4f, 0x6c, 0x33, 0x00, 0x00, 0xa0, 0x4f, 0x55, 0xdd, 0x00, 0x00, 0x2c, 0xcc, 0xf8,
-    0xaf, 0xcc, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x04, 0x7f, 0x00,
-    0xc0, 0x71, 0x00, 0x40, 0x04, 0x7f, 0xa0, 0xc3, 0x71, 0x00, 0x41, 0x80, 0x50, 0x70, 0x9c, 0x04, 0x40, 0x3c, 0x41, 0x40, 0x77,
-    0xf7, 0x04, 0x7f, 0x1c, 0xd9, 0x00, 0x00, 0x40, 0x84, 0x50, 0x42, 0x7f, 0x0f, 0x9c, 0x04, 0x40, 0x84, 0x50, 0x41, 0x9c, 0x04,
-    0x40, 0x30, 0x19, 0x7b, 0xf0, 0x04, 0x7f, 0x0c, 0xc0, 0x71, 0x00, 0x4d, 0x04, 0x7f, 0xb6, 0x33, 0x00, 0x00, 0x7f, 0x34, 0xc2,
-    0x71, 0x00, 0x70, 0x04, 0x7f, 0xb6, 0x33, 0x00, 0x00, 0x7f, 0x40, 0xc2, 0x71, 0x00, 0x70, 0x04, 0x7f, 0x7c, 0x35, 0x00, 0x00,
-    0x7f, 0xe4, 0xc1, 0x71, 0x00, 0x70, 0x04, 0x7f, 0x7c, 0x35, 0x00, 0x00, 0x7f, 0xf0, 0xc1, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xac,
-    0x2e, 0x00, 0x00, 0x7f, 0x44, 0xc1, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xac, 0x2e, 0x00, 0x00, 0x7f, 0x50, 0xc1, 0x71, 0x00, 0x70,
-    0x04, 0x7f, 0xe4, 0x2e, 0x00, 0x00, 0x7f, 0xf4, 0xc0, 0x71, 0x00, 0x70, 0x04, 0x7f, 0xe4, 0x2e, 0x00, 0x00, 0x7f, 0x00, 0xc1,
-    0x71, 0x00, 0x70, 0x04, 0x7f, 0x7c, 0x43, 0x00, 0x00, 0x7f, 0x94, 0xc1, 0x71, 0x00, 0x70, 0x04, 0x7f, 0x7c, 0x43, 0x00, 0x00,
-    0x7f, 0xa0, 0xc1, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x4f, 0x10, 0x00, 0x00, 0x00, 0x4c,
-    0x84, 0x4f, 0x13, 0x00, 0x20, 0x00, 0x48, 0x84, 0x4f, 0x2b, 0x00, 0x20, 0x00, 0x46, 0x84, 0x4f, 0x23, 0x00, 0x20, 0x00, 0x44,
-    0x84, 0x4f, 0x27, 0x00, 0x20, 0x00, 0x47, 0x84, 0x4f, 0x17, 0x00, 0x20, 0x00, 0x45, 0x84, 0x4f, 0x3f, 0x00, 0x20, 0x00, 0x64,
-    0x70, 0x87, 0x38, 0x56, 0x70, 0x87, 0x22, 0x56, 0x70, 0x87, 0x6f, 0x40, 0x56, 0x70, 0x87, 0x10, 0x56, 0x70, 0x87, 0x03, 0x54,
-    0x70, 0x87, 0x07, 0x54, 0x70, 0x87, 0x5f, 0x99, 0x00, 0x57, 0x70, 0x83, 0x55, 0x70, 0x87, 0x20, 0xef, 0x44, 0x97, 0x71, 0x00,
-    0x70, 0x87, 0x5f, 0xdc, 0x00, 0xd9, 0x04, 0x70, 0x87, 0x5f, 0x8f, 0x00, 0x58, 0x70, 0x87, 0x01, 0x56, 0x70, 0x83, 0x7f, 0x30,
-    0x97, 0x71, 0x00, 0x70, 0x84, 0x74, 0x43, 0x84, 0x6f, 0x64, 0x7f, 0x48, 0x97, 0x71, 0x00, 0x70, 0x84, 0x6f, 0x64, 0x7f, 0x4c,
-    0x97, 0x71, 0x00, 0x70, 0x84, 0x6f, 0x64, 0x7f, 0x50, 0x97, 0x71, 0x00, 0x70, 0x84, 0x6f, 0x64, 0x7f, 0x54, 0x97, 0x71, 0x00,
-    0x70, 0xa0, 0x4f, 0x57, 0xdd, 0x00, 0x00, 0xa0, 0x7f, 0x48, 0x97, 0x71, 0x00, 0xa0, 0x7f, 0x4c, 0x97, 0x71, 0x00, 0x04, 0x6c,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c,
-    0xcc, 0xf8, 0xaf, 0x7f, 0x00, 0x87, 0x5f, 0x80, 0x00, 0x55, 0x70, 0x83, 0xef, 0x34, 0x97, 0x71, 0x00, 0x70, 0x87, 0x58, 0x59,
-    0x70, 0x70, 0xb8, 0x4f, 0xff, 0x1f, 0xfe, 0xff, 0x4b, 0x70, 0x7b, 0x02, 0x3f, 0x5f, 0xe7, 0x00, 0x7f, 0x30, 0x97, 0x71, 0x00,
-    0x77, 0xf7, 0x84, 0x4f, 0x00, 0xe1, 0x81, 0x02, 0x4b, 0x70, 0xa0, 0x4f, 0x59, 0xdd, 0x00, 0x00, 0xa0, 0x7f, 0x48, 0x97, 0x71,
-    0x00, 0xa0, 0x7f, 0x4c, 0x97, 0x71, 0x00, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x22, 0x00, 0x18, 0x43, 0x08, 0x70, 0x10, 0x49, 0x84,
-    0x5f, 0xd0, 0x07, 0x41, 0x84, 0x41, 0x40, 0x94, 0x41, 0x28, 0x40, 0x77, 0xf9, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70,
-    0x10, 0x49, 0x9c, 0x04, 0x4c, 0xa0, 0x4f, 0x80, 0x01, 0x01, 0x00, 0xa0, 0x5a, 0xa0, 0x4f, 0xc8, 0xd8, 0x00, 0x00, 0x04, 0x74,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xec, 0x7f,
-    0xf8, 0xab, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x0c, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x4f, 0x13, 0x00,
-    0x20, 0x00, 0x64, 0x70, 0x87, 0xd9, 0x04, 0x59, 0x70, 0xfb, 0x5f, 0x80, 0x00, 0x59, 0x40, 0x3c, 0x5f, 0x80, 0x00, 0x40, 0x77,
-    0x29, 0xa0, 0x4f, 0x5b, 0xdd, 0x00, 0x00, 0xa0, 0x5f, 0xd2, 0x00, 0xa0, 0x1c, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17,
-    0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xcf, 0x89, 0xfb, 0x20,
-    0x59, 0x40, 0x3c, 0x20, 0x40, 0x77, 0x2a, 0xa0, 0x4f, 0x60, 0xdd, 0x00, 0x00, 0xa0, 0x5f, 0x0e, 0x01, 0xa0, 0x1c, 0x04, 0x68,
-    0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c,
-    0xcc, 0xf8, 0xaf, 0x59, 0xff, 0xfb, 0x10, 0x59, 0x40, 0x3c, 0x10, 0x40, 0x77, 0x2a, 0xa0, 0x4f, 0x67, 0xdd, 0x00, 0x00, 0xa0,
-    0x5f, 0x54, 0x01, 0xa0, 0x1c, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x28, 0xff, 0xfb, 0x6f, 0x44, 0x59, 0x40, 0x3c, 0x6f, 0x40, 0x40,
-    0x7f, 0x08, 0x24, 0x7f, 0x76, 0x35, 0x00, 0x00, 0x3c, 0x02, 0x7f, 0x58, 0x97, 0x71, 0x00, 0x43, 0x0f, 0x90, 0x7f, 0x58, 0x97,
-    0x71, 0x00, 0x70, 0x24, 0x7f, 0x76, 0x35, 0x00, 0x00, 0x80, 0x7f, 0x58, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x4f, 0x6d, 0xdd, 0x00,
-    0x00, 0xa0, 0x7f, 0x48, 0x97, 0x71, 0x00, 0xa0, 0x7f, 0x4c, 0x97, 0x71, 0x00, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17,
-    0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xcc, 0xfe, 0xfa,
-    0x5f, 0xff, 0x03, 0x7f, 0x02, 0x00, 0x40, 0x00, 0x40, 0x84, 0x40, 0x7f, 0x50, 0x97, 0x71, 0x00, 0x70, 0xfa, 0x5f, 0xff, 0x03,
-    0x7f, 0x00, 0x00, 0x40, 0x00, 0x40, 0x84, 0x40, 0x7f, 0x54, 0x97, 0x71, 0x00, 0x70, 0x28, 0x7f, 0x50, 0x97, 0x71, 0x00, 0x4b,
-    0x0a, 0x28, 0x7f, 0x54, 0x97, 0x71, 0x00, 0x43, 0x04, 0x7b, 0xcc, 0x3c, 0x5f, 0x16, 0x03, 0x7f, 0x50, 0x97, 0x71, 0x00, 0x4f,
-    0x0c, 0x84, 0x5f, 0x16, 0x03, 0x7f, 0x50, 0x97, 0x71, 0x00, 0x70, 0x3c, 0x5f, 0x00, 0x04, 0x7f, 0x54, 0x97, 0x71, 0x00, 0x4f,
-    0x0c, 0x84, 0x5f, 0x00, 0x04, 0x7f, 0x54, 0x97, 0x71, 0x00, 0x70, 0xfc, 0x7f, 0x54, 0x97, 0x71, 0x00, 0x5f, 0x00, 0x04, 0x40,
-    0x84, 0x40, 0x7f, 0x54, 0x97, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x50, 0x97, 0x71, 0x00, 0x7f, 0x48, 0x97, 0x71, 0x00, 0x70, 0x84,
-    0x7f, 0x54, 0x97, 0x71, 0x00, 0x7f, 0x4c, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x4f, 0x6f, 0xdd, 0x00, 0x00, 0xa0, 0x7f, 0x48, 0x97,
-    0x71, 0x00, 0xa0, 0x7f, 0x4c, 0x97, 0x71, 0x00, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x14, 0xfe, 0x30, 0xc8, 0x18, 0x49, 0x08, 0x70,
-    0x10, 0x49, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x87, 0x7f, 0x2f, 0x00, 0x20, 0x00, 0x7f, 0x30, 0x97, 0x71, 0x00, 0x70,
-    0x30, 0xc8, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x1c, 0x4c, 0x83, 0xc9, 0x16, 0x70, 0x84, 0x4f, 0x2f, 0x00, 0x20,
-    0x00, 0x43, 0x84, 0x4f, 0x27, 0x00, 0x20, 0x00, 0x45, 0x84, 0x4f, 0x2b, 0x00, 0x20, 0x00, 0x46, 0x84, 0x4f, 0x23, 0x00, 0x20,
-    0x00, 0x64, 0x70, 0x84, 0x4f, 0x13, 0x00, 0x20, 0x00, 0x68, 0x70, 0x87, 0x38, 0x56, 0x70, 0x87, 0x22, 0x56, 0x70, 0x87, 0x6f,
-    0x40, 0x56, 0x70, 0x87, 0x10, 0x56, 0x70, 0x87, 0x03, 0xd9, 0x04, 0x70, 0x87, 0x07, 0xd9, 0x04, 0x70, 0x87, 0x5f, 0x99, 0x00,
-    0x55, 0x70, 0x87, 0x5f, 0x80, 0x00, 0xd9, 0x08, 0x70, 0x87, 0x01, 0x56, 0x70, 0xa0, 0x4f, 0x71, 0xdd, 0x00, 0x00, 0x2c, 0xcc,
-    0xfc, 0xaf, 0xe6, 0x02, 0xa0, 0x4f, 0x94, 0xdd, 0x00, 0x00, 0xa0, 0x0a, 0xa0, 0x1c, 0x04, 0xc9, 0x18, 0x42, 0x2c, 0xcc, 0xf8,
-    0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x65,
-    0xfd, 0x24, 0x7f, 0xf8, 0x36, 0x00, 0x00, 0x87, 0x6f, 0x40, 0x56, 0x70, 0x83, 0x56, 0x70, 0x87, 0x53, 0x44, 0x24, 0x7f, 0xf8,
-    0x36, 0x00, 0x00, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x41, 0x84, 0x4f, 0x00, 0x95, 0x70, 0x00, 0x42, 0x7b, 0x0b, 0x84, 0x41,
-    0x40, 0x80, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x3c, 0x42, 0x41, 0x5b, 0xf4, 0x24, 0x7f, 0x0d, 0x37, 0x00, 0x00, 0x87, 0x53, 0x44,
-    0x3f, 0x5f, 0xe7, 0x00, 0x44, 0x77, 0x05, 0x18, 0x43, 0x08, 0x83, 0x56, 0x70, 0x3b, 0x44, 0x5f, 0x80, 0x00, 0x7f, 0x2d, 0xa0,
-    0x4f, 0xbc, 0xdd, 0x00, 0x00, 0xa0, 0x48, 0xa0, 0x47, 0x04, 0xc9, 0x18, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xec, 0xfc, 0xbb, 0x6f, 0x7f, 0x44,
-    0x87, 0x44, 0xc9, 0x15, 0x70, 0xe0, 0xc9, 0x15, 0xa0, 0x48, 0xa0, 0x47, 0x04, 0xc9, 0x18, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17,
-    0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0xbf, 0xfc, 0x83,
-    0x56, 0x70, 0x7b, 0x4b, 0x87, 0x6f, 0x40, 0x56, 0x70, 0x83, 0x56, 0x70, 0x87, 0x53, 0x44, 0x7b, 0x3e, 0x9c, 0x0a, 0x48, 0x3c,
-    0x5f, 0x20, 0x03, 0x48, 0x4b, 0x08, 0x84, 0x0a, 0x48, 0x9c, 0x0f, 0x47, 0x3c, 0x5f, 0x68, 0x01, 0x47, 0x4a, 0x6e, 0xff, 0x7a,
-    0x47, 0xff, 0x83, 0x56, 0x70, 0x3b, 0x55, 0x21, 0x7f, 0xfa, 0x83, 0x56, 0x70, 0xfb, 0x20, 0x55, 0x40, 0x3c, 0x20, 0x40, 0x7e,
-    0x21, 0xff, 0x84, 0x0a, 0x48, 0x84, 0x32, 0x47, 0x7a, 0x4d, 0xff, 0x83, 0x56, 0x70, 0x3b, 0x55, 0x21, 0x7f, 0xfa, 0x83, 0x56,
-    0x70, 0xfb, 0x20, 0x55, 0x40, 0x3c, 0x20, 0x40, 0x77, 0xb5, 0x7b, 0xa6, 0x70, 0x70, 0x10, 0x49, 0x84, 0x4f, 0x00, 0x00, 0x70,
-    0x00, 0x41, 0x84, 0x4f, 0x00, 0x95, 0x70, 0x00, 0x42, 0x7b, 0x0b, 0x84, 0x41, 0x40, 0x80, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x3c,
-    0x42, 0x41, 0x5b, 0xf4, 0x18, 0x49, 0x08, 0x70, 0x10, 0x43, 0x9c, 0x0c, 0x4c, 0x84, 0x4f, 0x00, 0x00, 0x40, 0x00, 0x48, 0x83,
-    0x65, 0x70, 0x24, 0x7f, 0xf9, 0x37, 0x00, 0x00, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x41, 0x84, 0x4f, 0x00, 0x90, 0x71, 0x00,
-    0x42, 0x7b, 0x10, 0x84, 0x41, 0x40, 0x84, 0x4f, 0xaa, 0xaa, 0xaa, 0xaa, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x3c, 0x42, 0x41, 0x5b,
-    0xef, 0x87, 0x01, 0x65, 0x70, 0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x63, 0xfb, 0x5f, 0xe0, 0x00, 0x45, 0x40,
-    0x3c, 0x5f, 0xe0, 0x00, 0x40, 0x7f, 0x2f, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x41, 0x84, 0x4f, 0x00, 0x90, 0x71, 0x00, 0x42,
-    0x7b, 0x0c, 0x84, 0x41, 0x40, 0x84, 0xff, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x3c, 0x42, 0x41, 0x5b, 0xf3, 0x87, 0x01, 0x65, 0x70,
-    0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x29, 0x9c, 0x04, 0x44, 0x93, 0x43, 0x3f, 0x11, 0x43, 0x4b, 0x32, 0xf8,
-    0x4f, 0xff, 0x0f, 0xff, 0x0f, 0x58, 0x40, 0x70, 0x84, 0x40, 0x42, 0x3c, 0x42, 0x41, 0x77, 0x49, 0x87, 0x01, 0x65, 0x70, 0x84,
-    0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0xf8, 0x4f, 0xff, 0x0f, 0xff, 0x0f, 0x58, 0x41, 0x84, 0x4f, 0x03, 0x00, 0x30, 0x00,
-    0x44, 0x83, 0x43, 0x7b, 0xcd, 0x84, 0x44, 0x59, 0x70, 0x87, 0xd9, 0x00, 0x45, 0xfb, 0x6f, 0x7c, 0x44, 0x40, 0x87, 0x40, 0xe0,
-    0x40, 0xd4, 0x02, 0x40, 0x40, 0x87, 0x40, 0x47, 0xfb, 0x1f, 0x45, 0x40, 0x87, 0x40, 0x46, 0x3f, 0x46, 0x47, 0x7e, 0x6a, 0xff,
-    0x7a, 0x36, 0xff, 0x18, 0x43, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x41, 0x84, 0x4f, 0x00,
-    0x90, 0x71, 0x00, 0x42, 0x7b, 0x10, 0x84, 0x41, 0x40, 0x84, 0x4f, 0xaa, 0xaa, 0xaa, 0xaa, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x3c,
-    0x42, 0x41, 0x5b, 0xef, 0x18, 0x49, 0x08, 0x10, 0x49, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x41, 0x84, 0x4f, 0x00, 0x90, 0x71,
-    0x00, 0x42, 0x7b, 0x0c, 0x84, 0x41, 0x40, 0x84, 0xff, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x3c, 0x42, 0x41, 0x5b, 0xf3, 0x18, 0x49,
-    0x08, 0x10, 0x44, 0x84, 0x74, 0x47, 0x84, 0x5a, 0x44, 0x84, 0x4f, 0x3b, 0x00, 0x20, 0x00, 0x46, 0x84, 0x4f, 0x3f, 0x00, 0x20,
-    0x00, 0x45, 0x87, 0x03, 0xef, 0x44, 0x97, 0x71, 0x00, 0x70, 0x80, 0x48, 0x7b, 0x30, 0x87, 0x01, 0x56, 0x70, 0xa0, 0x47, 0x84,
-    0x59, 0x41, 0x70, 0x84, 0x41, 0x40, 0x94, 0x41, 0x28, 0x40, 0x47, 0xf8, 0xbc, 0x04, 0x4c, 0x87, 0x01, 0x55, 0x70, 0xa0, 0x47,
-    0x84, 0x59, 0x41, 0x70, 0x84, 0x41, 0x40, 0x94, 0x41, 0x28, 0x40, 0x47, 0xf8, 0xbc, 0x04, 0x4c, 0x90, 0x48, 0x3c, 0x44, 0x48,
-    0x4b, 0xcf, 0x18, 0x44, 0x08, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0x84, 0x4f, 0x00, 0x00, 0x70, 0x00, 0x41, 0x84, 0x4f, 0x00, 0x95,
-    0x70, 0x00, 0x42, 0x7b, 0x0b, 0x84, 0x41, 0x40, 0x80, 0x50, 0x70, 0x9c, 0x04, 0x41, 0x3c, 0x42, 0x41, 0x5b, 0xf4, 0xa0, 0x5a,
-    0xa0, 0x0a, 0xa0, 0x0a, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x17, 0xc3, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x67, 0xfa, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x80, 0x40, 0x18,
-    0x49, 0x08, 0x70, 0x10, 0x44, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x80, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f,
-    0x00, 0x00, 0x70, 0x00, 0x41, 0x84, 0x4f, 0x00, 0x00, 0x74, 0x00, 0x42, 0x84, 0x00, 0x51, 0x70, 0x84, 0x6f, 0x55, 0x52, 0x70,
-    0x3c, 0x51, 0x52, 0x7e, 0x19, 0x00, 0x2c, 0x5c, 0x7f, 0xfc, 0x43, 0x00, 0x00, 0x87, 0x01, 0x7f, 0x86, 0x06, 0x60, 0x00, 0x70,
-    0x2c, 0x5c, 0x7f, 0xee, 0x44, 0x00, 0x00, 0x84, 0x4f, 0xff, 0xff, 0xff, 0x7f, 0x40, 0x84, 0x00, 0x42, 0xe8, 0x42, 0x04, 0x43,
-    0xdc, 0x43, 0x4f, 0x00, 0xc0, 0x71, 0x00, 0x44, 0x84, 0x40, 0x54, 0x70, 0x84, 0x54, 0x41, 0x3c, 0x40, 0x41, 0x7f, 0x0c, 0x84,
-    0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x7b, 0xd6, 0xd8, 0x01, 0x40, 0x40, 0x9c, 0x01, 0x42, 0x3c, 0x42, 0x5f, 0x00, 0x01,
-    0x76, 0xd2, 0xff, 0x18, 0x44, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x83, 0x7f, 0x5f, 0x97, 0x71, 0x00, 0x70, 0x80, 0x41, 0x7b,
-    0x19, 0x87, 0x41, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0x23, 0x00, 0x30, 0x00, 0xe0, 0x40, 0x3c, 0x41, 0x40, 0x77,
-    0x0d, 0x90, 0x41, 0x3c, 0x5f, 0xff, 0x00, 0x41, 0x4b, 0xe4, 0x7b, 0x07, 0x2c, 0x5c, 0xaf, 0x4f, 0x01, 0x2b, 0x7f, 0x5f, 0x97,
-    0x71, 0x00, 0x77, 0xca, 0x80, 0x48, 0x7b, 0x1d, 0x2b, 0x7f, 0x5f, 0x97, 0x71, 0x00, 0x7f, 0x0b, 0x83, 0x7f, 0x5f, 0x97, 0x71,
-    0x00, 0x70, 0x94, 0x48, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xaf, 0x3f, 0x01, 0x90, 0x48, 0x3c, 0x05, 0x48, 0x4b, 0xe2, 0x2c, 0x5c,
-    0xaf, 0x56, 0x00, 0x2b, 0x7f, 0x5f, 0x97, 0x71, 0x00, 0x77, 0x05, 0x18, 0x48, 0x08, 0x83, 0x7f, 0x5f, 0x97, 0x71, 0x00, 0x70,
-    0x7b, 0xe9, 0x70, 0x10, 0x49, 0x80, 0x41, 0x7b, 0x19, 0x87, 0x41, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0x23, 0x00,
-    0x30, 0x00, 0xe0, 0x40, 0x3c, 0x41, 0x40, 0x77, 0x0e, 0x90, 0x41, 0x3c, 0x5f, 0xff, 0x00, 0x41, 0x4b, 0xe4, 0x18, 0x49, 0x08,
-    0x93, 0x7f, 0x5f, 0x97, 0x71, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x49, 0x87, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x41, 0x87, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x41, 0x87, 0x0e, 0x7f, 0x0b, 0x00,
-    0x30, 0x00, 0x70, 0x83, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0e, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x03,
-    0x00, 0x30, 0x00, 0x70, 0x87, 0x05, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x5f, 0x80, 0x00, 0x7f, 0x0b, 0x00, 0x30, 0x00,
-    0x70, 0x87, 0x05, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x5f, 0x80, 0x00, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x5f,
-    0x88, 0x00, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0x3b, 0x7f, 0x33, 0x00, 0x30, 0x00, 0x5f, 0xdb, 0x00, 0x77, 0x51, 0x83, 0x7f,
-    0x23, 0x00, 0x30, 0x00, 0x70, 0x2b, 0x7f, 0x33, 0x00, 0x30, 0x00, 0x77, 0x42, 0x87, 0x05, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70,
-    0x83, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x05, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x03, 0x00, 0x30, 0x00,
-    0x70, 0x3f, 0x3f, 0x7f, 0x33, 0x00, 0x30, 0x00, 0x77, 0x1b, 0x87, 0x5f, 0x88, 0x00, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0xfb,
-    0x5f, 0xdb, 0x00, 0x7f, 0x33, 0x00, 0x30, 0x00, 0x40, 0x3c, 0x1b, 0x40, 0x7f, 0x11, 0x93, 0x7f, 0x5f, 0x97, 0x71, 0x00, 0x70,
-    0x84, 0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49, 0x93, 0x7f, 0x5f, 0x97, 0x71, 0x00, 0x70, 0x84,
-    0x01, 0x7f, 0x24, 0x97, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49, 0x9c, 0x4f, 0x10, 0x00, 0x00, 0x00, 0x4c, 0x70, 0xb0,
-    0x4f, 0x00, 0xe0, 0x81, 0x02, 0x4b, 0x70, 0x28, 0x40, 0x28, 0x40, 0x84, 0x5a, 0x40, 0x24, 0x7f, 0xf9, 0x3b, 0x00, 0x00, 0x84,
-    0x4f, 0x0b, 0x00, 0x30, 0x00, 0x7f, 0x60, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x0f, 0x00, 0x30, 0x00, 0x7f, 0x64, 0x97, 0x71,
-    0x00, 0x70, 0x87, 0x5f, 0x99, 0x00, 0x7f, 0x5c, 0x97, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x0c, 0x3c, 0x00, 0x00, 0x84, 0x4f, 0x03,
-    0x00, 0x30, 0x00, 0x7f, 0x60, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x07, 0x00, 0x30, 0x00, 0x7f, 0x64, 0x97, 0x71, 0x00, 0x70,
-    0x87, 0x5f, 0x99, 0x00, 0x7f, 0x5c, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x5f, 0x84, 0x4f, 0x0b, 0x00, 0x30, 0x00, 0x7f, 0x60, 0x97,
-    0x71, 0x00, 0x70, 0x84, 0x4f, 0x0f, 0x00, 0x30, 0x00, 0x7f, 0x64, 0x97, 0x71, 0x00, 0x70, 0x87, 0x11, 0x7f, 0x5c, 0x97, 0x71,
-    0x00, 0x70, 0x7b, 0x3d, 0x84, 0x4f, 0x03, 0x00, 0x30, 0x00, 0x7f, 0x60, 0x97, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x07, 0x00, 0x30,
-    0x00, 0x7f, 0x64, 0x97, 0x71, 0x00, 0x70, 0x87, 0x11, 0x7f, 0x5c, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x1b, 0x24, 0x7f, 0xf9, 0x3f,
-    0x00, 0x00, 0x3c, 0x04, 0x40, 0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0xb8, 0xdb, 0x00, 0x00,
-    0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x09, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f, 0xc9, 0x00, 0xef, 0x60,
-    0x97, 0x71, 0x00, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xe1, 0xf2, 0x87, 0x0f, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x7f, 0x5c, 0x97, 0x71, 0x00, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0b, 0xef,
-    0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x50, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0xef, 0x60, 0x97, 0x71, 0x00,
-    0x70, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x5f, 0xfe, 0x00, 0xa0, 0x00, 0xa0, 0x08, 0xa0, 0x5f, 0xc0, 0x00, 0xa0,
-    0x6f, 0x60, 0xa0, 0x04, 0xa0, 0x01, 0xa0, 0x02, 0x2c, 0xcc, 0xe0, 0xaf, 0x7f, 0x03, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4,
-    0x3f, 0x00, 0x00, 0xa0, 0x3e, 0xa0, 0x00, 0xa0, 0x08, 0xa0, 0x5f, 0xc0, 0x00, 0xa0, 0x6f, 0x60, 0xa0, 0x04, 0xa0, 0x01, 0xa0,
-    0x02, 0x2c, 0xcc, 0xe0, 0xaf, 0x5c, 0x03, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0xa0, 0x1e, 0xa0, 0x00,
-    0xa0, 0x08, 0xa0, 0x5f, 0xc0, 0x00, 0xa0, 0x6f, 0x60, 0xa0, 0x04, 0xa0, 0x01, 0xa0, 0x02, 0x2c, 0xcc, 0xe0, 0xaf, 0x39, 0x03,
-    0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0xa0, 0x0e, 0xa0, 0x00, 0xa0, 0x08, 0xa0, 0x5f, 0xc0, 0x00, 0xa0,
-    0x6f, 0x60, 0xa0, 0x04, 0xa0, 0x01, 0xa0, 0x02, 0x2c, 0xcc, 0xe0, 0xaf, 0x16, 0x03, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4,
-    0x3f, 0x00, 0x00, 0xa0, 0x06, 0xa0, 0x00, 0xa0, 0x08, 0xa0, 0x5f, 0xc0, 0x00, 0xa0, 0x6f, 0x60, 0xa0, 0x04, 0xa0, 0x01, 0xa0,
-    0x02, 0x2c, 0xcc, 0xe0, 0xaf, 0xf3, 0x02, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0xa0, 0x02, 0xa0, 0x00,
-    0xa0, 0x08, 0xa0, 0x5f, 0xc0, 0x00, 0xa0, 0x6f, 0x60, 0xa0, 0x04, 0xa0, 0x01, 0xa0, 0x02, 0x2c, 0xcc, 0xe0, 0xaf, 0xd0, 0x02,
-    0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0xa0, 0x06, 0xa0, 0x00, 0xa0, 0x07, 0xa0, 0x6f, 0x40, 0xa0, 0x20,
-    0xa0, 0x04, 0xa0, 0x01, 0xa0, 0x02, 0x2c, 0xcc, 0xe0, 0xaf, 0xaf, 0x02, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00,
-    0x00, 0xa0, 0x06, 0xa0, 0x00, 0xa0, 0x07, 0xa0, 0x6f, 0x40, 0xa0, 0x20, 0xa0, 0x04, 0xa0, 0x00, 0xa0, 0x02, 0x2c, 0xcc, 0xe0,
-    0xaf, 0x8e, 0x02, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0xa0, 0x06, 0xa0, 0x00, 0xa0, 0x07, 0xa0, 0x6f,
-    0x40, 0xa0, 0x20, 0xa0, 0x04, 0xa0, 0x01, 0xa0, 0x04, 0x2c, 0xcc, 0xe0, 0xaf, 0x6d, 0x02, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f,
-    0xf4, 0x3f, 0x00, 0x00, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x04, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f,
-    0x87, 0x00, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x0c, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x06, 0xef, 0x60, 0x97,
-    0x71, 0x00, 0x70, 0x87, 0x0d, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x7f, 0x5c,
-    0x97, 0x71, 0x00, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0e, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0xef, 0x60,
-    0x97, 0x71, 0x00, 0x70, 0x87, 0x03, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f, 0xc0, 0x00, 0xef, 0x60, 0x97, 0x71, 0x00,
-    0x70, 0x87, 0x03, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f, 0xc1, 0x00, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x05,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x60, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x05, 0xef, 0x60, 0x97, 0x71,
-    0x00, 0x70, 0x87, 0x6f, 0x68, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x6f, 0x55, 0xa0, 0x01, 0xa0, 0x01, 0x2c, 0xcc, 0xf4,
-    0xaf, 0x54, 0x03, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0xa0, 0x00, 0xa0, 0x01, 0xa0, 0x02, 0x2c, 0xcc,
-    0xf4, 0xaf, 0x3e, 0x03, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0x87, 0xef, 0x64, 0x97, 0x71, 0x00, 0x6c,
-    0x70, 0x87, 0x05, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x78, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x4f, 0xa0,
-    0x86, 0x01, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x83, 0xf0, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x5f, 0x80, 0x00, 0xef,
-    0x60, 0x97, 0x71, 0x00, 0x40, 0x87, 0x40, 0x6c, 0x70, 0x3f, 0x5f, 0x80, 0x00, 0x6c, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00,
-    0x00, 0x87, 0x05, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x68, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xa0, 0x4f, 0xa0,
-    0x86, 0x01, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x44, 0xf0, 0x87, 0xef, 0x64, 0x97, 0x71, 0x00, 0x6c, 0x70, 0xfb, 0x5f, 0x80, 0x00,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x40, 0x87, 0x40, 0x6c, 0x70, 0x2b, 0x6c, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0x87,
-    0x6f, 0x55, 0x6d, 0x70, 0x80, 0x64, 0x70, 0x7b, 0x56, 0x80, 0x68, 0x70, 0x87, 0x6d, 0xe0, 0x40, 0xa0, 0x40, 0xa0, 0x04, 0xa0,
-    0x03, 0x2c, 0xcc, 0xf4, 0xaf, 0x93, 0x02, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0x87, 0xef, 0x60, 0x97,
-    0x71, 0x00, 0x6c, 0x70, 0x7b, 0x27, 0xa0, 0x5f, 0xc4, 0x09, 0x2c, 0xcc, 0xfc, 0xaf, 0xe9, 0xef, 0x84, 0x68, 0x40, 0x90, 0x68,
-    0x70, 0x3c, 0x5f, 0xff, 0x00, 0x40, 0x4f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0x87, 0xef, 0x60, 0x97, 0x71, 0x00, 0x6c,
-    0x70, 0x3b, 0x6c, 0x01, 0x7f, 0xd8, 0x90, 0x64, 0x70, 0x3c, 0x04, 0x64, 0x4b, 0xa9, 0x87, 0x6d, 0xe0, 0x40, 0xa0, 0x40, 0xa0,
-    0x04, 0xa0, 0x03, 0x2c, 0xcc, 0xf4, 0xaf, 0x3d, 0x02, 0x28, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0xf4, 0x3f, 0x00, 0x00, 0x80, 0x68,
-    0x70, 0x87, 0xef, 0x64, 0x97, 0x71, 0x00, 0x6c, 0x70, 0x87, 0xef, 0x64, 0x97, 0x71, 0x00, 0x6c, 0x70, 0x87, 0xef, 0x64, 0x97,
-    0x71, 0x00, 0x6c, 0x70, 0x87, 0xef, 0x64, 0x97, 0x71, 0x00, 0x6c, 0x70, 0x87, 0x01, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x6c, 0x70, 0x7b, 0x2b, 0xa0, 0x5f, 0xc4, 0x09, 0x2c, 0xcc, 0xfc, 0xaf, 0x68, 0xef, 0x84, 0x68,
-    0x40, 0x90, 0x68, 0x70, 0x3c, 0x5f, 0xff, 0x00, 0x40, 0x4f, 0x04, 0x7b, 0x41, 0x87, 0x01, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70,
-    0x87, 0xef, 0x60, 0x97, 0x71, 0x00, 0x6c, 0x70, 0x3b, 0x6c, 0x20, 0x7f, 0xd4, 0x87, 0x30, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70,
-    0x87, 0x01, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xfb, 0x6f, 0x70, 0xef, 0x60, 0x97, 0x71, 0x00, 0x40, 0x87, 0x40, 0x6c, 0x70,
-    0x2b, 0x6c, 0x7f, 0x04, 0x7b, 0x09, 0x2c, 0x5c, 0xaf, 0x3b, 0x02, 0x7b, 0x07, 0x2c, 0x5c, 0xaf, 0x3c, 0xfb, 0x18, 0x49, 0x08,
-    0x10, 0x45, 0x87, 0xca, 0x0f, 0x46, 0x84, 0x78, 0x45, 0x87, 0x04, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xf3, 0xca, 0x1b, 0xca,
-    0x17, 0x40, 0xb3, 0xca, 0x1f, 0x40, 0xb3, 0x5f, 0x80, 0x00, 0x40, 0x87, 0x40, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x0c,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x73, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x0d, 0xef, 0x60, 0x97, 0x71, 0x00,
-    0x70, 0x87, 0x77, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x0e, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0xef, 0x60,
-    0x97, 0x71, 0x00, 0x70, 0x87, 0x03, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x46, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0x03, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0xf3, 0x01, 0x46, 0x40, 0x87, 0x40, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x05,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0xca, 0x13, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x05, 0xef, 0x60, 0x97, 0x71,
-    0x00, 0x70, 0xf3, 0x08, 0xca, 0x13, 0x40, 0x87, 0x40, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0x48, 0x83, 0x47, 0x7b,
-    0x5a, 0x18, 0x45, 0x08, 0xa0, 0x04, 0x2c, 0xcc, 0xfc, 0xaf, 0x92, 0x00, 0x28, 0x40, 0x77, 0x38, 0x87, 0x48, 0xef, 0x64, 0x97,
-    0x71, 0x00, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0xaf, 0x7e, 0x00, 0x28, 0x40, 0x77, 0x24, 0x87, 0xef, 0x64, 0x97, 0x71, 0x00,
-    0x41, 0x3f, 0x5f, 0xc0, 0x00, 0x46, 0x77, 0x09, 0x3f, 0x48, 0x41, 0x7f, 0x17, 0x7b, 0x0f, 0xfb, 0x6f, 0x7f, 0x41, 0x40, 0x87,
-    0x40, 0x41, 0x3f, 0x48, 0x41, 0x7f, 0x08, 0x84, 0x01, 0x40, 0x18, 0x45, 0x08, 0x87, 0x48, 0xe0, 0x40, 0xd0, 0x01, 0x40, 0x40,
-    0x87, 0x40, 0x48, 0x93, 0x47, 0x87, 0x47, 0xe0, 0x40, 0x3c, 0x45, 0x40, 0x5b, 0xa4, 0x87, 0x05, 0xef, 0x60, 0x97, 0x71, 0x00,
-    0x70, 0x87, 0xca, 0x13, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x03, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x46, 0xef,
-    0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x0e, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x80,
-    0x40, 0x18, 0x45, 0x08, 0x70, 0x10, 0x46, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x80, 0x48, 0x83, 0xef, 0x60, 0x97, 0x71,
-    0x00, 0x70, 0x87, 0xef, 0x60, 0x97, 0x71, 0x00, 0x46, 0x7b, 0x2c, 0xa0, 0x5f, 0xc4, 0x09, 0x2c, 0xcc, 0xfc, 0xaf, 0xad, 0xed,
-    0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x60, 0x97, 0x71, 0x00, 0x46, 0x70, 0x84, 0x48, 0x40, 0x90, 0x48, 0x3c,
-    0x5f, 0xff, 0x00, 0x40, 0x4f, 0x07, 0x84, 0x01, 0x40, 0x7b, 0x0f, 0x87, 0x46, 0xe0, 0x40, 0x38, 0x40, 0x5a, 0x7f, 0xcf, 0x80,
-    0x40, 0x7b, 0x02, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x80, 0x46, 0x84,
-    0x78, 0x40, 0x7b, 0x35, 0x87, 0x73, 0xef, 0x64, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0x01, 0x47, 0x7b, 0x2f, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0x47, 0x7b, 0x24, 0x87, 0x73, 0xef, 0x64, 0x97, 0x71,
-    0x00, 0x70, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0x47, 0x7b, 0x11, 0x3c, 0x40, 0x01, 0x7f, 0xca, 0x3c, 0x40, 0x02,
-    0x7f, 0xda, 0x3c, 0x40, 0x03, 0x7f, 0xe0, 0x87, 0xef, 0x60, 0x97, 0x71, 0x00, 0x48, 0x7b, 0x2c, 0xa0, 0x5f, 0xc4, 0x09, 0x2c,
-    0xcc, 0xfc, 0xaf, 0x15, 0xed, 0x84, 0x46, 0x40, 0x90, 0x46, 0x3c, 0x5f, 0xff, 0x00, 0x40, 0x4f, 0x07, 0x84, 0x01, 0x40, 0x7b,
-    0x1a, 0x87, 0x47, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0xef, 0x60, 0x97, 0x71, 0x00, 0x48, 0x3b, 0x48, 0x77, 0x7f, 0xd3,
-    0x80, 0x40, 0x7b, 0x02, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x0c, 0x00, 0x00, 0x00, 0x4c, 0x87, 0x02,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x04, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70,
-    0x87, 0x5f, 0x84, 0x00, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x0c, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x06, 0xef,
-    0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x0d, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0x7f, 0x5c, 0x97, 0x71, 0x00, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0e, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x01, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x02, 0xef, 0x60, 0x97, 0x71, 0x00,
-    0x70, 0x87, 0x03, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x5f, 0xc0, 0x00, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x05,
-    0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x6f, 0x60, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x03, 0xef, 0x60, 0x97, 0x71,
-    0x00, 0x70, 0x87, 0x5f, 0xc1, 0x00, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x05, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87,
-    0x6f, 0x68, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x70, 0xb8, 0x4f, 0xff, 0x1f, 0xfe, 0xff, 0x4b, 0x70, 0x28, 0x40, 0x83, 0x7f,
-    0x5e, 0x97, 0x71, 0x00, 0x70, 0x87, 0x7f, 0x5e, 0x97, 0x71, 0x00, 0x7f, 0x5d, 0x97, 0x71, 0x00, 0x70, 0x80, 0x68, 0x70, 0x84,
-    0x68, 0x64, 0x70, 0x83, 0xef, 0x64, 0x97, 0x71, 0x00, 0x70, 0x7b, 0x11, 0x84, 0x64, 0x40, 0x90, 0x64, 0x70, 0x3c, 0x5f, 0xff,
-    0x00, 0x40, 0x4f, 0x04, 0x7b, 0x53, 0x2b, 0x7f, 0x5d, 0x97, 0x71, 0x00, 0x7f, 0xeb, 0x87, 0xef, 0x64, 0x97, 0x71, 0x00, 0x59,
-    0x70, 0x87, 0x01, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x87, 0x10, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x64, 0x97,
-    0x71, 0x00, 0x70, 0x7b, 0x11, 0x84, 0x68, 0x40, 0x90, 0x68, 0x70, 0x3c, 0x5f, 0xff, 0x00, 0x40, 0x4f, 0x04, 0x7b, 0x1b, 0x2b,
-    0x7f, 0x5e, 0x97, 0x71, 0x00, 0x7f, 0xeb, 0x87, 0x09, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00,
-    0x70, 0x7b, 0x07, 0x2c, 0x5c, 0xaf, 0xbf, 0xf7, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x04, 0x00, 0x00,
-    0x00, 0x4c, 0x87, 0x7f, 0x13, 0x00, 0x30, 0x00, 0x61, 0x70, 0xfb, 0x0e, 0x61, 0x40, 0x87, 0x40, 0x59, 0x70, 0x2b, 0x59, 0x77,
-    0x07, 0x2c, 0x5c, 0xaf, 0x37, 0x00, 0x3f, 0x08, 0x59, 0x77, 0x07, 0x2c, 0x5c, 0xaf, 0x2d, 0x00, 0x3f, 0x04, 0x59, 0x77, 0x07,
-    0x2c, 0x5c, 0xaf, 0x3b, 0x00, 0x3f, 0x0c, 0x59, 0x77, 0x07, 0x2c, 0x5c, 0xaf, 0x31, 0x00, 0x83, 0xef, 0x60, 0x97, 0x71, 0x00,
-    0x70, 0x87, 0x38, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x30, 0xc8, 0x18, 0x49, 0x08, 0x10, 0x49, 0x87, 0x01, 0x7f, 0x5d, 0x97,
-    0x71, 0x00, 0x70, 0x87, 0x28, 0xef, 0x60, 0x97, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x87, 0xef,
-    0x64, 0x97, 0x71, 0x00, 0x40, 0x87, 0x01, 0x7f, 0x5e, 0x97, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x10, 0x46, 0x80, 0x48, 0x84,
-    0x4f, 0x00, 0x00, 0x60, 0x00, 0x46, 0x2c, 0x5c, 0x7f, 0xbe, 0x1d, 0x00, 0x00, 0x84, 0x40, 0x47, 0x7b, 0x10, 0x18, 0x46, 0x08,
-    0x87, 0xc6, 0x02, 0xe0, 0x40, 0x9c, 0x40, 0x48, 0x9c, 0x04, 0x46, 0x3c, 0x4f, 0xf8, 0x1f, 0x60, 0x00, 0x46, 0x5b, 0xee, 0x87,
-    0x7f, 0xfa, 0x1f, 0x60, 0x00, 0xe0, 0x40, 0xd0, 0x08, 0x40, 0x40, 0x87, 0x7f, 0xfe, 0x1f, 0x60, 0x00, 0xe0, 0x41, 0x9c, 0x41,
-    0x40, 0x84, 0x40, 0x42, 0xa4, 0x4f, 0x00, 0x00, 0x01, 0x00, 0x48, 0x3c, 0x42, 0x48, 0x77, 0x12, 0xa0, 0x47, 0x2c, 0xcc, 0xfc,
-    0x7f, 0xcc, 0x1d, 0x00, 0x00, 0x84, 0x01, 0x40, 0x18, 0x46, 0x08, 0x84, 0x4f, 0x00, 0x00, 0x60, 0x00, 0x40, 0x7b, 0x09, 0x83,
-    0xc0, 0x02, 0x70, 0x9c, 0x04, 0x40, 0x3c, 0x4f, 0x00, 0x20, 0x60, 0x00, 0x40, 0x5b, 0xf2, 0x87, 0x03, 0x7f, 0xe6, 0x06, 0x60,
-    0x00, 0x70, 0x87, 0x03, 0x7f, 0xea, 0x06, 0x60, 0x00, 0x70, 0x87, 0x03, 0x7f, 0xee, 0x06, 0x60, 0x00, 0x70, 0x2c, 0x5c, 0xaf,
-    0x5d, 0x00, 0x2c, 0x5c, 0x7f, 0x68, 0x6c, 0x00, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00, 0x80, 0x40,
-    0x18, 0x46, 0x08, 0x10, 0x49, 0x84, 0x4f, 0x00, 0x00, 0x60, 0x00, 0x40, 0x7b, 0x09, 0x83, 0xc0, 0x02, 0x70, 0x9c, 0x04, 0x40,
-    0x3c, 0x4f, 0x00, 0x20, 0x60, 0x00, 0x40, 0x5b, 0xf2, 0x87, 0x03, 0x7f, 0xe6, 0x06, 0x60, 0x00, 0x70, 0x87, 0x03, 0x7f, 0xea,
-    0x06, 0x60, 0x00, 0x70, 0x87, 0x03, 0x7f, 0xee, 0x06, 0x60, 0x00, 0x70, 0x2c, 0x5c, 0xaf, 0x0f, 0x00, 0x2c, 0x5c, 0x7f, 0x68,
-    0x6c, 0x00, 0x00, 0x18, 0x49, 0x08, 0x10, 0x47, 0x80, 0x48, 0x84, 0x4f, 0x00, 0x00, 0x60, 0x00, 0x47, 0x2c, 0x5c, 0x7f, 0xbe,
-    0x1d, 0x00, 0x00, 0x84, 0x40, 0x42, 0x7b, 0x0d, 0x87, 0xc7, 0x02, 0xe0, 0x40, 0x9c, 0x40, 0x48, 0x9c, 0x04, 0x47, 0x3c, 0x4f,
-    0xf8, 0x1f, 0x60, 0x00, 0x47, 0x5b, 0xee, 0xf8, 0x5f, 0xff, 0x00, 0x48, 0xe3, 0x40, 0xbb, 0x5f, 0xff, 0x00, 0x40, 0x87, 0x40,
-    0x7f, 0xfe, 0x1f, 0x60, 0x00, 0x70, 0xd4, 0x08, 0x48, 0x40, 0xbb, 0x5f, 0xff, 0x00, 0x40, 0x87, 0x40, 0x7f, 0xfa, 0x1f, 0x60,
-    0x00, 0x70, 0xa0, 0x42, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc, 0x1d, 0x00, 0x00, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10, 0x47, 0x84,
-    0x74, 0x48, 0x84, 0x00, 0x47, 0x3c, 0x5f, 0x82, 0x00, 0x5a, 0x4b, 0x46, 0x3c, 0x5f, 0x89, 0x00, 0x5a, 0x47, 0x3f, 0xf8, 0x0f,
-    0x5a, 0x40, 0xbc, 0x02, 0x40, 0x84, 0x40, 0x5a, 0x70, 0x84, 0x48, 0x40, 0x90, 0x48, 0xe8, 0x5f, 0xcc, 0x00, 0x5a, 0x41, 0x9c,
-    0x4f, 0x18, 0x00, 0x60, 0x00, 0x41, 0xd0, 0x02, 0x47, 0x42, 0x9c, 0x42, 0x41, 0x87, 0xc1, 0x02, 0x50, 0x70, 0x7f, 0x0d, 0x90,
-    0x47, 0xfc, 0x01, 0x78, 0x40, 0x3c, 0x40, 0x47, 0x4b, 0xd7, 0x2b, 0x58, 0x7f, 0x05, 0x83, 0x58, 0x70, 0x84, 0x47, 0x40, 0x18,
-    0x47, 0x08, 0x18, 0x47, 0x08, 0x10, 0x47, 0x84, 0x5a, 0x48, 0x84, 0x74, 0x47, 0x84, 0x00, 0x42, 0xe8, 0x5f, 0xcc, 0x00, 0x48,
-    0x40, 0x84, 0x42, 0x41, 0x9c, 0x4f, 0x18, 0x00, 0x60, 0x00, 0x40, 0x90, 0x42, 0xd0, 0x02, 0x41, 0x41, 0x9c, 0x41, 0x40, 0x84,
-    0x47, 0x41, 0x90, 0x47, 0x87, 0x51, 0xc0, 0x02, 0x70, 0x77, 0xdd, 0x18, 0x47, 0x08, 0x70, 0x10, 0x48, 0x2c, 0x5c, 0x7f, 0xbe,
-    0x1d, 0x00, 0x00, 0x84, 0x40, 0x48, 0x87, 0x7f, 0xfa, 0x1f, 0x60, 0x00, 0xe0, 0x40, 0xd0, 0x08, 0x40, 0x40, 0x87, 0x7f, 0xfe,
-    0x1f, 0x60, 0x00, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x42, 0x3f, 0x77, 0xda, 0x00, 0x4b, 0x0c, 0xff, 0x77, 0xda, 0x00,
-    0x40, 0xbc, 0x40, 0x42, 0x7b, 0x0a, 0xff, 0xda, 0x00, 0x77, 0x40, 0x9c, 0x40, 0x42, 0x87, 0x77, 0xda, 0x00, 0x70, 0xf8, 0x5f,
-    0xff, 0x00, 0x42, 0xe3, 0x40, 0xbb, 0x5f, 0xff, 0x00, 0x40, 0x87, 0x40, 0x7f, 0xfe, 0x1f, 0x60, 0x00, 0x70, 0xd4, 0x08, 0x42,
-    0x40, 0xbb, 0x5f, 0xff, 0x00, 0x40, 0x87, 0x40, 0x7f, 0xfa, 0x1f, 0x60, 0x00, 0x70, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0x7f, 0xcc,
-    0x1d, 0x00, 0x00, 0x18, 0x48, 0x08, 0x70, 0x10, 0x45, 0x9c, 0x0c, 0x4c, 0x84, 0x5a, 0x48, 0x84, 0xca, 0x14, 0x47, 0x04, 0x78,
-    0x46, 0x7b, 0x06, 0x84, 0xc7, 0x10, 0x47, 0x70, 0x84, 0x47, 0x45, 0x77, 0x27, 0xa0, 0x48, 0x04, 0x78, 0x40, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x48, 0xa0, 0xca, 0x10, 0xa0, 0x47,
-    0x2c, 0xcc, 0xe8, 0xda, 0x04, 0x18, 0x45, 0x08, 0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x45, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x9f, 0x3e, 0x55, 0x56,
-    0x43, 0x63, 0xa0, 0x48, 0xa0, 0x74, 0x84, 0x46, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x86,
-    0x55, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc6, 0x06, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03,
-    0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x74,
-    0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0xca, 0x10, 0xa0, 0xc7, 0x10, 0x2c, 0xcc, 0xe8, 0xaf, 0x30, 0xff, 0x86, 0x55, 0x56, 0x70, 0x3e, 0xc5, 0x02, 0xc6, 0x02, 0x43,
-    0x66, 0xa0, 0x48, 0xa0, 0x74, 0x84, 0x46, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x86, 0xc6,
-    0x04, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc5, 0x02, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03,
-    0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x74,
-    0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0xca, 0x10, 0xa0, 0xc7, 0x10, 0x2c, 0xcc, 0xe8, 0xaf, 0xc7, 0xfe, 0x86, 0xc5, 0x02, 0xc6, 0x02, 0x70, 0x3e, 0xc5, 0x04, 0xc6,
-    0x04, 0x4f, 0x67, 0xa0, 0x48, 0xa0, 0x74, 0x86, 0xc5, 0x04, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc6, 0x02, 0xe4, 0x40, 0xa0, 0x40,
-    0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0xdc, 0x04, 0x46, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc,
-    0xf8, 0xef, 0x74, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0xca, 0x10, 0xa0, 0xc7, 0x10, 0x2c, 0xcc, 0xe8, 0xaf, 0x5b, 0xfe, 0x86, 0xc5, 0x04, 0xc6, 0x04, 0x70, 0x3e,
-    0xc5, 0x06, 0xc6, 0x06, 0x4f, 0x66, 0xa0, 0x48, 0xa0, 0x74, 0x86, 0x56, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc5, 0x06, 0xe4, 0x40,
-    0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0xdc, 0x04, 0x46, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42,
-    0x2c, 0xcc, 0xf8, 0xef, 0x74, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0xa0, 0xc7, 0x10, 0x2c, 0xcc, 0xe8, 0xaf, 0xf0, 0xfd, 0x86, 0xc5, 0x06, 0xc6, 0x06,
-    0x70, 0xa0, 0x48, 0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0xc7, 0x08, 0xa0, 0xca, 0x10, 0xa0, 0x47, 0x2c, 0xcc, 0xe8, 0xda, 0x04, 0x18, 0x45, 0x08, 0x70, 0x70, 0x10,
-    0x48, 0x84, 0x5a, 0x48, 0xe0, 0x78, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x64, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x29, 0xa0, 0x48, 0xa0,
-    0x74, 0x04, 0x78, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xe0, 0xca, 0x10, 0xa0, 0xc8, 0x10, 0x2c, 0xcc, 0xe8, 0xaf, 0x76, 0xfd, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10,
-    0x46, 0x9c, 0x04, 0x4c, 0x84, 0xca, 0x10, 0x42, 0x84, 0x52, 0x48, 0xdc, 0x5f, 0xfc, 0x03, 0x48, 0x40, 0x3c, 0x40, 0xa8, 0xfc,
-    0x03, 0x77, 0x08, 0x24, 0x7f, 0xa0, 0x49, 0x00, 0x00, 0x84, 0xa8, 0xfc, 0x03, 0x47, 0x84, 0x7c, 0x57, 0x70, 0xdc, 0x04, 0x47,
-    0x41, 0x84, 0x41, 0x59, 0x70, 0x04, 0x74, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x48, 0x47,
-    0x7b, 0x15, 0xa0, 0x47, 0xa0, 0xa8, 0xfc, 0x03, 0x2c, 0xcc, 0xf8, 0xaf, 0xc0, 0x02, 0x28, 0x40, 0x7f, 0x0c, 0x9c, 0x0c, 0x47,
-    0x3c, 0xa8, 0xfc, 0x03, 0x47, 0x77, 0xe8, 0x3c, 0xa8, 0xfc, 0x03, 0x47, 0x77, 0x0b, 0x9c, 0x0c, 0xa8, 0xfc, 0x03, 0x70, 0x18,
-    0x46, 0x08, 0x84, 0xa8, 0xfc, 0x03, 0x40, 0x9c, 0x0c, 0xa8, 0xfc, 0x03, 0x70, 0x84, 0x40, 0x46, 0x7b, 0x1c, 0x84, 0x46, 0x41,
-    0xfc, 0x0c, 0x41, 0x40, 0x84, 0xc0, 0x08, 0xc1, 0x08, 0x70, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xbc,
-    0x0c, 0x46, 0x3c, 0x47, 0x46, 0x77, 0xe3, 0x84, 0x7c, 0x57, 0x70, 0xdc, 0x04, 0x47, 0x41, 0x84, 0x41, 0x59, 0x70, 0x04, 0x74,
-    0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x18, 0x46, 0x08, 0x70, 0x10, 0x46, 0x9c, 0x10, 0x4c, 0x84,
-    0xca, 0x14, 0x46, 0xdc, 0x10, 0x5a, 0x40, 0x28, 0x50, 0x77, 0x72, 0xdc, 0x10, 0x7c, 0x40, 0x28, 0x50, 0x7f, 0x36, 0xa0, 0x5a,
-    0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x7c, 0x04, 0xca, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46, 0x2c, 0xcc, 0xe8,
-    0xef, 0xec, 0x02, 0x00, 0x00, 0x18, 0x46, 0x08, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0x04, 0xca, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46, 0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0x18, 0x46, 0x08, 0x04, 0xca, 0x10,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0xc4, 0x03, 0x00, 0x00, 0x84, 0x4f, 0xf0, 0xaf, 0x71,
-    0x00, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0xf0, 0xa7, 0x71, 0x00, 0x7f, 0xec, 0xab, 0x71, 0x00, 0x70, 0xa0, 0x7c, 0xa0,
-    0x4f, 0xec, 0x48, 0x00, 0x00, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0xf0, 0xaf, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x48, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x4f, 0xf0, 0xa7, 0x71, 0x00, 0xa0, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xe4,
-    0xef, 0xe4, 0x02, 0x00, 0x00, 0x84, 0x4f, 0xf0, 0xab, 0x71, 0x00, 0x7f, 0xec, 0xaf, 0x71, 0x00, 0x70, 0xa0, 0x5a, 0xa0, 0x4f,
-    0xec, 0x48, 0x00, 0x00, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x4f, 0xf0, 0xab, 0x71, 0x00, 0xa0, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xe4, 0xef, 0xe4, 0x02, 0x00, 0x00,
-    0x84, 0x4f, 0xf0, 0xab, 0x71, 0x00, 0x47, 0x24, 0x7f, 0xe4, 0x4b, 0x00, 0x00, 0x84, 0x4f, 0xf0, 0xa7, 0x71, 0x00, 0x48, 0x24,
-    0x7f, 0xd7, 0x4b, 0x00, 0x00, 0xdc, 0x04, 0x47, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0xf0, 0xaf, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x48, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xe0, 0x59, 0xdc, 0x04, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x64, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x60, 0xa0, 0x57,
-    0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84,
-    0x4f, 0xf0, 0xaf, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c,
-    0xcc, 0xf4, 0xef, 0x78, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x58, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x46,
-    0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0x9c, 0x0c, 0x48, 0x3c, 0x7f, 0xec, 0xab, 0x71, 0x00, 0x48, 0x76, 0x36, 0xff,
-    0x9c, 0x0c, 0x47, 0x3c, 0x7f, 0xec, 0xaf, 0x71, 0x00, 0x47, 0x76, 0x1c, 0xff, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x47,
-    0x84, 0x5a, 0x42, 0x84, 0x74, 0x48, 0x3e, 0xc8, 0x0a, 0xc2, 0x06, 0x43, 0x21, 0x3e, 0xc2, 0x0a, 0xc8, 0x06, 0x43, 0x1a, 0xfe,
-    0xc8, 0x04, 0xc2, 0x04, 0x40, 0x86, 0x7f, 0xf0, 0xaf, 0x71, 0x00, 0xe4, 0x41, 0xa8, 0x41, 0x40, 0x43, 0x1a, 0x80, 0x40, 0x18,
-    0x47, 0x08, 0xfe, 0xc8, 0x06, 0xc2, 0x06, 0x40, 0x86, 0x7f, 0xf2, 0xaf, 0x71, 0x00, 0xe4, 0x41, 0xa8, 0x41, 0x40, 0x4b, 0x08,
-    0x84, 0x01, 0x40, 0x18, 0x47, 0x08, 0x80, 0x40, 0x18, 0x47, 0x08, 0x18, 0x47, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x08, 0x4c, 0x84,
-    0xca, 0x10, 0x48, 0xa0, 0x58, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xdc, 0x04, 0x48, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59,
-    0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x78, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0xc8, 0x08, 0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0x18, 0x48, 0x08, 0x10, 0x49, 0x9c, 0x14, 0x4c, 0x04, 0xca,
-    0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0xc4, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc,
-    0xf4, 0xef, 0x48, 0x03, 0x00, 0x00, 0x04, 0x64, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c,
-    0xa0, 0x4f, 0x44, 0x4c, 0x00, 0x00, 0x04, 0x64, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x5a, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xe4, 0xef, 0xe4, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x10, 0x49, 0xa0, 0x7c, 0x04, 0x74, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xda, 0x10, 0x2c,
-    0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0xa0, 0x5a, 0xa0, 0x4f, 0x54, 0x4d, 0x00,
-    0x00, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x7c, 0xa0, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xe4, 0xef, 0xe4, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x46, 0x84,
-    0x5a, 0x48, 0x77, 0x07, 0x80, 0x40, 0x18, 0x46, 0x08, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0xdc, 0x03, 0x00, 0x00, 0x28, 0x40,
-    0x77, 0x08, 0x84, 0xff, 0x40, 0x18, 0x46, 0x08, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0xf4, 0x02, 0x00, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xfc,
-    0xef, 0xe4, 0x04, 0x00, 0x00, 0x84, 0xc8, 0x10, 0x47, 0x7b, 0x1e, 0x18, 0x46, 0x08, 0xa0, 0xc7, 0x08, 0x2c, 0xcc, 0xfc, 0xef,
-    0x30, 0x02, 0x00, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x84, 0xc7, 0x10, 0x47, 0x3c, 0x00, 0x47,
-    0x77, 0xe4, 0x84, 0xc8, 0x14, 0xef, 0x28, 0x04, 0x00, 0x00, 0x70, 0x3c, 0x48, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x77, 0x09, 0x80,
-    0xef, 0x2c, 0x04, 0x00, 0x00, 0x70, 0x28, 0xc8, 0x14, 0x7f, 0x0a, 0x84, 0xc8, 0x14, 0x40, 0x80, 0xc0, 0x18, 0x70, 0x84, 0xef,
-    0x2c, 0x04, 0x00, 0x00, 0x46, 0x7b, 0x46, 0xdc, 0x08, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x0c, 0xa0, 0x46,
-    0x2c, 0xcc, 0xfc, 0xef, 0x68, 0x05, 0x00, 0x00, 0x84, 0xc6, 0x18, 0x46, 0x3c, 0x00, 0x46, 0x77, 0xb9, 0xa0, 0x48, 0x2c, 0xcc,
-    0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x80, 0x40, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x84, 0x5a, 0x48, 0x84, 0x78,
-    0x47, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03,
-    0x00, 0x00, 0x28, 0x40, 0x7f, 0x77, 0x84, 0xc8, 0x10, 0x46, 0x7b, 0x52, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x20, 0xa0, 0xc6, 0x08, 0x04, 0x74,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x47, 0x2c, 0xcc, 0xf4, 0xef, 0x28, 0x03, 0x00,
-    0x00, 0x18, 0x46, 0x08, 0x84, 0xc6, 0x10, 0x46, 0x3c, 0x00, 0x46, 0x77, 0xad, 0xa0, 0x48, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x47, 0x2c, 0xcc, 0xf4, 0xef, 0x28, 0x03, 0x00, 0x00, 0x18, 0x46, 0x08,
-    0x70, 0x10, 0x49, 0x84, 0xca, 0x10, 0x42, 0xa0, 0x7c, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x52, 0xa0, 0xc2, 0x04, 0x2c, 0xcc, 0xec, 0xef, 0xcc, 0x03, 0x00, 0x00,
-    0x18, 0x49, 0x08, 0x10, 0x49, 0xa0, 0x5a, 0xa0, 0x4f, 0x54, 0x4f, 0x00, 0x00, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0xa0, 0xca, 0x10, 0xa0, 0x00, 0x2c, 0xcc,
-    0xe4, 0xef, 0xe4, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x10, 0x49, 0x84, 0xca, 0x10, 0x42, 0xa0, 0x7c, 0x04, 0x74, 0x40, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x42, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x04, 0x42, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0xc2, 0x08, 0x2c, 0xcc, 0xe8, 0x7f, 0x44, 0xce, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10,
-    0x49, 0x9c, 0x0c, 0x4c, 0x04, 0x59, 0x41, 0x04, 0x74, 0x40, 0x84, 0x50, 0x51, 0x70, 0x3e, 0x78, 0x74, 0x77, 0x07, 0x3e, 0x7a,
-    0x76, 0x7f, 0x76, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x78, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0x7f, 0x7e, 0xcc, 0x00, 0x00,
-    0x04, 0x64, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x5a, 0xa0, 0x4f, 0xb0, 0x4f, 0x00, 0x00, 0xdc, 0x08, 0x5a, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x7c, 0x2c, 0xcc, 0xe4, 0xef, 0xe4, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49,
-    0x28, 0xca, 0x14, 0x77, 0x2f, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0xda, 0x10, 0x2c, 0xcc, 0xf4, 0xaf, 0x96, 0x06, 0x3c, 0xff, 0x40, 0x77, 0x0a, 0x84, 0x01, 0x7f,
-    0xf8, 0xaf, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x43, 0x9c, 0x08, 0x4c, 0x80, 0x7f, 0xf4, 0xaf,
-    0x71, 0x00, 0x70, 0x80, 0x7f, 0xf8, 0xaf, 0x71, 0x00, 0x70, 0x84, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x48, 0x24, 0x7f, 0x1a, 0x52,
-    0x00, 0x00, 0x18, 0x43, 0x08, 0x80, 0x44, 0x84, 0xc8, 0x10, 0x46, 0x24, 0x7f, 0x11, 0x52, 0x00, 0x00, 0x84, 0xc6, 0x10, 0x45,
-    0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84,
-    0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x0b, 0x52, 0x00, 0x00, 0xa0, 0x46, 0x84, 0x46, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x48, 0x2c, 0xcc, 0xe8,
-    0xaf, 0x17, 0x02, 0x84, 0x40, 0x43, 0x77, 0x08, 0x24, 0x7f, 0x0b, 0x52, 0x00, 0x00, 0x3c, 0xff, 0x43, 0x77, 0x66, 0xa0, 0x00,
-    0x2c, 0xcc, 0xfc, 0xaf, 0x77, 0x06, 0x28, 0x44, 0x77, 0x2f, 0x3c, 0x46, 0xc8, 0x10, 0x7f, 0x24, 0x84, 0xc6, 0x14, 0x40, 0x80,
-    0xc0, 0x10, 0x70, 0x84, 0xc8, 0x10, 0x7f, 0xf4, 0xaf, 0x71, 0x00, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x54, 0x06, 0x84,
-    0x46, 0xc8, 0x10, 0x70, 0x80, 0xc6, 0x14, 0x70, 0x80, 0x40, 0x18, 0x43, 0x08, 0x3c, 0x46, 0xc4, 0x10, 0x7f, 0xf7, 0x84, 0xc6,
-    0x14, 0x40, 0x80, 0xc0, 0x10, 0x70, 0x84, 0xc4, 0x10, 0x7f, 0xf4, 0xaf, 0x71, 0x00, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xaf,
-    0x27, 0x06, 0x84, 0x46, 0xc4, 0x10, 0x70, 0x84, 0x44, 0xc6, 0x14, 0x70, 0x7b, 0xd2, 0x84, 0xc6, 0x14, 0x40, 0x84, 0xc6, 0x10,
-    0xc0, 0x10, 0x70, 0x28, 0xc6, 0x10, 0x7f, 0x0c, 0x84, 0xc6, 0x10, 0x40, 0x84, 0xc6, 0x14, 0xc0, 0x14, 0x70, 0x84, 0xc6, 0x14,
-    0x44, 0xa0, 0xc6, 0x08, 0x2c, 0xcc, 0xfc, 0xef, 0x30, 0x02, 0x00, 0x00, 0xa0, 0x46, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00,
-    0x00, 0x7b, 0x05, 0x84, 0x46, 0x44, 0x84, 0x45, 0x46, 0x28, 0x46, 0x76, 0xe0, 0xfe, 0x84, 0xc8, 0x18, 0x48, 0x3c, 0x00, 0x48,
-    0x76, 0xca, 0xfe, 0xa0, 0x1c, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0x02, 0x00, 0x00, 0x84, 0x40, 0x47, 0x77, 0x08, 0x24, 0x7f, 0xd0,
-    0x52, 0x00, 0x00, 0xdc, 0x08, 0x47, 0x41, 0x84, 0x41, 0x64, 0x70, 0x04, 0x5a, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0x84, 0x7f, 0xf4, 0xaf, 0x71, 0x00, 0xc7, 0x10, 0x70, 0x84, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x48, 0x7b, 0x31,
-    0xa0, 0x47, 0xa0, 0x4f, 0x8c, 0x50, 0x00, 0x00, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x48, 0xa0, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xe4, 0xef, 0xe4, 0x02, 0x00,
-    0x00, 0x84, 0xc8, 0x18, 0x48, 0x3c, 0x00, 0x48, 0x7f, 0x0a, 0x28, 0x7f, 0xf8, 0xaf, 0x71, 0x00, 0x7f, 0xc6, 0x84, 0x7f, 0xf4,
-    0xaf, 0x71, 0x00, 0xc7, 0x10, 0x70, 0x28, 0x7f, 0xf8, 0xaf, 0x71, 0x00, 0x77, 0x29, 0x84, 0xc7, 0x10, 0x46, 0x7b, 0x39, 0x84,
-    0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xf8, 0xef, 0x2c, 0x02, 0x00, 0x00, 0x84, 0x40, 0x42, 0x77, 0x0f, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xaf, 0x1a, 0x05, 0x80, 0x40,
-    0x18, 0x43, 0x08, 0x84, 0x42, 0xc6, 0x08, 0x70, 0x84, 0xc6, 0x10, 0x46, 0x3c, 0x00, 0x46, 0x77, 0xc6, 0x84, 0xef, 0x28, 0x04,
-    0x00, 0x00, 0xc7, 0x14, 0x70, 0x80, 0xc7, 0x18, 0x70, 0x28, 0xef, 0x28, 0x04, 0x00, 0x00, 0x7f, 0x0e, 0x84, 0xef, 0x28, 0x04,
-    0x00, 0x00, 0x40, 0x84, 0x47, 0xc0, 0x18, 0x70, 0x28, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x77, 0x0a, 0x84, 0x47, 0xef, 0x2c, 0x04,
-    0x00, 0x00, 0x70, 0x28, 0xef, 0x28, 0x04, 0x00, 0x00, 0x77, 0x0a, 0x84, 0x47, 0xef, 0x28, 0x04, 0x00, 0x00, 0x70, 0xa0, 0x7f,
-    0x0c, 0x04, 0x00, 0x00, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8,
-    0xef, 0x14, 0x02, 0x00, 0x00, 0x84, 0x40, 0x57, 0x70, 0xdc, 0x04, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x40, 0x84, 0x50, 0xc7, 0x04,
-    0x70, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0xdc, 0x03, 0x00, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0xf0, 0x02, 0x00, 0x00,
-    0x84, 0x47, 0x40, 0x18, 0x43, 0x08, 0x70, 0x10, 0x44, 0x9c, 0x14, 0x4c, 0x84, 0x5a, 0x44, 0x04, 0x7c, 0x47, 0x04, 0x59, 0x46,
-    0x04, 0x59, 0x41, 0x04, 0x74, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x46, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x47, 0x40, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00,
-    0x00, 0x28, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x6a, 0x56, 0x00, 0x00, 0x3e, 0x57, 0x56, 0x43, 0x7d, 0xa0, 0x44, 0x84, 0x46, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x86, 0x57, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc6, 0x06, 0xe4,
-    0x40, 0xa0, 0x40, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x74, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x7c, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xe8, 0xaf, 0x3d, 0xff, 0x3c, 0xff,
-    0x40, 0x77, 0x08, 0x24, 0x7f, 0x8b, 0x56, 0x00, 0x00, 0x86, 0x57, 0x56, 0x70, 0x3e, 0xc7, 0x02, 0xc6, 0x02, 0x4b, 0x08, 0x24,
-    0x7f, 0xd7, 0x54, 0x00, 0x00, 0xa0, 0x44, 0x84, 0x46, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x86, 0xc6, 0x04, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc7, 0x02, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef,
-    0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8,
-    0xef, 0x74, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0x7c, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xe8, 0xaf, 0xb4, 0xfe, 0x3c, 0xff, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x8b, 0x56, 0x00, 0x00, 0x86,
-    0xc7, 0x02, 0xc6, 0x02, 0x70, 0x3e, 0xc7, 0x04, 0xc6, 0x04, 0x47, 0x08, 0x24, 0x7f, 0x63, 0x55, 0x00, 0x00, 0xa0, 0x44, 0x86,
-    0xc7, 0x04, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc6, 0x02, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c,
-    0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x04, 0x46, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x74, 0x03, 0x00, 0x00, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x7c, 0x40, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xe8, 0xaf,
-    0x28, 0xfe, 0x3c, 0xff, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x8b, 0x56, 0x00, 0x00, 0x86, 0xc7, 0x04, 0xc6, 0x04, 0x70, 0x3e, 0xc7,
-    0x06, 0xc6, 0x06, 0x47, 0x08, 0x24, 0x7f, 0xee, 0x55, 0x00, 0x00, 0xa0, 0x44, 0x86, 0x56, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0xc7,
-    0x06, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x04, 0x46, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x74, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x7c, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xe8, 0xaf, 0x9d, 0xfd, 0x3c, 0xff, 0x40, 0x77, 0x08, 0x24,
-    0x7f, 0x8b, 0x56, 0x00, 0x00, 0x86, 0xc7, 0x06, 0xc6, 0x06, 0x70, 0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x74, 0x02, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x25,
-    0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc,
-    0x0c, 0x44, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xf4, 0xaf, 0x04, 0x01, 0x18, 0x44, 0x08, 0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0x44, 0x40, 0xa0, 0x50, 0x2c, 0xcc,
-    0xf4, 0xaf, 0xe1, 0x00, 0x3c, 0xff, 0x40, 0x7f, 0x23, 0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x02, 0x00, 0x00, 0x84, 0x40, 0x45, 0x77, 0x08,
-    0x84, 0xff, 0x40, 0x18, 0x44, 0x08, 0xa0, 0x18, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0x02, 0x00, 0x00, 0x84, 0x40, 0x48, 0x77, 0x12,
-    0xa0, 0x45, 0x2c, 0xcc, 0xfc, 0xef, 0x30, 0x02, 0x00, 0x00, 0x84, 0xff, 0x40, 0x18, 0x44, 0x08, 0xdc, 0x08, 0x44, 0x40, 0xa0,
-    0x50, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x45, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xe8,
-    0xef, 0x38, 0x02, 0x00, 0x00, 0xdc, 0x0c, 0x44, 0x40, 0x84, 0x50, 0xc8, 0x0c, 0x70, 0x84, 0x48, 0x41, 0x84, 0x46, 0x40, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x45, 0xc8, 0x08, 0x70, 0x84, 0x44, 0xc8, 0x10, 0x70, 0xdc, 0x14,
-    0x44, 0x40, 0x84, 0x50, 0xc8, 0x14, 0x70, 0xdc, 0x14, 0x44, 0x40, 0x28, 0x50, 0x7f, 0x1c, 0xdc, 0x14, 0x44, 0x40, 0x84, 0x50,
-    0x40, 0x84, 0x48, 0xc0, 0x10, 0x70, 0xdc, 0x14, 0x44, 0x40, 0x84, 0x48, 0x50, 0x70, 0x84, 0x01, 0x40, 0x18, 0x44, 0x08, 0xdc,
-    0x10, 0xca, 0x14, 0x40, 0x84, 0x48, 0x50, 0x70, 0x7b, 0xe9, 0x18, 0x44, 0x08, 0x10, 0x46, 0x84, 0x7f, 0xf4, 0xaf, 0x71, 0x00,
-    0x48, 0x7b, 0x42, 0x18, 0x46, 0x08, 0x84, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x07, 0x80, 0x40, 0x18, 0x46, 0x08,
-    0x84, 0xc8, 0x10, 0x48, 0x3c, 0x00, 0x48, 0x77, 0xc0, 0xa0, 0x18, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0x02, 0x00, 0x00, 0x84, 0x40,
-    0x42, 0x77, 0x08, 0x84, 0xff, 0x40, 0x18, 0x46, 0x08, 0x84, 0x78, 0xc2, 0x0c, 0x70, 0x84, 0x42, 0x41, 0x04, 0x5a, 0x40, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x80, 0xc2, 0x08, 0x70, 0x84, 0x7f, 0xf4, 0xaf, 0x71, 0x00, 0xc2, 0x10,
-    0x70, 0x80, 0xc2, 0x14, 0x70, 0x28, 0x7f, 0xf4, 0xaf, 0x71, 0x00, 0x7f, 0x0e, 0xdc, 0x14, 0x7f, 0xf4, 0xaf, 0x71, 0x00, 0x40,
-    0x84, 0x42, 0x50, 0x70, 0x84, 0x42, 0x7f, 0xf4, 0xaf, 0x71, 0x00, 0x70, 0x80, 0x40, 0x18, 0x46, 0x08, 0x10, 0x47, 0x84, 0x7f,
-    0xf4, 0xaf, 0x71, 0x00, 0x48, 0x7b, 0x23, 0x84, 0xc8, 0x10, 0x47, 0x28, 0xc8, 0x08, 0x7f, 0x0d, 0xa0, 0xc8, 0x08, 0x2c, 0xcc,
-    0xfc, 0xef, 0x30, 0x02, 0x00, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x84, 0x47, 0x48, 0x28, 0x48,
-    0x77, 0xdd, 0x28, 0x5a, 0x7f, 0x0c, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x18, 0x47, 0x08, 0x70, 0x70,
-    0x70, 0x10, 0x45, 0x84, 0x5a, 0x48, 0x7f, 0x07, 0x28, 0xc8, 0x14, 0x77, 0x08, 0x84, 0x01, 0x40, 0x18, 0x45, 0x08, 0x2c, 0x5c,
-    0xef, 0x54, 0x02, 0x00, 0x00, 0x84, 0xc8, 0x14, 0x47, 0x24, 0x7f, 0x2d, 0x59, 0x00, 0x00, 0x18, 0x45, 0x08, 0xdc, 0x08, 0x47,
-    0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48,
-    0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0,
-    0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x29, 0x59, 0x00, 0x00, 0x84, 0xc8, 0x10, 0x46, 0x24, 0x7f,
-    0x24, 0x59, 0x00, 0x00, 0x84, 0xc6, 0x10, 0x45, 0x3c, 0x47, 0xc6, 0x0c, 0x77, 0x7d, 0xa0, 0xc6, 0x08, 0x84, 0x46, 0x40, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x46, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xec, 0xef, 0x7c, 0x03,
-    0x00, 0x00, 0x28, 0xc6, 0x14, 0x7f, 0x0e, 0x84, 0xc6, 0x14, 0x40, 0x84, 0xc6, 0x10, 0xc0, 0x10, 0x70, 0x7b, 0x08, 0x84, 0xc6,
-    0x10, 0xc8, 0x10, 0x70, 0x28, 0xc6, 0x10, 0x7f, 0x0c, 0x84, 0xc6, 0x10, 0x40, 0x84, 0xc6, 0x14, 0xc0, 0x14, 0x70, 0x84, 0xc7,
-    0x10, 0xc6, 0x10, 0x70, 0x80, 0xc6, 0x14, 0x70, 0x84, 0x48, 0xc6, 0x0c, 0x70, 0x28, 0xc7, 0x10, 0x7f, 0x0b, 0x84, 0xc7, 0x10,
-    0x40, 0x84, 0x46, 0xc0, 0x14, 0x70, 0x84, 0x46, 0xc7, 0x10, 0x70, 0x84, 0x45, 0x46, 0x28, 0x46, 0x76, 0x76, 0xff, 0x84, 0xc7,
-    0x14, 0x47, 0x3c, 0x00, 0x47, 0x76, 0x26, 0xff, 0x3c, 0x48, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x7f, 0x4e, 0x3c, 0x48, 0xef, 0x28,
-    0x04, 0x00, 0x00, 0x77, 0x0b, 0x84, 0xc8, 0x14, 0xef, 0x28, 0x04, 0x00, 0x00, 0x70, 0x28, 0xc8, 0x18, 0x7f, 0x0c, 0x84, 0xc8,
-    0x18, 0x40, 0x84, 0xc8, 0x14, 0xc0, 0x14, 0x70, 0x84, 0xc8, 0x14, 0x40, 0x84, 0xc8, 0x18, 0xc0, 0x18, 0x70, 0x84, 0xef, 0x2c,
-    0x04, 0x00, 0x00, 0x40, 0x84, 0x48, 0xc0, 0x14, 0x70, 0x84, 0xef, 0x2c, 0x04, 0x00, 0x00, 0xc8, 0x18, 0x70, 0x80, 0xc8, 0x14,
-    0x70, 0x84, 0x48, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x70, 0x84, 0xef, 0x28, 0x04, 0x00, 0x00, 0x47, 0x7b, 0x4f, 0x84, 0xc7, 0x10,
-    0x46, 0x7b, 0x40, 0x84, 0x46, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x07, 0x84, 0x48, 0xc6, 0x0c, 0x70, 0x84, 0xc6,
-    0x10, 0x46, 0x3c, 0x00, 0x46, 0x77, 0xbf, 0x84, 0xc7, 0x14, 0x47, 0x3c, 0x48, 0x47, 0x77, 0xb0, 0x2c, 0x5c, 0xef, 0x4c, 0x02,
-    0x00, 0x00, 0x84, 0x01, 0x40, 0x18, 0x45, 0x08, 0x10, 0x48, 0x9c, 0x08, 0x4c, 0x84, 0x5a, 0x48, 0x2c, 0x5c, 0xef, 0x54, 0x02,
-    0x00, 0x00, 0xa0, 0x48, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xf0, 0xef, 0x00, 0x03, 0x00, 0x00, 0xa0, 0x48, 0xdc, 0x08, 0x48, 0x40, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x04, 0x59, 0x42,
-    0x2c, 0xcc, 0xf4, 0xef, 0xac, 0x02, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc, 0xf0, 0xef, 0x00, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00,
-    0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00,
-    0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x7f, 0xb4, 0x04, 0x00, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xec, 0xef, 0xcc, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00,
-    0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x44, 0x9c, 0x08, 0x4c, 0x84, 0x5a, 0x48, 0x77, 0x08, 0x24, 0x7f, 0x7f, 0x5c,
-    0x00, 0x00, 0x28, 0xc8, 0x18, 0x77, 0x05, 0x18, 0x44, 0x08, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0x84, 0xc8, 0x18, 0x47,
-    0x24, 0x7f, 0x1d, 0x5c, 0x00, 0x00, 0xdc, 0x08, 0x47, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x19,
-    0x5c, 0x00, 0x00, 0x84, 0xc7, 0x10, 0x45, 0x24, 0x7f, 0x14, 0x5c, 0x00, 0x00, 0x84, 0xc5, 0x10, 0x44, 0x3c, 0x48, 0xc5, 0x0c,
-    0x7f, 0x08, 0x24, 0x7f, 0x11, 0x5c, 0x00, 0x00, 0xa0, 0xc5, 0x08, 0x84, 0x45, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x45, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xec, 0xef, 0x7c, 0x03, 0x00, 0x00, 0x28, 0xc5, 0x14, 0x7f,
-    0x0e, 0x84, 0xc5, 0x14, 0x40, 0x84, 0xc5, 0x10, 0xc0, 0x10, 0x70, 0x7b, 0x08, 0x84, 0xc5, 0x10, 0xc7, 0x10, 0x70, 0x28, 0xc5,
-    0x10, 0x7f, 0x0c, 0x84, 0xc5, 0x10, 0x40, 0x84, 0xc5, 0x14, 0xc0, 0x14, 0x70, 0x84, 0xc8, 0x10, 0xc5, 0x10, 0x70, 0x80, 0xc5,
-    0x14, 0x70, 0x28, 0xc8, 0x10, 0x7f, 0x0b, 0x84, 0xc8, 0x10, 0x40, 0x84, 0x45, 0xc0, 0x14, 0x70, 0x84, 0x45, 0xc8, 0x10, 0x70,
-    0x84, 0x47, 0xc5, 0x0c, 0x70, 0x04, 0x59, 0x41, 0x70, 0x84, 0x45, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0x84, 0xef, 0x28, 0x04, 0x00, 0x00, 0x46, 0x7b, 0x4e, 0x84, 0xc6, 0x10, 0x45, 0x7b, 0x3f, 0x84, 0x45, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00,
-    0x00, 0x28, 0x40, 0x7f, 0x07, 0x84, 0x47, 0xc5, 0x0c, 0x70, 0x84, 0xc5, 0x10, 0x45, 0x3c, 0x00, 0x45, 0x77, 0xc0, 0x84, 0xc6,
-    0x14, 0x46, 0x3c, 0x47, 0x46, 0x77, 0xb1, 0x84, 0x44, 0x45, 0x28, 0x45, 0x76, 0x05, 0xff, 0x84, 0xc7, 0x18, 0x47, 0x3c, 0x00,
-    0x47, 0x76, 0xb5, 0xfe, 0x3c, 0x48, 0xef, 0x28, 0x04, 0x00, 0x00, 0x7f, 0x4e, 0x3c, 0x48, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x77,
-    0x0b, 0x84, 0xc8, 0x18, 0xef, 0x2c, 0x04, 0x00, 0x00, 0x70, 0x28, 0xc8, 0x14, 0x7f, 0x0c, 0x84, 0xc8, 0x14, 0x40, 0x84, 0xc8,
-    0x18, 0xc0, 0x18, 0x70, 0x84, 0xc8, 0x18, 0x40, 0x84, 0xc8, 0x14, 0xc0, 0x14, 0x70, 0x84, 0xef, 0x28, 0x04, 0x00, 0x00, 0x40,
-    0x84, 0x48, 0xc0, 0x18, 0x70, 0x84, 0xef, 0x28, 0x04, 0x00, 0x00, 0xc8, 0x14, 0x70, 0x80, 0xc8, 0x18, 0x70, 0x84, 0x48, 0xef,
-    0x28, 0x04, 0x00, 0x00, 0x70, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x18, 0x44, 0x08, 0x70, 0x70, 0x10, 0x44, 0x9c, 0x0c,
-    0x4c, 0x28, 0x5a, 0x77, 0x08, 0x24, 0x7f, 0x27, 0x5e, 0x00, 0x00, 0xdc, 0x10, 0x5a, 0x40, 0x28, 0x50, 0x77, 0x08, 0x24, 0x7f,
-    0x27, 0x5e, 0x00, 0x00, 0x80, 0x7f, 0x04, 0xb0, 0x71, 0x00, 0x70, 0xa0, 0x18, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0x02, 0x00, 0x00,
-    0x84, 0x40, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x70, 0x77, 0x07, 0x84, 0x01, 0x40, 0x7b, 0x04, 0x80, 0x40, 0x84, 0x40, 0x45, 0x84,
-    0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x7f, 0x00, 0xb0, 0x71, 0x00, 0x70, 0x28, 0x45, 0x77, 0x39, 0xdc, 0x08, 0x5a, 0x40, 0x84, 0x7f,
-    0xfc, 0xaf, 0x71, 0x00, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0x7f, 0xfc, 0xaf, 0x71,
-    0x00, 0x40, 0x84, 0x5a, 0x50, 0x70, 0xdc, 0x10, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x40, 0x80, 0x50, 0x70, 0xdc, 0x14, 0x7f, 0xfc,
-    0xaf, 0x71, 0x00, 0x40, 0x80, 0x50, 0x70, 0x84, 0xef, 0x28, 0x04, 0x00, 0x00, 0x44, 0x7b, 0x59, 0x3c, 0x5a, 0x44, 0x77, 0x0c,
-    0x84, 0x01, 0x7f, 0x04, 0xb0, 0x71, 0x00, 0x70, 0x7b, 0x43, 0xdc, 0x08, 0x44, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f,
-    0x0d, 0xa0, 0x44, 0x2c, 0xcc, 0xfc, 0xaf, 0x3f, 0x01, 0x84, 0x40, 0x45, 0xdc, 0x14, 0x44, 0x40, 0x84, 0x50, 0x44, 0x3c, 0x00,
-    0x44, 0x7f, 0x06, 0x28, 0x45, 0x7f, 0xa2, 0x84, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x47, 0x7b, 0x77, 0x84, 0xc7, 0x10, 0x48, 0x3c,
-    0x5a, 0xc7, 0x0c, 0x7f, 0x37, 0x84, 0x47, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x02, 0x00, 0x00, 0x84, 0x40, 0xc7, 0x08, 0x70, 0x77, 0x07, 0x84, 0x01,
-    0x45, 0x7b, 0x42, 0xdc, 0x10, 0x5a, 0x40, 0x84, 0x50, 0x46, 0x24, 0x7f, 0x93, 0x5e, 0x00, 0x00, 0x28, 0xc7, 0x14, 0x7f, 0x0e,
-    0x84, 0xc7, 0x14, 0x40, 0x84, 0xc7, 0x10, 0xc0, 0x10, 0x70, 0x7b, 0x0b, 0x84, 0xc7, 0x10, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x70,
-    0x28, 0xc7, 0x10, 0x7f, 0x0c, 0x84, 0xc7, 0x10, 0x40, 0x84, 0xc7, 0x14, 0xc0, 0x14, 0x70, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef,
-    0x90, 0x02, 0x00, 0x00, 0x84, 0x48, 0x47, 0x28, 0x47, 0x7f, 0x06, 0x28, 0x45, 0x7f, 0x85, 0x28, 0x45, 0x7f, 0x11, 0xa0, 0x7f,
-    0xfc, 0xaf, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xa2, 0x03, 0x18, 0x44, 0x08, 0xdc, 0x10, 0x5a, 0x40, 0xa0, 0x50, 0x2c, 0xcc,
-    0xfc, 0xaf, 0x93, 0x03, 0xdc, 0x10, 0x5a, 0x40, 0x84, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x50, 0x70, 0x18, 0x44, 0x08, 0x04, 0x59,
-    0x41, 0x70, 0x84, 0x46, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xe0, 0x59, 0x84, 0x47, 0x40, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x64,
-    0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x34, 0xa0, 0xc6, 0x08, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xc7, 0x08, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0x84, 0xc6, 0x10, 0x46, 0x3c, 0x00,
-    0x46, 0x77, 0x94, 0x7a, 0x59, 0xff, 0x70, 0x10, 0x45, 0x9c, 0x1c, 0x4c, 0x84, 0x5a, 0x45, 0x84, 0x7f, 0x00, 0xb0, 0x71, 0x00,
-    0x47, 0x84, 0x00, 0x46, 0xdc, 0x08, 0x45, 0x40, 0x04, 0x59, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0x84, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x48, 0xdc, 0x10, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x40, 0x84, 0x50, 0x7f, 0xfc, 0xaf, 0x71,
-    0x00, 0x70, 0x28, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x7f, 0x0d, 0xdc, 0x14, 0x7f, 0xfc, 0xaf, 0x71, 0x00, 0x40, 0x80, 0x50, 0x70,
-    0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84,
-    0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xf0, 0xef, 0x6c, 0x03, 0x00, 0x00, 0x28, 0x40, 0x77, 0x08, 0x24, 0x7f, 0xf2, 0x60, 0x00, 0x00, 0x3e, 0x58, 0x59, 0x4f, 0x56,
-    0x28, 0x46, 0x77, 0x52, 0x86, 0x58, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x02, 0x48, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x86,
-    0x59, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x06, 0x48, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc,
-    0xf0, 0xef, 0x60, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0xdc, 0x0c, 0x48, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xf4, 0xaf, 0xc1, 0x01, 0x84, 0x40, 0x46, 0x86, 0x59, 0x58, 0x70,
-    0xdc, 0x02, 0x48, 0x40, 0x3e, 0x50, 0x62, 0x4f, 0x56, 0x86, 0x58, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x02, 0x48, 0x40, 0x86, 0x50,
-    0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x04, 0x48, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x62, 0xe4, 0x40, 0xa0, 0x40, 0x04,
-    0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf0, 0xef, 0x60, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0x48, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xf4, 0xaf, 0x68, 0x01, 0x84, 0x40,
-    0x46, 0xdc, 0x02, 0x48, 0x40, 0x86, 0x62, 0x50, 0x70, 0xdc, 0x04, 0x48, 0x40, 0x3e, 0x50, 0x64, 0x43, 0x5e, 0x28, 0x46, 0x77,
-    0x5a, 0x86, 0x64, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x02, 0x48, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x04, 0x48, 0x40,
-    0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x06, 0x48, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x14, 0x42, 0x2c,
-    0xcc, 0xf0, 0xef, 0x60, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xdc, 0x0c, 0x48, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xf4, 0xaf, 0x03, 0x01, 0x84, 0x40, 0x46, 0xdc, 0x04, 0x48,
-    0x40, 0x86, 0x64, 0x50, 0x70, 0xdc, 0x06, 0x48, 0x40, 0x3e, 0x50, 0x66, 0x43, 0x5a, 0x28, 0x46, 0x77, 0x56, 0x86, 0x58, 0xe4,
-    0x40, 0xa0, 0x40, 0x86, 0x66, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x04, 0x48, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xdc, 0x06,
-    0x48, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf0, 0xef, 0x60, 0x03, 0x00, 0x00, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0x48, 0x40, 0xa0,
-    0x50, 0x2c, 0xcc, 0xf4, 0xaf, 0xa2, 0x00, 0x84, 0x40, 0x46, 0xdc, 0x06, 0x48, 0x40, 0x86, 0x66, 0x50, 0x70, 0x28, 0x46, 0x77,
-    0x74, 0x28, 0x7f, 0x04, 0xb0, 0x71, 0x00, 0x7f, 0x1a, 0x84, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x45, 0x7b, 0x1c, 0x84, 0x48, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0x48, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xf4, 0xaf,
-    0x53, 0x00, 0x84, 0x40, 0x46, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x7b, 0x25, 0x84, 0x48, 0x40, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0x48, 0x40, 0xa0,
-    0x50, 0x2c, 0xcc, 0xf4, 0xaf, 0x24, 0x00, 0x84, 0x40, 0x46, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x3c,
-    0x47, 0x48, 0x76, 0x8c, 0xfd, 0x84, 0x46, 0x40, 0x18, 0x45, 0x08, 0x18, 0x45, 0x08, 0x70, 0x70, 0x10, 0x49, 0xa0, 0x18, 0x2c,
-    0xcc, 0xfc, 0xef, 0x18, 0x02, 0x00, 0x00, 0x84, 0x40, 0x42, 0x77, 0x08, 0x84, 0x01, 0x40, 0x18, 0x49, 0x08, 0x28, 0x7f, 0xfc,
-    0xaf, 0x71, 0x00, 0x7f, 0x46, 0xdc, 0x10, 0x7f, 0x00, 0xb0, 0x71, 0x00, 0x40, 0x84, 0x42, 0x50, 0x70, 0xdc, 0x14, 0x42, 0x40,
-    0x84, 0x7f, 0x00, 0xb0, 0x71, 0x00, 0x50, 0x70, 0xdc, 0x10, 0x42, 0x40, 0x80, 0x50, 0x70, 0x84, 0x42, 0x41, 0x04, 0x5a, 0x40,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x0c, 0x42, 0x40, 0x84, 0x78, 0x50, 0x70, 0x84, 0x42, 0x7f,
-    0x00, 0xb0, 0x71, 0x00, 0x70, 0x80, 0x40, 0x18, 0x49, 0x08, 0xdc, 0x14, 0x42, 0x40, 0x80, 0x50, 0x70, 0x84, 0x42, 0x7f, 0xfc,
-    0xaf, 0x71, 0x00, 0x70, 0x7b, 0xc5, 0x18, 0x49, 0x08, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x47, 0x7b, 0x2c, 0xdc, 0x10, 0x47, 0x40,
-    0x84, 0x50, 0x48, 0xdc, 0x08, 0x47, 0x40, 0x28, 0x50, 0x7f, 0x10, 0xdc, 0x08, 0x47, 0x40, 0xa0, 0x50, 0x2c, 0xcc, 0xfc, 0xef,
-    0x30, 0x02, 0x00, 0x00, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x84, 0x48, 0x47, 0x28, 0x47, 0x77, 0xd4,
-    0x18, 0x47, 0x08, 0x10, 0x49, 0x87, 0x5f, 0x8f, 0x00, 0x7f, 0x13, 0x00, 0x20, 0x00, 0x70, 0x87, 0x5f, 0x80, 0x00, 0x7f, 0x17,
-    0x00, 0x20, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x83, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x8b, 0x7f,
-    0x37, 0x00, 0x20, 0x00, 0x40, 0x87, 0x40, 0x48, 0xfb, 0x08, 0x48, 0x40, 0xd4, 0x01, 0x40, 0x40, 0xfb, 0x03, 0x48, 0x41, 0xb3,
-    0x41, 0x40, 0x87, 0x40, 0x48, 0x2b, 0x7f, 0xba, 0x06, 0x60, 0x00, 0x7f, 0x2e, 0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40,
-    0xfb, 0x04, 0x48, 0x41, 0xd4, 0x02, 0x41, 0x41, 0x87, 0x48, 0xe2, 0x42, 0xba, 0x02, 0x42, 0xb2, 0x42, 0x41, 0xfb, 0x01, 0x48,
-    0x42, 0xd0, 0x02, 0x42, 0x42, 0xb2, 0x42, 0x41, 0x86, 0x41, 0x50, 0x70, 0x18, 0x48, 0x08, 0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00,
-    0x00, 0x40, 0x87, 0x48, 0xe2, 0x41, 0x86, 0x41, 0x50, 0x70, 0x18, 0x48, 0x08, 0x10, 0x46, 0x84, 0x10, 0x47, 0x84, 0x5a, 0x42,
-    0xc6, 0x04, 0x78, 0x40, 0x86, 0x7a, 0x41, 0xa8, 0x32, 0x41, 0x9c, 0x41, 0x40, 0x9c, 0x40, 0x40, 0x9c, 0x52, 0x40, 0x84, 0x74,
-    0x41, 0xfa, 0x1f, 0x78, 0x42, 0xbc, 0x47, 0x42, 0x7f, 0x27, 0x84, 0x4f, 0x00, 0x00, 0xff, 0xff, 0x46, 0x3b, 0x02, 0x40, 0x77,
-    0x2f, 0x70, 0xd8, 0x42, 0xe2, 0x51, 0x48, 0xb4, 0x48, 0x50, 0x70, 0x9c, 0x6f, 0x64, 0x40, 0x9c, 0x02, 0x41, 0x94, 0x47, 0x77,
-    0xee, 0x18, 0x46, 0x08, 0x70, 0xb6, 0x51, 0x50, 0x70, 0x9c, 0x6f, 0x64, 0x40, 0x9c, 0x02, 0x41, 0x94, 0x47, 0x77, 0xf3, 0x18,
-    0x46, 0x08, 0x70, 0x70, 0xd8, 0x42, 0xe2, 0x51, 0x48, 0xb6, 0x48, 0x50, 0x70, 0xb8, 0x46, 0x48, 0xb4, 0x48, 0xc0, 0x02, 0x70,
-    0x9c, 0x02, 0x41, 0x9c, 0x6f, 0x64, 0x40, 0x94, 0x47, 0x77, 0xe6, 0x18, 0x46, 0x08, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x41, 0x84,
-    0x74, 0x42, 0x84, 0x78, 0x48, 0xf8, 0x02, 0x48, 0x7f, 0x08, 0xb0, 0x71, 0x00, 0x70, 0xf8, 0x01, 0x48, 0x7f, 0x0c, 0xb0, 0x71,
-    0x00, 0x70, 0x3c, 0x5f, 0x0a, 0x01, 0x41, 0x43, 0x0a, 0x84, 0x01, 0x7f, 0x0c, 0xb0, 0x71, 0x00, 0x70, 0x3c, 0x5f, 0x15, 0x02,
-    0x41, 0x4f, 0x09, 0x80, 0x7f, 0x0c, 0xb0, 0x71, 0x00, 0x70, 0x3c, 0x5f, 0x55, 0x01, 0x42, 0x43, 0x09, 0x80, 0x7f, 0x08, 0xb0,
-    0x71, 0x00, 0x70, 0x3c, 0x5f, 0xaa, 0x02, 0x42, 0x4f, 0x0a, 0x84, 0x02, 0x7f, 0x08, 0xb0, 0x71, 0x00, 0x70, 0xf0, 0x7f, 0x0c,
-    0xb0, 0x71, 0x00, 0x7f, 0x08, 0xb0, 0x71, 0x00, 0x40, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x2c,
-    0x5c, 0xef, 0xa0, 0x03, 0x00, 0x00, 0x84, 0x40, 0x48, 0xdc, 0x12, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x86, 0x50, 0x41, 0x92,
-    0x50, 0x70, 0x2a, 0x41, 0x77, 0x32, 0xdc, 0x14, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x2a, 0x50, 0x7f, 0x26, 0xa0, 0xef, 0x00,
-    0x04, 0x00, 0x00, 0xdc, 0x04, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x50, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40,
-    0x87, 0x50, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf4, 0xaf, 0xb8, 0x02, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0xb4, 0x03, 0x00,
-    0x00, 0x18, 0x48, 0x08, 0x70, 0x10, 0x49, 0xdc, 0x12, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x2a, 0x50, 0x4f, 0x0d, 0xdc, 0x12,
-    0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x96, 0x50, 0x70, 0x18, 0x49, 0x08, 0x10, 0x47, 0x84, 0x5a, 0x41, 0x84, 0x74, 0x48, 0x84,
-    0x78, 0x42, 0xfc, 0x58, 0x41, 0x47, 0x3c, 0x5f, 0x00, 0x08, 0x47, 0x4f, 0x0b, 0x9c, 0x5f, 0x00, 0x10, 0xc8, 0x04, 0x70, 0x7b,
-    0x10, 0x3c, 0x5f, 0x00, 0xf8, 0x47, 0x43, 0x09, 0xbc, 0x5f, 0x00, 0x10, 0xc8, 0x04, 0x70, 0x84, 0x41, 0x58, 0x70, 0xfc, 0xc8,
-    0x04, 0x41, 0x47, 0x43, 0x0f, 0x84, 0x41, 0xc8, 0x04, 0x70, 0x80, 0x47, 0x84, 0x47, 0x40, 0x18, 0x47, 0x08, 0x3c, 0x42, 0x47,
-    0x4b, 0xf7, 0xfc, 0x42, 0x41, 0xc8, 0x04, 0x70, 0xfc, 0x01, 0x42, 0x47, 0x7b, 0xeb, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10,
-    0x46, 0xfa, 0x5f, 0xff, 0x0f, 0x7f, 0x02, 0x00, 0x40, 0x00, 0x40, 0x70, 0x84, 0x40, 0x48, 0xfa, 0x5f, 0xff, 0x0f, 0x7f, 0x00,
-    0x00, 0x40, 0x00, 0x40, 0x8c, 0x40, 0x40, 0x70, 0x84, 0x40, 0x47, 0xdc, 0x08, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x84, 0xef,
-    0x00, 0x04, 0x00, 0x00, 0x50, 0x70, 0xdc, 0x0c, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xdc, 0x04, 0x7f, 0x00, 0x04, 0x00, 0x00,
-    0x41, 0x84, 0x51, 0x50, 0x70, 0xdc, 0x11, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x41,
-    0x87, 0x51, 0x50, 0x70, 0xa0, 0x48, 0xa0, 0x4f, 0x10, 0xb0, 0x71, 0x00, 0xa0, 0x5f, 0x20, 0x03, 0x2c, 0xcc, 0xf4, 0xaf, 0x3f,
-    0xff, 0x84, 0x40, 0x48, 0xa0, 0x47, 0xa0, 0x4f, 0x18, 0xb0, 0x71, 0x00, 0xa0, 0x5f, 0x00, 0x04, 0x2c, 0xcc, 0xf4, 0xaf, 0x2a,
-    0xff, 0x84, 0x40, 0x47, 0xa0, 0x48, 0xa0, 0x47, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x87, 0x50, 0xe0, 0x40, 0xa0,
-    0x40, 0x2c, 0xcc, 0xf4, 0xaf, 0x2b, 0xfe, 0x84, 0x40, 0x46, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x87, 0x50, 0xe0,
-    0x40, 0x3c, 0x40, 0x46, 0x7f, 0x73, 0x3c, 0x4f, 0x00, 0xdf, 0x00, 0x00, 0xef, 0x04, 0x04, 0x00, 0x00, 0x77, 0x66, 0xf8, 0x01,
-    0x46, 0x40, 0x3c, 0x01, 0x40, 0x77, 0x12, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x3b, 0x50, 0x01, 0x77, 0x05, 0x9c,
-    0x04, 0x48, 0x38, 0x46, 0x01, 0x77, 0x16, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xfb, 0x01, 0x50, 0x40, 0x3c, 0x01,
-    0x40, 0x77, 0x05, 0xbc, 0x04, 0x48, 0x38, 0x46, 0x02, 0x77, 0x16, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xfb, 0x02,
-    0x50, 0x40, 0x3c, 0x02, 0x40, 0x77, 0x05, 0x9c, 0x04, 0x47, 0xf8, 0x02, 0x46, 0x40, 0x3c, 0x02, 0x40, 0x77, 0x12, 0xdc, 0x10,
-    0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x3b, 0x50, 0x02, 0x77, 0x05, 0xbc, 0x04, 0x47, 0xd0, 0x03, 0x46, 0x40, 0x9c, 0xef, 0x04,
-    0x04, 0x00, 0x00, 0x40, 0x70, 0x84, 0x40, 0x42, 0x8e, 0xc2, 0x04, 0x40, 0x3c, 0x40, 0x48, 0x43, 0x0a, 0x8e, 0xc2, 0x04, 0x40,
-    0x70, 0x84, 0x40, 0x48, 0xde, 0x10, 0xc2, 0x04, 0x40, 0x9c, 0x48, 0x40, 0x3c, 0x5f, 0x20, 0x03, 0x40, 0x4f, 0x0d, 0xde, 0x10,
-    0xc2, 0x04, 0x40, 0xfc, 0x40, 0x5f, 0x20, 0x03, 0x48, 0x8e, 0xc2, 0x06, 0x40, 0x3c, 0x40, 0x47, 0x43, 0x0a, 0x8e, 0xc2, 0x06,
-    0x40, 0x70, 0x84, 0x40, 0x47, 0xde, 0x10, 0xc2, 0x06, 0x40, 0x9c, 0x47, 0x40, 0x3c, 0x5f, 0x00, 0x04, 0x40, 0x4f, 0x0d, 0xde,
-    0x10, 0xc2, 0x06, 0x40, 0xfc, 0x40, 0x5f, 0x00, 0x04, 0x47, 0xdc, 0x08, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x3c, 0x50, 0x48,
-    0x77, 0x20, 0xdc, 0x0c, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x3c, 0x50, 0x47, 0x77, 0x13, 0xdc, 0x14, 0x7f, 0x00, 0x04, 0x00,
-    0x00, 0x40, 0x2a, 0x50, 0x7f, 0x07, 0x80, 0x40, 0x18, 0x46, 0x08, 0x84, 0x48, 0xef, 0x00, 0x04, 0x00, 0x00, 0x70, 0xdc, 0x04,
-    0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x84, 0x47, 0x50, 0x70, 0xdc, 0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x84, 0x46, 0xe7,
-    0x50, 0x70, 0x84, 0x48, 0xe6, 0x7f, 0xa8, 0xa7, 0x71, 0x00, 0x70, 0x84, 0x47, 0xe6, 0x7f, 0xaa, 0xa7, 0x71, 0x00, 0x70, 0x84,
-    0x4f, 0xac, 0xa7, 0x71, 0x00, 0x41, 0x84, 0x4f, 0xa8, 0xa7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x01, 0x40, 0x18,
-    0x46, 0x08, 0x18, 0x46, 0x08, 0x70, 0x70, 0x10, 0x49, 0x86, 0x5a, 0xe4, 0x40, 0xfc, 0x40, 0x7f, 0x10, 0xb0, 0x71, 0x00, 0x7f,
-    0x14, 0xb0, 0x71, 0x00, 0x70, 0x86, 0x72, 0xe4, 0x40, 0xfc, 0x40, 0x7f, 0x18, 0xb0, 0x71, 0x00, 0x40, 0x84, 0x40, 0x7f, 0x1c,
-    0xb0, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x47, 0x9c, 0x04, 0x4c, 0xd0, 0x03, 0x78, 0x40, 0x9c, 0xef, 0x04,
-    0x04, 0x00, 0x00, 0x40, 0x70, 0x84, 0x40, 0x48, 0x84, 0x58, 0x7f, 0x20, 0xb0, 0x71, 0x00, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00,
-    0x00, 0xa0, 0x58, 0x86, 0xc8, 0x04, 0xe4, 0x40, 0x9c, 0x5a, 0x40, 0xa0, 0x40, 0x86, 0xc8, 0x06, 0xe4, 0x40, 0x9c, 0x74, 0x40,
-    0xa0, 0x40, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x50, 0x02, 0x00, 0x00, 0xdc, 0x14, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xdc, 0x14,
-    0x7f, 0x00, 0x04, 0x00, 0x00, 0x41, 0xfe, 0x51, 0x01, 0x41, 0x86, 0x41, 0x50, 0x70, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10,
-    0x49, 0x84, 0x7f, 0x54, 0x04, 0x00, 0x00, 0xef, 0x04, 0x04, 0x00, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x24, 0xb0, 0x71, 0x00, 0x70,
-    0x86, 0x10, 0x7f, 0x2e, 0xb0, 0x71, 0x00, 0x70, 0x86, 0x7f, 0x2e, 0xb0, 0x71, 0x00, 0x7f, 0x2c, 0xb0, 0x71, 0x00, 0x70, 0xdc,
-    0x12, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x82, 0x50, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49, 0x2c, 0x5c, 0xaf, 0x26, 0xfd, 0x28,
-    0x40, 0x7f, 0x66, 0xdc, 0x12, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x2a, 0x50, 0x77, 0x5a, 0xdc, 0x14, 0x7f, 0x00, 0x04, 0x00,
-    0x00, 0x40, 0x2a, 0x50, 0x7f, 0x2a, 0xdc, 0x08, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x50, 0xdc, 0x0c, 0x7f, 0x00, 0x04,
-    0x00, 0x00, 0x40, 0xa0, 0x50, 0xdc, 0x11, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x87, 0x50, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc,
-    0xf4, 0xaf, 0x0f, 0xff, 0xa0, 0xef, 0x00, 0x04, 0x00, 0x00, 0xdc, 0x04, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x50, 0xdc,
-    0x10, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x87, 0x50, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf4, 0xaf, 0xeb, 0xfe, 0x92, 0xef,
-    0x1c, 0x04, 0x00, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x41, 0x3c, 0x00, 0x41, 0x4f, 0x19,
-    0x82, 0xef, 0x1c, 0x04, 0x00, 0x00, 0x70, 0x86, 0xef, 0x1c, 0x04, 0x00, 0x00, 0xe4, 0x40, 0x3c, 0x41, 0x40, 0x4b, 0xf5, 0x18,
-    0x49, 0x08, 0x18, 0x49, 0x08, 0x10, 0x47, 0x84, 0x5a, 0x48, 0x84, 0x7f, 0x34, 0xb0, 0x71, 0x00, 0x47, 0x84, 0x48, 0x7f, 0x34,
-    0xb0, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0x28, 0x48, 0x77, 0x1b, 0x84, 0x7f, 0x54, 0x04, 0x00, 0x00,
-    0xef, 0x04, 0x04, 0x00, 0x00, 0x70, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x84, 0x47, 0x40, 0x18, 0x47, 0x08, 0x3c, 0xff,
-    0x48, 0x77, 0x10, 0x84, 0x4f, 0x00, 0xdf, 0x00, 0x00, 0xef, 0x04, 0x04, 0x00, 0x00, 0x70, 0x7b, 0xe2, 0x84, 0x7f, 0x5c, 0x04,
-    0x00, 0x00, 0xef, 0x04, 0x04, 0x00, 0x00, 0x70, 0x84, 0x7f, 0x5c, 0x04, 0x00, 0x00, 0x41, 0x7b, 0x19, 0x18, 0x47, 0x08, 0x84,
-    0x48, 0x51, 0x70, 0x84, 0xf8, 0x40, 0x86, 0x40, 0xc1, 0x06, 0x70, 0x86, 0x40, 0xc1, 0x04, 0x70, 0x9c, 0x08, 0x41, 0xdc, 0x20,
-    0x7f, 0x5c, 0x04, 0x00, 0x00, 0x40, 0x3c, 0x40, 0x41, 0x5b, 0xe1, 0x7b, 0xa7, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x4f, 0x00, 0x00,
-    0x00, 0x00, 0x4c, 0x70, 0xb0, 0x4f, 0x00, 0xe0, 0x81, 0x02, 0x4b, 0x70, 0xa0, 0xcc, 0xdc, 0xa0, 0xcc, 0xdc, 0x84, 0x4f, 0x20,
-    0xdf, 0x00, 0x00, 0x48, 0xa0, 0x48, 0xa0, 0x4d, 0x2c, 0xcc, 0xf0, 0x7f, 0xd0, 0x68, 0x00, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70,
-    0x10, 0x48, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0xce, 0xfc, 0x48, 0xa0, 0xc8, 0x04, 0xa0, 0x58, 0x84, 0x4f, 0x27,
-    0xdf, 0x00, 0x00, 0x48, 0xa0, 0x48, 0xa0, 0x48, 0x2c, 0xcc, 0xf0, 0x7f, 0xd0, 0x68, 0x00, 0x00, 0x18, 0x48, 0x08, 0x10, 0x48,
-    0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0xa0, 0xce, 0xfc, 0x70, 0xa0, 0x4b, 0x84, 0x4f, 0x2d, 0xdf, 0x00, 0x00, 0x48, 0xa0,
-    0x48, 0xa0, 0xce, 0xfc, 0x2c, 0xcc, 0xf0, 0x7f, 0xd0, 0x68, 0x00, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x4f,
-    0x00, 0x00, 0x00, 0x00, 0x4c, 0xa0, 0xce, 0xfc, 0x70, 0xa0, 0x4b, 0x84, 0x4f, 0x35, 0xdf, 0x00, 0x00, 0x48, 0xa0, 0x48, 0xa0,
-    0xce, 0xfc, 0x2c, 0xcc, 0xf0, 0x7f, 0xd0, 0x68, 0x00, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x18, 0x00,
-    0x00, 0x00, 0x4c, 0x2c, 0x5c, 0xef, 0x70, 0x03, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0xa0, 0x00, 0xa0, 0x5f, 0xc9,
-    0x03, 0xa0, 0x5f, 0x20, 0x03, 0xa0, 0x5f, 0x00, 0x04, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf0, 0xef, 0x60, 0x03, 0x00, 0x00,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc,
-    0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x78, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0xa0,
-    0x14, 0xa0, 0x5f, 0xd7, 0x03, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00,
-    0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x4f, 0x45, 0xdf, 0x00, 0x00, 0xa0, 0x7f,
-    0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04,
-    0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xd4, 0x03, 0x74,
-    0x40, 0xb8, 0x0f, 0x40, 0xa0, 0x40, 0xe0, 0x64, 0x2c, 0xcc, 0xf8, 0xaf, 0x79, 0x01, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xe0,
-    0x64, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x03, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x3f, 0x6f, 0x50, 0xda, 0x08, 0x7f, 0x72, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x4f, 0x54, 0xdf, 0x00, 0x00, 0xa0, 0x7f,
-    0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04,
-    0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x5a, 0xe0,
-    0x64, 0x2c, 0xcc, 0xf8, 0xaf, 0x02, 0x01, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xe0, 0x64, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00,
-    0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x10, 0x42, 0x2c,
-    0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0x3f, 0x6f, 0x4e, 0xda, 0x08, 0x7f, 0x72,
-    0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x4f, 0x5b, 0xdf, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xec, 0xef,
-    0xb8, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0xe0, 0x64, 0x2c, 0xcc, 0xf8, 0xaf, 0x8b, 0x00,
-    0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xe0, 0x64, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00,
-    0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x4f, 0x6b, 0xdf, 0x00, 0x00, 0xa0, 0x7f,
-    0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04,
-    0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x7b, 0x02, 0x2c, 0x5c, 0xef, 0xe0, 0x02, 0x00, 0x00, 0x84,
-    0x40, 0x5a, 0x70, 0x3c, 0x5f, 0x8e, 0x00, 0x40, 0x7f, 0x09, 0x3c, 0x5f, 0xae, 0x00, 0x5a, 0x77, 0xe9, 0x24, 0xef, 0x5c, 0x03,
-    0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x46, 0x84, 0x5a, 0x46, 0x84, 0x00, 0x47, 0x84, 0x47, 0x42, 0x7b, 0x45, 0xfc,
-    0x42, 0x07, 0x40, 0xd0, 0x02, 0x40, 0x40, 0xd4, 0x40, 0x46, 0x40, 0xb8, 0x0f, 0x40, 0x84, 0x40, 0x48, 0x77, 0x06, 0x28, 0x47,
-    0x7f, 0x2b, 0x3c, 0x09, 0x48, 0x4f, 0x12, 0xdc, 0x47, 0x74, 0x40, 0xf8, 0x5f, 0xff, 0x00, 0x48, 0xe3, 0x41, 0x9f, 0x37, 0x41,
-    0x7b, 0x10, 0xdc, 0x47, 0x74, 0x40, 0xf8, 0x5f, 0xff, 0x00, 0x48, 0xe3, 0x41, 0x9f, 0x30, 0x41, 0x87, 0x41, 0x50, 0x70, 0x90,
-    0x47, 0x90, 0x42, 0x3c, 0x08, 0x42, 0x4b, 0xba, 0x28, 0x47, 0x77, 0x0e, 0x84, 0x47, 0x40, 0x90, 0x47, 0x9c, 0x74, 0x40, 0x87,
-    0x30, 0x50, 0x70, 0xdc, 0x47, 0x74, 0x40, 0x83, 0x50, 0x70, 0x18, 0x46, 0x08, 0x70, 0x10, 0x48, 0x9c, 0x4f, 0x08, 0x00, 0x00,
-    0x00, 0x4c, 0x84, 0x7f, 0x48, 0xc3, 0x71, 0x00, 0x59, 0x70, 0x84, 0x7f, 0x4c, 0xc3, 0x71, 0x00, 0x64, 0x70, 0x84, 0x4f, 0x00,
-    0xe0, 0x81, 0x02, 0x7f, 0x48, 0xc3, 0x71, 0x00, 0x70, 0x04, 0x7f, 0x58, 0x6c, 0x00, 0x00, 0x7f, 0x4c, 0xc3, 0x71, 0x00, 0x70,
-    0x87, 0x7f, 0x33, 0x00, 0x30, 0x00, 0x48, 0x84, 0x59, 0x7f, 0x48, 0xc3, 0x71, 0x00, 0x70, 0x84, 0x64, 0x7f, 0x4c, 0xc3, 0x71,
-    0x00, 0x70, 0x87, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0xe0, 0x59, 0x70, 0x38, 0x48, 0x5f, 0xc0, 0x00, 0x77, 0x07, 0x87, 0x01, 0x40,
-    0x7b, 0x04, 0x83, 0x40, 0x87, 0x40, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x70, 0x2b, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x7f, 0x06, 0x28,
-    0x59, 0x77, 0x61, 0x83, 0x7f, 0xe2, 0x06, 0x60, 0x00, 0x70, 0x2b, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x7f, 0x13, 0x87, 0x01, 0x7f,
-    0xe6, 0x06, 0x60, 0x00, 0x70, 0x87, 0x7f, 0xe6, 0x06, 0x60, 0x00, 0x40, 0x7b, 0x11, 0x87, 0x03, 0x7f, 0xe6, 0x06, 0x60, 0x00,
-    0x70, 0x87, 0x7f, 0xe6, 0x06, 0x60, 0x00, 0x40, 0x2b, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x7f, 0x13, 0x87, 0x02, 0x7f, 0xea, 0x06,
-    0x60, 0x00, 0x70, 0x87, 0x7f, 0xea, 0x06, 0x60, 0x00, 0x40, 0x7b, 0x11, 0x87, 0x03, 0x7f, 0xea, 0x06, 0x60, 0x00, 0x70, 0x87,
-    0x7f, 0xea, 0x06, 0x60, 0x00, 0x40, 0x87, 0x03, 0x7f, 0xee, 0x06, 0x60, 0x00, 0x70, 0x7b, 0x0e, 0x87, 0x5f, 0xc0, 0x00, 0x48,
-    0x9c, 0x07, 0xcc, 0xf8, 0x70, 0x30, 0x45, 0x18, 0x48, 0x08, 0x70, 0x10, 0x49, 0xeb, 0x18, 0x7f, 0xe2, 0x06, 0x60, 0x00, 0x40,
-    0x9c, 0x4f, 0x90, 0xdf, 0x00, 0x00, 0x40, 0x84, 0x40, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x70, 0xeb, 0x18, 0x7f, 0xe6, 0x06, 0x60,
-    0x00, 0x40, 0x9c, 0x4f, 0x90, 0xdf, 0x00, 0x00, 0x40, 0x84, 0x40, 0x7f, 0xe4, 0xa7, 0x71, 0x00, 0x70, 0xeb, 0x18, 0x7f, 0xea,
-    0x06, 0x60, 0x00, 0x40, 0x9c, 0x4f, 0x90, 0xdf, 0x00, 0x00, 0x40, 0x84, 0x40, 0x7f, 0xe8, 0xa7, 0x71, 0x00, 0x70, 0xeb, 0x18,
-    0x7f, 0xee, 0x06, 0x60, 0x00, 0x40, 0x9c, 0x4f, 0x90, 0xdf, 0x00, 0x00, 0x40, 0x84, 0x40, 0x7f, 0xec, 0xa7, 0x71, 0x00, 0x70,
-    0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x04, 0x4c, 0xdc, 0x04, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0xa0, 0x50,
-    0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0x84, 0x40, 0x48, 0x7f, 0x12, 0xdc, 0x08,
-    0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59, 0x70, 0x2c, 0x5c, 0xd9, 0x00, 0x84, 0x48, 0x40, 0x18, 0x48, 0x08, 0x18,
-    0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00,
-    0x84, 0x40, 0x42, 0x3c, 0xff, 0x42, 0x7f, 0x07, 0xb8, 0x5f, 0xff, 0x00, 0x42, 0x2b, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x7f, 0x0b,
-    0x3f, 0x01, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x7f, 0x2b, 0x84, 0x42, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0xa0, 0x08, 0x2c,
-    0xcc, 0xfc, 0x7f, 0x8c, 0x71, 0x00, 0x00, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00,
-    0x84, 0x40, 0x42, 0x3c, 0xff, 0x42, 0x7f, 0xe2, 0x3c, 0x6f, 0x40, 0x42, 0x43, 0x07, 0x3c, 0x20, 0x42, 0x77, 0xe1, 0x3c, 0x20,
-    0x42, 0x77, 0x2c, 0x84, 0x06, 0x7f, 0x3c, 0xb0, 0x71, 0x00, 0x70, 0x7b, 0x0c, 0xa0, 0x08, 0x2c, 0xcc, 0xfc, 0x7f, 0x8c, 0x71,
-    0x00, 0x00, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x84, 0x40, 0x42, 0x3c, 0xff,
-    0x42, 0x7f, 0xe2, 0x9c, 0x02, 0x7f, 0x3c, 0xb0, 0x71, 0x00, 0x70, 0x3c, 0x08, 0x7f, 0x3c, 0xb0, 0x71, 0x00, 0x77, 0x35, 0x84,
-    0x02, 0x7f, 0x3c, 0xb0, 0x71, 0x00, 0x70, 0x84, 0x42, 0xe7, 0x7f, 0x38, 0xb0, 0x71, 0x00, 0x70, 0x7b, 0x0c, 0xa0, 0x08, 0x2c,
-    0xcc, 0xfc, 0x7f, 0x8c, 0x71, 0x00, 0x00, 0xa0, 0xef, 0x24, 0x90, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00,
-    0x84, 0x40, 0x42, 0x3c, 0xff, 0x42, 0x7f, 0xe2, 0xf8, 0x3f, 0x42, 0x40, 0x87, 0x7f, 0x38, 0xb0, 0x71, 0x00, 0xe0, 0x41, 0xd0,
-    0x7f, 0x3c, 0xb0, 0x71, 0x00, 0x41, 0x41, 0xb8, 0x5f, 0xc0, 0x00, 0x41, 0xb0, 0x41, 0x40, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10,
-    0x48, 0x9c, 0x04, 0x4c, 0xdc, 0x04, 0x7f, 0xe4, 0xa7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0x87, 0x73, 0xe0, 0x40, 0xa0, 0x40, 0x2c,
-    0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0x84, 0x40, 0x48, 0x7f, 0x12, 0xdc, 0x08, 0x7f, 0xe4, 0xa7, 0x71, 0x00, 0x40, 0x84,
-    0x50, 0x59, 0x70, 0x2c, 0x5c, 0xd9, 0x00, 0x84, 0x48, 0x40, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49,
-    0xa0, 0xef, 0xe4, 0xa7, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x10,
-    0x48, 0x9c, 0x04, 0x4c, 0xdc, 0x04, 0x7f, 0xe8, 0xa7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xa0, 0x5a, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8,
-    0x6f, 0x00, 0x00, 0x84, 0x40, 0x48, 0x7f, 0x12, 0xdc, 0x08, 0x7f, 0xe8, 0xa7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59, 0x70, 0x2c,
-    0x5c, 0xd9, 0x00, 0x84, 0x48, 0x40, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0xa0, 0xef, 0xe8, 0xa7,
-    0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x10, 0x48, 0x9c, 0x04, 0x4c,
-    0xdc, 0x04, 0x7f, 0xec, 0xa7, 0x71, 0x00, 0x40, 0xa0, 0x50, 0xa0, 0x5a, 0x2c, 0xcc, 0xf8, 0x7f, 0xd8, 0x6f, 0x00, 0x00, 0x84,
-    0x40, 0x48, 0x7f, 0x12, 0xdc, 0x08, 0x7f, 0xec, 0xa7, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59, 0x70, 0x2c, 0x5c, 0xd9, 0x00, 0x84,
-    0x48, 0x40, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0xa0, 0xef, 0xec, 0xa7, 0x71, 0x00, 0x2c, 0xcc,
-    0xfc, 0x7f, 0xac, 0x70, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0xdc, 0x10, 0x7f, 0x24,
-    0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59, 0x70, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xd9, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49,
-    0x9c, 0x04, 0x4c, 0xdc, 0x0c, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59, 0x70, 0x2c, 0x5c, 0xd9, 0x00, 0x18, 0x49,
-    0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0xdc, 0x14, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59,
-    0x70, 0xa0, 0x03, 0xa0, 0x00, 0x2c, 0xcc, 0xf8, 0xd9, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c,
-    0xdc, 0x14, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59, 0x70, 0xa0, 0x04, 0xa0, 0x00, 0x2c, 0xcc, 0xf8, 0xd9, 0x00,
-    0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0xdc, 0x14, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50,
-    0x59, 0x70, 0xa0, 0x05, 0xa0, 0x00, 0x2c, 0xcc, 0xf8, 0xd9, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04,
-    0x4c, 0xdc, 0x14, 0x7f, 0x24, 0x90, 0x71, 0x00, 0x40, 0x84, 0x50, 0x59, 0x70, 0xa0, 0x06, 0xa0, 0x00, 0x2c, 0xcc, 0xf8, 0xd9,
-    0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x80, 0x41, 0x84, 0x4f, 0x74, 0x97, 0x71, 0x00, 0x40, 0x7b, 0x0c, 0x84,
-    0x41, 0x50, 0x70, 0x84, 0x40, 0x41, 0x9c, 0x08, 0x40, 0x3c, 0x4f, 0x74, 0xa7, 0x71, 0x00, 0x40, 0x5b, 0xef, 0x84, 0x41, 0x7f,
-    0x98, 0xa7, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x45, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x5a, 0x48,
-    0x84, 0x74, 0x47, 0x70, 0xa0, 0x4b, 0x70, 0xb0, 0x4f, 0x00, 0xe0, 0x81, 0x02, 0x4b, 0x70, 0x84, 0x7f, 0x98, 0xa7, 0x71, 0x00,
-    0x45, 0x3c, 0x00, 0x45, 0x7f, 0x12, 0x3e, 0x5f, 0x80, 0x01, 0xc8, 0x08, 0x4f, 0x2a, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x7f,
-    0x22, 0x20, 0x4b, 0x70, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x77, 0x13, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x34, 0x03, 0x00,
-    0x00, 0x2c, 0x5c, 0xef, 0x70, 0x03, 0x00, 0x00, 0x80, 0x40, 0x7b, 0x49, 0x84, 0x55, 0x7f, 0x98, 0xa7, 0x71, 0x00, 0x70, 0x80,
-    0x55, 0x70, 0x84, 0x47, 0x40, 0x86, 0x40, 0x40, 0x86, 0x40, 0xc5, 0x04, 0x70, 0x2a, 0xc8, 0x08, 0x77, 0x10, 0x84, 0x45, 0x58,
-    0x70, 0x84, 0x45, 0x40, 0x84, 0x40, 0xc8, 0x04, 0x70, 0x7b, 0x0b, 0x84, 0x45, 0xd8, 0x00, 0x70, 0x84, 0x45, 0x58, 0x70, 0x92,
-    0xc8, 0x08, 0x70, 0x86, 0xc8, 0x08, 0xe4, 0x40, 0x86, 0x40, 0xe4, 0x46, 0x20, 0x4b, 0x70, 0x84, 0x46, 0x40, 0x7b, 0x02, 0x18,
-    0x45, 0x08, 0x70, 0x70, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x48, 0x84, 0x74, 0x47, 0x2b, 0x57, 0x7f, 0x1a, 0xa0, 0x48, 0x87, 0x57,
-    0xe0, 0x40, 0x9c, 0x01, 0x47, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x84, 0x40, 0x41, 0x43, 0xe6, 0x84,
-    0x41, 0x40, 0x18, 0x47, 0x08, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84,
-    0x5a, 0x48, 0x70, 0xa0, 0x4b, 0x70, 0xb0, 0x4f, 0x00, 0xe0, 0x81, 0x02, 0x4b, 0x70, 0x84, 0xc8, 0x04, 0x47, 0x77, 0x0a, 0x20,
-    0x4b, 0x70, 0x84, 0xff, 0x40, 0x7b, 0x40, 0x96, 0xc8, 0x08, 0x70, 0x86, 0xc8, 0x08, 0xe4, 0x40, 0x2a, 0x40, 0x77, 0x11, 0x87,
-    0x00, 0xe0, 0x40, 0x84, 0x40, 0x58, 0x70, 0x84, 0x40, 0xc8, 0x04, 0x70, 0x7b, 0x07, 0x84, 0x57, 0xc8, 0x04, 0x70, 0x84, 0x7f,
-    0x98, 0xa7, 0x71, 0x00, 0x57, 0x70, 0x86, 0xc7, 0x04, 0xe4, 0x46, 0x84, 0x47, 0x7f, 0x98, 0xa7, 0x71, 0x00, 0x70, 0x20, 0x4b,
-    0x70, 0x84, 0x46, 0x40, 0x7b, 0x02, 0x18, 0x46, 0x08, 0x70, 0x10, 0x47, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x5a,
-    0x48, 0x70, 0xa0, 0x4b, 0x70, 0xb0, 0x4f, 0x00, 0xe0, 0x81, 0x02, 0x4b, 0x70, 0x2a, 0xc8, 0x08, 0x77, 0x07, 0x20, 0x4b, 0x70,
-    0x7b, 0x22, 0x82, 0xc8, 0x08, 0x70, 0x84, 0x7f, 0x98, 0xa7, 0x71, 0x00, 0xd8, 0x00, 0x70, 0x84, 0xc8, 0x04, 0x7f, 0x98, 0xa7,
-    0x71, 0x00, 0x70, 0x80, 0xc8, 0x04, 0x70, 0x80, 0x58, 0x70, 0x20, 0x4b, 0x70, 0x18, 0x47, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49,
-    0x84, 0x16, 0x41, 0xdc, 0x14, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0x2a, 0x50, 0x4f, 0x05, 0xb0, 0x01, 0x41, 0x84, 0xef, 0x4c,
-    0x05, 0x00, 0x00, 0x40, 0x84, 0x50, 0x40, 0x2a, 0xc0, 0x08, 0x4f, 0x05, 0xb0, 0x08, 0x41, 0x84, 0x41, 0x40, 0x18, 0x49, 0x08,
-    0x18, 0x49, 0x08, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x48, 0x2c, 0x5c, 0xef, 0x20, 0x03, 0x00, 0x00, 0xb8, 0x48, 0x40, 0x84, 0x40,
-    0x41, 0x7f, 0xf3, 0x84, 0x41, 0x40, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x28, 0x00,
-    0x00, 0x00, 0x4c, 0x80, 0x64, 0x70, 0x2c, 0x5c, 0xef, 0x9c, 0x03, 0x00, 0x00, 0x86, 0x7f, 0xe8, 0xec, 0x00, 0x00, 0x7f, 0x40,
-    0xb0, 0x71, 0x00, 0x70, 0xfe, 0x1c, 0x7f, 0xee, 0xec, 0x00, 0x00, 0x40, 0x86, 0x40, 0x7f, 0x42, 0xb0, 0x71, 0x00, 0x70, 0x84,
-    0x4f, 0x44, 0xb0, 0x71, 0x00, 0x41, 0x84, 0x4f, 0xec, 0xec, 0x00, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x4f, 0xf0, 0xec,
-    0x00, 0x00, 0x84, 0x4f, 0xf8, 0xec, 0x00, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef,
-    0x60, 0x02, 0x00, 0x00, 0x84, 0x40, 0xc9, 0x10, 0x70, 0xdc, 0x12, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x6c,
-    0x70, 0x7b, 0x09, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0xdc, 0x12, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x2a, 0x50, 0x77,
-    0xef, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x4f, 0xf0, 0xec, 0x00, 0x00, 0x84, 0x4f, 0xf8, 0xec, 0x00, 0x00, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0xa0, 0x5f,
-    0xe4, 0x03, 0xa0, 0x5f, 0x20, 0x03, 0xa0, 0x5f, 0x00, 0x04, 0x04, 0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf0, 0xef, 0x60, 0x03, 0x00,
-    0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xec,
-    0xef, 0x7c, 0x03, 0x00, 0x00, 0x84, 0x01, 0xef, 0x78, 0x04, 0x00, 0x00, 0x70, 0x2b, 0x7f, 0x7e, 0x06, 0x60, 0x00, 0x7f, 0x09,
-    0x2c, 0x5c, 0x7f, 0x34, 0x96, 0x00, 0x00, 0x2b, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x7f, 0x09, 0x2c, 0x5c, 0x7f, 0xac, 0x96, 0x00,
-    0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x80, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x70, 0x2c, 0x5c, 0xaf, 0x66, 0x03, 0xa0,
-    0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xef, 0x03, 0x24, 0x7f, 0x7e, 0x75, 0x00, 0x00, 0x3c, 0x01, 0x59, 0x77, 0x1b, 0xa0, 0x64, 0x2c,
-    0xcc, 0xfc, 0xaf, 0xdc, 0x03, 0x80, 0x64, 0x70, 0xa0, 0x64, 0x2c, 0xcc, 0xfc, 0xaf, 0xd1, 0x03, 0x24, 0x7f, 0x7e, 0x75, 0x00,
-    0x00, 0xfc, 0x5f, 0x81, 0x00, 0x59, 0x40, 0x84, 0x40, 0x59, 0x70, 0xdc, 0x59, 0x64, 0x40, 0xbc, 0x01, 0x40, 0xa8, 0x14, 0x40,
-    0x2b, 0x80, 0x78, 0xe5, 0x00, 0x00, 0x7f, 0x17, 0x2b, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x77, 0x0f, 0x2c, 0x5c, 0xef, 0x70, 0x03,
-    0x00, 0x00, 0x24, 0x7f, 0x78, 0x75, 0x00, 0x00, 0xdc, 0x59, 0x64, 0x40, 0xbc, 0x01, 0x40, 0xa8, 0x14, 0x40, 0x87, 0x80, 0x70,
-    0xe5, 0x00, 0x00, 0xe0, 0x40, 0x24, 0x7f, 0x65, 0x75, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x70, 0x03, 0x00, 0x00, 0x24, 0x7f, 0x78,
-    0x75, 0x00, 0x00, 0xa0, 0x64, 0x2c, 0xcc, 0xfc, 0xaf, 0x6d, 0x03, 0xdc, 0x59, 0x64, 0x40, 0xbc, 0x01, 0x40, 0xa8, 0x14, 0x40,
-    0x84, 0x80, 0x74, 0xe5, 0x00, 0x00, 0x64, 0x70, 0xa0, 0x64, 0x2c, 0xcc, 0xfc, 0xaf, 0x53, 0x03, 0x24, 0x7f, 0x78, 0x75, 0x00,
-    0x00, 0xdc, 0x59, 0x64, 0x40, 0xbc, 0x01, 0x40, 0xa8, 0x14, 0x40, 0x84, 0x80, 0x74, 0xe5, 0x00, 0x00, 0xc9, 0x1c, 0x70, 0x84,
-    0xd9, 0x1c, 0x40, 0x87, 0x50, 0xe0, 0xc9, 0x14, 0x70, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xfc, 0x01, 0x59, 0x40, 0xa8, 0x6f, 0x5c, 0x40, 0xa0, 0x40, 0xa0, 0x01, 0x04, 0xc9, 0x20,
-    0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04,
-    0xc9, 0x24, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x10, 0x02, 0x00, 0x00, 0x04, 0xc9, 0x18, 0x41, 0x84, 0x50, 0x51, 0x70, 0x3c, 0x03,
-    0x59, 0x4f, 0x07, 0x9e, 0x1b, 0xc9, 0x18, 0x70, 0x3c, 0x05, 0x59, 0x4f, 0x07, 0x9e, 0x1b, 0xc9, 0x18, 0x70, 0xdc, 0x59, 0x64,
-    0x40, 0xbc, 0x01, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x18, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x2c, 0xcc, 0xf8, 0xaf, 0x79, 0x03, 0x90, 0xc9, 0x14, 0x70, 0xdc, 0x04, 0xc9, 0x1c, 0x40, 0xd0, 0x02, 0xc9, 0x14, 0x41, 0x9c,
-    0x41, 0x40, 0x28, 0x50, 0x77, 0x06, 0x80, 0xc9, 0x14, 0x70, 0x2c, 0x5c, 0xef, 0xb0, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x48,
-    0x02, 0x00, 0x00, 0x84, 0xd9, 0x1c, 0x40, 0x87, 0xc9, 0x17, 0x50, 0x70, 0x2c, 0x5c, 0xef, 0x94, 0x03, 0x00, 0x00, 0x2c, 0x5c,
-    0xef, 0x9c, 0x03, 0x00, 0x00, 0xdc, 0x24, 0xc9, 0x1c, 0x40, 0x28, 0x50, 0x7f, 0x14, 0xdc, 0x24, 0xc9, 0x1c, 0x40, 0x84, 0x50,
-    0xc9, 0x20, 0x70, 0xa0, 0xd9, 0x1c, 0x2c, 0xcc, 0xfc, 0xd9, 0x20, 0xdc, 0x59, 0x64, 0x40, 0xbc, 0x01, 0x40, 0xa0, 0x40, 0x04,
-    0xc9, 0x18, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x02, 0x03, 0x24,
-    0x7f, 0x78, 0x75, 0x00, 0x00, 0xa0, 0x64, 0x2c, 0xcc, 0xfc, 0xaf, 0x30, 0x02, 0xdc, 0x59, 0x64, 0x40, 0xbc, 0x01, 0x40, 0xa8,
-    0x14, 0x40, 0x84, 0x80, 0x74, 0xe5, 0x00, 0x00, 0xc9, 0x20, 0x70, 0xa0, 0x59, 0x2c, 0xcc, 0xfc, 0xd9, 0x20, 0x84, 0x40, 0x68,
-    0x70, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc,
-    0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x2b, 0x7f, 0x7e, 0x06, 0x60, 0x00, 0x7f, 0x09, 0x2c, 0x5c, 0x7f, 0x34, 0x96, 0x00, 0x00,
-    0x2b, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x7f, 0x09, 0x2c, 0x5c, 0x7f, 0xac, 0x96, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00,
-    0x00, 0x2c, 0x5c, 0xaf, 0x28, 0x01, 0x28, 0x68, 0x43, 0x0c, 0xdc, 0x01, 0x68, 0x40, 0x84, 0x40, 0x59, 0x70, 0x7b, 0x57, 0x84,
-    0x68, 0x64, 0x70, 0xa0, 0x64, 0x2c, 0xcc, 0xfc, 0xaf, 0x9f, 0x01, 0x7b, 0x49, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x4f,
-    0x5a, 0xf1, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x04, 0xc9, 0x20, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00,
-    0x7b, 0x15, 0x3c, 0x03, 0x40, 0x47, 0xc9, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0xc1, 0x24, 0x90, 0x14, 0xed, 0x00, 0x00,
-    0x28, 0x59, 0x47, 0x04, 0x7b, 0x11, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0xac, 0x02, 0x84, 0x40, 0x59, 0x70, 0x46, 0x5b, 0xfd,
-    0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef,
-    0x34, 0x03, 0x00, 0x00, 0xa0, 0x4f, 0xf0, 0xec, 0x00, 0x00, 0x84, 0x4f, 0xf8, 0xec, 0x00, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0xa0, 0x5f, 0xe4, 0x03, 0xa0, 0x5f,
-    0x20, 0x03, 0xa0, 0x5f, 0x00, 0x04, 0x04, 0xc9, 0x20, 0x42, 0x2c, 0xcc, 0xf0, 0xef, 0x60, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xec, 0xef, 0x7c, 0x03, 0x00,
-    0x00, 0xdc, 0x12, 0x7f, 0x00, 0x04, 0x00, 0x00, 0x40, 0x86, 0x6e, 0x50, 0x70, 0xa0, 0xc9, 0x10, 0x2c, 0xcc, 0xfc, 0xef, 0x60,
-    0x02, 0x00, 0x00, 0x80, 0xef, 0x78, 0x04, 0x00, 0x00, 0x70, 0x2c, 0x5c, 0x7f, 0x34, 0x96, 0x00, 0x00, 0x2c, 0x5c, 0x7f, 0xac,
-    0x96, 0x00, 0x00, 0x28, 0x59, 0x77, 0x0b, 0x2c, 0x5c, 0xef, 0x5c, 0x03, 0x00, 0x00, 0x7b, 0x04, 0x30, 0xc8, 0x18, 0x49, 0x08,
-    0x70, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x10, 0x4c, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0x4f, 0x40,
-    0xb0, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0xde, 0x6f, 0x53, 0x59, 0x40, 0x86, 0x40, 0x64, 0x70, 0x86, 0x7f, 0x46, 0xb0,
-    0x71, 0x00, 0x66, 0x70, 0x80, 0x48, 0x7b, 0x4b, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03,
-    0x00, 0x00, 0x3c, 0x02, 0x48, 0x7f, 0x07, 0x3c, 0x04, 0x48, 0x77, 0x07, 0x84, 0x0d, 0x40, 0x7b, 0x05, 0x84, 0x0a, 0x40, 0xa8,
-    0x09, 0x40, 0x9c, 0x02, 0x40, 0x84, 0x40, 0x42, 0x9e, 0x40, 0x59, 0x70, 0x9e, 0x42, 0x64, 0x70, 0x90, 0x48, 0x3c, 0x08, 0x48,
-    0x4b, 0xb4, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x80, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x70, 0x18, 0x48, 0x08, 0x70, 0x70,
-    0x10, 0x47, 0x9c, 0x08, 0x4c, 0x84, 0x5a, 0x47, 0x04, 0x59, 0x41, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51,
-    0x70, 0x92, 0x62, 0x70, 0x80, 0x48, 0x24, 0x7f, 0x82, 0x77, 0x00, 0x00, 0xe8, 0x14, 0x47, 0x40, 0x2b, 0x80, 0x78, 0xe5, 0x00,
-    0x00, 0x7f, 0x0a, 0x2b, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x7f, 0x67, 0xa0, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xe8, 0x14, 0x47, 0x40, 0xa0, 0x80, 0x68, 0xe5, 0x00, 0x00, 0x2c, 0xcc, 0xf4, 0xaf, 0xa5,
-    0x00, 0xe8, 0x14, 0x47, 0x40, 0x3f, 0x02, 0x80, 0x70, 0xe5, 0x00, 0x00, 0x77, 0x1a, 0xa0, 0x47, 0x04, 0x59, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xaf, 0x52, 0x00, 0x7b, 0x22, 0xa0, 0x01, 0x04, 0x59,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xe8, 0x14, 0x47, 0x40, 0xa0, 0x80, 0x6c, 0xe5, 0x00,
-    0x00, 0x2c, 0xcc, 0xf4, 0xaf, 0x60, 0x00, 0x3c, 0x02, 0x48, 0x7f, 0x07, 0x3c, 0x04, 0x48, 0x77, 0x07, 0x84, 0x0d, 0x40, 0x7b,
-    0x05, 0x84, 0x0a, 0x40, 0xaa, 0x09, 0x40, 0x9e, 0x02, 0x40, 0x9e, 0x40, 0x59, 0x70, 0x90, 0x48, 0x90, 0x47, 0x3c, 0x08, 0x48,
-    0x4a, 0x64, 0xff, 0x18, 0x47, 0x08, 0x70, 0x10, 0x48, 0xe8, 0x14, 0x5a, 0x40, 0x84, 0x80, 0x74, 0xe5, 0x00, 0x00, 0x42, 0x84,
-    0x52, 0x40, 0x87, 0x50, 0xe0, 0x48, 0xa0, 0x01, 0xa0, 0x74, 0xdc, 0x04, 0x42, 0x40, 0xd0, 0x02, 0x48, 0x41, 0x9c, 0x41, 0x40,
-    0xa0, 0x50, 0x2c, 0xcc, 0xf4, 0xaf, 0x0b, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x48, 0x9c, 0x08, 0x4c, 0xa0, 0x78, 0x2c,
-    0xcc, 0xfc, 0xef, 0xbc, 0x03, 0x00, 0x00, 0x84, 0x40, 0x48, 0x28, 0x78, 0x7f, 0x56, 0xec, 0x02, 0x48, 0x40, 0xfc, 0x40, 0x04,
-    0x40, 0xaa, 0x09, 0x40, 0x9e, 0x01, 0x40, 0x9e, 0x40, 0x74, 0x70, 0xe8, 0x0e, 0x5a, 0x40, 0x9e, 0x40, 0x76, 0x70, 0x2c, 0x5c,
-    0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x78, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x74,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xec, 0xef,
-    0xb8, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10,
-    0x47, 0x9c, 0x10, 0x4c, 0x24, 0x7f, 0x65, 0x79, 0x00, 0x00, 0x28, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x7f, 0x1a, 0xa0, 0x5a, 0xa0,
-    0x7f, 0x40, 0xbb, 0x71, 0x00, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xaf, 0x82, 0x02, 0x80, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x70,
-    0x2c, 0x5c, 0xef, 0xd8, 0x02, 0x00, 0x00, 0x87, 0x40, 0x47, 0x3f, 0x5f, 0x82, 0x00, 0x47, 0x4b, 0x09, 0x3f, 0x5f, 0x89, 0x00,
-    0x47, 0x4f, 0x1e, 0x87, 0x47, 0xe0, 0x40, 0x3c, 0x40, 0x5f, 0x8e, 0x00, 0x7f, 0x1a, 0x3c, 0x40, 0x5f, 0xae, 0x00, 0x77, 0x08,
-    0x24, 0x7f, 0x92, 0x79, 0x00, 0x00, 0x28, 0x5a, 0x7f, 0x0e, 0x87, 0x47, 0xe0, 0x40, 0x18, 0x47, 0x08, 0x80, 0x40, 0x18, 0x47,
-    0x08, 0x2c, 0x5c, 0xef, 0x70, 0x03, 0x00, 0x00, 0x24, 0x7f, 0x65, 0x79, 0x00, 0x00, 0x18, 0x47, 0x08, 0x38, 0x48, 0x04, 0x77,
-    0x08, 0x24, 0x7f, 0x65, 0x79, 0x00, 0x00, 0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x3a, 0x50, 0x01, 0x7f, 0x08, 0x24,
-    0x7f, 0x85, 0x79, 0x00, 0x00, 0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x3a, 0x50, 0x02, 0x7f, 0x08, 0x24, 0x7f, 0xa2,
-    0x79, 0x00, 0x00, 0x84, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x39, 0xa0, 0x5a, 0x2c, 0xcc, 0xfc, 0xaf,
-    0xb0, 0x00, 0x28, 0x40, 0x7f, 0x4d, 0x28, 0x5a, 0x77, 0x0a, 0x28, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x47, 0x0e, 0x28, 0x5a, 0x7f,
-    0x3d, 0x28, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x4f, 0x0f, 0xdc, 0x5f, 0x81, 0x00, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x40, 0x18, 0x47,
-    0x08, 0x84, 0xfe, 0x40, 0x18, 0x47, 0x08, 0x28, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x7f, 0x1a, 0xa0, 0x5a, 0xa0, 0x7f, 0x40, 0xbb,
-    0x71, 0x00, 0x04, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xaf, 0x74, 0x01, 0x80, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x70, 0xa0, 0x05, 0x2c,
-    0xcc, 0xfc, 0xef, 0xe4, 0x03, 0x00, 0x00, 0x84, 0x40, 0x48, 0x38, 0x48, 0x01, 0x7e, 0x32, 0xff, 0x7a, 0xbf, 0xfe, 0xa0, 0x04,
-    0x2c, 0xcc, 0xfc, 0xef, 0xe4, 0x03, 0x00, 0x00, 0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x3a, 0x50, 0x01, 0x77, 0xeb,
-    0x84, 0xff, 0x40, 0x18, 0x47, 0x08, 0xa0, 0x04, 0x2c, 0xcc, 0xfc, 0xef, 0xe4, 0x03, 0x00, 0x00, 0xdc, 0x08, 0x7f, 0x34, 0x04,
-    0x00, 0x00, 0x40, 0x3a, 0x50, 0x02, 0x77, 0xeb, 0x28, 0x5a, 0x7f, 0x08, 0x84, 0xfd, 0x40, 0x18, 0x47, 0x08, 0x84, 0x01, 0x40,
-    0x18, 0x47, 0x08, 0x70, 0x10, 0x46, 0x9c, 0x1c, 0x4c, 0x80, 0x46, 0x28, 0x5a, 0x7f, 0x0e, 0x04, 0x64, 0x41, 0x84, 0x4f, 0x44,
-    0xbb, 0x71, 0x00, 0x40, 0x7b, 0x0c, 0x04, 0x64, 0x41, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0xde,
-    0x6f, 0x53, 0x64, 0x40, 0x86, 0x40, 0x68, 0x70, 0x86, 0x7f, 0x46, 0xb0, 0x71, 0x00, 0x6a, 0x70, 0x80, 0x48, 0x7b, 0x62, 0x18,
-    0x46, 0x08, 0x84, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04,
-    0x64, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc,
-    0xf4, 0xef, 0x30, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x08, 0xdc, 0x01, 0x48, 0x46, 0x7b, 0x2c, 0x3c, 0x02, 0x48, 0x7f, 0x07,
-    0x3c, 0x04, 0x48, 0x77, 0x07, 0x84, 0x0d, 0x40, 0x7b, 0x05, 0x84, 0x0a, 0x40, 0xa8, 0x09, 0x40, 0x9c, 0x02, 0x40, 0x84, 0x40,
-    0x47, 0x9e, 0x40, 0x64, 0x70, 0x9e, 0x47, 0x68, 0x70, 0x90, 0x48, 0x3c, 0x08, 0x48, 0x4b, 0xa0, 0x3c, 0x46, 0x7f, 0x40, 0xbb,
-    0x71, 0x00, 0x7f, 0x36, 0x28, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x7f, 0x14, 0xa0, 0x5a, 0xa0, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x04,
-    0xc9, 0x14, 0x42, 0x2c, 0xcc, 0xf8, 0xaf, 0x50, 0x00, 0x28, 0x46, 0x7f, 0x10, 0xa0, 0x5a, 0xa0, 0x46, 0x04, 0xc9, 0x14, 0x42,
-    0x2c, 0xcc, 0xf8, 0xaf, 0x3e, 0x00, 0x84, 0x46, 0x7f, 0x40, 0xbb, 0x71, 0x00, 0x70, 0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00, 0x00,
-    0x40, 0x3a, 0x50, 0x04, 0x77, 0x11, 0x80, 0x40, 0x18, 0x46, 0x08, 0xa0, 0x04, 0x2c, 0xcc, 0xfc, 0xef, 0xe4, 0x03, 0x00, 0x00,
-    0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x3a, 0x50, 0x04, 0x77, 0xeb, 0x84, 0x01, 0x40, 0x18, 0x46, 0x08, 0x70, 0x10,
-    0x49, 0x9c, 0x14, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x3c, 0x09, 0x74, 0x77, 0x18, 0x04, 0x64, 0x41, 0x84, 0x4f, 0x48, 0xb0, 0x71,
-    0x00, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x7b, 0x4d, 0x28, 0x5a, 0x7f, 0x0e, 0x04, 0x64, 0x41,
-    0x84, 0x4f, 0x44, 0xbb, 0x71, 0x00, 0x40, 0x7b, 0x0c, 0x04, 0x64, 0x41, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xfc, 0x01, 0x74, 0x40, 0xaa, 0x6f, 0x5c, 0x40, 0x9e, 0x40, 0x64, 0x70, 0x3c,
-    0x03, 0x74, 0x4f, 0x06, 0x9e, 0x1b, 0x64, 0x70, 0x3c, 0x05, 0x74, 0x4f, 0x06, 0x9e, 0x1b, 0x64, 0x70, 0xde, 0x6f, 0x53, 0x64,
-    0x40, 0x86, 0x40, 0x68, 0x70, 0x04, 0x64, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x04, 0x6c, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0xac, 0x02, 0x00, 0x00, 0x04, 0x64, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00,
-    0x00, 0x04, 0x64, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x84, 0x59, 0x41, 0x04,
-    0x64, 0x40, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x49, 0x2b, 0xda, 0x00, 0x7f, 0x0d, 0xb8, 0x6f, 0xef, 0xef, 0x24, 0x04, 0x00, 0x00, 0x70, 0x7b, 0x0a, 0xb0, 0x10, 0xef,
-    0x24, 0x04, 0x00, 0x00, 0x70, 0xa0, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xf8, 0x7f, 0x18, 0x90, 0x00, 0x00, 0x28, 0x40, 0x7f, 0xf2,
-    0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x87, 0x7f, 0x02, 0x00, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0x0c, 0xed, 0x00, 0x00, 0xe0,
-    0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x08, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x2b, 0x7f, 0x16, 0x00,
-    0x60, 0x00, 0x7f, 0x0d, 0x87, 0x02, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x18, 0x49, 0x08, 0x87, 0x02, 0x7f, 0x3b, 0x00, 0x20,
-    0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0xb0, 0x03, 0x00, 0x00, 0x84, 0x40, 0x42, 0xff, 0x7f,
-    0xb2, 0x06, 0x60, 0x00, 0x01, 0x40, 0xff, 0x7f, 0xda, 0x06, 0x60, 0x00, 0x01, 0x41, 0xd0, 0x04, 0x41, 0x41, 0xb0, 0x41, 0x40,
-    0xd0, 0x03, 0x40, 0x40, 0xb3, 0x10, 0x40, 0xb3, 0x01, 0x40, 0x87, 0x40, 0x7f, 0x23, 0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x0b,
-    0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x09, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x5f,
-    0xc9, 0x00, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x02, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x0b, 0x00, 0x30,
-    0x00, 0x70, 0x87, 0x0f, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0f, 0x7f, 0x03,
-    0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0a, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x83, 0x7f,
-    0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0a, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x83, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87,
-    0x04, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xaa, 0x06, 0x60, 0x00, 0xe0, 0x40, 0xf3, 0x5f, 0x84, 0x00, 0x80, 0xa4,
-    0xf1, 0x00, 0x00, 0x40, 0x87, 0x40, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x04, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87,
-    0x7f, 0xd2, 0x06, 0x60, 0x00, 0xe0, 0x40, 0xf3, 0x5f, 0x84, 0x00, 0x80, 0xa4, 0xf1, 0x00, 0x00, 0x40, 0x87, 0x40, 0x7f, 0x03,
-    0x00, 0x30, 0x00, 0x70, 0x87, 0x0b, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x6f, 0x50, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70,
-    0x87, 0x0b, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x6f, 0x50, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0c, 0x7f, 0x0b,
-    0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xa6, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0x98, 0xf1, 0x00, 0x00, 0x7f, 0x0b, 0x00,
-    0x30, 0x00, 0x70, 0x87, 0x0d, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xa6, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80,
-    0x90, 0xf1, 0x00, 0x00, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0c, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xce,
-    0x06, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0x98, 0xf1, 0x00, 0x00, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0d, 0x7f, 0x03,
-    0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xce, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0x90, 0xf1, 0x00, 0x00, 0x7f, 0x03, 0x00,
-    0x30, 0x00, 0x70, 0x87, 0x0e, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x01, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x0e,
-    0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x01, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x01, 0x7f, 0x0b, 0x00, 0x30, 0x00,
-    0x70, 0x87, 0x16, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x01, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x16, 0x7f, 0x03,
-    0x00, 0x30, 0x00, 0x70, 0x87, 0x03, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xae, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x87,
-    0x80, 0xa0, 0xf1, 0x00, 0x00, 0xe0, 0x40, 0xd0, 0x06, 0x40, 0x40, 0xb3, 0x01, 0x40, 0x87, 0x40, 0x7f, 0x0b, 0x00, 0x30, 0x00,
-    0x70, 0x87, 0x03, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xd6, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0xa0, 0xf1,
-    0x00, 0x00, 0xe0, 0x40, 0xd0, 0x06, 0x40, 0x40, 0xb3, 0x01, 0x40, 0x87, 0x40, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x05,
-    0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0xae, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0xa0, 0xf1, 0x00, 0x00, 0xe0,
-    0x40, 0xd0, 0x05, 0x40, 0x40, 0xb3, 0x5f, 0x80, 0x00, 0x40, 0xb3, 0x08, 0x40, 0xb3, 0x02, 0x40, 0x87, 0x40, 0x7f, 0x4c, 0xbb,
-    0x71, 0x00, 0x70, 0x87, 0x40, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87, 0x05, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f,
-    0xd6, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x87, 0x80, 0xa0, 0xf1, 0x00, 0x00, 0xe0, 0x40, 0xd0, 0x05, 0x40, 0x40, 0xb3, 0x5f, 0x80,
-    0x00, 0x40, 0xb3, 0x08, 0x40, 0xb3, 0x02, 0x40, 0x87, 0x40, 0x7f, 0x4d, 0xbb, 0x71, 0x00, 0x70, 0x87, 0x40, 0x7f, 0x03, 0x00,
-    0x30, 0x00, 0x70, 0xa0, 0x42, 0x2c, 0xcc, 0xfc, 0xef, 0xb4, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x2b, 0x7f,
-    0x50, 0xbb, 0x71, 0x00, 0x77, 0x2e, 0xa0, 0x4f, 0x4e, 0xbb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xe4, 0x83, 0x00, 0x00, 0x28,
-    0x40, 0x77, 0x1c, 0xdc, 0x3c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x38, 0x03, 0x00, 0x00,
-    0x87, 0x40, 0x7f, 0x0f, 0x00, 0x30, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x2b, 0x7f, 0x51, 0xbb, 0x71, 0x00, 0x77,
-    0x2f, 0xa0, 0x4f, 0x4f, 0xbb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0x7f, 0xe4, 0x83, 0x00, 0x00, 0x28, 0x40, 0x77, 0x1d, 0xdc, 0x6f,
-    0x54, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x38, 0x03, 0x00, 0x00, 0x87, 0x40, 0x7f, 0x07,
-    0x00, 0x30, 0x00, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49, 0x3b, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x01, 0x7f, 0xf9, 0x87, 0x7f, 0x0f,
-    0x00, 0x30, 0x00, 0xe0, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x3b, 0x7f, 0x03, 0x00, 0x30,
-    0x00, 0x01, 0x7f, 0xf9, 0x87, 0x7f, 0x07, 0x00, 0x30, 0x00, 0xe0, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x49, 0x3b, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x04, 0x7f, 0xf9, 0x87, 0x73, 0x7f, 0x0f, 0x00, 0x30, 0x00, 0x70, 0x18, 0x49,
-    0x08, 0x70, 0x70, 0x10, 0x49, 0x3b, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x04, 0x7f, 0xf9, 0x87, 0x73, 0x7f, 0x07, 0x00, 0x30, 0x00,
-    0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0xb0, 0x03, 0x00, 0x00, 0x84, 0x40, 0x41, 0xfc, 0x03, 0x5a,
-    0x40, 0x4b, 0x5e, 0x3c, 0x03, 0x40, 0x47, 0x59, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x51, 0x24, 0x90, 0xa8, 0xf1, 0x00,
-    0x00, 0x87, 0x05, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0xf3, 0x10, 0x7f, 0x4c, 0xbb, 0x71, 0x00, 0x40, 0x87, 0x40, 0x7f, 0x0b,
-    0x00, 0x30, 0x00, 0x70, 0x7b, 0x31, 0x87, 0x05, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0xfb, 0x6f, 0x7f, 0x7f, 0x4c, 0xbb, 0x71,
-    0x00, 0x40, 0x87, 0x40, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x7b, 0x16, 0x87, 0x05, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x87,
-    0x7f, 0x4c, 0xbb, 0x71, 0x00, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0xa0, 0x41, 0x2c, 0xcc, 0xfc, 0xef, 0xb4, 0x03, 0x00, 0x00,
-    0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0xb0, 0x03, 0x00, 0x00, 0x84, 0x40, 0x41, 0xfc, 0x03, 0x5a, 0x40, 0x4b,
-    0x5e, 0x3c, 0x03, 0x40, 0x47, 0x59, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x51, 0x24, 0x90, 0xb8, 0xf1, 0x00, 0x00, 0x87,
-    0x05, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0xf3, 0x10, 0x7f, 0x4d, 0xbb, 0x71, 0x00, 0x40, 0x87, 0x40, 0x7f, 0x03, 0x00, 0x30,
-    0x00, 0x70, 0x7b, 0x31, 0x87, 0x05, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0xfb, 0x6f, 0x7f, 0x7f, 0x4d, 0xbb, 0x71, 0x00, 0x40,
-    0x87, 0x40, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x7b, 0x16, 0x87, 0x05, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x87, 0x7f, 0x4d,
-    0xbb, 0x71, 0x00, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0xa0, 0x41, 0x2c, 0xcc, 0xfc, 0xef, 0xb4, 0x03, 0x00, 0x00, 0x18, 0x49,
-    0x08, 0x70, 0x10, 0x47, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x2b, 0x7f, 0xf2, 0x06, 0x60, 0x00, 0x77, 0x09, 0x2c, 0x5c,
-    0xef, 0x78, 0x02, 0x00, 0x00, 0x87, 0x7f, 0x13, 0x00, 0x30, 0x00, 0xe0, 0x47, 0x70, 0x84, 0x47, 0x40, 0x24, 0x7f, 0xe9, 0x82,
-    0x00, 0x00, 0x2b, 0x7f, 0x51, 0xbb, 0x71, 0x00, 0x77, 0x1d, 0xdc, 0x6f, 0x54, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xfc, 0xef, 0x38, 0x03, 0x00, 0x00, 0x84, 0x40, 0x48, 0x3c, 0xff, 0x48, 0x77, 0x13, 0x87, 0x28, 0x7f, 0x03, 0x00,
-    0x30, 0x00, 0x70, 0x83, 0x7f, 0x4f, 0xbb, 0x71, 0x00, 0x70, 0x7b, 0x11, 0xf8, 0x5f, 0xff, 0x00, 0x48, 0xe3, 0x40, 0x87, 0x40,
-    0x7f, 0x07, 0x00, 0x30, 0x00, 0x70, 0x24, 0x7f, 0xfc, 0x82, 0x00, 0x00, 0x87, 0x10, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x24,
-    0x7f, 0xfc, 0x82, 0x00, 0x00, 0x87, 0x7f, 0x07, 0x00, 0x30, 0x00, 0xe0, 0x48, 0x3c, 0x13, 0x48, 0x77, 0x31, 0x2b, 0x7f, 0x02,
-    0x07, 0x60, 0x00, 0x7f, 0x29, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x7f, 0x13, 0x2b, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x77, 0x0b,
-    0x3f, 0x02, 0x7f, 0xe2, 0x06, 0x60, 0x00, 0x7f, 0x10, 0x87, 0x01, 0x7f, 0x51, 0xbb, 0x71, 0x00, 0x70, 0x24, 0x7f, 0xfc, 0x82,
-    0x00, 0x00, 0x3c, 0x11, 0x48, 0x77, 0x1c, 0x2b, 0x7f, 0x51, 0xbb, 0x71, 0x00, 0x7f, 0x14, 0x83, 0x7f, 0x51, 0xbb, 0x71, 0x00,
-    0x70, 0x87, 0x01, 0x7f, 0x4f, 0xbb, 0x71, 0x00, 0x70, 0x7a, 0x51, 0xff, 0xdc, 0x6f, 0x48, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40,
-    0xa0, 0x40, 0xa0, 0x48, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x3c, 0x5f, 0x5e, 0x01, 0x40, 0x77, 0x35, 0x2b, 0x7f,
-    0x82, 0x06, 0x60, 0x00, 0x77, 0x2d, 0x2b, 0x7f, 0xf6, 0x06, 0x60, 0x00, 0x77, 0x25, 0x28, 0xef, 0xf4, 0x03, 0x00, 0x00, 0x77,
-    0x1d, 0x3f, 0x02, 0x7f, 0xe2, 0x06, 0x60, 0x00, 0x77, 0x14, 0xa0, 0x13, 0x2c, 0xcc, 0xfc, 0xef, 0x8c, 0x03, 0x00, 0x00, 0x84,
-    0xff, 0xef, 0xf4, 0x03, 0x00, 0x00, 0x70, 0x3f, 0x03, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x77, 0x15, 0x3f, 0x02, 0x7f, 0xe2, 0x06,
-    0x60, 0x00, 0x77, 0x0c, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x98, 0xd9, 0x71, 0x00, 0x24, 0x7f, 0xfc, 0x82, 0x00, 0x00, 0x2b,
-    0x7f, 0x50, 0xbb, 0x71, 0x00, 0x77, 0x1c, 0xdc, 0x3c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef,
-    0x38, 0x03, 0x00, 0x00, 0x84, 0x40, 0x48, 0x3c, 0xff, 0x48, 0x77, 0x13, 0x87, 0x28, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x83,
-    0x7f, 0x4e, 0xbb, 0x71, 0x00, 0x70, 0x7b, 0x11, 0xf8, 0x5f, 0xff, 0x00, 0x48, 0xe3, 0x40, 0x87, 0x40, 0x7f, 0x0f, 0x00, 0x30,
-    0x00, 0x70, 0x24, 0x7f, 0xfc, 0x82, 0x00, 0x00, 0x87, 0x10, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x24, 0x7f, 0xfc, 0x82, 0x00,
-    0x00, 0x87, 0x7f, 0x0f, 0x00, 0x30, 0x00, 0xe0, 0x48, 0x3c, 0x13, 0x48, 0x77, 0x31, 0x2b, 0x7f, 0xfe, 0x06, 0x60, 0x00, 0x7f,
-    0x29, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x7f, 0x13, 0x2b, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x77, 0x0b, 0x3f, 0x01, 0x7f, 0xe2,
-    0x06, 0x60, 0x00, 0x7f, 0x10, 0x87, 0x01, 0x7f, 0x50, 0xbb, 0x71, 0x00, 0x70, 0x24, 0x7f, 0xfc, 0x82, 0x00, 0x00, 0x3c, 0x11,
-    0x48, 0x77, 0x1c, 0x2b, 0x7f, 0x50, 0xbb, 0x71, 0x00, 0x7f, 0x14, 0x83, 0x7f, 0x50, 0xbb, 0x71, 0x00, 0x70, 0x87, 0x01, 0x7f,
-    0x4e, 0xbb, 0x71, 0x00, 0x70, 0x7a, 0x52, 0xff, 0xdc, 0x30, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0xa0, 0x48, 0x2c,
-    0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x3c, 0x5f, 0x5e, 0x01, 0x40, 0x77, 0x35, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x77,
-    0x2d, 0x2b, 0x7f, 0xf6, 0x06, 0x60, 0x00, 0x77, 0x25, 0x28, 0xef, 0xf4, 0x03, 0x00, 0x00, 0x77, 0x1d, 0x3f, 0x01, 0x7f, 0xe2,
-    0x06, 0x60, 0x00, 0x77, 0x14, 0xa0, 0x13, 0x2c, 0xcc, 0xfc, 0xef, 0x8c, 0x03, 0x00, 0x00, 0x84, 0xff, 0xef, 0xf4, 0x03, 0x00,
-    0x00, 0x70, 0x3f, 0x03, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x77, 0x15, 0x3f, 0x01, 0x7f, 0xe2, 0x06, 0x60, 0x00, 0x77, 0x0c, 0xa0,
-    0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x98, 0xd9, 0x71, 0x00, 0x7b, 0x1c, 0x2c, 0x5c, 0xaf, 0x42, 0xf9, 0x7b, 0x15, 0x3c, 0x0e, 0x40,
-    0x47, 0xf6, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0xee, 0x24, 0x90, 0xc8, 0xf1, 0x00, 0x00, 0x3c, 0x08, 0x47, 0x43, 0x0c,
-    0x87, 0x38, 0x7f, 0x03, 0x00, 0x30, 0x00, 0x70, 0x7b, 0x0a, 0x87, 0x38, 0x7f, 0x0b, 0x00, 0x30, 0x00, 0x70, 0x30, 0xc8, 0x18,
-    0x47, 0x08, 0x10, 0x49, 0x87, 0x22, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x38, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x87,
-    0x6f, 0x40, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x10, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x7f, 0x8a, 0x06, 0x60,
-    0x00, 0xe0, 0x40, 0xf3, 0x00, 0x80, 0x04, 0xf2, 0x00, 0x00, 0x40, 0x87, 0x7f, 0x8e, 0x06, 0x60, 0x00, 0xe0, 0x41, 0xb3, 0x81,
-    0x08, 0xf2, 0x00, 0x00, 0x40, 0x87, 0x40, 0x7f, 0x03, 0x00, 0x20, 0x00, 0x70, 0x87, 0x07, 0x7f, 0x03, 0x00, 0x20, 0x00, 0x70,
-    0x87, 0x73, 0x7f, 0x07, 0x00, 0x20, 0x00, 0x70, 0x87, 0x6f, 0x70, 0x7f, 0x37, 0x00, 0x20, 0x00, 0x70, 0x87, 0x5f, 0x8f, 0x00,
-    0x7f, 0x13, 0x00, 0x20, 0x00, 0x70, 0x87, 0x01, 0x7f, 0x3b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x05, 0x7f, 0x0b, 0x00, 0x20, 0x00,
-    0x70, 0x2b, 0x7f, 0x16, 0x00, 0x60, 0x00, 0x7f, 0x0c, 0x87, 0x02, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x7b, 0x0a, 0x87, 0x02,
-    0x7f, 0x3b, 0x00, 0x20, 0x00, 0x70, 0x84, 0x01, 0x7f, 0x90, 0xa7, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49,
-    0x2b, 0x7f, 0x53, 0xbb, 0x71, 0x00, 0x77, 0x1a, 0xa0, 0x4f, 0x52, 0xbb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x18, 0x00, 0x28,
-    0x40, 0x77, 0x0a, 0x87, 0x04, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x4f,
-    0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x01, 0x40, 0x1f, 0xda, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x48, 0x83, 0x7f, 0x0b,
-    0x00, 0x20, 0x00, 0x70, 0x87, 0x7f, 0x0f, 0x00, 0x20, 0x00, 0xe0, 0x48, 0x3c, 0x13, 0x48, 0x77, 0x2d, 0x2b, 0x7f, 0xfa, 0x06,
-    0x60, 0x00, 0x7f, 0x25, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x7f, 0x12, 0x2b, 0x7f, 0xc6, 0x06, 0x60, 0x00, 0x77, 0x0a, 0x2b,
-    0x7f, 0xe2, 0x06, 0x60, 0x00, 0x7f, 0x0d, 0x87, 0x01, 0x7f, 0x53, 0xbb, 0x71, 0x00, 0x70, 0x18, 0x48, 0x08, 0x3c, 0x11, 0x48,
-    0x77, 0x31, 0x2b, 0x7f, 0x53, 0xbb, 0x71, 0x00, 0x7f, 0x29, 0x83, 0x7f, 0x53, 0xbb, 0x71, 0x00, 0x70, 0x2b, 0x7f, 0x53, 0xbb,
-    0x71, 0x00, 0x77, 0x1a, 0xa0, 0x4f, 0x52, 0xbb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x89, 0xff, 0x28, 0x40, 0x77, 0x0a, 0x87,
-    0x04, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0xa0, 0x7f, 0x48, 0x04, 0x00, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03,
-    0x00, 0x00, 0x3c, 0x5f, 0x9c, 0x01, 0x40, 0x77, 0x34, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x77, 0x2c, 0x2b, 0x7f, 0xf6, 0x06,
-    0x60, 0x00, 0x77, 0x24, 0x28, 0xef, 0xf4, 0x03, 0x00, 0x00, 0x77, 0x1c, 0x2b, 0x7f, 0xe2, 0x06, 0x60, 0x00, 0x77, 0x14, 0xa0,
-    0x13, 0x2c, 0xcc, 0xfc, 0xef, 0x8c, 0x03, 0x00, 0x00, 0x84, 0xff, 0xef, 0xf4, 0x03, 0x00, 0x00, 0x70, 0x3f, 0x03, 0x7f, 0x82,
-    0x06, 0x60, 0x00, 0x77, 0x14, 0x2b, 0x7f, 0xe2, 0x06, 0x60, 0x00, 0x77, 0x0c, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x98, 0xd9,
-    0x71, 0x00, 0x18, 0x48, 0x08, 0x10, 0x49, 0x83, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x3b, 0x7f, 0x07, 0x00, 0x20, 0x00, 0x04,
-    0x7f, 0x4d, 0x2b, 0x7f, 0x53, 0xbb, 0x71, 0x00, 0x77, 0x24, 0xdc, 0x18, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c,
-    0xcc, 0xfc, 0xef, 0x38, 0x03, 0x00, 0x00, 0x84, 0x40, 0x41, 0x3c, 0xff, 0x41, 0x7f, 0x0a, 0x28, 0x7f, 0x90, 0xa7, 0x71, 0x00,
-    0x77, 0x14, 0x87, 0x08, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x83, 0x7f, 0x52, 0xbb, 0x71, 0x00, 0x70, 0x18, 0x49, 0x08, 0xf8,
-    0x5f, 0xff, 0x00, 0x41, 0xe3, 0x40, 0x87, 0x40, 0x7f, 0x0f, 0x00, 0x20, 0x00, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49, 0x84, 0x5a,
-    0x7f, 0x90, 0xa7, 0x71, 0x00, 0x70, 0x83, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x3b, 0x7f, 0x07, 0x00, 0x20, 0x00, 0x04, 0x7f,
-    0x4c, 0x2b, 0x7f, 0x53, 0xbb, 0x71, 0x00, 0x77, 0x24, 0xdc, 0x18, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc,
-    0xfc, 0xef, 0x38, 0x03, 0x00, 0x00, 0x84, 0x40, 0x41, 0x3c, 0xff, 0x41, 0x7f, 0x0a, 0x28, 0x7f, 0x90, 0xa7, 0x71, 0x00, 0x77,
-    0x13, 0x87, 0x08, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x83, 0x7f, 0x52, 0xbb, 0x71, 0x00, 0x70, 0x7b, 0x11, 0xf8, 0x5f, 0xff,
-    0x00, 0x41, 0xe3, 0x40, 0x87, 0x40, 0x7f, 0x0f, 0x00, 0x20, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x83, 0x7f, 0x0b,
-    0x00, 0x20, 0x00, 0x70, 0x3b, 0x7f, 0x37, 0x00, 0x20, 0x00, 0x6f, 0x40, 0x7f, 0x08, 0x84, 0x01, 0x40, 0x18, 0x49, 0x08, 0x80,
-    0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x87, 0x04, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x18,
-    0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x87, 0x08, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x49, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x2c, 0x5c, 0xef, 0xa8, 0x03, 0x00, 0x00, 0x84, 0x40, 0x41, 0xfc,
-    0x03, 0x5a, 0x40, 0x4b, 0x60, 0x3c, 0x03, 0x40, 0x47, 0x5b, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x53, 0x24, 0x90, 0x0c,
-    0xf2, 0x00, 0x00, 0x87, 0x30, 0x7f, 0x37, 0x00, 0x20, 0x00, 0x70, 0x87, 0x6f, 0x40, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x87,
-    0x6f, 0x64, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x7b, 0x31, 0x87, 0x01, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x7b, 0x27, 0x87,
-    0x6f, 0x78, 0x7f, 0x0b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x6f, 0x70, 0x7f, 0x37, 0x00, 0x20, 0x00, 0x70, 0x87, 0x6f, 0x40, 0x7f,
-    0x3b, 0x00, 0x20, 0x00, 0x70, 0x7b, 0x0a, 0x87, 0x01, 0x7f, 0x3b, 0x00, 0x20, 0x00, 0x70, 0xa0, 0x41, 0x2c, 0xcc, 0xfc, 0xef,
-    0xb4, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x6f, 0x70, 0x4c, 0x84, 0x5a, 0x43, 0x04, 0x59,
-    0x41, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x5f, 0x20, 0x03, 0xa0, 0x0e, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03,
-    0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef,
-    0x10, 0x02, 0x00, 0x00, 0x04, 0x64, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0xa0, 0x0e, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c,
-    0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf8,
-    0xef, 0x10, 0x02, 0x00, 0x00, 0x84, 0x4f, 0x44, 0xbb, 0x71, 0x00, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x48, 0xbb, 0x71,
-    0x00, 0x41, 0x84, 0x4f, 0x44, 0xb0, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x80, 0x47, 0xfc, 0x01, 0x43, 0x40, 0xa8, 0x5f,
-    0xcc, 0x00, 0x40, 0x9c, 0x4f, 0x1a, 0x00, 0x60, 0x00, 0x40, 0x84, 0x40, 0x46, 0x24, 0x7f, 0xd2, 0x88, 0x00, 0x00, 0x2b, 0x7f,
-    0x54, 0xbb, 0x71, 0x00, 0x77, 0x08, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00, 0x97, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0x24, 0x7f,
-    0x9d, 0x8c, 0x00, 0x00, 0x93, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0x87, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x40, 0x3c, 0x47,
-    0x40, 0x47, 0x11, 0x3f, 0x31, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x47, 0x08, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00, 0x97, 0x7f, 0x54,
-    0xbb, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00, 0x88, 0x7f, 0x58, 0xbb, 0x71, 0x00, 0x40, 0x84, 0x40, 0x7f, 0x58,
-    0xbb, 0x71, 0x00, 0x70, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00, 0x28, 0x47, 0x77, 0x08, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00, 0x87,
-    0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x40, 0x3c, 0x47, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00, 0xa0, 0x7f, 0x08,
-    0x04, 0x00, 0x00, 0x04, 0xc9, 0x20, 0x40, 0x87, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0xa0, 0x40, 0xa0,
-    0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03,
-    0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x87, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x48, 0x24,
-    0x7f, 0xa6, 0x8d, 0x00, 0x00, 0x28, 0x44, 0x77, 0x48, 0x90, 0x44, 0xa0, 0x00, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x38, 0xf2, 0x00, 0x00, 0x2c, 0xcc, 0xf4, 0x7f, 0xbc, 0x77, 0x00, 0x00,
-    0xa0, 0x00, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x40, 0xf2,
-    0x00, 0x00, 0x2c, 0xcc, 0xf4, 0x7f, 0xbc, 0x77, 0x00, 0x00, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00, 0xa0, 0x7f, 0x08, 0x04, 0x00,
-    0x00, 0xe0, 0xc9, 0x20, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x86, 0x6c, 0xc9, 0x10,
-    0x70, 0x80, 0x47, 0x83, 0xc9, 0x20, 0x70, 0x83, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0xa0, 0x00, 0x04, 0xc9, 0x14, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x40, 0xf2, 0x00, 0x00, 0x2c, 0xcc, 0xf4, 0x7f, 0xbc,
-    0x77, 0x00, 0x00, 0xa0, 0x00, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0,
-    0x7f, 0x38, 0xf2, 0x00, 0x00, 0x2c, 0xcc, 0xf4, 0x7f, 0xbc, 0x77, 0x00, 0x00, 0x94, 0x44, 0x24, 0x7f, 0x9d, 0x8c, 0x00, 0x00,
-    0x18, 0x43, 0x08, 0x04, 0xc9, 0x20, 0x40, 0x9c, 0x47, 0x40, 0x87, 0x56, 0x50, 0x70, 0x90, 0x47, 0x9c, 0x04, 0x46, 0x3c, 0x32,
-    0x47, 0x43, 0x06, 0x2b, 0x56, 0x77, 0xe9, 0x84, 0x47, 0x48, 0x7b, 0x0e, 0x04, 0xc9, 0x20, 0x40, 0x9c, 0x48, 0x40, 0x83, 0x50,
-    0x70, 0x90, 0x48, 0x3c, 0x33, 0x48, 0x4b, 0xf1, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00,
-    0x84, 0x4f, 0x40, 0xb0, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x2b, 0x7f, 0x7e, 0x06, 0x60, 0x00, 0x7f, 0x09,
-    0x2c, 0x5c, 0x7f, 0x34, 0x96, 0x00, 0x00, 0x2b, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x7f, 0x09, 0x2c, 0x5c, 0x7f, 0xac, 0x96, 0x00,
-    0x00, 0x2c, 0x5c, 0x7f, 0x38, 0x76, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03,
-    0x00, 0x00, 0x87, 0x6f, 0x66, 0xc9, 0x18, 0x70, 0xdf, 0x30, 0x43, 0x40, 0x87, 0x40, 0xc9, 0x19, 0x70, 0x87, 0x3a, 0xc9, 0x1a,
-    0x70, 0x83, 0xc9, 0x1b, 0x70, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xe0, 0xc9, 0x18, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04,
-    0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc,
-    0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x04, 0x68, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xe0, 0xc9,
-    0x20, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x03, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x04, 0xc9, 0x10, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0x6c, 0x41, 0x04, 0x68, 0x40, 0x84, 0x50, 0x51, 0x70, 0x9e, 0x0e, 0xc9, 0x12, 0x70, 0x04, 0x68, 0x41, 0x84, 0x4f,
-    0x44, 0xbb, 0x71, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x80, 0x48, 0x7b, 0x42, 0xa0, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xd0, 0x02, 0x48, 0x40, 0xa0, 0x80, 0x1c, 0xf2, 0x00, 0x00, 0x2c, 0xcc, 0xf4,
-    0x7f, 0xbc, 0x77, 0x00, 0x00, 0x3c, 0x02, 0x48, 0x7f, 0x07, 0x3c, 0x04, 0x48, 0x77, 0x07, 0x84, 0x0d, 0x40, 0x7b, 0x05, 0x84,
-    0x0a, 0x40, 0xaa, 0x09, 0x40, 0x9e, 0x02, 0x40, 0x9e, 0x40, 0x68, 0x70, 0x90, 0x48, 0x3c, 0x08, 0x48, 0x4b, 0xbd, 0x86, 0x6a,
-    0xc9, 0x16, 0x70, 0xfe, 0x6f, 0x5c, 0x68, 0x40, 0x86, 0x40, 0xc9, 0x14, 0x70, 0x83, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0x80,
-    0x7f, 0x58, 0xbb, 0x71, 0x00, 0x70, 0x80, 0x44, 0x04, 0x68, 0x41, 0x04, 0x6c, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0x68, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xaf, 0xe3, 0x03, 0x2c, 0x5c, 0xef, 0x4c,
-    0x02, 0x00, 0x00, 0x24, 0x7f, 0xcc, 0x8c, 0x00, 0x00, 0x28, 0x44, 0x7f, 0x49, 0x3c, 0x5f, 0x89, 0x00, 0x45, 0x7f, 0x42, 0x80,
-    0x44, 0xa0, 0x00, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x40,
-    0xf2, 0x00, 0x00, 0x2c, 0xcc, 0xf4, 0x7f, 0xbc, 0x77, 0x00, 0x00, 0xa0, 0x00, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x38, 0xf2, 0x00, 0x00, 0x2c, 0xcc, 0xf4, 0x7f, 0xbc, 0x77, 0x00, 0x00,
-    0x3c, 0xfe, 0x45, 0x7f, 0x08, 0x24, 0x7f, 0xac, 0x8b, 0x00, 0x00, 0x84, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03, 0x00, 0x00, 0x28, 0x40, 0x77, 0x08, 0x24, 0x7f,
-    0xcc, 0x8c, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc,
-    0xaf, 0x34, 0x03, 0x3e, 0x6c, 0xef, 0x34, 0x04, 0x00, 0x00, 0x43, 0x0b, 0x83, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0x7b, 0x15,
-    0xfe, 0x6c, 0xef, 0x34, 0x04, 0x00, 0x00, 0x40, 0xac, 0x09, 0x40, 0x87, 0x40, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0x87, 0x7f,
-    0x54, 0xbb, 0x71, 0x00, 0xe0, 0x40, 0x3c, 0x47, 0x40, 0x4f, 0x0a, 0x87, 0x47, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0x3f, 0x32,
-    0x7f, 0x54, 0xbb, 0x71, 0x00, 0x4b, 0x0a, 0x87, 0x31, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0xeb, 0x09, 0x7f, 0x54, 0xbb, 0x71,
-    0x00, 0x40, 0x9e, 0x6c, 0x40, 0x86, 0x40, 0x68, 0x70, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xaf, 0xc6, 0x02, 0x24, 0x7f, 0xcc, 0x8c, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xaf, 0xac, 0x02, 0xfc, 0x5f, 0x85, 0x00, 0x45, 0x40, 0x4b, 0x15,
-    0x3c, 0x04, 0x40, 0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0x44, 0xf2, 0x00, 0x00, 0x28, 0x7f,
-    0x58, 0xbb, 0x71, 0x00, 0x7f, 0x44, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0x04, 0xc9, 0x20, 0x40, 0x87, 0x7f, 0x54, 0xbb, 0x71,
-    0x00, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0xa0, 0x40, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00,
-    0x84, 0x31, 0x48, 0x24, 0x7f, 0x01, 0x8e, 0x00, 0x00, 0x87, 0x45, 0xc9, 0x1a, 0x70, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xe0,
-    0xc9, 0x1a, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x00, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x04, 0xc9, 0x20, 0x40, 0x87, 0x7f,
-    0x54, 0xbb, 0x71, 0x00, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0x87, 0x45, 0x50, 0x70, 0x87, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x40,
-    0x3c, 0x47, 0x40, 0x77, 0x13, 0x3c, 0x32, 0x47, 0x43, 0x0e, 0x90, 0x47, 0x04, 0xc9, 0x20, 0x40, 0x9c, 0x47, 0x40, 0x83, 0x50,
-    0x70, 0x93, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x70, 0x3f, 0x31, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0x4f, 0x09, 0x97, 0x7f, 0x54, 0xbb,
-    0x71, 0x00, 0x70, 0xe8, 0x09, 0x47, 0x40, 0x9e, 0x6c, 0x40, 0x86, 0x40, 0xc9, 0x10, 0x70, 0xeb, 0x09, 0x7f, 0x54, 0xbb, 0x71,
-    0x00, 0x40, 0x9e, 0x6c, 0x40, 0x86, 0x40, 0x68, 0x70, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xaf, 0xa0, 0x01, 0xa0, 0x01, 0x2c, 0xcc, 0xfc, 0x7f, 0x2c, 0x78, 0x00, 0x00, 0x84, 0x40, 0x45,
-    0x7f, 0x22, 0x3c, 0xff, 0x45, 0x7f, 0x1d, 0x3c, 0xfd, 0x45, 0x7f, 0x18, 0x3c, 0x5f, 0x82, 0x00, 0x45, 0x7f, 0x11, 0x3c, 0x5f,
-    0x83, 0x00, 0x45, 0x7f, 0x0a, 0x3c, 0x5f, 0x84, 0x00, 0x45, 0x76, 0x9e, 0xfd, 0x2c, 0x5c, 0xef, 0xb0, 0x03, 0x00, 0x00, 0x2c,
-    0x5c, 0xef, 0x48, 0x02, 0x00, 0x00, 0x80, 0x48, 0xfc, 0x01, 0x43, 0x40, 0xa8, 0x5f, 0xcc, 0x00, 0x40, 0x9c, 0x4f, 0x1a, 0x00,
-    0x60, 0x00, 0x40, 0x84, 0x40, 0x46, 0x7b, 0x12, 0x04, 0xc9, 0x20, 0x40, 0x9c, 0x48, 0x40, 0x87, 0x50, 0x56, 0x70, 0x90, 0x48,
-    0x9c, 0x04, 0x46, 0x3c, 0x47, 0x48, 0x4f, 0xed, 0x2c, 0x5c, 0xef, 0x94, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x9c, 0x03, 0x00,
-    0x00, 0x3c, 0x5f, 0x83, 0x00, 0x45, 0x77, 0x0e, 0x3c, 0x08, 0x43, 0x77, 0x07, 0x84, 0x01, 0x43, 0x7b, 0x04, 0x90, 0x43, 0x3c,
-    0x5f, 0x84, 0x00, 0x45, 0x77, 0x0e, 0x3c, 0x01, 0x43, 0x77, 0x07, 0x84, 0x08, 0x43, 0x7b, 0x04, 0x94, 0x43, 0x3c, 0x5f, 0x84,
-    0x00, 0x45, 0x7e, 0x03, 0xf9, 0x3c, 0x5f, 0x83, 0x00, 0x45, 0x7e, 0xfb, 0xf8, 0x3c, 0xfd, 0x45, 0x7f, 0x0d, 0x28, 0x45, 0x47,
-    0x09, 0xfc, 0x01, 0x45, 0x40, 0x18, 0x43, 0x08, 0x80, 0x40, 0x18, 0x43, 0x08, 0x04, 0xc9, 0x20, 0x40, 0x9c, 0x48, 0x40, 0xdc,
-    0x01, 0x48, 0x41, 0x04, 0xc9, 0x20, 0x42, 0x9c, 0x42, 0x41, 0x87, 0x51, 0x50, 0x70, 0x90, 0x48, 0x3c, 0x47, 0x48, 0x4b, 0xe5,
-    0x94, 0x47, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0x04, 0xc9, 0x20, 0x40, 0x87, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x41, 0x9c,
-    0x41, 0x40, 0xa0, 0x40, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x7a, 0xb7, 0xfe, 0x04,
-    0xc9, 0x20, 0x40, 0x9c, 0x48, 0x40, 0x04, 0xc9, 0x20, 0x41, 0xfc, 0x01, 0x48, 0x42, 0x9c, 0x42, 0x41, 0x87, 0x51, 0x50, 0x70,
-    0x94, 0x48, 0x87, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x40, 0x3c, 0x40, 0x48, 0x47, 0xdd, 0x04, 0xc9, 0x20, 0x40, 0x87, 0x7f,
-    0x54, 0xbb, 0x71, 0x00, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0x87, 0x45, 0x50, 0x70, 0x3c, 0x32, 0x47, 0x43, 0x04, 0x90, 0x47, 0xa0,
-    0x7f, 0x08, 0x04, 0x00, 0x00, 0x04, 0xc9, 0x20, 0x40, 0x87, 0x7f, 0x54, 0xbb, 0x71, 0x00, 0xe0, 0x41, 0x9c, 0x41, 0x40, 0xa0,
-    0x40, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x03, 0x04, 0xc9, 0x68, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x7a, 0x25, 0xfe, 0x70, 0x70, 0x10, 0x49,
-    0x9c, 0x08, 0x4c, 0x28, 0x7f, 0x58, 0xbb, 0x71, 0x00, 0x7f, 0x61, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x5a, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0xa0, 0x00, 0xa0, 0x0e, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x10, 0x02, 0x00, 0x00, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x84, 0x03, 0x00, 0x00, 0x18, 0x49,
-    0x08, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x4f, 0x58, 0xf2, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x5a,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xec, 0xef,
-    0xb8, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xfc, 0xef, 0x38, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x87, 0x2a, 0x7f,
-    0x2b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x30, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x6f, 0x40, 0x7f, 0x2b, 0x00, 0x20, 0x00,
-    0x70, 0x87, 0x10, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x03, 0x7f, 0x23, 0x00, 0x20, 0x00, 0x70, 0x87, 0x07, 0x7f, 0x23,
-    0x00, 0x20, 0x00, 0x70, 0x87, 0x5f, 0x99, 0x00, 0x7f, 0x27, 0x00, 0x20, 0x00, 0x70, 0x87, 0x15, 0x7f, 0x2b, 0x00, 0x20, 0x00,
-    0x70, 0x87, 0x5f, 0x80, 0x00, 0x7f, 0x3b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x08, 0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x80, 0x7f,
-    0x5c, 0xbb, 0x71, 0x00, 0x70, 0x2b, 0x7f, 0x12, 0x00, 0x60, 0x00, 0x7f, 0x0b, 0x80, 0xef, 0x24, 0x04, 0x00, 0x00, 0x70, 0x7b,
-    0x0a, 0x84, 0x10, 0xef, 0x24, 0x04, 0x00, 0x00, 0x70, 0xdc, 0x03, 0x7f, 0x24, 0x04, 0x00, 0x00, 0x40, 0xf3, 0x02, 0x50, 0x40,
-    0x87, 0x40, 0x7f, 0x2f, 0x00, 0x20, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0xb0, 0x08, 0xef, 0x24, 0x04, 0x00,
-    0x00, 0x70, 0xa0, 0x00, 0xa0, 0x00, 0x2c, 0xcc, 0xf8, 0xaf, 0x5a, 0x00, 0x28, 0x40, 0x7f, 0xf4, 0xb8, 0xf7, 0xef, 0x24, 0x04,
-    0x00, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0xa0, 0x00, 0x2c, 0xcc, 0xfc, 0xaf, 0x98, 0x00, 0x28, 0x40, 0x7f, 0xf6,
-    0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x7b, 0x10, 0xa0, 0x48, 0xa0, 0x01, 0x2c, 0xcc, 0xf8, 0xaf, 0x28, 0x00, 0x28,
-    0x40, 0x7f, 0xf4, 0xdc, 0x24, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x38, 0x03, 0x00, 0x00,
-    0x84, 0x40, 0x48, 0x3c, 0xff, 0x48, 0x77, 0xda, 0x18, 0x48, 0x08, 0x70, 0x10, 0x45, 0x84, 0x5a, 0x48, 0x84, 0x74, 0x47, 0x84,
-    0x00, 0x46, 0x2c, 0x5c, 0xef, 0xb0, 0x03, 0x00, 0x00, 0x84, 0x40, 0x45, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xaf, 0x41, 0x00, 0x28,
-    0x40, 0x7f, 0x24, 0x28, 0x47, 0x77, 0x14, 0xdc, 0x03, 0x7f, 0x24, 0x04, 0x00, 0x00, 0x40, 0x87, 0x50, 0x7f, 0x2f, 0x00, 0x20,
-    0x00, 0x70, 0x7b, 0x0b, 0x84, 0x48, 0xe7, 0x7f, 0x2f, 0x00, 0x20, 0x00, 0x70, 0x84, 0x01, 0x46, 0xa0, 0x45, 0x2c, 0xcc, 0xfc,
-    0xef, 0xb4, 0x03, 0x00, 0x00, 0x84, 0x46, 0x40, 0x18, 0x45, 0x08, 0x18, 0x45, 0x08, 0x70, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x41,
-    0x83, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0x3c, 0x41, 0x7f, 0x5c, 0xbb, 0x71, 0x00, 0x77, 0x11, 0x3b, 0x7f, 0x27, 0x00, 0x20,
-    0x00, 0x04, 0x7f, 0x4b, 0x84, 0x01, 0x40, 0x18, 0x49, 0x08, 0x3b, 0x7f, 0x27, 0x00, 0x20, 0x00, 0x08, 0x7f, 0x3c, 0x28, 0x41,
-    0x7f, 0x3d, 0x87, 0x08, 0x7f, 0x3b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x7f, 0x96, 0x06, 0x60, 0x00, 0xe0, 0x40, 0x9c, 0x7f, 0x64,
-    0x04, 0x00, 0x00, 0x40, 0xfb, 0x0f, 0x50, 0x40, 0xb3, 0x5f, 0x90, 0x00, 0x40, 0x87, 0x40, 0x7f, 0x27, 0x00, 0x20, 0x00, 0x70,
-    0x84, 0x41, 0x7f, 0x5c, 0xbb, 0x71, 0x00, 0x70, 0x84, 0x01, 0x40, 0x18, 0x49, 0x08, 0x80, 0x40, 0x18, 0x49, 0x08, 0x87, 0x08,
-    0x7f, 0x3f, 0x00, 0x20, 0x00, 0x70, 0x87, 0x5f, 0x99, 0x00, 0x7f, 0x27, 0x00, 0x20, 0x00, 0x70, 0x7b, 0xdb, 0x18, 0x49, 0x08,
-    0x70, 0x10, 0x46, 0x9c, 0x4f, 0x08, 0x00, 0x00, 0x00, 0x4c, 0x83, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x7f, 0x27, 0x00,
-    0x20, 0x00, 0x64, 0x70, 0x83, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x7f, 0x2f, 0x00, 0x20, 0x00, 0x65, 0x70, 0x3b, 0x64,
-    0x6f, 0x50, 0x7f, 0x11, 0x87, 0x6f, 0x40, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0x24, 0x7f, 0x1a, 0x94, 0x00, 0x00, 0x3b, 0x64,
-    0x20, 0x7f, 0x48, 0xa0, 0x4f, 0x7e, 0x06, 0x60, 0x00, 0x3b, 0x65, 0x04, 0x7f, 0x06, 0x80, 0x40, 0x7b, 0x05, 0x84, 0x01, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x70, 0x05, 0x00, 0x00, 0x2c, 0x5c, 0xaf, 0xe3, 0x04, 0x3b, 0x65, 0x10, 0x7f, 0x12, 0x80,
-    0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x80, 0x7f, 0x60, 0xbb, 0x71, 0x00, 0x70, 0x7b, 0x0c, 0x84, 0x5f, 0x00, 0x01, 0xef, 0x20,
-    0x04, 0x00, 0x00, 0x70, 0x24, 0x7f, 0x1a, 0x94, 0x00, 0x00, 0x80, 0x7f, 0x60, 0xbb, 0x71, 0x00, 0x70, 0x3b, 0x65, 0x5f, 0x80,
-    0x00, 0x77, 0x08, 0x24, 0x7f, 0xd9, 0x93, 0x00, 0x00, 0xfb, 0x5f, 0xff, 0x00, 0x65, 0x40, 0x24, 0x7f, 0xa7, 0x92, 0x00, 0x00,
-    0x38, 0xef, 0x20, 0x04, 0x00, 0x00, 0x5f, 0x00, 0x01, 0x7f, 0x0f, 0x80, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x24, 0x7f, 0x1a,
-    0x94, 0x00, 0x00, 0x3f, 0x01, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x77, 0x14, 0xa0, 0x4f, 0x7a, 0x06, 0x60, 0x00, 0xa0, 0x00, 0x2c,
-    0xcc, 0xf8, 0xef, 0x70, 0x05, 0x00, 0x00, 0x7b, 0x1a, 0x2b, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x77, 0x12, 0xa0, 0x4f, 0x7a, 0x06,
-    0x60, 0x00, 0xa0, 0x01, 0x2c, 0xcc, 0xf8, 0xef, 0x70, 0x05, 0x00, 0x00, 0x2c, 0x5c, 0xaf, 0xc6, 0x04, 0x24, 0x7f, 0x1a, 0x94,
-    0x00, 0x00, 0x38, 0xef, 0x20, 0x04, 0x00, 0x00, 0x5f, 0x00, 0x01, 0x7f, 0x0f, 0x80, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x24,
-    0x7f, 0x1a, 0x94, 0x00, 0x00, 0x28, 0xef, 0x78, 0x04, 0x00, 0x00, 0x7f, 0x20, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40,
-    0xa0, 0x40, 0x87, 0x65, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x24, 0x7f, 0x1a, 0x94, 0x00,
-    0x00, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x77, 0x09, 0x2c, 0x5c, 0xef, 0xc8, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x5c, 0x03,
-    0x00, 0x00, 0x38, 0xef, 0x20, 0x04, 0x00, 0x00, 0x5f, 0x00, 0x01, 0x7f, 0x0f, 0x80, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x24,
-    0x7f, 0x1a, 0x94, 0x00, 0x00, 0x28, 0xef, 0x78, 0x04, 0x00, 0x00, 0x7f, 0x20, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40,
-    0xa0, 0x40, 0x87, 0x65, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x24, 0x7f, 0x1a, 0x94, 0x00,
-    0x00, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x34, 0x03, 0x00, 0x00, 0x9c, 0x04,
-    0x4e, 0x04, 0x7f, 0x94, 0x90, 0x71, 0x00, 0xce, 0xfc, 0x70, 0x24, 0x7f, 0x1a, 0x94, 0x00, 0x00, 0x3c, 0x40, 0x5f, 0x8e, 0x00,
-    0x7e, 0x45, 0xff, 0x3c, 0x40, 0x5f, 0xae, 0x00, 0x7f, 0x91, 0x3c, 0x40, 0x5f, 0xb2, 0x00, 0x7e, 0xe0, 0xfe, 0x3c, 0x40, 0x5f,
-    0xf2, 0x00, 0x7e, 0xd8, 0xfe, 0x2b, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x7f, 0x63, 0x3f, 0x5f, 0xc0, 0x00, 0x65, 0x4b, 0x5c, 0x3f,
-    0x5f, 0xc6, 0x00, 0x65, 0x47, 0x55, 0x87, 0x65, 0xe0, 0x40, 0x87, 0x80, 0x6c, 0xf2, 0x00, 0x00, 0x65, 0x70, 0xdc, 0x0c, 0x7f,
-    0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x87, 0x65, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00,
-    0xf8, 0x5f, 0x00, 0x07, 0xef, 0x20, 0x04, 0x00, 0x00, 0x40, 0x3c, 0x5f, 0x00, 0x01, 0x40, 0x77, 0x1a, 0x84, 0x5f, 0x00, 0x03,
-    0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0xfb, 0x5f, 0xff, 0x00, 0x65, 0x40, 0x9c, 0x40, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x24,
-    0x7f, 0x1a, 0x94, 0x00, 0x00, 0x87, 0x65, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xaf, 0xb3, 0x02, 0x84, 0x40, 0x48, 0xd0,
-    0x01, 0x48, 0x40, 0x3f, 0x80, 0x9c, 0xf2, 0x00, 0x00, 0x65, 0x7f, 0x08, 0x24, 0x7f, 0xd0, 0x93, 0x00, 0x00, 0xd0, 0x01, 0x48,
-    0x40, 0xfb, 0x5f, 0xff, 0x00, 0x80, 0x9d, 0xf2, 0x00, 0x00, 0x40, 0x87, 0x40, 0x65, 0x70, 0x3f, 0x5f, 0x80, 0x00, 0x65, 0x4b,
-    0x37, 0x3f, 0x5f, 0x89, 0x00, 0x65, 0x47, 0x30, 0x38, 0xef, 0x20, 0x04, 0x00, 0x00, 0x5f, 0x00, 0x01, 0x7f, 0x0b, 0x80, 0xef,
-    0x20, 0x04, 0x00, 0x00, 0x70, 0x7b, 0x1a, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x87, 0x65, 0xe0, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x7b, 0x7d, 0x87, 0x65, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc,
-    0xaf, 0x7b, 0x00, 0xf8, 0x5f, 0x00, 0x07, 0xef, 0x20, 0x04, 0x00, 0x00, 0x40, 0x3c, 0x5f, 0x00, 0x01, 0x40, 0x77, 0x14, 0x84,
-    0x5f, 0x00, 0x07, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x9c, 0x48, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x7b, 0x09, 0x2c, 0x5c,
-    0xef, 0x70, 0x03, 0x00, 0x00, 0x7b, 0x43, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x87, 0x65, 0xe0, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0xf8, 0x5f, 0x00, 0x07, 0xef, 0x20, 0x04, 0x00, 0x00, 0x40, 0x3c,
-    0x5f, 0x00, 0x01, 0x40, 0x77, 0x1a, 0x84, 0x5f, 0x00, 0x03, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0xfb, 0x5f, 0xff, 0x00, 0x65,
-    0x40, 0x9c, 0x40, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x48, 0x87, 0x73, 0x48, 0xfb,
-    0x5f, 0xff, 0x00, 0x48, 0x40, 0x3c, 0x5f, 0xc2, 0x00, 0x40, 0x77, 0x08, 0x24, 0x7f, 0x1c, 0x95, 0x00, 0x00, 0x4f, 0x08, 0x24,
-    0x7f, 0x03, 0x95, 0x00, 0x00, 0x3c, 0x5f, 0x8d, 0x00, 0x40, 0x7f, 0x61, 0x4f, 0x08, 0x24, 0x7f, 0xfa, 0x94, 0x00, 0x00, 0x3c,
-    0x5f, 0x8b, 0x00, 0x40, 0x7f, 0x2c, 0x47, 0x67, 0x3c, 0x5f, 0x8a, 0x00, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x50, 0x95, 0x00, 0x00,
-    0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0xa0, 0x4f, 0xd4, 0xf3, 0x00, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0x44,
-    0x03, 0x00, 0x00, 0x18, 0x48, 0x08, 0x38, 0xef, 0x20, 0x04, 0x00, 0x00, 0x5f, 0x00, 0x01, 0x77, 0x4c, 0xdc, 0x0c, 0x7f, 0x48,
-    0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0xa0, 0x4f, 0xdc, 0xf3, 0x00, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0x44, 0x03, 0x00, 0x00, 0x18,
-    0x48, 0x08, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0xa0, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00,
-    0x00, 0x18, 0x48, 0x08, 0x3c, 0x5f, 0x8c, 0x00, 0x40, 0x7f, 0x08, 0x24, 0x7f, 0x50, 0x95, 0x00, 0x00, 0x38, 0xef, 0x20, 0x04,
-    0x00, 0x00, 0x5f, 0x00, 0x01, 0x7f, 0x0c, 0x80, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x18, 0x48, 0x08, 0xdc, 0x0c, 0x7f, 0x48,
-    0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x87, 0x48, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x18,
-    0x48, 0x08, 0x3c, 0x5f, 0xc1, 0x00, 0x40, 0x7f, 0x1d, 0x7b, 0x4f, 0x3c, 0x5f, 0xc4, 0x00, 0x40, 0x7f, 0x14, 0x47, 0x0b, 0x3c,
-    0x5f, 0xc3, 0x00, 0x40, 0x7f, 0x0b, 0x7b, 0x3d, 0x3c, 0x5f, 0xc8, 0x00, 0x40, 0x77, 0x36, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00,
-    0x00, 0x40, 0xa0, 0x40, 0xa0, 0x4f, 0xe1, 0xf3, 0x00, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0x44, 0x03, 0x00, 0x00, 0xdc, 0x0c, 0x7f,
-    0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0xfb, 0x6f, 0x7f, 0x48, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00,
-    0x00, 0x18, 0x48, 0x08, 0x2b, 0x48, 0x7f, 0x1a, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x87, 0x48, 0xe0,
-    0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x18, 0x48, 0x08, 0x70, 0x10, 0x49, 0x38, 0xef, 0x20, 0x04,
-    0x00, 0x00, 0x5f, 0x00, 0x04, 0x7f, 0x4c, 0xf8, 0x5f, 0xff, 0x00, 0xef, 0x20, 0x04, 0x00, 0x00, 0x40, 0xd0, 0x01, 0x40, 0x40,
-    0x87, 0x80, 0x9d, 0xf2, 0x00, 0x00, 0x41, 0x3b, 0x41, 0x5f, 0x80, 0x00, 0x7f, 0x11, 0x87, 0x41, 0xe0, 0x40, 0xa0, 0x40, 0x2c,
-    0xcc, 0xfc, 0xaf, 0x81, 0xfe, 0x18, 0x49, 0x08, 0x2b, 0x41, 0x7f, 0x1a, 0xdc, 0x0c, 0x7f, 0x48, 0x04, 0x00, 0x00, 0x40, 0xa0,
-    0x40, 0x87, 0x41, 0xe0, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0xdc, 0x0c, 0x7f,
-    0x48, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0xf8, 0x5f, 0xff, 0x00, 0xef, 0x20, 0x04, 0x00, 0x00, 0x40, 0xa0, 0x40, 0x2c, 0xcc,
-    0xf8, 0xef, 0x40, 0x03, 0x00, 0x00, 0x7b, 0xdf, 0x70, 0x10, 0x47, 0x87, 0x73, 0x47, 0x84, 0x00, 0x41, 0x84, 0x6f, 0x48, 0x42,
-    0x7b, 0x1c, 0x18, 0x47, 0x08, 0xd0, 0x01, 0x48, 0x40, 0x3f, 0x80, 0x9c, 0xf2, 0x00, 0x00, 0x47, 0x5f, 0x08, 0xdc, 0x01, 0x48,
-    0x41, 0x7b, 0x06, 0xfc, 0x01, 0x48, 0x42, 0xdc, 0x42, 0x41, 0x40, 0xac, 0x02, 0x40, 0x70, 0x84, 0x40, 0x48, 0xd0, 0x01, 0x48,
-    0x40, 0x3f, 0x47, 0x80, 0x9c, 0xf2, 0x00, 0x00, 0x7f, 0x07, 0x3c, 0x42, 0x41, 0x4f, 0xce, 0x84, 0x48, 0x40, 0x18, 0x47, 0x08,
-    0x70, 0x10, 0x49, 0x2b, 0x7f, 0x82, 0x06, 0x60, 0x00, 0x7f, 0x0a, 0x28, 0xef, 0x78, 0x04, 0x00, 0x00, 0x7f, 0x4b, 0x2c, 0x5c,
-    0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x4f, 0xb8, 0xf3, 0x00, 0x00, 0x84, 0x4f, 0xc0, 0xf3, 0x00, 0x00, 0x40, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84,
-    0x4f, 0xcc, 0xf3, 0x00, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2b, 0x7f, 0x7e, 0x06,
-    0x60, 0x00, 0x7f, 0x09, 0x80, 0x40, 0x7b, 0x08, 0x18, 0x49, 0x08, 0x84, 0x02, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8, 0xef, 0x38,
-    0x02, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x2b, 0x7f, 0x82,
-    0x06, 0x60, 0x00, 0x7f, 0x0a, 0x28, 0xef, 0x78, 0x04, 0x00, 0x00, 0x7f, 0x4b, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0,
-    0x4f, 0xa4, 0xf3, 0x00, 0x00, 0x84, 0x4f, 0xac, 0xf3, 0x00, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0xd0, 0xf3, 0x00, 0x00, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2b, 0x7f, 0x7a, 0x06, 0x60, 0x00, 0x7f, 0x09, 0x80, 0x40,
-    0x7b, 0x08, 0x18, 0x49, 0x08, 0x84, 0x02, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0x2c, 0x5c, 0xef,
-    0x4c, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x83, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0xfb, 0x5f,
-    0xf1, 0x00, 0x7f, 0x27, 0x00, 0x20, 0x00, 0x40, 0x87, 0x40, 0x42, 0x7f, 0xec, 0x3b, 0x42, 0x01, 0x77, 0x18, 0x7b, 0x10, 0x80,
-    0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x80, 0x7f, 0x60, 0xbb, 0x71, 0x00, 0x70, 0x84, 0xff, 0x40, 0x18, 0x46, 0x08, 0x83, 0x7f,
-    0x2b, 0x00, 0x20, 0x00, 0x70, 0x87, 0x7f, 0x2f, 0x00, 0x20, 0x00, 0xe0, 0x41, 0x3b, 0x42, 0x5f, 0xf0, 0x00, 0x77, 0x20, 0x38,
-    0xef, 0x20, 0x04, 0x00, 0x00, 0x5f, 0x00, 0x01, 0x7f, 0x0f, 0x80, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x84, 0xff, 0x40, 0x18,
-    0x46, 0x08, 0x84, 0x41, 0x40, 0x18, 0x46, 0x08, 0x3b, 0x42, 0x20, 0x7f, 0x18, 0x3b, 0x42, 0x01, 0x7f, 0x13, 0x38, 0x41, 0x10,
-    0x77, 0xab, 0x84, 0x5f, 0x00, 0x01, 0xef, 0x20, 0x04, 0x00, 0x00, 0x70, 0x7b, 0xad, 0x3b, 0x42, 0x10, 0x7f, 0xa8, 0x87, 0x6f,
-    0x40, 0x7f, 0x2b, 0x00, 0x20, 0x00, 0x70, 0x7b, 0x9d, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x87, 0x7f, 0x86, 0x06,
-    0x60, 0x00, 0xe0, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x30, 0x04, 0x00, 0x00, 0x40, 0xfc, 0x04, 0x50, 0x40, 0x84, 0x40,
-    0xef, 0x14, 0x04, 0x00, 0x00, 0x70, 0x84, 0xef, 0x14, 0x04, 0x00, 0x00, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x70, 0x18, 0x49, 0x08,
-    0x10, 0x47, 0x84, 0x5a, 0x48, 0x84, 0x00, 0x47, 0x38, 0x78, 0x01, 0x77, 0x39, 0x9c, 0x03, 0x48, 0xd4, 0x02, 0x48, 0x48, 0x9c,
-    0x04, 0x48, 0x7b, 0x37, 0x18, 0x47, 0x08, 0x84, 0x48, 0xef, 0x40, 0x04, 0x00, 0x00, 0x70, 0xfc, 0xef, 0xec, 0x03, 0x00, 0x00,
-    0xef, 0x3c, 0x04, 0x00, 0x00, 0x40, 0xac, 0x04, 0x40, 0x84, 0x40, 0xef, 0x38, 0x04, 0x00, 0x00, 0x70, 0x84, 0x47, 0x40, 0x90,
-    0x47, 0x28, 0x40, 0x7f, 0x07, 0x80, 0x40, 0x18, 0x47, 0x08, 0x2c, 0x5c, 0xaf, 0xdb, 0x00, 0xfc, 0xef, 0xec, 0x03, 0x00, 0x00,
-    0xef, 0x3c, 0x04, 0x00, 0x00, 0x40, 0xac, 0x04, 0x40, 0x3c, 0x48, 0x40, 0x5b, 0xbc, 0xfc, 0x0c, 0xef, 0x3c, 0x04, 0x00, 0x00,
-    0x41, 0x84, 0x78, 0xc1, 0x04, 0x70, 0x84, 0x74, 0x51, 0x70, 0xfc, 0x04, 0x48, 0x40, 0x84, 0x40, 0xc1, 0x08, 0x70, 0xd0, 0x02,
-    0x48, 0x40, 0xbc, 0x40, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x70, 0x84, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x40, 0x84, 0x41, 0x50, 0x70,
-    0xdc, 0x04, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x40, 0x84, 0x40, 0xd1, 0x00, 0x70, 0x18, 0x47, 0x08, 0x70, 0x10, 0x49, 0xa0, 0x5a,
-    0xa0, 0x74, 0xa0, 0x00, 0x2c, 0xcc, 0xf4, 0xef, 0x58, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10,
-    0x49, 0x84, 0x5a, 0x40, 0x3c, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x40, 0x5f, 0x25, 0x3c, 0xef, 0x14, 0x04, 0x00, 0x00, 0x40, 0x53,
-    0x1c, 0x84, 0xc0, 0xfc, 0x40, 0x3c, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x40, 0x5f, 0x0f, 0x3c, 0xef, 0x14, 0x04, 0x00, 0x00, 0x40,
-    0x53, 0x06, 0xb0, 0x01, 0x50, 0x70, 0x18, 0x49, 0x08, 0x10, 0x49, 0x84, 0x5a, 0x42, 0x84, 0xef, 0x14, 0x04, 0x00, 0x00, 0x41,
-    0x7b, 0x1b, 0xbc, 0x0c, 0x41, 0x3c, 0x42, 0xc1, 0x04, 0x77, 0x06, 0xb0, 0x01, 0x51, 0x70, 0xdc, 0x01, 0xc1, 0x08, 0x40, 0xd0,
-    0x02, 0x40, 0x40, 0xbc, 0x40, 0x41, 0x3c, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x41, 0x57, 0xe0, 0x18, 0x49, 0x08, 0x70, 0x10, 0x46,
-    0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0xe4, 0xf3, 0x00, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x84, 0xef,
-    0x14, 0x04, 0x00, 0x00, 0x42, 0x70, 0x84, 0x42, 0x48, 0x7b, 0x67, 0xfc, 0x0c, 0x48, 0x40, 0x84, 0x40, 0x47, 0x38, 0x57, 0x01,
-    0x7f, 0x10, 0xdc, 0x04, 0xc7, 0x08, 0x40, 0xd0, 0x02, 0x40, 0x40, 0xbc, 0x40, 0x48, 0x7b, 0x4d, 0xbc, 0x04, 0x42, 0x84, 0xc7,
-    0x08, 0x46, 0x84, 0x46, 0x52, 0x70, 0x84, 0xc7, 0x04, 0xc2, 0xfc, 0x70, 0x84, 0x57, 0xc2, 0xf8, 0x70, 0xbc, 0x04, 0x42, 0xbc,
-    0x04, 0x42, 0xbc, 0x0c, 0x48, 0x84, 0x42, 0x47, 0xd0, 0x02, 0x46, 0x40, 0xfc, 0x40, 0x42, 0xd2, 0x00, 0x70, 0x28, 0x46, 0x7f,
-    0x12, 0x84, 0xc8, 0xfc, 0xc2, 0xfc, 0x70, 0xbc, 0x04, 0x42, 0xbc, 0x04, 0x48, 0x94, 0x46, 0x77, 0xf2, 0xbc, 0x04, 0x48, 0x84,
-    0x47, 0xc2, 0xfc, 0x70, 0xbc, 0x04, 0x42, 0x3c, 0xef, 0x3c, 0x04, 0x00, 0x00, 0x48, 0x57, 0x94, 0x84, 0x42, 0xef, 0x3c, 0x04,
-    0x00, 0x00, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0xe4, 0xf3, 0x00, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00,
-    0x00, 0x18, 0x46, 0x08, 0x10, 0x49, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x74, 0x2c, 0xcc, 0xf4, 0xef, 0x28, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x48,
-    0x9c, 0x08, 0x4c, 0x84, 0x5a, 0x48, 0x84, 0x42, 0x59, 0x70, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x48, 0xa0, 0x7f, 0x0c,
-    0x04, 0x00, 0x00, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x03, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x40, 0x84, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18,
-    0x48, 0x08, 0x10, 0x49, 0xd0, 0x02, 0x5a, 0x40, 0x9c, 0x7f, 0x90, 0xbb, 0x71, 0x00, 0x40, 0x84, 0x50, 0x40, 0x18, 0x49, 0x08,
-    0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x5f, 0x9c, 0x00, 0x4c, 0x84, 0x5a, 0x48, 0x84, 0x58, 0x7f, 0x90, 0xbb, 0x71,
-    0x00, 0x70, 0x7f, 0x10, 0x84, 0x4f, 0x64, 0x9a, 0x00, 0x00, 0x7f, 0x94, 0xbb, 0x71, 0x00, 0x70, 0x7b, 0x0b, 0x84, 0xc8, 0x08,
-    0x7f, 0x94, 0xbb, 0x71, 0x00, 0x70, 0x84, 0x7f, 0x94, 0xbb, 0x71, 0x00, 0x40, 0x04, 0x6c, 0x41, 0x84, 0x7f, 0x34, 0x04, 0x00,
-    0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x80, 0x64, 0x70, 0x80, 0x7f, 0x68, 0xbb, 0x71, 0x00, 0x70, 0x7b, 0x2b, 0x18, 0x43, 0x08,
-    0xa0, 0x7f, 0x68, 0xbb, 0x71, 0x00, 0xa0, 0xc9, 0x1c, 0x2c, 0xcc, 0xfc, 0xef, 0xbc, 0x03, 0x00, 0x00, 0xa0, 0x40, 0x2c, 0xcc,
-    0xf8, 0x7f, 0xf8, 0xa7, 0x00, 0x00, 0x84, 0x40, 0x7f, 0x68, 0xbb, 0x71, 0x00, 0x70, 0x90, 0x64, 0x70, 0xa0, 0x64, 0xa0, 0x7f,
-    0x90, 0xbb, 0x71, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0x94, 0xbb, 0x71, 0x00, 0x84, 0x40, 0xc9, 0x1c, 0x70, 0x77, 0xc5, 0x28, 0x64,
-    0x77, 0x08, 0x24, 0x7f, 0xf3, 0xa3, 0x00, 0x00, 0xe8, 0x09, 0x7f, 0x68, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x0a, 0x40, 0x84, 0x40,
-    0x7f, 0x6c, 0xbb, 0x71, 0x00, 0x70, 0x87, 0x00, 0xe0, 0x40, 0x86, 0x40, 0x7f, 0x72, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x40, 0x7f,
-    0x70, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x40, 0x7f, 0x80, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x40, 0x7f, 0x7c, 0xbb, 0x71, 0x00, 0x70,
-    0x86, 0x40, 0x7f, 0x7a, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x40, 0x7f, 0x78, 0xbb, 0x71, 0x00, 0x70, 0x3c, 0x10, 0x64, 0x47, 0x08,
-    0x84, 0x64, 0x68, 0x70, 0x7b, 0x2e, 0x84, 0x10, 0x68, 0x70, 0x86, 0x12, 0x7f, 0x7c, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x7f, 0x7c,
-    0xbb, 0x71, 0x00, 0x7f, 0x80, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x01, 0x7f, 0x7a, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x7f, 0x7a, 0xbb,
-    0x71, 0x00, 0x7f, 0x78, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x02, 0x7f, 0x82, 0xbb, 0x71, 0x00, 0x70, 0x84, 0x4f, 0x80, 0xbb, 0x71,
-    0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x6c, 0xbb, 0x71, 0x00, 0xa0, 0x64,
-    0xa0, 0x68, 0x2c, 0xcc, 0xf8, 0x7f, 0xe8, 0xa7, 0x00, 0x00, 0xa8, 0x0e, 0x40, 0xa0, 0x40, 0x04, 0xa9, 0x8c, 0x00, 0x42, 0x2c,
-    0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xa9, 0x90,
-    0x00, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x10, 0x02, 0x00, 0x00, 0x84, 0x4f, 0x84, 0xbb, 0x71, 0x00, 0x41, 0x84, 0x50, 0x51, 0x70,
-    0x84, 0x4f, 0x84, 0xbb, 0x71, 0x00, 0x40, 0x84, 0x4f, 0x74, 0xbb, 0x71, 0x00, 0x41, 0x84, 0x50, 0x51, 0x70, 0xfe, 0x01, 0x7f,
-    0x76, 0xbb, 0x71, 0x00, 0x40, 0x86, 0x40, 0x7f, 0x7e, 0xbb, 0x71, 0x00, 0x70, 0xfc, 0x68, 0x64, 0x40, 0xa0, 0x40, 0xa0, 0x00,
-    0x86, 0xc8, 0x06, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xf8, 0xa7, 0x00, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f,
-    0xe8, 0xa7, 0x00, 0x00, 0x84, 0x40, 0x44, 0xfc, 0x01, 0x68, 0x40, 0xa0, 0x40, 0xa0, 0x00, 0x86, 0xc8, 0x04, 0xe4, 0x40, 0xa0,
-    0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xf8, 0xa7, 0x00, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xe8, 0xa7, 0x00, 0x00, 0xaa, 0x0e,
-    0x40, 0x9e, 0x07, 0x40, 0xbe, 0x40, 0x6e, 0x70, 0xfe, 0x7f, 0x74, 0xbb, 0x71, 0x00, 0x5f, 0x20, 0x03, 0x40, 0xa0, 0x40, 0xa0,
-    0x00, 0x86, 0x6c, 0xe4, 0x40, 0x86, 0x7f, 0x7c, 0xbb, 0x71, 0x00, 0xe4, 0x41, 0xec, 0x02, 0x7f, 0x6c, 0xbb, 0x71, 0x00, 0x42,
-    0x9c, 0x42, 0x41, 0xbc, 0x41, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xf8, 0xa7, 0x00, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xf8,
-    0x7f, 0xe8, 0xa7, 0x00, 0x00, 0x86, 0x40, 0x6c, 0x70, 0xfe, 0x7f, 0x76, 0xbb, 0x71, 0x00, 0x5f, 0x00, 0x04, 0x40, 0xa0, 0x40,
-    0xa0, 0x00, 0x86, 0x6e, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xf8, 0xa7, 0x00, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xf8,
-    0x7f, 0xe8, 0xa7, 0x00, 0x00, 0x86, 0x40, 0x6e, 0x70, 0x84, 0x4f, 0x78, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xa9, 0x8c, 0x00, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x48, 0x03, 0x00, 0x00, 0x84, 0x4f, 0x78,
-    0xbb, 0x71, 0x00, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x80, 0xbb, 0x71, 0x00, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xa9, 0x8c, 0x00, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0x48, 0x03,
-    0x00, 0x00, 0x84, 0x4f, 0x80, 0xbb, 0x71, 0x00, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f,
-    0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xa9, 0x8c, 0x00, 0x42, 0x2c,
-    0xcc, 0xf4, 0xef, 0x48, 0x03, 0x00, 0x00, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84,
-    0x50, 0x51, 0x70, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1,
-    0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x02, 0x00, 0x00, 0x84, 0x40, 0xc9, 0x18, 0x70, 0x2c, 0x5c,
-    0xef, 0x54, 0x02, 0x00, 0x00, 0x28, 0xc9, 0x18, 0x7f, 0x3f, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x70, 0xbb, 0x71,
-    0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xc9,
-    0x18, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00,
-    0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x28, 0xc9, 0x18, 0x7f,
-    0x07, 0x84, 0x01, 0x40, 0x7b, 0x05, 0x84, 0x03, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0xa0, 0x7f,
-    0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x04, 0xa9, 0x8c, 0x00, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0xac, 0x02, 0x00,
-    0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x28, 0xc9, 0x18,
-    0x7f, 0x07, 0x84, 0x02, 0x40, 0x7b, 0x05, 0x84, 0x03, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x2c,
-    0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x84, 0x44, 0x45, 0xa0, 0x45, 0xa0, 0x64, 0xa0, 0x68, 0x2c, 0xcc, 0xf4, 0xaf, 0x2c, 0x06,
-    0x04, 0xc9, 0x14, 0x41, 0x84, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0xff, 0x43, 0x84, 0x43, 0x59,
-    0x70, 0x24, 0x7f, 0xea, 0xa2, 0x00, 0x00, 0x04, 0x6c, 0x41, 0x84, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x78, 0xbb, 0x71, 0x00, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef,
-    0x30, 0x03, 0x00, 0x00, 0x28, 0x40, 0x77, 0x08, 0x24, 0x7f, 0xc4, 0x9f, 0x00, 0x00, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c,
-    0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x80, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03, 0x00, 0x00, 0x28, 0x40,
-    0x7f, 0x2b, 0xde, 0x7f, 0x8e, 0xbb, 0x71, 0x00, 0x7f, 0x8a, 0xbb, 0x71, 0x00, 0x40, 0xac, 0x02, 0x40, 0x86, 0x40, 0x6e, 0x70,
-    0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xef, 0x5c, 0x02, 0x00,
-    0x00, 0xfe, 0x7f, 0x7a, 0xbb, 0x71, 0x00, 0x6e, 0x40, 0xa8, 0x64, 0x40, 0xfe, 0x7f, 0x7a, 0xbb, 0x71, 0x00, 0x7f, 0x7e, 0xbb,
-    0x71, 0x00, 0x41, 0xac, 0x41, 0x40, 0x70, 0x84, 0x40, 0x44, 0xfc, 0x10, 0x64, 0x40, 0xa0, 0x40, 0xa0, 0x00, 0xfc, 0x08, 0x44,
-    0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xf8, 0xa7, 0x00, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xe8, 0xa7, 0x00, 0x00,
-    0x84, 0x40, 0x44, 0x3c, 0x45, 0x44, 0x77, 0x08, 0x24, 0x7f, 0xc5, 0xa0, 0x00, 0x00, 0xa0, 0xc9, 0x18, 0xa0, 0x45, 0xa0, 0x43,
-    0xa0, 0x64, 0xa0, 0x68, 0x2c, 0xcc, 0xec, 0xaf, 0x41, 0x04, 0x7a, 0xd6, 0xfe, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x78, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03, 0x00, 0x00, 0x28, 0x40, 0x77,
-    0x08, 0x24, 0x7f, 0xc5, 0xa0, 0x00, 0x00, 0xfe, 0xc9, 0x14, 0x6c, 0x40, 0x84, 0x40, 0xa9, 0x8c, 0x00, 0x70, 0xa0, 0x40, 0x2c,
-    0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00, 0x3c, 0x06, 0x40, 0x43, 0x07, 0x80, 0xa9, 0x8c, 0x00, 0x70, 0xfe, 0xc9, 0x16, 0x6e,
-    0x40, 0x84, 0x40, 0xa9, 0x90, 0x00, 0x70, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00, 0x3c, 0x06, 0x40, 0x43,
-    0x07, 0x80, 0xa9, 0x90, 0x00, 0x70, 0xa0, 0xa9, 0x8c, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00, 0x84, 0x40, 0xa9,
-    0x94, 0x00, 0x70, 0xa0, 0xa9, 0x90, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00, 0x3c, 0xa9, 0x94, 0x00, 0x40, 0x4b,
-    0x09, 0x80, 0xa9, 0x8c, 0x00, 0x70, 0x7b, 0x07, 0x80, 0xa9, 0x90, 0x00, 0x70, 0x04, 0xc9, 0x14, 0x40, 0x9c, 0x04, 0x4c, 0x04,
-    0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xa9, 0x8c, 0x00, 0xa0, 0xa9, 0x90, 0x00, 0x04, 0xa9, 0x94, 0x00, 0x42, 0x2c,
-    0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xa9, 0x98,
-    0x00, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x10, 0x02, 0x00, 0x00, 0x04, 0x6c, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x6c, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xef, 0x5c, 0x02, 0x00, 0x00, 0x04, 0xc9, 0x14,
-    0x41, 0x04, 0x6c, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0xff, 0x59, 0x70, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc,
-    0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03, 0x00, 0x00, 0x28, 0x40, 0x7f, 0x78, 0x3e,
-    0x7f, 0x7c, 0xbb, 0x71, 0x00, 0x6c, 0x4b, 0x6f, 0xfc, 0x01, 0x68, 0x40, 0xa0, 0x40, 0xa0, 0x00, 0xfe, 0x7f, 0x82, 0xbb, 0x71,
-    0x00, 0x6e, 0x40, 0xac, 0x0e, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0x7f, 0xf8, 0xa7, 0x00, 0x00, 0xa0, 0x40, 0x2c, 0xcc, 0xf8,
-    0x7f, 0xe8, 0xa7, 0x00, 0x00, 0x84, 0x40, 0x59, 0x70, 0xbc, 0x43, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00,
-    0x00, 0x3c, 0x01, 0x40, 0x77, 0x32, 0x28, 0x43, 0x4b, 0x2e, 0x86, 0x7f, 0x82, 0xbb, 0x71, 0x00, 0xe4, 0x40, 0xe8, 0x0e, 0x59,
-    0x41, 0x9c, 0x41, 0x40, 0x86, 0x6e, 0xe4, 0x41, 0xbc, 0x41, 0x40, 0x9c, 0x07, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x00,
-    0x02, 0x00, 0x00, 0x3c, 0x04, 0x40, 0x4f, 0x06, 0x84, 0x43, 0x59, 0x70, 0x3c, 0x43, 0x59, 0x77, 0x08, 0x24, 0x7f, 0x66, 0xa2,
-    0x00, 0x00, 0x84, 0x4f, 0x80, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x43, 0x28, 0xa9, 0xa4, 0x00, 0x4b, 0x4d, 0x92, 0xa9, 0x9c, 0x00, 0x70, 0xe8, 0x0e, 0xa9,
-    0xa4, 0x00, 0x40, 0xbe, 0x01, 0x40, 0x9e, 0x40, 0xa9, 0x9e, 0x00, 0x70, 0xde, 0x0e, 0xa9, 0x9e, 0x00, 0x40, 0x86, 0x40, 0xa9,
-    0xa2, 0x00, 0x70, 0x96, 0xa9, 0xa0, 0x00, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0xa9, 0x9c, 0x00, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef,
-    0x68, 0x03, 0x00, 0x00, 0xbc, 0x0c, 0x4c, 0x84, 0x59, 0x43, 0x84, 0x4f, 0x80, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x43, 0x28, 0xa9, 0xa4, 0x00, 0x4b, 0x4d,
-    0x92, 0xa9, 0x9c, 0x00, 0x70, 0xe8, 0x0e, 0xa9, 0xa4, 0x00, 0x40, 0xbe, 0x01, 0x40, 0x9e, 0x40, 0xa9, 0x9e, 0x00, 0x70, 0xde,
-    0x0e, 0xa9, 0x9e, 0x00, 0x40, 0x86, 0x40, 0xa9, 0xa2, 0x00, 0x70, 0x96, 0xa9, 0xa0, 0x00, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00,
-    0x00, 0x04, 0xa9, 0x9c, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0xbc, 0x0c, 0x4c, 0x28, 0x59, 0x77, 0x38, 0x28, 0x45,
-    0x4f, 0x34, 0xfc, 0x01, 0x45, 0x44, 0x9e, 0x0e, 0x6e, 0x70, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84,
-    0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xef, 0x5c, 0x02, 0x00, 0x00, 0xa0, 0xc9, 0x18, 0xa0, 0x45, 0xa0, 0x43, 0xa0, 0x64, 0xa0,
-    0x68, 0x2c, 0xcc, 0xec, 0xaf, 0x65, 0x01, 0x7a, 0xfa, 0xfb, 0x3c, 0x0f, 0x59, 0x77, 0x3d, 0xfc, 0x68, 0x64, 0x40, 0x3c, 0x40,
-    0x45, 0x43, 0x34, 0xdc, 0x01, 0x45, 0x44, 0xbe, 0x0e, 0x6e, 0x70, 0x04, 0x6c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xfc, 0xef, 0x5c, 0x02, 0x00, 0x00, 0xa0, 0xc9, 0x18, 0xa0, 0x45, 0xa0, 0x43, 0xa0, 0x64,
-    0xa0, 0x68, 0x2c, 0xcc, 0xec, 0xaf, 0x25, 0x01, 0x7a, 0xba, 0xfb, 0xa0, 0x02, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0x03, 0x00, 0x00,
-    0xdc, 0x08, 0x7f, 0x34, 0x04, 0x00, 0x00, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xd4, 0x74, 0x08, 0x41, 0x38, 0x40, 0x41, 0x76, 0xc5,
-    0xfb, 0x28, 0xc9, 0x18, 0x7f, 0x58, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0xc9, 0x18, 0xdc, 0x08, 0xc9, 0x18, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0xc9, 0x18,
-    0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xec,
-    0xef, 0x7c, 0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0xa0, 0xc9, 0x18, 0x2c, 0xcc, 0xfc, 0xef, 0x30, 0x02,
-    0x00, 0x00, 0x24, 0x7f, 0xdc, 0xa3, 0x00, 0x00, 0xa0, 0xc9, 0x18, 0xa0, 0x45, 0xa0, 0x43, 0xa0, 0x64, 0xa0, 0x68, 0x2c, 0xcc,
-    0xec, 0xaf, 0x96, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03,
-    0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8,
-    0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x04, 0xa9, 0x94, 0x00, 0x42, 0x2c, 0xcc, 0xf4,
-    0xef, 0xac, 0x02, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x28, 0x43, 0x4b, 0x15, 0x84, 0x43, 0xe6, 0xc8, 0x04, 0x70,
-    0x84, 0x45, 0xe6, 0xc8, 0x06, 0x70, 0xdc, 0x45, 0x43, 0x40, 0x18, 0x43, 0x08, 0x84, 0xff, 0x40, 0x18, 0x43, 0x08, 0x70, 0x70,
-    0x70, 0x10, 0x49, 0x9c, 0x08, 0x4c, 0x28, 0x5a, 0x7f, 0x59, 0x2c, 0x5c, 0xef, 0x54, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04,
-    0x00, 0x00, 0x84, 0x4f, 0x70, 0xbb, 0x71, 0x00, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04,
-    0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf4, 0xef, 0xac, 0x02, 0x00, 0x00, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x02, 0x2c, 0xcc, 0xf0, 0xef, 0x68,
-    0x03, 0x00, 0x00, 0x2c, 0x5c, 0xef, 0x4c, 0x02, 0x00, 0x00, 0x18, 0x49, 0x08, 0x84, 0x4f, 0x80, 0xbb, 0x71, 0x00, 0x40, 0x9c,
-    0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x78, 0x28, 0xc9, 0x10,
-    0x4b, 0x40, 0x92, 0x68, 0x70, 0xe8, 0x0e, 0xc9, 0x10, 0x40, 0xbe, 0x01, 0x40, 0x9e, 0x40, 0x6a, 0x70, 0xde, 0x0e, 0x6a, 0x40,
-    0x86, 0x40, 0x6e, 0x70, 0x96, 0x6c, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x68, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00,
-    0x00, 0xbc, 0x0c, 0x4c, 0xa0, 0x74, 0xa0, 0x7c, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xf4, 0xaf, 0x09, 0x00, 0x18, 0x49, 0x08, 0x10,
-    0x43, 0x9c, 0x6f, 0x74, 0x4c, 0x84, 0x5a, 0x48, 0x84, 0x74, 0x47, 0x3c, 0x10, 0x47, 0x47, 0x08, 0x24, 0x7f, 0x92, 0xa5, 0x00,
-    0x00, 0xfe, 0x7f, 0x7a, 0xbb, 0x71, 0x00, 0x7f, 0x7e, 0xbb, 0x71, 0x00, 0x40, 0xa8, 0x48, 0x40, 0xac, 0x47, 0x40, 0x9e, 0x7f,
-    0x7a, 0xbb, 0x71, 0x00, 0x40, 0x86, 0x40, 0x7f, 0x8a, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x7f, 0x78, 0xbb, 0x71, 0x00, 0x7f, 0x88,
-    0xbb, 0x71, 0x00, 0x70, 0xdc, 0x10, 0x48, 0x40, 0xfe, 0x7f, 0x7a, 0xbb, 0x71, 0x00, 0x7f, 0x7e, 0xbb, 0x71, 0x00, 0x41, 0xa8,
-    0x41, 0x40, 0xac, 0x47, 0x40, 0x9e, 0x7f, 0x7a, 0xbb, 0x71, 0x00, 0x40, 0x86, 0x40, 0x7f, 0x8e, 0xbb, 0x71, 0x00, 0x70, 0x86,
-    0x7f, 0x7c, 0xbb, 0x71, 0x00, 0x7f, 0x8c, 0xbb, 0x71, 0x00, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x84, 0x4f, 0x88, 0xbb,
-    0x71, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x4f, 0x8c, 0xbb, 0x71, 0x00, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0xc9, 0x6c, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x74, 0x03,
-    0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03,
-    0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x04, 0xc9, 0x64, 0x41, 0x84, 0x4f, 0x80, 0xbb, 0x71, 0x00, 0x40, 0x84, 0x50,
-    0x51, 0x70, 0x84, 0x48, 0x44, 0x24, 0x7f, 0x56, 0xa6, 0x00, 0x00, 0x04, 0xc9, 0x68, 0x41, 0x04, 0xc9, 0x64, 0x40, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0x44, 0xa0, 0x7f, 0x90, 0xbb, 0x71, 0x00, 0x2c, 0xcc, 0xf8, 0xef, 0x94, 0xbb, 0x71, 0x00, 0x84, 0x40, 0x46,
-    0x04, 0x59, 0x45, 0x7b, 0x37, 0x3b, 0x56, 0x5f, 0x80, 0x00, 0x7f, 0x28, 0xdc, 0x01, 0x46, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc,
-    0xef, 0xbc, 0x03, 0x00, 0x00, 0x04, 0x59, 0x41, 0xfc, 0x41, 0x45, 0x41, 0x9c, 0x41, 0x40, 0xfc, 0x40, 0x7f, 0x68, 0xbb, 0x71,
-    0x00, 0x43, 0x24, 0x7f, 0x7d, 0xa6, 0x00, 0x00, 0x87, 0x56, 0x55, 0x70, 0x90, 0x45, 0x90, 0x46, 0x2b, 0x56, 0x77, 0xc9, 0x83,
-    0x55, 0x70, 0xe0, 0x59, 0x2c, 0xcc, 0xfc, 0xef, 0xd0, 0x02, 0x00, 0x00, 0xfc, 0x40, 0x7f, 0x6c, 0xbb, 0x71, 0x00, 0x40, 0xac,
-    0x02, 0x40, 0x9e, 0x40, 0xc9, 0x68, 0x70, 0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xe0, 0x59, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00,
-    0x04, 0xc9, 0x68, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x04, 0xc9, 0x6c, 0x42,
-    0x2c, 0xcc, 0xec, 0xef, 0xb8, 0x03, 0x00, 0x00, 0x9e, 0x0e, 0xc9, 0x66, 0x70, 0x90, 0x44, 0xdc, 0x78, 0x48, 0x40, 0xa0, 0x40,
-    0xa0, 0x47, 0x2c, 0xcc, 0xf8, 0x7f, 0xe8, 0xa7, 0x00, 0x00, 0x3c, 0x40, 0x44, 0x4a, 0x41, 0xff, 0x18, 0x43, 0x08, 0x84, 0x45,
-    0x40, 0x90, 0x45, 0xfb, 0x6f, 0x7f, 0x56, 0x41, 0x87, 0x41, 0x50, 0x70, 0x84, 0x43, 0x40, 0x94, 0x43, 0x28, 0x40, 0x47, 0xeb,
-    0x7a, 0x7b, 0xff, 0x70, 0x10, 0x49, 0x28, 0x78, 0x4b, 0x42, 0x92, 0x5a, 0x70, 0xe8, 0x0e, 0x78, 0x40, 0xbe, 0x01, 0x40, 0x9e,
-    0x40, 0x72, 0x70, 0xde, 0x0e, 0x72, 0x40, 0x86, 0x40, 0x76, 0x70, 0x96, 0x74, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04,
-    0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03,
-    0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x43, 0x86, 0x5a, 0x48, 0x86,
-    0x72, 0x47, 0x86, 0x74, 0x46, 0x86, 0x76, 0x45, 0xa0, 0x14, 0xa0, 0x78, 0x2c, 0xcc, 0xf8, 0xef, 0x50, 0x03, 0x00, 0x00, 0x84,
-    0x40, 0x43, 0x77, 0x07, 0x80, 0x40, 0x18, 0x43, 0x08, 0xfa, 0x6f, 0xe0, 0x48, 0x40, 0x86, 0x40, 0x42, 0x86, 0x46, 0x44, 0x3a,
-    0x44, 0x1f, 0x7f, 0x07, 0xb2, 0x1f, 0x44, 0x92, 0x44, 0xdc, 0x04, 0x43, 0x40, 0xfe, 0x42, 0x44, 0x41, 0xd4, 0x05, 0x41, 0x41,
-    0x84, 0x41, 0x50, 0x70, 0xdc, 0x08, 0x43, 0x40, 0x86, 0x48, 0x50, 0x70, 0xdc, 0x0a, 0x43, 0x40, 0x86, 0x47, 0x50, 0x70, 0xdc,
-    0x0c, 0x43, 0x40, 0x86, 0x46, 0x50, 0x70, 0xdc, 0x0e, 0x43, 0x40, 0x86, 0x45, 0x50, 0x70, 0xdc, 0x04, 0x43, 0x40, 0xd0, 0x02,
-    0x50, 0x40, 0xfe, 0x47, 0x45, 0x41, 0xa8, 0x41, 0x40, 0xa0, 0x40, 0xa0, 0x43, 0xa0, 0x78, 0x2c, 0xcc, 0xf4, 0xef, 0x58, 0x03,
-    0x00, 0x00, 0x28, 0x40, 0x77, 0x11, 0xa0, 0x43, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x80, 0x40, 0x18, 0x43, 0x08,
-    0xdc, 0x10, 0x43, 0x40, 0x80, 0x50, 0x70, 0xa0, 0x43, 0xdc, 0x08, 0x43, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x01, 0x2c, 0xcc, 0xf0, 0xef, 0x68, 0x03, 0x00, 0x00, 0x84, 0x43,
-    0x40, 0x18, 0x43, 0x08, 0x18, 0x43, 0x08, 0x70, 0x70, 0x10, 0x49, 0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41,
-    0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc, 0xf4, 0xef, 0x54, 0x03, 0x00, 0x00, 0x18,
-    0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x48, 0x7f, 0x16, 0xa0, 0x58, 0x2c, 0xcc, 0xfc, 0xef, 0x9c,
-    0x02, 0x00, 0x00, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x18, 0x48, 0x08, 0x70, 0x70, 0x84, 0x5a, 0x40,
-    0x84, 0x74, 0x41, 0x3c, 0x41, 0x40, 0x43, 0x03, 0x08, 0x84, 0x41, 0x40, 0x08, 0x84, 0x5a, 0x40, 0x84, 0x74, 0x41, 0x3c, 0x41,
-    0x40, 0x4f, 0x03, 0x08, 0x84, 0x41, 0x40, 0x08, 0x10, 0x46, 0x84, 0x5a, 0x48, 0x84, 0x78, 0x47, 0xa0, 0x48, 0x04, 0x74, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x14, 0x02, 0x00, 0x00, 0x84, 0x40,
-    0x46, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xdc, 0x08, 0x48, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xef, 0x30, 0x03,
-    0x00, 0x00, 0x28, 0x40, 0x7f, 0x1d, 0xfa, 0x1f, 0x74, 0x40, 0xfc, 0x40, 0x1f, 0x40, 0xd0, 0x40, 0x01, 0x40, 0x84, 0x40, 0x42,
-    0x3c, 0x03, 0x47, 0x77, 0x0c, 0xb4, 0x42, 0x56, 0x70, 0x18, 0x46, 0x08, 0x18, 0x46, 0x08, 0x3c, 0x02, 0x47, 0x77, 0x0c, 0x88,
-    0x42, 0x40, 0xb8, 0x40, 0x56, 0x70, 0x18, 0x46, 0x08, 0xb0, 0x42, 0x56, 0x70, 0x18, 0x46, 0x08, 0x70, 0x70, 0x10, 0x49, 0x3e,
-    0x74, 0x5a, 0x4b, 0x17, 0x3e, 0x78, 0x5a, 0x43, 0x12, 0x3e, 0x76, 0x72, 0x4b, 0x0d, 0x3e, 0x7a, 0x72, 0x43, 0x08, 0x84, 0x01,
-    0x40, 0x18, 0x49, 0x08, 0x80, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x10, 0x49, 0x84, 0x5a, 0xef, 0xf0, 0x03, 0x00, 0x00,
-    0x70, 0x84, 0x74, 0xef, 0xec, 0x03, 0x00, 0x00, 0x70, 0x84, 0xef, 0xf0, 0x03, 0x00, 0x00, 0x40, 0xfc, 0x04, 0xef, 0xec, 0x03,
-    0x00, 0x00, 0x50, 0x70, 0x84, 0xef, 0xec, 0x03, 0x00, 0x00, 0x40, 0xdc, 0x01, 0xef, 0xf0, 0x03, 0x00, 0x00, 0x41, 0x84, 0x41,
-    0xc0, 0xfc, 0x70, 0x84, 0xef, 0xf0, 0x03, 0x00, 0x00, 0xef, 0xe8, 0x03, 0x00, 0x00, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x46, 0xdc, 0x0f, 0x5a, 0x40, 0xd4, 0x03, 0x40, 0x40, 0x84, 0x40, 0x48, 0x84, 0xef, 0xe8, 0x03, 0x00, 0x00, 0x41, 0x84,
-    0xef, 0xe8, 0x03, 0x00, 0x00, 0x47, 0x84, 0x00, 0x46, 0x38, 0x51, 0x01, 0x77, 0x2b, 0x7b, 0x09, 0x18, 0x46, 0x08, 0x84, 0x52,
-    0x51, 0x70, 0x84, 0x51, 0x42, 0x38, 0x52, 0x01, 0x7f, 0xf6, 0xd0, 0x03, 0x48, 0x40, 0x9c, 0x41, 0x40, 0x3c, 0x40, 0x42, 0x5b,
-    0x0e, 0xd0, 0x03, 0x48, 0x40, 0x9c, 0x41, 0x40, 0x3c, 0x41, 0x40, 0x53, 0x27, 0x84, 0x41, 0x42, 0xf8, 0xfe, 0x51, 0x40, 0x70,
-    0x84, 0x40, 0x41, 0x3c, 0x42, 0x41, 0x57, 0xc4, 0x3c, 0xef, 0xe8, 0x03, 0x00, 0x00, 0x41, 0x77, 0x09, 0x90, 0x46, 0x3c, 0x01,
-    0x46, 0x4f, 0xb4, 0x80, 0x40, 0x18, 0x46, 0x08, 0xd0, 0x03, 0x48, 0x40, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x47, 0x3c, 0x47, 0x42,
-    0x5f, 0x06, 0x84, 0x51, 0x57, 0x70, 0xf0, 0x01, 0x47, 0x51, 0x70, 0xdc, 0x08, 0x41, 0x40, 0x84, 0x40, 0x42, 0x7b, 0x0c, 0x80,
-    0x52, 0x70, 0x80, 0xc2, 0x04, 0x70, 0x9c, 0x08, 0x42, 0xd0, 0x03, 0x48, 0x40, 0x9c, 0x41, 0x40, 0x3c, 0x40, 0x42, 0x5b, 0xec,
-    0x84, 0x74, 0xc1, 0x04, 0x70, 0xdc, 0x08, 0x41, 0x40, 0x18, 0x46, 0x08, 0x10, 0x49, 0xa0, 0x5a, 0xa0, 0x00, 0x2c, 0xcc, 0xf8,
-    0xef, 0x50, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x10, 0x49, 0xfc, 0x08, 0x5a, 0x41, 0xf8, 0xfe, 0x51, 0x40,
-    0x84, 0x40, 0x51, 0x70, 0x80, 0xc1, 0x04, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x48, 0x84, 0xef,
-    0xe8, 0x03, 0x00, 0x00, 0x41, 0x3c, 0x48, 0xc1, 0x04, 0x77, 0x10, 0xdc, 0x08, 0x41, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef,
-    0x90, 0x02, 0x00, 0x00, 0x84, 0xef, 0xe8, 0x03, 0x00, 0x00, 0x41, 0x7b, 0x26, 0x3c, 0x48, 0xc7, 0x04, 0x77, 0x1d, 0xfc, 0x04,
-    0xef, 0xec, 0x03, 0x00, 0x00, 0x40, 0x3c, 0x40, 0x47, 0x7f, 0x10, 0xdc, 0x08, 0x47, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef,
-    0x90, 0x02, 0x00, 0x00, 0x84, 0x47, 0x41, 0xf8, 0xfe, 0x51, 0x47, 0x3c, 0x41, 0x47, 0x57, 0xd5, 0xa0, 0x48, 0x2c, 0xcc, 0xfc,
-    0xef, 0xa0, 0x02, 0x00, 0x00, 0x18, 0x47, 0x08, 0x70, 0x10, 0x49, 0x3e, 0x7c, 0x5a, 0x43, 0x17, 0x3e, 0x74, 0x78, 0x43, 0x12,
-    0x3e, 0x7e, 0x72, 0x43, 0x0d, 0x3e, 0x76, 0x7a, 0x43, 0x08, 0x84, 0x01, 0x40, 0x18, 0x49, 0x08, 0x80, 0x40, 0x18, 0x49, 0x08,
-    0x18, 0x49, 0x08, 0x10, 0x49, 0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x4f, 0x8c, 0xf8, 0x00, 0x00, 0xa0, 0x78, 0x2c, 0xcc, 0xf0, 0xef, 0xd4, 0x02, 0x00, 0x00, 0x18,
-    0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0,
-    0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x4f, 0x8c, 0xf8, 0x00, 0x00, 0xa0, 0x7c, 0x2c, 0xcc, 0xec, 0xef, 0xcc,
-    0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x5a, 0x40, 0x9c, 0x08, 0x4c,
-    0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x78, 0xa0, 0x7c, 0x2c, 0xcc, 0xec,
-    0xef, 0xcc, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x41, 0x04, 0x74, 0x42, 0x3e, 0xc2, 0x04, 0x51,
-    0x43, 0x1b, 0x3e, 0xc1, 0x04, 0x52, 0x43, 0x15, 0x3e, 0xc2, 0x06, 0xc1, 0x02, 0x43, 0x0e, 0x3e, 0xc1, 0x06, 0xc2, 0x02, 0x43,
-    0x07, 0x84, 0x01, 0x40, 0x7b, 0x04, 0x80, 0x40, 0x28, 0x40, 0x77, 0x07, 0x80, 0x40, 0x18, 0x49, 0x08, 0x3e, 0x52, 0x51, 0x43,
-    0x06, 0x86, 0x52, 0x51, 0x70, 0x3e, 0xc2, 0x02, 0xc1, 0x02, 0x43, 0x08, 0x86, 0xc2, 0x02, 0xc1, 0x02, 0x70, 0x3e, 0xc2, 0x04,
-    0xc1, 0x04, 0x4f, 0x08, 0x86, 0xc2, 0x04, 0xc1, 0x04, 0x70, 0x3e, 0xc2, 0x06, 0xc1, 0x06, 0x4f, 0x08, 0x86, 0xc2, 0x06, 0xc1,
-    0x06, 0x70, 0x84, 0x01, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x48, 0xa0, 0x7f, 0x0c, 0x04,
-    0x00, 0x00, 0x04, 0x7c, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51,
-    0x70, 0xa0, 0x48, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc,
-    0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0xa0, 0x48, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04,
-    0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x7c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc,
-    0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0xa0, 0x7f, 0x0c, 0x04, 0x00,
-    0x00, 0x04, 0x7c, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x48, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x03, 0x2c, 0xcc, 0xe8,
-    0xef, 0x38, 0x02, 0x00, 0x00, 0x18, 0x48, 0x08, 0x10, 0x43, 0x9c, 0x14, 0x4c, 0x84, 0xca, 0x10, 0x43, 0x84, 0x5a, 0x48, 0x84,
-    0x78, 0x47, 0x84, 0x42, 0x59, 0x70, 0x28, 0x43, 0x77, 0x07, 0x84, 0x01, 0x40, 0x7b, 0x04, 0x80, 0x40, 0x84, 0x40, 0x44, 0x77,
-    0x08, 0x24, 0x7f, 0xca, 0xac, 0x00, 0x00, 0x82, 0x6a, 0x70, 0x87, 0xc8, 0x02, 0xe2, 0x6e, 0x70, 0x24, 0x7f, 0xca, 0xac, 0x00,
-    0x00, 0xdc, 0x0c, 0x48, 0x40, 0xd0, 0x03, 0x46, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x45, 0x28, 0x44, 0x77, 0x0e, 0x87, 0xc5,
-    0x02, 0xe2, 0x6a, 0x70, 0x87, 0xc5, 0x03, 0xe2, 0x6e, 0x70, 0x86, 0x55, 0x68, 0x70, 0x86, 0xc5, 0x08, 0x6c, 0x70, 0xa0, 0xc8,
-    0x08, 0x04, 0x68, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70,
-    0xa0, 0x47, 0x3b, 0xc5, 0x04, 0x5f, 0x80, 0x00, 0x7f, 0x0e, 0x87, 0xc5, 0x04, 0xe0, 0x40, 0xb0, 0x5f, 0x00, 0xff, 0x40, 0x7b,
-    0x07, 0x87, 0xc5, 0x04, 0xe0, 0x40, 0x86, 0x7c, 0xe4, 0x41, 0x9c, 0x41, 0x40, 0xa0, 0x40, 0xde, 0x6a, 0x7e, 0x40, 0xa0, 0x40,
-    0x04, 0xc9, 0x10, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c, 0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0x43, 0x2c, 0xcc, 0xe8, 0xef, 0x38, 0x02, 0x00, 0x00, 0x86, 0xe2, 0x7c, 0xe0, 0x40, 0x87, 0xc5, 0x05, 0xe0,
-    0x41, 0x9c, 0x41, 0x40, 0x86, 0x40, 0x7c, 0x70, 0x84, 0x74, 0x40, 0x90, 0x74, 0x70, 0x87, 0x50, 0xe0, 0x46, 0x76, 0x5c, 0xff,
-    0x84, 0x59, 0x41, 0x04, 0x7c, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x43, 0x08, 0x70, 0x10, 0x43, 0x9c, 0x4f,
-    0x40, 0x00, 0x00, 0x00, 0x4c, 0xdc, 0x08, 0x5a, 0x40, 0x3e, 0x50, 0x74, 0x43, 0x0a, 0xdc, 0x08, 0x5a, 0x40, 0x86, 0x50, 0x74,
-    0x70, 0xdc, 0x0c, 0x5a, 0x40, 0x3e, 0x50, 0x78, 0x4f, 0x0a, 0xdc, 0x0c, 0x5a, 0x40, 0x86, 0x50, 0x78, 0x70, 0xdc, 0x0a, 0x5a,
-    0x40, 0x3e, 0x50, 0x76, 0x43, 0x0a, 0xdc, 0x0a, 0x5a, 0x40, 0x86, 0x50, 0x76, 0x70, 0xdc, 0x0e, 0x5a, 0x40, 0x3e, 0x50, 0x7a,
-    0x4f, 0x0a, 0xdc, 0x0e, 0x5a, 0x40, 0x86, 0x50, 0x7a, 0x70, 0xdc, 0x08, 0x7c, 0x40, 0x3e, 0x50, 0xca, 0x10, 0x43, 0x18, 0xdc,
-    0x08, 0x7c, 0x40, 0xfe, 0xca, 0x10, 0x50, 0x40, 0x9e, 0x40, 0x74, 0x70, 0xdc, 0x08, 0x7c, 0x40, 0x86, 0x50, 0xca, 0x10, 0x70,
-    0xdc, 0x0a, 0x7c, 0x40, 0x3e, 0x50, 0xca, 0x12, 0x43, 0x18, 0xdc, 0x0a, 0x7c, 0x40, 0xfe, 0xca, 0x12, 0x50, 0x40, 0x9e, 0x40,
-    0x76, 0x70, 0xdc, 0x0a, 0x7c, 0x40, 0x86, 0x50, 0xca, 0x12, 0x70, 0xfe, 0x74, 0x78, 0x40, 0xdc, 0x0c, 0x7c, 0x41, 0xfe, 0xca,
-    0x10, 0x51, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x12, 0xdc, 0x0c, 0x7c, 0x40, 0xfe, 0xca, 0x10, 0x50, 0x40, 0x9e, 0x74, 0x40, 0x86,
-    0x40, 0x78, 0x70, 0xfe, 0x76, 0x7a, 0x40, 0xdc, 0x0e, 0x7c, 0x41, 0xfe, 0xca, 0x12, 0x51, 0x41, 0x3c, 0x41, 0x40, 0x4f, 0x12,
-    0xdc, 0x0e, 0x7c, 0x40, 0xfe, 0xca, 0x12, 0x50, 0x40, 0x9e, 0x76, 0x40, 0x86, 0x40, 0x7a, 0x70, 0xfe, 0x76, 0x7a, 0x40, 0x70,
-    0x84, 0x40, 0x43, 0xfe, 0x74, 0x78, 0x40, 0xbc, 0x01, 0x40, 0x84, 0x40, 0x45, 0x28, 0x43, 0x4f, 0x06, 0x28, 0x45, 0x43, 0x08,
-    0x24, 0x7f, 0x71, 0xbf, 0x00, 0x00, 0x3c, 0x20, 0x45, 0x43, 0x08, 0x24, 0x7f, 0x68, 0xb9, 0x00, 0x00, 0x84, 0x45, 0x44, 0x84,
-    0x43, 0x6c, 0x70, 0xfa, 0x1f, 0xca, 0x10, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0x84, 0x50,
-    0xc9, 0x30, 0x70, 0x88, 0xc9, 0x30, 0x40, 0x84, 0x40, 0xc9, 0x20, 0x70, 0x86, 0xca, 0x10, 0xe4, 0x40, 0x9c, 0x44, 0x40, 0xb8,
-    0x1f, 0x40, 0x90, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0x84, 0x50, 0xc9, 0x24, 0x70, 0x88,
-    0xc9, 0x24, 0x40, 0x84, 0x40, 0xc9, 0x34, 0x70, 0x86, 0xca, 0x10, 0x40, 0x70, 0x84, 0x40, 0x41, 0x9c, 0x44, 0x40, 0xc4, 0x05,
-    0x40, 0x40, 0xc4, 0x05, 0x41, 0x41, 0xfc, 0x41, 0x40, 0xc9, 0x10, 0x70, 0x70, 0xdc, 0x04, 0x5a, 0x40, 0xfc, 0xc9, 0x10, 0x50,
-    0x40, 0xd0, 0x02, 0x40, 0x40, 0x84, 0x40, 0x46, 0xdc, 0x04, 0x7c, 0x40, 0xfc, 0xc9, 0x10, 0x50, 0x40, 0xd0, 0x02, 0x40, 0x40,
-    0x84, 0x40, 0x45, 0x3c, 0x7c, 0x5a, 0x7f, 0x08, 0x24, 0x7f, 0x29, 0xaf, 0x00, 0x00, 0x3e, 0xca, 0x12, 0x76, 0x43, 0x6a, 0x84,
-    0x43, 0x40, 0x86, 0x40, 0x40, 0x96, 0x40, 0x9e, 0x40, 0x76, 0x70, 0x84, 0x43, 0x40, 0x86, 0x40, 0x40, 0x96, 0x40, 0x9e, 0x40,
-    0xca, 0x12, 0x70, 0x3e, 0xca, 0x10, 0x74, 0x43, 0x33, 0xb0, 0x08, 0xca, 0x14, 0x70, 0x84, 0x44, 0x40, 0x86, 0x40, 0x40, 0x9e,
-    0x74, 0x40, 0x86, 0x40, 0x74, 0x70, 0x84, 0x44, 0x40, 0x86, 0x40, 0x40, 0x9e, 0xca, 0x10, 0x40, 0x86, 0x40, 0xca, 0x10, 0x70,
-    0x8c, 0x46, 0x40, 0x70, 0x84, 0x40, 0x46, 0x8c, 0x45, 0x40, 0x70, 0x84, 0x40, 0x45, 0x7b, 0x18, 0xdc, 0x04, 0x5a, 0x40, 0xd0,
-    0x03, 0x50, 0x40, 0xbc, 0x40, 0x46, 0xdc, 0x04, 0x7c, 0x40, 0xd0, 0x03, 0x50, 0x40, 0xbc, 0x40, 0x45, 0x7b, 0x49, 0x3e, 0xca,
-    0x10, 0x74, 0x43, 0x43, 0xb0, 0x08, 0xca, 0x14, 0x70, 0x84, 0x44, 0x40, 0x86, 0x40, 0x40, 0x9e, 0x74, 0x40, 0x86, 0x40, 0x74,
-    0x70, 0x84, 0x44, 0x40, 0x86, 0x40, 0x40, 0x9e, 0xca, 0x10, 0x40, 0x86, 0x40, 0xca, 0x10, 0x70, 0xdc, 0x04, 0x5a, 0x40, 0xdc,
-    0xc9, 0x10, 0x50, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x84, 0x40, 0x46, 0xdc, 0x04, 0x7c, 0x40, 0xdc, 0xc9, 0x10, 0x50, 0x40, 0xd0,
-    0x02, 0x40, 0x40, 0x84, 0x40, 0x45, 0x94, 0xc9, 0x10, 0x70, 0xfa, 0x1f, 0xca, 0x10, 0x40, 0x84, 0x40, 0xc9, 0x18, 0x70, 0xfa,
-    0x1f, 0x74, 0x40, 0x84, 0x40, 0xc9, 0x1c, 0x70, 0xa0, 0x7c, 0x04, 0xca, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x14, 0x02, 0x00, 0x00, 0x84, 0x40, 0x47, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x14, 0x02, 0x00, 0x00, 0x84, 0x40, 0x48,
-    0xfc, 0xc9, 0x1c, 0xc9, 0x18, 0x40, 0x84, 0x40, 0x59, 0x70, 0x28, 0x59, 0x77, 0x09, 0xb0, 0x04, 0xca, 0x14, 0x70, 0x7b, 0x0a,
-    0x28, 0x59, 0x43, 0x06, 0x9c, 0x20, 0x59, 0x70, 0xfc, 0x59, 0x20, 0x40, 0x84, 0x40, 0x64, 0x70, 0x84, 0xca, 0x14, 0x40, 0x24,
-    0x7f, 0x4f, 0xb9, 0x00, 0x00, 0xd4, 0x02, 0xc9, 0x10, 0x40, 0x84, 0x40, 0x64, 0x70, 0xf8, 0x03, 0xc9, 0x10, 0x40, 0x84, 0x40,
-    0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0x70, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0xf8, 0x51,
-    0xc9, 0x24, 0x41, 0xb0, 0x41, 0x50, 0x70, 0x84, 0x64, 0x43, 0x4f, 0x22, 0xb0, 0x58, 0x57, 0x70, 0xb0, 0xc8, 0xfc, 0xc7, 0xfc,
-    0x70, 0xb0, 0xc8, 0xf8, 0xc7, 0xf8, 0x70, 0xb0, 0xc8, 0xf4, 0xc7, 0xf4, 0x70, 0xbc, 0x10, 0x47, 0xbc, 0x10, 0x48, 0x94, 0x43,
-    0x47, 0xe2, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x16, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0xb0,
-    0x51, 0x50, 0x70, 0x94, 0x43, 0x47, 0xee, 0xf8, 0x58, 0xc9, 0x20, 0x40, 0xb0, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45,
-    0x47, 0x94, 0x44, 0x77, 0x9a, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0x3c, 0xc9, 0x1c, 0xc9, 0x18, 0x43, 0x05, 0x9c, 0x04, 0x48,
-    0x84, 0x48, 0x40, 0xbc, 0x04, 0x48, 0x84, 0x50, 0x44, 0x70, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0xd4, 0x59, 0x44, 0x41, 0xd0,
-    0x64, 0x58, 0x42, 0xb0, 0x42, 0x41, 0xb8, 0xc9, 0x24, 0x41, 0xb0, 0x41, 0x50, 0x70, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x25, 0x84,
-    0x48, 0x40, 0xbc, 0x04, 0x48, 0xd4, 0x59, 0x50, 0x40, 0x70, 0x84, 0x40, 0x44, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0xd0, 0x64,
-    0x58, 0x41, 0xb0, 0x44, 0x41, 0xb0, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xdf, 0x84, 0x58, 0x44, 0xd4, 0x59, 0x44, 0x40, 0xd0,
-    0x64, 0xc8, 0xfc, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x20, 0x40, 0xb0, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47,
-    0x94, 0x6c, 0x70, 0x47, 0x94, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0xd4, 0x02, 0xc9, 0x10, 0x40, 0x84, 0x40, 0x64, 0x70, 0xf8,
-    0x03, 0xc9, 0x10, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0x70, 0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0x84, 0x48,
-    0x41, 0x9c, 0x04, 0x48, 0xf8, 0x51, 0xc9, 0x20, 0x41, 0xb0, 0x41, 0x50, 0x70, 0x84, 0x64, 0x43, 0x4f, 0x22, 0xb0, 0x58, 0x57,
-    0x70, 0xb0, 0xc8, 0x04, 0xc7, 0x04, 0x70, 0xb0, 0xc8, 0x08, 0xc7, 0x08, 0x70, 0xb0, 0xc8, 0x0c, 0xc7, 0x0c, 0x70, 0x9c, 0x10,
-    0x47, 0x9c, 0x10, 0x48, 0x94, 0x43, 0x47, 0xe2, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x16, 0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0x84,
-    0x48, 0x41, 0x9c, 0x04, 0x48, 0xb0, 0x51, 0x50, 0x70, 0x94, 0x43, 0x47, 0xee, 0xf8, 0x58, 0xc9, 0x24, 0x40, 0xb0, 0x40, 0x57,
-    0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x44, 0x77, 0x9a, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0x3c, 0xc9, 0x1c, 0xc9,
-    0x18, 0x4f, 0x05, 0xbc, 0x04, 0x48, 0x84, 0x48, 0x40, 0x9c, 0x04, 0x48, 0x84, 0x50, 0x44, 0x70, 0x84, 0x47, 0x40, 0x9c, 0x04,
-    0x47, 0xd0, 0x64, 0x44, 0x41, 0xd4, 0x59, 0x58, 0x42, 0xb0, 0x42, 0x41, 0xb8, 0xc9, 0x20, 0x41, 0xb0, 0x41, 0x50, 0x70, 0x84,
-    0xc9, 0x10, 0x43, 0x4f, 0x25, 0x84, 0x48, 0x40, 0x9c, 0x04, 0x48, 0xd0, 0x64, 0x50, 0x40, 0x70, 0x84, 0x40, 0x44, 0x84, 0x47,
-    0x40, 0x9c, 0x04, 0x47, 0xd4, 0x59, 0x58, 0x41, 0xb0, 0x44, 0x41, 0xb0, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xdf, 0x84, 0x58,
-    0x44, 0xd0, 0x64, 0x44, 0x40, 0xd4, 0x59, 0xc8, 0x04, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x24, 0x40, 0xb0, 0x40, 0x57, 0x70,
-    0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x6c, 0x70, 0x47, 0x94, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0xd4, 0x02, 0xc9, 0x10,
-    0x40, 0x84, 0x40, 0x64, 0x70, 0xf8, 0x03, 0xc9, 0x10, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0x70, 0x84, 0x47,
-    0x40, 0xbc, 0x04, 0x47, 0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0xf8, 0x51, 0xc9, 0x24, 0x41, 0x70, 0x88, 0x41, 0x41, 0xb8, 0x41,
-    0x50, 0x70, 0x84, 0x64, 0x43, 0x4f, 0x2e, 0x88, 0x58, 0x40, 0xb8, 0x40, 0x57, 0x70, 0x88, 0xc8, 0xfc, 0x40, 0xb8, 0x40, 0xc7,
-    0xfc, 0x70, 0x88, 0xc8, 0xf8, 0x40, 0xb8, 0x40, 0xc7, 0xf8, 0x70, 0x88, 0xc8, 0xf4, 0x40, 0xb8, 0x40, 0xc7, 0xf4, 0x70, 0xbc,
-    0x10, 0x47, 0xbc, 0x10, 0x48, 0x94, 0x43, 0x47, 0xd6, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x19, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47,
-    0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0x88, 0x51, 0x41, 0xb8, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xeb, 0xf8, 0x58, 0xc9, 0x20,
-    0x40, 0x70, 0x88, 0x40, 0x40, 0xb8, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x44, 0x77, 0x83, 0x24, 0x7f,
-    0x62, 0xb9, 0x00, 0x00, 0x3c, 0xc9, 0x1c, 0xc9, 0x18, 0x43, 0x05, 0x9c, 0x04, 0x48, 0x84, 0x48, 0x40, 0xbc, 0x04, 0x48, 0x84,
-    0x50, 0x44, 0x70, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0xd4, 0x59, 0x44, 0x41, 0xd0, 0x64, 0x58, 0x42, 0xb0, 0x42, 0x41, 0xb8,
-    0xc9, 0x24, 0x41, 0x70, 0x88, 0x41, 0x41, 0xb8, 0x41, 0x50, 0x70, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x28, 0x84, 0x48, 0x40, 0xbc,
-    0x04, 0x48, 0xd4, 0x59, 0x50, 0x40, 0x70, 0x84, 0x40, 0x44, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0xd0, 0x64, 0x58, 0x41, 0xb0,
-    0x44, 0x41, 0x88, 0x41, 0x41, 0xb8, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xdc, 0x84, 0x58, 0x44, 0xd4, 0x59, 0x44, 0x40, 0xd0,
-    0x64, 0xc8, 0xfc, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x20, 0x40, 0x70, 0x88, 0x40, 0x40, 0xb8, 0x40, 0x57, 0x70, 0x9c, 0x46,
-    0x48, 0x9c, 0x45, 0x47, 0x94, 0x6c, 0x70, 0x47, 0x89, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0xd4, 0x02, 0xc9, 0x10, 0x40, 0x84,
-    0x40, 0x64, 0x70, 0xf8, 0x03, 0xc9, 0x10, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0x70, 0x84, 0x47, 0x40, 0x9c,
-    0x04, 0x47, 0x84, 0x48, 0x41, 0x9c, 0x04, 0x48, 0xf8, 0x51, 0xc9, 0x20, 0x41, 0x70, 0x88, 0x41, 0x41, 0xb8, 0x41, 0x50, 0x70,
-    0x84, 0x64, 0x43, 0x4f, 0x2e, 0x88, 0x58, 0x40, 0xb8, 0x40, 0x57, 0x70, 0x88, 0xc8, 0x04, 0x40, 0xb8, 0x40, 0xc7, 0x04, 0x70,
-    0x88, 0xc8, 0x08, 0x40, 0xb8, 0x40, 0xc7, 0x08, 0x70, 0x88, 0xc8, 0x0c, 0x40, 0xb8, 0x40, 0xc7, 0x0c, 0x70, 0x9c, 0x10, 0x47,
-    0x9c, 0x10, 0x48, 0x94, 0x43, 0x47, 0xd6, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x19, 0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0x84, 0x48,
-    0x41, 0x9c, 0x04, 0x48, 0x88, 0x51, 0x41, 0xb8, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xeb, 0xf8, 0x58, 0xc9, 0x24, 0x40, 0x70,
-    0x88, 0x40, 0x40, 0xb8, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x44, 0x77, 0x83, 0x24, 0x7f, 0x62, 0xb9,
-    0x00, 0x00, 0x3c, 0xc9, 0x1c, 0xc9, 0x18, 0x4f, 0x05, 0xbc, 0x04, 0x48, 0x84, 0x48, 0x40, 0x9c, 0x04, 0x48, 0x84, 0x50, 0x44,
-    0x70, 0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0xd0, 0x64, 0x44, 0x41, 0xd4, 0x59, 0x58, 0x42, 0xb0, 0x42, 0x41, 0xb8, 0xc9, 0x20,
-    0x41, 0x70, 0x88, 0x41, 0x41, 0xb8, 0x41, 0x50, 0x70, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x28, 0x84, 0x48, 0x40, 0x9c, 0x04, 0x48,
-    0xd0, 0x64, 0x50, 0x40, 0x70, 0x84, 0x40, 0x44, 0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0xd4, 0x59, 0x58, 0x41, 0xb0, 0x44, 0x41,
-    0x88, 0x41, 0x41, 0xb8, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xdc, 0x84, 0x58, 0x44, 0xd0, 0x64, 0x44, 0x40, 0xd4, 0x59, 0xc8,
-    0x04, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x24, 0x40, 0x70, 0x88, 0x40, 0x40, 0xb8, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c,
-    0x45, 0x47, 0x94, 0x6c, 0x70, 0x47, 0x89, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0xd4, 0x02, 0xc9, 0x10, 0x40, 0x84, 0x40, 0x64,
-    0x70, 0xf8, 0x03, 0xc9, 0x10, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0x70, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47,
-    0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0xf8, 0x51, 0xc9, 0x24, 0x41, 0xb4, 0x41, 0x50, 0x70, 0x84, 0x64, 0x43, 0x4f, 0x22, 0xb4,
-    0x58, 0x57, 0x70, 0xb4, 0xc8, 0xfc, 0xc7, 0xfc, 0x70, 0xb4, 0xc8, 0xf8, 0xc7, 0xf8, 0x70, 0xb4, 0xc8, 0xf4, 0xc7, 0xf4, 0x70,
-    0xbc, 0x10, 0x47, 0xbc, 0x10, 0x48, 0x94, 0x43, 0x47, 0xe2, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x16, 0x84, 0x47, 0x40, 0xbc, 0x04,
-    0x47, 0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0xb4, 0x51, 0x50, 0x70, 0x94, 0x43, 0x47, 0xee, 0xf8, 0x58, 0xc9, 0x20, 0x40, 0xb4,
-    0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x44, 0x77, 0x9a, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0x3c, 0xc9,
-    0x1c, 0xc9, 0x18, 0x43, 0x05, 0x9c, 0x04, 0x48, 0x84, 0x48, 0x40, 0xbc, 0x04, 0x48, 0x84, 0x50, 0x44, 0x70, 0x84, 0x47, 0x40,
-    0xbc, 0x04, 0x47, 0xd4, 0x59, 0x44, 0x41, 0xd0, 0x64, 0x58, 0x42, 0xb0, 0x42, 0x41, 0xb8, 0xc9, 0x24, 0x41, 0xb4, 0x41, 0x50,
-    0x70, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x25, 0x84, 0x48, 0x40, 0xbc, 0x04, 0x48, 0xd4, 0x59, 0x50, 0x40, 0x70, 0x84, 0x40, 0x44,
-    0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0xd0, 0x64, 0x58, 0x41, 0xb0, 0x44, 0x41, 0xb4, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xdf,
-    0x84, 0x58, 0x44, 0xd4, 0x59, 0x44, 0x40, 0xd0, 0x64, 0xc8, 0xfc, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x20, 0x40, 0xb4, 0x40,
-    0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x6c, 0x70, 0x47, 0x94, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0xd4, 0x02,
-    0xc9, 0x10, 0x40, 0x84, 0x40, 0x64, 0x70, 0xf8, 0x03, 0xc9, 0x10, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0x70,
-    0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0x84, 0x48, 0x41, 0x9c, 0x04, 0x48, 0xf8, 0x51, 0xc9, 0x20, 0x41, 0xb4, 0x41, 0x50, 0x70,
-    0x84, 0x64, 0x43, 0x4f, 0x22, 0xb4, 0x58, 0x57, 0x70, 0xb4, 0xc8, 0x04, 0xc7, 0x04, 0x70, 0xb4, 0xc8, 0x08, 0xc7, 0x08, 0x70,
-    0xb4, 0xc8, 0x0c, 0xc7, 0x0c, 0x70, 0x9c, 0x10, 0x47, 0x9c, 0x10, 0x48, 0x94, 0x43, 0x47, 0xe2, 0x84, 0xc9, 0x10, 0x43, 0x4f,
-    0x16, 0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0x84, 0x48, 0x41, 0x9c, 0x04, 0x48, 0xb4, 0x51, 0x50, 0x70, 0x94, 0x43, 0x47, 0xee,
-    0xf8, 0x58, 0xc9, 0x24, 0x40, 0xb4, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x44, 0x77, 0x9a, 0x24, 0x7f,
-    0x62, 0xb9, 0x00, 0x00, 0x3c, 0xc9, 0x1c, 0xc9, 0x18, 0x4f, 0x05, 0xbc, 0x04, 0x48, 0xa0, 0x4a, 0xd0, 0x02, 0x59, 0x40, 0x9c,
-    0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0x84, 0x50, 0x44, 0x70, 0x88, 0x44, 0x41, 0x84, 0x44, 0x42, 0x84, 0x59, 0x4a, 0xa0, 0x49,
-    0xd8, 0x4a, 0x58, 0x44, 0xb8, 0x42, 0x44, 0x9c, 0x04, 0x48, 0xd8, 0x4a, 0x58, 0x40, 0xf8, 0x40, 0x41, 0x49, 0xb0, 0x44, 0x49,
-    0xb8, 0xcc, 0xd8, 0x49, 0xb4, 0x49, 0x57, 0x70, 0x9c, 0x04, 0x47, 0x84, 0xcc, 0xc8, 0x43, 0x7f, 0x1f, 0xf8, 0x42, 0x40, 0x44,
-    0x9c, 0x04, 0x48, 0xd8, 0x4a, 0x58, 0x40, 0xf8, 0x40, 0x41, 0x49, 0xb0, 0x44, 0x49, 0xb4, 0x49, 0x57, 0x70, 0x9c, 0x04, 0x47,
-    0x94, 0x43, 0x47, 0xe5, 0xd8, 0x4a, 0x58, 0x44, 0xb8, 0x42, 0x44, 0xd4, 0x4a, 0xc8, 0x04, 0x40, 0xb0, 0x44, 0x40, 0xb8, 0xcc,
-    0xdc, 0x40, 0xb4, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0xcc, 0xc4, 0x70, 0x47, 0x9c, 0x20, 0x49, 0x20,
-    0x4a, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0xd4, 0x02, 0xc9, 0x10, 0x40, 0x84, 0x40, 0x64, 0x70, 0xf8, 0x03, 0xc9, 0x10, 0x40,
-    0x84, 0x40, 0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0xf8, 0x57, 0xc9, 0x34, 0x40, 0x70, 0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0xf8,
-    0x51, 0xc9, 0x24, 0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70, 0xbc, 0x04, 0x47, 0x84, 0x64, 0x43, 0x4f, 0x22, 0x84, 0x58,
-    0x57, 0x70, 0x84, 0xc8, 0xfc, 0xc7, 0xfc, 0x70, 0x84, 0xc8, 0xf8, 0xc7, 0xf8, 0x70, 0x84, 0xc8, 0xf4, 0xc7, 0xf4, 0x70, 0xbc,
-    0x10, 0x47, 0xbc, 0x10, 0x48, 0x94, 0x43, 0x47, 0xe2, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x16, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47,
-    0x84, 0x48, 0x41, 0xbc, 0x04, 0x48, 0x84, 0x51, 0x50, 0x70, 0x94, 0x43, 0x47, 0xee, 0xf8, 0x57, 0xc9, 0x30, 0x40, 0xf8, 0x58,
-    0xc9, 0x20, 0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x44, 0x77, 0x8d, 0x24,
-    0x7f, 0x62, 0xb9, 0x00, 0x00, 0x3c, 0xc9, 0x1c, 0xc9, 0x18, 0x43, 0x05, 0x9c, 0x04, 0x48, 0x84, 0x48, 0x40, 0xbc, 0x04, 0x48,
-    0x84, 0x50, 0x44, 0xd4, 0x59, 0x44, 0x40, 0xd0, 0x64, 0x58, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x24, 0x40, 0xf8, 0xc9, 0x34,
-    0x57, 0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70, 0xbc, 0x04, 0x47, 0x84, 0xc9, 0x10, 0x43, 0x4f, 0x25, 0x84, 0x48, 0x40,
-    0xbc, 0x04, 0x48, 0xd4, 0x59, 0x50, 0x40, 0x70, 0x84, 0x40, 0x44, 0x84, 0x47, 0x40, 0xbc, 0x04, 0x47, 0xd0, 0x64, 0x58, 0x41,
-    0xb0, 0x44, 0x41, 0x84, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xdf, 0x84, 0x58, 0x44, 0xd4, 0x59, 0x44, 0x40, 0xd0, 0x64, 0xc8,
-    0xfc, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x20, 0x40, 0xf8, 0xc9, 0x30, 0x57, 0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70,
-    0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x6c, 0x70, 0x47, 0x88, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0x3c, 0x19, 0xc9, 0x10,
-    0x4f, 0x08, 0x24, 0x7f, 0x38, 0xb8, 0x00, 0x00, 0xfc, 0xc9, 0x10, 0x19, 0x40, 0x84, 0x40, 0x43, 0xd0, 0x02, 0x43, 0x40, 0xd0,
-    0x01, 0x43, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x40, 0x44, 0xd0, 0x02, 0xc9, 0x10, 0x40, 0x9c, 0x04, 0x40, 0x04, 0x7f, 0x82, 0xb7,
-    0x00, 0x00, 0x41, 0x9c, 0x44, 0x41, 0x84, 0x6c, 0x44, 0x84, 0xc9, 0x20, 0x42, 0xa0, 0x4a, 0x84, 0xc9, 0x24, 0x4a, 0xa0, 0x49,
-    0x84, 0x41, 0x49, 0xf4, 0x57, 0x58, 0x41, 0xb8, 0x42, 0x41, 0xb4, 0x41, 0x57, 0x70, 0x9c, 0x40, 0x48, 0x9c, 0x40, 0x47, 0x24,
-    0x59, 0x84, 0xc8, 0x9c, 0xc7, 0x9c, 0x70, 0x84, 0xc8, 0xa0, 0xc7, 0xa0, 0x70, 0x84, 0xc8, 0xa4, 0xc7, 0xa4, 0x70, 0x84, 0xc8,
-    0xa8, 0xc7, 0xa8, 0x70, 0x84, 0xc8, 0xac, 0xc7, 0xac, 0x70, 0x84, 0xc8, 0xb0, 0xc7, 0xb0, 0x70, 0x84, 0xc8, 0xb4, 0xc7, 0xb4,
-    0x70, 0x84, 0xc8, 0xb8, 0xc7, 0xb8, 0x70, 0x84, 0xc8, 0xbc, 0xc7, 0xbc, 0x70, 0x84, 0xc8, 0xc0, 0xc7, 0xc0, 0x70, 0x84, 0xc8,
-    0xc4, 0xc7, 0xc4, 0x70, 0x84, 0xc8, 0xc8, 0xc7, 0xc8, 0x70, 0x84, 0xc8, 0xcc, 0xc7, 0xcc, 0x70, 0x84, 0xc8, 0xd0, 0xc7, 0xd0,
-    0x70, 0x84, 0xc8, 0xd4, 0xc7, 0xd4, 0x70, 0x84, 0xc8, 0xd8, 0xc7, 0xd8, 0x70, 0x84, 0xc8, 0xdc, 0xc7, 0xdc, 0x70, 0x84, 0xc8,
-    0xe0, 0xc7, 0xe0, 0x70, 0x84, 0xc8, 0xe4, 0xc7, 0xe4, 0x70, 0x84, 0xc8, 0xe8, 0xc7, 0xe8, 0x70, 0x84, 0xc8, 0xec, 0xc7, 0xec,
-    0x70, 0x84, 0xc8, 0xf0, 0xc7, 0xf0, 0x70, 0x84, 0xc8, 0xf4, 0xc7, 0xf4, 0x70, 0x84, 0xc8, 0xf8, 0xc7, 0xf8, 0x70, 0x84, 0xc8,
-    0xfc, 0xc7, 0xfc, 0x70, 0xf4, 0x57, 0x58, 0x41, 0xb8, 0x4a, 0x41, 0xb4, 0x41, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47,
-    0x94, 0x44, 0x76, 0x44, 0xff, 0x20, 0x49, 0x20, 0x4a, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0xd4, 0x02, 0xc9, 0x10, 0x40, 0x84,
-    0x40, 0x64, 0x70, 0xf8, 0x03, 0xc9, 0x10, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x84, 0x6c, 0x44, 0xf8, 0x57, 0xc9, 0x30, 0x40,
-    0x70, 0x84, 0x48, 0x41, 0x9c, 0x04, 0x48, 0xf8, 0x51, 0xc9, 0x20, 0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70, 0x9c, 0x04,
-    0x47, 0x84, 0x64, 0x43, 0x4f, 0x22, 0x84, 0x58, 0x57, 0x70, 0x84, 0xc8, 0x04, 0xc7, 0x04, 0x70, 0x84, 0xc8, 0x08, 0xc7, 0x08,
-    0x70, 0x84, 0xc8, 0x0c, 0xc7, 0x0c, 0x70, 0x9c, 0x10, 0x47, 0x9c, 0x10, 0x48, 0x94, 0x43, 0x47, 0xe2, 0x84, 0xc9, 0x10, 0x43,
-    0x4f, 0x16, 0x84, 0x47, 0x40, 0x9c, 0x04, 0x47, 0x84, 0x48, 0x41, 0x9c, 0x04, 0x48, 0x84, 0x51, 0x50, 0x70, 0x94, 0x43, 0x47,
-    0xee, 0xf8, 0x57, 0xc9, 0x34, 0x40, 0xf8, 0x58, 0xc9, 0x24, 0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48,
-    0x9c, 0x45, 0x47, 0x94, 0x44, 0x77, 0x8d, 0x24, 0x7f, 0x62, 0xb9, 0x00, 0x00, 0x3c, 0xc9, 0x1c, 0xc9, 0x18, 0x4f, 0x05, 0xbc,
-    0x04, 0x48, 0x84, 0x48, 0x40, 0x9c, 0x04, 0x48, 0x84, 0x50, 0x44, 0xd0, 0x64, 0x44, 0x40, 0xd4, 0x59, 0x58, 0x41, 0xb0, 0x41,
-    0x40, 0xb8, 0xc9, 0x20, 0x40, 0xf8, 0xc9, 0x30, 0x57, 0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70, 0x9c, 0x04, 0x47, 0x84,
-    0xc9, 0x10, 0x43, 0x4f, 0x25, 0x84, 0x48, 0x40, 0x9c, 0x04, 0x48, 0xd0, 0x64, 0x50, 0x40, 0x70, 0x84, 0x40, 0x44, 0x84, 0x47,
-    0x40, 0x9c, 0x04, 0x47, 0xd4, 0x59, 0x58, 0x41, 0xb0, 0x44, 0x41, 0x84, 0x41, 0x50, 0x70, 0x94, 0x43, 0x47, 0xdf, 0x84, 0x58,
-    0x44, 0xd0, 0x64, 0x44, 0x40, 0xd4, 0x59, 0xc8, 0x04, 0x41, 0xb0, 0x41, 0x40, 0xb8, 0xc9, 0x24, 0x40, 0xf8, 0xc9, 0x34, 0x57,
-    0x41, 0xb0, 0x41, 0x40, 0x84, 0x40, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x6c, 0x70, 0x47, 0x88, 0x7b, 0x15,
-    0x3c, 0x0f, 0x40, 0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0x0c, 0xf9, 0x00, 0x00, 0x24, 0x7f,
-    0x71, 0xbf, 0x00, 0x00, 0xfa, 0x1f, 0xca, 0x10, 0x40, 0x70, 0x84, 0x40, 0x44, 0xfa, 0x1f, 0x74, 0x40, 0x70, 0x84, 0x40, 0x46,
-    0xdc, 0x45, 0x46, 0x40, 0x3c, 0x1f, 0x40, 0x4f, 0x29, 0xb0, 0x04, 0xca, 0x14, 0x70, 0xd4, 0x46, 0xff, 0x40, 0x84, 0x40, 0xc9,
-    0x20, 0x70, 0xdc, 0x45, 0x46, 0x40, 0xb8, 0x1f, 0x40, 0x90, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00,
-    0x40, 0x84, 0x50, 0xc9, 0x24, 0x70, 0xdc, 0x45, 0x44, 0x40, 0x3c, 0x1f, 0x40, 0x5f, 0x29, 0xb0, 0x08, 0xca, 0x14, 0x70, 0xd4,
-    0x44, 0xff, 0x40, 0x84, 0x40, 0xc9, 0x28, 0x70, 0xdc, 0x45, 0x44, 0x40, 0xb8, 0x1f, 0x40, 0x90, 0x40, 0xd0, 0x02, 0x40, 0x40,
-    0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0x84, 0x50, 0xc9, 0x2c, 0x70, 0x84, 0x44, 0xc9, 0x18, 0x70, 0xfc, 0x46, 0x44, 0x40,
-    0x84, 0x40, 0x44, 0x84, 0x45, 0x59, 0x70, 0x3c, 0x7c, 0x5a, 0x77, 0x41, 0x3e, 0xca, 0x12, 0x76, 0x43, 0x3b, 0x84, 0x43, 0x40,
-    0x86, 0x40, 0x40, 0x96, 0x40, 0x9e, 0x40, 0x76, 0x70, 0x84, 0x43, 0x40, 0x86, 0x40, 0x40, 0x96, 0x40, 0x9e, 0x40, 0xca, 0x12,
-    0x70, 0xdc, 0x04, 0x5a, 0x40, 0xd0, 0x02, 0x50, 0x40, 0x8c, 0x40, 0x40, 0x70, 0x84, 0x40, 0x46, 0xdc, 0x04, 0x7c, 0x40, 0xd0,
-    0x02, 0x50, 0x40, 0x8c, 0x40, 0x40, 0x70, 0x84, 0x40, 0x45, 0x7b, 0x1a, 0xdc, 0x04, 0x5a, 0x40, 0xd0, 0x02, 0x50, 0x40, 0x70,
-    0x84, 0x40, 0x46, 0xdc, 0x04, 0x7c, 0x40, 0xd0, 0x02, 0x50, 0x40, 0x70, 0x84, 0x40, 0x45, 0xa0, 0x5a, 0x04, 0x74, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x14, 0x02, 0x00, 0x00, 0x84, 0x40, 0x48,
-    0xa0, 0x7c, 0x04, 0xca, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef,
-    0x14, 0x02, 0x00, 0x00, 0x84, 0x40, 0x47, 0x84, 0xca, 0x14, 0x40, 0x24, 0x7f, 0x5c, 0xbf, 0x00, 0x00, 0xdc, 0x01, 0x59, 0x40,
-    0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xd4, 0xc9, 0x18, 0x50, 0x40, 0x84, 0x40, 0xc9, 0x20, 0x70,
-    0x84, 0xc9, 0x20, 0x41, 0xd8, 0x44, 0x58, 0x42, 0xb4, 0x57, 0x42, 0xb8, 0x41, 0x42, 0xb4, 0x42, 0x57, 0x70, 0x9c, 0x46, 0x48,
-    0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xea, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0xfc, 0x44, 0x20, 0x40, 0x84, 0x40, 0xc9, 0x2c,
-    0x70, 0xdc, 0x01, 0x59, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xd4, 0xc9, 0x18, 0x50, 0x40,
-    0x84, 0x40, 0xc9, 0x28, 0x70, 0xa0, 0x4a, 0x84, 0xc9, 0x28, 0x40, 0x84, 0xc9, 0x2c, 0x4a, 0xd0, 0x4a, 0x58, 0x41, 0xd4, 0x44,
-    0xc8, 0x04, 0x42, 0xb0, 0x42, 0x41, 0xb4, 0x57, 0x41, 0xb8, 0x40, 0x41, 0xb4, 0x41, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45,
-    0x47, 0x94, 0x43, 0x47, 0xe2, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0xa0, 0x4a, 0x84, 0xc9, 0x28, 0x40, 0x84, 0xc9,
-    0x2c, 0x4a, 0xd8, 0x44, 0x58, 0x41, 0xf4, 0x57, 0x41, 0x42, 0xb8, 0x40, 0x42, 0xb4, 0x42, 0x57, 0x70, 0xb4, 0xc7, 0x04, 0x41,
-    0xb8, 0x4a, 0x41, 0xb4, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xdd, 0x20, 0x4a, 0x24,
-    0x7f, 0x6f, 0xbf, 0x00, 0x00, 0xa0, 0x4a, 0xfc, 0x44, 0x20, 0x4a, 0x84, 0xc9, 0x20, 0x40, 0x84, 0xc9, 0x24, 0x42, 0xa0, 0x49,
-    0xf8, 0x40, 0x58, 0x41, 0xf8, 0x42, 0xc8, 0x04, 0x4a, 0xb0, 0x4a, 0x41, 0xd8, 0x44, 0x41, 0x41, 0xf4, 0x57, 0x41, 0x4a, 0xb8,
-    0xc9, 0x28, 0x4a, 0xb4, 0x4a, 0x57, 0x70, 0xb4, 0xc7, 0x04, 0x41, 0xb8, 0xc9, 0x2c, 0x41, 0xb4, 0x41, 0xc7, 0x04, 0x70, 0x9c,
-    0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xcf, 0x20, 0x49, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0xdc, 0x01,
-    0x59, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xd4, 0xc9, 0x18, 0x50, 0x40, 0x84, 0x40, 0xc9,
-    0x20, 0x70, 0x84, 0xc9, 0x20, 0x41, 0xd8, 0x44, 0x58, 0x42, 0xb8, 0x41, 0x42, 0xb0, 0x42, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c,
-    0x45, 0x47, 0x94, 0x43, 0x47, 0xed, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0x84, 0xc9, 0x20, 0x40, 0xa0, 0x4a, 0x84, 0xc9, 0x24,
-    0x4a, 0xf8, 0x40, 0x58, 0x42, 0xf8, 0x4a, 0xc8, 0x04, 0x41, 0xb0, 0x42, 0x41, 0xd8, 0x44, 0x41, 0x41, 0xb0, 0x41, 0x57, 0x70,
-    0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xe4, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0x3c, 0x10, 0x59,
-    0x47, 0x2f, 0x84, 0x4f, 0x00, 0x00, 0xff, 0xff, 0x42, 0xf0, 0xc9, 0x28, 0xc9, 0x2c, 0x40, 0xd8, 0x44, 0x58, 0x41, 0xb8, 0x40,
-    0x41, 0xb2, 0x41, 0xc7, 0x02, 0x70, 0xb8, 0x42, 0x41, 0xb0, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94,
-    0x43, 0x47, 0xe4, 0x7b, 0x2c, 0x84, 0xc9, 0x28, 0x40, 0xa0, 0x4a, 0x84, 0xc9, 0x2c, 0x4a, 0xd8, 0x44, 0x58, 0x41, 0xf8, 0x40,
-    0x41, 0x42, 0xb0, 0x42, 0x57, 0x70, 0xb8, 0x4a, 0x41, 0xb0, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94,
-    0x43, 0x47, 0xe4, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0x84, 0xc9, 0x20, 0x40, 0xa0, 0x4a, 0x84, 0xc9, 0x24, 0x4a,
-    0x3c, 0x10, 0x59, 0x4f, 0x30, 0xf8, 0x40, 0x58, 0x41, 0xf8, 0x4a, 0xc8, 0x04, 0x42, 0xb0, 0x42, 0x41, 0xd8, 0x44, 0x41, 0x41,
-    0xf8, 0xc9, 0x28, 0x41, 0x42, 0xb0, 0x42, 0x57, 0x70, 0xb8, 0xc9, 0x2c, 0x41, 0xb0, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48,
-    0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xd6, 0x7b, 0x2d, 0xf8, 0x40, 0x58, 0x41, 0xf8, 0x4a, 0xc8, 0x04, 0x42, 0xb0, 0x42, 0x41,
-    0xd8, 0x44, 0x41, 0x41, 0xb2, 0x41, 0xc7, 0x02, 0x70, 0xb8, 0x4f, 0x00, 0x00, 0xff, 0xff, 0x41, 0xb0, 0x41, 0xc7, 0x04, 0x70,
-    0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xd7, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0xdc, 0x01, 0x59,
-    0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xd4, 0xc9, 0x18, 0x50, 0x40, 0x84, 0x40, 0xc9, 0x20,
-    0x70, 0x84, 0xc9, 0x20, 0x41, 0xd8, 0x44, 0x58, 0x42, 0xb8, 0x41, 0x42, 0x88, 0x42, 0x42, 0xb8, 0x42, 0x57, 0x70, 0x9c, 0x46,
-    0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xea, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0x84, 0xc9, 0x20, 0x40, 0xa0, 0x4a, 0x84,
-    0xc9, 0x24, 0x4a, 0xf8, 0x40, 0x58, 0x42, 0xf8, 0x4a, 0xc8, 0x04, 0x41, 0xb0, 0x42, 0x41, 0xd8, 0x44, 0x41, 0x41, 0x88, 0x41,
-    0x41, 0xb8, 0x41, 0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xe1, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf,
-    0x00, 0x00, 0x3c, 0x10, 0x59, 0x47, 0x32, 0x84, 0x4f, 0xff, 0xff, 0x00, 0x00, 0x42, 0xf0, 0xc9, 0x28, 0xc9, 0x2c, 0x40, 0xd8,
-    0x44, 0x58, 0x41, 0xb8, 0x40, 0x41, 0x88, 0x41, 0x41, 0xba, 0x41, 0xc7, 0x02, 0x70, 0xb0, 0x42, 0x41, 0xb8, 0x41, 0xc7, 0x04,
-    0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xe1, 0x7b, 0x32, 0x84, 0xc9, 0x28, 0x40, 0xa0, 0x4a, 0x84, 0xc9,
-    0x2c, 0x4a, 0xd8, 0x44, 0x58, 0x41, 0xf8, 0x40, 0x41, 0x42, 0x88, 0x42, 0x42, 0xb8, 0x42, 0x57, 0x70, 0xb8, 0x4a, 0x41, 0x88,
-    0x41, 0x41, 0xb8, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xde, 0x20, 0x4a, 0x24, 0x7f,
-    0x6f, 0xbf, 0x00, 0x00, 0x84, 0xc9, 0x20, 0x40, 0xa0, 0x4a, 0x84, 0xc9, 0x24, 0x4a, 0x3c, 0x10, 0x59, 0x4f, 0x37, 0xf8, 0x40,
-    0x58, 0x41, 0xf8, 0x4a, 0xc8, 0x04, 0x42, 0xb0, 0x42, 0x41, 0xd8, 0x44, 0x41, 0x41, 0xf8, 0xc9, 0x28, 0x41, 0x42, 0x88, 0x42,
-    0x42, 0xb8, 0x42, 0x57, 0x70, 0xb8, 0xc9, 0x2c, 0x41, 0x70, 0x88, 0x41, 0x41, 0xb8, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48,
-    0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xcf, 0x7b, 0x30, 0xf8, 0x40, 0x58, 0x41, 0xf8, 0x4a, 0xc8, 0x04, 0x42, 0xb0, 0x42, 0x41,
-    0xd8, 0x44, 0x41, 0x41, 0x88, 0x41, 0x41, 0xba, 0x41, 0xc7, 0x02, 0x70, 0xb0, 0x4f, 0xff, 0xff, 0x00, 0x00, 0x41, 0xb8, 0x41,
-    0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xd4, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00,
-    0xdc, 0x01, 0x59, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xd4, 0xc9, 0x18, 0x50, 0x40, 0x84,
-    0x40, 0xc9, 0x20, 0x70, 0x84, 0xc9, 0x20, 0x41, 0xd8, 0x44, 0x58, 0x42, 0xb8, 0x41, 0x42, 0xb4, 0x42, 0x57, 0x70, 0x9c, 0x46,
-    0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xed, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0x84, 0xc9, 0x20, 0x40, 0xa0, 0x4a, 0x84,
-    0xc9, 0x24, 0x4a, 0xf8, 0x40, 0x58, 0x42, 0xf8, 0x4a, 0xc8, 0x04, 0x41, 0xb0, 0x42, 0x41, 0xd8, 0x44, 0x41, 0x41, 0xb4, 0x41,
-    0x57, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xe4, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0x3c,
-    0x10, 0x59, 0x47, 0x2f, 0x84, 0x4f, 0x00, 0x00, 0xff, 0xff, 0x42, 0xf0, 0xc9, 0x28, 0xc9, 0x2c, 0x40, 0xd8, 0x44, 0x58, 0x41,
-    0xb8, 0x40, 0x41, 0xb6, 0x41, 0xc7, 0x02, 0x70, 0xb8, 0x42, 0x41, 0xb4, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45,
-    0x47, 0x94, 0x43, 0x47, 0xe4, 0x7b, 0x2c, 0x84, 0xc9, 0x28, 0x40, 0xa0, 0x4a, 0x84, 0xc9, 0x2c, 0x4a, 0xd8, 0x44, 0x58, 0x41,
-    0xf8, 0x40, 0x41, 0x42, 0xb4, 0x42, 0x57, 0x70, 0xb8, 0x4a, 0x41, 0xb4, 0x41, 0xc7, 0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45,
-    0x47, 0x94, 0x43, 0x47, 0xe4, 0x20, 0x4a, 0x24, 0x7f, 0x6f, 0xbf, 0x00, 0x00, 0x84, 0xc9, 0x20, 0x40, 0xa0, 0x4a, 0x84, 0xc9,
-    0x24, 0x4a, 0x3c, 0x10, 0x59, 0x4f, 0x30, 0xf8, 0x40, 0x58, 0x41, 0xf8, 0x4a, 0xc8, 0x04, 0x42, 0xb0, 0x42, 0x41, 0xd8, 0x44,
-    0x41, 0x41, 0xf8, 0xc9, 0x28, 0x41, 0x42, 0xb4, 0x42, 0x57, 0x70, 0xb8, 0xc9, 0x2c, 0x41, 0xb4, 0x41, 0xc7, 0x04, 0x70, 0x9c,
-    0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xd6, 0x7b, 0x2d, 0xf8, 0x40, 0x58, 0x41, 0xf8, 0x4a, 0xc8, 0x04, 0x42, 0xb0,
-    0x42, 0x41, 0xd8, 0x44, 0x41, 0x41, 0xb6, 0x41, 0xc7, 0x02, 0x70, 0xb8, 0x4f, 0x00, 0x00, 0xff, 0xff, 0x41, 0xb4, 0x41, 0xc7,
-    0x04, 0x70, 0x9c, 0x46, 0x48, 0x9c, 0x45, 0x47, 0x94, 0x43, 0x47, 0xd7, 0x20, 0x4a, 0x7b, 0x15, 0x3c, 0x0f, 0x40, 0x47, 0x10,
-    0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0x4c, 0xf9, 0x00, 0x00, 0x7b, 0x02, 0x18, 0x43, 0x08, 0x10, 0x49,
-    0xa0, 0x7f, 0x08, 0x04, 0x00, 0x00, 0xa0, 0x5a, 0x2c, 0xcc, 0xf8, 0xef, 0xc0, 0x03, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49,
-    0x08, 0x10, 0x48, 0x84, 0x74, 0x41, 0x84, 0x00, 0x42, 0xdc, 0x0c, 0x5a, 0x48, 0x7b, 0x1a, 0x18, 0x48, 0x08, 0x87, 0x51, 0xe0,
-    0x40, 0xd0, 0x03, 0x40, 0x40, 0x9c, 0x48, 0x40, 0x87, 0xc0, 0x05, 0xe0, 0x40, 0x9c, 0x40, 0x42, 0x90, 0x41, 0x2b, 0x51, 0x77,
-    0xe9, 0x84, 0x42, 0x40, 0x18, 0x48, 0x08, 0x10, 0x43, 0x9c, 0x08, 0x4c, 0x04, 0x74, 0x40, 0xdc, 0x08, 0x5a, 0x41, 0x3e, 0x51,
-    0x50, 0x43, 0x06, 0x86, 0x51, 0x50, 0x70, 0x9c, 0x02, 0x40, 0x9c, 0x02, 0x41, 0x3e, 0x51, 0x50, 0x43, 0x06, 0x86, 0x51, 0x50,
-    0x70, 0x9c, 0x02, 0x40, 0x9c, 0x02, 0x41, 0x3e, 0x51, 0x50, 0x4f, 0x06, 0x86, 0x51, 0x50, 0x70, 0x9c, 0x02, 0x40, 0x9c, 0x02,
-    0x41, 0x3e, 0x51, 0x50, 0x4f, 0x06, 0x86, 0x51, 0x50, 0x70, 0x3e, 0x74, 0x78, 0x4f, 0x76, 0xa0, 0x5a, 0xa0, 0x74, 0x2c, 0xcc,
-    0xf8, 0x7f, 0x18, 0xc2, 0x00, 0x00, 0x84, 0x40, 0x48, 0x84, 0x5a, 0x47, 0xd0, 0x02, 0xc7, 0x04, 0x47, 0x84, 0x7c, 0x46, 0xfe,
-    0x76, 0x7a, 0x44, 0xfa, 0x1f, 0x76, 0x40, 0xd0, 0x02, 0x40, 0x43, 0x86, 0x74, 0x42, 0xd4, 0x42, 0xff, 0x59, 0x70, 0xfe, 0x01,
-    0x78, 0x45, 0xc4, 0x45, 0x4f, 0x00, 0x00, 0x00, 0x80, 0x64, 0x70, 0xc4, 0x05, 0x45, 0x45, 0xc4, 0x05, 0x42, 0x42, 0xbc, 0x42,
-    0x45, 0x94, 0x45, 0x43, 0x0f, 0xb8, 0x64, 0x59, 0x70, 0x88, 0x59, 0x64, 0x70, 0xbc, 0x04, 0x47, 0x7b, 0x0d, 0xdc, 0x01, 0x45,
-    0x40, 0xd0, 0x02, 0x40, 0x40, 0xbc, 0x40, 0x47, 0xc0, 0x02, 0xca, 0x10, 0x40, 0x4b, 0x0d, 0x3c, 0x0c, 0x40, 0x47, 0x08, 0x24,
-    0x90, 0x94, 0x05, 0x01, 0x00, 0xbc, 0x08, 0x4c, 0x18, 0x43, 0x08, 0x94, 0x44, 0x4b, 0xf8, 0xdc, 0x46, 0x43, 0x42, 0x84, 0x52,
-    0x42, 0x9c, 0x04, 0x43, 0xb8, 0x6f, 0x7f, 0x43, 0xf8, 0x59, 0x42, 0x40, 0xb4, 0x40, 0x58, 0x70, 0x9c, 0x04, 0x48, 0x84, 0x45,
-    0x41, 0x4b, 0x3c, 0x7f, 0x32, 0xd4, 0x02, 0x41, 0x40, 0x4f, 0x21, 0xb4, 0x42, 0x58, 0x70, 0xb4, 0x42, 0xc8, 0x04, 0x70, 0xb4,
-    0x42, 0xc8, 0x08, 0x70, 0xb4, 0x42, 0xc8, 0x0c, 0x70, 0x9c, 0x10, 0x48, 0x94, 0x40, 0x47, 0xe8, 0xb8, 0x03, 0x41, 0x4f, 0x0d,
-    0xb4, 0x42, 0x58, 0x70, 0x9c, 0x04, 0x48, 0x94, 0x41, 0x47, 0xf7, 0xf8, 0x64, 0x42, 0x40, 0xb4, 0x40, 0x58, 0x70, 0x9c, 0x47,
-    0x48, 0x7b, 0xa1, 0x88, 0x59, 0x59, 0x70, 0x88, 0x64, 0x64, 0x70, 0x94, 0x44, 0x4b, 0x8f, 0xdc, 0x46, 0x43, 0x42, 0x88, 0x52,
-    0x42, 0x9c, 0x04, 0x43, 0xb8, 0x6f, 0x7f, 0x43, 0xf0, 0x59, 0x42, 0x40, 0xb8, 0x40, 0x58, 0x70, 0x9c, 0x04, 0x48, 0x84, 0x45,
-    0x41, 0x4b, 0x3c, 0x7f, 0x32, 0xd4, 0x02, 0x41, 0x40, 0x4f, 0x21, 0xb8, 0x42, 0x58, 0x70, 0xb8, 0x42, 0xc8, 0x04, 0x70, 0xb8,
-    0x42, 0xc8, 0x08, 0x70, 0xb8, 0x42, 0xc8, 0x0c, 0x70, 0x9c, 0x10, 0x48, 0x94, 0x40, 0x47, 0xe8, 0xb8, 0x03, 0x41, 0x4f, 0x0d,
-    0xb8, 0x42, 0x58, 0x70, 0x9c, 0x04, 0x48, 0x94, 0x41, 0x47, 0xf7, 0xf0, 0x64, 0x42, 0x40, 0xb8, 0x40, 0x58, 0x70, 0x9c, 0x47,
-    0x48, 0x7b, 0xa1, 0x94, 0x44, 0x4a, 0x2e, 0xff, 0xdc, 0x46, 0x43, 0x42, 0x84, 0x52, 0x42, 0x9c, 0x04, 0x43, 0xb8, 0x6f, 0x7f,
-    0x43, 0xf8, 0x59, 0x42, 0x40, 0xb0, 0x40, 0x58, 0x70, 0x9c, 0x04, 0x48, 0x84, 0x45, 0x41, 0x4b, 0x3c, 0x7f, 0x32, 0xd4, 0x02,
-    0x41, 0x40, 0x4f, 0x21, 0xb0, 0x42, 0x58, 0x70, 0xb0, 0x42, 0xc8, 0x04, 0x70, 0xb0, 0x42, 0xc8, 0x08, 0x70, 0xb0, 0x42, 0xc8,
-    0x0c, 0x70, 0x9c, 0x10, 0x48, 0x94, 0x40, 0x47, 0xe8, 0xb8, 0x03, 0x41, 0x4f, 0x0d, 0xb0, 0x42, 0x58, 0x70, 0x9c, 0x04, 0x48,
-    0x94, 0x41, 0x47, 0xf7, 0xf8, 0x64, 0x42, 0x40, 0xb0, 0x40, 0x58, 0x70, 0x9c, 0x47, 0x48, 0x7b, 0xa0, 0x94, 0x44, 0x4a, 0xcc,
-    0xfe, 0xdc, 0x46, 0x43, 0x42, 0x84, 0x52, 0x42, 0x9c, 0x04, 0x43, 0xb8, 0x6f, 0x7f, 0x43, 0xf8, 0x59, 0x42, 0x40, 0xf4, 0x58,
-    0x42, 0x40, 0xb8, 0x59, 0x40, 0xb4, 0x40, 0x58, 0x70, 0x9c, 0x04, 0x48, 0x84, 0x45, 0x41, 0x4b, 0x3f, 0x7f, 0x32, 0xd4, 0x02,
-    0x41, 0x40, 0x4f, 0x21, 0x84, 0x42, 0x58, 0x70, 0x84, 0x42, 0xc8, 0x04, 0x70, 0x84, 0x42, 0xc8, 0x08, 0x70, 0x84, 0x42, 0xc8,
-    0x0c, 0x70, 0x9c, 0x10, 0x48, 0x94, 0x40, 0x47, 0xe8, 0xb8, 0x03, 0x41, 0x4f, 0x0d, 0x84, 0x42, 0x58, 0x70, 0x9c, 0x04, 0x48,
-    0x94, 0x41, 0x47, 0xf7, 0xf4, 0x58, 0x42, 0x40, 0xb8, 0x64, 0x40, 0xb4, 0x40, 0x58, 0x70, 0x9c, 0x47, 0x48, 0x7b, 0x96, 0x70,
-    0x70, 0x70, 0x84, 0x5a, 0x42, 0xc6, 0x05, 0x74, 0x40, 0xc6, 0x05, 0xc2, 0x08, 0x41, 0xbc, 0x41, 0x40, 0xfe, 0xc2, 0x0a, 0x76,
-    0x41, 0xa8, 0xe0, 0xc2, 0x04, 0x41, 0x9c, 0x41, 0x40, 0xc0, 0x02, 0x40, 0x40, 0x9c, 0x52, 0x40, 0x08, 0xde, 0x5a, 0x74, 0x52,
-    0x70, 0xde, 0x72, 0x76, 0xc2, 0x02, 0x70, 0x84, 0x42, 0x40, 0x08, 0xfe, 0x74, 0x5a, 0x52, 0x70, 0xfe, 0x76, 0x72, 0xc2, 0x02,
-    0x70, 0x84, 0x42, 0x40, 0x08, 0x86, 0x7a, 0x40, 0xde, 0x5a, 0x40, 0x52, 0x70, 0xde, 0x72, 0x40, 0xc2, 0x02, 0x70, 0xfe, 0x40,
-    0x74, 0xc2, 0x04, 0x70, 0xfe, 0x40, 0x76, 0xc2, 0x06, 0x70, 0x84, 0x42, 0x40, 0x08, 0x86, 0x76, 0x41, 0x86, 0x5a, 0x40, 0xae,
-    0x41, 0x40, 0x86, 0x40, 0x52, 0x70, 0x86, 0x72, 0x40, 0xae, 0x41, 0x40, 0x86, 0x40, 0xc2, 0x02, 0x70, 0x84, 0x42, 0x40, 0x08,
-    0x86, 0x76, 0x41, 0x86, 0x5a, 0x40, 0xaa, 0x41, 0x40, 0x86, 0x40, 0x52, 0x70, 0x86, 0x72, 0x40, 0xaa, 0x41, 0x40, 0x86, 0x40,
-    0xc2, 0x02, 0x70, 0x84, 0x42, 0x40, 0x08, 0xfe, 0x78, 0x5a, 0x52, 0x70, 0xfe, 0x7a, 0x72, 0xc2, 0x02, 0x70, 0xfe, 0x78, 0x74,
-    0xc2, 0x04, 0x70, 0xfe, 0x7a, 0x76, 0xc2, 0x06, 0x70, 0x84, 0x42, 0x40, 0x08, 0xde, 0x5a, 0x78, 0x52, 0x70, 0xde, 0x72, 0x7a,
-    0xc2, 0x02, 0x70, 0xde, 0x74, 0x78, 0xc2, 0x04, 0x70, 0xde, 0x76, 0x7a, 0xc2, 0x06, 0x70, 0x84, 0x42, 0x40, 0x08, 0x80, 0x40,
-    0x3c, 0x5a, 0x74, 0x77, 0x04, 0x90, 0x40, 0x08, 0x80, 0x40, 0x3c, 0x5a, 0x78, 0x77, 0x09, 0x3c, 0x74, 0x7c, 0x77, 0x04, 0x90,
-    0x40, 0x08, 0x70, 0x86, 0x72, 0x52, 0x70, 0x86, 0x76, 0xc2, 0x02, 0x70, 0x86, 0x7a, 0xc2, 0x04, 0x70, 0x86, 0x7e, 0xc2, 0x06,
-    0x70, 0x84, 0x42, 0x40, 0x08, 0x86, 0x72, 0x52, 0x70, 0x86, 0x76, 0xc2, 0x02, 0x70, 0x84, 0x42, 0x40, 0x08, 0x84, 0x5a, 0x52,
-    0x70, 0x84, 0x74, 0xc2, 0x04, 0x70, 0x84, 0x42, 0x40, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0xa0, 0x5a, 0xa0,
-    0x74, 0x86, 0x7a, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x7c, 0xa0, 0x00, 0xa0, 0x00, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xef, 0x2c,
-    0x03, 0x00, 0x00, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xe8, 0xaf,
-    0x3a, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0xa0, 0x00, 0xa0, 0x74, 0x86, 0x7a, 0xe4, 0x40, 0xa0,
-    0x40, 0xa0, 0x00, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x00, 0x2c, 0xcc,
-    0xe8, 0xaf, 0x0e, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x43, 0x86, 0x7a, 0x43, 0x86, 0x5f, 0x00, 0x08,
-    0x7f, 0x9c, 0xbb, 0x71, 0x00, 0x70, 0xde, 0x08, 0x43, 0x40, 0xa8, 0x0c, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x18, 0x02,
-    0x00, 0x00, 0x84, 0x40, 0x7f, 0x98, 0xbb, 0x71, 0x00, 0x70, 0x77, 0x08, 0x84, 0xff, 0x40, 0x18, 0x43, 0x08, 0x84, 0x7f, 0x98,
-    0xbb, 0x71, 0x00, 0x44, 0x84, 0x00, 0x48, 0x84, 0x74, 0x46, 0x70, 0x84, 0x46, 0x45, 0x80, 0x47, 0x7b, 0x2e, 0x18, 0x43, 0x08,
-    0x3e, 0x5f, 0x00, 0x80, 0x56, 0x77, 0x1f, 0xa0, 0x45, 0xa0, 0x46, 0xa0, 0x44, 0x2c, 0xcc, 0xf4, 0xaf, 0x7b, 0x00, 0x84, 0x40,
-    0x42, 0xe8, 0x0c, 0x42, 0x40, 0x9c, 0x40, 0x44, 0x9c, 0x42, 0x47, 0xdc, 0x04, 0x46, 0x45, 0x90, 0x48, 0x9c, 0x04, 0x46, 0x86,
-    0x43, 0xe4, 0x40, 0x3c, 0x40, 0x48, 0x4b, 0xd0, 0xa0, 0x45, 0xa0, 0x46, 0xa0, 0x44, 0x2c, 0xcc, 0xf4, 0xaf, 0x50, 0x00, 0x9c,
-    0x40, 0x47, 0x7f, 0x41, 0xa0, 0x5a, 0x84, 0x47, 0x40, 0x86, 0x40, 0x40, 0x86, 0x40, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x7c, 0x04,
-    0xca, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xec, 0xaf,
-    0xe3, 0x01, 0x84, 0x40, 0x48, 0xa0, 0x7f, 0x98, 0xbb, 0x71, 0x00, 0x2c, 0xcc, 0xfc, 0xef, 0x90, 0x02, 0x00, 0x00, 0x84, 0x48,
-    0x40, 0x18, 0x43, 0x08, 0x80, 0x48, 0x7b, 0xea, 0x70, 0x70, 0x10, 0x43, 0x3c, 0x5a, 0x74, 0x57, 0x07, 0x80, 0x40, 0x18, 0x43,
-    0x08, 0xfc, 0x04, 0x74, 0x41, 0x86, 0x51, 0x42, 0x86, 0xc1, 0x02, 0x44, 0x84, 0x5a, 0x48, 0x84, 0x78, 0x47, 0x82, 0x43, 0x7b,
-    0x5f, 0x18, 0x43, 0x08, 0x86, 0x58, 0x45, 0x86, 0xc8, 0x02, 0x46, 0x3e, 0x7f, 0x9c, 0xbb, 0x71, 0x00, 0x46, 0x43, 0x0a, 0x86,
-    0x46, 0x7f, 0x9c, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x42, 0x57, 0x70, 0x86, 0x44, 0xc7, 0x02, 0x70, 0x86, 0x45, 0xc7, 0x04, 0x70,
-    0x86, 0x46, 0xc7, 0x06, 0x70, 0x87, 0x01, 0xc7, 0x08, 0x70, 0x3e, 0xc7, 0x02, 0xc7, 0x06, 0x47, 0x0f, 0x3e, 0xc7, 0x02, 0xc7,
-    0x06, 0x77, 0x10, 0x3e, 0x57, 0xc7, 0x04, 0x4f, 0x0a, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xaf, 0x2e, 0x00, 0x86, 0x45, 0x42, 0x86,
-    0x46, 0x44, 0x9c, 0x0c, 0x47, 0x92, 0x43, 0x9c, 0x04, 0x48, 0x3c, 0x74, 0x48, 0x5b, 0xa3, 0xa0, 0x78, 0x86, 0x43, 0xe4, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xaf, 0x45, 0x00, 0x86, 0x43, 0xe4, 0x40, 0x18, 0x43, 0x08, 0x10, 0x48, 0x84, 0x5a, 0x42, 0xdc,
-    0x02, 0x42, 0x40, 0x86, 0x50, 0x48, 0xdc, 0x02, 0x42, 0x40, 0xdc, 0x06, 0x42, 0x41, 0x86, 0x51, 0x50, 0x70, 0xdc, 0x06, 0x42,
-    0x40, 0x86, 0x48, 0x50, 0x70, 0x86, 0x52, 0x48, 0xdc, 0x04, 0x42, 0x40, 0x86, 0x50, 0x52, 0x70, 0xdc, 0x04, 0x42, 0x40, 0x86,
-    0x48, 0x50, 0x70, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x46, 0x84, 0x5a, 0x46, 0xe8, 0x0c, 0x74, 0x40, 0x9c, 0x46, 0x40, 0x84,
-    0x40, 0x47, 0x84, 0x46, 0x41, 0x7b, 0x60, 0x2b, 0xc1, 0x08, 0x7f, 0x58, 0x3e, 0xc1, 0x06, 0xc1, 0x02, 0x77, 0x51, 0x3c, 0x46,
-    0x41, 0x77, 0x0e, 0xe8, 0x0c, 0x74, 0x40, 0x9c, 0x41, 0x40, 0xbc, 0x0c, 0x40, 0x7b, 0x06, 0xfc, 0x0c, 0x41, 0x40, 0x84, 0x40,
-    0x42, 0x3c, 0x47, 0x41, 0x53, 0x0b, 0xdc, 0x0c, 0x41, 0x40, 0x84, 0x40, 0x48, 0x7b, 0x05, 0x84, 0x46, 0x48, 0x3e, 0xc1, 0x02,
-    0xc2, 0x02, 0x77, 0x0f, 0x3e, 0xc1, 0x02, 0xc8, 0x02, 0x77, 0x1a, 0x83, 0xc1, 0x08, 0x70, 0x7b, 0x14, 0x3e, 0xc1, 0x02, 0xc2,
-    0x06, 0x77, 0x0d, 0x3e, 0xc1, 0x02, 0xc8, 0x06, 0x77, 0x06, 0x83, 0xc1, 0x08, 0x70, 0x9c, 0x0c, 0x41, 0x3c, 0x47, 0x41, 0x5b,
-    0x9f, 0x18, 0x46, 0x08, 0x70, 0x70, 0x10, 0x49, 0xdc, 0x02, 0x5a, 0x40, 0xdc, 0x02, 0x74, 0x41, 0x3e, 0x51, 0x50, 0x47, 0x44,
-    0xdc, 0x02, 0x5a, 0x40, 0xdc, 0x02, 0x74, 0x41, 0x3e, 0x51, 0x50, 0x4b, 0x4a, 0x3e, 0xda, 0x04, 0xda, 0x00, 0x47, 0x30, 0x3e,
-    0xda, 0x04, 0xda, 0x00, 0x4b, 0x3c, 0xdc, 0x04, 0x5a, 0x40, 0xdc, 0x04, 0x74, 0x41, 0x3e, 0x51, 0x50, 0x47, 0x1c, 0xdc, 0x04,
-    0x5a, 0x40, 0xdc, 0x04, 0x74, 0x41, 0x3e, 0x51, 0x50, 0x4b, 0x22, 0xdc, 0x06, 0x5a, 0x40, 0xdc, 0x06, 0x74, 0x41, 0x3e, 0x51,
-    0x50, 0x4f, 0x08, 0x84, 0xff, 0x40, 0x18, 0x49, 0x08, 0xdc, 0x06, 0x5a, 0x40, 0xdc, 0x06, 0x74, 0x41, 0x3e, 0x51, 0x50, 0x43,
-    0x08, 0x84, 0x01, 0x40, 0x18, 0x49, 0x08, 0x80, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x14,
-    0x4c, 0x86, 0x76, 0x43, 0xa0, 0x7f, 0x98, 0xbb, 0x71, 0x00, 0x86, 0x43, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x0c, 0xa0, 0x4f, 0xb8,
-    0xc5, 0x00, 0x00, 0x2c, 0xcc, 0xf0, 0xef, 0xd0, 0x04, 0x00, 0x00, 0xea, 0x0c, 0x43, 0x40, 0x9c, 0x7f, 0x98, 0xbb, 0x71, 0x00,
-    0x40, 0x84, 0x40, 0x68, 0x70, 0x84, 0x7f, 0x98, 0xbb, 0x71, 0x00, 0x47, 0x84, 0x00, 0x48, 0x84, 0x47, 0x45, 0xdc, 0x02, 0x45,
-    0x40, 0x86, 0x50, 0x44, 0x7b, 0x0a, 0x18, 0x43, 0x08, 0x9c, 0x0c, 0x47, 0x90, 0x48, 0x3e, 0xc7, 0x02, 0x44, 0x4f, 0xf7, 0xfc,
-    0x0c, 0x47, 0x46, 0x24, 0x7f, 0x6c, 0xc7, 0x00, 0x00, 0x28, 0x78, 0x77, 0x0d, 0x3e, 0x7e, 0x44, 0x43, 0x08, 0x24, 0x7f, 0x76,
-    0xc7, 0x00, 0x00, 0xa0, 0x5a, 0x86, 0x44, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x45, 0xa0, 0x46, 0xa0, 0x78, 0x04, 0x7c, 0x40, 0x9c,
-    0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0x2c, 0xcc, 0xe4, 0xaf, 0xc1, 0x00, 0x28, 0x40,
-    0x7f, 0x08, 0x84, 0x01, 0x40, 0x18, 0x43, 0x08, 0x84, 0x45, 0x42, 0x7b, 0x1d, 0xdc, 0x08, 0x42, 0x40, 0x2b, 0x50, 0x7f, 0x12,
-    0xdc, 0x06, 0x42, 0x40, 0x3e, 0x50, 0x44, 0x47, 0x09, 0xdc, 0x08, 0x42, 0x40, 0x83, 0x50, 0x70, 0x9c, 0x0c, 0x42, 0x3c, 0x46,
-    0x42, 0x5f, 0xe2, 0x3c, 0x68, 0x47, 0x53, 0x77, 0x86, 0x43, 0xe4, 0x40, 0x3c, 0x40, 0x48, 0x4b, 0x41, 0x7b, 0x6c, 0x84, 0x45,
-    0x42, 0x7b, 0x30, 0xdc, 0x06, 0x42, 0x40, 0x3e, 0x50, 0xc7, 0x02, 0x77, 0x23, 0xdc, 0x04, 0x42, 0x40, 0x3e, 0x50, 0x57, 0x7f,
-    0x13, 0x3e, 0xc7, 0x02, 0xc7, 0x06, 0x77, 0x13, 0xdc, 0x04, 0x42, 0x40, 0x3e, 0x50, 0xc7, 0x04, 0x77, 0x09, 0xdc, 0x08, 0x42,
-    0x40, 0x83, 0x50, 0x70, 0x9c, 0x0c, 0x42, 0x3c, 0x46, 0x42, 0x5f, 0xcf, 0x9c, 0x0c, 0x47, 0x90, 0x48, 0x86, 0x44, 0xe4, 0x40,
-    0xde, 0x01, 0xc7, 0x02, 0x41, 0x3c, 0x41, 0x40, 0x47, 0x0b, 0x86, 0x43, 0xe4, 0x40, 0x3c, 0x40, 0x48, 0x4b, 0xae, 0xfc, 0x0c,
-    0x47, 0x46, 0x7b, 0x05, 0x9c, 0x0c, 0x45, 0x3c, 0x68, 0x45, 0x53, 0x0a, 0xdc, 0x08, 0x45, 0x40, 0x2b, 0x50, 0x7f, 0xf2, 0x96,
-    0x44, 0x3e, 0x7f, 0x9c, 0xbb, 0x71, 0x00, 0x44, 0x42, 0x1a, 0xff, 0x80, 0x40, 0x18, 0x43, 0x08, 0x70, 0x10, 0x43, 0x9c, 0x6f,
-    0x48, 0x4c, 0x86, 0x76, 0x43, 0x84, 0x78, 0x48, 0x84, 0x00, 0x44, 0x7b, 0x36, 0x18, 0x43, 0x08, 0x2b, 0xc8, 0x08, 0x7f, 0x2b,
-    0x3e, 0xc8, 0x06, 0xc8, 0x02, 0x7f, 0x24, 0x86, 0x43, 0xe4, 0x40, 0xa0, 0x40, 0xa0, 0x48, 0x2c, 0xcc, 0xf8, 0xaf, 0x5c, 0x01,
-    0x04, 0x59, 0x41, 0x70, 0x84, 0x44, 0x42, 0x90, 0x44, 0xd0, 0x01, 0x42, 0x42, 0x9c, 0x42, 0x41, 0x86, 0x40, 0x51, 0x70, 0x9c,
-    0x0c, 0x48, 0x3c, 0x7c, 0x48, 0x57, 0x07, 0x3c, 0x1f, 0x44, 0x4b, 0xc7, 0x3c, 0x02, 0x44, 0x43, 0x07, 0x80, 0x40, 0x18, 0x43,
-    0x08, 0x80, 0x45, 0x84, 0x01, 0x47, 0x7b, 0x66, 0x04, 0x59, 0x40, 0xfc, 0x01, 0x47, 0x41, 0xd0, 0x01, 0x41, 0x41, 0x9c, 0x41,
-    0x40, 0x04, 0x59, 0x41, 0xd0, 0x01, 0x47, 0x42, 0x9c, 0x42, 0x41, 0x3e, 0x51, 0x50, 0x4f, 0x47, 0x04, 0x59, 0x40, 0xfc, 0x01,
-    0x47, 0x41, 0xd0, 0x01, 0x41, 0x41, 0x9c, 0x41, 0x40, 0x86, 0x50, 0xe4, 0x46, 0x04, 0x59, 0x40, 0xfc, 0x01, 0x47, 0x41, 0xd0,
-    0x01, 0x41, 0x41, 0x9c, 0x41, 0x40, 0x04, 0x59, 0x41, 0xd0, 0x01, 0x47, 0x42, 0x9c, 0x42, 0x41, 0x86, 0x51, 0x50, 0x70, 0x04,
-    0x59, 0x40, 0xd0, 0x01, 0x47, 0x41, 0x9c, 0x41, 0x40, 0x84, 0x46, 0x41, 0x86, 0x41, 0x41, 0x86, 0x41, 0x50, 0x70, 0x84, 0x01,
-    0x45, 0x90, 0x47, 0x3c, 0x44, 0x47, 0x4b, 0x99, 0x28, 0x45, 0x77, 0x8e, 0x28, 0xca, 0x10, 0x77, 0x12, 0x3e, 0x43, 0xca, 0x16,
-    0x76, 0x7e, 0xff, 0x84, 0x01, 0x47, 0x24, 0x7f, 0xf8, 0xc8, 0x00, 0x00, 0x84, 0x01, 0x47, 0x7b, 0x62, 0xa0, 0x5a, 0x04, 0x59,
-    0x40, 0xfc, 0x01, 0x47, 0x41, 0xd0, 0x01, 0x41, 0x41, 0x9c, 0x41, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0x86, 0x43, 0xe4,
-    0x40, 0xa0, 0x40, 0x04, 0x59, 0x40, 0xd0, 0x01, 0x47, 0x41, 0x9c, 0x41, 0x40, 0x86, 0x50, 0xe4, 0x40, 0xa0, 0x40, 0xde, 0x01,
-    0x43, 0x40, 0xa0, 0x40, 0x04, 0xc9, 0x40, 0x42, 0x2c, 0xcc, 0xf0, 0xef, 0x60, 0x03, 0x00, 0x00, 0x9c, 0x08, 0x4c, 0x04, 0xcc,
-    0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x10, 0xa0, 0xca, 0x18, 0x2c, 0xcc, 0xec,
-    0xef, 0x08, 0x03, 0x00, 0x00, 0x9c, 0x02, 0x47, 0x3c, 0x44, 0x47, 0x4b, 0x9d, 0x7a, 0x08, 0xff, 0x04, 0x59, 0x40, 0xfc, 0x01,
-    0x47, 0x41, 0xd0, 0x01, 0x41, 0x41, 0x9c, 0x41, 0x40, 0x3e, 0xca, 0x14, 0x50, 0x47, 0x18, 0x04, 0x59, 0x40, 0xd0, 0x01, 0x47,
-    0x41, 0x9c, 0x41, 0x40, 0x3e, 0x50, 0xca, 0x14, 0x47, 0x08, 0x84, 0x01, 0x40, 0x18, 0x43, 0x08, 0x9c, 0x02, 0x47, 0x3c, 0x44,
-    0x47, 0x4b, 0xd0, 0x7a, 0xd3, 0xfe, 0x10, 0x43, 0x9c, 0x20, 0x4c, 0x86, 0xda, 0x04, 0xe4, 0x47, 0xdc, 0x02, 0x74, 0x40, 0x86,
-    0x50, 0xe4, 0x42, 0xdc, 0x04, 0x74, 0x40, 0x86, 0x50, 0xe4, 0x46, 0xdc, 0x06, 0x74, 0x40, 0x86, 0x50, 0xe4, 0x48, 0x3c, 0x48,
-    0x42, 0x77, 0x19, 0x3c, 0x46, 0x47, 0x4f, 0x07, 0x84, 0x47, 0x45, 0x7b, 0x05, 0x84, 0x46, 0x45, 0x84, 0x45, 0x40, 0x86, 0x40,
-    0xe4, 0x40, 0x18, 0x43, 0x08, 0xfc, 0x48, 0x42, 0x64, 0x70, 0xfc, 0x46, 0x47, 0x43, 0xe8, 0x46, 0x42, 0x40, 0xe8, 0x48, 0x47,
-    0x41, 0xbc, 0x41, 0x40, 0x84, 0x40, 0x44, 0xe8, 0x43, 0x5a, 0x40, 0x9c, 0x44, 0x40, 0xac, 0x64, 0x40, 0x70, 0x84, 0x40, 0x45,
-    0x86, 0x45, 0xe4, 0x40, 0x18, 0x43, 0x08, 0x18, 0x43, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00,
-    0x41, 0x04, 0x5a, 0x40, 0x84, 0x50, 0x51, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x74, 0x2c, 0xcc, 0xf4, 0xef, 0xc8, 0x02, 0x00, 0x00, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x41,
-    0x04, 0x5a, 0x40, 0x84, 0x50, 0x51, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9e, 0x5a, 0xef, 0x44, 0x04, 0x00,
-    0x00, 0x70, 0xdc, 0x02, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x40, 0x9e, 0x72, 0x50, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10,
-    0x49, 0x9c, 0x08, 0x4c, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51,
-    0x70, 0x04, 0x5a, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x64, 0x42, 0x2c, 0xcc, 0xf8,
-    0xef, 0x10, 0x02, 0x00, 0x00, 0x04, 0x59, 0x41, 0x84, 0x50, 0x51, 0x70, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x40, 0x9c, 0x04,
-    0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0xa0, 0x74, 0x2c, 0xcc, 0xf4, 0xef, 0xc8, 0x02, 0x00, 0x00, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x41, 0x04, 0x59,
-    0x40, 0x84, 0x50, 0x51, 0x70, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0xa0, 0x7f, 0x0c, 0x04, 0x00, 0x00, 0x04, 0x5a, 0x40,
-    0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41,
-    0x84, 0x50, 0x51, 0x70, 0xa0, 0x78, 0x2c, 0xcc, 0xf0, 0xef, 0x84, 0x03, 0x00, 0x00, 0x84, 0x7f, 0x44, 0x04, 0x00, 0x00, 0x41,
-    0x04, 0x74, 0x40, 0x84, 0x50, 0x51, 0x70, 0x18, 0x49, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0x3e, 0x78, 0x74, 0x77, 0x07,
-    0x3e, 0x7a, 0x76, 0x7f, 0x6e, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x78,
-    0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x59, 0x42, 0x2c, 0xcc, 0xf8, 0xaf, 0xbc, 0x01,
-    0x04, 0x78, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x5a, 0xdc, 0x08, 0x5a, 0x40, 0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84,
-    0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50,
-    0x51, 0x70, 0x04, 0x78, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0x7c, 0x2c, 0xcc, 0xe8,
-    0x7f, 0x44, 0xce, 0x00, 0x00, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x10, 0x49, 0x84, 0x5a, 0x41, 0x84, 0x74, 0x42, 0x47, 0x21,
-    0x28, 0x42, 0x77, 0x0f, 0x84, 0x41, 0x40, 0x86, 0x40, 0x40, 0x86, 0x40, 0xe4, 0x40, 0x18, 0x49, 0x08, 0x8c, 0x42, 0x40, 0x70,
-    0x84, 0x40, 0x42, 0x8c, 0x41, 0x40, 0x70, 0x84, 0x40, 0x41, 0x28, 0x41, 0x43, 0x09, 0xfc, 0x01, 0x42, 0x40, 0xbc, 0x40, 0x41,
-    0xec, 0x42, 0x41, 0x40, 0x86, 0x40, 0xe4, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x49, 0x84, 0x5a, 0x41,
-    0x84, 0x74, 0x42, 0x47, 0x21, 0x28, 0x42, 0x77, 0x0f, 0x84, 0x41, 0x40, 0x86, 0x40, 0x40, 0x86, 0x40, 0xe4, 0x40, 0x18, 0x49,
-    0x08, 0x8c, 0x42, 0x40, 0x70, 0x84, 0x40, 0x42, 0x8c, 0x41, 0x40, 0x70, 0x84, 0x40, 0x41, 0x28, 0x41, 0x4f, 0x09, 0xfc, 0x01,
-    0x42, 0x40, 0x9c, 0x40, 0x41, 0xec, 0x42, 0x41, 0x40, 0x86, 0x40, 0xe4, 0x40, 0x18, 0x49, 0x08, 0x18, 0x49, 0x08, 0x70, 0x70,
-    0x10, 0x48, 0x84, 0x5a, 0x42, 0xfc, 0x7f, 0xa0, 0xbb, 0x71, 0x00, 0x42, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x86, 0x7f, 0xa8, 0xbb,
-    0x71, 0x00, 0xe4, 0x41, 0xa8, 0x41, 0x40, 0x86, 0x7f, 0xaa, 0xbb, 0x71, 0x00, 0xe4, 0x41, 0x9c, 0x41, 0x40, 0xa0, 0x40, 0x86,
-    0x7f, 0xaa, 0xbb, 0x71, 0x00, 0xe4, 0x40, 0xd0, 0x01, 0x40, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x8c, 0x02, 0x00, 0x00,
-    0x9c, 0x7f, 0xa4, 0xbb, 0x71, 0x00, 0x40, 0x70, 0x84, 0x40, 0x48, 0x2a, 0x7f, 0xac, 0xbb, 0x71, 0x00, 0x7f, 0x08, 0x8c, 0x48,
-    0x40, 0x18, 0x48, 0x08, 0x84, 0x48, 0x40, 0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x47, 0x84, 0x5a, 0x48,
-    0x2a, 0x7f, 0xac, 0xbb, 0x71, 0x00, 0x7f, 0x07, 0x8c, 0x48, 0x40, 0x7b, 0x05, 0x84, 0x48, 0x40, 0xbc, 0x7f, 0xa4, 0xbb, 0x71,
-    0x00, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x86, 0x7f, 0xaa, 0xbb, 0x71, 0x00, 0xe4, 0x41, 0xa8, 0x41, 0x40, 0x86, 0x7f, 0xa8, 0xbb,
-    0x71, 0x00, 0xe4, 0x41, 0xbc, 0x41, 0x40, 0xa0, 0x40, 0x86, 0x7f, 0xa8, 0xbb, 0x71, 0x00, 0xe4, 0x40, 0xd0, 0x01, 0x40, 0x40,
-    0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xef, 0x44, 0x02, 0x00, 0x00, 0x9c, 0x7f, 0xa0, 0xbb, 0x71, 0x00, 0x40, 0x70, 0x84, 0x40, 0x47,
-    0x2a, 0x7f, 0xa8, 0xbb, 0x71, 0x00, 0x7f, 0x1a, 0x7b, 0x07, 0x18, 0x47, 0x08, 0x94, 0x47, 0xfc, 0x01, 0x47, 0x40, 0xa0, 0x40,
-    0x2c, 0xcc, 0xfc, 0xaf, 0x2e, 0xff, 0x3c, 0x48, 0x40, 0x7f, 0xef, 0x84, 0x47, 0x40, 0x18, 0x47, 0x08, 0x70, 0x10, 0x45, 0x9c,
-    0x10, 0x4c, 0x84, 0x42, 0x59, 0x70, 0x82, 0x45, 0x86, 0x01, 0x7f, 0xae, 0xbb, 0x71, 0x00, 0x70, 0x82, 0x7f, 0xac, 0xbb, 0x71,
-    0x00, 0x70, 0xfe, 0x5a, 0x74, 0x40, 0x70, 0x84, 0x40, 0x48, 0xfe, 0x72, 0x76, 0x40, 0x70, 0x84, 0x40, 0x47, 0xa0, 0x48, 0x2c,
-    0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00, 0x84, 0x40, 0x6c, 0x70, 0xa0, 0x47, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00,
-    0x3c, 0x6c, 0x40, 0x4f, 0x2c, 0x82, 0x7f, 0xae, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x5a, 0xe4, 0x46, 0x86, 0x72, 0x5a, 0x70, 0x84,
-    0x46, 0xe6, 0x72, 0x70, 0x86, 0x74, 0xe4, 0x46, 0x86, 0x76, 0x74, 0x70, 0x84, 0x46, 0xe6, 0x76, 0x70, 0x84, 0x48, 0x46, 0x84,
-    0x47, 0x48, 0x84, 0x46, 0x47, 0x28, 0x48, 0x43, 0x2c, 0x92, 0x45, 0x86, 0x5a, 0xe4, 0x46, 0x86, 0x74, 0x5a, 0x70, 0x84, 0x46,
-    0xe6, 0x74, 0x70, 0x86, 0x72, 0xe4, 0x46, 0x86, 0x76, 0x72, 0x70, 0x84, 0x46, 0xe6, 0x76, 0x70, 0x8c, 0x48, 0x40, 0x70, 0x84,
-    0x40, 0x48, 0x8c, 0x47, 0x40, 0x70, 0x84, 0x40, 0x47, 0x28, 0x47, 0x43, 0x1e, 0x92, 0x7f, 0xac, 0xbb, 0x71, 0x00, 0x70, 0x8c,
-    0x47, 0x40, 0x70, 0x84, 0x40, 0x47, 0x8e, 0x72, 0x40, 0x86, 0x40, 0x72, 0x70, 0x8e, 0x76, 0x40, 0x86, 0x40, 0x76, 0x70, 0x84,
-    0x47, 0xe6, 0x7f, 0xa8, 0xbb, 0x71, 0x00, 0x70, 0x84, 0x48, 0xe6, 0x7f, 0xaa, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x5a, 0xe4, 0x7f,
-    0xa0, 0xbb, 0x71, 0x00, 0x70, 0x86, 0x72, 0xe4, 0x7f, 0xa4, 0xbb, 0x71, 0x00, 0x70, 0x2a, 0x45, 0x7f, 0x08, 0xde, 0x01, 0x5a,
-    0x40, 0x7b, 0x06, 0xfe, 0x01, 0x74, 0x40, 0x86, 0x40, 0x64, 0x70, 0x86, 0x40, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xaf,
-    0x21, 0xfe, 0x86, 0x40, 0x66, 0x70, 0x2a, 0x7f, 0xae, 0xbb, 0x71, 0x00, 0x77, 0x0f, 0x86, 0x64, 0xe4, 0x46, 0x86, 0x66, 0x64,
-    0x70, 0x84, 0x46, 0xe6, 0x66, 0x70, 0x84, 0x59, 0x41, 0x04, 0x64, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x59, 0x40, 0x18, 0x45,
-    0x08, 0x70, 0x70, 0x10, 0x48, 0x84, 0x5a, 0x48, 0xa0, 0x48, 0x2c, 0xcc, 0xfc, 0xaf, 0xe9, 0xfd, 0x84, 0x40, 0x42, 0x2a, 0x7f,
-    0xac, 0xbb, 0x71, 0x00, 0x7f, 0x09, 0x8c, 0x42, 0x40, 0x70, 0x84, 0x40, 0x42, 0xfc, 0x7f, 0xa0, 0xbb, 0x71, 0x00, 0x48, 0x40,
-    0xd0, 0x01, 0x40, 0x40, 0x9c, 0x02, 0x40, 0xaa, 0x7f, 0xa8, 0xbb, 0x71, 0x00, 0x40, 0xfc, 0x7f, 0xa4, 0xbb, 0x71, 0x00, 0x42,
-    0x41, 0xd0, 0x01, 0x41, 0x41, 0x90, 0x41, 0xaa, 0x7f, 0xaa, 0xbb, 0x71, 0x00, 0x41, 0xbe, 0x41, 0x40, 0x86, 0x40, 0xe4, 0x40,
-    0x18, 0x48, 0x08, 0x18, 0x48, 0x08, 0x70, 0x70, 0x70, 0x10, 0x49, 0x9c, 0x04, 0x4c, 0x3e, 0x76, 0x72, 0x43, 0x07, 0x84, 0x04,
-    0x40, 0x7b, 0x0e, 0x3e, 0x7a, 0x72, 0x4b, 0x07, 0x84, 0x08, 0x40, 0x7b, 0x04, 0x80, 0x40, 0x84, 0x40, 0x59, 0x70, 0x3e, 0x74,
-    0x5a, 0x43, 0x0b, 0x84, 0x01, 0x40, 0xb0, 0x59, 0x40, 0x18, 0x49, 0x08, 0x3e, 0x78, 0x5a, 0x4b, 0x07, 0x84, 0x02, 0x40, 0x7b,
-    0xf2, 0x80, 0x40, 0x7b, 0xee, 0x18, 0x49, 0x08, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x10, 0x4c, 0x84, 0x5a, 0x48, 0x80, 0x68, 0x70,
-    0x2a, 0x7f, 0xae, 0xbb, 0x71, 0x00, 0x77, 0x3d, 0x86, 0x7c, 0xe4, 0x47, 0x86, 0x7e, 0x7c, 0x70, 0x84, 0x47, 0xe6, 0x7e, 0x70,
-    0x86, 0xca, 0x10, 0xe4, 0x47, 0x86, 0xca, 0x12, 0xca, 0x10, 0x70, 0x84, 0x47, 0xe6, 0xca, 0x12, 0x70, 0x86, 0x74, 0xe4, 0x47,
-    0x86, 0x76, 0x74, 0x70, 0x84, 0x47, 0xe6, 0x76, 0x70, 0x86, 0x78, 0xe4, 0x47, 0x86, 0x7a, 0x78, 0x70, 0x84, 0x47, 0xe6, 0x7a,
-    0x70, 0x90, 0x68, 0x70, 0x86, 0x74, 0xe4, 0x45, 0x86, 0x78, 0xe4, 0x44, 0x86, 0x76, 0xe4, 0x43, 0x86, 0x7a, 0xe4, 0x59, 0x70,
-    0x04, 0x7c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x08, 0x4c, 0x04,
-    0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xaf, 0x3f, 0xff, 0x84, 0x40,
-    0x47, 0x04, 0xca, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40, 0x9c, 0x08,
-    0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xaf, 0x13, 0xff,
-    0x84, 0x40, 0x46, 0x24, 0x7f, 0x37, 0xd0, 0x00, 0x00, 0x38, 0x47, 0x46, 0x77, 0x5f, 0x28, 0x47, 0x77, 0x2e, 0x04, 0x64, 0x41,
-    0x04, 0x7c, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0x7c, 0x41, 0x04, 0xca, 0x10, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0xca, 0x10,
-    0x41, 0x04, 0x64, 0x40, 0x84, 0x50, 0x51, 0x70, 0x84, 0x47, 0xe6, 0x6c, 0x70, 0x84, 0x46, 0x47, 0x86, 0x6c, 0xe4, 0x46, 0x3c,
-    0x00, 0x47, 0x77, 0x08, 0x24, 0x7f, 0x3e, 0xd0, 0x00, 0x00, 0x38, 0x47, 0x01, 0x7f, 0x22, 0x84, 0x45, 0xe6, 0x7c, 0x70, 0x86,
-    0x45, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0x9a, 0xcb, 0x00, 0x00, 0x86, 0x40, 0x7e, 0x70, 0x24, 0x7f, 0x0c, 0xd0,
-    0x00, 0x00, 0x18, 0x43, 0x08, 0x38, 0x47, 0x02, 0x7f, 0x26, 0x84, 0x44, 0x40, 0x86, 0x40, 0x40, 0x96, 0x40, 0x86, 0x40, 0x7c,
-    0x70, 0x86, 0x40, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0x9a, 0xcb, 0x00, 0x00, 0x86, 0x40, 0x7e, 0x70, 0x24, 0x7f,
-    0x0c, 0xd0, 0x00, 0x00, 0x38, 0x47, 0x04, 0x7f, 0x3a, 0x2a, 0x7f, 0xac, 0xbb, 0x71, 0x00, 0x7f, 0x1d, 0x84, 0x43, 0xe6, 0x7e,
-    0x70, 0xfe, 0x01, 0x7e, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xfe, 0xcb, 0x00, 0x00, 0x96, 0x40, 0x86, 0x40, 0x7c, 0x70,
-    0x7b, 0x58, 0x84, 0x43, 0xe6, 0x7e, 0x70, 0xa0, 0x43, 0x2c, 0xcc, 0xfc, 0x7f, 0xfe, 0xcb, 0x00, 0x00, 0x86, 0x40, 0x7c, 0x70,
-    0x7b, 0x43, 0x38, 0x47, 0x08, 0x7f, 0x3e, 0x2a, 0x7f, 0xac, 0xbb, 0x71, 0x00, 0x7f, 0x1e, 0xfe, 0x01, 0x62, 0x40, 0x86, 0x40,
-    0x7e, 0x70, 0xfc, 0x01, 0x59, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xfe, 0xcb, 0x00, 0x00, 0x86, 0x40, 0x7c, 0x70, 0x7b,
-    0x1a, 0xfe, 0x01, 0x62, 0x40, 0x86, 0x40, 0x7e, 0x70, 0xa0, 0x59, 0x2c, 0xcc, 0xfc, 0x7f, 0xfe, 0xcb, 0x00, 0x00, 0x96, 0x40,
-    0x86, 0x40, 0x7c, 0x70, 0x04, 0x7c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x40,
-    0x9c, 0x08, 0x4c, 0x04, 0xcc, 0xf8, 0x41, 0x84, 0xc0, 0x04, 0xc1, 0x04, 0x70, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf4, 0xaf,
-    0xd6, 0xfd, 0x84, 0x40, 0x47, 0xf0, 0x46, 0x47, 0x40, 0x76, 0xc5, 0xfe, 0x28, 0x68, 0x7f, 0x20, 0x86, 0x7c, 0xe4, 0x47, 0x86,
-    0x7e, 0x7c, 0x70, 0x84, 0x47, 0xe6, 0x7e, 0x70, 0x86, 0xca, 0x10, 0xe4, 0x47, 0x86, 0xca, 0x12, 0xca, 0x10, 0x70, 0x84, 0x47,
-    0xe6, 0xca, 0x12, 0x70, 0xa0, 0x48, 0x04, 0x7c, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x04,
-    0xca, 0x10, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0xa0, 0xca, 0x14, 0x2c, 0xcc, 0xf0, 0x7f,
-    0x90, 0xd0, 0x00, 0x00, 0x18, 0x43, 0x08, 0x70, 0x70, 0x70, 0x10, 0x43, 0x9c, 0x18, 0x4c, 0x84, 0x5a, 0x48, 0x2a, 0x7f, 0xae,
-    0xbb, 0x71, 0x00, 0x77, 0x34, 0x3e, 0x7a, 0x76, 0x4f, 0x22, 0x04, 0xc9, 0x14, 0x41, 0x04, 0x74, 0x40, 0x84, 0x50, 0x51, 0x70,
-    0x04, 0x74, 0x41, 0x04, 0x78, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0x78, 0x41, 0x04, 0xc9, 0x14, 0x40, 0x84, 0x50, 0x51, 0x70,
-    0x3e, 0x78, 0x74, 0x4f, 0x2f, 0xb0, 0x5f, 0x00, 0x04, 0x7c, 0x70, 0x7b, 0x27, 0x3e, 0x78, 0x74, 0x4f, 0x22, 0x04, 0xc9, 0x14,
-    0x41, 0x04, 0x74, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0x74, 0x41, 0x04, 0x78, 0x40, 0x84, 0x50, 0x51, 0x70, 0x04, 0x78, 0x41,
-    0x04, 0xc9, 0x14, 0x40, 0x84, 0x50, 0x51, 0x70, 0x86, 0x74, 0x64, 0x70, 0x86, 0x76, 0x66, 0x70, 0xfe, 0x64, 0x78, 0x40, 0x86,
-    0x40, 0x59, 0x70, 0xfe, 0x66, 0x7a, 0x40, 0x86, 0x40, 0x62, 0x70, 0x4f, 0x07, 0x84, 0x01, 0x46, 0x7b, 0x05, 0x84, 0xff, 0x46,
-    0xa0, 0x48, 0x04, 0x74, 0x40, 0x9c, 0x04, 0x4c, 0x04, 0xcc, 0xfc, 0x41, 0x84, 0x50, 0x51, 0x70, 0x2c, 0xcc, 0xf8, 0xef, 0x14,
-    0x02, 0x00, 0x00, 0x84, 0x40, 0x44, 0xe8, 0xc8, 0x04, 0x46, 0x43, 0xfa, 0x1f, 0x64, 0x40, 0xfc, 0x40, 0x1f, 0x40, 0xd0, 0x40,
-    0x01, 0x40, 0x84, 0x40, 0x45, 0x86, 0x59, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00, 0x86, 0x40,
-    0x59, 0x70, 0x86, 0x62, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0xef, 0x00, 0x02, 0x00, 0x00, 0x86, 0x40, 0x62, 0x70, 0x2a,
-    0x7f, 0xae, 0xbb, 0x71, 0x00, 0x77, 0x08, 0x24, 0x7f, 0x08, 0xd3, 0x00, 0x00, 0xde, 0x01, 0x59, 0x40, 0x84, 0x40, 0xc9, 0x10,
-    0x70, 0x2a, 0x62, 0x7f, 0x08, 0x24, 0x7f, 0xc0, 0xd2, 0x00, 0x00, 0xde, 0xc9, 0x12, 0x64, 0x40, 0x86, 0x40, 0x66, 0x70, 0x3a,
-    0x64, 0x1f, 0x77, 0x08, 0x24, 0x7f, 0x9e, 0xd2, 0x00, 0x00, 0xfa, 0x1f, 0x64, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68,
-    0x04, 0x00, 0x00, 0x40, 0x84, 0x50, 0x47, 0x84, 0x7c, 0x40, 0x3c, 0x03, 0x40, 0x4f, 0x08, 0x24, 0x7f, 0x9e, 0xd2, 0x00, 0x00,
-    0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x43, 0x08, 0x24, 0x7f, 0x9e, 0xd2, 0x00, 0x00, 0x24, 0x90, 0xac, 0x06, 0x01, 0x00, 0xfa,
-    0x6f, 0xe0, 0x64, 0x40, 0xfa, 0x6f, 0xe0, 0x66, 0x41, 0x3c, 0x41, 0x40, 0x77, 0x1b, 0xfa, 0x1f, 0x66, 0x40, 0xd0, 0x02, 0x40,
-    0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xb4, 0x50, 0x47, 0xb0, 0x47, 0x54, 0x70, 0x18, 0x43, 0x08, 0x84, 0x44, 0x40,
-    0x9c, 0x04, 0x44, 0x88, 0x47, 0x41, 0xb0, 0x41, 0x50, 0x70, 0xf2, 0x1f, 0x64, 0x40, 0x9e, 0x01, 0x40, 0x86, 0x40, 0x64, 0x70,
-    0x24, 0x7f, 0x9e, 0xd2, 0x00, 0x00, 0xfa, 0x6f, 0xe0, 0x64, 0x40, 0xfa, 0x6f, 0xe0, 0x66, 0x41, 0x3c, 0x41, 0x40, 0x77, 0x1f,
-    0xfa, 0x1f, 0x66, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xb4, 0x50, 0x47, 0x70, 0x88, 0x47,
-    0x40, 0xb8, 0x40, 0x54, 0x70, 0x18, 0x43, 0x08, 0x84, 0x44, 0x40, 0x9c, 0x04, 0x44, 0xb8, 0x47, 0x50, 0x70, 0xf2, 0x1f, 0x64,
-    0x40, 0x9e, 0x01, 0x40, 0x86, 0x40, 0x64, 0x70, 0x7b, 0x42, 0xfa, 0x6f, 0xe0, 0x64, 0x40, 0xfa, 0x6f, 0xe0, 0x66, 0x41, 0x3c,
-    0x41, 0x40, 0x77, 0x1b, 0xfa, 0x1f, 0x66, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xb4, 0x50,
-    0x47, 0xb4, 0x47, 0x54, 0x70, 0x18, 0x43, 0x08, 0x84, 0x44, 0x40, 0x9c, 0x04, 0x44, 0x88, 0x47, 0x41, 0xb4, 0x41, 0x50, 0x70,
-    0xf2, 0x1f, 0x64, 0x40, 0x9e, 0x01, 0x40, 0x86, 0x40, 0x64, 0x70, 0x84, 0x7c, 0x40, 0x3c, 0x03, 0x40, 0x4f, 0x08, 0x24, 0x7f,
-    0xa8, 0xd3, 0x00, 0x00, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x43, 0x08, 0x24, 0x7f, 0xa8, 0xd3, 0x00, 0x00, 0x24, 0x90, 0xbc,
-    0x06, 0x01, 0x00, 0x86, 0x64, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xaa, 0xcd, 0x00, 0x00, 0x84, 0x40, 0x47, 0x86,
-    0x7f, 0xa8, 0xbb, 0x71, 0x00, 0xe4, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x84, 0x40, 0x68, 0x70, 0x86, 0x7f, 0xaa, 0xbb, 0x71, 0x00,
-    0xe4, 0x40, 0xd0, 0x01, 0x40, 0x40, 0xfc, 0x40, 0x68, 0x6c, 0x70, 0x84, 0x7c, 0x40, 0x3c, 0x03, 0x40, 0x47, 0x10, 0xc0, 0x02,
-    0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24, 0x90, 0xcc, 0x06, 0x01, 0x00, 0xde, 0x01, 0x62, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70,
-    0x2a, 0x59, 0x77, 0x18, 0x84, 0x7c, 0x40, 0x3c, 0x03, 0x40, 0x47, 0x10, 0xc0, 0x02, 0x40, 0x40, 0x28, 0x40, 0x4b, 0x08, 0x24,
-    0x90, 0xdc, 0x06, 0x01, 0x00, 0x86, 0x66, 0xe4, 0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xfc, 0x7f, 0xaa, 0xcd, 0x00, 0x00, 0x84, 0x40,
-    0x47, 0x86, 0x7f, 0xa8, 0xbb, 0x71, 0x00, 0xe4, 0x40, 0xd0, 0x01, 0x40, 0x40, 0x84, 0x40, 0x68, 0x70, 0x86, 0x7f, 0xaa, 0xbb,
-    0x71, 0x00, 0xe4, 0x40, 0xd0, 0x01, 0x40, 0x40, 0xfc, 0x40, 0x68, 0x6c, 0x70, 0x84, 0x7c, 0x40, 0x3c, 0x40, 0x00, 0x7f, 0x64,
-    0x3c, 0x40, 0x01, 0x7f, 0x5f, 0x3c, 0x40, 0x02, 0x77, 0x08, 0x24, 0x7f, 0x27, 0xd4, 0x00, 0x00, 0x3c, 0x40, 0x03, 0x77, 0x08,
-    0x24, 0x7f, 0x8d, 0xd4, 0x00, 0x00, 0x3c, 0x40, 0x5f, 0x00, 0x04, 0x7f, 0x74, 0x3c, 0x40, 0x5f, 0x01, 0x04, 0x7f, 0x6d, 0x3c,
-    0x40, 0x5f, 0x02, 0x04, 0x77, 0x08, 0x24, 0x7f, 0x5c, 0xd4, 0x00, 0x00, 0x3c, 0x40, 0x5f, 0x03, 0x04, 0x77, 0x08, 0x24, 0x7f,
-    0xbf, 0xd4, 0x00, 0x00, 0x18, 0x43, 0x08, 0x88, 0x45, 0x40, 0x84, 0x40, 0x45, 0xb8, 0x45, 0x54, 0x70, 0x94, 0xc9, 0x10, 0x70,
-    0x7f, 0x0b, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x7b, 0xef, 0x18, 0x43, 0x08, 0xb0, 0x45, 0x54, 0x70, 0x94, 0xc9, 0x10,
-    0x70, 0x7f, 0x0b, 0x28, 0x47, 0x43, 0x0a, 0x9c, 0x68, 0x47, 0x7b, 0x18, 0x18, 0x43, 0x08, 0x9c, 0x6c, 0x47, 0xd4, 0x01, 0x45,
-    0x45, 0x77, 0x0c, 0x84, 0x4f, 0x00, 0x00, 0x00, 0x80, 0x45, 0x9c, 0x04, 0x44, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x7b,
-    0xd0, 0xb0, 0x45, 0x54, 0x70, 0x94, 0xc9, 0x10, 0x70, 0x7f, 0x0b, 0x28, 0x47, 0x43, 0x0a, 0x9c, 0x68, 0x47, 0x7b, 0x14, 0x18,
-    0x43, 0x08, 0x9c, 0x6c, 0x47, 0xd0, 0x01, 0x45, 0x45, 0x77, 0x08, 0xbc, 0x04, 0x44, 0x84, 0x01, 0x45, 0xd0, 0x02, 0x43, 0x40,
-    0x9c, 0x40, 0x44, 0x7b, 0xd4, 0x88, 0x45, 0x40, 0xb8, 0x40, 0x54, 0x70, 0x94, 0xc9, 0x10, 0x70, 0x7f, 0x0b, 0x28, 0x47, 0x43,
-    0x0a, 0x9c, 0x68, 0x47, 0x7b, 0x18, 0x18, 0x43, 0x08, 0x9c, 0x6c, 0x47, 0xd4, 0x01, 0x45, 0x45, 0x77, 0x0c, 0x84, 0x4f, 0x00,
-    0x00, 0x00, 0x80, 0x45, 0x9c, 0x04, 0x44, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x7b, 0xcd, 0x88, 0x45, 0x40, 0xb8, 0x40,
-    0x54, 0x70, 0x94, 0xc9, 0x10, 0x70, 0x7f, 0x0b, 0x28, 0x47, 0x43, 0x0a, 0x9c, 0x68, 0x47, 0x7b, 0x14, 0x18, 0x43, 0x08, 0x9c,
-    0x6c, 0x47, 0xd0, 0x01, 0x45, 0x45, 0x77, 0x08, 0xbc, 0x04, 0x44, 0x84, 0x01, 0x45, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44,
-    0x7b, 0xd1, 0xb4, 0x45, 0x54, 0x70, 0x94, 0xc9, 0x10, 0x70, 0x7f, 0x0b, 0x28, 0x47, 0x43, 0x0a, 0x9c, 0x68, 0x47, 0x7b, 0x18,
-    0x18, 0x43, 0x08, 0x9c, 0x6c, 0x47, 0xd4, 0x01, 0x45, 0x45, 0x77, 0x0c, 0x84, 0x4f, 0x00, 0x00, 0x00, 0x80, 0x45, 0x9c, 0x04,
-    0x44, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x7b, 0xd0, 0xb4, 0x45, 0x54, 0x70, 0x94, 0xc9, 0x10, 0x70, 0x7f, 0x0b, 0x28,
-    0x47, 0x43, 0x0a, 0x9c, 0x68, 0x47, 0x7b, 0x14, 0x18, 0x43, 0x08, 0x9c, 0x6c, 0x47, 0xd0, 0x01, 0x45, 0x45, 0x77, 0x08, 0xbc,
-    0x04, 0x44, 0x84, 0x01, 0x45, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x7b, 0xd4, 0x84, 0x44, 0x40, 0x84, 0xff, 0x50, 0x70,
-    0x9c, 0x04, 0x44, 0x9e, 0x20, 0x64, 0x70, 0x3e, 0x66, 0x64, 0x4f, 0xef, 0x3a, 0x66, 0x1f, 0x7e, 0xa5, 0xfe, 0xfa, 0x1f, 0x66,
-    0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0xb0, 0x50, 0x54, 0x70, 0x7a, 0x8f, 0xfe, 0x84, 0x44,
-    0x40, 0x80, 0x50, 0x70, 0x9c, 0x04, 0x44, 0x9e, 0x20, 0x64, 0x70, 0x3e, 0x66, 0x64, 0x4f, 0xf0, 0x3a, 0x66, 0x1f, 0x7e, 0x77,
-    0xfe, 0xfa, 0x1f, 0x66, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04, 0x00, 0x00, 0x40, 0x88, 0x50, 0x40, 0xb8, 0x40,
-    0x54, 0x70, 0x7a, 0x5e, 0xfe, 0x84, 0x44, 0x40, 0x9c, 0x04, 0x44, 0xb4, 0xff, 0x50, 0x70, 0x9e, 0x20, 0x64, 0x70, 0x3e, 0x66,
-    0x64, 0x4f, 0xef, 0x3a, 0x66, 0x1f, 0x7e, 0x45, 0xfe, 0xfa, 0x1f, 0x66, 0x40, 0xd0, 0x02, 0x40, 0x40, 0x9c, 0x7f, 0x68, 0x04,
-    0x00, 0x00, 0x40, 0xb4, 0x50, 0x54, 0x70, 0x7a, 0x2f, 0xfe, 0x9c, 0x68, 0x47, 0x7b, 0x0c, 0x9c, 0x6c, 0x47, 0xd0, 0x02, 0x43,
-    0x40, 0x9c, 0x40, 0x44, 0xb0, 0x45, 0x54, 0x70, 0x94, 0xc9, 0x10, 0x70, 0x7f, 0x18, 0xd4, 0x01, 0x45, 0x45, 0x77, 0x0c, 0x84,
-    0x4f, 0x00, 0x00, 0x00, 0x80, 0x45, 0x9c, 0x04, 0x44, 0x28, 0x47, 0x43, 0xda, 0x7b, 0xd3, 0x18, 0x43, 0x08, 0x9c, 0x68, 0x47,
-    0x7b, 0x0c, 0x9c, 0x6c, 0x47, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x88, 0x45, 0x40, 0xb8, 0x40, 0x54, 0x70, 0x94, 0xc9,
-    0x10, 0x70, 0x7f, 0x18, 0xd4, 0x01, 0x45, 0x45, 0x77, 0x0c, 0x84, 0x4f, 0x00, 0x00, 0x00, 0x80, 0x45, 0x9c, 0x04, 0x44, 0x28,
-    0x47, 0x43, 0xd7, 0x7b, 0xd0, 0x18, 0x43, 0x08, 0x9c, 0x68, 0x47, 0x7b, 0x0c, 0x9c, 0x6c, 0x47, 0xd0, 0x02, 0x43, 0x40, 0x9c,
-    0x40, 0x44, 0xb4, 0x45, 0x54, 0x70, 0x94, 0xc9, 0x10, 0x70, 0x7f, 0x18, 0xd4, 0x01, 0x45, 0x45, 0x77, 0x0c, 0x84, 0x4f, 0x00,
-    0x00, 0x00, 0x80, 0x45, 0x9c, 0x04, 0x44, 0x28, 0x47, 0x43, 0xda, 0x7b, 0xd3, 0x18, 0x43, 0x08, 0xb0, 0x45, 0x54, 0x70, 0x94,
-    0xc9, 0x10, 0x70, 0x7f, 0x0b, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x7b, 0xef, 0x18, 0x43, 0x08, 0xb4, 0x45, 0x54, 0x70,
-    0x94, 0xc9, 0x10, 0x70, 0x7f, 0x0b, 0xd0, 0x02, 0x43, 0x40, 0x9c, 0x40, 0x44, 0x7b, 0xef, 0x18, 0x43, 0x08, 0x70, 0x70, 0x70,
-    0x10, 0x48, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x5a, 0x48, 0x3c, 0x00, 0x48, 0x4b, 0x07, 0x84, 0x48, 0x40, 0x7b,
-    0x05, 0x8c, 0x48, 0x40, 0x7b, 0x02, 0x18, 0x48, 0x08, 0x70, 0x70, 0x10, 0x47, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84,
-    0x5a, 0x48, 0xdc, 0x01, 0x48, 0x40, 0x84, 0x40, 0x47, 0x7b, 0x02, 0x84, 0x48, 0x40, 0x90, 0x48, 0x2b, 0x50, 0x77, 0xf9, 0xfc,
-    0x47, 0x48, 0x40, 0x7b, 0x02, 0x18, 0x47, 0x08, 0x70, 0x10, 0x49, 0x9c, 0x4f, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x7c, 0x7f,
-    0xb0, 0xbb, 0x71, 0x00, 0x70, 0x84, 0x78, 0x7f, 0xb4, 0xbb, 0x71, 0x00, 0x70, 0xa0, 0x5a, 0xe8, 0x78, 0x74, 0x40, 0x9c, 0x5a,
-    0x40, 0xa0, 0x40, 0x2c, 0xcc, 0xf8, 0xaf, 0x0c, 0x00, 0x18, 0x49, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x9c, 0x4f, 0x14, 0x00,
-    0x00, 0x00, 0x4c, 0x84, 0x7f, 0xb4, 0xbb, 0x71, 0x00, 0x46, 0xfc, 0x5a, 0x74, 0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0x3c, 0x46,
-    0x40, 0x57, 0x08, 0x24, 0x7f, 0xf7, 0xd7, 0x00, 0x00, 0xd0, 0x01, 0x46, 0x40, 0xec, 0xe0, 0x40, 0xc9, 0x10, 0x40, 0xa8, 0x46,
-    0x40, 0x84, 0x40, 0xc9, 0x10, 0x70, 0xdc, 0xc9, 0x10, 0x5a, 0x40, 0x84, 0x40, 0x64, 0x70, 0x84, 0x40, 0x68, 0x70, 0x84, 0x5a,
-    0x48, 0xfc, 0x46, 0x74, 0x40, 0x70, 0x84, 0x40, 0x47, 0x3c, 0x64, 0x48, 0x53, 0x35, 0xa0, 0x48, 0xa0, 0x64, 0x2c, 0xcc, 0xf8,
-    0xef, 0xb0, 0xbb, 0x71, 0x00, 0x84, 0x40, 0x6c, 0x70, 0x77, 0x16, 0xbc, 0x46, 0x64, 0x70, 0xa0, 0x48, 0xa0, 0x64, 0x2c, 0xcc,
-    0xf8, 0xaf, 0xd3, 0x00, 0x24, 0x7f, 0xf4, 0xd7, 0x00, 0x00, 0x28, 0x6c, 0x43, 0x0b, 0x9c, 0x46, 0x48, 0x24, 0x7f, 0xf4, 0xd7,
-    0x00, 0x00, 0x3c, 0x68, 0x47, 0x5f, 0x5d, 0xa0, 0x68, 0xa0, 0x47, 0x2c, 0xcc, 0xf8, 0xef, 0xb0, 0xbb, 0x71, 0x00, 0x84, 0x40,
-    0x6c, 0x70, 0x77, 0x12, 0x9c, 0x46, 0x68, 0x70, 0xa0, 0x68, 0xa0, 0x47, 0x2c, 0xcc, 0xf8, 0xaf, 0x9b, 0x00, 0x7b, 0xdb, 0x28,
-    0x6c, 0x4f, 0x32, 0x3c, 0x64, 0x48, 0x77, 0x1b, 0x9c, 0x46, 0x68, 0x70, 0xa0, 0x48, 0xa0, 0x68, 0xa0, 0x47, 0x2c, 0xcc, 0xf4,
-    0xaf, 0xb8, 0x00, 0x9c, 0x46, 0x64, 0x70, 0x84, 0x64, 0x48, 0x7b, 0xb9, 0xa0, 0x48, 0xa0, 0x47, 0x2c, 0xcc, 0xf8, 0xaf, 0x6d,
-    0x00, 0xbc, 0x46, 0x47, 0x9c, 0x46, 0x48, 0x7b, 0x59, 0xbc, 0x46, 0x47, 0x7b, 0xa2, 0x3c, 0x64, 0x48, 0x77, 0x38, 0xfc, 0x5a,
-    0x64, 0x40, 0xfc, 0x68, 0x74, 0x41, 0x3c, 0x41, 0x40, 0x4b, 0x16, 0xdc, 0x46, 0x68, 0x40, 0xa0, 0x40, 0xa0, 0x74, 0x2c, 0xcc,
-    0xf8, 0xaf, 0x00, 0xff, 0x84, 0x64, 0x74, 0x70, 0x7b, 0x14, 0xa0, 0x5a, 0xa0, 0x64, 0x2c, 0xcc, 0xf8, 0xaf, 0xf0, 0xfe, 0xdc,
-    0x46, 0x68, 0x40, 0x84, 0x40, 0x5a, 0x70, 0x7a, 0xf2, 0xfe, 0xbc, 0x46, 0x64, 0x70, 0xa0, 0x47, 0xa0, 0x64, 0xa0, 0x48, 0x2c,
-    0xcc, 0xf4, 0xaf, 0x4d, 0x00, 0xbc, 0x46, 0x68, 0x70, 0x84, 0x68, 0x47, 0x7a, 0x16, 0xff, 0x18, 0x46, 0x08, 0x70, 0x70, 0x10,
-    0x46, 0x9c, 0x4f, 0x04, 0x00, 0x00, 0x00, 0x4c, 0x84, 0x7f, 0xb4, 0xbb, 0x71, 0x00, 0x59, 0x70, 0x84, 0x5a, 0x48, 0x84, 0x74,
-    0x47, 0x87, 0x58, 0x46, 0x70, 0x84, 0x48, 0x40, 0x90, 0x48, 0x87, 0x57, 0x50, 0x70, 0x84, 0x47, 0x40, 0x90, 0x47, 0x87, 0x46,
-    0x50, 0x70, 0x94, 0x59, 0x70, 0x77, 0xe7, 0x18, 0x46, 0x08, 0x70, 0x70, 0x70, 0x10, 0x46, 0x9c, 0x4f, 0x08, 0x00, 0x00, 0x00,
-    0x4c, 0x84, 0x7f, 0xb4, 0xbb, 0x71, 0x00, 0x64, 0x70, 0x84, 0x5a, 0x48, 0x84, 0x74, 0x47, 0x84, 0x78, 0x46, 0x87, 0x58, 0xe0,
-    0x59, 0x70, 0x84, 0x48, 0x40, 0x90, 0x48, 0x87, 0x56, 0x50, 0x70, 0x84, 0x46, 0x40, 0x90, 0x46, 0x87, 0x57, 0x50, 0x70, 0x84,
-    0x47, 0x40, 0x90, 0x47, 0x87, 0x63, 0x50, 0x70, 0x94, 0x64, 0x70, 0x77, 0xdd, 0x18, 0x46, 0x08, 0x70, 0x70, 0x00, 0x00, 0xd8,
-    0xb0, 0x00, 0x08, 0x07, 0x05, 0x00, 0x00, 0x0b, 0x89, 0x00, 0x00, 0x0b, 0xb6, 0x00, 0x00, 0x0b, 0x95, 0x00, 0x00, 0x0b, 0xb6,
-    0x00, 0x00, 0x0b, 0x9c, 0x00, 0x00, 0x0b, 0x95, 0x00, 0x00, 0x0b, 0xb6, 0x00, 0x00, 0x0b, 0x95, 0x00, 0x00, 0x12, 0x6d, 0x00,
-    0x00, 0x12, 0x36, 0x00, 0x00, 0x12, 0x3d, 0x00, 0x00, 0x12, 0x2d, 0x1b, 0x5b, 0x3f, 0x38, 0x3b, 0x37, 0x3b, 0x35, 0x63, 0x00,
-    0x1b, 0x5b, 0x00, 0x1b, 0x5b, 0x48, 0x1b, 0x5b, 0x32, 0x4a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x19, 0x00, 0x00, 0x00, 0x00, 0x03, 0x20, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0xc1, 0xc1, 0x81, 0x01, 0x40,
-    0xc3, 0x01, 0x03, 0xc0, 0x02, 0x80, 0xc2, 0x41, 0xc6, 0x01, 0x06, 0xc0, 0x07, 0x80, 0xc7, 0x41, 0x05, 0x00, 0xc5, 0xc1, 0xc4,
-    0x81, 0x04, 0x40, 0x00, 0x00, 0xcc, 0x01, 0xd8, 0x01, 0x14, 0x00, 0xf0, 0x01, 0x3c, 0x00, 0x28, 0x00, 0xe4, 0x01, 0xa0, 0x01,
-    0x6c, 0x00, 0x78, 0x00, 0xb4, 0x01, 0x50, 0x00, 0x9c, 0x01, 0x88, 0x01, 0x44, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x71, 0xc0,
-    0x00, 0x02, 0x81, 0xe1, 0x80, 0x00, 0x00, 0x1a, 0xec, 0x00, 0x71, 0xc4, 0xf0, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x1a, 0xec,
-    0x00, 0x71, 0xc4, 0xf0, 0x00, 0x71, 0xc4, 0xf0, 0x00, 0x71, 0xc8, 0xec, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x08, 0x00, 0x71, 0xc0, 0x50, 0x02, 0x81, 0xe1, 0x80, 0x00, 0x00, 0x68, 0x80, 0x00, 0x71, 0xc8, 0xf0, 0x02, 0x81, 0xe1, 0x00,
-    0x00, 0x00, 0x68, 0x80, 0x00, 0x71, 0xc8, 0xf0, 0x00, 0x71, 0xc8, 0xf0, 0x00, 0x71, 0xca, 0x7c, 0x00, 0x00, 0x00, 0x08, 0x00,
-    0x71, 0xc0, 0xa0, 0x02, 0x81, 0xe1, 0x80, 0x00, 0x00, 0x68, 0x58, 0x00, 0x71, 0xca, 0x80, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00,
-    0x68, 0x58, 0x00, 0x71, 0xca, 0x80, 0x00, 0x71, 0xca, 0x80, 0x00, 0x71, 0xcc, 0x0c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc0,
-    0xf0, 0x02, 0x81, 0xe1, 0x80, 0x00, 0x00, 0x1d, 0x32, 0x00, 0x71, 0xcc, 0x10, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x1d, 0x32,
-    0x00, 0x71, 0xcc, 0x10, 0x00, 0x71, 0xcc, 0x10, 0x00, 0x71, 0xcd, 0x9c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc1, 0x40, 0x02,
-    0x81, 0xe1, 0x80, 0x00, 0x00, 0x1d, 0x3b, 0x00, 0x71, 0xcd, 0xa0, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x1d, 0x3b, 0x00, 0x71,
-    0xcd, 0xa0, 0x00, 0x71, 0xcd, 0xa0, 0x00, 0x71, 0xcf, 0x2c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc1, 0x90, 0x02, 0x81, 0xe1,
-    0x80, 0x00, 0x00, 0x80, 0x78, 0x00, 0x71, 0xcf, 0x30, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x80, 0x78, 0x00, 0x71, 0xcf, 0x30,
-    0x00, 0x71, 0xcf, 0x30, 0x00, 0x71, 0xd0, 0xbc, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc1, 0xe0, 0x02, 0x81, 0xc1, 0x80, 0x00,
-    0x00, 0x1c, 0xef, 0x00, 0x71, 0xd0, 0xc0, 0x02, 0x81, 0xc1, 0x00, 0x00, 0x00, 0x1c, 0xef, 0x00, 0x71, 0xd0, 0xc0, 0x00, 0x71,
-    0xd0, 0xc0, 0x00, 0x71, 0xd2, 0x4c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc2, 0x30, 0x02, 0x81, 0xc1, 0x80, 0x00, 0x00, 0x1c,
-    0xf8, 0x00, 0x71, 0xd2, 0x50, 0x02, 0x81, 0xc1, 0x00, 0x00, 0x00, 0x1c, 0xf8, 0x00, 0x71, 0xd2, 0x50, 0x00, 0x71, 0xd2, 0x50,
-    0x00, 0x71, 0xd3, 0xdc, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc2, 0x80, 0x02, 0x81, 0xe1, 0x80, 0x00, 0x00, 0x68, 0xa8, 0x00,
-    0x71, 0xd3, 0xe0, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0xa8, 0x00, 0x71, 0xd3, 0xe0, 0x00, 0x71, 0xd3, 0xe0, 0x00, 0x71,
-    0xd5, 0x6c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc2, 0xd0, 0x02, 0x81, 0xe1, 0x80, 0x00, 0x00, 0x68, 0xa8, 0x00, 0x71, 0xd5,
-    0x70, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0xa8, 0x00, 0x71, 0xd5, 0x70, 0x00, 0x71, 0xd5, 0x70, 0x00, 0x71, 0xd6, 0xfc,
-    0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0x90, 0x94, 0x02, 0x81, 0xe1, 0x80, 0x00, 0x00, 0x71, 0xac, 0x00, 0x71, 0x90, 0xe4, 0x02,
-    0x81, 0xe1, 0x80, 0x00, 0x00, 0x71, 0xac, 0x00, 0x71, 0x90, 0xe4, 0x00, 0x71, 0x90, 0xe4, 0x00, 0x71, 0x97, 0x20, 0x00, 0x00,
-    0x00, 0x20, 0x00, 0x71, 0xc3, 0x20, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68,
-    0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00,
-    0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02,
-    0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00,
-    0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1,
-    0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28,
-    0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x68, 0x28, 0x00, 0x00, 0x00, 0x08, 0x00, 0x71, 0xc3, 0xa0, 0x02, 0x81, 0xe1, 0x80, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x81, 0xe1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3b, 0x63, 0x00, 0x00, 0x3b, 0x8b, 0x00, 0x00, 0x3b,
-    0xaf, 0x00, 0x00, 0x3b, 0xd1, 0x00, 0x00, 0x3b, 0xf3, 0x52, 0x4f, 0x4d, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x53, 0x48, 0x4f,
-    0x52, 0x54, 0x52, 0x41, 0x4d, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x57, 0x41, 0x49, 0x54, 0x49, 0x4e, 0x47, 0x20, 0x46, 0x4f,
-    0x52, 0x20, 0x4b, 0x45, 0x59, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x20, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x00, 0x52, 0x41, 0x4d,
-    0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x4e, 0x4f, 0x4e, 0x56, 0x4f, 0x4c, 0x41, 0x54, 0x49, 0x4c, 0x45, 0x20, 0x4d, 0x45, 0x4d,
-    0x4f, 0x52, 0x59, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x49, 0x2f, 0x4f, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x45, 0x58, 0x54,
-    0x45, 0x52, 0x4e, 0x41, 0x4c, 0x20, 0x44, 0x55, 0x41, 0x52, 0x54, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x45, 0x58, 0x54, 0x52,
-    0x41, 0x20, 0x49, 0x4f, 0x20, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x50, 0x49, 0x4f, 0x20, 0x61,
-    0x6e, 0x64, 0x20, 0x4d, 0x4f, 0x55, 0x53, 0x45, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x52, 0x41, 0x4d, 0x20, 0x54, 0x45, 0x53,
-    0x54, 0x00, 0x4d, 0x45, 0x4d, 0x4f, 0x52, 0x59, 0x20, 0x53, 0x49, 0x5a, 0x45, 0x3a, 0x20, 0x00, 0x31, 0x4d, 0x00, 0x32, 0x35,
-    0x36, 0x4b, 0x00, 0x4e, 0x4f, 0x4e, 0x56, 0x4f, 0x4c, 0x41, 0x54, 0x49, 0x4c, 0x45, 0x20, 0x4d, 0x45, 0x4d, 0x4f, 0x52, 0x59,
-    0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x49, 0x2f, 0x4f, 0x20, 0x54, 0x45, 0x53, 0x54, 0x00, 0x4b, 0x45, 0x59, 0x42, 0x4f, 0x41,
-    0x52, 0x44, 0x20, 0x52, 0x45, 0x51, 0x55, 0x45, 0x53, 0x54, 0x45, 0x44, 0x20, 0x53, 0x45, 0x4c, 0x46, 0x2d, 0x54, 0x45, 0x53,
-    0x54, 0x00, 0x43, 0x4f, 0x50, 0x59, 0x52, 0x49, 0x47, 0x48, 0x54, 0x20, 0x31, 0x39, 0x38, 0x33, 0x20, 0x54, 0x45, 0x4c, 0x45,
-    0x54, 0x59, 0x50, 0x45, 0x20, 0x43, 0x4f, 0x52, 0x50, 0x4f, 0x52, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x00, 0x54, 0x45, 0x52, 0x4d,
-    0x49, 0x4e, 0x41, 0x4c, 0x20, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x20, 0x4e, 0x55, 0x4d, 0x42, 0x45, 0x52, 0x3a, 0x20,
-    0x00, 0x31, 0x53, 0x54, 0x20, 0x41, 0x56, 0x41, 0x49, 0x4c, 0x41, 0x42, 0x4c, 0x45, 0x20, 0x4d, 0x45, 0x4d, 0x4f, 0x52, 0x59,
-    0x20, 0x41, 0x44, 0x44, 0x52, 0x45, 0x53, 0x53, 0x3a, 0x20, 0x30, 0x78, 0x00, 0x4d, 0x4f, 0x55, 0x53, 0x45, 0x20, 0x54, 0x45,
-    0x53, 0x54, 0x20, 0x2d, 0x20, 0x68, 0x69, 0x74, 0x20, 0x52, 0x45, 0x54, 0x55, 0x52, 0x4e, 0x20, 0x74, 0x6f, 0x20, 0x65, 0x78,
-    0x69, 0x74, 0x00, 0x62, 0x75, 0x74, 0x74, 0x6f, 0x6e, 0x73, 0x20, 0x64, 0x65, 0x70, 0x72, 0x65, 0x73, 0x73, 0x65, 0x64, 0x20,
-    0x3d, 0x20, 0x00, 0x2b, 0x00, 0x2b, 0x00, 0x2b, 0x00, 0x4c, 0x45, 0x46, 0x54, 0x00, 0x4d, 0x49, 0x44, 0x44, 0x4c, 0x45, 0x00,
-    0x52, 0x49, 0x47, 0x48, 0x54, 0x00, 0x2b, 0x00, 0x2b, 0x00, 0x4b, 0x45, 0x59, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x20, 0x54, 0x45,
-    0x53, 0x54, 0x20, 0x2d, 0x20, 0x68, 0x69, 0x74, 0x20, 0x52, 0x45, 0x54, 0x55, 0x52, 0x4e, 0x20, 0x74, 0x6f, 0x20, 0x65, 0x78,
-    0x69, 0x74, 0x00, 0x4e, 0x4f, 0x4e, 0x2d, 0x41, 0x53, 0x43, 0x49, 0x49, 0x20, 0x43, 0x48, 0x41, 0x52, 0x41, 0x43, 0x54, 0x45,
-    0x52, 0x53, 0x20, 0x57, 0x49, 0x4c, 0x4c, 0x20, 0x42, 0x45, 0x20, 0x55, 0x4e, 0x44, 0x45, 0x52, 0x4c, 0x49, 0x4e, 0x45, 0x44,
-    0x00, 0x5f, 0x00, 0x00, 0x00, 0x20, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x20, 0x44, 0x41, 0x54, 0x45, 0x3a, 0x20, 0x44,
-    0x65, 0x63, 0x20, 0x31, 0x37, 0x2c, 0x20, 0x31, 0x39, 0x38, 0x34, 0x20, 0x00, 0x00, 0x00, 0x00, 0xff, 0x80, 0xfe, 0x00, 0xf8,
-    0x00, 0xfc, 0x00, 0xfe, 0x00, 0xdf, 0x00, 0xcf, 0x80, 0x87, 0xc0, 0x83, 0xe0, 0x01, 0xf0, 0x00, 0xf8, 0x00, 0x7c, 0x00, 0x3e,
-    0x00, 0x1f, 0x00, 0x0e, 0x00, 0x04, 0x00, 0x04, 0x00, 0x0e, 0x00, 0x1f, 0x00, 0x3e, 0x00, 0x7c, 0x00, 0xf8, 0x01, 0xf0, 0x83,
-    0xe0, 0x87, 0xc0, 0xcf, 0x80, 0xdf, 0x00, 0xfe, 0x00, 0xfc, 0x00, 0xf8, 0x00, 0xfe, 0x00, 0xff, 0x80, 0x01, 0xff, 0x00, 0x7f,
-    0x00, 0x1f, 0x00, 0x3f, 0x00, 0x7f, 0x00, 0xfb, 0x01, 0xf3, 0x03, 0xe1, 0x07, 0xc1, 0x0f, 0x80, 0x1f, 0x00, 0x3e, 0x00, 0x7c,
-    0x00, 0xf8, 0x00, 0x70, 0x00, 0x20, 0x00, 0x20, 0x00, 0x70, 0x00, 0xf8, 0x00, 0x7c, 0x00, 0x3e, 0x00, 0x1f, 0x00, 0x0f, 0x80,
-    0x07, 0xc1, 0x03, 0xe1, 0x01, 0xf3, 0x00, 0xfb, 0x00, 0x7f, 0x00, 0x3f, 0x00, 0x1f, 0x00, 0x7f, 0x01, 0xff, 0x40, 0xff, 0xe0,
-    0x01, 0x70, 0x01, 0x38, 0x01, 0x1c, 0x01, 0x0e, 0x41, 0x07, 0xc1, 0x03, 0xc1, 0x83, 0xc1, 0x87, 0xc1, 0x80, 0x01, 0x80, 0x01,
-    0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0xff, 0xff, 0xff, 0x02, 0x80, 0x07, 0x80, 0x0e, 0x80, 0x1c, 0x80, 0x38, 0x82, 0x70, 0x83,
-    0xf0, 0x83, 0xe0, 0x83, 0xc1, 0x83, 0xe1, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0xff, 0xff, 0xff, 0xff,
-    0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x87, 0xc1, 0x83, 0xc1, 0x03, 0xc1, 0x07, 0xc1, 0x0e, 0x41, 0x1c,
-    0x01, 0x38, 0x01, 0x70, 0x01, 0xe0, 0x01, 0x40, 0xff, 0xff, 0xff, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01,
-    0x83, 0xe1, 0x83, 0xc1, 0x83, 0xc0, 0x83, 0xe0, 0x82, 0x70, 0x80, 0x38, 0x80, 0x1c, 0x80, 0x0e, 0x80, 0x07, 0xff, 0x02, 0x00,
-    0x00, 0xde, 0x20, 0xff, 0xf1, 0x00, 0x00, 0x00, 0x00, 0xdd, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xde, 0x40, 0xff, 0xf1,
-    0xff, 0xf1, 0x00, 0x00, 0xde, 0x00, 0x00, 0x00, 0xff, 0xf1, 0x00, 0x00, 0xde, 0xa0, 0xff, 0xf6, 0xff, 0xfa, 0x00, 0x00, 0xde,
-    0xc0, 0xff, 0xfa, 0xff, 0xfa, 0x00, 0x00, 0xde, 0x60, 0xff, 0xf6, 0xff, 0xf6, 0x00, 0x00, 0xde, 0x80, 0xff, 0xfa, 0xff, 0xf6,
-    0x4e, 0x6f, 0x72, 0x6d, 0x61, 0x6c, 0x00, 0x53, 0x74, 0x61, 0x63, 0x6b, 0x00, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x00,
-    0x53, 0x74, 0x72, 0x61, 0x79, 0x20, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x72, 0x75, 0x70, 0x74, 0x00, 0x20, 0x45, 0x78, 0x63, 0x65,
-    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x23, 0x30, 0x78, 0x00, 0x20, 0x61, 0x74, 0x20, 0x30, 0x78, 0x00, 0x2c, 0x20, 0x46, 0x61,
-    0x75, 0x6c, 0x74, 0x65, 0x64, 0x20, 0x50, 0x43, 0x42, 0x50, 0x3d, 0x00, 0x21, 0x20, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x53, 0x45, 0x54, 0x55, 0x50, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x69,
-    0x6e, 0x75, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x71, 0x90, 0x2c, 0x00, 0x71, 0x90, 0x44, 0x00, 0x00, 0x83, 0xbc, 0x00, 0x00,
-    0x1d, 0x65, 0x00, 0x00, 0x1d, 0x44, 0x00, 0x00, 0x85, 0xe8, 0x00, 0x71, 0x90, 0x5c, 0x00, 0x71, 0x90, 0x68, 0x00, 0x00, 0x7e,
-    0xa4, 0x00, 0x00, 0x7f, 0x18, 0x00, 0x00, 0x7f, 0x50, 0x00, 0x00, 0x7f, 0x80, 0x00, 0x71, 0x90, 0x74, 0x00, 0x71, 0x90, 0x80,
-    0x00, 0x00, 0x7e, 0xde, 0x00, 0x00, 0x7f, 0x34, 0x00, 0x00, 0x7f, 0x68, 0x00, 0x00, 0x7f, 0xfc, 0x00, 0x71, 0x90, 0x50, 0x00,
-    0x71, 0x90, 0x50, 0x00, 0x00, 0x8f, 0xe8, 0x00, 0x00, 0x8f, 0xb0, 0x00, 0x00, 0x8f, 0xb0, 0x00, 0x00, 0x8f, 0xd4, 0x00, 0x60,
-    0x00, 0x02, 0x00, 0x00, 0xed, 0x24, 0x00, 0x00, 0xed, 0x29, 0x00, 0x00, 0xed, 0x2e, 0x00, 0x00, 0xed, 0x33, 0x00, 0x00, 0xed,
-    0x38, 0x00, 0x00, 0xed, 0x3e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7b, 0xe2, 0x00, 0x60, 0x00, 0x06,
-    0x00, 0x00, 0xed, 0x42, 0x00, 0x00, 0xed, 0x45, 0x00, 0x00, 0xed, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x0a, 0x00, 0x00,
-    0xed, 0x4e, 0x00, 0x00, 0xed, 0x54, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x0e, 0x00, 0x00, 0xed, 0x58,
-    0x00, 0x00, 0xed, 0x5d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x12, 0x00, 0x00, 0xed, 0x62, 0x00, 0x00,
-    0xed, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7b, 0xb4, 0x00, 0x60, 0x00, 0x16, 0x00, 0x00, 0xed, 0x69, 0x00, 0x00, 0xed, 0x6e,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x02, 0x00, 0x60, 0x06, 0x8a, 0x00, 0x00, 0xed, 0x74, 0x00, 0x00, 0xed, 0x79, 0x00, 0x00,
-    0xed, 0x7d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x7b, 0xe2, 0x00, 0x60, 0x06, 0x8e, 0x00, 0x00, 0xed, 0x82, 0x00, 0x00, 0xed, 0x84, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x7b, 0xe2, 0x00, 0x60, 0x06, 0x92, 0x00, 0x00, 0xed, 0x86, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x60, 0x06, 0x96, 0x00, 0x00, 0xed, 0x8d, 0x00, 0x00, 0xed, 0x92, 0x00, 0x00, 0xed, 0x97, 0x00, 0x00, 0xed, 0x9c,
-    0x00, 0x00, 0xed, 0xa1, 0x00, 0x00, 0xed, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8f, 0xd4, 0x00,
-    0x60, 0x06, 0x9a, 0x00, 0x00, 0xed, 0xab, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x06,
-    0x9e, 0x00, 0x00, 0xed, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x06, 0xa2, 0x00,
-    0x00, 0xed, 0xb2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x06, 0xa6, 0x00, 0x00, 0xed,
-    0xb9, 0x00, 0x00, 0xed, 0xbe, 0x00, 0x00, 0xed, 0xc3, 0x00, 0x00, 0xed, 0xc8, 0x00, 0x00, 0xed, 0xcd, 0x00, 0x00, 0xed, 0xd3,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xaa, 0x00, 0x00, 0xed, 0xd7, 0x00,
-    0x00, 0xed, 0xdc, 0x00, 0x00, 0xed, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xae, 0x00, 0x00, 0xed, 0xe5, 0x00, 0x00, 0xed,
-    0xe7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xb2, 0x00, 0x00, 0xed, 0xe9, 0x00, 0x00, 0xed, 0xf0, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xe2, 0x00, 0x00, 0xed, 0xf7, 0x00, 0x00, 0xed, 0xfe, 0x00, 0x00, 0xee,
-    0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x6c, 0x68, 0x00, 0x60, 0x06, 0xb6, 0x00, 0x00, 0xee, 0x0c, 0x00, 0x00, 0xee, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x60, 0x06, 0xe6, 0x00, 0x00, 0xee, 0x13, 0x00, 0x00, 0xee, 0x1a, 0x00, 0x00, 0xee, 0x21, 0x00, 0x00, 0xee,
-    0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6c, 0x68,
-    0x00, 0x60, 0x06, 0xba, 0x00, 0x00, 0xee, 0x2f, 0x00, 0x00, 0xee, 0x35, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60,
-    0x06, 0xbe, 0x00, 0x00, 0xee, 0x3a, 0x00, 0x00, 0xee, 0x41, 0x00, 0x00, 0xee, 0x48, 0x00, 0x00, 0xee, 0x4f, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x06, 0xc2,
-    0x00, 0x00, 0xee, 0x56, 0x00, 0x00, 0xee, 0x5f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x06, 0xca, 0x00, 0x00,
-    0xee, 0x65, 0x00, 0x00, 0xee, 0x6d, 0x00, 0x00, 0xee, 0x77, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x06, 0xce, 0x00, 0x00, 0xee, 0x7e,
-    0x00, 0x00, 0xee, 0x83, 0x00, 0x00, 0xee, 0x88, 0x00, 0x00, 0xee, 0x8d, 0x00, 0x00, 0xee, 0x92, 0x00, 0x00, 0xee, 0x98, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xd2, 0x00, 0x00, 0xee, 0x9c, 0x00, 0x00,
-    0xee, 0xa1, 0x00, 0x00, 0xee, 0xa5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xd6, 0x00, 0x00, 0xee, 0xaa, 0x00, 0x00, 0xee, 0xac,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xda, 0x00, 0x00, 0xee, 0xae, 0x00, 0x00, 0xee, 0xb5, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x7c, 0x24, 0x00, 0x60, 0x06, 0xea, 0x00, 0x00, 0xee, 0xbc, 0x00, 0x00, 0xee, 0xc3, 0x00, 0x00, 0xee, 0xca,
-    0x00, 0x00, 0xee, 0xd1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x6c, 0x68, 0x00, 0x60, 0x06, 0xee, 0x00, 0x00, 0xee, 0xd8, 0x00, 0x00, 0xee, 0xdf, 0x00, 0x00, 0xee, 0xe6, 0x00, 0x00,
-    0xee, 0xed, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6c,
-    0x68, 0x00, 0x60, 0x06, 0xf6, 0x00, 0x00, 0xee, 0xf4, 0x00, 0x00, 0xee, 0xf7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x60, 0x06, 0xfa, 0x00, 0x00, 0xee, 0xfb, 0x00, 0x00, 0xee, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x06,
-    0xfe, 0x00, 0x00, 0xef, 0x02, 0x00, 0x00, 0xef, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x07, 0x02, 0x00,
-    0x00, 0xef, 0x09, 0x00, 0x00, 0xef, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x07, 0x0a, 0x00, 0x00, 0xef,
-    0x10, 0x00, 0x00, 0xef, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x17, 0x00, 0x00, 0xef, 0x1c, 0x01,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x24, 0x00, 0x00, 0xef, 0x29, 0x01, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x31, 0x00, 0x00, 0xef, 0x39, 0x01, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x3f, 0x00, 0x00, 0xef, 0x47, 0x01, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x4c, 0x00, 0x00, 0xef, 0x54, 0x01, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x30, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x5c, 0x00, 0x00, 0xef, 0x65, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x58, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x6d, 0x00, 0x00, 0xef, 0x72, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0x77, 0x00, 0x00, 0xef, 0x7e, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0xef, 0x86, 0x00, 0x00, 0xef, 0x8d, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0xef, 0x95, 0x00, 0x00, 0xef, 0x9c, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x01, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xef, 0xa4, 0x00, 0x00, 0xef, 0xab, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0x01, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xb3,
-    0x00, 0x00, 0xef, 0xb8, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xbd, 0x00,
-    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xdf, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xc3, 0x00, 0x00,
-    0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xca, 0x00, 0x00, 0x00,
-    0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe1, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xd4, 0x00, 0x00, 0x00, 0x00,
-    0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe1, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xd9, 0x00, 0x00, 0x00, 0x00, 0x02,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0xe4, 0xc8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xe2, 0x00, 0x00, 0xef, 0xe7, 0x01, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xec, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0xe1, 0x58, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0xe1, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xef, 0xf9, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe1,
-    0xa8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe1, 0xd0,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0xf0, 0x08, 0x00, 0x00, 0xf0, 0x0d, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xf0, 0x12, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe1, 0xf8, 0x01, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0xf0, 0x18, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe2, 0x20, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0xf0, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe2, 0x48, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0,
-    0x29, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe2, 0x70, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x2e,
-    0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe4, 0xf0, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x37, 0x00, 0x00, 0xf0,
-    0x3c, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x41, 0x00, 0x00, 0x00, 0x00,
-    0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x48, 0x00, 0x00, 0x00, 0x00, 0x02,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0xe2, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x51, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0xe4, 0xa0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x5a, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0xe5, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x64, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xe2, 0x98, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x69, 0x00, 0x00, 0xf0, 0x6e, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x73, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe2, 0xe8,
-    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0xf0, 0x7b, 0x00, 0x00, 0xf0, 0x80, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0,
-    0x85, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x8c,
-    0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe3, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x95, 0x00,
-    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x9e, 0x00, 0x00,
-    0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe3, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xa5, 0x00, 0x00, 0x00,
-    0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe3, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xac, 0x00, 0x00, 0x00, 0x00,
-    0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe3, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xb2, 0x00, 0x00, 0x00, 0x00, 0x01,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xb7, 0x00, 0x00, 0xf0, 0xbc, 0x01, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xc1, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0xe0, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xc9, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xe0, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xd0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xd5, 0x00, 0x00, 0xf0, 0xda, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0xf0, 0xdd, 0x00, 0x00, 0xf0, 0xe2, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0xf0, 0xe5, 0x00, 0x00, 0xf0, 0xea, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0xf0, 0xed, 0x00, 0x00, 0xf0, 0xf2, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0xf0, 0xf5, 0x00, 0x00, 0xf0, 0xfa, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0xf0, 0xfd, 0x00, 0x00, 0xf1, 0x02, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1,
-    0x05, 0x00, 0x00, 0xf1, 0x0a, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x0d,
-    0x00, 0x00, 0xf1, 0x12, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x15, 0x00,
-    0x00, 0xf1, 0x1a, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x1f, 0x00, 0x00,
-    0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe3, 0xb0, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x25, 0x00, 0x00, 0x00,
-    0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe3, 0xd8, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x2c, 0x00, 0x00, 0x00, 0x00,
-    0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe4, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x36, 0x00, 0x00, 0x00, 0x00, 0x02,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0xe4, 0x28, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x3b, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0xe5, 0x18, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x44, 0x00, 0x00, 0xf1, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x4e, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0xe4, 0x50, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf1, 0x54, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe4,
-    0x78, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x03, 0x20, 0x04, 0x00, 0x00, 0x71, 0xb0, 0x50, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x00, 0x03,
-    0x20, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x74, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x66, 0x88, 0x99, 0xbb, 0xcc, 0x44,
-    0x00, 0x00, 0x00, 0x00, 0x73, 0x4c, 0x00, 0x00, 0x73, 0x59, 0x00, 0x00, 0x73, 0x81, 0x00, 0x00, 0x74, 0x96, 0x31, 0x32, 0x30,
-    0x30, 0x00, 0x32, 0x34, 0x30, 0x30, 0x00, 0x34, 0x38, 0x30, 0x30, 0x00, 0x39, 0x36, 0x30, 0x30, 0x00, 0x31, 0x39, 0x32, 0x30,
-    0x30, 0x00, 0x33, 0x30, 0x30, 0x00, 0x43, 0x52, 0x00, 0x4c, 0x46, 0x00, 0x43, 0x52, 0x2f, 0x4c, 0x46, 0x00, 0x49, 0x6e, 0x64,
-    0x65, 0x78, 0x00, 0x4e, 0x4c, 0x20, 0x00, 0x46, 0x75, 0x6c, 0x6c, 0x00, 0x48, 0x61, 0x6c, 0x66, 0x00, 0x4f, 0x6e, 0x00, 0x4f,
-    0x66, 0x66, 0x00, 0x44, 0x61, 0x72, 0x6b, 0x00, 0x4c, 0x69, 0x67, 0x68, 0x74, 0x00, 0x4e, 0x6f, 0x6e, 0x65, 0x00, 0x4f, 0x64,
-    0x64, 0x00, 0x45, 0x76, 0x65, 0x6e, 0x00, 0x38, 0x00, 0x37, 0x00, 0x52, 0x53, 0x2d, 0x32, 0x33, 0x32, 0x00, 0x31, 0x32, 0x30,
-    0x30, 0x00, 0x32, 0x34, 0x30, 0x30, 0x00, 0x34, 0x38, 0x30, 0x30, 0x00, 0x39, 0x36, 0x30, 0x30, 0x00, 0x31, 0x39, 0x32, 0x30,
-    0x30, 0x00, 0x33, 0x30, 0x30, 0x00, 0x45, 0x76, 0x65, 0x6e, 0x00, 0x38, 0x00, 0x52, 0x53, 0x2d, 0x32, 0x33, 0x32, 0x00, 0x31,
-    0x32, 0x30, 0x30, 0x00, 0x32, 0x34, 0x30, 0x30, 0x00, 0x34, 0x38, 0x30, 0x30, 0x00, 0x39, 0x36, 0x30, 0x30, 0x00, 0x31, 0x39,
-    0x32, 0x30, 0x30, 0x00, 0x33, 0x30, 0x30, 0x00, 0x4e, 0x6f, 0x6e, 0x65, 0x00, 0x4f, 0x64, 0x64, 0x00, 0x45, 0x76, 0x65, 0x6e,
-    0x00, 0x38, 0x00, 0x37, 0x00, 0x52, 0x53, 0x2d, 0x32, 0x33, 0x32, 0x00, 0x52, 0x53, 0x2d, 0x34, 0x32, 0x32, 0x00, 0x50, 0x6f,
-    0x72, 0x74, 0x20, 0x41, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x43, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x44, 0x00, 0x4f, 0x66,
-    0x66, 0x00, 0x4f, 0x6e, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x41, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x43, 0x00, 0x50, 0x6f,
-    0x72, 0x74, 0x20, 0x44, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x42, 0x00, 0x52, 0x69, 0x67, 0x68, 0x74, 0x00, 0x4c, 0x65, 0x66,
-    0x74, 0x00, 0x31, 0x35, 0x20, 0x63, 0x70, 0x73, 0x00, 0x32, 0x30, 0x20, 0x63, 0x70, 0x73, 0x00, 0x33, 0x30, 0x20, 0x63, 0x70,
-    0x73, 0x00, 0x36, 0x30, 0x20, 0x63, 0x70, 0x73, 0x00, 0x4e, 0x6f, 0x20, 0x42, 0x6c, 0x69, 0x6e, 0x6b, 0x00, 0x42, 0x6c, 0x69,
-    0x6e, 0x6b, 0x00, 0x56, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x00, 0x49, 0x6e, 0x76, 0x69, 0x73, 0x69, 0x62, 0x6c, 0x65, 0x00,
-    0x53, 0x70, 0x61, 0x63, 0x65, 0x73, 0x00, 0x31, 0x32, 0x30, 0x30, 0x00, 0x32, 0x34, 0x30, 0x30, 0x00, 0x34, 0x38, 0x30, 0x30,
-    0x00, 0x39, 0x36, 0x30, 0x30, 0x00, 0x31, 0x39, 0x32, 0x30, 0x30, 0x00, 0x33, 0x30, 0x30, 0x00, 0x4e, 0x6f, 0x6e, 0x65, 0x00,
-    0x4f, 0x64, 0x64, 0x00, 0x45, 0x76, 0x65, 0x6e, 0x00, 0x38, 0x00, 0x37, 0x00, 0x52, 0x53, 0x2d, 0x32, 0x33, 0x32, 0x00, 0x52,
-    0x53, 0x2d, 0x34, 0x32, 0x32, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x41, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x43, 0x00, 0x50,
-    0x6f, 0x72, 0x74, 0x20, 0x44, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x42, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x41, 0x00, 0x50,
-    0x6f, 0x72, 0x74, 0x20, 0x43, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x44, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x42, 0x00, 0x4f,
-    0x6e, 0x00, 0x4f, 0x66, 0x66, 0x00, 0x4f, 0x66, 0x66, 0x00, 0x4f, 0x6e, 0x00, 0x4f, 0x66, 0x66, 0x00, 0x4f, 0x6e, 0x00, 0x4f,
-    0x66, 0x66, 0x00, 0x4f, 0x6e, 0x00, 0x4e, 0x6f, 0x00, 0x59, 0x65, 0x73, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x00, 0x4f, 0x70, 0x74,
-    0x69, 0x6f, 0x6e, 0x73, 0x00, 0x48, 0x6f, 0x73, 0x74, 0x00, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x00, 0x50, 0x72, 0x65,
-    0x66, 0x65, 0x72, 0x2d, 0x00, 0x65, 0x6e, 0x63, 0x65, 0x73, 0x00, 0x45, 0x64, 0x69, 0x74, 0x20, 0x50, 0x46, 0x00, 0x4b, 0x65,
-    0x79, 0x73, 0x00, 0x50, 0x72, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x00, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x00, 0x41, 0x55,
-    0x58, 0x20, 0x50, 0x6f, 0x72, 0x74, 0x00, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d,
-    0x65, 0x6e, 0x75, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x41, 0x00, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x00, 0x50, 0x6f,
-    0x72, 0x74, 0x20, 0x42, 0x00, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x43, 0x00, 0x4f,
-    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x00, 0x50, 0x6f, 0x72, 0x74, 0x20, 0x44, 0x00, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73,
-    0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x53, 0x70, 0x65, 0x65, 0x64, 0x00, 0x50, 0x61, 0x72, 0x69,
-    0x74, 0x79, 0x00, 0x42, 0x69, 0x74, 0x73, 0x2f, 0x43, 0x68, 0x61, 0x72, 0x00, 0x54, 0x79, 0x70, 0x65, 0x00, 0x52, 0x63, 0x76,
-    0x20, 0x46, 0x6c, 0x6f, 0x77, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x53, 0x70, 0x65, 0x65, 0x64,
-    0x00, 0x50, 0x61, 0x72, 0x69, 0x74, 0x79, 0x00, 0x42, 0x69, 0x74, 0x73, 0x2f, 0x43, 0x68, 0x61, 0x72, 0x00, 0x54, 0x79, 0x70,
-    0x65, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x53, 0x70, 0x65, 0x65, 0x64, 0x00, 0x50, 0x61, 0x72,
-    0x69, 0x74, 0x79, 0x00, 0x42, 0x69, 0x74, 0x73, 0x2f, 0x43, 0x68, 0x61, 0x72, 0x00, 0x54, 0x79, 0x70, 0x65, 0x00, 0x52, 0x63,
-    0x76, 0x20, 0x46, 0x6c, 0x6f, 0x77, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x44, 0x75, 0x70, 0x6c,
-    0x65, 0x78, 0x00, 0x45, 0x6e, 0x63, 0x6f, 0x64, 0x69, 0x6e, 0x67, 0x00, 0x47, 0x65, 0x6e, 0x20, 0x46, 0x6c, 0x6f, 0x77, 0x00,
-    0x50, 0x61, 0x73, 0x73, 0x20, 0x46, 0x6c, 0x6f, 0x77, 0x00, 0x48, 0x6f, 0x73, 0x74, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d,
-    0x65, 0x6e, 0x75, 0x00, 0x50, 0x72, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75,
-    0x00, 0x53, 0x63, 0x72, 0x65, 0x65, 0x6e, 0x00, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x73, 0x00, 0x4b, 0x65, 0x79, 0x20,
-    0x54, 0x6f, 0x6e, 0x65, 0x00, 0x43, 0x75, 0x72, 0x73, 0x6f, 0x72, 0x00, 0x52, 0x65, 0x70, 0x65, 0x61, 0x74, 0x00, 0x4d, 0x6f,
-    0x75, 0x73, 0x65, 0x00, 0x4d, 0x6f, 0x72, 0x65, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x4e, 0x65,
-    0x77, 0x6c, 0x69, 0x6e, 0x65, 0x00, 0x52, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x00, 0x4d, 0x6f, 0x72, 0x65, 0x00, 0x45, 0x64, 0x69,
-    0x74, 0x00, 0x66, 0x31, 0x00, 0x45, 0x64, 0x69, 0x74, 0x00, 0x66, 0x32, 0x00, 0x45, 0x64, 0x69, 0x74, 0x00, 0x66, 0x33, 0x00,
-    0x45, 0x64, 0x69, 0x74, 0x00, 0x66, 0x34, 0x00, 0x45, 0x64, 0x69, 0x74, 0x00, 0x66, 0x35, 0x00, 0x45, 0x64, 0x69, 0x74, 0x00,
-    0x66, 0x36, 0x00, 0x45, 0x64, 0x69, 0x74, 0x00, 0x66, 0x37, 0x00, 0x45, 0x64, 0x69, 0x74, 0x00, 0x66, 0x38, 0x00, 0x4d, 0x61,
-    0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x53, 0x70, 0x65, 0x65, 0x64, 0x00, 0x50, 0x61, 0x72, 0x69, 0x74, 0x79, 0x00,
-    0x42, 0x69, 0x74, 0x73, 0x2f, 0x43, 0x68, 0x61, 0x72, 0x00, 0x54, 0x79, 0x70, 0x65, 0x00, 0x52, 0x63, 0x76, 0x20, 0x46, 0x6c,
-    0x6f, 0x77, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x00, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x41, 0x55, 0x58, 0x20, 0x31, 0x00, 0x41, 0x55,
-    0x58, 0x20, 0x32, 0x00, 0x53, 0x45, 0x54, 0x55, 0x50, 0x3a, 0x20, 0x46, 0x61, 0x74, 0x61, 0x6c, 0x20, 0x45, 0x72, 0x72, 0x6f,
-    0x72, 0x2f, 0x49, 0x6c, 0x6c, 0x65, 0x67, 0x61, 0x6c, 0x20, 0x4b, 0x65, 0x79, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x20, 0x28,
-    0x50, 0x72, 0x65, 0x73, 0x73, 0x20, 0x53, 0x45, 0x54, 0x55, 0x50, 0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x3e, 0x1e, 0x0e, 0x06, 0x02, 0xfe, 0x00, 0x00, 0x03, 0x01, 0x00, 0x00, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00,
-    0x7f, 0xa5, 0x00, 0x00, 0x7f, 0xbf, 0x00, 0x00, 0x7f, 0xda, 0x00, 0x00, 0x7f, 0xda, 0x00, 0x00, 0x80, 0x21, 0x00, 0x00, 0x80,
-    0x3b, 0x00, 0x00, 0x80, 0x56, 0x00, 0x00, 0x80, 0x56, 0x00, 0x00, 0x80, 0xa2, 0x00, 0x00, 0x82, 0xe2, 0x00, 0x00, 0x80, 0xeb,
-    0x00, 0x00, 0x82, 0xe2, 0x00, 0x00, 0x80, 0xf9, 0x00, 0x00, 0x82, 0xe2, 0x00, 0x00, 0x80, 0xf9, 0x00, 0x00, 0x82, 0xe2, 0x00,
-    0x00, 0x81, 0xc5, 0x00, 0x00, 0x82, 0xe2, 0x00, 0x00, 0x82, 0x0d, 0x00, 0x00, 0x82, 0xe2, 0x00, 0x00, 0x82, 0x1b, 0x00, 0x00,
-    0x82, 0xe2, 0x00, 0x00, 0x82, 0x1b, 0x10, 0x04, 0x00, 0x00, 0x03, 0x02, 0x00, 0x00, 0x00, 0x00, 0x86, 0x0d, 0x00, 0x00, 0x86,
-    0x29, 0x00, 0x00, 0x86, 0x33, 0x00, 0x00, 0x86, 0x50, 0x00, 0x00, 0xf2, 0x5c, 0x00, 0x00, 0xf2, 0x66, 0x00, 0x00, 0xf2, 0x6f,
-    0x00, 0x00, 0xf2, 0x78, 0x00, 0x00, 0xf2, 0x7b, 0x00, 0x00, 0xf2, 0x7e, 0x00, 0x00, 0xf2, 0x85, 0x00, 0x00, 0xf2, 0x8c, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0xf2, 0x92, 0x00, 0x00, 0x87, 0x2e, 0x00, 0x00, 0x87, 0x49, 0x00, 0x00, 0x87, 0x79, 0x00, 0x00,
-    0x87, 0x8e, 0x00, 0x00, 0x87, 0xf2, 0x01, 0x00, 0x00, 0x00, 0x4d, 0x61, 0x69, 0x6e, 0x20, 0x4d, 0x65, 0x6e, 0x75, 0x00, 0x4e,
-    0x65, 0x78, 0x74, 0x20, 0x4b, 0x65, 0x79, 0x00, 0x50, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x00, 0x3c, 0x2d, 0x00, 0x2d,
-    0x3e, 0x00, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x00, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x00, 0x45, 0x72, 0x61, 0x73, 0x65,
-    0x00, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x72, 0x6d, 0x00, 0x00, 0x00, 0x8f, 0x81, 0x90, 0xc2, 0x91, 0xc8, 0x92, 0xc1, 0x93, 0x8a,
-    0x94, 0x34, 0x95, 0x35, 0x96, 0x36, 0x97, 0x37, 0x98, 0x38, 0x99, 0x39, 0x9a, 0xc4, 0x9b, 0xc3, 0xaf, 0x80, 0xb0, 0x00, 0xb1,
-    0x00, 0xb3, 0x00, 0xb4, 0x00, 0xb5, 0x00, 0xb6, 0x0a, 0xb7, 0x00, 0xb8, 0x00, 0xb9, 0x00, 0xba, 0x8d, 0xbb, 0x00, 0xbc, 0x00,
-    0xbd, 0x00, 0xbe, 0x00, 0xbf, 0x00, 0xc0, 0xc8, 0xc1, 0xc1, 0xc2, 0xc2, 0xc3, 0xc3, 0xc4, 0xc4, 0xc6, 0x8a, 0xc7, 0x0d, 0xc8,
-    0x82, 0xc9, 0x83, 0xca, 0x84, 0xcb, 0x85, 0xcc, 0x86, 0xcd, 0x87, 0xce, 0x88, 0xcf, 0x89, 0xd0, 0x09, 0xd1, 0x08, 0xd2, 0x37,
-    0xd3, 0x34, 0xd4, 0x38, 0xd5, 0x35, 0xd6, 0x39, 0xd7, 0x36, 0xde, 0x7f, 0xe2, 0x00, 0xe3, 0x1b, 0xe5, 0x8b, 0xe7, 0x0d, 0xe8,
-    0x82, 0xe9, 0x83, 0xea, 0x84, 0xeb, 0x85, 0xec, 0x86, 0xed, 0x87, 0xee, 0x88, 0xef, 0x89, 0xf0, 0x09, 0xf1, 0x08, 0xf2, 0x00,
-    0xf3, 0x1b, 0xf5, 0x8c, 0xf6, 0x0a, 0xfe, 0x7f, 0x31, 0x32, 0x30, 0x2e, 0x2d, 0xc5, 0x33, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xf9, 0x93, 0x93, 0x9f, 0xf9, 0x93, 0x93, 0x9f, 0xf8, 0x93, 0x91, 0x1f, 0xf8, 0x93, 0x92, 0x9f, 0xf8, 0x13,
-    0x92, 0x9f, 0xf9, 0x13, 0x93, 0x9f, 0xf9, 0x13, 0x93, 0x9f, 0xf9, 0x90, 0x13, 0x9f, 0xf9, 0x98, 0x33, 0x9f, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc3, 0xc7, 0x83, 0x81,
-    0x81, 0x83, 0x01, 0x01, 0x9d, 0x39, 0x39, 0x3f, 0x9f, 0x39, 0x39, 0x07, 0x9f, 0x01, 0x01, 0x83, 0x9f, 0x39, 0x03, 0xf9, 0x9d,
-    0x39, 0x3f, 0xf9, 0xc1, 0x39, 0x3f, 0x01, 0xc3, 0x39, 0x3f, 0x03, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0x00, 0x00, 0xf3, 0x34, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x0e, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0xf3, 0x6c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00,
-    0x01, 0x0d, 0x03, 0xf2, 0x01, 0xe0, 0x03, 0xf2, 0x1b, 0x5b, 0x37, 0x30, 0x3b, 0x31, 0x48, 0x00, 0x1b, 0x5b, 0x32, 0x4a, 0x00,
-    0x1b, 0x5b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x32, 0x00, 0x32, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11,
-    0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44,
-    0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11,
-    0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11,
-    0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44,
-    0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11,
-    0x11, 0x44, 0x44, 0x44, 0x44, 0x11, 0x11, 0x11, 0x11, 0x44, 0x44, 0x44, 0x44, 0x03, 0xc0, 0x34, 0x20, 0x37, 0xe0, 0x13, 0xc0,
-    0x17, 0xf0, 0x18, 0x68, 0x20, 0x74, 0x20, 0xd4, 0x41, 0x8a, 0x43, 0x0a, 0x43, 0x0a, 0x41, 0x8a, 0x20, 0x94, 0x20, 0x1c, 0x78,
-    0x7e, 0x67, 0xf6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x03, 0xe7, 0xe7, 0x3f, 0xfc, 0x0f, 0xf0, 0x0d, 0xb0, 0x07, 0xe0,
-    0x06, 0x60, 0x37, 0xec, 0xe4, 0x27, 0xc3, 0xc3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0xff, 0xe0, 0x01, 0x70, 0x01, 0x38,
-    0x01, 0x1d, 0x01, 0x0f, 0x01, 0x87, 0x01, 0x8f, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01,
-    0x80, 0x01, 0xff, 0xff, 0x07, 0xe0, 0x1f, 0xf8, 0x39, 0x9c, 0x63, 0xc6, 0x6f, 0xf6, 0xcd, 0xb3, 0xd9, 0x9b, 0xff, 0xff, 0xff,
-    0xff, 0xd9, 0x9b, 0xcd, 0xb3, 0x6f, 0xf6, 0x63, 0xc6, 0x39, 0x9c, 0x1f, 0xf8, 0x07, 0xe0, 0x01, 0x00, 0x00, 0xe0, 0x00, 0x10,
-    0x03, 0xe0, 0x04, 0x00, 0x0f, 0xe0, 0x12, 0x3c, 0x1f, 0xe2, 0x10, 0x1a, 0x10, 0x1a, 0x10, 0x02, 0x10, 0x3c, 0x18, 0x10, 0x6f,
-    0xec, 0x40, 0x04, 0x3f, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x84, 0x04, 0x42,
-    0x04, 0x41, 0xff, 0xe1, 0x4f, 0xf1, 0x3f, 0xfe, 0x17, 0xf0, 0x03, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01,
-    0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0xfc, 0x7e, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
-    0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0xfc, 0x3f, 0xfc, 0x3f, 0xe0, 0x0f, 0xd8, 0x1b, 0xcc, 0x33, 0xc6, 0x63, 0x02, 0xc0, 0x03,
-    0x80, 0x03, 0x80, 0xc6, 0x40, 0xcc, 0x63, 0xd8, 0x33, 0xf0, 0x1b, 0xe0, 0x07, 0xfc, 0x3f, 0xfc, 0x3f, 0x00, 0x0e, 0x06, 0x1b,
-    0x02, 0x41, 0x72, 0xc3, 0xdb, 0x86, 0x8b, 0x0c, 0x8b, 0xc0, 0xda, 0x6c, 0x76, 0x6c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x03, 0x94,
-    0xa6, 0x63, 0x2c, 0x63, 0x18, 0x94, 0x90, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55,
-    0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa,
-    0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55,
-    0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55,
-    0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa,
-    0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55,
-    0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22,
-    0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22,
-    0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88,
-    0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22,
-    0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88,
-    0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88,
-    0x88, 0x88, 0x88, 0x22, 0x22, 0x22, 0x22, 0x88, 0x88, 0x88, 0x88, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd,
-    0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77,
-    0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd,
-    0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd,
-    0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77,
-    0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd,
-    0xdd, 0x77, 0x77, 0x77, 0x77, 0xdd, 0xdd, 0xdd, 0xdd, 0x77, 0x77, 0x77, 0x77, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc,
-    0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x33, 0x33, 0x33, 0x33, 0x33,
-    0x33, 0x33, 0x33, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xcc, 0xcc,
-    0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc,
-    0xcc, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x33, 0x33, 0x33, 0x33,
-    0x33, 0x33, 0x33, 0x33, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0xcc,
-    0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-    0x00, 0x00, 0xb8, 0xc9, 0x00, 0x00, 0xb1, 0x2c, 0x00, 0x00, 0xb3, 0x69, 0x00, 0x00, 0xb5, 0x78, 0x00, 0x00, 0xb7, 0x29, 0x00,
-    0x00, 0xb0, 0xa8, 0x00, 0x00, 0xb2, 0xce, 0x00, 0x00, 0xb4, 0xf4, 0x00, 0x00, 0xb6, 0x9f, 0x00, 0x00, 0xb0, 0x2a, 0x00, 0x00,
-    0xb2, 0x45, 0x00, 0x00, 0xb4, 0x76, 0x00, 0x00, 0xb6, 0x0e, 0x00, 0x00, 0xaf, 0xa6, 0x00, 0x00, 0xb1, 0xaa, 0x00, 0x00, 0xb3,
-    0xf2, 0x00, 0x00, 0xba, 0x86, 0x00, 0x00, 0xbb, 0x99, 0x00, 0x00, 0xbc, 0xd3, 0x00, 0x00, 0xbe, 0x26, 0x00, 0x00, 0xba, 0xc1,
-    0x00, 0x00, 0xbb, 0xd1, 0x00, 0x00, 0xbd, 0x0e, 0x00, 0x00, 0xbe, 0x5e, 0x00, 0x00, 0xbb, 0x15, 0x00, 0x00, 0xbc, 0x01, 0x00,
-    0x00, 0xbd, 0x41, 0x00, 0x00, 0xbe, 0x8e, 0x00, 0x00, 0xbb, 0x4c, 0x00, 0x00, 0xbc, 0x63, 0x00, 0x00, 0xbd, 0xac, 0x00, 0x00,
-    0xbe, 0xf0, 0xc4, 0x7f, 0xcc, 0x0f, 0x07, 0x83, 0xc0, 0x80, 0x00, 0xf0, 0x00, 0x00, 0x10, 0x4f, 0x00, 0x00, 0xc0, 0x60, 0xf0,
-    0x78, 0x3c, 0x1e, 0x0f, 0x06, 0x20, 0xc0, 0xf0, 0x38, 0x3c, 0x0c, 0x0f, 0x07, 0xc3, 0x03, 0xc1, 0x20, 0x00, 0x0c, 0x0d, 0x80,
-    0x01, 0x83, 0x80, 0xe0, 0x1c, 0x0c, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x78, 0x18, 0x3f, 0x1f, 0x81, 0x8f, 0xf3,
-    0xf3, 0xfc, 0xfc, 0x7e, 0x00, 0x00, 0x00, 0xc0, 0x03, 0x01, 0xf8, 0x00, 0x18, 0x7f, 0x1f, 0x9f, 0x8f, 0xf7, 0xf9, 0xf9, 0x86,
-    0x7e, 0x03, 0x30, 0xd8, 0x0c, 0x37, 0x19, 0xf9, 0xfc, 0x3c, 0x7f, 0x1f, 0x9f, 0xec, 0x36, 0x1b, 0x0d, 0x86, 0xc3, 0x7f, 0x8f,
-    0x0c, 0x03, 0xc0, 0xc0, 0x00, 0xe0, 0x00, 0x60, 0x00, 0x00, 0x60, 0x00, 0xf0, 0x01, 0x80, 0x00, 0x00, 0x30, 0x0f, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xc3, 0x0f, 0x00, 0x00, 0x00, 0xa4,
-    0x7f, 0xd2, 0x09, 0x04, 0x82, 0x41, 0x40, 0x00, 0x48, 0x02, 0x3f, 0xe8, 0x89, 0x00, 0x81, 0x20, 0x90, 0x48, 0x24, 0x12, 0x09,
-    0x04, 0x85, 0x21, 0x20, 0x90, 0x44, 0x24, 0x12, 0x09, 0x04, 0x44, 0x81, 0x21, 0x20, 0x00, 0x0c, 0x0d, 0x80, 0x07, 0xe6, 0xc1,
-    0xb0, 0x18, 0x18, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xcc, 0x38, 0x73, 0xb9, 0xc3, 0x8c, 0x07, 0x38, 0x0d, 0xce,
-    0xe7, 0x00, 0x00, 0x01, 0x80, 0x01, 0x83, 0x0c, 0x00, 0x3c, 0x63, 0xb9, 0xd9, 0xcc, 0x06, 0x03, 0x9d, 0x86, 0x18, 0x03, 0x31,
-    0x98, 0x0e, 0x77, 0x1b, 0x9d, 0x8e, 0x66, 0x63, 0xb9, 0xc3, 0x0c, 0x36, 0x1b, 0x0c, 0xcc, 0x66, 0x01, 0x8c, 0x0c, 0x00, 0xc1,
-    0xe0, 0x00, 0x60, 0x00, 0x60, 0x00, 0x00, 0x60, 0x01, 0x98, 0x01, 0x80, 0x18, 0x06, 0x30, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x03, 0x01, 0x80, 0x00, 0x10, 0xa4, 0x7f, 0xd0, 0x08,
-    0x04, 0x02, 0x02, 0x20, 0x30, 0x48, 0x01, 0x3f, 0xe8, 0x88, 0x01, 0x01, 0x00, 0x80, 0x48, 0x24, 0x12, 0x09, 0x04, 0x85, 0x21,
-    0x00, 0x80, 0x40, 0x20, 0x10, 0x08, 0x04, 0x04, 0x01, 0xc1, 0x20, 0x00, 0x0c, 0x0d, 0x8c, 0xcf, 0xf6, 0xdb, 0x30, 0x38, 0x30,
-    0x06, 0x26, 0x43, 0x00, 0x00, 0x00, 0x00, 0x19, 0x86, 0x78, 0x01, 0x80, 0xc7, 0x8c, 0x06, 0x00, 0x0d, 0x86, 0xc3, 0x00, 0x00,
-    0x03, 0x00, 0x00, 0xc3, 0x0c, 0x00, 0x66, 0x61, 0xb0, 0x18, 0xec, 0x06, 0x03, 0x01, 0x86, 0x18, 0x03, 0x33, 0x18, 0x0f, 0xf7,
-    0x9b, 0x0d, 0x86, 0xc3, 0x61, 0xb0, 0x03, 0x0c, 0x36, 0x1b, 0x0c, 0xcc, 0x66, 0x01, 0x8c, 0x06, 0x00, 0xc3, 0x30, 0x00, 0x70,
-    0x00, 0x60, 0x00, 0x00, 0x60, 0x01, 0x80, 0x01, 0x80, 0x00, 0x00, 0x30, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x03, 0x01, 0x80, 0x00, 0x22, 0x94, 0x7f, 0xcc, 0x0e, 0x07, 0x03, 0x82,
-    0x20, 0x78, 0x78, 0x00, 0x80, 0x05, 0x0e, 0x02, 0x00, 0xc0, 0x60, 0x48, 0x24, 0x12, 0x09, 0x04, 0x84, 0xa0, 0xc0, 0xe0, 0x40,
-    0x38, 0x0c, 0x0e, 0x07, 0x05, 0x81, 0x41, 0x20, 0x00, 0x0c, 0x0d, 0x8c, 0xcd, 0x83, 0xb3, 0x30, 0x30, 0x30, 0x06, 0x16, 0x83,
-    0x00, 0x00, 0x00, 0x00, 0x19, 0x8e, 0x18, 0x03, 0x00, 0xcd, 0x8c, 0x06, 0x00, 0x0d, 0x86, 0xc3, 0x00, 0x07, 0x06, 0x00, 0x00,
-    0x60, 0x0c, 0xfc, 0xc3, 0x61, 0xb0, 0x18, 0x6c, 0x06, 0x03, 0x01, 0x86, 0x18, 0x03, 0x36, 0x18, 0x0f, 0xf7, 0x9b, 0x0d, 0x86,
-    0xc3, 0x61, 0xb0, 0x03, 0x0c, 0x36, 0x1b, 0x0c, 0x78, 0x3c, 0x03, 0x0c, 0x06, 0x00, 0xc6, 0x18, 0x00, 0x30, 0x00, 0x60, 0x00,
-    0x00, 0x60, 0x01, 0x80, 0x01, 0x80, 0x00, 0x00, 0x30, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x03, 0x01, 0x83, 0x00, 0x44, 0x94, 0x7f, 0xc2, 0x08, 0x04, 0x02, 0x03, 0xe0, 0x78, 0x48,
-    0x00, 0x40, 0x02, 0x08, 0x04, 0x00, 0x20, 0x10, 0x48, 0x24, 0x12, 0x09, 0x04, 0x84, 0xa0, 0x20, 0x80, 0x44, 0x20, 0x02, 0x08,
-    0x04, 0x04, 0x83, 0xa1, 0x20, 0x00, 0x0c, 0x00, 0x1f, 0xef, 0x80, 0x63, 0x60, 0x00, 0x30, 0x06, 0x0f, 0x03, 0x00, 0x00, 0x00,
-    0x00, 0x31, 0x9e, 0x18, 0x06, 0x01, 0xd9, 0x8c, 0x06, 0x00, 0x19, 0xce, 0xe7, 0x1c, 0x07, 0x0c, 0x0f, 0xf0, 0x30, 0x19, 0x86,
-    0xc3, 0x63, 0xb0, 0x18, 0x6c, 0x07, 0xe3, 0x01, 0x86, 0x18, 0x03, 0x3c, 0x18, 0x0d, 0xb7, 0xdb, 0x0d, 0x8e, 0xc3, 0x63, 0xb8,
-    0x03, 0x0c, 0x36, 0x1b, 0x0c, 0x78, 0x3c, 0x06, 0x0c, 0x03, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x7e, 0x60, 0x1f, 0x80, 0x67, 0xe1,
-    0x81, 0xf9, 0xfc, 0x18, 0x06, 0x31, 0x83, 0x0f, 0xe7, 0xe1, 0xf1, 0xfc, 0x7f, 0x7f, 0x1f, 0xdf, 0x8c, 0x66, 0x1b, 0x0d, 0x86,
-    0xc3, 0x7f, 0x8e, 0x03, 0x01, 0xc7, 0x88, 0x88, 0x8c, 0x7f, 0xd2, 0x09, 0x04, 0x82, 0x42, 0x20, 0x78, 0x48, 0x7f, 0xff, 0xe0,
-    0x08, 0x07, 0xfd, 0x20, 0x90, 0xf0, 0x78, 0x3c, 0x1e, 0x0f, 0x04, 0x61, 0x20, 0x90, 0x38, 0x24, 0x12, 0x09, 0x04, 0x03, 0x00,
-    0x01, 0x20, 0x00, 0x0c, 0x00, 0x0c, 0xc7, 0xe0, 0xc1, 0xc0, 0x00, 0x30, 0x06, 0x3f, 0xdf, 0xe0, 0x0f, 0xf0, 0x00, 0x31, 0xb6,
-    0x18, 0x0c, 0x1f, 0x9f, 0xef, 0xe7, 0xf0, 0x30, 0xfc, 0x7f, 0x1c, 0x00, 0x18, 0x0f, 0xf0, 0x18, 0x31, 0xba, 0xc3, 0x7f, 0x30,
-    0x18, 0x6f, 0xc6, 0x03, 0x01, 0xfe, 0x18, 0x03, 0x38, 0x18, 0x0c, 0x36, 0xdb, 0x0d, 0xfc, 0xc3, 0x7f, 0x1f, 0x83, 0x0c, 0x36,
-    0x1b, 0x6c, 0x30, 0x18, 0x3f, 0x0c, 0x03, 0x00, 0xc0, 0x00, 0x00, 0x00, 0xc3, 0x7f, 0x30, 0xcf, 0xec, 0x37, 0xe3, 0x0d, 0x86,
-    0x18, 0x06, 0x33, 0x03, 0x0d, 0xb6, 0x33, 0x19, 0x86, 0xc3, 0x70, 0x30, 0x06, 0x0c, 0x66, 0x1b, 0x0c, 0xcc, 0x66, 0x03, 0x3c,
-    0x03, 0x00, 0xf4, 0x78, 0x10, 0x00, 0x7f, 0xcc, 0x0f, 0x07, 0x83, 0xc2, 0x20, 0x78, 0xf3, 0x00, 0x7f, 0xe3, 0xe9, 0xe4, 0x00,
-    0xc0, 0x60, 0x00, 0x00, 0x03, 0xc0, 0xc0, 0x60, 0x00, 0xc0, 0xf0, 0x00, 0x3c, 0x0c, 0x0f, 0x04, 0x60, 0x30, 0x18, 0xcc, 0x00,
-    0x0c, 0x00, 0x0c, 0xc1, 0xf1, 0x81, 0xc0, 0x00, 0x30, 0x06, 0x0f, 0x1f, 0xe0, 0x0f, 0xf0, 0x00, 0x61, 0xe6, 0x18, 0x18, 0x01,
-    0xc1, 0x80, 0x76, 0x38, 0x61, 0xce, 0x03, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x30, 0x61, 0xa6, 0xff, 0x63, 0xb0, 0x18, 0x6c, 0x06,
-    0x03, 0x3d, 0x86, 0x18, 0x03, 0x3c, 0x18, 0x0c, 0x36, 0xfb, 0x0d, 0x80, 0xc3, 0x78, 0x01, 0xc3, 0x0c, 0x33, 0x33, 0x6c, 0x78,
-    0x18, 0x18, 0x0c, 0x01, 0x80, 0xc0, 0x00, 0x00, 0x00, 0x03, 0x61, 0xb0, 0x18, 0x6c, 0x31, 0x83, 0x0d, 0x86, 0x18, 0x06, 0x36,
-    0x03, 0x0d, 0xb6, 0x33, 0x19, 0x86, 0xc3, 0x60, 0x30, 0x06, 0x0c, 0x63, 0x33, 0x0c, 0x78, 0x66, 0x06, 0x0e, 0x03, 0x01, 0xc0,
-    0x30, 0x22, 0x11, 0x7f, 0xc0, 0x00, 0x00, 0x00, 0x38, 0x00, 0xcc, 0x04, 0x80, 0x80, 0x00, 0x81, 0x22, 0x00, 0x18, 0x0e, 0x1c,
-    0x06, 0x02, 0x41, 0x20, 0xa0, 0x90, 0x00, 0x00, 0x18, 0x80, 0x00, 0x00, 0x60, 0x90, 0x48, 0x24, 0x12, 0x00, 0x00, 0x00, 0x1f,
-    0xe1, 0xb3, 0x73, 0x60, 0x00, 0x30, 0x06, 0x16, 0x83, 0x03, 0x80, 0x00, 0x00, 0x61, 0xc6, 0x18, 0x30, 0x00, 0xc1, 0x80, 0x36,
-    0x18, 0xc1, 0x86, 0x03, 0x00, 0x07, 0x06, 0x0f, 0xf0, 0x60, 0x61, 0xb6, 0xc3, 0x61, 0xb0, 0x18, 0x6c, 0x06, 0x03, 0x0d, 0x86,
-    0x18, 0x03, 0x36, 0x18, 0x0c, 0x36, 0x7b, 0x0d, 0x80, 0xdb, 0x6c, 0x00, 0xc3, 0x0c, 0x33, 0x33, 0xfc, 0x78, 0x18, 0x30, 0x0c,
-    0x01, 0x80, 0xc0, 0x00, 0x00, 0x00, 0x7f, 0x61, 0xb0, 0x18, 0x6f, 0xe1, 0x83, 0x0d, 0x86, 0x18, 0x06, 0x3c, 0x03, 0x0d, 0xb6,
-    0x33, 0x19, 0x86, 0xc3, 0x60, 0x1f, 0x86, 0x0c, 0x63, 0x33, 0x6c, 0x30, 0x3c, 0x0c, 0x06, 0x03, 0x01, 0x80, 0x00, 0x44, 0x11,
-    0x7f, 0xc2, 0x21, 0x10, 0xf8, 0x44, 0x49, 0x86, 0x04, 0x01, 0x00, 0x00, 0x81, 0x01, 0x00, 0x24, 0x04, 0x08, 0x02, 0x00, 0x40,
-    0x21, 0x20, 0xa0, 0x44, 0x0e, 0x14, 0x98, 0xc3, 0x80, 0x90, 0x80, 0x40, 0x20, 0x10, 0x00, 0x00, 0x00, 0x0c, 0xcf, 0xf6, 0xdb,
-    0x3c, 0x00, 0x30, 0x06, 0x26, 0x43, 0x03, 0x80, 0x01, 0xc0, 0xc1, 0x86, 0x18, 0x60, 0x00, 0xc1, 0x80, 0x36, 0x18, 0xc1, 0x86,
-    0x03, 0x1c, 0x07, 0x03, 0x0f, 0xf0, 0xc0, 0x01, 0x9e, 0xc3, 0x61, 0xb0, 0x18, 0xec, 0x06, 0x03, 0x0d, 0x86, 0x18, 0x63, 0x33,
-    0x18, 0x0c, 0x36, 0x7b, 0x0d, 0x80, 0xdf, 0x66, 0x00, 0xc3, 0x0c, 0x31, 0xe3, 0xfc, 0xcc, 0x18, 0x60, 0x0c, 0x00, 0xc0, 0xc0,
-    0x00, 0x00, 0x00, 0xc3, 0x61, 0xb0, 0x18, 0x6c, 0x01, 0x81, 0xfd, 0x86, 0x18, 0x06, 0x3e, 0x03, 0x0d, 0xb6, 0x33, 0x19, 0x86,
-    0xc3, 0x60, 0x00, 0xc6, 0x0c, 0x61, 0xe3, 0xfc, 0x78, 0x18, 0x18, 0x06, 0x03, 0x01, 0x80, 0x00, 0x88, 0x11, 0x7f, 0xc1, 0x40,
-    0xa0, 0x20, 0x44, 0x51, 0xfe, 0x03, 0x02, 0x3f, 0xe0, 0x81, 0xc0, 0x80, 0x24, 0x04, 0x08, 0x02, 0x00, 0x80, 0xe1, 0xf0, 0xc0,
-    0x38, 0x09, 0x16, 0x95, 0x42, 0x40, 0x80, 0x60, 0x30, 0x18, 0x0c, 0x00, 0x0c, 0x00, 0x0c, 0xc7, 0xe0, 0xdb, 0x18, 0x00, 0x18,
-    0x0c, 0x00, 0x03, 0x03, 0x00, 0x01, 0xc0, 0xc0, 0xcc, 0x18, 0x60, 0x39, 0xc1, 0x8e, 0x77, 0x38, 0xc1, 0xce, 0xe7, 0x1c, 0x06,
-    0x01, 0x80, 0x01, 0x80, 0x00, 0xc0, 0xc3, 0x63, 0xb9, 0xd9, 0xcc, 0x06, 0x03, 0x9d, 0x86, 0x18, 0x77, 0x31, 0x98, 0x0c, 0x36,
-    0x3b, 0x9d, 0x80, 0x6e, 0x63, 0x39, 0xc3, 0x0e, 0x71, 0xe3, 0x9c, 0xcc, 0x18, 0x60, 0x0c, 0x00, 0xc0, 0xc0, 0x00, 0x00, 0x00,
-    0xc3, 0x61, 0xb0, 0xd8, 0x6c, 0x01, 0x80, 0x0d, 0x86, 0x18, 0x06, 0x33, 0x03, 0x0d, 0xb6, 0x33, 0x19, 0xfc, 0x7f, 0x60, 0x00,
-    0xc6, 0xcc, 0x61, 0xe3, 0x9c, 0xcc, 0x30, 0x30, 0x06, 0x03, 0x01, 0x80, 0x00, 0x10, 0x11, 0x7f, 0xc0, 0x80, 0x40, 0x20, 0x54,
-    0x60, 0x30, 0x00, 0x80, 0x3f, 0xe0, 0x81, 0x00, 0x00, 0x24, 0x04, 0x09, 0x02, 0x01, 0x00, 0x20, 0x20, 0xc0, 0x10, 0x0e, 0x12,
-    0x92, 0x43, 0x80, 0x80, 0x10, 0x08, 0x04, 0x02, 0x00, 0x0c, 0x00, 0x00, 0x01, 0x80, 0x71, 0xfc, 0x00, 0x0c, 0x18, 0x00, 0x00,
-    0x06, 0x00, 0x00, 0x01, 0x80, 0x78, 0x7e, 0x7f, 0x9f, 0x81, 0x87, 0xe3, 0xf0, 0xc0, 0xfc, 0x7e, 0x00, 0x0c, 0x00, 0xc0, 0x03,
-    0x00, 0x60, 0x7e, 0xc3, 0x7f, 0x1f, 0x9f, 0x8f, 0xf6, 0x01, 0xf9, 0x86, 0x7e, 0x3e, 0x30, 0xdf, 0xec, 0x36, 0x39, 0xf9, 0x80,
-    0x3f, 0x61, 0x9f, 0x83, 0x07, 0xe0, 0xc3, 0x0d, 0x86, 0x18, 0x7f, 0x8f, 0x00, 0x63, 0xc0, 0x00, 0x00, 0x00, 0x7f, 0x7f, 0x1f,
-    0x8f, 0xe7, 0xf1, 0x80, 0x0d, 0x86, 0x18, 0x06, 0x31, 0x8f, 0xcd, 0xb6, 0x31, 0xf1, 0x80, 0x03, 0x60, 0x3f, 0x83, 0x87, 0xe0,
-    0xc3, 0x0d, 0x86, 0x60, 0x7f, 0x83, 0xc3, 0x0f, 0x00, 0x00, 0x00, 0x11, 0x7f, 0xc1, 0x40, 0xa0, 0x20, 0x38, 0x50, 0x00, 0x04,
-    0x80, 0x00, 0x00, 0x01, 0x00, 0x00, 0x18, 0x04, 0x1f, 0x07, 0x03, 0xc1, 0x20, 0x20, 0xa0, 0x10, 0x09, 0x11, 0x90, 0x42, 0x40,
-    0x90, 0x90, 0x48, 0x24, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x01, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-];
+use crate::rom::ROM_HALF_SIZE;
+
+/// Low half of the 8;7;5 firmware, mapped at 0x00000.
+pub static LO_ROM: [u8; ROM_HALF_SIZE] = *include_bytes!("../roms/rom_lo.bin");
@@ -0,0 +1,177 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::coff::{self, Coff, CoffSymbol};
+use crate::err::SymbolError;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Symbols have no size, so an address is only resolved to a symbol
+/// at most this far below it.
+const MAX_OFFSET: u32 = 0x1000;
+
+/// Entry points in the 8;7;5 firmware.
+pub static ROM_875_SYMBOLS: &str = include_str!("../roms/rom_875.sym");
+
+/// Maps addresses to names, for resolving addresses to `symbol+offset`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SymbolTable {
+    symbols: BTreeMap<u32, String>,
+}
+
+impl SymbolTable {
+    pub fn new() -> SymbolTable {
+        SymbolTable::default()
+    }
+
+    /// Parse a text symbol table. Each line holds a hexadecimal
+    /// address and a name, separated by whitespace. Blank lines and
+    /// lines starting with `#` are ignored.
+    ///
+    ///   00001aec reset
+    ///   0x1dcc   splx
+    pub fn parse(text: &str) -> Result<SymbolTable, SymbolError> {
+        let mut table = SymbolTable::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let address = fields.next().unwrap();
+            let address = address.strip_prefix("0x").unwrap_or(address);
+            let address = u32::from_str_radix(address, 16).map_err(|_| SymbolError::Syntax(i + 1))?;
+
+            match (fields.next(), fields.next()) {
+                (Some(name), None) => table.insert(address, name),
+                _ => return Err(SymbolError::Syntax(i + 1)),
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Collect the external, static and label symbols defined in the
+    /// sections of a WE32000 COFF file. Section names are skipped.
+    pub fn from_coff(data: &[u8]) -> Result<SymbolTable, SymbolError> {
+        Ok(SymbolTable::from_coff_symbols(&Coff::parse(data)?.symbols))
+    }
+
+    /// As `from_coff`, for a file that has already been parsed.
+    pub fn from_coff_symbols(symbols: &[CoffSymbol]) -> SymbolTable {
+        let mut table = SymbolTable::new();
+
+        for sym in symbols {
+            let class = sym.class == coff::C_EXT || sym.class == coff::C_STAT || sym.class == coff::C_LABEL;
+            if class && sym.section > 0 && !sym.name.is_empty() && !sym.name.starts_with('.') {
+                table.insert(sym.value, &sym.name);
+            }
+        }
+
+        table
+    }
+
+    /// Read a symbol table file, which is either a COFF file or in
+    /// the text format.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SymbolTable, SymbolError> {
+        let data = fs::read(path)?;
+
+        if coff::be16(&data, 0).ok() == Some(coff::WE32MAGIC) {
+            SymbolTable::from_coff(&data)
+        } else {
+            SymbolTable::parse(&String::from_utf8_lossy(&data))
+        }
+    }
+
+    /// Add a symbol, replacing any other at the same address.
+    pub fn insert(&mut self, address: u32, name: &str) {
+        self.symbols.insert(address, name.to_string());
+    }
+
+    /// Add all symbols from `other`, which replace any at the same
+    /// addresses.
+    pub fn extend(&mut self, other: &SymbolTable) {
+        self.symbols.extend(other.symbols.iter().map(|(a, n)| (*a, n.clone())));
+    }
+
+    pub fn clear(&mut self) {
+        self.symbols.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Iterate over the symbols in address order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.symbols.iter().map(|(a, n)| (*a, n.as_str()))
+    }
+
+    pub fn address_of(&self, name: &str) -> Option<u32> {
+        self.symbols.iter().find(|(_, n)| *n == name).map(|(a, _)| *a)
+    }
+
+    /// Find the closest symbol at or below `address`, and the offset
+    /// of `address` from it.
+    pub fn lookup(&self, address: u32) -> Option<(&str, u32)> {
+        self.symbols
+            .range(..=address)
+            .next_back()
+            .map(|(a, n)| (n.as_str(), address - a))
+            .filter(|(_, offset)| *offset < MAX_OFFSET)
+    }
+
+    /// Render `address` as `symbol` or `symbol+0x10`, if it can be
+    /// resolved.
+    pub fn symbolize(&self, address: u32) -> Option<String> {
+        self.lookup(address).map(|(name, offset)| match offset {
+            0 => name.to_string(),
+            _ => format!("{}+0x{:x}", name, offset),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_text_symbols() {
+        let table = SymbolTable::parse("# comment\n\n00001aec reset\n  0x1dcc\tsplx  \n").unwrap();
+
+        assert_eq!(2, table.len());
+        assert_eq!(Some(0x1aec), table.address_of("reset"));
+        assert_eq!(Some(0x1dcc), table.address_of("splx"));
+
+        assert!(matches!(SymbolTable::parse("1aec\n"), Err(SymbolError::Syntax(1))));
+        assert!(matches!(SymbolTable::parse("\nreset 1aec\n"), Err(SymbolError::Syntax(2))));
+        assert!(matches!(SymbolTable::parse("1aec reset now\n"), Err(SymbolError::Syntax(1))));
+    }
+
+    #[test]
+    fn resolves_symbol_and_offset() {
+        let mut table = SymbolTable::new();
+        table.insert(0x1aec, "reset");
+        table.insert(0x700000, "main");
+
+        assert_eq!(Some(("reset", 0)), table.lookup(0x1aec));
+        assert_eq!(Some("reset".to_string()), table.symbolize(0x1aec));
+        assert_eq!(Some("reset+0x14".to_string()), table.symbolize(0x1b00));
+        assert_eq!(Some("main+0x2".to_string()), table.symbolize(0x700002));
+        assert_eq!(None, table.symbolize(0x1000));
+        assert_eq!(None, table.symbolize(0x2aec));
+    }
+
+    #[test]
+    fn bundled_rom_symbols_parse() {
+        let table = SymbolTable::parse(ROM_875_SYMBOLS).unwrap();
+        assert_eq!(Some(0x1aec), table.address_of("reset"));
+    }
+}
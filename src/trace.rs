@@ -0,0 +1,241 @@
+#![allow(clippy::unreadable_literal)]
+
+use crate::cpu::{CpuLevel, Instruction, R_PC, R_PSW};
+use crate::disasm::{self, REGISTER_NAMES};
+use crate::err::CpuError;
+use crate::symbols::SymbolTable;
+
+use std::io::Write;
+use std::ops::Range;
+use std::sync::Arc;
+
+const PSW_FLAGS: [(u32, char); 4] = [(0x00200000, 'N'), (0x00100000, 'Z'), (0x00080000, 'V'), (0x00040000, 'C')];
+
+//
+// Execution Trace
+//
+// Each traced step is written as one line: the PC and privilege
+// level, the disassembled instruction, the values moved to or from
+// its operands, every register it changed, and the PSW flags.
+//
+//   00700000 K  movw &0x600000,%r6           [600000 600000] %r6=00600000 ----
+//
+// With a symbol table, the PC, branch targets and absolute addresses
+// are also shown as symbol+offset.
+//
+//   00700000 <start> K  brb 0x700003 <start+0x3>     [0] ----
+//
+
+/// Selects which steps are traced. An empty filter traces everything.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TraceFilter {
+    /// Only trace instructions at these addresses
+    pub addresses: Option<Range<u32>>,
+    /// Only trace at these privilege levels. Empty means all levels.
+    pub levels: Vec<CpuLevel>,
+    /// Only trace these opcodes. Empty means all opcodes.
+    pub opcodes: Vec<u16>,
+}
+
+impl TraceFilter {
+    pub fn matches(&self, pc: u32, level: CpuLevel, opcode: Option<u16>) -> bool {
+        let in_range = match &self.addresses {
+            Some(r) => r.contains(&pc),
+            None => true,
+        };
+        in_range
+            && (self.levels.is_empty() || self.levels.contains(&level))
+            && (self.opcodes.is_empty() || opcode.is_some_and(|op| self.opcodes.contains(&op)))
+    }
+}
+
+/// CPU state at the start of a traced step.
+struct Snapshot {
+    r: [u32; 16],
+    level: CpuLevel,
+}
+
+/// Writes an execution trace. A Tracer with no writer, or one that
+/// is disabled, costs the CPU a single test per step.
+#[derive(Default)]
+pub struct Tracer {
+    writer: Option<Box<dyn Write + Send>>,
+    filter: TraceFilter,
+    enabled: bool,
+    start: Option<Snapshot>,
+    symbols: Option<Arc<SymbolTable>>,
+}
+
+fn level_name(level: CpuLevel) -> char {
+    match level {
+        CpuLevel::Kernel => 'K',
+        CpuLevel::Executive => 'E',
+        CpuLevel::Supervisor => 'S',
+        CpuLevel::User => 'U',
+    }
+}
+
+fn flags(psw: u32) -> String {
+    PSW_FLAGS
+        .iter()
+        .map(|(mask, name)| {
+            if psw & mask != 0 {
+                *name
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+impl Tracer {
+    pub fn new() -> Tracer {
+        Tracer::default()
+    }
+
+    /// Write the trace to `writer`, replacing any previous writer.
+    pub fn set_writer<W: Write + Send + 'static>(&mut self, writer: W) {
+        self.close();
+        self.writer = Some(Box::new(writer));
+    }
+
+    /// Flush and drop the writer. Tracing stops until a new writer is
+    /// set.
+    pub fn close(&mut self) {
+        if let Some(mut w) = self.writer.take() {
+            let _ = w.flush();
+        }
+        self.start = None;
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.start = None;
+            if let Some(w) = self.writer.as_mut() {
+                let _ = w.flush();
+            }
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// True when steps are being traced.
+    pub fn is_active(&self) -> bool {
+        self.enabled && self.writer.is_some()
+    }
+
+    pub fn filter(&self) -> &TraceFilter {
+        &self.filter
+    }
+
+    pub fn set_filter(&mut self, filter: TraceFilter) {
+        self.filter = filter;
+    }
+
+    /// Resolve addresses in the trace with `symbols`.
+    pub fn set_symbols(&mut self, symbols: Option<Arc<SymbolTable>>) {
+        self.symbols = symbols;
+    }
+
+    /// Start a step, once any pending interrupt has been taken.
+    pub(crate) fn begin(&mut self, r: &[u32; 16], level: CpuLevel) {
+        self.start = Some(Snapshot {
+            r: *r,
+            level,
+        });
+    }
+
+    /// Finish a step. `instr` is the instruction executed, or None if
+    /// it could not be decoded. A write error ends the trace.
+    pub(crate) fn end(&mut self, instr: Option<&Instruction>, r: &[u32; 16], result: &Result<i32, CpuError>) {
+        let start = match self.start.take() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let pc = start.r[R_PC];
+
+        if !self.filter.matches(pc, start.level, instr.map(|i| i.opcode)) {
+            return;
+        }
+
+        let symbols = self.symbols.as_deref();
+
+        let mut line = format!("{:08x} ", pc);
+        if let Some(symbol) = symbols.and_then(|s| s.symbolize(pc)) {
+            line.push_str(&format!("<{}> ", symbol));
+        }
+        line.push_str(&format!("{}  ", level_name(start.level)));
+
+        match instr {
+            Some(instr) => {
+                let text = disasm::format_instruction(instr, pc, symbols).replace('\t', " ");
+                line.push_str(&format!("{:<28}", text));
+
+                let values: Vec<String> =
+                    instr.operands.iter().take_while(|op| op.size > 0).map(|op| format!("{:x}", op.data)).collect();
+                if !values.is_empty() {
+                    line.push_str(&format!(" [{}]", values.join(" ")));
+                }
+            }
+            None => line.push_str(&format!("{:<28}", "???")),
+        }
+
+        for (i, (old, new)) in start.r.iter().zip(r.iter()).enumerate() {
+            if i != R_PC && i != R_PSW && old != new {
+                line.push_str(&format!(" {}={:08x}", REGISTER_NAMES[i], new));
+            }
+        }
+
+        line.push(' ');
+        line.push_str(&flags(r[R_PSW]));
+
+        if let Err(e) = result {
+            line.push_str(&format!(" ! {}", e));
+        }
+
+        if let Some(w) = self.writer.as_mut() {
+            if writeln!(w, "{}", line).is_err() {
+                self.close();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter = TraceFilter::default();
+        assert!(filter.matches(0x4460, CpuLevel::Kernel, Some(0x84)));
+        assert!(filter.matches(0x700000, CpuLevel::User, None));
+    }
+
+    #[test]
+    fn filters_by_address_level_and_opcode() {
+        let filter = TraceFilter {
+            addresses: Some(0x700000..0x800000),
+            levels: vec![CpuLevel::User, CpuLevel::Supervisor],
+            opcodes: vec![0x84, 0x3009],
+        };
+
+        assert!(filter.matches(0x700010, CpuLevel::User, Some(0x84)));
+        assert!(filter.matches(0x7ffffe, CpuLevel::Supervisor, Some(0x3009)));
+        assert!(!filter.matches(0x4460, CpuLevel::User, Some(0x84)));
+        assert!(!filter.matches(0x700010, CpuLevel::Kernel, Some(0x84)));
+        assert!(!filter.matches(0x700010, CpuLevel::User, Some(0x70)));
+        assert!(!filter.matches(0x700010, CpuLevel::User, None));
+    }
+
+    #[test]
+    fn renders_psw_flags() {
+        assert_eq!("----", flags(0));
+        assert_eq!("-Z--", flags(0x00100000));
+        assert_eq!("N--C", flags(0x00240000));
+    }
+}